@@ -30,10 +30,7 @@ fn main() {
                 }),
         )
         .add_plugins(EguiPlugin::default())
-        .add_plugins(KonnektorenThemePlugin)
-        .add_plugins(UIPlugin)
-        .add_plugins(ScreensPlugin)
-        .add_plugins(InputPlugin)
+        .add_plugins(KonnektorenPlugins)
         .add_systems(Startup, setup_demo)
         .add_systems(
             Update,
@@ -0,0 +1,322 @@
+use super::{ChallengeAsset, KonnektorenAssetRegistry, LevelAsset};
+use bevy::{
+    asset::{io::Reader, AssetLoader, LoadContext, LoadState},
+    prelude::*,
+    reflect::TypePath,
+};
+use serde::Deserialize;
+use serde_yaml;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// A single asset inside an asset pack
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AssetPackEntry {
+    /// Logical ID used to register the asset in the registry
+    pub id: String,
+    /// Asset path, may point to a remote source (e.g. `https://...`)
+    pub path: String,
+}
+
+/// An installable bundle of challenges and levels
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AssetPack {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    /// Image path or URL shown in the marketplace
+    #[serde(default)]
+    pub thumbnail: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub challenges: Vec<AssetPackEntry>,
+    #[serde(default)]
+    pub levels: Vec<AssetPackEntry>,
+}
+
+impl AssetPack {
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            description: String::new(),
+            version: None,
+            thumbnail: None,
+            tags: vec![],
+            challenges: vec![],
+            levels: vec![],
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    pub fn with_thumbnail(mut self, thumbnail: impl Into<String>) -> Self {
+        self.thumbnail = Some(thumbnail.into());
+        self
+    }
+
+    pub fn add_challenge(mut self, id: impl Into<String>, path: impl Into<String>) -> Self {
+        self.challenges.push(AssetPackEntry {
+            id: id.into(),
+            path: path.into(),
+        });
+        self
+    }
+
+    pub fn add_level(mut self, id: impl Into<String>, path: impl Into<String>) -> Self {
+        self.levels.push(AssetPackEntry {
+            id: id.into(),
+            path: path.into(),
+        });
+        self
+    }
+}
+
+/// Manifest listing the asset packs that are available for installation.
+///
+/// The manifest is loaded through the asset server, so it can live in the
+/// local assets folder or on a remote asset source
+/// (e.g. `https://example.com/content.packs.yml` with bevy's `https` feature).
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct AssetPackManifest {
+    pub packs: Vec<AssetPack>,
+}
+
+impl AssetPackManifest {
+    /// Get a pack by ID
+    pub fn get_pack(&self, id: &str) -> Option<&AssetPack> {
+        self.packs.iter().find(|pack| pack.id == id)
+    }
+}
+
+/// Loader for asset pack manifests in YAML format
+#[derive(Default)]
+pub struct AssetPackManifestLoader;
+
+/// Possible errors that can be produced by AssetPackManifestLoader
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum AssetPackManifestLoaderError {
+    /// An IO Error
+    #[error("Could not load asset pack manifest: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A YAML parsing error
+    #[error("Could not parse YAML asset pack manifest: {0}")]
+    YamlError(#[from] serde_yaml::Error),
+
+    /// The manifest breaks a content rule
+    #[error("Invalid asset pack manifest: {0}")]
+    Invalid(String),
+}
+
+impl AssetLoader for AssetPackManifestLoader {
+    type Asset = AssetPackManifest;
+    type Settings = ();
+    type Error = AssetPackManifestLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let manifest = serde_yaml::from_slice::<AssetPackManifest>(&bytes)?;
+        let issues = super::manifest_issues(&manifest);
+        if !issues.is_empty() {
+            return Err(AssetPackManifestLoaderError::Invalid(
+                super::validation::join_issues(&issues),
+            ));
+        }
+
+        info!(
+            "Loaded asset pack manifest with {} packs",
+            manifest.packs.len()
+        );
+
+        Ok(manifest)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["packs.yml", "packs.yaml"]
+    }
+}
+
+/// Installation status of an asset pack
+#[derive(Debug, Clone, PartialEq)]
+pub enum AssetPackStatus {
+    /// Assets are being downloaded/loaded
+    Installing,
+    /// All assets of the pack are loaded and registered
+    Installed,
+    /// At least one asset of the pack failed to load
+    Failed(String),
+}
+
+/// Resource tracking installed asset packs
+#[derive(Resource, Default, Debug)]
+pub struct InstalledAssetPacks {
+    pub packs: HashMap<String, AssetPack>,
+    pub status: HashMap<String, AssetPackStatus>,
+}
+
+impl InstalledAssetPacks {
+    /// Get the status of a pack, `None` if it was never installed
+    pub fn get_status(&self, pack_id: &str) -> Option<&AssetPackStatus> {
+        self.status.get(pack_id)
+    }
+
+    /// Check if a pack is fully installed
+    pub fn is_installed(&self, pack_id: &str) -> bool {
+        matches!(self.status.get(pack_id), Some(AssetPackStatus::Installed))
+    }
+
+    /// Get all installed pack IDs
+    pub fn get_installed_packs(&self) -> Vec<String> {
+        self.status
+            .iter()
+            .filter_map(|(id, status)| {
+                if *status == AssetPackStatus::Installed {
+                    Some(id.clone())
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// Message requesting the installation of an asset pack
+#[derive(Message, Debug, Clone)]
+pub struct InstallAssetPack {
+    pub pack: AssetPack,
+}
+
+/// Messages about asset pack installation progress
+#[derive(Message, Debug, Clone)]
+pub enum AssetPackEvent {
+    /// All assets of the pack finished loading
+    Installed { pack_id: String },
+    /// The pack could not be installed
+    Failed { pack_id: String, error: String },
+}
+
+/// System to start loading the assets of requested packs, packs installed
+/// or being installed are skipped
+pub fn install_asset_packs(
+    mut install_events: MessageReader<InstallAssetPack>,
+    asset_server: Res<AssetServer>,
+    mut registry: ResMut<KonnektorenAssetRegistry>,
+    mut installed: ResMut<InstalledAssetPacks>,
+) {
+    for event in install_events.read() {
+        let pack = &event.pack;
+
+        match installed.get_status(&pack.id) {
+            Some(AssetPackStatus::Installed) => {
+                info!("Asset pack '{}' is already installed", pack.id);
+                continue;
+            }
+            Some(AssetPackStatus::Installing) => {
+                info!("Asset pack '{}' is already being installed", pack.id);
+                continue;
+            }
+            Some(AssetPackStatus::Failed(_)) | None => {}
+        }
+
+        info!(
+            "Installing asset pack '{}' ({} challenges, {} levels)",
+            pack.id,
+            pack.challenges.len(),
+            pack.levels.len()
+        );
+
+        for entry in &pack.challenges {
+            let handle: Handle<ChallengeAsset> = asset_server.load(entry.path.clone());
+            registry.register_challenge(entry.id.clone(), handle);
+        }
+
+        for entry in &pack.levels {
+            let handle: Handle<LevelAsset> = asset_server.load(entry.path.clone());
+            registry.register_level(entry.id.clone(), handle);
+        }
+
+        installed.packs.insert(pack.id.clone(), pack.clone());
+        installed
+            .status
+            .insert(pack.id.clone(), AssetPackStatus::Installing);
+    }
+}
+
+/// System to track the loading state of installing packs
+pub fn track_asset_pack_installation(
+    asset_server: Res<AssetServer>,
+    registry: Res<KonnektorenAssetRegistry>,
+    mut installed: ResMut<InstalledAssetPacks>,
+    mut pack_events: MessageWriter<AssetPackEvent>,
+) {
+    let installing: Vec<String> = installed
+        .status
+        .iter()
+        .filter(|(_, status)| **status == AssetPackStatus::Installing)
+        .map(|(id, _)| id.clone())
+        .collect();
+
+    for pack_id in installing {
+        let Some(pack) = installed.packs.get(&pack_id) else {
+            continue;
+        };
+
+        let challenge_states = pack.challenges.iter().map(|entry| {
+            let state = registry
+                .get_challenge_handle(&entry.id)
+                .map(|handle| asset_server.load_state(handle.id()));
+            (registry.is_challenge_loaded(&entry.id), state)
+        });
+        let level_states = pack.levels.iter().map(|entry| {
+            let state = registry
+                .get_level_handle(&entry.id)
+                .map(|handle| asset_server.load_state(handle.id()));
+            (registry.is_level_loaded(&entry.id), state)
+        });
+
+        let mut all_loaded = true;
+        let mut failure = None;
+        for (loaded, state) in challenge_states.chain(level_states) {
+            if let Some(LoadState::Failed(error)) = state {
+                failure = Some(error.to_string());
+                break;
+            }
+            all_loaded &= loaded;
+        }
+
+        if let Some(error) = failure {
+            warn!("Asset pack '{}' failed to install: {}", pack_id, error);
+            installed
+                .status
+                .insert(pack_id.clone(), AssetPackStatus::Failed(error.clone()));
+            pack_events.write(AssetPackEvent::Failed { pack_id, error });
+        } else if all_loaded {
+            info!("Asset pack '{}' installed", pack_id);
+            installed
+                .status
+                .insert(pack_id.clone(), AssetPackStatus::Installed);
+            pack_events.write(AssetPackEvent::Installed { pack_id });
+        }
+    }
+}
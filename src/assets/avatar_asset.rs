@@ -0,0 +1,109 @@
+use bevy::{
+    asset::{io::Reader, AssetLoader, LoadContext},
+    prelude::*,
+    reflect::TypePath,
+};
+use serde::Deserialize;
+use serde_yaml;
+use thiserror::Error;
+
+/// A selectable avatar part, e.g. a hairstyle
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AvatarPart {
+    pub id: String,
+    pub name: String,
+    /// Image path, relative to the assets folder
+    pub image: String,
+}
+
+/// A layer of the avatar, drawn bottom to top in the order of the part set
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AvatarPartLayer {
+    pub id: String,
+    pub name: String,
+    /// Whether the player may tint this layer with a theme color
+    #[serde(default)]
+    pub tintable: bool,
+    /// Whether the layer may be left empty
+    #[serde(default)]
+    pub optional: bool,
+    pub parts: Vec<AvatarPart>,
+}
+
+impl AvatarPartLayer {
+    /// Get a part by ID
+    pub fn get_part(&self, id: &str) -> Option<&AvatarPart> {
+        self.parts.iter().find(|part| part.id == id)
+    }
+}
+
+/// Asset describing the layered parts an avatar can be built from.
+///
+/// A plain sprite set is a part set with a single layer.
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct AvatarPartSet {
+    pub id: String,
+    pub name: String,
+    pub layers: Vec<AvatarPartLayer>,
+}
+
+impl AvatarPartSet {
+    /// Get a layer by ID
+    pub fn get_layer(&self, id: &str) -> Option<&AvatarPartLayer> {
+        self.layers.iter().find(|layer| layer.id == id)
+    }
+
+    /// Get all image paths used by this part set
+    pub fn image_paths(&self) -> impl Iterator<Item = &str> {
+        self.layers
+            .iter()
+            .flat_map(|layer| layer.parts.iter().map(|part| part.image.as_str()))
+    }
+}
+
+/// Loader for avatar part sets in YAML format
+#[derive(Default)]
+pub struct AvatarPartSetLoader;
+
+/// Possible errors that can be produced by AvatarPartSetLoader
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum AvatarPartSetLoaderError {
+    /// An IO Error
+    #[error("Could not load avatar part set: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A YAML parsing error
+    #[error("Could not parse YAML avatar part set: {0}")]
+    YamlError(#[from] serde_yaml::Error),
+}
+
+impl AssetLoader for AvatarPartSetLoader {
+    type Asset = AvatarPartSet;
+    type Settings = ();
+    type Error = AvatarPartSetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let part_set = serde_yaml::from_slice::<AvatarPartSet>(&bytes)?;
+
+        info!(
+            "Loaded avatar part set '{}' with {} layers",
+            part_set.name,
+            part_set.layers.len()
+        );
+
+        Ok(part_set)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["avatar.yml", "avatar.yaml"]
+    }
+}
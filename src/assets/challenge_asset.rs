@@ -49,7 +49,10 @@ impl ChallengeAsset {
 
 /// Loader for challenge files in YAML format
 #[derive(Default)]
-pub struct ChallengeAssetLoader;
+pub struct ChallengeAssetLoader {
+    /// Applied to every challenge before it is validated
+    pub transforms: super::SharedContentTransforms<ChallengeType>,
+}
 
 /// Possible errors that can be produced by ChallengeAssetLoader
 #[non_exhaustive]
@@ -63,6 +66,14 @@ pub enum ChallengeAssetLoaderError {
     /// A YAML parsing error
     #[error("Could not parse YAML challenge: {0}")]
     YamlError(#[from] serde_yaml::Error),
+
+    /// A JSON parsing error
+    #[error("Could not parse JSON challenge: {0}")]
+    JsonError(#[from] serde_json::Error),
+
+    /// The challenge breaks a content rule
+    #[error("Invalid challenge: {0}")]
+    Invalid(String),
 }
 
 #[cfg(not(feature = "assets"))]
@@ -100,8 +111,24 @@ impl AssetLoader for ChallengeAssetLoader {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
 
-        let challenge_type = serde_yaml::from_slice::<ChallengeType>(&bytes)?;
+        let is_json = load_context
+            .path()
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+        let mut challenge_type = if is_json {
+            serde_json::from_slice::<ChallengeType>(&bytes)?
+        } else {
+            serde_yaml::from_slice::<ChallengeType>(&bytes)?
+        };
         let file_path = load_context.path().to_string_lossy().to_string();
+        self.transforms.apply(&mut challenge_type, &file_path);
+
+        let issues = super::challenge_issues(&challenge_type);
+        if !issues.is_empty() {
+            return Err(ChallengeAssetLoaderError::Invalid(
+                super::validation::join_issues(&issues),
+            ));
+        }
 
         info!(
             "Loaded challenge '{}' ({}) from {}",
@@ -117,7 +144,7 @@ impl AssetLoader for ChallengeAssetLoader {
     }
 
     fn extensions(&self) -> &[&str] {
-        &["yml", "yaml"]
+        &["yml", "yaml", "json"]
     }
 }
 
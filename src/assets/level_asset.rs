@@ -63,7 +63,10 @@ impl LevelAsset {
 
 /// Loader for level files in YAML format
 #[derive(Default)]
-pub struct LevelAssetLoader;
+pub struct LevelAssetLoader {
+    /// Applied to every level before it is validated
+    pub transforms: super::SharedContentTransforms<GamePath>,
+}
 
 /// Possible errors that can be produced by LevelAssetLoader
 #[non_exhaustive]
@@ -77,6 +80,10 @@ pub enum LevelAssetLoaderError {
     /// A YAML parsing error
     #[error("Could not parse YAML level: {0}")]
     YamlError(#[from] serde_yaml::Error),
+
+    /// The level breaks a content rule
+    #[error("Invalid level: {0}")]
+    Invalid(String),
 }
 
 #[cfg(not(feature = "assets"))]
@@ -114,8 +121,16 @@ impl AssetLoader for LevelAssetLoader {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
 
-        let game_path = serde_yaml::from_slice::<GamePath>(&bytes)?;
+        let mut game_path = serde_yaml::from_slice::<GamePath>(&bytes)?;
         let file_path = load_context.path().to_string_lossy().to_string();
+        self.transforms.apply(&mut game_path, &file_path);
+
+        let issues = super::level_issues(&game_path);
+        if !issues.is_empty() {
+            return Err(LevelAssetLoaderError::Invalid(
+                super::validation::join_issues(&issues),
+            ));
+        }
 
         info!(
             "Loaded level '{}' ({}) with {} challenges from {}",
@@ -1,27 +1,69 @@
+pub mod asset_pack;
+pub mod avatar_asset;
 pub mod challenge_asset;
 pub mod level_asset;
-
+#[cfg(feature = "editor")]
+pub mod preview;
+pub mod survey_asset;
+pub mod transform;
+pub mod validation;
+
+pub use asset_pack::*;
+pub use avatar_asset::*;
 use bevy::prelude::*;
 pub use challenge_asset::*;
 pub use level_asset::*;
+#[cfg(feature = "editor")]
+pub use preview::*;
 use std::collections::HashMap;
+pub use survey_asset::*;
+pub use transform::*;
+pub use validation::*;
 
 /// Plugin for loading Konnektoren assets (challenges, levels)
 /// This plugin is focused on data loading only - no game logic
+///
+/// The challenge and level loaders apply the [`ContentTransform`]s added
+/// with the [`ContentTransformPlugin`].
+#[derive(Default)]
 pub struct KonnektorenAssetsPlugin;
 
 impl Plugin for KonnektorenAssetsPlugin {
     fn build(&self, app: &mut App) {
+        app.init_resource::<LoaderContentTransforms>();
+        let transforms = app.world().resource::<LoaderContentTransforms>().clone();
+
         app
             // Register asset types and loaders
             .init_asset::<ChallengeAsset>()
-            .init_asset_loader::<ChallengeAssetLoader>()
+            .register_asset_loader(ChallengeAssetLoader {
+                transforms: transforms.challenges,
+            })
             .init_asset::<LevelAsset>()
-            .init_asset_loader::<LevelAssetLoader>()
+            .register_asset_loader(LevelAssetLoader {
+                transforms: transforms.levels,
+            })
+            .init_asset::<AssetPackManifest>()
+            .init_asset_loader::<AssetPackManifestLoader>()
+            .init_asset::<AvatarPartSet>()
+            .init_asset_loader::<AvatarPartSetLoader>()
+            .init_asset::<SurveyAsset>()
+            .init_asset_loader::<SurveyAssetLoader>()
             // Initialize shared asset registry
             .init_resource::<KonnektorenAssetRegistry>()
-            // Add asset tracking system
-            .add_systems(Update, update_asset_registry);
+            .init_resource::<InstalledAssetPacks>()
+            .add_message::<InstallAssetPack>()
+            .add_message::<AssetPackEvent>()
+            // Add asset tracking systems
+            .add_systems(
+                Update,
+                (
+                    install_asset_packs,
+                    update_asset_registry,
+                    track_asset_pack_installation,
+                )
+                    .chain(),
+            );
     }
 }
 
@@ -53,6 +95,24 @@ impl KonnektorenAssetRegistry {
         self.level_holders.insert(id, handle);
     }
 
+    /// Remove a challenge from the registry.
+    ///
+    /// The asset is unloaded once no other handle holds it.
+    pub fn unregister_challenge(&mut self, id: &str) -> Option<Handle<ChallengeAsset>> {
+        self.challenge_holders.remove(id);
+        self.loaded_challenges.remove(id);
+        self.challenges.remove(id)
+    }
+
+    /// Remove a level from the registry.
+    ///
+    /// The asset is unloaded once no other handle holds it.
+    pub fn unregister_level(&mut self, id: &str) -> Option<Handle<LevelAsset>> {
+        self.level_holders.remove(id);
+        self.loaded_levels.remove(id);
+        self.levels.remove(id)
+    }
+
     /// Get a challenge handle by ID
     pub fn get_challenge_handle(&self, id: &str) -> Option<&Handle<ChallengeAsset>> {
         self.challenges.get(id)
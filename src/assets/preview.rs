@@ -0,0 +1,200 @@
+use super::ChallengeAsset;
+#[cfg(feature = "profile")]
+use crate::profile::ChallengeRuntimeEvent;
+use crate::ui::notifications::Notification;
+use bevy::{asset::AssetLoadFailedEvent, prelude::*};
+use konnektoren_core::challenges::challenge_type::ChallengeType;
+
+/// Plugin for the content preview mode.
+///
+/// While [`ContentPreview`] is enabled, a change to the file of the played
+/// challenge restarts it at the same question: the plugin sends a
+/// [`ChallengePreviewRestart`] for the game to restart its challenge runtime
+/// with. Saving in the challenge editor or in a text editor gives content
+/// authors a near-instant feedback loop.
+///
+/// Files are only reloaded when the asset server watches for changes, which
+/// needs Bevy's `file_watcher` feature on native:
+///
+/// ```ignore
+/// app.add_plugins(ContentPreviewPlugin)
+///     .insert_resource(ContentPreview::enabled());
+///
+/// fn restart_challenge(mut restarts: MessageReader<ChallengePreviewRestart>) {
+///     for restart in restarts.read() {
+///         // Start the challenge of `restart.handle` at `restart.question_index`
+///     }
+/// }
+/// ```
+///
+/// The played challenge and question are taken from the
+/// [`ChallengeRuntimeEvent`]s of the game with the `profile` feature, or set
+/// with [`ContentPreview::start`] and [`ContentPreview::show_question`].
+///
+/// [`ChallengeRuntimeEvent`]: crate::profile::ChallengeRuntimeEvent
+pub struct ContentPreviewPlugin;
+
+impl Plugin for ContentPreviewPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ContentPreview>()
+            .add_message::<ChallengePreviewRestart>()
+            .add_message::<Notification>()
+            .add_systems(Startup, check_preview_file_watching)
+            .add_systems(
+                Update,
+                (restart_modified_challenges, report_preview_load_failures),
+            );
+
+        #[cfg(feature = "profile")]
+        app.add_message::<ChallengeRuntimeEvent>().add_systems(
+            Update,
+            track_previewed_challenge.before(restart_modified_challenges),
+        );
+    }
+}
+
+/// Resource with the state of the content preview mode
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContentPreview {
+    pub enabled: bool,
+    /// ID of the challenge being played
+    pub challenge_id: Option<String>,
+    /// Index of the question shown in the challenge
+    pub question_index: usize,
+}
+
+impl ContentPreview {
+    /// Preview mode switched on
+    pub fn enabled() -> Self {
+        Self {
+            enabled: true,
+            ..default()
+        }
+    }
+
+    /// A challenge was started
+    pub fn start(&mut self, challenge_id: impl Into<String>) {
+        self.challenge_id = Some(challenge_id.into());
+        self.question_index = 0;
+    }
+
+    /// A question of the challenge was shown
+    pub fn show_question(&mut self, index: usize) {
+        self.question_index = index;
+    }
+
+    /// The challenge ended, changes to it no longer restart anything
+    pub fn stop(&mut self) {
+        self.challenge_id = None;
+        self.question_index = 0;
+    }
+}
+
+/// Message to restart the previewed challenge after its file changed
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct ChallengePreviewRestart {
+    pub challenge_id: String,
+    pub handle: Handle<ChallengeAsset>,
+    /// Question to continue at, within the questions of the reloaded challenge
+    pub question_index: usize,
+}
+
+/// Number of questions of a challenge, if its type has a list of questions
+fn question_count(challenge: &ChallengeType) -> Option<usize> {
+    match challenge {
+        ChallengeType::MultipleChoice(multiple_choice) => Some(multiple_choice.questions.len()),
+        _ => None,
+    }
+}
+
+fn check_preview_file_watching(asset_server: Res<AssetServer>, preview: Res<ContentPreview>) {
+    if preview.enabled && !asset_server.watching_for_changes() {
+        warn!("Content preview is enabled but the asset server does not watch for changes, enable Bevy's file_watcher feature to reload edited files");
+    }
+}
+
+/// System to follow the played challenge and question
+#[cfg(feature = "profile")]
+fn track_previewed_challenge(
+    mut events: MessageReader<ChallengeRuntimeEvent>,
+    mut preview: ResMut<ContentPreview>,
+) {
+    for event in events.read() {
+        match event {
+            ChallengeRuntimeEvent::Started { challenge_id } => preview.start(challenge_id.clone()),
+            ChallengeRuntimeEvent::QuestionShown { index, .. } => preview.show_question(*index),
+            ChallengeRuntimeEvent::Finished { .. } => preview.stop(),
+            ChallengeRuntimeEvent::AnswerChosen { .. }
+            | ChallengeRuntimeEvent::AudioPlayed { .. } => {}
+        }
+    }
+}
+
+/// System to restart the previewed challenge when its asset was reloaded
+fn restart_modified_challenges(
+    mut asset_events: MessageReader<AssetEvent<ChallengeAsset>>,
+    mut challenges: ResMut<Assets<ChallengeAsset>>,
+    mut preview: ResMut<ContentPreview>,
+    mut restarts: MessageWriter<ChallengePreviewRestart>,
+    mut notifications: MessageWriter<Notification>,
+) {
+    for event in asset_events.read() {
+        let AssetEvent::Modified { id } = event else {
+            continue;
+        };
+
+        if !preview.enabled {
+            continue;
+        }
+
+        let Some(challenge) = challenges.get(*id) else {
+            continue;
+        };
+
+        if preview.challenge_id.as_deref() != Some(challenge.id()) {
+            continue;
+        }
+
+        let question_index = match question_count(&challenge.challenge_type) {
+            Some(count) => preview.question_index.min(count.saturating_sub(1)),
+            None => preview.question_index,
+        };
+        let challenge_id = challenge.id().to_string();
+        let name = challenge.name().to_string();
+
+        let Some(handle) = challenges.get_strong_handle(*id) else {
+            continue;
+        };
+
+        info!(
+            "Restarting previewed challenge '{}' at question {}",
+            challenge_id, question_index
+        );
+        preview.question_index = question_index;
+        notifications.write(Notification::info(
+            "Challenge reloaded",
+            format!("{} at question {}", name, question_index + 1),
+        ));
+        restarts.write(ChallengePreviewRestart {
+            challenge_id,
+            handle,
+            question_index,
+        });
+    }
+}
+
+/// System to tell content authors why an edited challenge did not reload
+fn report_preview_load_failures(
+    mut failures: MessageReader<AssetLoadFailedEvent<ChallengeAsset>>,
+    preview: Res<ContentPreview>,
+    mut notifications: MessageWriter<Notification>,
+) {
+    for failure in failures.read() {
+        if preview.enabled {
+            notifications.write(Notification::error(
+                format!("{} not reloaded", failure.path),
+                failure.error.to_string(),
+            ));
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use bevy::{
+    asset::{io::Reader, AssetLoader, LoadContext},
+    prelude::*,
+    reflect::TypePath,
+};
+use serde::Deserialize;
+use serde_yaml;
+use thiserror::Error;
+
+fn default_likert_scale() -> u8 {
+    5
+}
+
+/// Kind of a survey question
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SurveyQuestionKind {
+    /// Pick exactly one of the options
+    SingleChoice { options: Vec<String> },
+    /// Rate on a scale from 1 to `scale`
+    Likert {
+        #[serde(default = "default_likert_scale")]
+        scale: u8,
+        #[serde(default)]
+        min_label: Option<String>,
+        #[serde(default)]
+        max_label: Option<String>,
+    },
+    /// Answer with free text
+    FreeText {
+        #[serde(default)]
+        multiline: bool,
+        #[serde(default)]
+        max_length: Option<usize>,
+    },
+}
+
+/// A single question of a survey
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SurveyQuestion {
+    pub id: String,
+    pub text: String,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(flatten)]
+    pub kind: SurveyQuestionKind,
+}
+
+/// Asset describing a survey, loaded from `*.survey.yml` files
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct SurveyAsset {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub questions: Vec<SurveyQuestion>,
+}
+
+impl SurveyAsset {
+    /// Get a question by ID
+    pub fn get_question(&self, id: &str) -> Option<&SurveyQuestion> {
+        self.questions.iter().find(|question| question.id == id)
+    }
+}
+
+/// Loader for surveys in YAML format
+#[derive(Default)]
+pub struct SurveyAssetLoader;
+
+/// Possible errors that can be produced by SurveyAssetLoader
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum SurveyAssetLoaderError {
+    /// An IO Error
+    #[error("Could not load survey asset: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A YAML parsing error
+    #[error("Could not parse YAML survey: {0}")]
+    YamlError(#[from] serde_yaml::Error),
+}
+
+impl AssetLoader for SurveyAssetLoader {
+    type Asset = SurveyAsset;
+    type Settings = ();
+    type Error = SurveyAssetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let survey = serde_yaml::from_slice::<SurveyAsset>(&bytes)?;
+
+        info!(
+            "Loaded survey '{}' ({}) with {} questions",
+            survey.title,
+            survey.id,
+            survey.questions.len()
+        );
+
+        Ok(survey)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["survey.yml", "survey.yaml"]
+    }
+}
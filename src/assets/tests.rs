@@ -1,6 +1,6 @@
 use super::{
-    ChallengeAsset, KonnektorenAssetLoader, KonnektorenAssetRegistry, KonnektorenAssetsPlugin,
-    LevelAsset,
+    AssetPack, AssetPackStatus, ChallengeAsset, InstallAssetPack, InstalledAssetPacks,
+    KonnektorenAssetLoader, KonnektorenAssetRegistry, KonnektorenAssetsPlugin, LevelAsset,
 };
 use bevy::asset::AssetPlugin;
 use bevy::prelude::*;
@@ -128,6 +128,29 @@ fn test_real_asset_loading() {
     // In a real scenario, you'd wait for asset events or use asset loading states
 }
 
+#[test]
+fn test_double_install_request() {
+    let mut app = create_test_app();
+    let pack = AssetPack::new("starter", "Starter")
+        .with_version("1.0")
+        .add_challenge("articles", "challenges/articles.yml");
+
+    app.world_mut()
+        .write_message(InstallAssetPack { pack: pack.clone() });
+    app.world_mut().write_message(InstallAssetPack {
+        pack: pack.with_version("2.0"),
+    });
+    app.update();
+
+    // The second request does not restart the installation
+    let installed = app.world().resource::<InstalledAssetPacks>();
+    assert_eq!(installed.packs["starter"].version.as_deref(), Some("1.0"));
+    assert!(matches!(
+        installed.get_status("starter"),
+        Some(AssetPackStatus::Installing | AssetPackStatus::Installed)
+    ));
+}
+
 // Test the asset registry methods more thoroughly
 #[test]
 fn test_asset_registry_methods() {
@@ -173,3 +196,208 @@ fn test_update_system() {
     assert!(!registry.is_challenge_loaded("test_challenge"));
     assert!(!registry.is_level_loaded("test_level"));
 }
+
+#[test]
+fn test_survey_asset_parsing() {
+    use super::{SurveyAsset, SurveyQuestionKind};
+
+    let yaml = include_str!("../../assets/feedback.survey.yml");
+    let survey: SurveyAsset = serde_yaml::from_str(yaml).unwrap();
+
+    assert_eq!(survey.id, "classroom-feedback");
+    assert_eq!(survey.questions.len(), 3);
+
+    let difficulty = survey.get_question("difficulty").unwrap();
+    assert!(difficulty.required);
+    assert_eq!(
+        difficulty.kind,
+        SurveyQuestionKind::Likert {
+            scale: 5,
+            min_label: Some("Too easy".to_string()),
+            max_label: Some("Too hard".to_string()),
+        }
+    );
+
+    let favorite = survey.get_question("favorite").unwrap();
+    assert!(!favorite.required);
+    assert!(matches!(
+        &favorite.kind,
+        SurveyQuestionKind::SingleChoice { options } if options.len() == 3
+    ));
+
+    let comment = survey.get_question("comment").unwrap();
+    assert_eq!(
+        comment.kind,
+        SurveyQuestionKind::FreeText {
+            multiline: true,
+            max_length: Some(500),
+        }
+    );
+}
+
+#[test]
+fn test_content_validation() {
+    use super::{validate_content, ContentKind};
+    use std::path::Path;
+
+    assert_eq!(
+        ContentKind::from_path(Path::new("assets/a1.level.yml")),
+        Some(ContentKind::Level)
+    );
+    assert_eq!(
+        ContentKind::from_path(Path::new("assets/feedback.survey.yml")),
+        None
+    );
+
+    let challenge = include_bytes!("../../assets/challenges/articles.yml");
+    assert!(validate_content(ContentKind::Challenge, challenge).is_empty());
+    let level = include_bytes!("../../assets/a1.level.yml");
+    assert!(validate_content(ContentKind::Level, level).is_empty());
+
+    let broken = validate_content(ContentKind::Level, b"id: test\nname: [");
+    assert_eq!(broken.len(), 1);
+    assert!(broken[0].line.is_some());
+
+    let manifest = b"packs:\n  - id: a\n    name: A\n    challenges:\n      - id: c\n        path: ''\n  - id: a\n    name: B\n";
+    assert_eq!(validate_content(ContentKind::Manifest, manifest).len(), 2);
+}
+
+#[cfg(feature = "editor")]
+#[test]
+fn test_preview_restarts_modified_challenge() {
+    use super::{ChallengePreviewRestart, ContentPreview, ContentPreviewPlugin};
+    use konnektoren_core::challenges::{
+        challenge_type::ChallengeType,
+        multiple_choice::{MultipleChoice, Question},
+    };
+
+    let question = |text: &str| Question {
+        question: text.to_string(),
+        help: String::new(),
+        option: 0,
+        image: None,
+    };
+
+    let mut app = create_test_app();
+    app.add_plugins(ContentPreviewPlugin);
+    let handle = app
+        .world_mut()
+        .resource_mut::<Assets<ChallengeAsset>>()
+        .add(ChallengeAsset {
+            challenge_type: ChallengeType::MultipleChoice(MultipleChoice {
+                id: "articles".to_string(),
+                name: "Artikel".to_string(),
+                lang: "de".to_string(),
+                options: vec![],
+                questions: vec![question("Haus"), question("Baum"), question("Katze")],
+            }),
+            file_path: "challenges/articles.yml".to_string(),
+        });
+    app.update();
+
+    let mut preview = ContentPreview::enabled();
+    preview.start("articles");
+    preview.show_question(2);
+    app.insert_resource(preview);
+
+    // An author removes the last question
+    {
+        let mut challenges = app.world_mut().resource_mut::<Assets<ChallengeAsset>>();
+        let challenge = challenges.get_mut(&handle).unwrap();
+        let ChallengeType::MultipleChoice(multiple_choice) = &mut challenge.challenge_type else {
+            unreachable!();
+        };
+        multiple_choice.questions.pop();
+    }
+    // Asset events are sent at the end of a frame and read in the next one
+    app.update();
+    app.update();
+
+    let restarts: Vec<_> = app
+        .world_mut()
+        .resource_mut::<Messages<ChallengePreviewRestart>>()
+        .drain()
+        .collect();
+    assert_eq!(restarts.len(), 1);
+    assert_eq!(restarts[0].challenge_id, "articles");
+    assert_eq!(restarts[0].handle.id(), handle.id());
+    assert_eq!(restarts[0].question_index, 1);
+}
+
+#[test]
+fn test_content_transforms() {
+    use super::{ContentTransforms, ReplaceText};
+    use konnektoren_core::challenges::{
+        challenge_type::ChallengeType,
+        multiple_choice::{MultipleChoice, Question},
+    };
+
+    let question = |text: &str| Question {
+        question: text.to_string(),
+        help: String::new(),
+        option: 0,
+        image: None,
+    };
+    let mut challenge = ChallengeType::MultipleChoice(MultipleChoice {
+        id: "greetings".to_string(),
+        name: "Grüße aus {city}".to_string(),
+        lang: "de".to_string(),
+        options: vec![],
+        questions: vec![question("Hallo {name}"), question("Tschüss {name}")],
+    });
+
+    let mut transforms = ContentTransforms::default();
+    transforms.push(
+        ReplaceText::new()
+            .with("{city}", "Wien")
+            .with("{name}", "Ada"),
+    );
+    transforms.push(|challenge: &mut ChallengeType, _file_path: &str| {
+        if let ChallengeType::MultipleChoice(multiple_choice) = challenge {
+            multiple_choice.questions.truncate(1);
+        }
+    });
+    transforms.apply(&mut challenge, "greetings.yml");
+
+    let ChallengeType::MultipleChoice(multiple_choice) = challenge else {
+        unreachable!();
+    };
+    assert_eq!(multiple_choice.name, "Grüße aus Wien");
+    assert_eq!(multiple_choice.questions.len(), 1);
+    assert_eq!(multiple_choice.questions[0].question, "Hallo Ada");
+}
+
+#[test]
+fn test_loader_applies_challenge_transform() {
+    use super::ContentTransformPlugin;
+    use konnektoren_core::challenges::challenge_type::ChallengeType;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .add_plugins(ContentTransformPlugin::default().with_challenge_transform(
+            |challenge: &mut ChallengeType, file_path: &str| {
+                if let ChallengeType::MultipleChoice(multiple_choice) = challenge {
+                    multiple_choice.name = format!("Preview of {}", file_path);
+                }
+            },
+        ))
+        // Transforms apply regardless of the plugin order
+        .add_plugins(KonnektorenAssetsPlugin);
+    let handle = app.load_challenge("articles", "challenges/articles.yml");
+
+    for _ in 0..200 {
+        app.update();
+        if app
+            .world()
+            .resource::<Assets<ChallengeAsset>>()
+            .contains(&handle)
+        {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    let challenges = app.world().resource::<Assets<ChallengeAsset>>();
+    let challenge = challenges.get(&handle).expect("challenge loaded");
+    assert_eq!(challenge.name(), "Preview of challenges/articles.yml");
+}
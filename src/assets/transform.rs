@@ -0,0 +1,239 @@
+use bevy::{log::warn, prelude::*};
+use konnektoren_core::{challenges::challenge_type::ChallengeType, game::GamePath};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    fmt,
+    sync::{Arc, RwLock},
+};
+
+/// Plugin adding [`ContentTransform`]s to the challenge and level loaders of
+/// the [`KonnektorenAssetsPlugin`], in any order with that plugin. It can be
+/// added several times, the transforms apply in the order they were added.
+///
+/// [`KonnektorenAssetsPlugin`]: super::KonnektorenAssetsPlugin
+#[derive(Default)]
+pub struct ContentTransformPlugin {
+    pub challenge_transforms: ContentTransforms<ChallengeType>,
+    pub level_transforms: ContentTransforms<GamePath>,
+}
+
+impl ContentTransformPlugin {
+    pub fn with_challenge_transform(
+        mut self,
+        transform: impl ContentTransform<ChallengeType>,
+    ) -> Self {
+        self.challenge_transforms.push(transform);
+        self
+    }
+
+    pub fn with_level_transform(mut self, transform: impl ContentTransform<GamePath>) -> Self {
+        self.level_transforms.push(transform);
+        self
+    }
+}
+
+impl Plugin for ContentTransformPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LoaderContentTransforms>();
+        let transforms = app.world().resource::<LoaderContentTransforms>();
+        transforms.challenges.extend(&self.challenge_transforms);
+        transforms.levels.extend(&self.level_transforms);
+    }
+
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+/// Resource with the transforms of the challenge and level loaders, shared
+/// with the registered loaders
+#[derive(Resource, Debug, Clone, Default)]
+pub struct LoaderContentTransforms {
+    pub challenges: SharedContentTransforms<ChallengeType>,
+    pub levels: SharedContentTransforms<GamePath>,
+}
+
+/// Hook changing content after it is parsed, before the loader validates
+/// and stores the asset.
+///
+/// Transforms are configured per loader with the [`ContentTransformPlugin`],
+/// e.g. to substitute variables, apply locale specific replacements or filter
+/// questions by difficulty. Closures taking the content and its file path
+/// are transforms too:
+///
+/// ```ignore
+/// app.add_plugins(KonnektorenAssetsPlugin).add_plugins(
+///     ContentTransformPlugin::default()
+///         .with_challenge_transform(ReplaceText::new().with("{school}", "Goethe-Schule"))
+///         .with_challenge_transform(|challenge: &mut ChallengeType, _path: &str| {
+///             if let ChallengeType::MultipleChoice(multiple_choice) = challenge {
+///                 multiple_choice.questions.truncate(10);
+///             }
+///         }),
+/// );
+/// ```
+pub trait ContentTransform<T>: Send + Sync + 'static {
+    /// Change the content loaded from the file at `file_path`
+    fn transform(&self, content: &mut T, file_path: &str);
+}
+
+impl<T, F> ContentTransform<T> for F
+where
+    F: Fn(&mut T, &str) + Send + Sync + 'static,
+{
+    fn transform(&self, content: &mut T, file_path: &str) {
+        self(content, file_path)
+    }
+}
+
+/// Transforms of one loader, applied in the order they were added
+pub struct ContentTransforms<T: 'static>(Vec<Arc<dyn ContentTransform<T>>>);
+
+impl<T: 'static> Default for ContentTransforms<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T: 'static> Clone for ContentTransforms<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: 'static> fmt::Debug for ContentTransforms<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ContentTransforms({})", self.0.len())
+    }
+}
+
+impl<T: 'static> ContentTransforms<T> {
+    pub fn push(&mut self, transform: impl ContentTransform<T>) {
+        self.0.push(Arc::new(transform));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn apply(&self, content: &mut T, file_path: &str) {
+        for transform in &self.0 {
+            transform.transform(content, file_path);
+        }
+    }
+}
+
+/// Transforms of one loader shared with the app, transforms added after the
+/// loader was registered apply to the assets loaded from then on
+pub struct SharedContentTransforms<T: 'static>(Arc<RwLock<ContentTransforms<T>>>);
+
+impl<T: 'static> Default for SharedContentTransforms<T> {
+    fn default() -> Self {
+        Self(Arc::default())
+    }
+}
+
+impl<T: 'static> Clone for SharedContentTransforms<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: 'static> fmt::Debug for SharedContentTransforms<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.read() {
+            Ok(transforms) => transforms.fmt(f),
+            Err(_) => write!(f, "ContentTransforms(poisoned)"),
+        }
+    }
+}
+
+impl<T: 'static> SharedContentTransforms<T> {
+    pub fn push(&self, transform: impl ContentTransform<T>) {
+        if let Ok(mut transforms) = self.0.write() {
+            transforms.push(transform);
+        }
+    }
+
+    /// Append the transforms in their order
+    pub fn extend(&self, other: &ContentTransforms<T>) {
+        if let Ok(mut transforms) = self.0.write() {
+            transforms.0.extend(other.0.iter().cloned());
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0
+            .read()
+            .map_or(true, |transforms| transforms.is_empty())
+    }
+
+    pub fn apply(&self, content: &mut T, file_path: &str) {
+        if let Ok(transforms) = self.0.read() {
+            transforms.apply(content, file_path);
+        }
+    }
+}
+
+/// Transform replacing text in every string of the content, e.g. to fill in
+/// `{variables}` or to swap regional words like "Brötchen" for "Semmel"
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReplaceText {
+    replacements: Vec<(String, String)>,
+}
+
+impl ReplaceText {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace every occurrence of `from` with `to`
+    pub fn with(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.replacements.push((from.into(), to.into()));
+        self
+    }
+
+    fn replace_in(&self, value: &mut serde_yaml::Value) {
+        match value {
+            serde_yaml::Value::String(text) => {
+                for (from, to) in &self.replacements {
+                    if text.contains(from.as_str()) {
+                        *text = text.replace(from.as_str(), to);
+                    }
+                }
+            }
+            serde_yaml::Value::Sequence(values) => {
+                values.iter_mut().for_each(|value| self.replace_in(value));
+            }
+            serde_yaml::Value::Mapping(mapping) => {
+                mapping
+                    .values_mut()
+                    .for_each(|value| self.replace_in(value));
+            }
+            serde_yaml::Value::Tagged(tagged) => self.replace_in(&mut tagged.value),
+            _ => {}
+        }
+    }
+}
+
+impl<T> ContentTransform<T> for ReplaceText
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    fn transform(&self, content: &mut T, file_path: &str) {
+        if self.replacements.is_empty() {
+            return;
+        }
+
+        // Walk the strings through the YAML representation, which works for
+        // every content type the loaders parse
+        let replaced = serde_yaml::to_value(&*content).and_then(|mut value| {
+            self.replace_in(&mut value);
+            serde_yaml::from_value::<T>(value)
+        });
+        match replaced {
+            Ok(replaced) => *content = replaced,
+            Err(error) => warn!("Could not replace text in {}: {}", file_path, error),
+        }
+    }
+}
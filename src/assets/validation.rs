@@ -0,0 +1,185 @@
+use super::AssetPackManifest;
+use konnektoren_core::{challenges::challenge_type::ChallengeType, game::GamePath};
+use std::{collections::HashSet, fmt, path::Path};
+
+/// Kind of content file, detected from the file name like the asset loaders do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentKind {
+    Challenge,
+    Level,
+    Manifest,
+}
+
+impl ContentKind {
+    /// Detect the kind from the extension, `None` for files the loaders ignore
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?.to_lowercase();
+        if name.ends_with(".level.yml") || name.ends_with(".level.yaml") {
+            Some(ContentKind::Level)
+        } else if name.ends_with(".packs.yml") || name.ends_with(".packs.yaml") {
+            Some(ContentKind::Manifest)
+        } else if name.ends_with(".survey.yml") || name.ends_with(".survey.yaml") {
+            // Surveys have their own loader and format
+            None
+        } else if name.ends_with(".yml") || name.ends_with(".yaml") {
+            Some(ContentKind::Challenge)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentKind::Challenge => "challenge",
+            ContentKind::Level => "level",
+            ContentKind::Manifest => "manifest",
+        }
+    }
+}
+
+impl fmt::Display for ContentKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A problem found in a content file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentIssue {
+    /// 1-based line, if known
+    pub line: Option<usize>,
+    /// 1-based column, if known
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl ContentIssue {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    fn from_yaml(error: &serde_yaml::Error) -> Self {
+        let location = error.location();
+        Self {
+            line: location.as_ref().map(|l| l.line()),
+            column: location.as_ref().map(|l| l.column()),
+            message: error.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ContentIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// Rules a challenge has to satisfy beyond parsing
+pub fn challenge_issues(challenge: &ChallengeType) -> Vec<ContentIssue> {
+    let mut issues = Vec::new();
+    if challenge.id().trim().is_empty() {
+        issues.push(ContentIssue::new("challenge id is empty"));
+    }
+    if challenge.name().trim().is_empty() {
+        issues.push(ContentIssue::new(format!(
+            "challenge '{}' has no name",
+            challenge.id()
+        )));
+    }
+    issues
+}
+
+/// Rules a level has to satisfy beyond parsing
+pub fn level_issues(level: &GamePath) -> Vec<ContentIssue> {
+    let mut issues = Vec::new();
+    if level.id.trim().is_empty() {
+        issues.push(ContentIssue::new("level id is empty"));
+    }
+
+    let mut ids = HashSet::new();
+    for (index, entry) in level.challenges.iter().enumerate() {
+        if entry.challenge.trim().is_empty() {
+            issues.push(ContentIssue::new(format!(
+                "challenges[{}] '{}' does not reference a challenge",
+                index, entry.id
+            )));
+        }
+        if !ids.insert(entry.id.as_str()) {
+            issues.push(ContentIssue::new(format!(
+                "challenges[{}] duplicates id '{}'",
+                index, entry.id
+            )));
+        }
+    }
+    issues
+}
+
+/// Rules an asset pack manifest has to satisfy beyond parsing
+pub fn manifest_issues(manifest: &AssetPackManifest) -> Vec<ContentIssue> {
+    let mut issues = Vec::new();
+    let mut pack_ids = HashSet::new();
+
+    for pack in &manifest.packs {
+        if pack.id.trim().is_empty() {
+            issues.push(ContentIssue::new(format!(
+                "pack '{}' has an empty id",
+                pack.name
+            )));
+        } else if !pack_ids.insert(pack.id.as_str()) {
+            issues.push(ContentIssue::new(format!(
+                "pack id '{}' is used more than once",
+                pack.id
+            )));
+        }
+
+        for (section, entries) in [("challenges", &pack.challenges), ("levels", &pack.levels)] {
+            let mut ids = HashSet::new();
+            for entry in entries {
+                if entry.path.trim().is_empty() {
+                    issues.push(ContentIssue::new(format!(
+                        "pack '{}' {} entry '{}' has an empty path",
+                        pack.id, section, entry.id
+                    )));
+                }
+                if !ids.insert(entry.id.as_str()) {
+                    issues.push(ContentIssue::new(format!(
+                        "pack '{}' {} entry id '{}' is used more than once",
+                        pack.id, section, entry.id
+                    )));
+                }
+            }
+        }
+    }
+    issues
+}
+
+/// Parse and validate a content file without Bevy.
+///
+/// Runs the same parsing and rules as the asset loaders, an empty result
+/// means the file loads.
+pub fn validate_content(kind: ContentKind, bytes: &[u8]) -> Vec<ContentIssue> {
+    let parsed = match kind {
+        ContentKind::Challenge => {
+            serde_yaml::from_slice::<ChallengeType>(bytes).map(|c| challenge_issues(&c))
+        }
+        ContentKind::Level => serde_yaml::from_slice::<GamePath>(bytes).map(|l| level_issues(&l)),
+        ContentKind::Manifest => {
+            serde_yaml::from_slice::<AssetPackManifest>(bytes).map(|m| manifest_issues(&m))
+        }
+    };
+
+    parsed.unwrap_or_else(|error| vec![ContentIssue::from_yaml(&error)])
+}
+
+/// Join issues into one loader error message
+pub(crate) fn join_issues(issues: &[ContentIssue]) -> String {
+    issues
+        .iter()
+        .map(ContentIssue::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
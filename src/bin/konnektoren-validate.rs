@@ -0,0 +1,157 @@
+//! Validate challenge, level and asset pack manifest files outside of Bevy.
+//!
+//! ```text
+//! konnektoren-validate [--format text|json] <file or directory>...
+//! ```
+//!
+//! Directories are searched recursively, the file kind is detected from the
+//! extension like the asset loaders do. Every issue is printed on its own line,
+//! either as `path:line:column: kind: message` or as a JSON object. The exit
+//! code is 1 if any issue was found and 2 on usage errors.
+
+use konnektoren_bevy::assets::{validate_content, ContentIssue, ContentKind};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Text,
+    Json,
+}
+
+const USAGE: &str = "usage: konnektoren-validate [--format text|json] <file or directory>...";
+
+fn main() -> ExitCode {
+    let mut format = Format::Text;
+    let mut paths = Vec::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => match args.next().as_deref() {
+                Some("text") => format = Format::Text,
+                Some("json") => format = Format::Json,
+                _ => {
+                    eprintln!("{}", USAGE);
+                    return ExitCode::from(2);
+                }
+            },
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            _ => paths.push(PathBuf::from(arg)),
+        }
+    }
+
+    if paths.is_empty() {
+        eprintln!("{}", USAGE);
+        return ExitCode::from(2);
+    }
+
+    let mut files = Vec::new();
+    let mut failed = false;
+    for path in &paths {
+        if let Err(error) = collect_files(path, &mut files) {
+            print_issue(format, path, None, &ContentIssue::new(error.to_string()));
+            failed = true;
+        }
+    }
+
+    for (path, kind) in &files {
+        let issues = match fs::read(path) {
+            Ok(bytes) => validate_content(*kind, &bytes),
+            Err(error) => vec![ContentIssue::new(error.to_string())],
+        };
+        for issue in &issues {
+            print_issue(format, path, Some(*kind), issue);
+        }
+        failed |= !issues.is_empty();
+    }
+
+    if format == Format::Text {
+        eprintln!(
+            "checked {} file(s), {}",
+            files.len(),
+            if failed { "found issues" } else { "all valid" }
+        );
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Collect content files, explicitly named files are checked as challenges
+/// if their kind can't be detected
+fn collect_files(path: &Path, files: &mut Vec<(PathBuf, ContentKind)>) -> std::io::Result<()> {
+    if !path.is_dir() {
+        fs::metadata(path)?;
+        let kind = ContentKind::from_path(path).unwrap_or(ContentKind::Challenge);
+        files.push((path.to_path_buf(), kind));
+        return Ok(());
+    }
+
+    let mut entries = fs::read_dir(path)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort();
+
+    for entry in entries {
+        if entry.is_dir() {
+            collect_files(&entry, files)?;
+        } else if let Some(kind) = ContentKind::from_path(&entry) {
+            files.push((entry, kind));
+        }
+    }
+    Ok(())
+}
+
+fn print_issue(format: Format, path: &Path, kind: Option<ContentKind>, issue: &ContentIssue) {
+    let file = path.display().to_string();
+    match format {
+        Format::Text => println!(
+            "{}:{}:{}: {}: {}",
+            file,
+            issue.line.unwrap_or(0),
+            issue.column.unwrap_or(0),
+            kind.map_or("error", |kind| kind.as_str()),
+            issue.message
+        ),
+        Format::Json => println!(
+            "{{\"file\":{},\"kind\":{},\"line\":{},\"column\":{},\"message\":{}}}",
+            json_string(&file),
+            kind.map_or("null".to_string(), |kind| json_string(kind.as_str())),
+            json_number(issue.line),
+            json_number(issue.column),
+            json_string(&issue.message)
+        ),
+    }
+}
+
+fn json_number(value: Option<usize>) -> String {
+    value.map_or("null".to_string(), |value| value.to_string())
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
@@ -60,6 +60,7 @@ impl InputController {
 
 /// Input source tracking
 #[derive(Reflect, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
 pub enum InputSource {
     Keyboard(KeyboardScheme),
     Gamepad(Entity),
@@ -81,6 +82,7 @@ impl InputSource {
 /// Component to map specific inputs to a player
 #[derive(Component, Reflect, Clone)]
 #[reflect(Component)]
+#[require(GamepadBindings)]
 pub struct PlayerInputMapping {
     pub player_id: u32,
     pub primary_device: Option<InputDevice>,
@@ -130,12 +132,96 @@ impl PlayerInputMapping {
     }
 }
 
+/// Storage key of the gamepad bindings of the players
+pub const GAMEPAD_BINDINGS_STORAGE_KEY: &str = "gamepad_bindings";
+
+/// Action a gamepad button can be bound to
+#[derive(Reflect, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GamepadAction {
+    /// Confirm/Select/Activate
+    Primary,
+    /// Back/Cancel/Abort
+    Secondary,
+}
+
+impl GamepadAction {
+    pub const ALL: [Self; 2] = [Self::Primary, Self::Secondary];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Primary => "Confirm",
+            Self::Secondary => "Back",
+        }
+    }
+}
+
+/// Gamepad buttons of a player's actions.
+///
+/// Kept per player in the [`InputDeviceAssignment`] and copied to the
+/// controller of the player. Start and Select always confirm and go back, the
+/// D-pad and the left stick move.
+#[derive(Component, Reflect, Clone, Debug, PartialEq, Eq)]
+#[reflect(Component)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub struct GamepadBindings {
+    pub primary: GamepadButton,
+    pub secondary: GamepadButton,
+}
+
+impl Default for GamepadBindings {
+    fn default() -> Self {
+        Self {
+            primary: GamepadButton::South,
+            secondary: GamepadButton::East,
+        }
+    }
+}
+
+impl GamepadBindings {
+    pub fn button(&self, action: GamepadAction) -> GamepadButton {
+        match action {
+            GamepadAction::Primary => self.primary,
+            GamepadAction::Secondary => self.secondary,
+        }
+    }
+
+    pub fn set_button(&mut self, action: GamepadAction, button: GamepadButton) {
+        match action {
+            GamepadAction::Primary => self.primary = button,
+            GamepadAction::Secondary => self.secondary = button,
+        }
+    }
+
+    /// Buttons for the primary action
+    pub fn primary_buttons(&self) -> [GamepadButton; 2] {
+        [self.primary, GamepadButton::Start]
+    }
+
+    /// Buttons for the secondary action
+    pub fn secondary_buttons(&self) -> [GamepadButton; 2] {
+        [self.secondary, GamepadButton::Select]
+    }
+
+    /// Action the button is bound to
+    pub fn action_for(&self, button: GamepadButton) -> Option<GamepadAction> {
+        if self.primary_buttons().contains(&button) {
+            Some(GamepadAction::Primary)
+        } else if self.secondary_buttons().contains(&button) {
+            Some(GamepadAction::Secondary)
+        } else {
+            None
+        }
+    }
+}
+
 /// Resource for tracking device assignments
 #[derive(Resource, Reflect, Default)]
 #[reflect(Resource)]
 pub struct InputDeviceAssignment {
     pub assignments: HashMap<u32, InputDevice>, // player_id -> device
     pub max_players: u32,
+    /// Gamepad buttons of the players who changed them
+    pub gamepad_bindings: HashMap<u32, GamepadBindings>,
 }
 
 impl InputDeviceAssignment {
@@ -143,6 +229,7 @@ impl InputDeviceAssignment {
         Self {
             assignments: HashMap::new(),
             max_players,
+            gamepad_bindings: HashMap::new(),
         }
     }
 
@@ -182,6 +269,29 @@ impl InputDeviceAssignment {
             .map(|(player_id, _)| *player_id)
     }
 
+    /// Another player whose keyboard scheme uses the key
+    pub fn get_player_for_key(&self, key: KeyCode, player_id: u32) -> Option<u32> {
+        self.assignments
+            .iter()
+            .find(|(other, device)| {
+                **other != player_id
+                    && matches!(device, InputDevice::Keyboard(scheme) if scheme.uses_key(key))
+            })
+            .map(|(other, _)| *other)
+    }
+
+    /// Gamepad buttons of a player, the defaults unless changed
+    pub fn get_gamepad_bindings(&self, player_id: u32) -> GamepadBindings {
+        self.gamepad_bindings
+            .get(&player_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn set_gamepad_bindings(&mut self, player_id: u32, bindings: GamepadBindings) {
+        self.gamepad_bindings.insert(player_id, bindings);
+    }
+
     /// Remove device assignment from a player
     pub fn unassign_player(&mut self, player_id: u32) {
         self.assignments.remove(&player_id);
@@ -206,6 +316,9 @@ pub struct InputSettings {
     pub movement_threshold: f32,
     pub auto_assign_devices: bool,
     pub allow_keyboard_sharing: bool, // Allow multiple players to use different keyboard schemes
+    /// Seconds between checks of the available devices, gamepad connections
+    /// are picked up right away
+    pub device_refresh_interval: f32,
 }
 
 impl Default for InputSettings {
@@ -215,12 +328,14 @@ impl Default for InputSettings {
             movement_threshold: 0.1,
             auto_assign_devices: true,
             allow_keyboard_sharing: true,
+            device_refresh_interval: 2.0,
         }
     }
 }
 
 /// Events for input system
-#[derive(Message, Debug, Clone)]
+#[derive(Message, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
 pub enum InputEvent {
     /// Device assigned to player
     DeviceAssigned { player_id: u32, device: InputDevice },
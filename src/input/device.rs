@@ -2,13 +2,15 @@ use bevy::prelude::*;
 use std::hash::Hash; // Add this import
 
 /// Resource to track available input devices
-#[derive(Resource, Reflect, Default, Clone)]
+#[derive(Resource, Reflect, Default, Clone, PartialEq)]
 #[reflect(Resource)]
 pub struct AvailableInputDevices {
     pub gamepads: Vec<Entity>,
     pub mouse: bool,
     pub touch: bool,
     pub keyboard: bool,
+    /// Keyboard schemes the players created, from the [`CustomSchemes`]
+    pub custom_schemes: Vec<CustomKeyboardScheme>,
 }
 
 impl AvailableInputDevices {
@@ -21,13 +23,11 @@ impl AvailableInputDevices {
             devices.push(InputDevice::Keyboard(KeyboardScheme::Arrows));
             devices.push(InputDevice::Keyboard(KeyboardScheme::IJKL));
 
-            // Add some common custom schemes
-            devices.push(InputDevice::Keyboard(KeyboardScheme::Custom {
-                up: KeyCode::KeyT,
-                down: KeyCode::KeyG,
-                left: KeyCode::KeyF,
-                right: KeyCode::KeyH,
-            }));
+            for scheme in &self.custom_schemes {
+                devices.push(InputDevice::Keyboard(KeyboardScheme::UserDefined(
+                    scheme.clone(),
+                )));
+            }
         }
 
         // Add gamepad devices
@@ -95,7 +95,16 @@ impl AvailableInputDevices {
     }
 }
 
+/// Message sent when devices were connected or disconnected, e.g. to refresh
+/// device lists
+#[derive(Message, Debug, Clone, Default)]
+pub struct DevicesChanged {
+    pub added: Vec<InputDevice>,
+    pub removed: Vec<InputDevice>,
+}
+
 #[derive(Reflect, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
 pub enum InputDevice {
     Keyboard(KeyboardScheme),
     Gamepad(u32),
@@ -105,6 +114,7 @@ pub enum InputDevice {
 
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Reflect, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
 pub enum KeyboardScheme {
     WASD,
     Arrows,
@@ -115,6 +125,105 @@ pub enum KeyboardScheme {
         left: KeyCode,
         right: KeyCode,
     },
+    /// Named scheme with action keys, created by a player
+    UserDefined(CustomKeyboardScheme),
+}
+
+/// Keyboard scheme created by a player on the input configuration screen
+#[derive(Reflect, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustomKeyboardScheme {
+    pub name: String,
+    pub up: KeyCode,
+    pub down: KeyCode,
+    pub left: KeyCode,
+    pub right: KeyCode,
+    /// Confirm/Select/Activate
+    pub primary: KeyCode,
+    /// Back/Cancel/Abort
+    pub secondary: KeyCode,
+}
+
+/// Storage key of the custom keyboard schemes
+pub const CUSTOM_SCHEMES_STORAGE_KEY: &str = "custom_keyboard_schemes";
+
+/// Resource with the keyboard schemes players created, offered next to the
+/// built-in schemes in the device lists.
+///
+/// With the `storage` feature the schemes are persisted.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "storage",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct CustomSchemes {
+    pub schemes: Vec<CustomKeyboardScheme>,
+}
+
+impl CustomSchemes {
+    /// Add a scheme, replacing the scheme with the same name
+    pub fn add(&mut self, scheme: CustomKeyboardScheme) {
+        match self
+            .schemes
+            .iter_mut()
+            .find(|existing| existing.name == scheme.name)
+        {
+            Some(existing) => *existing = scheme,
+            None => self.schemes.push(scheme),
+        }
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<CustomKeyboardScheme> {
+        let index = self.schemes.iter().position(|scheme| scheme.name == name)?;
+        Some(self.schemes.remove(index))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&CustomKeyboardScheme> {
+        self.schemes.iter().find(|scheme| scheme.name == name)
+    }
+}
+
+impl CustomKeyboardScheme {
+    /// Copy of a scheme to rebind its keys, built-in schemes keep their first
+    /// confirm and back keys
+    pub fn from_scheme(name: impl Into<String>, scheme: &KeyboardScheme) -> Self {
+        let (up, down, left, right) = scheme.get_keys();
+        Self {
+            name: name.into(),
+            up,
+            down,
+            left,
+            right,
+            primary: scheme.primary_keys()[0],
+            secondary: scheme.secondary_keys()[0],
+        }
+    }
+
+    /// Keys in the order up, down, left, right, confirm, back
+    pub fn keys(&self) -> [KeyCode; 6] {
+        [
+            self.up,
+            self.down,
+            self.left,
+            self.right,
+            self.primary,
+            self.secondary,
+        ]
+    }
+
+    /// Bind a key, `index` in the order of [`CustomKeyboardScheme::keys`]
+    pub fn set_key(&mut self, index: usize, key: KeyCode) {
+        match index {
+            0 => self.up = key,
+            1 => self.down = key,
+            2 => self.left = key,
+            3 => self.right = key,
+            4 => self.primary = key,
+            5 => self.secondary = key,
+            _ => warn!("No key binding {} in keyboard scheme {}", index, self.name),
+        }
+    }
 }
 
 impl KeyboardScheme {
@@ -134,15 +243,43 @@ impl KeyboardScheme {
                 left,
                 right,
             } => (*up, *down, *left, *right),
+            KeyboardScheme::UserDefined(scheme) => {
+                (scheme.up, scheme.down, scheme.left, scheme.right)
+            }
         }
     }
 
-    pub fn name(&self) -> &'static str {
+    /// Keys for the primary action (Confirm/Select/Activate)
+    pub fn primary_keys(&self) -> &[KeyCode] {
+        match self {
+            KeyboardScheme::UserDefined(scheme) => std::slice::from_ref(&scheme.primary),
+            _ => &[KeyCode::Space, KeyCode::Enter],
+        }
+    }
+
+    /// Keys for the secondary action (Back/Cancel/Abort)
+    pub fn secondary_keys(&self) -> &[KeyCode] {
+        match self {
+            KeyboardScheme::UserDefined(scheme) => std::slice::from_ref(&scheme.secondary),
+            _ => &[KeyCode::Escape, KeyCode::Backspace],
+        }
+    }
+
+    /// Whether the key moves or acts in this scheme
+    pub fn uses_key(&self, key: KeyCode) -> bool {
+        let (up, down, left, right) = self.get_keys();
+        [up, down, left, right].contains(&key)
+            || self.primary_keys().contains(&key)
+            || self.secondary_keys().contains(&key)
+    }
+
+    pub fn name(&self) -> &str {
         match self {
             KeyboardScheme::WASD => "WASD",
             KeyboardScheme::Arrows => "Arrow Keys",
             KeyboardScheme::IJKL => "IJKL",
             KeyboardScheme::Custom { .. } => "Custom Keys",
+            KeyboardScheme::UserDefined(scheme) => &scheme.name,
         }
     }
 
@@ -160,6 +297,10 @@ impl KeyboardScheme {
             } => {
                 format!("{:?}/{:?}/{:?}/{:?}", up, down, left, right)
             }
+            KeyboardScheme::UserDefined(scheme) => format!(
+                "{:?}/{:?}/{:?}/{:?}, {:?} to confirm, {:?} to go back",
+                scheme.up, scheme.down, scheme.left, scheme.right, scheme.primary, scheme.secondary
+            ),
         }
     }
 }
@@ -262,6 +403,15 @@ mod tests {
             mouse: true,
             touch: false,
             keyboard: true,
+            custom_schemes: vec![CustomKeyboardScheme {
+                name: "TFGH".to_string(),
+                up: KeyCode::KeyT,
+                down: KeyCode::KeyG,
+                left: KeyCode::KeyF,
+                right: KeyCode::KeyH,
+                primary: KeyCode::KeyR,
+                secondary: KeyCode::KeyY,
+            }],
         };
 
         let available = devices.get_available_devices();
@@ -279,6 +429,10 @@ mod tests {
         assert!(available
             .iter()
             .any(|d| matches!(d, InputDevice::Keyboard(KeyboardScheme::IJKL))));
+        assert!(available.iter().any(|d| matches!(
+            d,
+            InputDevice::Keyboard(KeyboardScheme::UserDefined(scheme)) if scheme.name == "TFGH"
+        )));
 
         // Check that we have gamepads
         assert!(available
@@ -1,9 +1,17 @@
 pub mod components;
 pub mod device;
+pub mod player_style;
 pub mod plugin;
+pub mod recorder;
+pub mod split_screen;
 pub mod systems;
+pub mod virtual_controls;
 
 pub use components::*;
 pub use device::*;
+pub use player_style::*;
 pub use plugin::*;
+pub use recorder::*;
+pub use split_screen::*;
 pub use systems::*;
+pub use virtual_controls::*;
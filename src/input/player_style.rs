@@ -0,0 +1,110 @@
+use crate::theme::KonnektorenTheme;
+use bevy::prelude::*;
+use bevy_egui::egui;
+use std::collections::HashMap;
+
+/// Resource with the color and display name of every player, so "Player 1
+/// is blue" in the input configuration, the hot-seat screens and HUD widgets
+/// alike.
+///
+/// Players without a custom color get the theme colors in turn, players
+/// without a custom name are called "Player N":
+///
+/// ```ignore
+/// app.insert_resource(
+///     PlayerStyle::default()
+///         .with_name(0, "Ada")
+///         .with_color(1, egui::Color32::from_rgb(255, 105, 180)),
+/// );
+/// ```
+#[derive(Resource, Debug, Clone, Default)]
+pub struct PlayerStyle {
+    colors: HashMap<u32, egui::Color32>,
+    names: HashMap<u32, String>,
+}
+
+impl PlayerStyle {
+    pub fn with_color(mut self, player_id: u32, color: egui::Color32) -> Self {
+        self.set_color(player_id, color);
+        self
+    }
+
+    pub fn with_name(mut self, player_id: u32, name: impl Into<String>) -> Self {
+        self.set_name(player_id, name);
+        self
+    }
+
+    pub fn set_color(&mut self, player_id: u32, color: egui::Color32) {
+        self.colors.insert(player_id, color);
+    }
+
+    pub fn set_name(&mut self, player_id: u32, name: impl Into<String>) {
+        self.names.insert(player_id, name.into());
+    }
+
+    /// Go back to the theme color of a player
+    pub fn reset_color(&mut self, player_id: u32) {
+        self.colors.remove(&player_id);
+    }
+
+    /// Go back to "Player N" for a player
+    pub fn reset_name(&mut self, player_id: u32) {
+        self.names.remove(&player_id);
+    }
+
+    pub fn color(&self, player_id: u32, theme: &KonnektorenTheme) -> egui::Color32 {
+        self.colors.get(&player_id).copied().unwrap_or_else(|| {
+            let palette = player_palette(theme);
+            palette[player_id as usize % palette.len()]
+        })
+    }
+
+    pub fn name(&self, player_id: u32) -> String {
+        self.names
+            .get(&player_id)
+            .cloned()
+            .unwrap_or_else(|| format!("Player {}", player_id + 1))
+    }
+
+    /// Name of a player in their color, e.g. for a HUD
+    pub fn label(&self, player_id: u32, theme: &KonnektorenTheme) -> egui::RichText {
+        egui::RichText::new(self.name(player_id))
+            .color(self.color(player_id, theme))
+            .strong()
+    }
+}
+
+/// Theme colors players get in turn by their player id
+pub fn player_palette(theme: &KonnektorenTheme) -> [egui::Color32; 6] {
+    [
+        theme.primary,
+        theme.secondary,
+        theme.accent,
+        theme.info,
+        theme.warning,
+        theme.success,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_player_style_defaults_and_overrides() {
+        let theme = KonnektorenTheme::default();
+        let mut style = PlayerStyle::default().with_name(1, "Grace");
+
+        assert_eq!(style.name(0), "Player 1");
+        assert_eq!(style.name(1), "Grace");
+        assert_eq!(style.color(0, &theme), theme.primary);
+        assert_eq!(style.color(1, &theme), theme.secondary);
+        // The palette repeats for many players
+        assert_eq!(style.color(6, &theme), theme.primary);
+
+        style.set_color(0, egui::Color32::RED);
+        assert_eq!(style.color(0, &theme), egui::Color32::RED);
+        style.reset_color(0);
+        assert_eq!(style.color(0, &theme), theme.primary);
+    }
+}
@@ -1,5 +1,10 @@
-use super::{components::*, device::AvailableInputDevices, systems::*};
-use bevy::prelude::*;
+use super::{
+    components::*,
+    device::{AvailableInputDevices, CustomSchemes, DevicesChanged},
+    player_style::PlayerStyle,
+    systems::*,
+};
+use bevy::{input::gamepad::GamepadConnectionEvent, prelude::*};
 
 /// Main input plugin that provides all input functionality
 pub struct InputPlugin;
@@ -11,18 +16,23 @@ impl Plugin for InputPlugin {
             .init_resource::<AvailableInputDevices>()
             .init_resource::<InputDeviceAssignment>()
             .init_resource::<InputSettings>()
+            .init_resource::<PlayerStyle>()
+            .init_resource::<CustomSchemes>()
             // Register types for reflection
             .register_type::<InputController>()
             .register_type::<PlayerInputMapping>()
             .register_type::<InputDeviceAssignment>()
+            .register_type::<GamepadBindings>()
             .register_type::<InputSettings>()
             // Add events
             .add_message::<InputEvent>()
+            .add_message::<DevicesChanged>()
+            .add_message::<GamepadConnectionEvent>()
             // Add core input systems
             .add_systems(
                 Update,
                 (
-                    detect_gamepads,
+                    refresh_available_devices,
                     auto_assign_devices,
                     update_player_mappings,
                     handle_keyboard_input,
@@ -32,6 +42,12 @@ impl Plugin for InputPlugin {
                     .chain(),
             );
 
+        #[cfg(feature = "storage")]
+        {
+            app.add_systems(Startup, (load_custom_schemes, load_gamepad_bindings))
+                .add_systems(PostUpdate, (save_custom_schemes, save_gamepad_bindings));
+        }
+
         info!("InputPlugin loaded");
     }
 }
@@ -0,0 +1,339 @@
+use super::{
+    components::*,
+    systems::{clear_input_states, handle_gamepad_input},
+};
+#[cfg(feature = "storage")]
+use crate::storage::StorageError;
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+/// Plugin for recording the [`InputEvent`]s of a session and playing them
+/// back.
+///
+/// A recording keeps the frame and time of every event. Playback writes the
+/// events again in the same frames and applies them to the
+/// [`InputController`]s and the [`InputDeviceAssignment`], so UI flows and
+/// gameplay can be tested without real devices. With the `storage` feature a
+/// recording can be saved and loaded as JSON.
+///
+/// ```ignore
+/// app.add_plugins((InputPlugin, InputRecorderPlugin));
+///
+/// fn start(mut recorder: ResMut<InputRecorder>) {
+///     recorder.start_recording();
+/// }
+///
+/// fn stop(mut recorder: ResMut<InputRecorder>) {
+///     let recording = recorder.stop_recording();
+///     // Later, e.g. in a test
+///     recorder.play(recording);
+/// }
+/// ```
+pub struct InputRecorderPlugin;
+
+impl Plugin for InputRecorderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputRecorder>()
+            .init_resource::<InputDeviceAssignment>()
+            .add_message::<InputEvent>()
+            .add_systems(
+                Update,
+                (
+                    play_input_recording
+                        .after(handle_gamepad_input)
+                        .before(clear_input_states),
+                    record_input_events.after(clear_input_states),
+                ),
+            );
+    }
+}
+
+/// Input event at a point of a recording
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordedInputEvent {
+    /// Frames since the recording started
+    pub frame: u32,
+    /// Seconds of virtual time since the recording started
+    pub time: f32,
+    pub event: InputEvent,
+}
+
+/// Input events of a session in the order they happened
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputRecording {
+    pub events: Vec<RecordedInputEvent>,
+    /// Frames the recording lasts, playback ends after them
+    pub frames: u32,
+}
+
+impl InputRecording {
+    /// Seconds of virtual time until the last event
+    pub fn duration(&self) -> f32 {
+        self.events.last().map_or(0.0, |event| event.time)
+    }
+
+    #[cfg(feature = "storage")]
+    pub fn to_json(&self) -> Result<String, StorageError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    #[cfg(feature = "storage")]
+    pub fn from_json(json: &str) -> Result<Self, StorageError> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+enum RecorderMode {
+    #[default]
+    Idle,
+    Recording {
+        recording: InputRecording,
+        started: f32,
+    },
+    Playing {
+        recording: InputRecording,
+        /// Index of the next event to play
+        next: usize,
+        frame: u32,
+    },
+}
+
+/// Resource recording and playing back input events
+#[derive(Resource, Debug, Clone, Default)]
+pub struct InputRecorder {
+    mode: RecorderMode,
+}
+
+impl InputRecorder {
+    /// Start a new recording, stopping a running playback
+    pub fn start_recording(&mut self) {
+        self.mode = RecorderMode::Recording {
+            recording: InputRecording::default(),
+            started: -1.0,
+        };
+    }
+
+    /// Stop recording and return what was recorded
+    pub fn stop_recording(&mut self) -> InputRecording {
+        match std::mem::take(&mut self.mode) {
+            RecorderMode::Recording { recording, .. } => recording,
+            mode => {
+                self.mode = mode;
+                InputRecording::default()
+            }
+        }
+    }
+
+    /// Play a recording from its first frame, stopping a running recording
+    pub fn play(&mut self, recording: InputRecording) {
+        self.mode = RecorderMode::Playing {
+            recording,
+            next: 0,
+            frame: 0,
+        };
+    }
+
+    pub fn stop_playback(&mut self) {
+        if self.is_playing() {
+            self.mode = RecorderMode::Idle;
+        }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        matches!(self.mode, RecorderMode::Recording { .. })
+    }
+
+    pub fn is_playing(&self) -> bool {
+        matches!(self.mode, RecorderMode::Playing { .. })
+    }
+}
+
+/// System to record the input events of the frame
+fn record_input_events(
+    time: Res<Time>,
+    mut recorder: ResMut<InputRecorder>,
+    mut events: MessageReader<InputEvent>,
+) {
+    let RecorderMode::Recording { recording, started } = &mut recorder.mode else {
+        events.clear();
+        return;
+    };
+
+    let now = time.elapsed_secs();
+    if *started < 0.0 {
+        *started = now;
+    }
+    for event in events.read() {
+        recording.events.push(RecordedInputEvent {
+            frame: recording.frames,
+            time: now - *started,
+            event: event.clone(),
+        });
+    }
+    recording.frames += 1;
+}
+
+/// System to write the recorded events of the frame and apply them to the
+/// controllers
+fn play_input_recording(
+    mut recorder: ResMut<InputRecorder>,
+    mut assignment: ResMut<InputDeviceAssignment>,
+    mut controllers: Query<&mut InputController>,
+    mut input_events: MessageWriter<InputEvent>,
+) {
+    let RecorderMode::Playing {
+        recording,
+        next,
+        frame,
+    } = &mut recorder.mode
+    else {
+        return;
+    };
+
+    let mut moved = HashSet::new();
+    while let Some(recorded) = recording
+        .events
+        .get(*next)
+        .filter(|recorded| recorded.frame <= *frame)
+    {
+        let event = recorded.event.clone();
+        *next += 1;
+
+        match &event {
+            InputEvent::DeviceAssigned { player_id, device } => {
+                assignment.assign_device(*player_id, device.clone());
+            }
+            InputEvent::DeviceUnassigned { player_id } => {
+                assignment.unassign_player(*player_id);
+            }
+            InputEvent::PrimaryAction { player_id, source }
+            | InputEvent::SecondaryAction { player_id, source } => {
+                let primary = matches!(event, InputEvent::PrimaryAction { .. });
+                for mut controller in controllers.iter_mut() {
+                    if controller.player_id == *player_id {
+                        if primary {
+                            controller.primary_action = true;
+                        } else {
+                            controller.secondary_action = true;
+                        }
+                        controller.input_source = source.clone();
+                    }
+                }
+            }
+            InputEvent::Movement {
+                player_id,
+                direction,
+                source,
+            } => {
+                moved.insert(*player_id);
+                for mut controller in controllers.iter_mut() {
+                    if controller.player_id == *player_id {
+                        controller.movement = *direction;
+                        controller.input_source = source.clone();
+                    }
+                }
+            }
+        }
+        input_events.write(event);
+    }
+
+    // Players stop when the recording has no movement for them
+    let recorded_players: HashSet<u32> = recording
+        .events
+        .iter()
+        .filter_map(|recorded| match recorded.event {
+            InputEvent::Movement { player_id, .. } => Some(player_id),
+            _ => None,
+        })
+        .collect();
+    for mut controller in controllers.iter_mut() {
+        if recorded_players.contains(&controller.player_id)
+            && !moved.contains(&controller.player_id)
+            && controller.movement != Vec2::ZERO
+        {
+            controller.movement = Vec2::ZERO;
+        }
+    }
+
+    *frame += 1;
+    if *frame >= recording.frames {
+        info!("Input playback finished");
+        recorder.mode = RecorderMode::Idle;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        input::{InputControllerExt, InputDevice, InputPlugin, KeyboardScheme},
+        test_utils::TestHarness,
+    };
+
+    #[test]
+    fn test_record_and_play_input() {
+        let mut harness = TestHarness::new().with_plugins((InputPlugin, InputRecorderPlugin));
+        harness.world_mut().commands().spawn_input_controller(0);
+        harness
+            .world_mut()
+            .resource_mut::<InputDeviceAssignment>()
+            .assign_device(0, InputDevice::Keyboard(KeyboardScheme::WASD));
+        harness.run_frames(2);
+
+        harness
+            .world_mut()
+            .resource_mut::<InputRecorder>()
+            .start_recording();
+        harness.step();
+        harness.press_key_code(KeyCode::KeyW);
+        harness.press_key_code(KeyCode::Space);
+        harness.step();
+        let recording = harness
+            .world_mut()
+            .resource_mut::<InputRecorder>()
+            .stop_recording();
+        assert!(recording.events.iter().any(|recorded| matches!(
+            recorded.event,
+            InputEvent::PrimaryAction { player_id: 0, .. }
+        )));
+        let movement = recording
+            .events
+            .iter()
+            .find(|recorded| matches!(recorded.event, InputEvent::Movement { .. }))
+            .unwrap();
+        assert_eq!(movement.frame, 1);
+
+        #[cfg(feature = "storage")]
+        let recording = InputRecording::from_json(&recording.to_json().unwrap()).unwrap();
+
+        // Playback writes the same events in the same frames
+        harness.read_messages::<InputEvent>();
+        harness
+            .world_mut()
+            .resource_mut::<InputRecorder>()
+            .play(recording.clone());
+        harness.step();
+        harness.step();
+        let mut played = harness.read_messages::<InputEvent>();
+        let controller = harness
+            .world_mut()
+            .query::<&InputController>()
+            .single(harness.world())
+            .unwrap();
+        assert_eq!(controller.movement, Vec2::Y);
+
+        while harness.world().resource::<InputRecorder>().is_playing() {
+            harness.step();
+            played.extend(harness.read_messages::<InputEvent>());
+        }
+        let recorded: Vec<_> = recording
+            .events
+            .iter()
+            .map(|recorded| recorded.event.clone())
+            .collect();
+        assert_eq!(played, recorded);
+    }
+}
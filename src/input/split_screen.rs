@@ -0,0 +1,178 @@
+use super::components::InputDeviceAssignment;
+use bevy::{camera::Viewport, prelude::*, window::PrimaryWindow};
+
+/// Plugin splitting the primary window between the cameras of the players.
+///
+/// Tag one camera per player with [`SplitScreenCamera`]. The window is split
+/// by the number of players in the [`InputDeviceAssignment`], so the layout
+/// follows players joining and leaving. Cameras of players without a device
+/// are deactivated. Without assigned players the camera of player 0 gets the
+/// whole window.
+///
+/// ```ignore
+/// app.add_plugins(SplitScreenPlugin);
+/// for player_id in 0..4 {
+///     commands.spawn((Camera2d, SplitScreenCamera::new(player_id)));
+/// }
+/// ```
+pub struct SplitScreenPlugin;
+
+impl Plugin for SplitScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputDeviceAssignment>()
+            .init_resource::<SplitScreenSettings>()
+            .add_systems(PostUpdate, update_split_screen_viewports);
+    }
+}
+
+/// Marks the camera of a player in split-screen
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SplitScreenCamera {
+    pub player_id: u32,
+}
+
+impl SplitScreenCamera {
+    pub fn new(player_id: u32) -> Self {
+        Self { player_id }
+    }
+}
+
+/// How two players share the window
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SplitScreenLayout {
+    /// Side by side in landscape windows, stacked in portrait windows
+    #[default]
+    Auto,
+    /// Side by side
+    Vertical,
+    /// Stacked
+    Horizontal,
+}
+
+/// Settings of the [`SplitScreenPlugin`]
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct SplitScreenSettings {
+    pub layout: SplitScreenLayout,
+    /// Physical pixels between the viewports, showing the clear color of the
+    /// window as divider
+    pub gap: u32,
+}
+
+impl Default for SplitScreenSettings {
+    fn default() -> Self {
+        Self {
+            layout: SplitScreenLayout::Auto,
+            gap: 2,
+        }
+    }
+}
+
+/// Viewport rectangles (position, size) for a number of players in a window
+/// of the given physical size.
+///
+/// One player gets the whole window, two split it by the layout, three and
+/// four share a 2×2 grid.
+pub fn split_screen_viewports(
+    players: usize,
+    window_size: UVec2,
+    settings: &SplitScreenSettings,
+) -> Vec<(UVec2, UVec2)> {
+    let (columns, rows) = match players {
+        0 | 1 => (1, 1),
+        2 => match settings.layout {
+            SplitScreenLayout::Vertical => (2, 1),
+            SplitScreenLayout::Horizontal => (1, 2),
+            SplitScreenLayout::Auto if window_size.x >= window_size.y => (2, 1),
+            SplitScreenLayout::Auto => (1, 2),
+        },
+        _ => (2, 2),
+    };
+
+    let gap = settings.gap;
+    let cell = UVec2::new(
+        window_size.x.saturating_sub(gap * (columns - 1)) / columns,
+        window_size.y.saturating_sub(gap * (rows - 1)) / rows,
+    );
+    (0..players.clamp(1, 4) as u32)
+        .map(|slot| {
+            let (column, row) = (slot % columns, slot / columns);
+            let position = UVec2::new(column * (cell.x + gap), row * (cell.y + gap));
+            (position, cell.max(UVec2::ONE))
+        })
+        .collect()
+}
+
+/// System to update the camera viewports when players, window size or
+/// cameras change
+fn update_split_screen_viewports(
+    assignment: Res<InputDeviceAssignment>,
+    settings: Res<SplitScreenSettings>,
+    windows: Query<Ref<Window>, With<PrimaryWindow>>,
+    mut cameras: Query<(Ref<SplitScreenCamera>, &mut Camera)>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let cameras_changed = cameras.iter().any(|(camera, _)| camera.is_changed());
+    if !assignment.is_changed()
+        && !settings.is_changed()
+        && !window.is_changed()
+        && !cameras_changed
+    {
+        return;
+    }
+
+    let mut players = assignment.get_assigned_players();
+    players.sort_unstable();
+    players.truncate(4);
+    if players.is_empty() {
+        players.push(0);
+    }
+    let viewports = split_screen_viewports(players.len(), window.physical_size(), &settings);
+
+    for (split_screen, mut camera) in cameras.iter_mut() {
+        match players.iter().position(|id| *id == split_screen.player_id) {
+            Some(slot) => {
+                let (physical_position, physical_size) = viewports[slot];
+                camera.is_active = true;
+                camera.order = slot as isize;
+                camera.viewport = Some(Viewport {
+                    physical_position,
+                    physical_size,
+                    ..default()
+                });
+            }
+            None => camera.is_active = false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_screen_viewports() {
+        let settings = SplitScreenSettings::default();
+        let window = UVec2::new(1282, 722);
+
+        assert_eq!(
+            split_screen_viewports(1, window, &settings),
+            vec![(UVec2::ZERO, window)]
+        );
+        assert_eq!(
+            split_screen_viewports(2, window, &settings),
+            vec![
+                (UVec2::ZERO, UVec2::new(640, 722)),
+                (UVec2::new(642, 0), UVec2::new(640, 722)),
+            ]
+        );
+        // Stacked in portrait windows
+        let portrait = split_screen_viewports(2, UVec2::new(720, 1282), &settings);
+        assert_eq!(portrait[1], (UVec2::new(0, 642), UVec2::new(720, 640)));
+
+        let grid = split_screen_viewports(3, window, &settings);
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid[2], (UVec2::new(0, 362), UVec2::new(640, 360)));
+    }
+}
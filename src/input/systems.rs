@@ -1,8 +1,14 @@
+#[cfg(feature = "storage")]
+use super::device::CUSTOM_SCHEMES_STORAGE_KEY;
 use super::{
     components::*,
-    device::{AvailableInputDevices, InputDevice},
+    device::{AvailableInputDevices, CustomSchemes, DevicesChanged, InputDevice},
 };
-use bevy::prelude::*;
+#[cfg(feature = "storage")]
+use crate::storage::{PersistenceScheduler, Storage};
+use bevy::{input::gamepad::GamepadConnectionEvent, prelude::*};
+#[cfg(feature = "storage")]
+use std::collections::HashMap;
 
 /// System to handle keyboard input
 pub fn handle_keyboard_input(
@@ -66,10 +72,8 @@ pub fn handle_keyboard_input(
         }
 
         // Handle action input
-        let primary_pressed =
-            keyboard.just_pressed(KeyCode::Space) || keyboard.just_pressed(KeyCode::Enter);
-        let secondary_pressed =
-            keyboard.just_pressed(KeyCode::Escape) || keyboard.just_pressed(KeyCode::Backspace);
+        let primary_pressed = keyboard.any_just_pressed(scheme.primary_keys().iter().copied());
+        let secondary_pressed = keyboard.any_just_pressed(scheme.secondary_keys().iter().copied());
 
         if primary_pressed {
             controller.primary_action = true;
@@ -96,11 +100,11 @@ pub fn handle_keyboard_input(
 /// System to handle gamepad input
 pub fn handle_gamepad_input(
     gamepads: Query<(Entity, &Gamepad)>,
-    mut controller_query: Query<(&mut InputController, &PlayerInputMapping)>,
+    mut controller_query: Query<(&mut InputController, &PlayerInputMapping, &GamepadBindings)>,
     settings: Res<InputSettings>,
     mut input_events: MessageWriter<InputEvent>,
 ) {
-    for (mut controller, mapping) in &mut controller_query {
+    for (mut controller, mapping, bindings) in &mut controller_query {
         if !controller.enabled || !mapping.enabled {
             continue;
         }
@@ -167,8 +171,10 @@ pub fn handle_gamepad_input(
         }
 
         // Handle action input
-        if gamepad.just_pressed(GamepadButton::South) || gamepad.just_pressed(GamepadButton::Start)
-        {
+        let just_pressed = |buttons: [GamepadButton; 2]| {
+            buttons.iter().any(|button| gamepad.just_pressed(*button))
+        };
+        if just_pressed(bindings.primary_buttons()) {
             controller.primary_action = true;
             controller.input_source = InputSource::Gamepad(*gamepad_entity);
 
@@ -178,8 +184,7 @@ pub fn handle_gamepad_input(
             });
         }
 
-        if gamepad.just_pressed(GamepadButton::East) || gamepad.just_pressed(GamepadButton::Select)
-        {
+        if just_pressed(bindings.secondary_buttons()) {
             controller.secondary_action = true;
             controller.input_source = InputSource::Gamepad(*gamepad_entity);
 
@@ -191,27 +196,138 @@ pub fn handle_gamepad_input(
     }
 }
 
-/// System to detect and track connected gamepads
-pub fn detect_gamepads(
+/// System keeping the [`AvailableInputDevices`] current.
+///
+/// Checks right away when a gamepad connects or disconnects or the
+/// [`CustomSchemes`] change, and every
+/// [`InputSettings::device_refresh_interval`] seconds for the platform
+/// flags. Sends [`DevicesChanged`] when the available devices changed.
+#[allow(clippy::too_many_arguments)]
+pub fn refresh_available_devices(
+    time: Res<Time>,
+    settings: Res<InputSettings>,
+    mut since_check: Local<Option<f32>>,
+    mut connections: MessageReader<GamepadConnectionEvent>,
+    custom_schemes: Option<Res<CustomSchemes>>,
     mut available_devices: ResMut<AvailableInputDevices>,
     gamepads: Query<Entity, With<Gamepad>>,
+    mut devices_changed: MessageWriter<DevicesChanged>,
 ) {
-    let old_count = available_devices.gamepads.len();
+    let connection_changed = connections.read().count() > 0
+        || custom_schemes
+            .as_ref()
+            .is_some_and(|custom_schemes| custom_schemes.is_changed());
+    let elapsed = since_check.map_or(f32::INFINITY, |secs| secs + time.delta_secs());
+    if !connection_changed && elapsed < settings.device_refresh_interval {
+        *since_check = Some(elapsed);
+        return;
+    }
+    *since_check = Some(0.0);
+
+    let mut refreshed = available_devices.clone();
+    refreshed.gamepads = gamepads.iter().collect();
+    refreshed.custom_schemes = custom_schemes
+        .map(|custom_schemes| custom_schemes.schemes.clone())
+        .unwrap_or_default();
+    refreshed.update_availability();
+    if *available_devices == refreshed {
+        return;
+    }
 
-    // Update connected gamepads list
-    available_devices.gamepads.clear();
-    for gamepad_entity in gamepads.iter() {
-        available_devices.gamepads.push(gamepad_entity);
+    let before = available_devices.get_available_devices();
+    let after = refreshed.get_available_devices();
+    let changed = DevicesChanged {
+        added: after
+            .iter()
+            .filter(|device| !before.contains(device))
+            .cloned()
+            .collect(),
+        removed: before
+            .iter()
+            .filter(|device| !after.contains(device))
+            .cloned()
+            .collect(),
+    };
+    info!(
+        "Input devices changed: {} added, {} removed",
+        changed.added.len(),
+        changed.removed.len()
+    );
+
+    *available_devices = refreshed;
+    devices_changed.write(changed);
+}
+
+/// System to restore the custom keyboard schemes from storage
+#[cfg(feature = "storage")]
+pub fn load_custom_schemes(
+    storage: Option<Res<Storage>>,
+    mut custom_schemes: ResMut<CustomSchemes>,
+) {
+    let Some(storage) = storage else {
+        return;
+    };
+
+    match storage.load::<CustomSchemes>(CUSTOM_SCHEMES_STORAGE_KEY) {
+        Ok(Some(stored)) => *custom_schemes = stored,
+        Ok(None) => {}
+        Err(e) => warn!("Failed to load custom keyboard schemes: {}", e),
     }
+}
 
-    let new_count = available_devices.gamepads.len();
+/// System to persist the custom keyboard schemes whenever they change
+#[cfg(feature = "storage")]
+pub fn save_custom_schemes(
+    scheduler: Option<ResMut<PersistenceScheduler>>,
+    custom_schemes: Res<CustomSchemes>,
+) {
+    if !custom_schemes.is_changed() {
+        return;
+    }
 
-    if old_count != new_count {
-        info!("Gamepad count changed: {} -> {}", old_count, new_count);
+    if let Some(mut scheduler) = scheduler {
+        scheduler.schedule(CUSTOM_SCHEMES_STORAGE_KEY, &*custom_schemes);
+    }
+}
+
+/// System to restore the gamepad bindings of the players from storage
+#[cfg(feature = "storage")]
+pub fn load_gamepad_bindings(
+    storage: Option<Res<Storage>>,
+    mut assignment: ResMut<InputDeviceAssignment>,
+) {
+    let Some(storage) = storage else {
+        return;
+    };
+
+    match storage.load::<HashMap<u32, GamepadBindings>>(GAMEPAD_BINDINGS_STORAGE_KEY) {
+        Ok(Some(stored)) => assignment.gamepad_bindings = stored,
+        Ok(None) => {}
+        Err(e) => warn!("Failed to load gamepad bindings: {}", e),
+    }
+}
+
+/// System to persist the gamepad bindings whenever a player changes them
+#[cfg(feature = "storage")]
+pub fn save_gamepad_bindings(
+    scheduler: Option<ResMut<PersistenceScheduler>>,
+    assignment: Res<InputDeviceAssignment>,
+    mut saved: Local<Option<HashMap<u32, GamepadBindings>>>,
+) {
+    // The assignment also changes with every assigned device
+    if !assignment.is_changed() || saved.as_ref() == Some(&assignment.gamepad_bindings) {
+        return;
+    }
+    // The first bindings are the stored ones
+    let first = saved.is_none();
+    *saved = Some(assignment.gamepad_bindings.clone());
+    if first {
+        return;
     }
 
-    // Update general device availability
-    available_devices.update_availability();
+    if let Some(mut scheduler) = scheduler {
+        scheduler.schedule(GAMEPAD_BINDINGS_STORAGE_KEY, &assignment.gamepad_bindings);
+    }
 }
 
 /// System to assign devices to players automatically if enabled
@@ -246,18 +362,19 @@ pub fn auto_assign_devices(
 /// System to update player input mappings from assignments
 pub fn update_player_mappings(
     assignment: Res<InputDeviceAssignment>,
-    mut mappings: Query<&mut PlayerInputMapping>,
+    mut mappings: Query<(&mut PlayerInputMapping, &mut GamepadBindings)>,
 ) {
     if !assignment.is_changed() {
         return;
     }
 
-    for mut mapping in mappings.iter_mut() {
+    for (mut mapping, mut bindings) in mappings.iter_mut() {
         if let Some(device) = assignment.get_device_for_player(mapping.player_id) {
             mapping.primary_device = Some(device.clone());
         } else {
             mapping.primary_device = None;
         }
+        bindings.set_if_neq(assignment.get_gamepad_bindings(mapping.player_id));
     }
 }
 
@@ -268,3 +385,53 @@ pub fn clear_input_states(mut controllers: Query<&mut InputController>) {
         controller.secondary_action = false;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{InputPlugin, KeyboardScheme};
+    use bevy::input::gamepad::GamepadConnection;
+
+    #[test]
+    fn test_refresh_on_gamepad_connection() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, InputPlugin))
+            .init_resource::<ButtonInput<KeyCode>>();
+        app.update();
+
+        let changes: Vec<DevicesChanged> = app
+            .world_mut()
+            .resource_mut::<Messages<DevicesChanged>>()
+            .drain()
+            .collect();
+        assert_eq!(changes.len(), 1);
+        assert!(changes[0]
+            .added
+            .contains(&InputDevice::Keyboard(KeyboardScheme::WASD)));
+
+        // A connected gamepad shows up before the next periodic check
+        let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+        app.world_mut().write_message(GamepadConnectionEvent::new(
+            gamepad,
+            GamepadConnection::Connected {
+                name: "Test Gamepad".to_string(),
+                vendor_id: None,
+                product_id: None,
+            },
+        ));
+        app.update();
+
+        let changes: Vec<DevicesChanged> = app
+            .world_mut()
+            .resource_mut::<Messages<DevicesChanged>>()
+            .drain()
+            .collect();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].added, vec![InputDevice::Gamepad(0)]);
+        assert!(changes[0].removed.is_empty());
+        assert_eq!(
+            app.world().resource::<AvailableInputDevices>().gamepads,
+            vec![gamepad]
+        );
+    }
+}
@@ -0,0 +1,321 @@
+use super::{
+    components::*,
+    device::InputDevice,
+    systems::{clear_input_states, handle_gamepad_input},
+};
+use crate::theme::KonnektorenTheme;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts, EguiPrimaryContextPass};
+
+/// Plugin for on-screen controls of players playing with touch.
+///
+/// While [`InputDevice::Touch`] is assigned to a player, a virtual joystick
+/// and the action buttons of the [`VirtualControlsSettings`] are shown in the
+/// bottom corners of the screen. They feed the [`InputController`] of that
+/// player like a gamepad would, so games need no touch specific code.
+///
+/// ```ignore
+/// app.add_plugins((InputPlugin, VirtualControlsPlugin))
+///     .insert_resource(VirtualControlsSettings {
+///         joystick_side: VirtualJoystickSide::Right,
+///         ..default()
+///     });
+/// ```
+pub struct VirtualControlsPlugin;
+
+impl Plugin for VirtualControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputDeviceAssignment>()
+            .init_resource::<InputSettings>()
+            .init_resource::<VirtualControlsSettings>()
+            .init_resource::<VirtualControlsState>()
+            .add_message::<InputEvent>()
+            .add_systems(
+                Update,
+                apply_virtual_controls
+                    .after(handle_gamepad_input)
+                    .before(clear_input_states),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_virtual_controls.run_if(virtual_controls_active),
+            );
+    }
+}
+
+/// Bottom corner of the screen holding the joystick, the buttons take the
+/// other one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VirtualJoystickSide {
+    #[default]
+    Left,
+    Right,
+}
+
+/// On-screen button triggering an action
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VirtualButton {
+    pub action: GamepadAction,
+    pub label: String,
+}
+
+impl VirtualButton {
+    /// Button labelled with the name of the action
+    pub fn new(action: GamepadAction) -> Self {
+        Self {
+            action,
+            label: action.name().to_string(),
+        }
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+}
+
+/// Layout of the virtual controls, can be changed at runtime
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct VirtualControlsSettings {
+    pub joystick_side: VirtualJoystickSide,
+    /// Radius of the joystick in points
+    pub joystick_radius: f32,
+    /// Buttons from top to bottom
+    pub buttons: Vec<VirtualButton>,
+    /// Width and height of a button in points
+    pub button_size: f32,
+    /// Distance of the controls from the screen edges in points
+    pub margin: f32,
+    /// Opacity of the controls, they are drawn over the game
+    pub opacity: f32,
+}
+
+impl Default for VirtualControlsSettings {
+    fn default() -> Self {
+        Self {
+            joystick_side: VirtualJoystickSide::Left,
+            joystick_radius: 64.0,
+            buttons: GamepadAction::ALL
+                .into_iter()
+                .map(VirtualButton::new)
+                .collect(),
+            button_size: 64.0,
+            margin: 24.0,
+            opacity: 0.6,
+        }
+    }
+}
+
+/// Resource with the input of the virtual controls, applied to the touch
+/// player in the next update
+#[derive(Resource, Debug, Clone, Default)]
+pub struct VirtualControlsState {
+    /// Joystick deflection, y pointing up and at most 1 long
+    pub movement: Vec2,
+    /// Actions of the buttons pressed since the last update
+    pressed: Vec<GamepadAction>,
+}
+
+impl VirtualControlsState {
+    /// Press the button of an action, e.g. from a custom touch control
+    pub fn press(&mut self, action: GamepadAction) {
+        self.pressed.push(action);
+    }
+}
+
+/// Run condition that is true while a player plays with touch
+pub fn virtual_controls_active(assignment: Option<Res<InputDeviceAssignment>>) -> bool {
+    assignment.is_some_and(|assignment| assignment.is_device_assigned(&InputDevice::Touch))
+}
+
+/// System to feed the virtual controls into the controllers of touch players
+pub fn apply_virtual_controls(
+    mut state: ResMut<VirtualControlsState>,
+    mut controller_query: Query<(&mut InputController, &PlayerInputMapping)>,
+    settings: Res<InputSettings>,
+    mut input_events: MessageWriter<InputEvent>,
+) {
+    let pressed = std::mem::take(&mut state.pressed);
+
+    for (mut controller, mapping) in &mut controller_query {
+        if !controller.enabled || !mapping.enabled {
+            continue;
+        }
+
+        let touch = [&mapping.primary_device, &mapping.secondary_device]
+            .into_iter()
+            .any(|device| matches!(device, Some(InputDevice::Touch)));
+        if !touch {
+            continue;
+        }
+
+        let movement = state.movement.clamp_length_max(1.0);
+        if movement.length() > settings.movement_threshold {
+            controller.movement = movement;
+            controller.input_source = InputSource::Touch;
+
+            input_events.write(InputEvent::Movement {
+                player_id: controller.player_id,
+                direction: movement,
+                source: InputSource::Touch,
+            });
+        } else if controller.input_source == InputSource::Touch {
+            controller.movement = Vec2::ZERO;
+        }
+
+        for action in &pressed {
+            controller.input_source = InputSource::Touch;
+            let player_id = controller.player_id;
+            match action {
+                GamepadAction::Primary => {
+                    controller.primary_action = true;
+                    input_events.write(InputEvent::PrimaryAction {
+                        player_id,
+                        source: InputSource::Touch,
+                    });
+                }
+                GamepadAction::Secondary => {
+                    controller.secondary_action = true;
+                    input_events.write(InputEvent::SecondaryAction {
+                        player_id,
+                        source: InputSource::Touch,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// System to render the joystick and the action buttons
+fn render_virtual_controls(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    settings: Res<VirtualControlsSettings>,
+    mut state: ResMut<VirtualControlsState>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let (joystick_align, buttons_align, buttons_x) = match settings.joystick_side {
+        VirtualJoystickSide::Left => (egui::Align2::LEFT_BOTTOM, egui::Align2::RIGHT_BOTTOM, -1.0),
+        VirtualJoystickSide::Right => (egui::Align2::RIGHT_BOTTOM, egui::Align2::LEFT_BOTTOM, 1.0),
+    };
+    let margin = settings.margin;
+    let joystick_x = -buttons_x;
+
+    let movement = egui::Area::new(egui::Id::new("konnektoren_virtual_joystick"))
+        .anchor(joystick_align, egui::vec2(joystick_x * margin, -margin))
+        .show(ctx, |ui| {
+            let radius = settings.joystick_radius;
+            let (rect, response) =
+                ui.allocate_exact_size(egui::Vec2::splat(radius * 2.0), egui::Sense::drag());
+            let center = rect.center();
+
+            let offset = match response.interact_pointer_pos() {
+                Some(pointer) if response.dragged() => {
+                    let offset = pointer - center;
+                    offset * (radius / offset.length().max(radius))
+                }
+                _ => egui::Vec2::ZERO,
+            };
+
+            let painter = ui.painter();
+            painter.circle(
+                center,
+                radius,
+                theme.base_200.gamma_multiply(settings.opacity),
+                egui::Stroke::new(2.0, theme.base_300.gamma_multiply(settings.opacity)),
+            );
+            painter.circle_filled(
+                center + offset,
+                radius * 0.45,
+                theme.primary.gamma_multiply(settings.opacity),
+            );
+
+            // egui's y axis points down
+            Vec2::new(offset.x, -offset.y) / radius
+        })
+        .inner;
+    state.movement = movement;
+
+    egui::Area::new(egui::Id::new("konnektoren_virtual_buttons"))
+        .anchor(buttons_align, egui::vec2(buttons_x * margin, -margin))
+        .show(ctx, |ui| {
+            ui.spacing_mut().item_spacing.y = margin / 2.0;
+            for button in &settings.buttons {
+                let fill = match button.action {
+                    GamepadAction::Primary => theme.primary,
+                    GamepadAction::Secondary => theme.secondary,
+                };
+                let response = ui.add(
+                    egui::Button::new(
+                        egui::RichText::new(&button.label)
+                            .strong()
+                            .color(theme.base_100),
+                    )
+                    .fill(fill.gamma_multiply(settings.opacity))
+                    .corner_radius(egui::CornerRadius::same((settings.button_size / 2.0) as u8))
+                    .min_size(egui::Vec2::splat(settings.button_size)),
+                );
+                if response.clicked() {
+                    state.press(button.action);
+                }
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        input::{InputControllerExt, InputPlugin},
+        test_utils::TestHarness,
+        theme::KonnektorenThemePlugin,
+    };
+
+    #[test]
+    fn test_virtual_controls() {
+        let mut harness = TestHarness::new()
+            .with_screen_size(800.0, 600.0)
+            .with_plugins((KonnektorenThemePlugin, InputPlugin, VirtualControlsPlugin));
+        harness.world_mut().commands().spawn_input_controller(0);
+        harness.run_frames(2);
+        assert!(!harness.has_widget("Confirm"));
+
+        harness
+            .world_mut()
+            .resource_mut::<InputDeviceAssignment>()
+            .assign_device(0, InputDevice::Touch);
+        harness.run_frames(2);
+        assert!(harness.has_widget("Confirm"));
+        assert!(harness.has_widget("Back"));
+
+        harness.read_messages::<InputEvent>();
+        harness.click("Confirm");
+        harness.run_frames(2);
+        let events = harness.read_messages::<InputEvent>();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            InputEvent::PrimaryAction {
+                player_id: 0,
+                source: InputSource::Touch
+            }
+        )));
+        let controller = harness
+            .world_mut()
+            .query::<&InputController>()
+            .single(harness.world())
+            .unwrap();
+        assert_eq!(controller.input_source, InputSource::Touch);
+
+        // Pushing the joystick up moves the player up
+        let center = egui::pos2(24.0 + 64.0, 600.0 - 24.0 - 64.0);
+        harness.drag(center, center - egui::vec2(0.0, 64.0));
+        let events = harness.read_messages::<InputEvent>();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            InputEvent::Movement { player_id: 0, direction, .. } if direction.y > 0.5
+        )));
+    }
+}
@@ -16,7 +16,25 @@ pub mod screens;
 #[cfg(feature = "settings")]
 pub mod settings;
 
+#[cfg(feature = "storage")]
+pub mod storage;
+
+#[cfg(feature = "profile")]
+pub mod profile;
+
+#[cfg(feature = "web")]
+pub mod web;
+
+pub mod lifecycle;
+pub mod plugins;
+
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+
 pub mod prelude {
+    pub use crate::lifecycle::*;
+    pub use crate::plugins::KonnektorenPlugins;
+
     #[cfg(feature = "assets")]
     pub use crate::assets::{
         KonnektorenAssetLoader, KonnektorenAssetRegistry, KonnektorenAssetsPlugin, *,
@@ -29,11 +47,52 @@ pub mod prelude {
     pub use crate::ui::{UIPlugin, *};
 
     #[cfg(feature = "settings")]
-    pub use crate::settings::{builders::*, components::*, systems::*, SettingsPlugin};
+    pub use crate::settings::{
+        builders::*, components::*, display::*, history::*, id::*, index::*, observers::*,
+        performance::*, profiles::*, systems::*, SettingsPlugin,
+    };
+
+    #[cfg(feature = "audio")]
+    pub use crate::settings::audio::*;
+
+    #[cfg(all(feature = "screens", feature = "assets", feature = "profile"))]
+    pub use crate::screens::avatar::*;
+
+    #[cfg(all(feature = "screens", feature = "assets"))]
+    pub use crate::screens::survey::*;
+
+    #[cfg(all(feature = "screens", feature = "input"))]
+    pub use crate::screens::hot_seat::*;
+
+    #[cfg(feature = "editor")]
+    pub use crate::screens::{challenge_editor::*, level_editor::*};
+
+    #[cfg(feature = "asset-inspector")]
+    pub use crate::screens::asset_inspector::*;
+
+    #[cfg(all(feature = "screens", feature = "profile"))]
+    pub use crate::screens::{class_report::*, classroom::*, replay_viewer::*, setup_wizard::*};
 
     #[cfg(feature = "screens")]
-    pub use crate::screens::{about::*, credits::*, settings::*, splash::*, ScreensPlugin};
+    pub use crate::screens::{
+        about::*, any_screen_active, auto_pause::*, crash::*, credits::*, events::*, inactivity::*,
+        inbox::*, lobby::*, main_menu::*, marketplace::*, redeem_code::*, session_summary::*,
+        settings::*, shell::*, splash::*, state::*, track_active_screen, window_branding::*,
+        world_space::*, AnyScreenActive, NavigateBack, ScreensPlugin,
+    };
 
     #[cfg(feature = "input")]
-    pub use crate::input::{components::*, device::*, plugin::*, systems::*, InputPlugin};
+    pub use crate::input::{
+        components::*, device::*, player_style::*, plugin::*, recorder::*, split_screen::*,
+        systems::*, virtual_controls::*, InputPlugin,
+    };
+
+    #[cfg(feature = "storage")]
+    pub use crate::storage::{StoragePlugin, *};
+
+    #[cfg(feature = "profile")]
+    pub use crate::profile::{ProfilePlugin, *};
+
+    #[cfg(feature = "web")]
+    pub use crate::web::{WebPlugin, *};
 }
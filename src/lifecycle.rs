@@ -0,0 +1,240 @@
+#[cfg(feature = "storage")]
+use crate::storage::FlushStorage;
+use bevy::{
+    prelude::*,
+    window::{AppLifecycle, WindowFocused, WindowOccluded},
+};
+
+/// Plugin normalizing the platform lifecycle signals into
+/// [`AppLifecycleEvent`] messages.
+///
+/// - The app is paused when it is suspended on mobile or its window loses
+///   focus, e.g. when the player switches to another app or browser tab.
+/// - It is hidden while the window is minimized or fully covered, and while
+///   the browser tab is in the background.
+///
+/// The default [`LifecycleBehavior`] pauses the virtual time while paused,
+/// reports [`AppLifecycleState::is_muted`] while hidden and flushes the
+/// persisted state (with the `storage` feature) when the app is paused or
+/// hidden, as mobile systems and browsers may end it without further notice.
+#[derive(Default)]
+pub struct LifecyclePlugin {
+    pub behavior: LifecycleBehavior,
+}
+
+impl LifecyclePlugin {
+    pub fn with_behavior(mut self, behavior: LifecycleBehavior) -> Self {
+        self.behavior = behavior;
+        self
+    }
+}
+
+impl Plugin for LifecyclePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.behavior.clone())
+            .init_resource::<AppLifecycleState>()
+            .add_message::<AppLifecycle>()
+            .add_message::<WindowFocused>()
+            .add_message::<WindowOccluded>()
+            .add_message::<AppLifecycleEvent>()
+            .add_systems(
+                PreUpdate,
+                (read_lifecycle_signals, apply_lifecycle_behavior).chain(),
+            );
+
+        #[cfg(feature = "storage")]
+        app.add_message::<FlushStorage>();
+
+        info!("LifecyclePlugin loaded");
+    }
+}
+
+/// Default reactions to lifecycle changes, can be changed at runtime
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct LifecycleBehavior {
+    /// Pause the virtual time, and with it the gameplay, while paused
+    pub pause_gameplay: bool,
+    /// Mute the audio while hidden, see [`AppLifecycleState::is_muted`]
+    pub mute_when_hidden: bool,
+    /// Flush the persisted state when paused or hidden
+    pub save_when_paused: bool,
+}
+
+impl Default for LifecycleBehavior {
+    fn default() -> Self {
+        Self {
+            pause_gameplay: true,
+            mute_when_hidden: true,
+            save_when_paused: true,
+        }
+    }
+}
+
+/// Lifecycle changes of the app
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppLifecycleEvent {
+    /// The app was suspended or lost focus
+    Paused,
+    /// The app is back in the foreground
+    Resumed,
+    /// The window or browser tab is no longer visible
+    Hidden,
+    /// The window or browser tab is visible again
+    Visible,
+}
+
+/// Resource with the current lifecycle state
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AppLifecycleState {
+    pub paused: bool,
+    pub hidden: bool,
+    /// Whether the audio should be muted, games without the built-in audio
+    /// integration apply it to their `GlobalVolume`
+    pub muted: bool,
+    /// The virtual time was paused by the lifecycle, not by the game
+    paused_time: bool,
+}
+
+impl AppLifecycleState {
+    pub fn is_active(&self) -> bool {
+        !self.paused && !self.hidden
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.muted
+    }
+}
+
+/// System to turn the platform signals into [`AppLifecycleEvent`]s
+fn read_lifecycle_signals(
+    mut lifecycle: MessageReader<AppLifecycle>,
+    mut focus: MessageReader<WindowFocused>,
+    mut occluded: MessageReader<WindowOccluded>,
+    mut state: ResMut<AppLifecycleState>,
+    mut events: MessageWriter<AppLifecycleEvent>,
+) {
+    let mut paused = None;
+    for event in lifecycle.read() {
+        match event {
+            AppLifecycle::WillSuspend | AppLifecycle::Suspended => paused = Some(true),
+            AppLifecycle::WillResume | AppLifecycle::Running => paused = Some(false),
+            AppLifecycle::Idle => {}
+        }
+    }
+    for event in focus.read() {
+        paused = Some(!event.focused);
+    }
+    let hidden = occluded.read().last().map(|event| event.occluded);
+
+    if let Some(paused) = paused.filter(|paused| *paused != state.paused) {
+        state.paused = paused;
+        events.write(if paused {
+            AppLifecycleEvent::Paused
+        } else {
+            AppLifecycleEvent::Resumed
+        });
+    }
+    if let Some(hidden) = hidden.filter(|hidden| *hidden != state.hidden) {
+        state.hidden = hidden;
+        events.write(if hidden {
+            AppLifecycleEvent::Hidden
+        } else {
+            AppLifecycleEvent::Visible
+        });
+    }
+}
+
+/// System to apply the [`LifecycleBehavior`]
+fn apply_lifecycle_behavior(
+    mut events: MessageReader<AppLifecycleEvent>,
+    behavior: Res<LifecycleBehavior>,
+    mut state: ResMut<AppLifecycleState>,
+    mut time: ResMut<Time<Virtual>>,
+    #[cfg(feature = "storage")] mut flush: MessageWriter<FlushStorage>,
+) {
+    for event in events.read() {
+        info!("App lifecycle: {:?}", event);
+        match event {
+            AppLifecycleEvent::Paused => {
+                if behavior.pause_gameplay && !time.is_paused() {
+                    time.pause();
+                    state.paused_time = true;
+                }
+            }
+            AppLifecycleEvent::Resumed => {
+                // Keep the time paused when the game paused it, e.g. in a menu
+                if state.paused_time {
+                    time.unpause();
+                    state.paused_time = false;
+                }
+            }
+            AppLifecycleEvent::Hidden => {
+                state.muted = behavior.mute_when_hidden;
+            }
+            AppLifecycleEvent::Visible => {
+                state.muted = false;
+            }
+        }
+
+        #[cfg(feature = "storage")]
+        if behavior.save_when_paused
+            && matches!(event, AppLifecycleEvent::Paused | AppLifecycleEvent::Hidden)
+        {
+            flush.write(FlushStorage);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::MessageCursor;
+
+    #[test]
+    fn test_lifecycle_events() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, LifecyclePlugin::default()));
+        let window = app.world_mut().spawn_empty().id();
+        app.update();
+
+        app.world_mut().write_message(AppLifecycle::Suspended);
+        app.world_mut().write_message(WindowOccluded {
+            window,
+            occluded: true,
+        });
+        app.update();
+        let state = *app.world().resource::<AppLifecycleState>();
+        assert!(state.paused && state.hidden && state.is_muted());
+        assert!(app.world().resource::<Time<Virtual>>().is_paused());
+        #[cfg(feature = "storage")]
+        assert_eq!(app.world().resource::<Messages<FlushStorage>>().len(), 2);
+
+        // Focus coming back resumes the app
+        app.world_mut().write_message(WindowFocused {
+            window,
+            focused: true,
+        });
+        app.world_mut().write_message(WindowOccluded {
+            window,
+            occluded: false,
+        });
+        app.update();
+        assert!(app.world().resource::<AppLifecycleState>().is_active());
+        assert!(!app.world().resource::<Time<Virtual>>().is_paused());
+
+        let mut cursor = MessageCursor::<AppLifecycleEvent>::default();
+        let events: Vec<_> = cursor
+            .read(app.world().resource::<Messages<AppLifecycleEvent>>())
+            .copied()
+            .collect();
+        assert_eq!(
+            events,
+            [
+                AppLifecycleEvent::Paused,
+                AppLifecycleEvent::Hidden,
+                AppLifecycleEvent::Resumed,
+                AppLifecycleEvent::Visible
+            ]
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use bevy::{app::PluginGroupBuilder, prelude::*};
+
+/// Plugin group with all Konnektoren plugins enabled by the crate features.
+///
+/// Contains the lifecycle, theme, UI, screens, settings, input and assets
+/// plugins. The
+/// `EguiPlugin` is not part of the group and has to be added by the game.
+///
+/// Members can be disabled or replaced through the group builder:
+///
+/// ```ignore
+/// app.add_plugins(EguiPlugin::default())
+///     .add_plugins(KonnektorenPlugins.build().disable::<InputPlugin>());
+/// ```
+pub struct KonnektorenPlugins;
+
+impl PluginGroup for KonnektorenPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        let group =
+            PluginGroupBuilder::start::<Self>().add(crate::lifecycle::LifecyclePlugin::default());
+
+        #[cfg(feature = "theme")]
+        let group = group.add(crate::theme::KonnektorenThemePlugin);
+
+        #[cfg(feature = "ui")]
+        let group = group.add(crate::ui::UIPlugin);
+
+        #[cfg(feature = "assets")]
+        let group = group.add(crate::assets::KonnektorenAssetsPlugin);
+
+        #[cfg(feature = "settings")]
+        let group = group.add(crate::settings::SettingsPlugin);
+
+        #[cfg(feature = "input")]
+        let group = group.add(crate::input::InputPlugin);
+
+        #[cfg(feature = "screens")]
+        let group = group.add(crate::screens::ScreensPlugin);
+
+        group
+    }
+}
@@ -0,0 +1,500 @@
+use super::{ChallengeRuntimeEvent, PlayerProfile};
+#[cfg(feature = "settings")]
+use crate::settings::{get_setting_value, Setting, SettingChangedEvent, SettingsIndex};
+#[cfg(feature = "storage")]
+use crate::storage::{PersistenceScheduler, Storage};
+use bevy::prelude::*;
+use std::collections::{HashMap, VecDeque};
+
+/// Setting ID of the difficulty selection
+pub const DIFFICULTY_SETTING_ID: &str = "difficulty";
+
+/// Storage key of the answers and recommended tiers of the players
+pub const DIFFICULTY_STORAGE_KEY: &str = "difficulty";
+
+/// Plugin scaling the question difficulty with the accuracy of each player.
+///
+/// Answers checked by the game ([`ChallengeRuntimeEvent::AnswerChosen`] with
+/// `correct` set) count for the player of the [`PlayerProfile`]. Once enough
+/// answers were given, a high accuracy recommends the next harder
+/// [`DifficultyTier`] and a low one the next easier tier. The game picks the
+/// questions of the tier returned by [`DifficultyScaling::tier`]:
+///
+/// ```ignore
+/// app.add_plugins(DifficultyPlugin);
+///
+/// fn next_question(scaling: Res<DifficultyScaling>, profile: Res<PlayerProfile>) {
+///     let tier = scaling.tier(&profile.id);
+///     // Choose a question of the tier
+/// }
+/// ```
+///
+/// With [`DifficultyMode::Adaptive`] the recommendation is selected
+/// automatically, a [`DifficultyChanged`] is sent whenever it changes.
+/// The "Difficulty" selection of the settings (with the `settings` feature)
+/// sets the mode, its last option is "Adaptive".
+///
+/// With the `storage` feature the answers and recommended tiers of the
+/// players are persisted. The window and thresholds stay as configured by the
+/// app, e.g. with an inserted [`DifficultyScaling`].
+pub struct DifficultyPlugin;
+
+impl Plugin for DifficultyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DifficultyScaling>()
+            .add_message::<ChallengeRuntimeEvent>()
+            .add_message::<DifficultyChanged>()
+            .add_systems(Update, track_answer_accuracy);
+
+        #[cfg(feature = "settings")]
+        app.add_message::<SettingChangedEvent>()
+            .init_resource::<SettingsIndex>()
+            .add_systems(PostStartup, apply_initial_difficulty_setting)
+            .add_systems(
+                Update,
+                apply_difficulty_setting.before(track_answer_accuracy),
+            );
+
+        #[cfg(feature = "storage")]
+        {
+            app.add_systems(Startup, load_difficulty_scaling)
+                .add_systems(PostUpdate, save_difficulty_scaling);
+        }
+
+        info!("DifficultyPlugin loaded");
+    }
+}
+
+/// Difficulty tier of questions, from easiest to hardest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub enum DifficultyTier {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Expert,
+}
+
+impl DifficultyTier {
+    pub const ALL: [Self; 4] = [Self::Easy, Self::Normal, Self::Hard, Self::Expert];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Easy => "Easy",
+            Self::Normal => "Normal",
+            Self::Hard => "Hard",
+            Self::Expert => "Expert",
+        }
+    }
+
+    /// The next harder tier, if any
+    pub fn harder(&self) -> Option<Self> {
+        Self::ALL.get(*self as usize + 1).copied()
+    }
+
+    /// The next easier tier, if any
+    pub fn easier(&self) -> Option<Self> {
+        (*self as usize)
+            .checked_sub(1)
+            .and_then(|index| Self::ALL.get(index).copied())
+    }
+}
+
+/// How the difficulty is chosen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub enum DifficultyMode {
+    /// Always the same tier
+    Fixed(DifficultyTier),
+    /// The tier recommended by the accuracy of the player
+    Adaptive,
+}
+
+impl Default for DifficultyMode {
+    fn default() -> Self {
+        Self::Fixed(DifficultyTier::default())
+    }
+}
+
+impl DifficultyMode {
+    /// Mode of an option of the difficulty selection, see [`difficulty_labels`]
+    pub fn from_selection(index: usize) -> Option<Self> {
+        match DifficultyTier::ALL.get(index) {
+            Some(tier) => Some(Self::Fixed(*tier)),
+            None if index == DifficultyTier::ALL.len() => Some(Self::Adaptive),
+            None => None,
+        }
+    }
+
+    /// Option of the difficulty selection showing the mode
+    pub fn selection(&self) -> usize {
+        match self {
+            Self::Fixed(tier) => *tier as usize,
+            Self::Adaptive => DifficultyTier::ALL.len(),
+        }
+    }
+}
+
+/// Labels of the difficulty selection, the tiers followed by "Adaptive"
+pub fn difficulty_labels() -> Vec<String> {
+    DifficultyTier::ALL
+        .iter()
+        .map(|tier| tier.label().to_string())
+        .chain(std::iter::once("Adaptive".to_string()))
+        .collect()
+}
+
+/// Recent answers and recommended tier of one player
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "storage",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct PlayerDifficulty {
+    /// Whether the recent answers were correct, oldest first
+    pub answers: VecDeque<bool>,
+    pub recommended: DifficultyTier,
+}
+
+impl PlayerDifficulty {
+    /// Share of correct recent answers, `None` before the first answer
+    pub fn accuracy(&self) -> Option<f32> {
+        if self.answers.is_empty() {
+            return None;
+        }
+        let correct = self.answers.iter().filter(|correct| **correct).count();
+        Some(correct as f32 / self.answers.len() as f32)
+    }
+}
+
+/// Resource with the difficulty mode and the accuracy of every player
+#[derive(Resource, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "storage",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct DifficultyScaling {
+    pub mode: DifficultyMode,
+    /// Number of recent answers the accuracy is computed from
+    pub window: usize,
+    /// Answers needed before the recommendation changes
+    pub min_answers: usize,
+    /// Accuracy recommending the next harder tier
+    pub raise_above: f32,
+    /// Accuracy recommending the next easier tier
+    pub lower_below: f32,
+    /// State per player, by [`PlayerProfile`] id
+    pub players: HashMap<String, PlayerDifficulty>,
+}
+
+impl Default for DifficultyScaling {
+    fn default() -> Self {
+        Self {
+            mode: DifficultyMode::default(),
+            window: 20,
+            min_answers: 10,
+            raise_above: 0.85,
+            lower_below: 0.6,
+            players: HashMap::new(),
+        }
+    }
+}
+
+impl DifficultyScaling {
+    pub fn with_mode(mut self, mode: DifficultyMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn with_window(mut self, window: usize, min_answers: usize) -> Self {
+        self.window = window.max(1);
+        self.min_answers = min_answers.clamp(1, self.window);
+        self
+    }
+
+    pub fn with_thresholds(mut self, lower_below: f32, raise_above: f32) -> Self {
+        self.lower_below = lower_below;
+        self.raise_above = raise_above;
+        self
+    }
+
+    pub fn player(&self, player_id: &str) -> Option<&PlayerDifficulty> {
+        self.players.get(player_id)
+    }
+
+    /// Rolling accuracy of a player
+    pub fn accuracy(&self, player_id: &str) -> Option<f32> {
+        self.player(player_id).and_then(PlayerDifficulty::accuracy)
+    }
+
+    /// Tier recommended by the accuracy of a player
+    pub fn recommended(&self, player_id: &str) -> DifficultyTier {
+        self.player(player_id)
+            .map(|player| player.recommended)
+            .unwrap_or_default()
+    }
+
+    /// Tier to choose questions from for a player
+    pub fn tier(&self, player_id: &str) -> DifficultyTier {
+        match self.mode {
+            DifficultyMode::Fixed(tier) => tier,
+            DifficultyMode::Adaptive => self.recommended(player_id),
+        }
+    }
+
+    pub fn is_adaptive(&self) -> bool {
+        self.mode == DifficultyMode::Adaptive
+    }
+
+    /// Count an answer of a player, returns the new recommended tier if it
+    /// changed.
+    ///
+    /// The recent answers are cleared on a change, so the player answers
+    /// questions of the new tier before it changes again.
+    pub fn record_answer(&mut self, player_id: &str, correct: bool) -> Option<DifficultyTier> {
+        let (window, min_answers) = (self.window.max(1), self.min_answers);
+        let (raise_above, lower_below) = (self.raise_above, self.lower_below);
+
+        let player = self.players.entry(player_id.to_string()).or_default();
+        player.answers.push_back(correct);
+        while player.answers.len() > window {
+            player.answers.pop_front();
+        }
+
+        if player.answers.len() < min_answers {
+            return None;
+        }
+        let accuracy = player.accuracy()?;
+        let tier = if accuracy >= raise_above {
+            player.recommended.harder()
+        } else if accuracy < lower_below {
+            player.recommended.easier()
+        } else {
+            None
+        }?;
+
+        player.recommended = tier;
+        player.answers.clear();
+        Some(tier)
+    }
+
+    /// Forget the answers of a player
+    pub fn reset(&mut self, player_id: &str) {
+        self.players.remove(player_id);
+    }
+}
+
+/// Message sent when the adaptive difficulty selected a new tier for a player
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct DifficultyChanged {
+    pub player_id: String,
+    pub tier: DifficultyTier,
+}
+
+/// System to count the checked answers of the current player
+fn track_answer_accuracy(
+    mut events: MessageReader<ChallengeRuntimeEvent>,
+    mut scaling: ResMut<DifficultyScaling>,
+    profile: Option<Res<PlayerProfile>>,
+    mut changes: MessageWriter<DifficultyChanged>,
+) {
+    for event in events.read() {
+        let ChallengeRuntimeEvent::AnswerChosen {
+            correct: Some(correct),
+            ..
+        } = event
+        else {
+            continue;
+        };
+
+        let player_id = profile
+            .as_ref()
+            .map(|profile| profile.id.clone())
+            .unwrap_or_default();
+        let Some(tier) = scaling.record_answer(&player_id, *correct) else {
+            continue;
+        };
+
+        info!(
+            "Recommended difficulty of '{}' is now {:?}",
+            player_id, tier
+        );
+        if scaling.is_adaptive() {
+            changes.write(DifficultyChanged { player_id, tier });
+        }
+    }
+}
+
+/// System to update the difficulty mode from the settings
+#[cfg(feature = "settings")]
+fn apply_difficulty_setting(
+    mut setting_events: MessageReader<SettingChangedEvent>,
+    mut scaling: ResMut<DifficultyScaling>,
+) {
+    for event in setting_events.read() {
+        if !event.is(DIFFICULTY_SETTING_ID) {
+            continue;
+        }
+        let Some(mode) = event
+            .new_value
+            .as_selection()
+            .and_then(DifficultyMode::from_selection)
+        else {
+            continue;
+        };
+        if scaling.mode != mode {
+            scaling.mode = mode;
+        }
+    }
+}
+
+/// System to apply the difficulty setting spawned at startup, e.g. a
+/// persisted "Adaptive"
+#[cfg(feature = "settings")]
+fn apply_initial_difficulty_setting(
+    index: Res<SettingsIndex>,
+    settings: Query<&Setting>,
+    mut scaling: ResMut<DifficultyScaling>,
+) {
+    let Some(mode) = get_setting_value(&settings, &index, DIFFICULTY_SETTING_ID)
+        .and_then(|value| value.as_selection())
+        .and_then(DifficultyMode::from_selection)
+    else {
+        return;
+    };
+    if scaling.mode != mode {
+        scaling.mode = mode;
+    }
+}
+
+/// System to restore the answers of the players from storage
+#[cfg(feature = "storage")]
+fn load_difficulty_scaling(storage: Option<Res<Storage>>, mut scaling: ResMut<DifficultyScaling>) {
+    let Some(storage) = storage else {
+        return;
+    };
+
+    match storage.load::<HashMap<String, PlayerDifficulty>>(DIFFICULTY_STORAGE_KEY) {
+        Ok(Some(players)) => scaling.players = players,
+        Ok(None) => {}
+        Err(e) => warn!("Failed to load difficulty: {}", e),
+    }
+}
+
+/// System to persist the answers of the players whenever they change
+#[cfg(feature = "storage")]
+fn save_difficulty_scaling(
+    scheduler: Option<ResMut<PersistenceScheduler>>,
+    scaling: Res<DifficultyScaling>,
+) {
+    if !scaling.is_changed() {
+        return;
+    }
+
+    if let Some(mut scheduler) = scheduler {
+        scheduler.schedule(DIFFICULTY_STORAGE_KEY, &scaling.players);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adaptive_difficulty() {
+        let mut scaling = DifficultyScaling::default()
+            .with_mode(DifficultyMode::Adaptive)
+            .with_window(4, 4);
+
+        for _ in 0..3 {
+            assert_eq!(scaling.record_answer("anna", true), None);
+        }
+        assert_eq!(
+            scaling.record_answer("anna", true),
+            Some(DifficultyTier::Hard)
+        );
+        assert_eq!(scaling.tier("anna"), DifficultyTier::Hard);
+        assert_eq!(scaling.accuracy("anna"), None);
+
+        for correct in [true, false, false, true] {
+            scaling.record_answer("anna", correct);
+        }
+        assert_eq!(scaling.tier("anna"), DifficultyTier::Normal);
+
+        // Players are scaled separately
+        assert_eq!(scaling.record_answer("ben", false), None);
+        assert_eq!(scaling.accuracy("ben"), Some(0.0));
+        assert_eq!(scaling.tier("ben"), DifficultyTier::Normal);
+
+        scaling.mode = DifficultyMode::Fixed(DifficultyTier::Easy);
+        assert_eq!(scaling.tier("anna"), DifficultyTier::Easy);
+        assert_eq!(scaling.recommended("anna"), DifficultyTier::Normal);
+    }
+
+    #[test]
+    fn test_difficulty_selection() {
+        let labels = difficulty_labels();
+        assert_eq!(labels.last().map(String::as_str), Some("Adaptive"));
+        for (index, _) in labels.iter().enumerate() {
+            let mode = DifficultyMode::from_selection(index).unwrap();
+            assert_eq!(mode.selection(), index);
+        }
+        assert_eq!(
+            DifficultyMode::from_selection(labels.len() - 1),
+            Some(DifficultyMode::Adaptive)
+        );
+        assert_eq!(DifficultyMode::from_selection(labels.len()), None);
+    }
+
+    #[cfg(feature = "settings")]
+    #[test]
+    fn test_initial_difficulty_setting() {
+        use crate::settings::{SettingType, SettingValue, SettingsPlugin};
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, SettingsPlugin, DifficultyPlugin))
+            .add_systems(Startup, |mut commands: Commands| {
+                commands.spawn(Setting::new(
+                    DIFFICULTY_SETTING_ID,
+                    "Difficulty",
+                    SettingValue::Selection(DifficultyMode::Adaptive.selection()),
+                    SettingType::Selection {
+                        options: difficulty_labels(),
+                    },
+                ));
+            });
+        app.update();
+
+        assert!(app.world().resource::<DifficultyScaling>().is_adaptive());
+    }
+
+    #[cfg(feature = "storage")]
+    #[test]
+    fn test_difficulty_storage_keeps_configuration() {
+        let mut stored = DifficultyScaling::default();
+        stored.record_answer("anna", true);
+        let storage = Storage::memory();
+        storage
+            .save(DIFFICULTY_STORAGE_KEY, &stored.players)
+            .unwrap();
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(storage)
+            .insert_resource(
+                DifficultyScaling::default()
+                    .with_window(4, 2)
+                    .with_thresholds(0.5, 0.9),
+            )
+            .add_plugins(DifficultyPlugin);
+        app.update();
+
+        let scaling = app.world().resource::<DifficultyScaling>();
+        assert_eq!(scaling.accuracy("anna"), Some(1.0));
+        assert_eq!((scaling.window, scaling.min_answers), (4, 2));
+        assert_eq!((scaling.lower_below, scaling.raise_above), (0.5, 0.9));
+    }
+}
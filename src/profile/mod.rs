@@ -0,0 +1,409 @@
+#[cfg(feature = "storage")]
+use crate::storage::{PersistenceScheduler, Storage};
+use crate::theme::ThemeColor;
+use bevy::prelude::*;
+use chrono::{Days, NaiveDate};
+use std::collections::{BTreeMap, BTreeSet};
+
+pub mod difficulty;
+pub mod replay;
+pub mod report;
+pub use difficulty::*;
+pub use replay::*;
+pub use report::*;
+
+/// Storage key of the persisted player profile
+pub const PROFILE_STORAGE_KEY: &str = "profile";
+
+/// Plugin providing the [`PlayerProfile`] resource
+///
+/// [`FavoriteToggled`] messages of the screens update the favorites of the
+/// profile.
+pub struct ProfilePlugin;
+
+impl Plugin for ProfilePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayerProfile>()
+            .add_message::<FavoriteToggled>()
+            .add_systems(Update, apply_favorite_toggles);
+
+        #[cfg(feature = "storage")]
+        {
+            app.add_systems(Startup, load_player_profile)
+                .add_systems(PostUpdate, save_player_profile);
+        }
+
+        info!("ProfilePlugin loaded");
+    }
+}
+
+/// The selected part (and tint) of one avatar layer
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub struct AvatarLayerChoice {
+    pub layer: String,
+    pub part: String,
+    #[cfg_attr(feature = "storage", serde(default))]
+    pub tint: Option<ThemeColor>,
+}
+
+/// Appearance of a player's avatar, built from the layers of an avatar part set
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub struct AvatarAppearance {
+    /// Asset path of the part set the layers refer to
+    #[cfg_attr(feature = "storage", serde(default))]
+    pub part_set: Option<String>,
+    #[cfg_attr(feature = "storage", serde(default))]
+    pub layers: Vec<AvatarLayerChoice>,
+}
+
+impl AvatarAppearance {
+    pub fn new(part_set: impl Into<String>) -> Self {
+        Self {
+            part_set: Some(part_set.into()),
+            layers: vec![],
+        }
+    }
+
+    /// Get the choice for a layer
+    pub fn get(&self, layer: &str) -> Option<&AvatarLayerChoice> {
+        self.layers.iter().find(|choice| choice.layer == layer)
+    }
+
+    /// Select a part for a layer, keeping the tint of the previous choice
+    pub fn set_part(&mut self, layer: &str, part: impl Into<String>) {
+        let part = part.into();
+        match self.layers.iter_mut().find(|choice| choice.layer == layer) {
+            Some(choice) => choice.part = part,
+            None => self.layers.push(AvatarLayerChoice {
+                layer: layer.to_string(),
+                part,
+                tint: None,
+            }),
+        }
+    }
+
+    /// Set the tint of a layer that has a part selected
+    pub fn set_tint(&mut self, layer: &str, tint: Option<ThemeColor>) {
+        if let Some(choice) = self.layers.iter_mut().find(|choice| choice.layer == layer) {
+            choice.tint = tint;
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+}
+
+/// Practice sessions per day, the source of streaks and the calendar heatmap
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub struct PracticeHistory {
+    days: BTreeMap<NaiveDate, u32>,
+}
+
+impl PracticeHistory {
+    /// Count a practice session on a day
+    pub fn record(&mut self, date: NaiveDate) {
+        *self.days.entry(date).or_default() += 1;
+    }
+
+    /// Count a practice session today
+    pub fn record_today(&mut self) {
+        self.record(chrono::Local::now().date_naive());
+    }
+
+    /// Sessions on a day
+    pub fn count(&self, date: NaiveDate) -> u32 {
+        self.days.get(&date).copied().unwrap_or_default()
+    }
+
+    /// Sessions per day, only days with practice are included
+    pub fn days(&self) -> &BTreeMap<NaiveDate, u32> {
+        &self.days
+    }
+
+    /// Consecutive days with practice up to today.
+    ///
+    /// A streak is not broken before the day is over, so it may also end
+    /// yesterday.
+    pub fn current_streak(&self, today: NaiveDate) -> u32 {
+        let mut day = if self.count(today) > 0 {
+            today
+        } else {
+            match today.checked_sub_days(Days::new(1)) {
+                Some(yesterday) => yesterday,
+                None => return 0,
+            }
+        };
+
+        let mut streak = 0;
+        while self.count(day) > 0 {
+            streak += 1;
+            match day.checked_sub_days(Days::new(1)) {
+                Some(previous) => day = previous,
+                None => break,
+            }
+        }
+        streak
+    }
+
+    /// Most consecutive days with practice ever
+    pub fn longest_streak(&self) -> u32 {
+        let mut longest = 0;
+        let mut streak = 0;
+        let mut previous: Option<NaiveDate> = None;
+        for date in self.days.keys() {
+            let consecutive = previous
+                .and_then(|previous| previous.checked_add_days(Days::new(1)))
+                .is_some_and(|next| next == *date);
+            streak = if consecutive { streak + 1 } else { 1 };
+            longest = longest.max(streak);
+            previous = Some(*date);
+        }
+        longest
+    }
+}
+
+/// Result of one played challenge
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub struct PerformanceRecord {
+    pub challenge_id: String,
+    pub date: NaiveDate,
+    /// Score in percent
+    pub score: u32,
+    /// Seconds spent on the challenge
+    pub duration_secs: u32,
+    /// Classroom session the result was submitted to
+    #[cfg_attr(feature = "storage", serde(default))]
+    pub session_id: Option<String>,
+    /// Times audio clips of listening questions were played again
+    #[cfg_attr(feature = "storage", serde(default))]
+    pub audio_replays: u32,
+}
+
+impl PerformanceRecord {
+    pub fn new(challenge_id: impl Into<String>, date: NaiveDate, score: u32) -> Self {
+        Self {
+            challenge_id: challenge_id.into(),
+            date,
+            score: score.min(100),
+            duration_secs: 0,
+            session_id: None,
+            audio_replays: 0,
+        }
+    }
+
+    pub fn with_duration(mut self, duration_secs: u32) -> Self {
+        self.duration_secs = duration_secs;
+        self
+    }
+
+    pub fn with_audio_replays(mut self, audio_replays: u32) -> Self {
+        self.audio_replays = audio_replays;
+        self
+    }
+}
+
+/// Profile of the current player
+#[derive(Resource, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerProfile {
+    pub id: String,
+    pub name: String,
+    #[cfg_attr(feature = "storage", serde(default))]
+    pub avatar: AvatarAppearance,
+    #[cfg_attr(feature = "storage", serde(default))]
+    pub history: PracticeHistory,
+    #[cfg_attr(feature = "storage", serde(default))]
+    pub results: Vec<PerformanceRecord>,
+    /// IDs of the challenges the player starred to return to them quickly
+    #[cfg_attr(feature = "storage", serde(default))]
+    pub favorites: BTreeSet<String>,
+}
+
+impl Default for PlayerProfile {
+    fn default() -> Self {
+        Self {
+            id: "player".to_string(),
+            name: "Player".to_string(),
+            avatar: AvatarAppearance::default(),
+            history: PracticeHistory::default(),
+            results: vec![],
+            favorites: BTreeSet::new(),
+        }
+    }
+}
+
+impl PlayerProfile {
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            avatar: AvatarAppearance::default(),
+            history: PracticeHistory::default(),
+            results: vec![],
+            favorites: BTreeSet::new(),
+        }
+    }
+
+    pub fn with_avatar(mut self, avatar: AvatarAppearance) -> Self {
+        self.avatar = avatar;
+        self
+    }
+
+    /// Record the result of a played challenge, counting it as practice on
+    /// its day
+    pub fn record_result(&mut self, record: PerformanceRecord) {
+        self.history.record(record.date);
+        self.results.push(record);
+    }
+
+    pub fn is_favorite(&self, challenge_id: &str) -> bool {
+        self.favorites.contains(challenge_id)
+    }
+
+    /// Star or unstar a challenge
+    pub fn set_favorite(&mut self, challenge_id: &str, favorite: bool) {
+        if favorite {
+            self.favorites.insert(challenge_id.to_string());
+        } else {
+            self.favorites.remove(challenge_id);
+        }
+    }
+}
+
+/// Message sent by screens when the player starred or unstarred a challenge
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct FavoriteToggled {
+    pub challenge_id: String,
+    /// Whether the challenge is a favorite now
+    pub favorite: bool,
+}
+
+/// Filter of challenge lists, shown as tabs above the list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FavoritesFilter {
+    #[default]
+    All,
+    Favorites,
+}
+
+impl FavoritesFilter {
+    pub const ALL: [Self; 2] = [Self::All, Self::Favorites];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::All => "All",
+            Self::Favorites => "Favorites",
+        }
+    }
+
+    /// Tab labels in the order of [`FavoritesFilter::ALL`]
+    pub fn labels() -> [&'static str; 2] {
+        Self::ALL.map(|filter| filter.label())
+    }
+
+    /// Whether a challenge is shown with the filter
+    pub fn matches(&self, profile: &PlayerProfile, challenge_id: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Favorites => profile.is_favorite(challenge_id),
+        }
+    }
+}
+
+/// System to update the favorites of the profile
+fn apply_favorite_toggles(
+    mut toggles: MessageReader<FavoriteToggled>,
+    mut profile: ResMut<PlayerProfile>,
+) {
+    for toggle in toggles.read() {
+        if profile.is_favorite(&toggle.challenge_id) != toggle.favorite {
+            profile.set_favorite(&toggle.challenge_id, toggle.favorite);
+        }
+    }
+}
+
+/// System to restore the player profile from storage
+#[cfg(feature = "storage")]
+fn load_player_profile(storage: Option<Res<Storage>>, mut profile: ResMut<PlayerProfile>) {
+    let Some(storage) = storage else {
+        return;
+    };
+
+    match storage.load::<PlayerProfile>(PROFILE_STORAGE_KEY) {
+        Ok(Some(stored)) => {
+            info!("Loaded player profile '{}'", stored.name);
+            *profile = stored;
+        }
+        Ok(None) => {}
+        Err(e) => warn!("Failed to load player profile: {}", e),
+    }
+}
+
+/// System to persist the player profile whenever it changes
+#[cfg(feature = "storage")]
+fn save_player_profile(
+    scheduler: Option<ResMut<PersistenceScheduler>>,
+    profile: Res<PlayerProfile>,
+) {
+    if !profile.is_changed() {
+        return;
+    }
+
+    if let Some(mut scheduler) = scheduler {
+        scheduler.schedule(PROFILE_STORAGE_KEY, &*profile);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_practice_streaks() {
+        let date = |day| NaiveDate::from_ymd_opt(2025, 3, day).unwrap();
+        let mut history = PracticeHistory::default();
+        for day in [1, 2, 3, 4, 8, 9, 10] {
+            history.record(date(day));
+        }
+        history.record(date(10));
+
+        assert_eq!(history.count(date(10)), 2);
+        assert_eq!(history.longest_streak(), 4);
+        assert_eq!(history.current_streak(date(10)), 3);
+        // Today is not over yet
+        assert_eq!(history.current_streak(date(11)), 3);
+        assert_eq!(history.current_streak(date(12)), 0);
+    }
+
+    #[test]
+    fn test_favorite_toggles() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, ProfilePlugin));
+        app.world_mut().write_message(FavoriteToggled {
+            challenge_id: "articles".to_string(),
+            favorite: true,
+        });
+        app.world_mut().write_message(FavoriteToggled {
+            challenge_id: "verbs".to_string(),
+            favorite: true,
+        });
+        app.update();
+        app.world_mut().write_message(FavoriteToggled {
+            challenge_id: "verbs".to_string(),
+            favorite: false,
+        });
+        app.update();
+
+        let profile = app.world().resource::<PlayerProfile>();
+        assert!(profile.is_favorite("articles"));
+        assert!(!profile.is_favorite("verbs"));
+        assert!(FavoritesFilter::Favorites.matches(profile, "articles"));
+        assert!(!FavoritesFilter::Favorites.matches(profile, "verbs"));
+        assert!(FavoritesFilter::All.matches(profile, "verbs"));
+    }
+}
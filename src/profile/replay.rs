@@ -0,0 +1,276 @@
+use super::PlayerProfile;
+#[cfg(feature = "storage")]
+use crate::storage::{Storage, StorageError};
+use bevy::prelude::*;
+use chrono::NaiveDateTime;
+
+/// Prefix of the storage keys replays are saved to
+pub const REPLAY_STORAGE_PREFIX: &str = "replay";
+
+/// Storage key of the list of saved replay keys
+pub const REPLAY_INDEX_STORAGE_KEY: &str = "replays";
+
+/// Plugin recording challenge sessions into [`Replay`]s.
+///
+/// The game sends [`ChallengeRuntimeEvent`]s while a challenge is played. A
+/// recording starts with [`ChallengeRuntimeEvent::Started`] and ends with
+/// [`ChallengeRuntimeEvent::Finished`], then the replay is saved to the
+/// [`Storage`] (with the `storage` feature) and kept in the
+/// [`ReplayRecorder`]. Replays can be watched in the replay viewer screen.
+///
+/// ```ignore
+/// app.add_plugins(ReplayRecorderPlugin);
+///
+/// events.write(ChallengeRuntimeEvent::Started { challenge_id: "articles".into() });
+/// events.write(ChallengeRuntimeEvent::QuestionShown { index: 0, question: "__ Haus".into() });
+/// events.write(ChallengeRuntimeEvent::AnswerChosen { index: 0, answer: "das".into(), correct: Some(true) });
+/// events.write(ChallengeRuntimeEvent::Finished { score: 100 });
+/// ```
+pub struct ReplayRecorderPlugin;
+
+impl Plugin for ReplayRecorderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReplayRecorder>()
+            .add_message::<ChallengeRuntimeEvent>()
+            .add_message::<ReplayRecorded>()
+            .add_systems(Update, record_challenge_events);
+
+        info!("ReplayRecorderPlugin loaded");
+    }
+}
+
+/// Something that happened while a challenge was played
+#[derive(Message, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChallengeRuntimeEvent {
+    /// A challenge was started, begins a new recording
+    Started { challenge_id: String },
+    /// A question was shown to the player
+    QuestionShown { index: usize, question: String },
+    /// The player chose an answer, `correct` if the game checked it already
+    AnswerChosen {
+        index: usize,
+        answer: String,
+        correct: Option<bool>,
+    },
+    /// The audio clip of a listening question was played, again if it was
+    /// played before
+    AudioPlayed { index: usize, clip: String },
+    /// The challenge ended with a score in percent, ends the recording
+    Finished { score: u32 },
+}
+
+/// A recorded event with the seconds since the challenge started
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReplayEvent {
+    pub time_secs: f32,
+    pub event: ChallengeRuntimeEvent,
+}
+
+/// Recording of one challenge session, for teachers to review or to debug
+/// reported scoring issues
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub struct Replay {
+    pub challenge_id: String,
+    /// Name of the player, from the [`PlayerProfile`] if available
+    pub player: String,
+    pub recorded: NaiveDateTime,
+    pub events: Vec<ReplayEvent>,
+}
+
+impl Replay {
+    pub fn new(
+        challenge_id: impl Into<String>,
+        player: impl Into<String>,
+        recorded: NaiveDateTime,
+    ) -> Self {
+        Self {
+            challenge_id: challenge_id.into(),
+            player: player.into(),
+            recorded,
+            events: vec![],
+        }
+    }
+
+    pub fn push(&mut self, time_secs: f32, event: ChallengeRuntimeEvent) {
+        self.events.push(ReplayEvent { time_secs, event });
+    }
+
+    /// Seconds from the start to the last event
+    pub fn duration_secs(&self) -> f32 {
+        self.events.last().map_or(0.0, |event| event.time_secs)
+    }
+
+    /// Number of events that happened up to the given time
+    pub fn events_until(&self, time_secs: f32) -> usize {
+        self.events
+            .partition_point(|event| event.time_secs <= time_secs)
+    }
+
+    /// Final score, if the challenge was finished
+    pub fn score(&self) -> Option<u32> {
+        self.events
+            .iter()
+            .rev()
+            .find_map(|event| match event.event {
+                ChallengeRuntimeEvent::Finished { score } => Some(score),
+                _ => None,
+            })
+    }
+
+    /// Number of times audio clips were played again after the first time,
+    /// e.g. for [`PerformanceRecord::with_audio_replays`]
+    ///
+    /// [`PerformanceRecord::with_audio_replays`]: super::PerformanceRecord::with_audio_replays
+    pub fn audio_replays(&self) -> u32 {
+        let mut played = std::collections::HashSet::new();
+        self.events
+            .iter()
+            .filter(|event| match &event.event {
+                ChallengeRuntimeEvent::AudioPlayed { index, .. } => !played.insert(*index),
+                _ => false,
+            })
+            .count() as u32
+    }
+
+    /// Storage key the replay is saved to
+    pub fn storage_key(&self) -> String {
+        format!(
+            "{}_{}_{}",
+            REPLAY_STORAGE_PREFIX,
+            self.challenge_id,
+            self.recorded.format("%Y%m%d%H%M%S")
+        )
+    }
+
+    /// Save the replay and add it to the replay index, returns the storage key
+    #[cfg(feature = "storage")]
+    pub fn save(&self, storage: &Storage) -> Result<String, StorageError> {
+        let key = self.storage_key();
+        storage.save(&key, self)?;
+
+        let mut index = Self::saved_keys(storage)?;
+        if !index.contains(&key) {
+            index.push(key.clone());
+            storage.save(REPLAY_INDEX_STORAGE_KEY, &index)?;
+        }
+        Ok(key)
+    }
+
+    /// Load a saved replay
+    #[cfg(feature = "storage")]
+    pub fn load(storage: &Storage, key: &str) -> Result<Option<Self>, StorageError> {
+        storage.load(key)
+    }
+
+    /// Storage keys of all saved replays, oldest first
+    #[cfg(feature = "storage")]
+    pub fn saved_keys(storage: &Storage) -> Result<Vec<String>, StorageError> {
+        Ok(storage
+            .load::<Vec<String>>(REPLAY_INDEX_STORAGE_KEY)?
+            .unwrap_or_default())
+    }
+}
+
+/// Resource with the running recording and the last finished replay
+#[derive(Resource, Debug, Clone)]
+pub struct ReplayRecorder {
+    /// Events are ignored while false
+    pub enabled: bool,
+    current: Option<(Replay, f64)>,
+    last: Option<Replay>,
+}
+
+impl Default for ReplayRecorder {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            current: None,
+            last: None,
+        }
+    }
+}
+
+impl ReplayRecorder {
+    pub fn is_recording(&self) -> bool {
+        self.current.is_some()
+    }
+
+    /// The last finished replay
+    pub fn last_replay(&self) -> Option<&Replay> {
+        self.last.as_ref()
+    }
+}
+
+/// Message sent when a recording finished
+#[derive(Message, Debug, Clone)]
+pub struct ReplayRecorded {
+    pub replay: Replay,
+    /// Storage key the replay was saved to
+    pub key: Option<String>,
+}
+
+/// System to record the challenge runtime events
+fn record_challenge_events(
+    mut events: MessageReader<ChallengeRuntimeEvent>,
+    mut recorder: ResMut<ReplayRecorder>,
+    time: Res<Time>,
+    profile: Option<Res<PlayerProfile>>,
+    mut recorded: MessageWriter<ReplayRecorded>,
+    #[cfg(feature = "storage")] storage: Option<Res<Storage>>,
+) {
+    for event in events.read() {
+        if !recorder.enabled {
+            continue;
+        }
+        let now = time.elapsed_secs_f64();
+
+        if let ChallengeRuntimeEvent::Started { challenge_id } = event {
+            let player = profile
+                .as_ref()
+                .map(|profile| profile.name.clone())
+                .unwrap_or_default();
+            let mut replay = Replay::new(challenge_id, player, chrono::Local::now().naive_local());
+            replay.push(0.0, event.clone());
+            recorder.current = Some((replay, now));
+            continue;
+        }
+
+        let Some((replay, started)) = recorder.current.as_mut() else {
+            continue;
+        };
+        replay.push((now - *started) as f32, event.clone());
+
+        if matches!(event, ChallengeRuntimeEvent::Finished { .. }) {
+            let Some((replay, _)) = recorder.current.take() else {
+                continue;
+            };
+
+            #[cfg(feature = "storage")]
+            let key = storage
+                .as_ref()
+                .and_then(|storage| match replay.save(storage) {
+                    Ok(key) => Some(key),
+                    Err(e) => {
+                        warn!("Failed to save replay: {}", e);
+                        None
+                    }
+                });
+            #[cfg(not(feature = "storage"))]
+            let key = None;
+
+            info!(
+                "Recorded replay of '{}' with {} events",
+                replay.challenge_id,
+                replay.events.len()
+            );
+            recorded.write(ReplayRecorded {
+                replay: replay.clone(),
+                key,
+            });
+            recorder.last = Some(replay);
+        }
+    }
+}
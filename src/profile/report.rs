@@ -0,0 +1,209 @@
+use super::PlayerProfile;
+#[cfg(feature = "storage")]
+use crate::storage::{Storage, StorageError};
+use chrono::NaiveDate;
+
+/// Prefix of the storage keys reports are exported to
+pub const CLASS_REPORT_STORAGE_PREFIX: &str = "class_report";
+
+/// File format of an exported report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Csv,
+    Json,
+}
+
+impl ReportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ReportFormat::Csv => "csv",
+            ReportFormat::Json => "json",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ReportFormat::Csv => "CSV",
+            ReportFormat::Json => "JSON",
+        }
+    }
+}
+
+/// Progress and performance of one student, aggregated from a profile
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize))]
+pub struct StudentSummary {
+    pub id: String,
+    pub name: String,
+    pub challenges_played: usize,
+    /// Average score in percent, `None` without results
+    pub average_score: Option<f32>,
+    pub best_score: Option<u32>,
+    pub total_time_secs: u64,
+    /// Days with at least one practice session
+    pub practice_days: usize,
+    pub current_streak: u32,
+    pub longest_streak: u32,
+    pub last_practice: Option<NaiveDate>,
+}
+
+impl StudentSummary {
+    pub fn from_profile(profile: &PlayerProfile, today: NaiveDate) -> Self {
+        let results = &profile.results;
+        let average_score = (!results.is_empty())
+            .then(|| results.iter().map(|r| r.score as f32).sum::<f32>() / results.len() as f32);
+
+        Self {
+            id: profile.id.clone(),
+            name: profile.name.clone(),
+            challenges_played: results.len(),
+            average_score,
+            best_score: results.iter().map(|r| r.score).max(),
+            total_time_secs: results.iter().map(|r| u64::from(r.duration_secs)).sum(),
+            practice_days: profile.history.days().len(),
+            current_streak: profile.history.current_streak(today),
+            longest_streak: profile.history.longest_streak(),
+            last_practice: profile.history.days().keys().next_back().copied(),
+        }
+    }
+}
+
+/// Summary of the results of a class, for teachers to review or print.
+///
+/// ```ignore
+/// let report = ClassReport::from_profiles("Class 4b", &profiles, today);
+/// report.export(&storage, ReportFormat::Csv)?;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize))]
+pub struct ClassReport {
+    pub title: String,
+    pub generated: NaiveDate,
+    pub students: Vec<StudentSummary>,
+}
+
+impl ClassReport {
+    /// Build a report from the profiles of a class, sorted by student name
+    pub fn from_profiles<'a>(
+        title: impl Into<String>,
+        profiles: impl IntoIterator<Item = &'a PlayerProfile>,
+        today: NaiveDate,
+    ) -> Self {
+        let mut students: Vec<_> = profiles
+            .into_iter()
+            .map(|profile| StudentSummary::from_profile(profile, today))
+            .collect();
+        students.sort_by_key(|student| student.name.to_lowercase());
+
+        Self {
+            title: title.into(),
+            generated: today,
+            students,
+        }
+    }
+
+    /// Average score of all students with results
+    pub fn class_average(&self) -> Option<f32> {
+        let scores: Vec<f32> = self
+            .students
+            .iter()
+            .filter_map(|student| student.average_score)
+            .collect();
+        (!scores.is_empty()).then(|| scores.iter().sum::<f32>() / scores.len() as f32)
+    }
+
+    /// One line per student, with a header line
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(
+            "id,name,challenges_played,average_score,best_score,total_time_secs,practice_days,current_streak,longest_streak,last_practice\n",
+        );
+        for student in &self.students {
+            let fields = [
+                csv_field(&student.id),
+                csv_field(&student.name),
+                student.challenges_played.to_string(),
+                student
+                    .average_score
+                    .map(|score| format!("{:.1}", score))
+                    .unwrap_or_default(),
+                student
+                    .best_score
+                    .map(|score| score.to_string())
+                    .unwrap_or_default(),
+                student.total_time_secs.to_string(),
+                student.practice_days.to_string(),
+                student.current_streak.to_string(),
+                student.longest_streak.to_string(),
+                student
+                    .last_practice
+                    .map(|date| date.to_string())
+                    .unwrap_or_default(),
+            ];
+            csv.push_str(&fields.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    #[cfg(feature = "storage")]
+    pub fn to_json(&self) -> Result<String, StorageError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Storage key the report is exported to in the given format
+    pub fn storage_key(&self, format: ReportFormat) -> String {
+        format!(
+            "{}_{}.{}",
+            CLASS_REPORT_STORAGE_PREFIX,
+            self.generated,
+            format.extension()
+        )
+    }
+
+    /// Write the report to the storage backend, returns the storage key
+    #[cfg(feature = "storage")]
+    pub fn export(&self, storage: &Storage, format: ReportFormat) -> Result<String, StorageError> {
+        let content = match format {
+            ReportFormat::Csv => self.to_csv(),
+            ReportFormat::Json => self.to_json()?,
+        };
+        let key = self.storage_key(format);
+        storage.backend().set(&key, &content)?;
+        Ok(key)
+    }
+}
+
+/// Quote a CSV field if it contains separators, quotes or line breaks
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::PerformanceRecord;
+
+    #[test]
+    fn test_class_report_csv() {
+        let date = |day| NaiveDate::from_ymd_opt(2025, 3, day).unwrap();
+        let mut anna = PlayerProfile::new("anna", "Anna");
+        anna.record_result(PerformanceRecord::new("articles", date(1), 80).with_duration(120));
+        anna.record_result(PerformanceRecord::new("verbs", date(2), 100).with_duration(60));
+        let ben = PlayerProfile::new("ben", "Ben, Jr.");
+
+        let report = ClassReport::from_profiles("Class 4b", [&ben, &anna], date(2));
+        assert_eq!(report.students[0].name, "Anna");
+        assert_eq!(report.students[0].current_streak, 2);
+        assert_eq!(report.class_average(), Some(90.0));
+
+        let csv = report.to_csv();
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[1], "anna,Anna,2,90.0,100,180,2,2,2,2025-03-02");
+        assert_eq!(lines[2], "ben,\"Ben, Jr.\",0,,,0,0,0,0,");
+    }
+}
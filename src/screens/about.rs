@@ -1,14 +1,22 @@
+use super::{
+    dismiss_on_inactivity, forward_screen_events, insert_inactivity_timeout, links::LinkOpener,
+    track_active_screen, ScreenAction, ScreenContexts, ScreenKind, ScreenMessage,
+    WorldScreenContextPass,
+};
 use crate::{
     theme::KonnektorenTheme,
     ui::{
+        banner::ThemedBanner,
+        menu_background::MenuBackgroundState,
         responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
         widgets::{ResponsiveText, ThemedButton},
     },
 };
 use bevy::prelude::*;
 use bevy_egui::{
     egui::{self, Color32, Widget},
-    EguiContexts, EguiPrimaryContextPass,
+    EguiPrimaryContextPass,
 };
 use chrono::Utc;
 
@@ -17,9 +25,20 @@ pub struct AboutPlugin;
 
 impl Plugin for AboutPlugin {
     fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveAbout>(app);
+        forward_screen_events::<AboutDismissed>(app);
+        dismiss_on_inactivity::<ActiveAbout, _>(app, |entity| AboutDismissed { entity });
+
         app.add_message::<AboutDismissed>()
             .add_systems(Update, (check_about_config, handle_about_completion))
-            .add_systems(EguiPrimaryContextPass, render_about_ui);
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_about_ui.run_if(any_with_component::<ActiveAbout>),
+            )
+            .add_systems(
+                WorldScreenContextPass,
+                render_about_ui.run_if(any_with_component::<ActiveAbout>),
+            );
     }
 }
 
@@ -54,6 +73,8 @@ pub struct AboutConfig {
     pub custom_sections: Vec<CustomSection>,
     /// Button text for dismissal
     pub dismiss_button_text: String,
+    /// Dismiss the screen after this many seconds without input
+    pub auto_dismiss: Option<f32>,
 }
 
 /// Website link configuration
@@ -106,6 +127,7 @@ impl Default for AboutConfig {
             extension_widget: None,
             custom_sections: vec![],
             dismiss_button_text: "← Back".to_string(),
+            auto_dismiss: None,
         }
     }
 }
@@ -217,6 +239,13 @@ impl AboutConfig {
         self
     }
 
+    /// Dismiss the screen after the given seconds without input, e.g. to
+    /// return a kiosk to the menu
+    pub fn with_auto_dismiss(mut self, seconds: f32) -> Self {
+        self.auto_dismiss = Some(seconds);
+        self
+    }
+
     /// Create a default game-focused about config
     pub fn for_game(title: impl Into<String>) -> Self {
         Self {
@@ -242,12 +271,19 @@ pub struct ActiveAbout {
     navigation_state: NavigationState,
 }
 
-/// Navigation state for keyboard/gamepad support
+/// Navigation state for keyboard/gamepad support.
+///
+/// Website links have the indices `1..=max_index`, the back button has index 0
+/// and comes after the links.
 #[derive(Clone)]
 pub struct NavigationState {
     pub current_index: usize,
     pub max_index: usize,
     pub enabled: bool,
+    /// Whether the back button takes part in the navigation
+    pub has_back_button: bool,
+    /// Focus moved in this frame, the focused link scrolls into view
+    pub focus_moved: bool,
 }
 
 impl Default for NavigationState {
@@ -256,16 +292,52 @@ impl Default for NavigationState {
             current_index: 0,
             max_index: 0,
             enabled: true,
+            has_back_button: false,
+            focus_moved: false,
         }
     }
 }
 
+impl NavigationState {
+    /// Focusable indices from top to bottom
+    fn focus_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (1..=self.max_index).collect();
+        if self.has_back_button {
+            order.push(0);
+        }
+        order
+    }
+
+    /// Move the focus by `step` elements, wrapping around at the ends
+    pub fn move_focus(&mut self, step: isize) {
+        let order = self.focus_order();
+        if order.is_empty() {
+            return;
+        }
+        let position = order
+            .iter()
+            .position(|index| *index == self.current_index)
+            .unwrap_or(0) as isize;
+        let next = (position + step).rem_euclid(order.len() as isize) as usize;
+        self.current_index = order[next];
+        self.focus_moved = true;
+    }
+}
+
 /// Event sent when about screen should be dismissed
 #[derive(Message)]
 pub struct AboutDismissed {
     pub entity: Entity,
 }
 
+impl ScreenMessage for AboutDismissed {
+    const KIND: ScreenKind = ScreenKind::About;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        Some((ScreenAction::Dismissed, self.entity))
+    }
+}
+
 /// System to check for new about configurations and set them up
 #[allow(clippy::type_complexity)]
 fn check_about_config(
@@ -282,38 +354,46 @@ fn check_about_config(
             commands.entity(existing_entity).remove::<ActiveAbout>();
         }
 
-        // Calculate navigation indices
-        let mut nav_state = NavigationState {
+        // Calculate navigation indices, focus starts at the first link
+        let nav_state = NavigationState {
+            current_index: usize::from(!config.websites.is_empty()),
             max_index: config.websites.len(),
+            has_back_button: config.manual_dismissal,
             ..Default::default()
         };
-        if config.manual_dismissal {
-            nav_state.max_index += 1;
-        }
 
-        commands.entity(entity).insert(ActiveAbout {
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.insert(ActiveAbout {
             config: config.clone(),
             navigation_state: nav_state,
         });
+        insert_inactivity_timeout(&mut entity_commands, config.auto_dismiss);
     }
 }
 
 /// System to render about UI
+#[allow(clippy::too_many_arguments)]
 fn render_about_ui(
-    mut contexts: EguiContexts,
+    mut contexts: ScreenContexts,
     theme: Res<KonnektorenTheme>,
     responsive: Res<ResponsiveInfo>,
     mut query: Query<(Entity, &mut ActiveAbout)>,
     mut dismiss_events: MessageWriter<AboutDismissed>,
     input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    background: Option<Res<MenuBackgroundState>>,
+    links: LinkOpener,
 ) {
     if query.is_empty() {
         return;
     }
 
-    if let Ok(ctx) = contexts.ctx_mut() {
-        // Only render the first (most recent) about screen to avoid widget ID conflicts
-        if let Some((entity, mut about)) = query.iter_mut().next() {
+    // Only render the first (most recent) about screen to avoid widget ID conflicts
+    if let Some((entity, mut about)) = query
+        .iter_mut()
+        .find(|(entity, _)| contexts.renders(*entity))
+    {
+        if let Some(ctx) = contexts.ctx_for(entity) {
             // Check dismissal first with separate borrow
             let should_dismiss =
                 about.config.manual_dismissal && input.just_pressed(KeyCode::Escape);
@@ -328,30 +408,72 @@ fn render_about_ui(
                 navigation_state,
             } = &mut *about;
 
+            // Up/Down (or the d-pad) move the focus, Enter (or A) activates it
+            let gamepad_pressed =
+                |button| gamepads.iter().any(|gamepad| gamepad.just_pressed(button));
+            navigation_state.focus_moved = false;
+            if navigation_state.enabled {
+                if input.just_pressed(KeyCode::ArrowDown)
+                    || gamepad_pressed(GamepadButton::DPadDown)
+                {
+                    navigation_state.move_focus(1);
+                } else if input.just_pressed(KeyCode::ArrowUp)
+                    || gamepad_pressed(GamepadButton::DPadUp)
+                {
+                    navigation_state.move_focus(-1);
+                }
+
+                let activate = input.just_pressed(KeyCode::Enter)
+                    || input.just_pressed(KeyCode::NumpadEnter)
+                    || gamepad_pressed(GamepadButton::South);
+                if activate {
+                    match navigation_state.current_index {
+                        0 if navigation_state.has_back_button => {
+                            dismiss_events.write(AboutDismissed { entity });
+                            return;
+                        }
+                        0 => {}
+                        index => {
+                            if let Some(website) = config.websites.get(index - 1) {
+                                links.open(&website.url);
+                            }
+                        }
+                    }
+                }
+            }
+
             egui::CentralPanel::default()
                 .frame(egui::Frame::NONE.fill(theme.base_100))
                 .show(ctx, |ui| {
-                    render_about_content(
-                        ui,
-                        config,
-                        navigation_state,
-                        &theme,
-                        &responsive,
-                        entity,
-                        &mut dismiss_events,
-                    );
+                    scoped_ui(ui, (entity, "about"), |ui| {
+                        if let Some(background) = &background {
+                            background.paint(ui.painter(), ui.max_rect(), &theme);
+                        }
+                        render_about_content(
+                            ui,
+                            config,
+                            navigation_state,
+                            &theme,
+                            &responsive,
+                            &links,
+                            entity,
+                            &mut dismiss_events,
+                        );
+                    })
                 });
         }
     }
 }
 
 /// Render about screen content
+#[allow(clippy::too_many_arguments)]
 fn render_about_content(
     ui: &mut egui::Ui,
     config: &AboutConfig,
     nav_state: &mut NavigationState,
     theme: &KonnektorenTheme,
     responsive: &ResponsiveInfo,
+    links: &LinkOpener,
     entity: Entity,
     dismiss_events: &mut MessageWriter<AboutDismissed>,
 ) {
@@ -376,7 +498,7 @@ fn render_about_content(
             .max_height(scroll_height)
             .auto_shrink([false; 2])
             .show(ui, |ui| {
-                render_content_sections(ui, config, nav_state, theme, responsive);
+                render_content_sections(ui, config, nav_state, theme, responsive, links);
             });
 
         // Back button at bottom
@@ -454,6 +576,7 @@ fn render_content_sections(
     nav_state: &NavigationState,
     theme: &KonnektorenTheme,
     responsive: &ResponsiveInfo,
+    links: &LinkOpener,
 ) {
     let section_spacing = responsive.spacing(ResponsiveSpacing::Large);
 
@@ -520,11 +643,12 @@ fn render_content_sections(
 
     // Status message section
     if let Some((message, color)) = &config.status_message {
-        render_section(ui, theme, responsive, "Status", |ui| {
-            ResponsiveText::new(message, ResponsiveFontSize::Medium, *color)
-                .responsive(responsive)
-                .ui(ui);
-        });
+        ThemedBanner::new("about.status", message, theme)
+            .title("Status")
+            .color(*color)
+            .dismissible(false)
+            .responsive(responsive)
+            .show(ui);
         ui.add_space(section_spacing * 1.5);
     }
 
@@ -543,7 +667,7 @@ fn render_content_sections(
             ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
 
             for (index, website) in config.websites.iter().enumerate() {
-                render_website_link(ui, theme, responsive, nav_state, website, index + 1);
+                render_website_link(ui, theme, responsive, nav_state, links, website, index + 1);
                 ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
             }
         });
@@ -668,6 +792,7 @@ fn render_website_link(
     theme: &KonnektorenTheme,
     responsive: &ResponsiveInfo,
     nav_state: &NavigationState,
+    links: &LinkOpener,
     website: &WebsiteLink,
     nav_index: usize,
 ) {
@@ -692,7 +817,7 @@ fn render_website_link(
         ..Default::default()
     };
 
-    link_frame.show(ui, |ui| {
+    let response = link_frame.show(ui, |ui| {
         ui.vertical_centered(|ui| {
             ResponsiveText::new(&website.title, ResponsiveFontSize::Medium, theme.primary)
                 .responsive(responsive)
@@ -715,13 +840,22 @@ fn render_website_link(
                 website.url.clone()
             };
 
-            let url_button = ThemedButton::new(&button_text, theme).responsive(responsive);
+            let url_button = ThemedButton::new(&button_text, theme)
+                .responsive(responsive)
+                .enabled(links.allowed());
 
-            if ui.add(url_button).clicked() {
-                open_url(&website.url);
+            let response = ui.add(url_button);
+            if !links.allowed() {
+                response.on_disabled_hover_text("Blocked by parental controls");
+            } else if response.clicked() {
+                links.open(&website.url);
             }
         });
     });
+
+    if is_focused && nav_state.focus_moved {
+        response.response.scroll_to_me(None);
+    }
 }
 
 fn render_dismiss_button(
@@ -734,11 +868,18 @@ fn render_dismiss_button(
     dismiss_events: &mut MessageWriter<AboutDismissed>,
 ) {
     ui.vertical_centered(|ui| {
-        let _is_focused = nav_state.enabled && nav_state.current_index == 0;
+        let is_focused = nav_state.enabled && nav_state.current_index == 0;
 
         let back_button = ThemedButton::new(&config.dismiss_button_text, theme)
             .responsive(responsive)
-            .width(if responsive.is_mobile() { 200.0 } else { 250.0 });
+            .width(if responsive.is_mobile() { 200.0 } else { 250.0 })
+            .with_style(move |button| {
+                if is_focused {
+                    button.stroke(egui::Stroke::new(2.0, theme.accent))
+                } else {
+                    button
+                }
+            });
 
         if ui.add(back_button).clicked() {
             dismiss_events.write(AboutDismissed { entity });
@@ -757,34 +898,6 @@ fn handle_about_completion(
     }
 }
 
-/// Helper function to open URLs
-fn open_url(url: &str) {
-    #[cfg(target_arch = "wasm32")]
-    {
-        use wasm_bindgen::prelude::*;
-
-        #[wasm_bindgen]
-        extern "C" {
-            #[wasm_bindgen(js_namespace = ["window"], js_name = open)]
-            fn window_open(url: &str);
-        }
-
-        window_open(url);
-    }
-
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        if let Err(e) = std::process::Command::new("xdg-open")
-            .arg(url)
-            .spawn()
-            .or_else(|_| std::process::Command::new("open").arg(url).spawn())
-            .or_else(|_| std::process::Command::new("start").arg(url).spawn())
-        {
-            error!("Failed to open URL: {}", e);
-        }
-    }
-}
-
 /// Helper trait for easy about screen setup
 pub trait AboutScreenExt {
     /// Add an about screen with the given configuration
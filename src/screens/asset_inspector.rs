@@ -0,0 +1,571 @@
+use super::{forward_screen_events, track_active_screen, ScreenAction, ScreenKind, ScreenMessage};
+use crate::{
+    assets::{ChallengeAsset, ContentKind, KonnektorenAssetRegistry, LevelAsset},
+    theme::KonnektorenTheme,
+    ui::{
+        responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
+        widgets::{ResponsiveText, ThemedButton},
+    },
+};
+use bevy::{asset::LoadState, prelude::*};
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+use std::sync::Arc;
+
+/// Plugin for the asset inspector debug screen
+pub struct AssetInspectorPlugin;
+
+impl Plugin for AssetInspectorPlugin {
+    fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveAssetInspector>(app);
+        forward_screen_events::<AssetInspectorEvent>(app);
+
+        app.add_message::<AssetInspectorEvent>()
+            .add_systems(
+                Update,
+                (check_asset_inspector_config, handle_asset_inspector_events).chain(),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_asset_inspector_ui.run_if(any_with_component::<ActiveAssetInspector>),
+            );
+    }
+}
+
+/// Configuration for the asset inspector screen.
+///
+/// Lists the challenges and levels of the [`KonnektorenAssetRegistry`] with
+/// their load state, the number of strong handles keeping them alive and an
+/// estimate of their memory use, with buttons to reload or unload them.
+#[derive(Component, Clone)]
+pub struct AssetInspectorConfig {
+    pub title: String,
+    pub dismiss_button_text: String,
+    /// Allow manual dismissal (back button/escape)
+    pub manual_dismissal: bool,
+}
+
+impl Default for AssetInspectorConfig {
+    fn default() -> Self {
+        Self {
+            title: "Asset Inspector".to_string(),
+            dismiss_button_text: "← Back".to_string(),
+            manual_dismissal: true,
+        }
+    }
+}
+
+impl AssetInspectorConfig {
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn with_dismiss_button_text(mut self, text: impl Into<String>) -> Self {
+        self.dismiss_button_text = text.into();
+        self
+    }
+
+    pub fn with_manual_dismissal(mut self, manual_dismissal: bool) -> Self {
+        self.manual_dismissal = manual_dismissal;
+        self
+    }
+}
+
+/// Component marking an active asset inspector screen
+#[derive(Component)]
+pub struct ActiveAssetInspector {
+    config: AssetInspectorConfig,
+    /// Only show assets whose ID or path contains this text
+    filter: String,
+}
+
+/// Events for asset inspector interactions
+#[derive(Message, Debug, Clone, PartialEq)]
+pub enum AssetInspectorEvent {
+    /// Load the asset from its file again
+    Reload {
+        entity: Entity,
+        kind: ContentKind,
+        id: String,
+    },
+    /// Remove the asset from the registry, freeing it once no other handle
+    /// holds it
+    Unload {
+        entity: Entity,
+        kind: ContentKind,
+        id: String,
+    },
+    /// Asset inspector screen dismissed
+    Dismissed { entity: Entity },
+}
+
+impl ScreenMessage for AssetInspectorEvent {
+    const KIND: ScreenKind = ScreenKind::AssetInspector;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::Reload { entity, .. } | Self::Unload { entity, .. } => {
+                Some((ScreenAction::Changed, *entity))
+            }
+            Self::Dismissed { entity } => Some((ScreenAction::Dismissed, *entity)),
+        }
+    }
+}
+
+/// One registry entry as shown by the inspector
+#[derive(Debug, Clone, PartialEq)]
+pub struct InspectedAsset {
+    pub kind: ContentKind,
+    pub id: String,
+    pub path: Option<String>,
+    pub load_state: &'static str,
+    /// Strong handles keeping the asset alive, including the registry's own
+    pub strong_handles: usize,
+    /// Rough estimate of the memory used by the loaded asset, in bytes
+    pub approximate_bytes: Option<usize>,
+}
+
+fn load_state_label<A: Asset>(
+    handle: &Handle<A>,
+    assets: &Assets<A>,
+    asset_server: &AssetServer,
+) -> &'static str {
+    // Assets added directly are not known to the asset server
+    if assets.contains(handle) {
+        return "Loaded";
+    }
+    match asset_server.load_state(handle) {
+        LoadState::NotLoaded => "Not loaded",
+        LoadState::Loading => "Loading",
+        LoadState::Loaded => "Loaded",
+        LoadState::Failed(_) => "Failed",
+    }
+}
+
+fn strong_handles<A: Asset>(handle: &Handle<A>) -> usize {
+    match handle {
+        Handle::Strong(handle) => Arc::strong_count(handle),
+        _ => 0,
+    }
+}
+
+/// Size of the asset struct plus its serialized content, close enough to
+/// compare assets and spot the large ones
+fn approximate_bytes<T: serde::Serialize>(value: &T, file_path: &str) -> usize {
+    std::mem::size_of::<T>()
+        + file_path.len()
+        + serde_yaml::to_string(value).map_or(0, |yaml| yaml.len())
+}
+
+/// Collect the registry entries with their state, sorted by kind and ID
+pub fn inspect_assets(
+    registry: &KonnektorenAssetRegistry,
+    asset_server: &AssetServer,
+    challenges: &Assets<ChallengeAsset>,
+    levels: &Assets<LevelAsset>,
+) -> Vec<InspectedAsset> {
+    let mut assets: Vec<InspectedAsset> = registry
+        .challenges
+        .iter()
+        .map(|(id, handle)| InspectedAsset {
+            kind: ContentKind::Challenge,
+            id: id.clone(),
+            path: handle.path().map(ToString::to_string),
+            load_state: load_state_label(handle, challenges, asset_server),
+            strong_handles: strong_handles(handle),
+            approximate_bytes: challenges.get(handle).map(|challenge| {
+                approximate_bytes(&challenge.challenge_type, &challenge.file_path)
+            }),
+        })
+        .chain(registry.levels.iter().map(|(id, handle)| {
+            InspectedAsset {
+                kind: ContentKind::Level,
+                id: id.clone(),
+                path: handle.path().map(ToString::to_string),
+                load_state: load_state_label(handle, levels, asset_server),
+                strong_handles: strong_handles(handle),
+                approximate_bytes: levels
+                    .get(handle)
+                    .map(|level| approximate_bytes(&level.game_path, &level.file_path)),
+            }
+        }))
+        .collect();
+    assets.sort_by(|a, b| (a.kind.as_str(), &a.id).cmp(&(b.kind.as_str(), &b.id)));
+    assets
+}
+
+/// Format a byte count as e.g. "512 B" or "1.5 KB"
+fn format_bytes(bytes: usize) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KB", bytes as f32 / 1024.0)
+    } else {
+        format!("{:.1} MB", bytes as f32 / (1024.0 * 1024.0))
+    }
+}
+
+/// System to check for new asset inspector configurations and set them up
+#[allow(clippy::type_complexity)]
+fn check_asset_inspector_config(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &AssetInspectorConfig),
+        (Without<ActiveAssetInspector>, Changed<AssetInspectorConfig>),
+    >,
+    existing: Query<Entity, With<ActiveAssetInspector>>,
+) {
+    for (entity, config) in query.iter() {
+        info!("Setting up asset inspector for entity {:?}", entity);
+
+        // Clean up any existing asset inspectors first
+        for existing_entity in existing.iter() {
+            commands
+                .entity(existing_entity)
+                .remove::<ActiveAssetInspector>();
+        }
+
+        commands.entity(entity).insert(ActiveAssetInspector {
+            config: config.clone(),
+            filter: String::new(),
+        });
+    }
+}
+
+/// System to reload and unload assets and handle dismissal
+fn handle_asset_inspector_events(
+    mut commands: Commands,
+    mut events: MessageReader<AssetInspectorEvent>,
+    asset_server: Res<AssetServer>,
+    mut registry: Option<ResMut<KonnektorenAssetRegistry>>,
+) {
+    for event in events.read() {
+        match event {
+            AssetInspectorEvent::Reload { kind, id, .. } => {
+                let Some(registry) = registry.as_ref() else {
+                    continue;
+                };
+                let path = match kind {
+                    ContentKind::Challenge => registry
+                        .get_challenge_handle(id)
+                        .and_then(|handle| handle.path().cloned()),
+                    ContentKind::Level => registry
+                        .get_level_handle(id)
+                        .and_then(|handle| handle.path().cloned()),
+                    ContentKind::Manifest => None,
+                };
+                if let Some(path) = path {
+                    info!("Reloading {} '{}' from {}", kind, id, path);
+                    asset_server.reload(path);
+                }
+            }
+            AssetInspectorEvent::Unload { kind, id, .. } => {
+                let Some(registry) = registry.as_mut() else {
+                    continue;
+                };
+                info!("Unloading {} '{}'", kind, id);
+                match kind {
+                    ContentKind::Challenge => {
+                        registry.unregister_challenge(id);
+                    }
+                    ContentKind::Level => {
+                        registry.unregister_level(id);
+                    }
+                    ContentKind::Manifest => {}
+                }
+            }
+            AssetInspectorEvent::Dismissed { entity } => {
+                info!("Dismissing asset inspector for entity {:?}", entity);
+                commands.entity(*entity).remove::<ActiveAssetInspector>();
+            }
+        }
+    }
+}
+
+/// System to render the asset inspector UI
+#[allow(clippy::too_many_arguments)]
+fn render_asset_inspector_ui(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    asset_server: Res<AssetServer>,
+    registry: Option<Res<KonnektorenAssetRegistry>>,
+    challenges: Option<Res<Assets<ChallengeAsset>>>,
+    levels: Option<Res<Assets<LevelAsset>>>,
+    mut query: Query<(Entity, &mut ActiveAssetInspector)>,
+    mut events: MessageWriter<AssetInspectorEvent>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    // The registry and asset types are added by the KonnektorenAssetsPlugin
+    let assets = match (registry, challenges, levels) {
+        (Some(registry), Some(challenges), Some(levels)) => {
+            inspect_assets(&registry, &asset_server, &challenges, &levels)
+        }
+        _ => vec![],
+    };
+
+    // Only render the first (most recent) screen to avoid widget ID conflicts
+    if let Some((entity, mut active)) = query.iter_mut().next() {
+        if active.config.manual_dismissal && input.just_pressed(KeyCode::Escape) {
+            events.write(AssetInspectorEvent::Dismissed { entity });
+            return;
+        }
+
+        egui::CentralPanel::default()
+            .frame(egui::Frame::NONE.fill(theme.base_100))
+            .show(ctx, |ui| {
+                scoped_ui(ui, (entity, "asset_inspector"), |ui| {
+                    render_asset_inspector_content(
+                        ui,
+                        &mut active,
+                        &assets,
+                        &theme,
+                        &responsive,
+                        entity,
+                        &mut events,
+                    );
+                })
+            });
+    }
+}
+
+/// Render the registry entries as a table with reload and unload buttons
+fn render_asset_inspector_content(
+    ui: &mut egui::Ui,
+    active: &mut ActiveAssetInspector,
+    assets: &[InspectedAsset],
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    events: &mut MessageWriter<AssetInspectorEvent>,
+) {
+    let config = active.config.clone();
+    let total_bytes: usize = assets.iter().filter_map(|a| a.approximate_bytes).sum();
+    let count = |kind| assets.iter().filter(|a| a.kind == kind).count();
+
+    ui.vertical_centered(|ui| {
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+        ResponsiveText::new(&config.title, ResponsiveFontSize::Title, theme.primary)
+            .responsive(responsive)
+            .strong()
+            .ui(ui);
+        ResponsiveText::new(
+            &format!(
+                "{} challenges · {} levels · ~{}",
+                count(ContentKind::Challenge),
+                count(ContentKind::Level),
+                format_bytes(total_bytes)
+            ),
+            ResponsiveFontSize::Small,
+            theme.base_content,
+        )
+        .responsive(responsive)
+        .ui(ui);
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+        ui.add(
+            egui::TextEdit::singleline(&mut active.filter)
+                .id_salt("asset_inspector_filter")
+                .hint_text("Filter")
+                .desired_width(240.0),
+        );
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+    });
+
+    if config.manual_dismissal {
+        let button_width = if responsive.is_mobile() { 140.0 } else { 180.0 };
+        egui::TopBottomPanel::bottom("asset_inspector_actions")
+            .frame(egui::Frame::NONE.fill(theme.base_100))
+            .show_inside(ui, |ui| {
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                let back_button = ThemedButton::new(&config.dismiss_button_text, theme)
+                    .responsive(responsive)
+                    .width(button_width);
+                if ui.add(back_button).clicked() {
+                    events.write(AssetInspectorEvent::Dismissed { entity });
+                }
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+            });
+    }
+
+    let filter = active.filter.to_lowercase();
+    let shown: Vec<&InspectedAsset> = assets
+        .iter()
+        .filter(|asset| {
+            asset.id.to_lowercase().contains(&filter)
+                || asset
+                    .path
+                    .as_ref()
+                    .is_some_and(|path| path.to_lowercase().contains(&filter))
+        })
+        .collect();
+
+    if shown.is_empty() {
+        ui.vertical_centered(|ui| {
+            ResponsiveText::new(
+                "No assets registered",
+                ResponsiveFontSize::Medium,
+                theme.base_content,
+            )
+            .responsive(responsive)
+            .ui(ui);
+        });
+        return;
+    }
+
+    let font_size = responsive.font_size(ResponsiveFontSize::Small);
+    let text = |ui: &mut egui::Ui, text: String, color: egui::Color32| {
+        ui.label(egui::RichText::new(text).size(font_size).color(color));
+    };
+
+    egui::ScrollArea::both().show(ui, |ui| {
+        egui::Grid::new("asset_inspector_table")
+            .striped(true)
+            .spacing([
+                responsive.spacing(ResponsiveSpacing::Medium),
+                responsive.spacing(ResponsiveSpacing::Small),
+            ])
+            .show(ui, |ui| {
+                for header in ["Kind", "ID", "Path", "State", "Handles", "Memory", ""] {
+                    ui.label(
+                        egui::RichText::new(header)
+                            .size(font_size)
+                            .color(theme.primary)
+                            .strong(),
+                    );
+                }
+                ui.end_row();
+
+                for asset in shown {
+                    let color = theme.base_content;
+                    let state_color = match asset.load_state {
+                        "Loaded" => theme.success,
+                        "Failed" => theme.error,
+                        _ => theme.warning,
+                    };
+                    text(ui, asset.kind.to_string(), color);
+                    text(ui, asset.id.clone(), color);
+                    text(
+                        ui,
+                        asset.path.clone().unwrap_or_else(|| "–".to_string()),
+                        color,
+                    );
+                    text(ui, asset.load_state.to_string(), state_color);
+                    text(ui, asset.strong_handles.to_string(), color);
+                    text(
+                        ui,
+                        asset
+                            .approximate_bytes
+                            .map(format_bytes)
+                            .unwrap_or_else(|| "–".to_string()),
+                        color,
+                    );
+                    scoped_ui(ui, ("asset_row", asset.kind.as_str(), &asset.id), |ui| {
+                        ui.horizontal(|ui| {
+                            if ui.small_button("Reload").clicked() {
+                                events.write(AssetInspectorEvent::Reload {
+                                    entity,
+                                    kind: asset.kind,
+                                    id: asset.id.clone(),
+                                });
+                            }
+                            if ui
+                                .small_button(egui::RichText::new("Unload").color(theme.error))
+                                .clicked()
+                            {
+                                events.write(AssetInspectorEvent::Unload {
+                                    entity,
+                                    kind: asset.kind,
+                                    id: asset.id.clone(),
+                                });
+                            }
+                        });
+                    });
+                    ui.end_row();
+                }
+            });
+    });
+}
+
+/// Helper trait for easy asset inspector screen setup
+pub trait AssetInspectorScreenExt {
+    /// Add an asset inspector screen with the given configuration
+    fn spawn_asset_inspector(&mut self, config: AssetInspectorConfig) -> Entity;
+}
+
+impl AssetInspectorScreenExt for Commands<'_, '_> {
+    fn spawn_asset_inspector(&mut self, config: AssetInspectorConfig) -> Entity {
+        self.spawn((Name::new("Asset Inspector Screen"), config))
+            .id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assets::KonnektorenAssetsPlugin, test_utils::TestHarness, theme::KonnektorenThemePlugin,
+        ui::UIPlugin,
+    };
+    use konnektoren_core::game::GamePath;
+
+    #[test]
+    fn test_unload_asset_from_inspector() {
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            KonnektorenAssetsPlugin,
+            AssetInspectorPlugin,
+        ));
+        let level = harness
+            .world_mut()
+            .resource_mut::<Assets<LevelAsset>>()
+            .add(LevelAsset {
+                game_path: GamePath {
+                    id: "a1".to_string(),
+                    name: "Level A1".to_string(),
+                    challenges: vec![],
+                    map: None,
+                },
+                file_path: "a1.level.yml".to_string(),
+            });
+        harness
+            .world_mut()
+            .resource_mut::<KonnektorenAssetRegistry>()
+            .register_level("a1".to_string(), level.clone());
+
+        harness.world_mut().spawn(AssetInspectorConfig::default());
+        harness.run_frames(2);
+
+        {
+            let world = harness.world();
+            let assets = inspect_assets(
+                world.resource::<KonnektorenAssetRegistry>(),
+                world.resource::<AssetServer>(),
+                world.resource::<Assets<ChallengeAsset>>(),
+                world.resource::<Assets<LevelAsset>>(),
+            );
+            assert_eq!(assets.len(), 1);
+            assert_eq!(assets[0].kind, ContentKind::Level);
+            assert_eq!(assets[0].load_state, "Loaded");
+            // The test and the registry hold the handle twice
+            assert_eq!(assets[0].strong_handles, 3);
+            assert!(assets[0].approximate_bytes.is_some());
+        }
+        assert!(harness.has_widget("a1"));
+
+        harness.click("Unload");
+        harness.run_frames(1);
+
+        let registry = harness.world().resource::<KonnektorenAssetRegistry>();
+        assert!(registry.get_level_handle("a1").is_none());
+        assert!(!harness.has_widget("a1"));
+    }
+}
@@ -0,0 +1,313 @@
+use super::{AnyScreenActive, MainMenuConfig, MainMenuEvent, MainMenuScreenExt, MenuEntry};
+use crate::{
+    lifecycle::{AppLifecycleEvent, AppLifecycleState, LifecycleBehavior},
+    theme::KonnektorenTheme,
+};
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts, EguiPrimaryContextPass};
+
+/// ID of the pause menu entry that resumes the game
+pub const RESUME_ENTRY_ID: &str = "resume";
+
+/// Plugin pausing the game when the player switches away from it.
+///
+/// When the window loses focus or the browser tab is hidden during gameplay,
+/// i.e. while no screen is shown, the pause menu of the [`AutoPauseBehavior`]
+/// is spawned, the virtual time is paused and [`AppLifecycleState::is_muted`]
+/// is set. Choosing the [`RESUME_ENTRY_ID`] entry, or coming back with
+/// [`AutoPauseBehavior::resume_on_return`], shows a 3-2-1 countdown before
+/// the game goes on. Other entries of the menu are handled by the game
+/// through [`MainMenuEvent::Selected`].
+///
+/// Needs the [`LifecyclePlugin`](crate::lifecycle::LifecyclePlugin) and the
+/// [`MainMenuPlugin`](super::MainMenuPlugin), both part of the
+/// `KonnektorenPlugins`:
+///
+/// ```ignore
+/// app.add_plugins(AutoPausePlugin::default().with_behavior(AutoPauseBehavior {
+///     resume_on_return: true,
+///     ..default()
+/// }));
+/// ```
+#[derive(Default)]
+pub struct AutoPausePlugin {
+    pub behavior: AutoPauseBehavior,
+}
+
+impl AutoPausePlugin {
+    pub fn with_behavior(mut self, behavior: AutoPauseBehavior) -> Self {
+        self.behavior = behavior;
+        self
+    }
+}
+
+impl Plugin for AutoPausePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.behavior.clone())
+            .init_resource::<AutoPauseState>()
+            .init_resource::<AppLifecycleState>()
+            .init_resource::<LifecycleBehavior>()
+            .init_resource::<AnyScreenActive>()
+            .add_message::<AppLifecycleEvent>()
+            .add_message::<MainMenuEvent>()
+            .add_systems(
+                Update,
+                (
+                    pause_on_focus_loss,
+                    handle_pause_menu_events,
+                    update_auto_pause,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_resume_countdown.run_if(resume_countdown_active),
+            );
+
+        info!("AutoPausePlugin loaded");
+    }
+}
+
+/// When and how the game is paused automatically, can be changed at runtime
+#[derive(Resource, Clone)]
+pub struct AutoPauseBehavior {
+    pub enabled: bool,
+    /// Also pause when the window loses focus, not only when it is hidden
+    pub on_focus_loss: bool,
+    /// Pause menu, an entry with the [`RESUME_ENTRY_ID`] resumes the game
+    pub menu: MainMenuConfig,
+    /// Seconds counted down before the game goes on, 0 resumes right away
+    pub countdown_secs: u32,
+    /// Resume as soon as the player comes back instead of waiting for the
+    /// resume entry
+    pub resume_on_return: bool,
+}
+
+impl Default for AutoPauseBehavior {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            on_focus_loss: true,
+            menu: MainMenuConfig::new("Paused")
+                .with_entry(MenuEntry::new(RESUME_ENTRY_ID, "Resume").with_icon("▶")),
+            countdown_secs: 3,
+            resume_on_return: false,
+        }
+    }
+}
+
+/// Resource with the state of the automatic pause
+#[derive(Resource, Debug, Default)]
+pub struct AutoPauseState {
+    /// Pause menu spawned by the plugin
+    menu: Option<Entity>,
+    countdown: Option<Timer>,
+    /// The virtual time was paused by the plugin
+    paused_time: bool,
+    /// The audio was muted by the plugin
+    muted: bool,
+}
+
+impl AutoPauseState {
+    /// Whether the game is held by the pause menu or the countdown
+    pub fn is_paused(&self) -> bool {
+        self.menu.is_some() || self.countdown.is_some()
+    }
+
+    pub fn menu(&self) -> Option<Entity> {
+        self.menu
+    }
+
+    /// Whole seconds left of the resume countdown
+    pub fn countdown_remaining(&self) -> Option<u32> {
+        self.countdown
+            .as_ref()
+            .map(|countdown| (countdown.remaining_secs().ceil() as u32).max(1))
+    }
+
+    /// Close the pause menu and count down to resume
+    fn resume(&mut self, commands: &mut Commands, countdown_secs: u32) {
+        if let Some(menu) = self.menu.take() {
+            commands.entity(menu).despawn();
+        }
+        self.countdown = (countdown_secs > 0)
+            .then(|| Timer::from_seconds(countdown_secs as f32, TimerMode::Once));
+    }
+}
+
+/// Run condition that is true while the resume countdown is shown
+pub fn resume_countdown_active(state: Option<Res<AutoPauseState>>) -> bool {
+    state.is_some_and(|state| state.countdown.is_some())
+}
+
+/// System to spawn the pause menu when the player switches away, and to
+/// resume when they come back with [`AutoPauseBehavior::resume_on_return`]
+fn pause_on_focus_loss(
+    mut commands: Commands,
+    mut events: MessageReader<AppLifecycleEvent>,
+    behavior: Res<AutoPauseBehavior>,
+    lifecycle: Res<AppLifecycleState>,
+    screens: Res<AnyScreenActive>,
+    mut state: ResMut<AutoPauseState>,
+) {
+    for event in events.read() {
+        let pause = match event {
+            AppLifecycleEvent::Paused => behavior.on_focus_loss,
+            AppLifecycleEvent::Hidden => true,
+            AppLifecycleEvent::Resumed | AppLifecycleEvent::Visible => false,
+        };
+
+        if pause {
+            // Menus and other screens already hold the game
+            if !behavior.enabled || state.menu.is_some() || screens.is_active() {
+                continue;
+            }
+            info!("Focus lost, pausing the game");
+            let menu = commands.spawn_main_menu(behavior.menu.clone());
+            commands.entity(menu).insert(Name::new("Pause Menu Screen"));
+            state.menu = Some(menu);
+            state.countdown = None;
+        } else if behavior.resume_on_return && lifecycle.is_active() && state.menu.is_some() {
+            state.resume(&mut commands, behavior.countdown_secs);
+        }
+    }
+}
+
+/// System to resume when the resume entry of the pause menu is chosen
+fn handle_pause_menu_events(
+    mut commands: Commands,
+    mut events: MessageReader<MainMenuEvent>,
+    behavior: Res<AutoPauseBehavior>,
+    mut state: ResMut<AutoPauseState>,
+) {
+    for event in events.read() {
+        let MainMenuEvent::Selected { entity, id } = event;
+        if state.menu == Some(*entity) && id == RESUME_ENTRY_ID {
+            state.resume(&mut commands, behavior.countdown_secs);
+        }
+    }
+}
+
+/// System to hold the virtual time and the audio while paused and to count
+/// down to resume
+fn update_auto_pause(
+    real_time: Res<Time<Real>>,
+    mut time: ResMut<Time<Virtual>>,
+    mut state: ResMut<AutoPauseState>,
+    mut lifecycle: ResMut<AppLifecycleState>,
+    lifecycle_behavior: Res<LifecycleBehavior>,
+    entities: Query<()>,
+) {
+    // The game closed the pause menu itself, e.g. to quit to the main menu
+    if state.menu.is_some_and(|menu| !entities.contains(menu)) {
+        state.menu = None;
+    }
+    if let Some(countdown) = state.countdown.as_mut() {
+        if countdown.tick(real_time.delta()).is_finished() {
+            state.countdown = None;
+        }
+    }
+
+    if state.is_paused() {
+        // The lifecycle resumes the time when the focus comes back
+        if !time.is_paused() {
+            time.pause();
+            state.paused_time = true;
+        }
+        if !lifecycle.muted {
+            lifecycle.muted = true;
+            state.muted = true;
+        }
+        return;
+    }
+
+    if state.paused_time {
+        time.unpause();
+        state.paused_time = false;
+    }
+    if state.muted {
+        lifecycle.muted = lifecycle.hidden && lifecycle_behavior.mute_when_hidden;
+        state.muted = false;
+    }
+}
+
+/// System to render the resume countdown over the game
+fn render_resume_countdown(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    state: Res<AutoPauseState>,
+) {
+    let (Ok(ctx), Some(remaining)) = (contexts.ctx_mut(), state.countdown_remaining()) else {
+        return;
+    };
+
+    egui::Area::new(egui::Id::new("konnektoren_resume_countdown"))
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .order(egui::Order::Foreground)
+        .interactable(false)
+        .show(ctx, |ui| {
+            ui.label(
+                egui::RichText::new(remaining.to_string())
+                    .size(96.0)
+                    .strong()
+                    .color(theme.primary),
+            );
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        lifecycle::LifecyclePlugin, screens::MainMenuPlugin, test_utils::TestHarness,
+        theme::KonnektorenThemePlugin, ui::UIPlugin,
+    };
+    use bevy::{time::TimeUpdateStrategy, window::WindowFocused};
+    use std::time::Duration;
+
+    #[test]
+    fn test_pause_on_focus_loss() {
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            LifecyclePlugin::default(),
+            MainMenuPlugin,
+            AutoPausePlugin::default(),
+        ));
+        harness
+            .app_mut()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )));
+        let window = harness.world_mut().spawn_empty().id();
+        harness.run_frames(2);
+
+        harness.write_message(WindowFocused {
+            window,
+            focused: false,
+        });
+        harness.run_frames(2);
+        assert!(harness.has_widget("▶ Resume"));
+        assert!(harness.world().resource::<Time<Virtual>>().is_paused());
+        assert!(harness.world().resource::<AppLifecycleState>().is_muted());
+
+        // Coming back keeps the menu until the player resumes
+        harness.write_message(WindowFocused {
+            window,
+            focused: true,
+        });
+        harness.run_frames(2);
+        assert!(harness.world().resource::<Time<Virtual>>().is_paused());
+
+        harness.click("▶ Resume");
+        harness.run_frames(2);
+        assert!(!harness.has_widget("▶ Resume"));
+        assert!(harness.has_widget("3"));
+        assert!(harness.world().resource::<Time<Virtual>>().is_paused());
+
+        harness.run_frames(30);
+        assert!(!harness.world().resource::<AutoPauseState>().is_paused());
+        assert!(!harness.world().resource::<Time<Virtual>>().is_paused());
+        assert!(!harness.world().resource::<AppLifecycleState>().is_muted());
+    }
+}
@@ -0,0 +1,657 @@
+use super::{forward_screen_events, track_active_screen, ScreenAction, ScreenKind, ScreenMessage};
+use crate::{
+    assets::{AvatarPartLayer, AvatarPartSet},
+    profile::{AvatarAppearance, PlayerProfile, ProfilePlugin},
+    theme::{KonnektorenTheme, ThemeColor},
+    ui::{
+        responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
+        texture_cache::{EguiTextureCache, EguiTextureCachePlugin},
+        widgets::{AvatarLayerTexture, AvatarWidget, ResponsiveText, SpinnerWidget, ThemedButton},
+    },
+};
+use bevy::prelude::*;
+use bevy_egui::{
+    egui::{self, TextureId, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+use std::collections::{HashMap, HashSet};
+
+/// Plugin for the avatar builder screen and avatar textures
+pub struct AvatarPlugin;
+
+impl Plugin for AvatarPlugin {
+    fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveAvatarBuilder>(app);
+        forward_screen_events::<AvatarEvent>(app);
+
+        // The avatar is stored in the player profile
+        if !app.is_plugin_added::<ProfilePlugin>() {
+            app.add_plugins(ProfilePlugin);
+        }
+        if !app.is_plugin_added::<EguiTextureCachePlugin>() {
+            app.add_plugins(EguiTextureCachePlugin);
+        }
+
+        app.init_resource::<AvatarTextures>()
+            .add_message::<AvatarEvent>()
+            .add_systems(
+                Update,
+                (
+                    check_avatar_builder_config,
+                    request_avatar_assets,
+                    load_avatar_textures,
+                    init_avatar_draft,
+                    handle_avatar_events,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_avatar_builder_ui.run_if(any_with_component::<ActiveAvatarBuilder>),
+            );
+    }
+}
+
+/// Shared avatar part sets and their egui textures.
+///
+/// Keeps the textures of the current [`PlayerProfile`] avatar loaded, so other
+/// screens can render it with [`AvatarTextures::layers_for`] and [`AvatarWidget`].
+#[derive(Resource, Default)]
+pub struct AvatarTextures {
+    part_sets: HashMap<String, Handle<AvatarPartSet>>,
+    // Images referenced in the shared texture cache
+    images: HashSet<String>,
+    textures: HashMap<String, TextureId>,
+}
+
+impl AvatarTextures {
+    /// Get the part set handle for a path, if it was requested
+    pub fn part_set(&self, path: &str) -> Option<&Handle<AvatarPartSet>> {
+        self.part_sets.get(path)
+    }
+
+    /// Get the egui texture of an image path, once loaded
+    pub fn texture(&self, image: &str) -> Option<TextureId> {
+        self.textures.get(image).copied()
+    }
+
+    fn request_part_set(&mut self, path: &str, asset_server: &AssetServer) {
+        if !self.part_sets.contains_key(path) {
+            info!("Loading avatar part set: {}", path);
+            self.part_sets
+                .insert(path.to_string(), asset_server.load(path.to_string()));
+        }
+    }
+
+    fn request_image(
+        &mut self,
+        path: &str,
+        texture_cache: &mut EguiTextureCache,
+        asset_server: &AssetServer,
+    ) {
+        if self.images.insert(path.to_string()) {
+            texture_cache.acquire(path, asset_server);
+        }
+    }
+
+    /// Resolve the textures of an appearance, bottom layer first
+    pub fn layers_for(
+        &self,
+        appearance: &AvatarAppearance,
+        part_sets: &Assets<AvatarPartSet>,
+        theme: &KonnektorenTheme,
+    ) -> Vec<AvatarLayerTexture> {
+        let Some(part_set) = appearance
+            .part_set
+            .as_deref()
+            .and_then(|path| self.part_sets.get(path))
+            .and_then(|handle| part_sets.get(handle))
+        else {
+            return vec![];
+        };
+
+        part_set
+            .layers
+            .iter()
+            .filter_map(|layer| {
+                let choice = appearance.get(&layer.id)?;
+                let part = layer.get_part(&choice.part)?;
+                let texture = self.texture(&part.image)?;
+                let tint = choice
+                    .tint
+                    .map(|color| theme.color(color))
+                    .unwrap_or(egui::Color32::WHITE);
+                Some(AvatarLayerTexture { texture, tint })
+            })
+            .collect()
+    }
+}
+
+/// Configuration for the avatar builder screen
+#[derive(Component, Clone)]
+pub struct AvatarBuilderConfig {
+    pub title: String,
+    /// Asset path of the part set (`*.avatar.yml`)
+    pub part_set_path: String,
+    /// Theme colors offered for tinting
+    pub palette: Vec<ThemeColor>,
+    pub save_button_text: String,
+    pub dismiss_button_text: String,
+    /// Allow manual dismissal (back button/escape)
+    pub manual_dismissal: bool,
+    /// Dismiss the screen after saving
+    pub dismiss_on_save: bool,
+}
+
+impl AvatarBuilderConfig {
+    pub fn new(part_set_path: impl Into<String>) -> Self {
+        Self {
+            title: "Your Avatar".to_string(),
+            part_set_path: part_set_path.into(),
+            palette: ThemeColor::ALL.to_vec(),
+            save_button_text: "Save".to_string(),
+            dismiss_button_text: "← Back".to_string(),
+            manual_dismissal: true,
+            dismiss_on_save: true,
+        }
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn with_palette(mut self, palette: Vec<ThemeColor>) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    pub fn with_save_button_text(mut self, text: impl Into<String>) -> Self {
+        self.save_button_text = text.into();
+        self
+    }
+
+    pub fn with_dismiss_button_text(mut self, text: impl Into<String>) -> Self {
+        self.dismiss_button_text = text.into();
+        self
+    }
+
+    pub fn with_manual_dismissal(mut self, manual_dismissal: bool) -> Self {
+        self.manual_dismissal = manual_dismissal;
+        self
+    }
+
+    pub fn with_dismiss_on_save(mut self, dismiss_on_save: bool) -> Self {
+        self.dismiss_on_save = dismiss_on_save;
+        self
+    }
+}
+
+/// Component marking an active avatar builder screen
+#[derive(Component)]
+pub struct ActiveAvatarBuilder {
+    config: AvatarBuilderConfig,
+    draft: Option<AvatarAppearance>,
+    selected_layer: usize,
+}
+
+/// Events for avatar builder interactions
+#[derive(Message, Debug, Clone)]
+pub enum AvatarEvent {
+    /// The avatar was saved to the player profile
+    Saved {
+        entity: Entity,
+        appearance: AvatarAppearance,
+    },
+    /// Avatar builder dismissed
+    Dismissed { entity: Entity },
+}
+
+impl ScreenMessage for AvatarEvent {
+    const KIND: ScreenKind = ScreenKind::Avatar;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::Saved { entity, .. } => Some((ScreenAction::Submitted, *entity)),
+            Self::Dismissed { entity } => Some((ScreenAction::Dismissed, *entity)),
+        }
+    }
+}
+
+/// System to check for new avatar builder configurations and set them up
+#[allow(clippy::type_complexity)]
+fn check_avatar_builder_config(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &AvatarBuilderConfig),
+        (Without<ActiveAvatarBuilder>, Changed<AvatarBuilderConfig>),
+    >,
+    existing: Query<Entity, With<ActiveAvatarBuilder>>,
+) {
+    for (entity, config) in query.iter() {
+        info!("Setting up avatar builder for entity {:?}", entity);
+
+        // Clean up any existing avatar builders first
+        for existing_entity in existing.iter() {
+            commands
+                .entity(existing_entity)
+                .remove::<ActiveAvatarBuilder>();
+        }
+
+        commands.entity(entity).insert(ActiveAvatarBuilder {
+            config: config.clone(),
+            draft: None,
+            selected_layer: 0,
+        });
+    }
+}
+
+/// System to request the part sets and images needed by builders and the profile
+fn request_avatar_assets(
+    asset_server: Res<AssetServer>,
+    part_sets: Option<Res<Assets<AvatarPartSet>>>,
+    profile: Res<PlayerProfile>,
+    builders: Query<&ActiveAvatarBuilder>,
+    mut avatar_textures: ResMut<AvatarTextures>,
+    mut texture_cache: ResMut<EguiTextureCache>,
+) {
+    // Part sets are registered by the KonnektorenAssetsPlugin
+    let Some(part_sets) = part_sets else {
+        return;
+    };
+
+    // The builder shows every part of its set
+    for builder in builders.iter() {
+        avatar_textures.request_part_set(&builder.config.part_set_path, &asset_server);
+
+        let part_set = avatar_textures
+            .part_set(&builder.config.part_set_path)
+            .and_then(|handle| part_sets.get(handle));
+        if let Some(part_set) = part_set {
+            for image in part_set.image_paths() {
+                avatar_textures.request_image(image, &mut texture_cache, &asset_server);
+            }
+        }
+    }
+
+    // Other screens only need the selected parts of the profile avatar
+    let Some(path) = profile.avatar.part_set.as_deref() else {
+        return;
+    };
+    avatar_textures.request_part_set(path, &asset_server);
+
+    let part_set = avatar_textures
+        .part_set(path)
+        .and_then(|handle| part_sets.get(handle));
+    if let Some(part_set) = part_set {
+        for layer in &part_set.layers {
+            let part = profile
+                .avatar
+                .get(&layer.id)
+                .and_then(|choice| layer.get_part(&choice.part));
+            if let Some(part) = part {
+                avatar_textures.request_image(&part.image, &mut texture_cache, &asset_server);
+            }
+        }
+    }
+}
+
+/// System to pick up avatar textures once the shared cache has loaded them
+fn load_avatar_textures(
+    texture_cache: Res<EguiTextureCache>,
+    mut avatar_textures: ResMut<AvatarTextures>,
+) {
+    let AvatarTextures {
+        images, textures, ..
+    } = &mut *avatar_textures;
+
+    for path in images.iter() {
+        if textures.contains_key(path) {
+            continue;
+        }
+
+        if let Some(texture_id) = texture_cache.texture(path) {
+            textures.insert(path.clone(), texture_id);
+        }
+    }
+}
+
+/// System to start editing from the profile avatar once the part set is loaded
+fn init_avatar_draft(
+    part_sets: Option<Res<Assets<AvatarPartSet>>>,
+    profile: Res<PlayerProfile>,
+    avatar_textures: Res<AvatarTextures>,
+    mut builders: Query<&mut ActiveAvatarBuilder>,
+) {
+    let Some(part_sets) = part_sets else {
+        return;
+    };
+
+    for mut builder in builders.iter_mut() {
+        if builder.draft.is_some() {
+            continue;
+        }
+
+        let path = builder.config.part_set_path.clone();
+        let Some(part_set) = avatar_textures
+            .part_set(&path)
+            .and_then(|handle| part_sets.get(handle))
+        else {
+            continue;
+        };
+
+        let draft = if profile.avatar.part_set.as_deref() == Some(path.as_str()) {
+            profile.avatar.clone()
+        } else {
+            // Start with the first part of every required layer
+            let mut appearance = AvatarAppearance::new(path);
+            for layer in part_set.layers.iter().filter(|layer| !layer.optional) {
+                if let Some(part) = layer.parts.first() {
+                    appearance.set_part(&layer.id, part.id.clone());
+                }
+            }
+            appearance
+        };
+
+        builder.draft = Some(draft);
+    }
+}
+
+/// System to handle avatar builder events
+fn handle_avatar_events(
+    mut commands: Commands,
+    mut events: MessageReader<AvatarEvent>,
+    mut profile: ResMut<PlayerProfile>,
+    builders: Query<&ActiveAvatarBuilder>,
+) {
+    for event in events.read() {
+        match event {
+            AvatarEvent::Saved { entity, appearance } => {
+                info!("Saving avatar to player profile");
+                profile.avatar = appearance.clone();
+
+                if builders
+                    .get(*entity)
+                    .is_ok_and(|builder| builder.config.dismiss_on_save)
+                {
+                    commands.entity(*entity).remove::<ActiveAvatarBuilder>();
+                }
+            }
+            AvatarEvent::Dismissed { entity } => {
+                info!("Dismissing avatar builder for entity {:?}", entity);
+                commands.entity(*entity).remove::<ActiveAvatarBuilder>();
+            }
+        }
+    }
+}
+
+/// System to render the avatar builder UI
+#[allow(clippy::too_many_arguments)]
+fn render_avatar_builder_ui(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    part_sets: Option<Res<Assets<AvatarPartSet>>>,
+    avatar_textures: Res<AvatarTextures>,
+    mut query: Query<(Entity, &mut ActiveAvatarBuilder)>,
+    mut events: MessageWriter<AvatarEvent>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    let Some(part_sets) = part_sets else {
+        return;
+    };
+
+    if query.is_empty() {
+        return;
+    }
+
+    if let Ok(ctx) = contexts.ctx_mut() {
+        // Only render the first (most recent) builder to avoid widget ID conflicts
+        if let Some((entity, mut builder)) = query.iter_mut().next() {
+            if builder.config.manual_dismissal && input.just_pressed(KeyCode::Escape) {
+                events.write(AvatarEvent::Dismissed { entity });
+                return;
+            }
+
+            let part_set = avatar_textures
+                .part_set(&builder.config.part_set_path)
+                .and_then(|handle| part_sets.get(handle));
+
+            egui::CentralPanel::default()
+                .frame(egui::Frame::NONE.fill(theme.base_100))
+                .show(ctx, |ui| {
+                    scoped_ui(ui, (entity, "avatar"), |ui| {
+                        render_avatar_builder_content(
+                            ui,
+                            &mut builder,
+                            part_set,
+                            &part_sets,
+                            &avatar_textures,
+                            &theme,
+                            &responsive,
+                            entity,
+                            &mut events,
+                        );
+                    })
+                });
+        }
+    }
+}
+
+/// Render avatar builder content
+#[allow(clippy::too_many_arguments)]
+fn render_avatar_builder_content(
+    ui: &mut egui::Ui,
+    builder: &mut ActiveAvatarBuilder,
+    part_set: Option<&AvatarPartSet>,
+    part_sets: &Assets<AvatarPartSet>,
+    avatar_textures: &AvatarTextures,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    events: &mut MessageWriter<AvatarEvent>,
+) {
+    let config = builder.config.clone();
+
+    ui.vertical_centered(|ui| {
+        let max_width = if responsive.is_mobile() {
+            ui.available_width() * 0.95
+        } else {
+            800.0_f32.min(ui.available_width() * 0.9)
+        };
+
+        ui.set_max_width(max_width);
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+        ResponsiveText::new(&config.title, ResponsiveFontSize::Title, theme.primary)
+            .responsive(responsive)
+            .strong()
+            .ui(ui);
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+        let (Some(part_set), Some(draft)) = (part_set, builder.draft.as_mut()) else {
+            // Part set still loading
+            SpinnerWidget::new(theme, 48.0)
+                .responsive(responsive)
+                .ui(ui);
+            return;
+        };
+
+        // Preview
+        let layers = avatar_textures.layers_for(draft, part_sets, theme);
+        let preview_size = if responsive.is_mobile() { 120.0 } else { 160.0 };
+        AvatarWidget::new(theme, &layers)
+            .size(preview_size)
+            .responsive(responsive)
+            .ui(ui);
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+        // Layer tabs
+        ui.horizontal_wrapped(|ui| {
+            for (index, layer) in part_set.layers.iter().enumerate() {
+                if ui
+                    .selectable_label(builder.selected_layer == index, &layer.name)
+                    .clicked()
+                {
+                    builder.selected_layer = index;
+                }
+            }
+        });
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+
+        let scroll_height = ui.available_height() - 80.0;
+        egui::ScrollArea::vertical()
+            .id_salt("avatar_parts_scroll")
+            .max_height(scroll_height)
+            .auto_shrink([false; 2])
+            .show(ui, |ui| {
+                if let Some(layer) = part_set.layers.get(builder.selected_layer) {
+                    render_layer_parts(
+                        ui,
+                        layer,
+                        draft,
+                        avatar_textures,
+                        &config,
+                        theme,
+                        responsive,
+                    );
+                }
+            });
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+        let button_width = if responsive.is_mobile() { 200.0 } else { 250.0 };
+        let save_button = ThemedButton::new(&config.save_button_text, theme)
+            .responsive(responsive)
+            .width(button_width);
+        if ui.add(save_button).clicked() {
+            events.write(AvatarEvent::Saved {
+                entity,
+                appearance: draft.clone(),
+            });
+        }
+
+        if config.manual_dismissal {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+            let back_button = ThemedButton::new(&config.dismiss_button_text, theme)
+                .responsive(responsive)
+                .width(button_width);
+
+            if ui.add(back_button).clicked() {
+                events.write(AvatarEvent::Dismissed { entity });
+            }
+        }
+    });
+}
+
+/// Render the part choices and tint swatches of a layer
+fn render_layer_parts(
+    ui: &mut egui::Ui,
+    layer: &AvatarPartLayer,
+    draft: &mut AvatarAppearance,
+    avatar_textures: &AvatarTextures,
+    config: &AvatarBuilderConfig,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+) {
+    let part_size = if responsive.is_mobile() { 56.0 } else { 72.0 };
+    let selected_part = draft.get(&layer.id).map(|choice| choice.part.clone());
+
+    ui.horizontal_wrapped(|ui| {
+        if layer.optional {
+            let none_button = egui::Button::new("∅")
+                .min_size(egui::vec2(part_size, part_size))
+                .selected(selected_part.is_none());
+            if ui.add(none_button).on_hover_text("None").clicked() {
+                draft.layers.retain(|choice| choice.layer != layer.id);
+            }
+        }
+
+        for part in &layer.parts {
+            let selected = selected_part.as_deref() == Some(part.id.as_str());
+            let response = match avatar_textures.texture(&part.image) {
+                Some(texture) => {
+                    let image =
+                        egui::Image::from_texture((texture, egui::vec2(part_size, part_size)))
+                            .fit_to_exact_size(egui::vec2(part_size, part_size));
+                    ui.add(egui::Button::image(image).selected(selected))
+                }
+                None => ui.add(
+                    egui::Button::new(&part.name)
+                        .min_size(egui::vec2(part_size, part_size))
+                        .selected(selected),
+                ),
+            };
+
+            if response.on_hover_text(&part.name).clicked() {
+                draft.set_part(&layer.id, part.id.clone());
+            }
+        }
+    });
+
+    if !layer.tintable || selected_part.is_none() {
+        return;
+    }
+
+    ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+    ResponsiveText::new("Color", ResponsiveFontSize::Medium, theme.base_content)
+        .responsive(responsive)
+        .ui(ui);
+
+    let current_tint = draft.get(&layer.id).and_then(|choice| choice.tint);
+    let swatch_size = if responsive.is_mobile() { 40.0 } else { 32.0 };
+
+    ui.horizontal_wrapped(|ui| {
+        // "No tint" swatch keeps the original image colors
+        let swatches = std::iter::once(None).chain(config.palette.iter().copied().map(Some));
+        for tint in swatches {
+            let (rect, response) =
+                ui.allocate_exact_size(egui::vec2(swatch_size, swatch_size), egui::Sense::click());
+            let radius = swatch_size / 2.0 - 2.0;
+            let color = tint
+                .map(|tint| theme.color(tint))
+                .unwrap_or(egui::Color32::WHITE);
+
+            ui.painter().circle_filled(rect.center(), radius, color);
+            if tint.is_none() {
+                ui.painter().line_segment(
+                    [
+                        rect.left_bottom() + egui::vec2(6.0, -6.0),
+                        rect.right_top() + egui::vec2(-6.0, 6.0),
+                    ],
+                    egui::Stroke::new(2.0, theme.error),
+                );
+            }
+            if current_tint == tint {
+                ui.painter().circle_stroke(
+                    rect.center(),
+                    radius + 1.0,
+                    egui::Stroke::new(3.0, theme.base_content),
+                );
+            }
+
+            let label = tint
+                .map(|tint| format!("{:?}", tint))
+                .unwrap_or_else(|| "Original".to_string());
+            if response.on_hover_text(label).clicked() {
+                draft.set_tint(&layer.id, tint);
+            }
+        }
+    });
+}
+
+/// Helper trait for easy avatar builder setup
+pub trait AvatarScreenExt {
+    /// Add an avatar builder with the given configuration
+    fn spawn_avatar_builder(&mut self, config: AvatarBuilderConfig) -> Entity;
+}
+
+impl AvatarScreenExt for Commands<'_, '_> {
+    fn spawn_avatar_builder(&mut self, config: AvatarBuilderConfig) -> Entity {
+        self.spawn((Name::new("Avatar Builder Screen"), config))
+            .id()
+    }
+}
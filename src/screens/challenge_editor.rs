@@ -0,0 +1,959 @@
+use super::{forward_screen_events, track_active_screen, ScreenAction, ScreenKind, ScreenMessage};
+use crate::{
+    assets::{challenge_issues, validate_content, ChallengeAsset, ContentIssue, ContentKind},
+    theme::KonnektorenTheme,
+    ui::{
+        notifications::Notification,
+        responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
+        widgets::{ResponsiveText, SpinnerWidget, ThemedButton},
+    },
+};
+use bevy::{
+    asset::{io::AssetSourceId, LoadState},
+    prelude::*,
+    tasks::{block_on, futures_lite::future, IoTaskPool, Task},
+};
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+use konnektoren_core::challenges::{
+    challenge_type::ChallengeType,
+    multiple_choice::{MultipleChoice, MultipleChoiceOption, Question},
+};
+use std::{collections::HashSet, path::Path};
+
+/// Plugin for the challenge editor screen
+pub struct ChallengeEditorPlugin;
+
+impl Plugin for ChallengeEditorPlugin {
+    fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveChallengeEditor>(app);
+        forward_screen_events::<ChallengeEditorEvent>(app);
+
+        app.add_message::<ChallengeEditorEvent>()
+            .add_message::<Notification>()
+            .add_systems(
+                Update,
+                (
+                    check_challenge_editor_config,
+                    load_challenge_drafts,
+                    start_challenge_saves,
+                    poll_challenge_saves,
+                    handle_challenge_editor_dismissal,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_challenge_editor_ui.run_if(any_with_component::<ActiveChallengeEditor>),
+            );
+    }
+}
+
+/// Configuration for the challenge editor screen.
+///
+/// The editor loads the challenge at `path` through the asset server and
+/// writes it back to the same path of the default asset source, as YAML or,
+/// for `*.json` paths, as JSON:
+///
+/// ```ignore
+/// commands.spawn(ChallengeEditorConfig::open("challenges/articles.yml"));
+/// commands.spawn(ChallengeEditorConfig::new_challenge("challenges/colors.yml"));
+/// ```
+#[derive(Component, Clone)]
+pub struct ChallengeEditorConfig {
+    pub title: String,
+    /// Asset path the challenge is loaded from and saved to
+    pub path: String,
+    /// Challenge to edit instead of loading `path`
+    pub challenge: Option<ChallengeType>,
+    pub save_button_text: String,
+    pub dismiss_button_text: String,
+    /// Allow manual dismissal (back button/escape)
+    pub manual_dismissal: bool,
+}
+
+impl ChallengeEditorConfig {
+    /// Edit the challenge at an asset path
+    pub fn open(path: impl Into<String>) -> Self {
+        Self {
+            title: "Challenge Editor".to_string(),
+            path: path.into(),
+            challenge: None,
+            save_button_text: "Save".to_string(),
+            dismiss_button_text: "← Back".to_string(),
+            manual_dismissal: true,
+        }
+    }
+
+    /// Author a new multiple choice challenge, saved to an asset path
+    pub fn new_challenge(path: impl Into<String>) -> Self {
+        let path = path.into();
+        let challenge = blank_challenge(&path);
+        Self::open(path).with_challenge(challenge)
+    }
+
+    /// Edit a challenge that is already in memory
+    pub fn with_challenge(mut self, challenge: ChallengeType) -> Self {
+        self.challenge = Some(challenge);
+        self
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn with_save_button_text(mut self, text: impl Into<String>) -> Self {
+        self.save_button_text = text.into();
+        self
+    }
+
+    pub fn with_dismiss_button_text(mut self, text: impl Into<String>) -> Self {
+        self.dismiss_button_text = text.into();
+        self
+    }
+
+    pub fn with_manual_dismissal(mut self, manual_dismissal: bool) -> Self {
+        self.manual_dismissal = manual_dismissal;
+        self
+    }
+}
+
+/// Component marking an active challenge editor screen
+#[derive(Component)]
+pub struct ActiveChallengeEditor {
+    config: ChallengeEditorConfig,
+    handle: Option<Handle<ChallengeAsset>>,
+    draft: Option<ChallengeType>,
+    /// Why the challenge at the path could not be loaded
+    load_error: Option<String>,
+    selected_question: usize,
+    issues: Vec<ContentIssue>,
+    /// Edited since the last save
+    dirty: bool,
+    saving: Option<Task<Result<(), String>>>,
+}
+
+impl ActiveChallengeEditor {
+    /// The challenge as currently edited
+    pub fn draft(&self) -> Option<&ChallengeType> {
+        self.draft.as_ref()
+    }
+
+    /// Problems that keep the draft from being saved
+    pub fn issues(&self) -> &[ContentIssue] {
+        &self.issues
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn set_draft(&mut self, draft: ChallengeType) {
+        self.draft = Some(draft);
+        self.validate();
+    }
+
+    fn validate(&mut self) {
+        self.issues = self
+            .draft
+            .as_ref()
+            .map(challenge_draft_issues)
+            .unwrap_or_default();
+    }
+}
+
+/// Events for challenge editor interactions
+#[derive(Message, Debug, Clone)]
+pub enum ChallengeEditorEvent {
+    /// Saving the draft was requested
+    Save { entity: Entity, path: String },
+    /// The draft was written to its asset path
+    Saved { entity: Entity, path: String },
+    /// Writing the draft failed
+    SaveFailed {
+        entity: Entity,
+        path: String,
+        error: String,
+    },
+    /// Challenge editor screen dismissed
+    Dismissed { entity: Entity },
+}
+
+impl ScreenMessage for ChallengeEditorEvent {
+    const KIND: ScreenKind = ScreenKind::ChallengeEditor;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::Save { entity, .. } => Some((ScreenAction::Submitted, *entity)),
+            Self::Saved { entity, .. } | Self::SaveFailed { entity, .. } => {
+                Some((ScreenAction::Changed, *entity))
+            }
+            Self::Dismissed { entity } => Some((ScreenAction::Dismissed, *entity)),
+        }
+    }
+}
+
+/// Empty multiple choice challenge to start authoring from, named after its file
+fn blank_challenge(path: &str) -> ChallengeType {
+    let id = Path::new(path)
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("challenge")
+        .to_string();
+    ChallengeType::MultipleChoice(MultipleChoice {
+        name: id.clone(),
+        id,
+        lang: "de".to_string(),
+        options: vec![],
+        questions: vec![],
+    })
+}
+
+/// Serialize a challenge in the format of its path, JSON for `*.json` and
+/// YAML otherwise, like the [`ChallengeAssetLoader`](crate::assets::ChallengeAssetLoader) reads it
+pub fn serialize_challenge(challenge: &ChallengeType, path: &str) -> Result<Vec<u8>, String> {
+    let is_json = Path::new(path)
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+    if is_json {
+        serde_json::to_vec_pretty(challenge).map_err(|e| e.to_string())
+    } else {
+        serde_yaml::to_string(challenge)
+            .map(String::into_bytes)
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Problems of an edited challenge.
+///
+/// Besides the rules of the asset loader, a multiple choice challenge needs
+/// options with unique ids and questions whose answer is one of the options.
+/// The draft is serialized and parsed again, so a challenge without issues loads.
+pub fn challenge_draft_issues(challenge: &ChallengeType) -> Vec<ContentIssue> {
+    let mut issues = challenge_issues(challenge);
+
+    if let ChallengeType::MultipleChoice(multiple_choice) = challenge {
+        if multiple_choice.options.is_empty() {
+            issues.push(ContentIssue::new("add at least one option"));
+        }
+        let mut ids = HashSet::new();
+        for option in &multiple_choice.options {
+            if !ids.insert(option.id) {
+                issues.push(ContentIssue::new(format!(
+                    "option id {} is used twice",
+                    option.id
+                )));
+            }
+            if option.name.trim().is_empty() {
+                issues.push(ContentIssue::new(format!(
+                    "option {} has no text",
+                    option.id
+                )));
+            }
+        }
+        if multiple_choice.questions.is_empty() {
+            issues.push(ContentIssue::new("add at least one question"));
+        }
+        for (index, question) in multiple_choice.questions.iter().enumerate() {
+            if question.question.trim().is_empty() {
+                issues.push(ContentIssue::new(format!(
+                    "question {} has no prompt",
+                    index + 1
+                )));
+            }
+            if !ids.contains(&question.option) {
+                issues.push(ContentIssue::new(format!(
+                    "question {} has no correct answer",
+                    index + 1
+                )));
+            }
+        }
+    }
+
+    match serde_yaml::to_string(challenge) {
+        Ok(yaml) => {
+            for issue in validate_content(ContentKind::Challenge, yaml.as_bytes()) {
+                if !issues.contains(&issue) {
+                    issues.push(issue);
+                }
+            }
+        }
+        Err(error) => issues.push(ContentIssue::new(error.to_string())),
+    }
+
+    issues
+}
+
+/// System to check for new challenge editor configurations and set them up
+#[allow(clippy::type_complexity)]
+fn check_challenge_editor_config(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &ChallengeEditorConfig),
+        (
+            Without<ActiveChallengeEditor>,
+            Changed<ChallengeEditorConfig>,
+        ),
+    >,
+    existing: Query<Entity, With<ActiveChallengeEditor>>,
+) {
+    for (entity, config) in query.iter() {
+        info!("Setting up challenge editor for entity {:?}", entity);
+
+        // Clean up any existing challenge editors first
+        for existing_entity in existing.iter() {
+            commands
+                .entity(existing_entity)
+                .remove::<ActiveChallengeEditor>();
+        }
+
+        let mut active = ActiveChallengeEditor {
+            config: config.clone(),
+            handle: None,
+            draft: None,
+            load_error: None,
+            selected_question: 0,
+            issues: vec![],
+            dirty: false,
+            saving: None,
+        };
+        if let Some(challenge) = config.challenge.clone() {
+            active.set_draft(challenge);
+        }
+        commands.entity(entity).insert(active);
+    }
+}
+
+/// System to load the challenges to edit into drafts
+fn load_challenge_drafts(
+    asset_server: Res<AssetServer>,
+    challenges: Option<Res<Assets<ChallengeAsset>>>,
+    mut query: Query<&mut ActiveChallengeEditor>,
+) {
+    // Challenges are registered by the KonnektorenAssetsPlugin
+    let Some(challenges) = challenges else {
+        return;
+    };
+
+    for mut active in query.iter_mut() {
+        if active.draft.is_some() {
+            continue;
+        }
+
+        let Some(handle) = active.handle.clone() else {
+            info!("Loading challenge to edit: {}", active.config.path);
+            active.handle = Some(asset_server.load(active.config.path.clone()));
+            continue;
+        };
+
+        if let Some(challenge) = challenges.get(&handle) {
+            let draft = challenge.challenge_type.clone();
+            active.set_draft(draft);
+        } else if let LoadState::Failed(error) = asset_server.load_state(&handle) {
+            warn!("Could not load challenge {}: {}", active.config.path, error);
+            active.load_error = Some(error.to_string());
+            let draft = blank_challenge(&active.config.path);
+            active.set_draft(draft);
+        }
+    }
+}
+
+/// System to save drafts requested by the editor
+fn start_challenge_saves(
+    mut events: MessageReader<ChallengeEditorEvent>,
+    asset_server: Res<AssetServer>,
+    mut query: Query<&mut ActiveChallengeEditor>,
+) {
+    for event in events.read() {
+        let ChallengeEditorEvent::Save { entity, path } = event else {
+            continue;
+        };
+
+        let Ok(mut active) = query.get_mut(*entity) else {
+            continue;
+        };
+
+        if active.saving.is_some() {
+            continue;
+        }
+
+        let Some(bytes) = active
+            .draft
+            .as_ref()
+            .and_then(|draft| serialize_challenge(draft, path).ok())
+        else {
+            continue;
+        };
+
+        info!("Saving challenge to {}", path);
+        active.saving = Some(write_asset_bytes(&asset_server, path.clone(), bytes));
+    }
+}
+
+/// Write a file to the default asset source in the background, where the
+/// asset server loads it from again
+pub(super) fn write_asset_bytes(
+    asset_server: &AssetServer,
+    path: String,
+    bytes: Vec<u8>,
+) -> Task<Result<(), String>> {
+    let asset_server = asset_server.clone();
+    IoTaskPool::get().spawn(async move {
+        let source = asset_server
+            .get_source(AssetSourceId::Default)
+            .map_err(|e| e.to_string())?;
+        let writer = source.writer().map_err(|e| e.to_string())?;
+        writer
+            .write_bytes(Path::new(&path), &bytes)
+            .await
+            .map_err(|e| e.to_string())
+    })
+}
+
+/// System to poll running saves
+fn poll_challenge_saves(
+    mut query: Query<(Entity, &mut ActiveChallengeEditor)>,
+    mut events: MessageWriter<ChallengeEditorEvent>,
+    mut notifications: MessageWriter<Notification>,
+) {
+    for (entity, mut active) in query.iter_mut() {
+        let Some(task) = active.saving.as_mut() else {
+            continue;
+        };
+
+        let Some(result) = block_on(future::poll_once(task)) else {
+            continue;
+        };
+
+        active.saving = None;
+        let path = active.config.path.clone();
+
+        match result {
+            Ok(()) => {
+                notifications.write(Notification::success("Challenge saved", path.clone()));
+                active.dirty = false;
+                active.load_error = None;
+                events.write(ChallengeEditorEvent::Saved { entity, path });
+            }
+            Err(error) => {
+                warn!("Saving challenge to {} failed: {}", path, error);
+                notifications.write(Notification::error("Challenge not saved", error.clone()));
+                events.write(ChallengeEditorEvent::SaveFailed {
+                    entity,
+                    path,
+                    error,
+                });
+            }
+        }
+    }
+}
+
+/// System to close dismissed challenge editors
+fn handle_challenge_editor_dismissal(
+    mut commands: Commands,
+    mut events: MessageReader<ChallengeEditorEvent>,
+) {
+    for event in events.read() {
+        if let ChallengeEditorEvent::Dismissed { entity } = event {
+            info!("Dismissing challenge editor for entity {:?}", entity);
+            commands.entity(*entity).remove::<ActiveChallengeEditor>();
+        }
+    }
+}
+
+/// System to render the challenge editor UI
+fn render_challenge_editor_ui(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    mut query: Query<(Entity, &mut ActiveChallengeEditor)>,
+    mut events: MessageWriter<ChallengeEditorEvent>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    if query.is_empty() {
+        return;
+    }
+
+    if let Ok(ctx) = contexts.ctx_mut() {
+        // Only render the first (most recent) editor to avoid widget ID conflicts
+        if let Some((entity, mut active)) = query.iter_mut().next() {
+            if active.config.manual_dismissal && input.just_pressed(KeyCode::Escape) {
+                events.write(ChallengeEditorEvent::Dismissed { entity });
+                return;
+            }
+
+            egui::CentralPanel::default()
+                .frame(egui::Frame::NONE.fill(theme.base_100))
+                .show(ctx, |ui| {
+                    scoped_ui(ui, (entity, "challenge_editor"), |ui| {
+                        render_challenge_editor_content(
+                            ui,
+                            &mut active,
+                            &theme,
+                            &responsive,
+                            entity,
+                            &mut events,
+                        );
+                    })
+                });
+        }
+    }
+}
+
+/// Render challenge editor content
+fn render_challenge_editor_content(
+    ui: &mut egui::Ui,
+    active: &mut ActiveChallengeEditor,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    events: &mut MessageWriter<ChallengeEditorEvent>,
+) {
+    let config = active.config.clone();
+
+    ui.vertical_centered(|ui| {
+        let max_width = if responsive.is_mobile() {
+            ui.available_width() * 0.95
+        } else {
+            900.0_f32.min(ui.available_width() * 0.9)
+        };
+
+        ui.set_max_width(max_width);
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+        ResponsiveText::new(&config.title, ResponsiveFontSize::Title, theme.primary)
+            .responsive(responsive)
+            .strong()
+            .ui(ui);
+        ResponsiveText::new(
+            &format!("{}{}", config.path, if active.dirty { " •" } else { "" }),
+            ResponsiveFontSize::Small,
+            theme.base_content.gamma_multiply(0.7),
+        )
+        .responsive(responsive)
+        .ui(ui);
+
+        if active.draft.is_none() {
+            // Challenge still loading
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+            SpinnerWidget::new(theme, 48.0)
+                .responsive(responsive)
+                .ui(ui);
+            return;
+        }
+
+        if let Some(error) = &active.load_error {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+            ResponsiveText::new(
+                &format!("Could not load the challenge ({error}), saving replaces the file"),
+                ResponsiveFontSize::Small,
+                theme.warning,
+            )
+            .responsive(responsive)
+            .ui(ui);
+        }
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+        let scroll_height = ui.available_height() - 200.0;
+        let mut changed = false;
+        egui::ScrollArea::vertical()
+            .id_salt("challenge_editor_scroll")
+            .max_height(scroll_height)
+            .auto_shrink([false; 2])
+            .show(ui, |ui| {
+                let ActiveChallengeEditor {
+                    draft,
+                    selected_question,
+                    ..
+                } = &mut *active;
+                match draft {
+                    Some(ChallengeType::MultipleChoice(challenge)) => {
+                        changed = render_multiple_choice_editor(
+                            ui,
+                            challenge,
+                            selected_question,
+                            theme,
+                            responsive,
+                        );
+                    }
+                    _ => {
+                        ResponsiveText::new(
+                            "This challenge type can not be edited here yet",
+                            ResponsiveFontSize::Medium,
+                            theme.base_content,
+                        )
+                        .responsive(responsive)
+                        .ui(ui);
+                    }
+                }
+            });
+
+        if changed {
+            active.dirty = true;
+            active.validate();
+        }
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+        render_issues(ui, &active.issues, theme, responsive);
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+        let editable = matches!(active.draft, Some(ChallengeType::MultipleChoice(_)));
+        let can_save = editable && active.issues.is_empty() && active.saving.is_none();
+        let button_width = if responsive.is_mobile() { 200.0 } else { 250.0 };
+        ui.horizontal(|ui| {
+            let save_button = ThemedButton::new(&config.save_button_text, theme)
+                .responsive(responsive)
+                .width(button_width)
+                .enabled(can_save);
+            if ui.add(save_button).clicked() {
+                events.write(ChallengeEditorEvent::Save {
+                    entity,
+                    path: config.path.clone(),
+                });
+            }
+
+            if active.saving.is_some() {
+                SpinnerWidget::new(theme, 24.0)
+                    .responsive(responsive)
+                    .ui(ui);
+            }
+
+            if config.manual_dismissal {
+                let back_button = ThemedButton::new(&config.dismiss_button_text, theme)
+                    .responsive(responsive)
+                    .width(button_width);
+                if ui.add(back_button).clicked() {
+                    events.write(ChallengeEditorEvent::Dismissed { entity });
+                }
+            }
+        });
+    });
+}
+
+/// Render the fields of a multiple choice challenge, returns whether anything changed
+fn render_multiple_choice_editor(
+    ui: &mut egui::Ui,
+    challenge: &mut MultipleChoice,
+    selected_question: &mut usize,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+) -> bool {
+    let mut changed = false;
+    let font_size = responsive.font_size(ResponsiveFontSize::Medium);
+    let field_width = ui.available_width() * 0.7;
+
+    egui::Grid::new("challenge_fields")
+        .num_columns(2)
+        .spacing([12.0, 8.0])
+        .show(ui, |ui| {
+            for (label, value) in [
+                ("ID", &mut challenge.id),
+                ("Name", &mut challenge.name),
+                ("Language", &mut challenge.lang),
+            ] {
+                ui.label(
+                    egui::RichText::new(label)
+                        .size(font_size)
+                        .color(theme.base_content),
+                );
+                changed |= ui
+                    .add(
+                        egui::TextEdit::singleline(value)
+                            .font(egui::FontId::proportional(font_size))
+                            .desired_width(field_width),
+                    )
+                    .changed();
+                ui.end_row();
+            }
+        });
+
+    ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+    section_heading(ui, "Options", theme, responsive);
+
+    let mut remove_option = None;
+    for (index, option) in challenge.options.iter_mut().enumerate() {
+        scoped_ui(ui, ("challenge_option", index), |ui| {
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(format!("#{}", option.id))
+                        .size(font_size)
+                        .color(theme.base_content.gamma_multiply(0.7)),
+                );
+                changed |= ui
+                    .add(
+                        egui::TextEdit::singleline(&mut option.name)
+                            .hint_text("Answer text")
+                            .font(egui::FontId::proportional(font_size))
+                            .desired_width(field_width),
+                    )
+                    .changed();
+                if ui
+                    .button(egui::RichText::new("✕").color(theme.error))
+                    .on_hover_text("Remove option")
+                    .clicked()
+                {
+                    remove_option = Some(index);
+                }
+            });
+        });
+    }
+    if let Some(index) = remove_option {
+        challenge.options.remove(index);
+        changed = true;
+    }
+    if ui.button("+ Add option").clicked() {
+        let id = challenge
+            .options
+            .iter()
+            .map(|option| option.id + 1)
+            .max()
+            .unwrap_or(0);
+        challenge.options.push(MultipleChoiceOption {
+            id,
+            name: String::new(),
+        });
+        changed = true;
+    }
+
+    ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+    section_heading(ui, "Questions", theme, responsive);
+
+    ui.horizontal_wrapped(|ui| {
+        for (index, question) in challenge.questions.iter().enumerate() {
+            let label = if question.question.trim().is_empty() {
+                format!("{}.", index + 1)
+            } else {
+                format!("{}. {}", index + 1, truncate(&question.question, 24))
+            };
+            if ui
+                .selectable_label(*selected_question == index, label)
+                .clicked()
+            {
+                *selected_question = index;
+            }
+        }
+        if ui.button("+ Add question").clicked() {
+            challenge.questions.push(Question {
+                question: String::new(),
+                help: String::new(),
+                option: challenge
+                    .options
+                    .first()
+                    .map(|option| option.id)
+                    .unwrap_or(0),
+                image: None,
+            });
+            *selected_question = challenge.questions.len() - 1;
+            changed = true;
+        }
+    });
+
+    *selected_question = (*selected_question).min(challenge.questions.len().saturating_sub(1));
+    let options = &challenge.options;
+    let mut remove_question = false;
+    if let Some(question) = challenge.questions.get_mut(*selected_question) {
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+        scoped_ui(ui, ("challenge_question", *selected_question), |ui| {
+            ui.label(
+                egui::RichText::new("Prompt")
+                    .size(font_size)
+                    .color(theme.base_content),
+            );
+            changed |= ui
+                .add(
+                    egui::TextEdit::multiline(&mut question.question)
+                        .font(egui::FontId::proportional(font_size))
+                        .desired_rows(2)
+                        .desired_width(f32::INFINITY),
+                )
+                .changed();
+
+            ui.label(
+                egui::RichText::new("Help")
+                    .size(font_size)
+                    .color(theme.base_content),
+            );
+            changed |= ui
+                .add(
+                    egui::TextEdit::singleline(&mut question.help)
+                        .font(egui::FontId::proportional(font_size))
+                        .desired_width(f32::INFINITY),
+                )
+                .changed();
+
+            ui.label(
+                egui::RichText::new("Correct answer")
+                    .size(font_size)
+                    .color(theme.base_content),
+            );
+            ui.horizontal_wrapped(|ui| {
+                for option in options {
+                    let text = egui::RichText::new(&option.name).size(font_size).color(
+                        if question.option == option.id {
+                            theme.success
+                        } else {
+                            theme.base_content
+                        },
+                    );
+                    changed |= ui
+                        .radio_value(&mut question.option, option.id, text)
+                        .changed();
+                }
+            });
+
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+            if ui
+                .button(egui::RichText::new("Remove question").color(theme.error))
+                .clicked()
+            {
+                remove_question = true;
+            }
+        });
+    }
+    if remove_question {
+        challenge.questions.remove(*selected_question);
+        changed = true;
+    }
+
+    changed
+}
+
+fn section_heading(
+    ui: &mut egui::Ui,
+    text: &str,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+) {
+    ResponsiveText::new(text, ResponsiveFontSize::Large, theme.secondary)
+        .responsive(responsive)
+        .strong()
+        .ui(ui);
+    ui.add_space(responsive.spacing(ResponsiveSpacing::XSmall));
+}
+
+/// Render the live validation result
+fn render_issues(
+    ui: &mut egui::Ui,
+    issues: &[ContentIssue],
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+) {
+    if issues.is_empty() {
+        ResponsiveText::new("✔ Ready to save", ResponsiveFontSize::Small, theme.success)
+            .responsive(responsive)
+            .ui(ui);
+        return;
+    }
+
+    for issue in issues {
+        ResponsiveText::new(
+            &format!("⚠ {}", issue),
+            ResponsiveFontSize::Small,
+            theme.error,
+        )
+        .responsive(responsive)
+        .ui(ui);
+    }
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        text.to_string()
+    } else {
+        format!("{}…", text.chars().take(max_chars).collect::<String>())
+    }
+}
+
+/// Helper trait for easy challenge editor screen setup
+pub trait ChallengeEditorScreenExt {
+    /// Add a challenge editor screen with the given configuration
+    fn spawn_challenge_editor(&mut self, config: ChallengeEditorConfig) -> Entity;
+}
+
+impl ChallengeEditorScreenExt for Commands<'_, '_> {
+    fn spawn_challenge_editor(&mut self, config: ChallengeEditorConfig) -> Entity {
+        self.spawn((Name::new("Challenge Editor Screen"), config))
+            .id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+
+    #[test]
+    fn test_challenge_editor_validates_draft() {
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            ChallengeEditorPlugin,
+        ));
+        let entity = harness
+            .world_mut()
+            .spawn(ChallengeEditorConfig::new_challenge(
+                "challenges/colors.yml",
+            ))
+            .id();
+        harness.run_frames(2);
+
+        let active = harness
+            .world()
+            .get::<ActiveChallengeEditor>(entity)
+            .expect("editor is active");
+        assert_eq!(active.draft().map(ChallengeType::id), Some("colors"));
+        assert!(active
+            .issues()
+            .contains(&ContentIssue::new("add at least one question")));
+
+        harness.click("+ Add option");
+        harness.run_frames(1);
+        harness.click("+ Add question");
+        harness.run_frames(1);
+
+        let active = harness
+            .world()
+            .get::<ActiveChallengeEditor>(entity)
+            .expect("editor is active");
+        assert!(active.is_dirty());
+        assert!(active
+            .issues()
+            .contains(&ContentIssue::new("option 0 has no text")));
+        assert!(active
+            .issues()
+            .contains(&ContentIssue::new("question 1 has no prompt")));
+    }
+
+    #[test]
+    fn test_serialized_draft_loads_again() {
+        let challenge = ChallengeType::MultipleChoice(MultipleChoice {
+            id: "colors".to_string(),
+            name: "Colors".to_string(),
+            lang: "de".to_string(),
+            options: vec![MultipleChoiceOption {
+                id: 0,
+                name: "rot".to_string(),
+            }],
+            questions: vec![Question {
+                question: "red".to_string(),
+                help: String::new(),
+                option: 0,
+                image: None,
+            }],
+        });
+
+        assert!(challenge_draft_issues(&challenge).is_empty());
+
+        let yaml = serialize_challenge(&challenge, "colors.yml").unwrap();
+        assert!(validate_content(ContentKind::Challenge, &yaml).is_empty());
+
+        let json = serialize_challenge(&challenge, "colors.json").unwrap();
+        let parsed = serde_json::from_slice::<ChallengeType>(&json).unwrap();
+        assert_eq!(parsed.id(), "colors");
+    }
+}
@@ -0,0 +1,495 @@
+use super::{forward_screen_events, track_active_screen, ScreenAction, ScreenKind, ScreenMessage};
+#[cfg(feature = "storage")]
+use crate::storage::Storage;
+use crate::{
+    profile::{ClassReport, PlayerProfile, ReportFormat},
+    theme::KonnektorenTheme,
+    ui::{
+        notifications::Notification,
+        responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
+        widgets::{ResponsiveText, ThemedButton},
+    },
+};
+use bevy::prelude::*;
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+
+/// Plugin for the class report screen
+pub struct ClassReportPlugin;
+
+impl Plugin for ClassReportPlugin {
+    fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveClassReport>(app);
+        forward_screen_events::<ClassReportEvent>(app);
+
+        app.add_message::<ClassReportEvent>()
+            .add_message::<Notification>()
+            .add_systems(
+                Update,
+                (check_class_report_config, handle_class_report_events).chain(),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_class_report_ui.run_if(any_with_component::<ActiveClassReport>),
+            );
+    }
+}
+
+/// Configuration for the class report screen
+#[derive(Component, Clone)]
+pub struct ClassReportConfig {
+    pub title: String,
+    /// Profiles of the class, the current [`PlayerProfile`] if empty
+    pub profiles: Vec<PlayerProfile>,
+    /// Export formats offered, needs the `storage` feature
+    pub export_formats: Vec<ReportFormat>,
+    pub manual_dismissal: bool,
+    pub dismiss_button_text: String,
+}
+
+impl Default for ClassReportConfig {
+    fn default() -> Self {
+        Self {
+            title: "Class Report".to_string(),
+            profiles: vec![],
+            export_formats: vec![ReportFormat::Csv, ReportFormat::Json],
+            manual_dismissal: true,
+            dismiss_button_text: "Back".to_string(),
+        }
+    }
+}
+
+impl ClassReportConfig {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..default()
+        }
+    }
+
+    pub fn with_profiles(mut self, profiles: Vec<PlayerProfile>) -> Self {
+        self.profiles = profiles;
+        self
+    }
+
+    pub fn with_export_formats(mut self, formats: Vec<ReportFormat>) -> Self {
+        self.export_formats = formats;
+        self
+    }
+
+    pub fn with_manual_dismissal(mut self, manual_dismissal: bool) -> Self {
+        self.manual_dismissal = manual_dismissal;
+        self
+    }
+
+    pub fn with_dismiss_button_text(mut self, text: impl Into<String>) -> Self {
+        self.dismiss_button_text = text.into();
+        self
+    }
+}
+
+/// Component marking an active class report screen
+#[derive(Component)]
+pub struct ActiveClassReport {
+    config: ClassReportConfig,
+    report: ClassReport,
+}
+
+impl ActiveClassReport {
+    pub fn report(&self) -> &ClassReport {
+        &self.report
+    }
+}
+
+/// Events for class report interactions
+#[derive(Message, Debug, Clone)]
+pub enum ClassReportEvent {
+    /// Export of the report requested
+    Export {
+        entity: Entity,
+        format: ReportFormat,
+    },
+    /// The report was written to storage under `key`
+    Exported {
+        entity: Entity,
+        format: ReportFormat,
+        key: String,
+    },
+    /// The export failed
+    ExportFailed { entity: Entity, error: String },
+    /// Class report screen dismissed
+    Dismissed { entity: Entity },
+}
+
+impl ScreenMessage for ClassReportEvent {
+    const KIND: ScreenKind = ScreenKind::ClassReport;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::Export { entity, .. } => Some((ScreenAction::Submitted, *entity)),
+            Self::Exported { .. } | Self::ExportFailed { .. } => None,
+            Self::Dismissed { entity } => Some((ScreenAction::Dismissed, *entity)),
+        }
+    }
+}
+
+/// System to check for new class report configurations and set them up
+#[allow(clippy::type_complexity)]
+fn check_class_report_config(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &ClassReportConfig),
+        (Without<ActiveClassReport>, Changed<ClassReportConfig>),
+    >,
+    existing: Query<Entity, With<ActiveClassReport>>,
+    profile: Option<Res<PlayerProfile>>,
+) {
+    for (entity, config) in query.iter() {
+        info!("Setting up class report screen for entity {:?}", entity);
+
+        for existing_entity in existing.iter() {
+            commands
+                .entity(existing_entity)
+                .remove::<ActiveClassReport>();
+        }
+
+        let today = chrono::Local::now().date_naive();
+        let report = if config.profiles.is_empty() {
+            ClassReport::from_profiles(&config.title, profile.as_deref(), today)
+        } else {
+            ClassReport::from_profiles(&config.title, &config.profiles, today)
+        };
+
+        commands.entity(entity).insert(ActiveClassReport {
+            config: config.clone(),
+            report,
+        });
+    }
+}
+
+/// System to export reports and handle dismissal
+fn handle_class_report_events(
+    mut commands: Commands,
+    mut events: ParamSet<(
+        MessageReader<ClassReportEvent>,
+        MessageWriter<ClassReportEvent>,
+    )>,
+    query: Query<&ActiveClassReport>,
+    mut notifications: MessageWriter<Notification>,
+    #[cfg(feature = "storage")] storage: Option<Res<Storage>>,
+) {
+    let mut results = vec![];
+    for event in events.p0().read() {
+        match event {
+            ClassReportEvent::Export { entity, format } => {
+                let Ok(active) = query.get(*entity) else {
+                    continue;
+                };
+
+                #[cfg(feature = "storage")]
+                let result = match &storage {
+                    Some(storage) => active
+                        .report
+                        .export(storage, *format)
+                        .map_err(|e| e.to_string()),
+                    None => Err("No storage available".to_string()),
+                };
+                #[cfg(not(feature = "storage"))]
+                let result: Result<String, String> = {
+                    let _ = active;
+                    Err("Exporting needs the storage feature".to_string())
+                };
+
+                results.push(match result {
+                    Ok(key) => {
+                        notifications.write(Notification::success(
+                            "Report exported",
+                            format!("Saved as {}", key),
+                        ));
+                        ClassReportEvent::Exported {
+                            entity: *entity,
+                            format: *format,
+                            key,
+                        }
+                    }
+                    Err(error) => {
+                        warn!("Class report export failed: {}", error);
+                        notifications.write(Notification::error("Export failed", error.clone()));
+                        ClassReportEvent::ExportFailed {
+                            entity: *entity,
+                            error,
+                        }
+                    }
+                });
+            }
+            ClassReportEvent::Dismissed { entity } => {
+                info!("Dismissing class report screen for entity {:?}", entity);
+                commands.entity(*entity).remove::<ActiveClassReport>();
+            }
+            _ => {}
+        }
+    }
+    events.p1().write_batch(results);
+}
+
+/// System to render the class report UI
+fn render_class_report_ui(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    query: Query<(Entity, &ActiveClassReport)>,
+    mut events: MessageWriter<ClassReportEvent>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    // Only render the first (most recent) screen to avoid widget ID conflicts
+    if let Some((entity, active)) = query.iter().next() {
+        if active.config.manual_dismissal && input.just_pressed(KeyCode::Escape) {
+            events.write(ClassReportEvent::Dismissed { entity });
+            return;
+        }
+
+        egui::CentralPanel::default()
+            .frame(egui::Frame::NONE.fill(theme.base_100))
+            .show(ctx, |ui| {
+                scoped_ui(ui, (entity, "class_report"), |ui| {
+                    render_class_report_content(
+                        ui,
+                        active,
+                        &theme,
+                        &responsive,
+                        entity,
+                        &mut events,
+                    );
+                })
+            });
+    }
+}
+
+/// Render the report as a printable table with export buttons
+fn render_class_report_content(
+    ui: &mut egui::Ui,
+    active: &ActiveClassReport,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    events: &mut MessageWriter<ClassReportEvent>,
+) {
+    let report = &active.report;
+    let config = &active.config;
+
+    ui.vertical_centered(|ui| {
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+        ResponsiveText::new(&report.title, ResponsiveFontSize::Title, theme.primary)
+            .responsive(responsive)
+            .strong()
+            .ui(ui);
+
+        let average = report
+            .class_average()
+            .map(|average| format!(" · Class average {:.0}%", average))
+            .unwrap_or_default();
+        ResponsiveText::new(
+            &format!(
+                "{} · {} students{}",
+                report.generated,
+                report.students.len(),
+                average
+            ),
+            ResponsiveFontSize::Small,
+            theme.base_content,
+        )
+        .responsive(responsive)
+        .ui(ui);
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+    });
+
+    let button_width = if responsive.is_mobile() { 140.0 } else { 180.0 };
+    egui::TopBottomPanel::bottom("class_report_actions")
+        .frame(egui::Frame::NONE.fill(theme.base_100))
+        .show_inside(ui, |ui| {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+            ui.horizontal_wrapped(|ui| {
+                #[cfg(feature = "storage")]
+                for format in &config.export_formats {
+                    let label = format!("Export {}", format.label());
+                    let button = ThemedButton::new(&label, theme)
+                        .responsive(responsive)
+                        .width(button_width)
+                        .enabled(!report.students.is_empty());
+                    if ui.add(button).clicked() {
+                        events.write(ClassReportEvent::Export {
+                            entity,
+                            format: *format,
+                        });
+                    }
+                }
+
+                if config.manual_dismissal {
+                    let back_button = ThemedButton::new(&config.dismiss_button_text, theme)
+                        .responsive(responsive)
+                        .width(button_width);
+                    if ui.add(back_button).clicked() {
+                        events.write(ClassReportEvent::Dismissed { entity });
+                    }
+                }
+            });
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+        });
+
+    if report.students.is_empty() {
+        ui.vertical_centered(|ui| {
+            ResponsiveText::new(
+                "No results yet",
+                ResponsiveFontSize::Medium,
+                theme.base_content,
+            )
+            .responsive(responsive)
+            .ui(ui);
+        });
+        return;
+    }
+
+    let header_color = theme.primary;
+    let font_size = responsive.font_size(ResponsiveFontSize::Small);
+    let text = |ui: &mut egui::Ui, text: String, color: egui::Color32| {
+        ui.label(egui::RichText::new(text).size(font_size).color(color));
+    };
+
+    egui::ScrollArea::both().show(ui, |ui| {
+        egui::Grid::new("class_report_table")
+            .striped(true)
+            .spacing([
+                responsive.spacing(ResponsiveSpacing::Medium),
+                responsive.spacing(ResponsiveSpacing::Small),
+            ])
+            .show(ui, |ui| {
+                for header in [
+                    "Student",
+                    "Played",
+                    "Average",
+                    "Best",
+                    "Time",
+                    "Days",
+                    "Streak",
+                    "Last practice",
+                ] {
+                    ui.label(
+                        egui::RichText::new(header)
+                            .size(font_size)
+                            .color(header_color)
+                            .strong(),
+                    );
+                }
+                ui.end_row();
+
+                for student in &report.students {
+                    let color = theme.base_content;
+                    text(ui, student.name.clone(), color);
+                    text(ui, student.challenges_played.to_string(), color);
+                    text(
+                        ui,
+                        student
+                            .average_score
+                            .map(|score| format!("{:.0}%", score))
+                            .unwrap_or_else(|| "–".to_string()),
+                        color,
+                    );
+                    text(
+                        ui,
+                        student
+                            .best_score
+                            .map(|score| format!("{}%", score))
+                            .unwrap_or_else(|| "–".to_string()),
+                        color,
+                    );
+                    text(ui, format_duration(student.total_time_secs), color);
+                    text(ui, student.practice_days.to_string(), color);
+                    text(
+                        ui,
+                        format!(
+                            "{} (best {})",
+                            student.current_streak, student.longest_streak
+                        ),
+                        color,
+                    );
+                    text(
+                        ui,
+                        student
+                            .last_practice
+                            .map(|date| date.to_string())
+                            .unwrap_or_else(|| "–".to_string()),
+                        color,
+                    );
+                    ui.end_row();
+                }
+            });
+    });
+}
+
+/// Format seconds as e.g. "1h 05m" or "3m"
+fn format_duration(secs: u64) -> String {
+    let minutes = secs / 60;
+    if minutes >= 60 {
+        format!("{}h {:02}m", minutes / 60, minutes % 60)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+/// Helper trait for easy class report screen setup
+pub trait ClassReportScreenExt {
+    /// Add a class report screen with the given configuration
+    fn spawn_class_report(&mut self, config: ClassReportConfig) -> Entity;
+}
+
+impl ClassReportScreenExt for Commands<'_, '_> {
+    fn spawn_class_report(&mut self, config: ClassReportConfig) -> Entity {
+        self.spawn((Name::new("Class Report Screen"), config)).id()
+    }
+}
+
+#[cfg(all(test, feature = "storage"))]
+mod tests {
+    use super::*;
+    use crate::{
+        profile::PerformanceRecord, test_utils::TestHarness, theme::KonnektorenThemePlugin,
+        ui::UIPlugin,
+    };
+
+    #[test]
+    fn test_class_report_export() {
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let mut anna = PlayerProfile::new("anna", "Anna");
+        anna.record_result(PerformanceRecord::new("articles", date, 80));
+
+        let mut harness =
+            TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin, ClassReportPlugin));
+        harness.world_mut().insert_resource(Storage::memory());
+        harness.world_mut().spawn(
+            ClassReportConfig::new("Class 4b")
+                .with_profiles(vec![anna])
+                .with_export_formats(vec![ReportFormat::Csv]),
+        );
+        harness.run_frames(2);
+        assert!(harness.has_widget("Anna"));
+
+        harness.click("Export CSV");
+        harness.run_frames(2);
+
+        let storage = harness.world().resource::<Storage>();
+        let key = format!(
+            "{}_{}.csv",
+            crate::profile::CLASS_REPORT_STORAGE_PREFIX,
+            chrono::Local::now().date_naive()
+        );
+        let csv = storage.backend().get(&key).unwrap();
+        assert!(csv.contains("anna,Anna,1,80.0,80"));
+    }
+}
@@ -0,0 +1,960 @@
+use super::{
+    forward_screen_events, normalize_code, track_active_screen, ClassReportConfig,
+    ClassReportScreenExt, ScreenAction, ScreenKind, ScreenMessage,
+};
+use crate::{
+    profile::{PerformanceRecord, PlayerProfile},
+    theme::KonnektorenTheme,
+    ui::{
+        notifications::Notification,
+        qr_code::QrCodeWidget,
+        responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
+        widgets::{ResponsiveText, SpinnerWidget, ThemedButton},
+    },
+};
+use bevy::{
+    prelude::*,
+    tasks::{block_on, futures_lite::future, BoxedFuture, IoTaskPool, Task},
+};
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::{BuildHasher, Hasher},
+    sync::{Arc, Mutex},
+};
+
+/// Number of characters of a join code
+pub const JOIN_CODE_LENGTH: usize = 6;
+
+/// Characters of join codes, without look-alikes like 0/O and 1/I
+const JOIN_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+/// Plugin for classroom sessions.
+///
+/// A teacher starts a session and shows its join code, as text and QR code.
+/// Students enter the code, and results they record with
+/// [`PlayerProfile::record_result`] from then on are tagged with the session
+/// id and submitted through the [`ClassroomBackend`]. The teacher fetches
+/// the results of the session and reviews them as a class report.
+pub struct ClassroomPlugin;
+
+impl Plugin for ClassroomPlugin {
+    fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveClassroom>(app);
+        forward_screen_events::<ClassroomEvent>(app);
+
+        app.add_message::<ClassroomEvent>()
+            .add_message::<Notification>()
+            .init_resource::<Classroom>()
+            .init_resource::<ClassroomTasks>()
+            .init_resource::<ClassroomBackendResource>()
+            .add_systems(
+                Update,
+                (
+                    check_classroom_config,
+                    start_classroom_requests,
+                    poll_classroom_tasks,
+                    submit_classroom_results,
+                    handle_classroom_completion,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_classroom_ui.run_if(any_with_component::<ActiveClassroom>),
+            );
+    }
+}
+
+/// A classroom session students join with its code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassroomSession {
+    pub id: String,
+    pub join_code: String,
+}
+
+/// A result a student submits to a session
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassroomSubmission {
+    pub session_id: String,
+    pub student_id: String,
+    pub student_name: String,
+    pub record: PerformanceRecord,
+}
+
+/// Result of a classroom backend request
+pub type ClassroomResult<T> = Result<T, String>;
+
+/// Backend connecting teacher and student instances, e.g. a server
+pub trait ClassroomBackend: Send + Sync + 'static {
+    /// Create a session for a teacher. Runs on the IO task pool, like all
+    /// requests.
+    fn create_session(&self) -> BoxedFuture<'static, ClassroomResult<ClassroomSession>>;
+
+    /// Find the session of a (normalized) join code
+    fn join_session(
+        &self,
+        join_code: String,
+    ) -> BoxedFuture<'static, ClassroomResult<ClassroomSession>>;
+
+    fn submit_result(
+        &self,
+        submission: ClassroomSubmission,
+    ) -> BoxedFuture<'static, ClassroomResult<()>>;
+
+    /// Results of a session, one profile per student
+    fn session_results(
+        &self,
+        session_id: String,
+    ) -> BoxedFuture<'static, ClassroomResult<Vec<PlayerProfile>>>;
+}
+
+/// Classroom backend keeping the sessions in memory.
+///
+/// Clones share the sessions, which is enough for tests and for teacher
+/// and students taking turns on one device.
+#[derive(Debug, Clone, Default)]
+pub struct LocalClassroomBackend {
+    sessions: Arc<Mutex<LocalSessions>>,
+}
+
+#[derive(Debug, Default)]
+struct LocalSessions {
+    next_id: u64,
+    /// Session ids by join code
+    codes: HashMap<String, String>,
+    /// Students of each session by their id
+    students: HashMap<String, BTreeMap<String, PlayerProfile>>,
+}
+
+impl ClassroomBackend for LocalClassroomBackend {
+    fn create_session(&self) -> BoxedFuture<'static, ClassroomResult<ClassroomSession>> {
+        let result = self
+            .sessions
+            .lock()
+            .map_err(|_| "Classroom sessions unavailable".to_string())
+            .map(|mut sessions| {
+                sessions.next_id += 1;
+                let id = format!("local-{}", sessions.next_id);
+                let join_code = loop {
+                    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+                    hasher.write_u64(sessions.next_id);
+                    let code = join_code_from_seed(hasher.finish());
+                    if !sessions.codes.contains_key(&code) {
+                        break code;
+                    }
+                };
+                sessions.codes.insert(join_code.clone(), id.clone());
+                sessions.students.insert(id.clone(), BTreeMap::new());
+                ClassroomSession { id, join_code }
+            });
+        Box::pin(async move { result })
+    }
+
+    fn join_session(
+        &self,
+        join_code: String,
+    ) -> BoxedFuture<'static, ClassroomResult<ClassroomSession>> {
+        let result = self
+            .sessions
+            .lock()
+            .ok()
+            .and_then(|sessions| sessions.codes.get(&join_code).cloned())
+            .map(|id| ClassroomSession { id, join_code })
+            .ok_or_else(|| "No class session with this code".to_string());
+        Box::pin(async move { result })
+    }
+
+    fn submit_result(
+        &self,
+        submission: ClassroomSubmission,
+    ) -> BoxedFuture<'static, ClassroomResult<()>> {
+        let result = self
+            .sessions
+            .lock()
+            .ok()
+            .and_then(|mut sessions| {
+                let students = sessions.students.get_mut(&submission.session_id)?;
+                students
+                    .entry(submission.student_id.clone())
+                    .or_insert_with(|| {
+                        PlayerProfile::new(&submission.student_id, &submission.student_name)
+                    })
+                    .record_result(submission.record);
+                Some(())
+            })
+            .ok_or_else(|| "The class session has ended".to_string());
+        Box::pin(async move { result })
+    }
+
+    fn session_results(
+        &self,
+        session_id: String,
+    ) -> BoxedFuture<'static, ClassroomResult<Vec<PlayerProfile>>> {
+        let result = self
+            .sessions
+            .lock()
+            .ok()
+            .and_then(|sessions| {
+                sessions
+                    .students
+                    .get(&session_id)
+                    .map(|students| students.values().cloned().collect())
+            })
+            .ok_or_else(|| "The class session has ended".to_string());
+        Box::pin(async move { result })
+    }
+}
+
+/// Join code of [`JOIN_CODE_LENGTH`] characters from a random seed
+pub fn join_code_from_seed(mut seed: u64) -> String {
+    (0..JOIN_CODE_LENGTH)
+        .map(|_| {
+            // xorshift64
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            JOIN_CODE_ALPHABET[(seed % JOIN_CODE_ALPHABET.len() as u64) as usize] as char
+        })
+        .collect()
+}
+
+/// Resource holding the active classroom backend
+#[derive(Resource, Clone)]
+pub struct ClassroomBackendResource(pub Arc<dyn ClassroomBackend>);
+
+impl Default for ClassroomBackendResource {
+    fn default() -> Self {
+        Self(Arc::new(LocalClassroomBackend::default()))
+    }
+}
+
+impl ClassroomBackendResource {
+    pub fn new(backend: impl ClassroomBackend) -> Self {
+        Self(Arc::new(backend))
+    }
+}
+
+/// Whether this instance runs a session or takes part in one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClassroomRole {
+    Teacher,
+    Student,
+}
+
+/// Resource with the classroom session of this instance
+#[derive(Resource, Debug, Default)]
+pub struct Classroom {
+    role: Option<ClassroomRole>,
+    session: Option<ClassroomSession>,
+    /// Results of the session, fetched by the teacher
+    students: Vec<PlayerProfile>,
+    /// Profile results already tagged, for students
+    tagged: usize,
+}
+
+impl Classroom {
+    pub fn role(&self) -> Option<ClassroomRole> {
+        self.role
+    }
+
+    pub fn session(&self) -> Option<&ClassroomSession> {
+        self.session.as_ref()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.session.is_some()
+    }
+
+    /// Results of the session as of the last refresh, one profile per student
+    pub fn students(&self) -> &[PlayerProfile] {
+        &self.students
+    }
+
+    /// End or leave the session
+    pub fn leave(&mut self) {
+        self.role = None;
+        self.session = None;
+        self.students.clear();
+    }
+}
+
+#[derive(Resource, Default)]
+struct ClassroomTasks {
+    session: Option<(
+        Entity,
+        ClassroomRole,
+        Task<ClassroomResult<ClassroomSession>>,
+    )>,
+    results: Option<(Entity, Task<ClassroomResult<Vec<PlayerProfile>>>)>,
+    submissions: Vec<Task<ClassroomResult<()>>>,
+}
+
+/// Configuration for the classroom screen
+#[derive(Component, Clone)]
+pub struct ClassroomConfig {
+    pub role: ClassroomRole,
+    pub title: String,
+    pub description: Option<String>,
+    /// Link the join code is appended to in the QR code, e.g. a web build
+    /// reading the code from the URL. Only the code without a link.
+    pub join_url: Option<String>,
+    pub report_title: String,
+    pub manual_dismissal: bool,
+    pub dismiss_button_text: String,
+}
+
+impl ClassroomConfig {
+    pub fn teacher() -> Self {
+        Self {
+            role: ClassroomRole::Teacher,
+            title: "Class Session".to_string(),
+            description: Some(
+                "Start a session and let your students join with the code".to_string(),
+            ),
+            join_url: None,
+            report_title: "Class Session Report".to_string(),
+            manual_dismissal: true,
+            dismiss_button_text: "Back".to_string(),
+        }
+    }
+
+    pub fn student() -> Self {
+        Self {
+            role: ClassroomRole::Student,
+            title: "Join Class".to_string(),
+            description: Some("Enter the code your teacher shows you".to_string()),
+            ..Self::teacher()
+        }
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_join_url(mut self, join_url: impl Into<String>) -> Self {
+        self.join_url = Some(join_url.into());
+        self
+    }
+
+    pub fn with_report_title(mut self, report_title: impl Into<String>) -> Self {
+        self.report_title = report_title.into();
+        self
+    }
+
+    pub fn with_manual_dismissal(mut self, manual_dismissal: bool) -> Self {
+        self.manual_dismissal = manual_dismissal;
+        self
+    }
+
+    /// Text encoded in the QR code of a join code
+    pub fn join_link(&self, join_code: &str) -> String {
+        match &self.join_url {
+            Some(url) => format!("{}{}", url, join_code),
+            None => join_code.to_string(),
+        }
+    }
+}
+
+/// Component marking an active classroom screen
+#[derive(Component)]
+pub struct ActiveClassroom {
+    config: ClassroomConfig,
+    input: String,
+    error: Option<String>,
+}
+
+/// Events for classroom interactions
+#[derive(Message, Debug, Clone)]
+pub enum ClassroomEvent {
+    /// The teacher asked for a new session
+    StartSession { entity: Entity },
+    /// A student entered a join code
+    Join { entity: Entity, join_code: String },
+    /// The teacher asked for the latest results
+    RefreshResults { entity: Entity },
+    /// The teacher asked for the class report of the session
+    ShowReport { entity: Entity },
+    /// The session was ended or left
+    Leave { entity: Entity },
+    SessionStarted {
+        entity: Entity,
+        session: ClassroomSession,
+    },
+    Joined {
+        entity: Entity,
+        session: ClassroomSession,
+    },
+    /// New results were fetched
+    ResultsUpdated { entity: Entity, students: usize },
+    /// A backend request failed
+    Failed { entity: Entity, error: String },
+    /// Classroom screen dismissed
+    Dismissed { entity: Entity },
+}
+
+impl ScreenMessage for ClassroomEvent {
+    const KIND: ScreenKind = ScreenKind::Classroom;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::StartSession { entity } | Self::Join { entity, .. } => {
+                Some((ScreenAction::Submitted, *entity))
+            }
+            Self::SessionStarted { entity, .. }
+            | Self::Joined { entity, .. }
+            | Self::ResultsUpdated { entity, .. }
+            | Self::Leave { entity } => Some((ScreenAction::Changed, *entity)),
+            Self::Dismissed { entity } => Some((ScreenAction::Dismissed, *entity)),
+            Self::RefreshResults { .. } | Self::ShowReport { .. } | Self::Failed { .. } => None,
+        }
+    }
+}
+
+/// System to check for new classroom configurations and set them up
+#[allow(clippy::type_complexity)]
+fn check_classroom_config(
+    mut commands: Commands,
+    query: Query<(Entity, &ClassroomConfig), (Without<ActiveClassroom>, Changed<ClassroomConfig>)>,
+    existing: Query<Entity, With<ActiveClassroom>>,
+) {
+    for (entity, config) in query.iter() {
+        info!("Setting up classroom screen for entity {:?}", entity);
+
+        for existing_entity in existing.iter() {
+            commands.entity(existing_entity).remove::<ActiveClassroom>();
+        }
+
+        commands.entity(entity).insert(ActiveClassroom {
+            config: config.clone(),
+            input: String::new(),
+            error: None,
+        });
+    }
+}
+
+/// System to send classroom requests to the backend
+fn start_classroom_requests(
+    mut events: MessageReader<ClassroomEvent>,
+    backend: Res<ClassroomBackendResource>,
+    mut classroom: ResMut<Classroom>,
+    mut tasks: ResMut<ClassroomTasks>,
+) {
+    let pool = IoTaskPool::get();
+    for event in events.read() {
+        match event {
+            ClassroomEvent::StartSession { entity } if tasks.session.is_none() => {
+                info!("Starting classroom session");
+                let task = pool.spawn(backend.0.create_session());
+                tasks.session = Some((*entity, ClassroomRole::Teacher, task));
+            }
+            ClassroomEvent::Join { entity, join_code } if tasks.session.is_none() => {
+                info!("Joining classroom session");
+                let task = pool.spawn(backend.0.join_session(join_code.clone()));
+                tasks.session = Some((*entity, ClassroomRole::Student, task));
+            }
+            ClassroomEvent::RefreshResults { entity } if tasks.results.is_none() => {
+                let Some(session) = &classroom.session else {
+                    continue;
+                };
+                let task = pool.spawn(backend.0.session_results(session.id.clone()));
+                tasks.results = Some((*entity, task));
+            }
+            ClassroomEvent::Leave { .. } => {
+                info!("Leaving classroom session");
+                classroom.leave();
+                tasks.results = None;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// System to poll running classroom requests
+fn poll_classroom_tasks(
+    mut tasks: ResMut<ClassroomTasks>,
+    mut classroom: ResMut<Classroom>,
+    profile: Option<Res<PlayerProfile>>,
+    mut query: Query<&mut ActiveClassroom>,
+    mut events: MessageWriter<ClassroomEvent>,
+    mut notifications: MessageWriter<Notification>,
+) {
+    if let Some((entity, role, task)) = tasks.session.as_mut() {
+        if let Some(result) = block_on(future::poll_once(task)) {
+            let (entity, role) = (*entity, *role);
+            tasks.session = None;
+
+            match result {
+                Ok(session) => {
+                    classroom.leave();
+                    classroom.role = Some(role);
+                    classroom.session = Some(session.clone());
+                    // Only results recorded from now on belong to the session
+                    classroom.tagged = profile.as_ref().map_or(0, |p| p.results.len());
+                    if let Ok(mut active) = query.get_mut(entity) {
+                        active.input.clear();
+                        active.error = None;
+                    }
+                    events.write(match role {
+                        ClassroomRole::Teacher => {
+                            ClassroomEvent::SessionStarted { entity, session }
+                        }
+                        ClassroomRole::Student => {
+                            notifications.write(Notification::success(
+                                "Joined class",
+                                "Your results are shared with your teacher",
+                            ));
+                            ClassroomEvent::Joined { entity, session }
+                        }
+                    });
+                }
+                Err(error) => {
+                    warn!("Classroom session request failed: {}", error);
+                    if let Ok(mut active) = query.get_mut(entity) {
+                        active.error = Some(error.clone());
+                    }
+                    events.write(ClassroomEvent::Failed { entity, error });
+                }
+            }
+        }
+    }
+
+    if let Some((entity, task)) = tasks.results.as_mut() {
+        if let Some(result) = block_on(future::poll_once(task)) {
+            let entity = *entity;
+            tasks.results = None;
+
+            match result {
+                Ok(students) => {
+                    let count = students.len();
+                    classroom.students = students;
+                    events.write(ClassroomEvent::ResultsUpdated {
+                        entity,
+                        students: count,
+                    });
+                }
+                Err(error) => {
+                    warn!("Fetching classroom results failed: {}", error);
+                    notifications.write(Notification::error("Results not loaded", error.clone()));
+                    events.write(ClassroomEvent::Failed { entity, error });
+                }
+            }
+        }
+    }
+
+    tasks
+        .submissions
+        .retain_mut(|task| match block_on(future::poll_once(task)) {
+            Some(Err(error)) => {
+                warn!("Submitting a classroom result failed: {}", error);
+                false
+            }
+            Some(Ok(())) => false,
+            None => true,
+        });
+}
+
+/// System tagging new results of a student with the session id and
+/// submitting them
+fn submit_classroom_results(
+    mut classroom: ResMut<Classroom>,
+    profile: Option<ResMut<PlayerProfile>>,
+    backend: Res<ClassroomBackendResource>,
+    mut tasks: ResMut<ClassroomTasks>,
+) {
+    let Some(mut profile) = profile else {
+        return;
+    };
+    let Some(session) = classroom.session.clone() else {
+        return;
+    };
+    if classroom.role != Some(ClassroomRole::Student) {
+        return;
+    }
+
+    let count = profile.results.len();
+    if count <= classroom.tagged {
+        // Results were cleared, e.g. by a profile reset
+        classroom.tagged = classroom.tagged.min(count);
+        return;
+    }
+
+    let pool = IoTaskPool::get();
+    let (student_id, student_name) = (profile.id.clone(), profile.name.clone());
+    for record in &mut profile.results[classroom.tagged..] {
+        record.session_id = Some(session.id.clone());
+        let submission = ClassroomSubmission {
+            session_id: session.id.clone(),
+            student_id: student_id.clone(),
+            student_name: student_name.clone(),
+            record: record.clone(),
+        };
+        tasks
+            .submissions
+            .push(pool.spawn(backend.0.submit_result(submission)));
+    }
+    classroom.tagged = count;
+}
+
+/// System to show reports and handle dismissal of the classroom screen
+fn handle_classroom_completion(
+    mut commands: Commands,
+    mut events: MessageReader<ClassroomEvent>,
+    classroom: Res<Classroom>,
+    query: Query<&ActiveClassroom>,
+) {
+    for event in events.read() {
+        match event {
+            ClassroomEvent::ShowReport { entity } => {
+                let Ok(active) = query.get(*entity) else {
+                    continue;
+                };
+                if classroom.students.is_empty() {
+                    continue;
+                }
+                commands.spawn_class_report(
+                    ClassReportConfig::new(&active.config.report_title)
+                        .with_profiles(classroom.students.clone()),
+                );
+            }
+            ClassroomEvent::Dismissed { entity } => {
+                info!("Dismissing classroom screen for entity {:?}", entity);
+                commands.entity(*entity).remove::<ActiveClassroom>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// System to render the classroom UI
+#[allow(clippy::too_many_arguments)]
+fn render_classroom_ui(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    classroom: Res<Classroom>,
+    tasks: Res<ClassroomTasks>,
+    mut query: Query<(Entity, &mut ActiveClassroom)>,
+    mut events: MessageWriter<ClassroomEvent>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    // Only render the first (most recent) screen to avoid widget ID conflicts
+    if let Some((entity, mut active)) = query.iter_mut().next() {
+        if active.config.manual_dismissal && input.just_pressed(KeyCode::Escape) {
+            events.write(ClassroomEvent::Dismissed { entity });
+            return;
+        }
+
+        egui::CentralPanel::default()
+            .frame(egui::Frame::NONE.fill(theme.base_100))
+            .show(ctx, |ui| {
+                scoped_ui(ui, (entity, "classroom"), |ui| {
+                    egui::ScrollArea::vertical().show(ui, |ui| {
+                        render_classroom_content(
+                            ui,
+                            &mut active,
+                            &classroom,
+                            &tasks,
+                            &theme,
+                            &responsive,
+                            entity,
+                            &mut events,
+                        );
+                    });
+                })
+            });
+    }
+}
+
+/// Render classroom screen content for the role of the screen
+#[allow(clippy::too_many_arguments)]
+fn render_classroom_content(
+    ui: &mut egui::Ui,
+    active: &mut ActiveClassroom,
+    classroom: &Classroom,
+    tasks: &ClassroomTasks,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    events: &mut MessageWriter<ClassroomEvent>,
+) {
+    let config = active.config.clone();
+    let session = classroom
+        .session()
+        .filter(|_| classroom.role() == Some(config.role));
+    let button_width = if responsive.is_mobile() { 200.0 } else { 250.0 };
+
+    ui.vertical_centered(|ui| {
+        ui.add_space(responsive.spacing(ResponsiveSpacing::XLarge));
+        ResponsiveText::new(&config.title, ResponsiveFontSize::Title, theme.primary)
+            .responsive(responsive)
+            .strong()
+            .ui(ui);
+
+        if let (Some(description), None) = (&config.description, session) {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+            ResponsiveText::new(description, ResponsiveFontSize::Medium, theme.base_content)
+                .responsive(responsive)
+                .ui(ui);
+        }
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+        match (config.role, session) {
+            (ClassroomRole::Teacher, Some(session)) => {
+                ResponsiveText::new("Join code", ResponsiveFontSize::Small, theme.base_content)
+                    .responsive(responsive)
+                    .ui(ui);
+                ui.label(
+                    egui::RichText::new(&session.join_code)
+                        .font(egui::FontId::monospace(
+                            responsive.font_size(ResponsiveFontSize::Title),
+                        ))
+                        .color(theme.primary)
+                        .strong(),
+                );
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                let link = config.join_link(&session.join_code);
+                QrCodeWidget::new(&link, theme)
+                    .size(if responsive.is_mobile() { 160.0 } else { 220.0 })
+                    .responsive(responsive)
+                    .ui(ui);
+
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                let results: usize = classroom.students().iter().map(|s| s.results.len()).sum();
+                ResponsiveText::new(
+                    &format!(
+                        "{} students · {} results",
+                        classroom.students().len(),
+                        results
+                    ),
+                    ResponsiveFontSize::Medium,
+                    theme.base_content,
+                )
+                .responsive(responsive)
+                .ui(ui);
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                if tasks.results.is_some() {
+                    SpinnerWidget::new(theme, 32.0)
+                        .responsive(responsive)
+                        .ui(ui);
+                } else {
+                    let refresh = ThemedButton::new("Refresh results", theme)
+                        .responsive(responsive)
+                        .width(button_width);
+                    if ui.add(refresh).clicked() {
+                        events.write(ClassroomEvent::RefreshResults { entity });
+                    }
+                }
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+
+                let report = ThemedButton::new("View report", theme)
+                    .responsive(responsive)
+                    .width(button_width)
+                    .enabled(!classroom.students().is_empty());
+                if ui.add(report).clicked() {
+                    events.write(ClassroomEvent::ShowReport { entity });
+                }
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+
+                let end = ThemedButton::new("End session", theme)
+                    .responsive(responsive)
+                    .width(button_width);
+                if ui.add(end).clicked() {
+                    events.write(ClassroomEvent::Leave { entity });
+                }
+            }
+            (ClassroomRole::Student, Some(session)) => {
+                ResponsiveText::new(
+                    &format!("✔ Joined class {}", session.join_code),
+                    ResponsiveFontSize::Large,
+                    theme.success,
+                )
+                .responsive(responsive)
+                .ui(ui);
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                ResponsiveText::new(
+                    "Your results are shared with your teacher",
+                    ResponsiveFontSize::Medium,
+                    theme.base_content,
+                )
+                .responsive(responsive)
+                .ui(ui);
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+                let leave = ThemedButton::new("Leave class", theme)
+                    .responsive(responsive)
+                    .width(button_width);
+                if ui.add(leave).clicked() {
+                    events.write(ClassroomEvent::Leave { entity });
+                }
+            }
+            (role, None) => {
+                if role == ClassroomRole::Student {
+                    let font_size = responsive.font_size(ResponsiveFontSize::Large);
+                    let code_edit = ui.add(
+                        egui::TextEdit::singleline(&mut active.input)
+                            .id_salt("classroom_join_code")
+                            .hint_text("ABC123")
+                            .font(egui::FontId::monospace(font_size))
+                            .horizontal_align(egui::Align::Center)
+                            .desired_width(button_width)
+                            .margin(egui::Margin::symmetric(12, 10))
+                            .interactive(tasks.session.is_none()),
+                    );
+                    if !code_edit.has_focus() && !code_edit.lost_focus() {
+                        code_edit.request_focus();
+                    }
+                    if code_edit.changed() {
+                        active.input = normalize_code(&active.input)
+                            .chars()
+                            .take(JOIN_CODE_LENGTH)
+                            .collect();
+                        active.error = None;
+                    }
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                }
+
+                if let Some(error) = &active.error {
+                    ResponsiveText::new(error, ResponsiveFontSize::Small, theme.error)
+                        .responsive(responsive)
+                        .ui(ui);
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                }
+
+                if tasks.session.is_some() {
+                    SpinnerWidget::new(theme, 32.0)
+                        .responsive(responsive)
+                        .ui(ui);
+                } else if role == ClassroomRole::Teacher {
+                    let start = ThemedButton::new("Start session", theme)
+                        .responsive(responsive)
+                        .width(button_width);
+                    if ui.add(start).clicked() {
+                        events.write(ClassroomEvent::StartSession { entity });
+                    }
+                } else {
+                    let complete = active.input.len() == JOIN_CODE_LENGTH;
+                    let join = ThemedButton::new("Join", theme)
+                        .responsive(responsive)
+                        .width(button_width)
+                        .enabled(complete);
+                    let submit_with_enter =
+                        complete && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    if ui.add(join).clicked() || submit_with_enter {
+                        events.write(ClassroomEvent::Join {
+                            entity,
+                            join_code: active.input.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        if config.manual_dismissal {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+            let back_button = ThemedButton::new(&config.dismiss_button_text, theme)
+                .responsive(responsive)
+                .width(button_width);
+            if ui.add(back_button).clicked() {
+                events.write(ClassroomEvent::Dismissed { entity });
+            }
+        }
+    });
+}
+
+/// Helper trait for easy classroom screen setup
+pub trait ClassroomScreenExt {
+    /// Add a classroom screen with the given configuration
+    fn spawn_classroom(&mut self, config: ClassroomConfig) -> Entity;
+
+    /// Add the screen for a teacher to run a session
+    fn spawn_classroom_teacher(&mut self) -> Entity;
+
+    /// Add the screen for a student to join a session
+    fn spawn_classroom_student(&mut self) -> Entity;
+}
+
+impl ClassroomScreenExt for Commands<'_, '_> {
+    fn spawn_classroom(&mut self, config: ClassroomConfig) -> Entity {
+        self.spawn((Name::new("Classroom Screen"), config)).id()
+    }
+
+    fn spawn_classroom_teacher(&mut self) -> Entity {
+        self.spawn_classroom(ClassroomConfig::teacher())
+    }
+
+    fn spawn_classroom_student(&mut self) -> Entity {
+        self.spawn_classroom(ClassroomConfig::student())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+
+    fn run_until(harness: &mut TestHarness, done: impl Fn(&World) -> bool) {
+        for _ in 0..50 {
+            if done(harness.world()) {
+                return;
+            }
+            harness.step();
+        }
+        panic!("Classroom request did not finish");
+    }
+
+    #[test]
+    fn test_student_results_tagged_with_session() {
+        let backend = LocalClassroomBackend::default();
+        let session = block_on(backend.create_session()).unwrap();
+        assert_eq!(session.join_code.len(), JOIN_CODE_LENGTH);
+
+        let mut harness =
+            TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin, ClassroomPlugin));
+        harness
+            .world_mut()
+            .insert_resource(ClassroomBackendResource::new(backend.clone()));
+        let mut profile = PlayerProfile::new("anna", "Anna");
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        profile.record_result(PerformanceRecord::new("warmup", date, 50));
+        harness.world_mut().insert_resource(profile);
+        harness.world_mut().spawn(ClassroomConfig::student());
+        harness.run_frames(2);
+
+        harness.type_text(&session.join_code.to_lowercase());
+        harness.click("Join");
+        run_until(&mut harness, |world| {
+            world.resource::<Classroom>().session() == Some(&session)
+        });
+
+        harness
+            .world_mut()
+            .resource_mut::<PlayerProfile>()
+            .record_result(PerformanceRecord::new("articles", date, 90));
+        let id = session.id.clone();
+        run_until(&mut harness, |_| {
+            block_on(backend.session_results(id.clone())).is_ok_and(|s| !s.is_empty())
+        });
+
+        let students = block_on(backend.session_results(session.id.clone())).unwrap();
+        assert_eq!(students[0].name, "Anna");
+        // Only the result recorded after joining is shared
+        assert_eq!(students[0].results.len(), 1);
+        assert_eq!(students[0].results[0].session_id, Some(session.id));
+    }
+}
@@ -0,0 +1,622 @@
+use super::{forward_screen_events, track_active_screen, ScreenAction, ScreenKind, ScreenMessage};
+#[cfg(feature = "assets")]
+use super::{SurveyAnswer, SurveyAnswerValue, SurveyResponse, SurveySinkResource};
+#[cfg(feature = "storage")]
+use crate::storage::Storage;
+use crate::{
+    theme::KonnektorenTheme,
+    ui::{
+        log_capture::LogBuffer,
+        notifications::Notification,
+        responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
+        widgets::{ResponsiveText, ThemedButton},
+    },
+};
+use bevy::{
+    ecs::error::{BevyError, ErrorContext},
+    prelude::*,
+};
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+use chrono::NaiveDateTime;
+use std::sync::{Mutex, Once};
+
+/// Storage key of the report of a crash in the previous session
+pub const CRASH_REPORT_STORAGE_KEY: &str = "crash_report";
+
+/// Survey id crash reports are submitted with to the feedback sink
+pub const CRASH_REPORT_SURVEY_ID: &str = "crash-report";
+
+/// Number of recent log lines added to a crash report
+const CRASH_REPORT_LOG_LINES: usize = 50;
+
+/// Crash waiting to be shown, set by the panic hook and the error handler
+static PENDING_CRASH: Mutex<Option<CrashReport>> = Mutex::new(None);
+
+/// Log buffer and storage the panic hook writes the report with
+static CRASH_CONTEXT: Mutex<Option<CrashContext>> = Mutex::new(None);
+
+static INSTALL_PANIC_HOOK: Once = Once::new();
+
+#[derive(Clone, Default)]
+struct CrashContext {
+    log: Option<LogBuffer>,
+    #[cfg(feature = "storage")]
+    storage: Option<Storage>,
+}
+
+/// Plugin showing a "Something went wrong" screen instead of a silent freeze.
+///
+/// Installs a panic hook that captures the panic message and the recent
+/// lines of the [`LogBuffer`] (see [`log_capture_layer`]). Panics in
+/// background tasks are shown right away. A panic on the main thread ends
+/// the app, so the report is saved to the [`Storage`] and shown on the next
+/// start, which is also how it works on the web, where the page stops.
+///
+/// With [`catch_system_errors`](Self::catch_system_errors) errors returned by
+/// systems are shown on the crash screen instead of panicking, so web builds
+/// keep running.
+///
+/// [`log_capture_layer`]: crate::ui::log_capture_layer
+/// [`Storage`]: crate::storage::Storage
+#[derive(Default)]
+pub struct CrashReportPlugin {
+    pub catch_system_errors: bool,
+}
+
+impl CrashReportPlugin {
+    pub fn catch_system_errors(mut self) -> Self {
+        self.catch_system_errors = true;
+        self
+    }
+}
+
+impl Plugin for CrashReportPlugin {
+    fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveCrashScreen>(app);
+        forward_screen_events::<CrashScreenEvent>(app);
+
+        app.add_message::<CrashScreenEvent>()
+            .add_message::<Notification>()
+            .add_systems(
+                Update,
+                (
+                    show_pending_crash,
+                    check_crash_screen_config,
+                    handle_crash_screen_events,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_crash_screen_ui.run_if(any_with_component::<ActiveCrashScreen>),
+            );
+
+        #[cfg(feature = "storage")]
+        app.add_systems(Startup, load_previous_crash);
+
+        if self.catch_system_errors {
+            app.set_error_handler(report_system_error);
+        }
+    }
+
+    fn finish(&self, app: &mut App) {
+        let context = CrashContext {
+            log: app.world().get_resource::<LogBuffer>().cloned(),
+            #[cfg(feature = "storage")]
+            storage: app.world().get_resource::<Storage>().cloned(),
+        };
+        if let Ok(mut current) = CRASH_CONTEXT.lock() {
+            *current = Some(context);
+        }
+
+        INSTALL_PANIC_HOOK.call_once(|| {
+            let previous = std::panic::take_hook();
+            std::panic::set_hook(Box::new(move |info| {
+                let message = info
+                    .payload()
+                    .downcast_ref::<&str>()
+                    .map(|message| message.to_string())
+                    .or_else(|| info.payload().downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "Unknown panic".to_string());
+                let location = info
+                    .location()
+                    .map(|location| format!("{}:{}", location.file(), location.line()));
+                report_crash(CrashReport::new(message).with_location(location));
+                previous(info);
+            }));
+        });
+    }
+}
+
+/// What went wrong, with the log lines leading up to it
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub struct CrashReport {
+    pub message: String,
+    /// Source location or failed system
+    pub location: Option<String>,
+    pub time: NaiveDateTime,
+    pub log: Vec<String>,
+    /// The crash ended the previous session
+    #[cfg_attr(feature = "storage", serde(default))]
+    pub previous_session: bool,
+}
+
+impl CrashReport {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            location: None,
+            time: chrono::Local::now().naive_local(),
+            log: vec![],
+            previous_session: false,
+        }
+    }
+
+    pub fn with_location(mut self, location: Option<String>) -> Self {
+        self.location = location;
+        self
+    }
+
+    pub fn with_log(mut self, log: Vec<String>) -> Self {
+        self.log = log;
+        self
+    }
+
+    /// Plain text of the report, e.g. for the clipboard
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "{}\n{}\n",
+            self.time.format("%Y-%m-%d %H:%M:%S"),
+            self.message
+        );
+        if let Some(location) = &self.location {
+            text.push_str(&format!("at {}\n", location));
+        }
+        if !self.log.is_empty() {
+            text.push_str("\nRecent log:\n");
+            for line in &self.log {
+                text.push_str(line);
+                text.push('\n');
+            }
+        }
+        text
+    }
+
+    #[cfg(feature = "assets")]
+    fn to_survey_response(&self) -> SurveyResponse {
+        let text = |question_id: &str, value: String| SurveyAnswer {
+            question_id: question_id.to_string(),
+            value: SurveyAnswerValue::Text(value),
+        };
+        SurveyResponse {
+            survey_id: CRASH_REPORT_SURVEY_ID.to_string(),
+            timestamp: self.time.and_utc().timestamp(),
+            answers: vec![
+                text("message", self.message.clone()),
+                text("location", self.location.clone().unwrap_or_default()),
+                text("log", self.log.join("\n")),
+            ],
+        }
+    }
+}
+
+/// Show the crash screen for a report, adding the recent log lines.
+///
+/// Can be called from any thread, e.g. for errors a game handles itself.
+pub fn report_crash(mut report: CrashReport) {
+    let context = CRASH_CONTEXT
+        .lock()
+        .ok()
+        .and_then(|context| context.clone())
+        .unwrap_or_default();
+
+    if report.log.is_empty() {
+        if let Some(log) = &context.log {
+            report.log = log
+                .recent(CRASH_REPORT_LOG_LINES)
+                .iter()
+                .map(|line| line.to_string())
+                .collect();
+        }
+    }
+
+    // Shown on the next start if this ends the app
+    #[cfg(feature = "storage")]
+    if let Some(storage) = &context.storage {
+        let stored = CrashReport {
+            previous_session: true,
+            ..report.clone()
+        };
+        let _ = storage.save(CRASH_REPORT_STORAGE_KEY, &stored);
+    }
+
+    if let Ok(mut pending) = PENDING_CRASH.lock() {
+        *pending = Some(report);
+    }
+}
+
+/// Error handler showing errors of systems and commands on the crash screen
+fn report_system_error(error: BevyError, context: ErrorContext) {
+    error!("{} failed: {}", context, error);
+    report_crash(CrashReport::new(error.to_string()).with_location(Some(context.to_string())));
+}
+
+/// System to show the crash screen for the report of a crash in the previous
+/// session
+#[cfg(feature = "storage")]
+fn load_previous_crash(mut commands: Commands, storage: Option<Res<Storage>>) {
+    let Some(storage) = storage else {
+        return;
+    };
+
+    match storage.load::<CrashReport>(CRASH_REPORT_STORAGE_KEY) {
+        Ok(Some(report)) => {
+            let _ = storage.remove(CRASH_REPORT_STORAGE_KEY);
+            commands.spawn_crash_screen(CrashScreenConfig::new(report));
+        }
+        Ok(None) => {}
+        Err(e) => warn!("Failed to load crash report: {}", e),
+    }
+}
+
+/// System to show the crash screen for crashes reported since the last frame
+fn show_pending_crash(
+    mut commands: Commands,
+    #[cfg(feature = "storage")] storage: Option<Res<Storage>>,
+) {
+    let Some(report) = PENDING_CRASH
+        .lock()
+        .ok()
+        .and_then(|mut pending| pending.take())
+    else {
+        return;
+    };
+
+    // The app survived, nothing to show on the next start
+    #[cfg(feature = "storage")]
+    if let Some(storage) = storage {
+        let _ = storage.remove(CRASH_REPORT_STORAGE_KEY);
+    }
+
+    commands.spawn_crash_screen(CrashScreenConfig::new(report));
+}
+
+/// Configuration for the crash screen
+#[derive(Component, Clone)]
+pub struct CrashScreenConfig {
+    pub report: CrashReport,
+    pub title: String,
+    pub description: String,
+    /// Offer sending the report through the survey sink
+    pub allow_submission: bool,
+    pub dismiss_button_text: String,
+}
+
+impl CrashScreenConfig {
+    pub fn new(report: CrashReport) -> Self {
+        let description = if report.previous_session {
+            "The game closed unexpectedly last time. Sending us the report helps fixing it."
+        } else {
+            "An error occurred. Sending us the report helps fixing it."
+        };
+        Self {
+            report,
+            title: "Something went wrong".to_string(),
+            description: description.to_string(),
+            allow_submission: true,
+            dismiss_button_text: "Continue".to_string(),
+        }
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    pub fn with_submission(mut self, allow_submission: bool) -> Self {
+        self.allow_submission = allow_submission;
+        self
+    }
+
+    pub fn with_dismiss_button_text(mut self, text: impl Into<String>) -> Self {
+        self.dismiss_button_text = text.into();
+        self
+    }
+}
+
+/// Component marking an active crash screen
+#[derive(Component)]
+pub struct ActiveCrashScreen {
+    config: CrashScreenConfig,
+    submitted: bool,
+}
+
+impl ActiveCrashScreen {
+    pub fn report(&self) -> &CrashReport {
+        &self.config.report
+    }
+}
+
+/// Events for crash screen interactions
+#[derive(Message, Debug, Clone)]
+pub enum CrashScreenEvent {
+    /// The report was copied to the clipboard
+    Copied {
+        entity: Entity,
+    },
+    /// Sending the report requested
+    Submit {
+        entity: Entity,
+    },
+    /// The report was sent
+    Submitted {
+        entity: Entity,
+    },
+    SubmitFailed {
+        entity: Entity,
+        error: String,
+    },
+    /// Crash screen dismissed
+    Dismissed {
+        entity: Entity,
+    },
+}
+
+impl ScreenMessage for CrashScreenEvent {
+    const KIND: ScreenKind = ScreenKind::Crash;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::Submit { entity } => Some((ScreenAction::Submitted, *entity)),
+            Self::Dismissed { entity } => Some((ScreenAction::Dismissed, *entity)),
+            _ => None,
+        }
+    }
+}
+
+/// System to check for new crash screen configurations and set them up
+#[allow(clippy::type_complexity)]
+fn check_crash_screen_config(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &CrashScreenConfig),
+        (Without<ActiveCrashScreen>, Changed<CrashScreenConfig>),
+    >,
+    existing: Query<Entity, With<ActiveCrashScreen>>,
+) {
+    for (entity, config) in query.iter() {
+        info!("Setting up crash screen for entity {:?}", entity);
+
+        for existing_entity in existing.iter() {
+            commands
+                .entity(existing_entity)
+                .remove::<ActiveCrashScreen>();
+        }
+
+        commands.entity(entity).insert(ActiveCrashScreen {
+            config: config.clone(),
+            submitted: false,
+        });
+    }
+}
+
+/// System to send reports and handle dismissal
+fn handle_crash_screen_events(
+    mut commands: Commands,
+    mut events: ParamSet<(
+        MessageReader<CrashScreenEvent>,
+        MessageWriter<CrashScreenEvent>,
+    )>,
+    mut query: Query<&mut ActiveCrashScreen>,
+    mut notifications: MessageWriter<Notification>,
+    #[cfg(feature = "assets")] sink: Option<Res<SurveySinkResource>>,
+) {
+    let mut results = vec![];
+    for event in events.p0().read() {
+        match event {
+            CrashScreenEvent::Submit { entity } => {
+                let Ok(mut active) = query.get_mut(*entity) else {
+                    continue;
+                };
+
+                #[cfg(feature = "assets")]
+                let result = match &sink {
+                    Some(sink) => sink.0.submit(&active.config.report.to_survey_response()),
+                    None => Err("No feedback sink available".to_string()),
+                };
+                #[cfg(not(feature = "assets"))]
+                let result: Result<(), String> =
+                    Err("Sending reports needs the assets feature".to_string());
+
+                results.push(match result {
+                    Ok(()) => {
+                        active.submitted = true;
+                        notifications.write(Notification::success(
+                            "Report sent",
+                            "Thank you for helping us!",
+                        ));
+                        CrashScreenEvent::Submitted { entity: *entity }
+                    }
+                    Err(error) => {
+                        warn!("Sending crash report failed: {}", error);
+                        notifications.write(Notification::error("Sending failed", error.clone()));
+                        CrashScreenEvent::SubmitFailed {
+                            entity: *entity,
+                            error,
+                        }
+                    }
+                });
+            }
+            CrashScreenEvent::Dismissed { entity } => {
+                info!("Dismissing crash screen for entity {:?}", entity);
+                commands.entity(*entity).despawn();
+            }
+            _ => {}
+        }
+    }
+    events.p1().write_batch(results);
+}
+
+/// System to render the crash screen UI
+fn render_crash_screen_ui(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    query: Query<(Entity, &ActiveCrashScreen)>,
+    mut events: MessageWriter<CrashScreenEvent>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    // Only render the first (most recent) screen to avoid widget ID conflicts
+    let Some((entity, active)) = query.iter().next() else {
+        return;
+    };
+    let config = &active.config;
+    let report = &config.report;
+
+    egui::CentralPanel::default()
+        .frame(egui::Frame::NONE.fill(theme.base_100))
+        .show(ctx, |ui| {
+            scoped_ui(ui, (entity, "crash"), |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::XLarge));
+                    ResponsiveText::new(&config.title, ResponsiveFontSize::Title, theme.error)
+                        .responsive(&responsive)
+                        .strong()
+                        .ui(ui);
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                    ResponsiveText::new(
+                        &config.description,
+                        ResponsiveFontSize::Medium,
+                        theme.base_content,
+                    )
+                    .responsive(&responsive)
+                    .ui(ui);
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                });
+
+                let button_width = if responsive.is_mobile() { 140.0 } else { 180.0 };
+                egui::TopBottomPanel::bottom("crash_screen_actions")
+                    .frame(egui::Frame::NONE.fill(theme.base_100))
+                    .show_inside(ui, |ui| {
+                        ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                        ui.horizontal_wrapped(|ui| {
+                            let copy_button = ThemedButton::new("Copy report", &theme)
+                                .responsive(&responsive)
+                                .width(button_width);
+                            if ui.add(copy_button).clicked() {
+                                ui.ctx().copy_text(report.to_text());
+                                events.write(CrashScreenEvent::Copied { entity });
+                            }
+
+                            if config.allow_submission {
+                                let send_button = ThemedButton::new("Send report", &theme)
+                                    .responsive(&responsive)
+                                    .width(button_width)
+                                    .enabled(!active.submitted);
+                                if ui.add(send_button).clicked() {
+                                    events.write(CrashScreenEvent::Submit { entity });
+                                }
+                            }
+
+                            let dismiss_button =
+                                ThemedButton::new(&config.dismiss_button_text, &theme)
+                                    .responsive(&responsive)
+                                    .width(button_width);
+                            if ui.add(dismiss_button).clicked() {
+                                events.write(CrashScreenEvent::Dismissed { entity });
+                            }
+                        });
+                        ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                    });
+
+                let font_size = responsive.font_size(ResponsiveFontSize::Small);
+                egui::Frame::new()
+                    .fill(theme.base_200)
+                    .inner_margin(responsive.spacing(ResponsiveSpacing::Small))
+                    .show(ui, |ui| {
+                        egui::ScrollArea::vertical()
+                            .stick_to_bottom(true)
+                            .show(ui, |ui| {
+                                ui.set_width(ui.available_width());
+                                ui.label(
+                                    egui::RichText::new(&report.message)
+                                        .size(font_size)
+                                        .color(theme.error)
+                                        .monospace(),
+                                );
+                                if let Some(location) = &report.location {
+                                    ui.label(
+                                        egui::RichText::new(format!("at {}", location))
+                                            .size(font_size)
+                                            .color(theme.base_content)
+                                            .monospace(),
+                                    );
+                                }
+                                ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                                for line in &report.log {
+                                    ui.label(
+                                        egui::RichText::new(line)
+                                            .size(font_size)
+                                            .color(theme.base_content)
+                                            .monospace(),
+                                    );
+                                }
+                            });
+                    });
+            })
+        });
+}
+
+/// Helper trait for easy crash screen setup
+pub trait CrashScreenExt {
+    /// Add a crash screen with the given configuration
+    fn spawn_crash_screen(&mut self, config: CrashScreenConfig) -> Entity;
+}
+
+impl CrashScreenExt for Commands<'_, '_> {
+    fn spawn_crash_screen(&mut self, config: CrashScreenConfig) -> Entity {
+        self.spawn((Name::new("Crash Screen"), config)).id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+
+    #[test]
+    fn test_crash_in_task_shows_screen() {
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            CrashReportPlugin::default(),
+        ));
+        harness.app_mut().finish();
+        harness.step();
+        assert!(!harness.has_widget("Something went wrong"));
+
+        let result = std::thread::spawn(|| panic!("lost connection to the classroom")).join();
+        assert!(result.is_err());
+        harness.run_frames(2);
+
+        assert!(harness.has_widget("Something went wrong"));
+        assert!(harness.has_widget("lost connection to the classroom"));
+
+        harness.click("Continue");
+        harness.run_frames(2);
+        assert!(!harness.has_widget("Something went wrong"));
+    }
+}
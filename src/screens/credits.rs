@@ -1,7 +1,13 @@
+use super::{
+    dismiss_on_inactivity, forward_screen_events, insert_inactivity_timeout, track_active_screen,
+    ScreenAction, ScreenKind, ScreenMessage,
+};
 use crate::{
     theme::KonnektorenTheme,
     ui::{
+        menu_background::MenuBackgroundState,
         responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
         widgets::{ResponsiveText, ThemedButton},
     },
 };
@@ -16,9 +22,16 @@ pub struct CreditsPlugin;
 
 impl Plugin for CreditsPlugin {
     fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveCredits>(app);
+        forward_screen_events::<CreditsDismissed>(app);
+        dismiss_on_inactivity::<ActiveCredits, _>(app, |entity| CreditsDismissed { entity });
+
         app.add_message::<CreditsDismissed>()
             .add_systems(Update, (check_credits_config, handle_credits_completion))
-            .add_systems(EguiPrimaryContextPass, render_credits_ui);
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_credits_ui.run_if(any_with_component::<ActiveCredits>),
+            );
     }
 }
 
@@ -47,6 +60,8 @@ pub struct CreditsConfig {
     pub custom_sections: Vec<CustomCreditsSection>,
     /// Button text for dismissal
     pub dismiss_button_text: String,
+    /// Dismiss the screen after this many seconds without input
+    pub auto_dismiss: Option<f32>,
 }
 
 /// Custom section for extending the credits screen
@@ -99,6 +114,7 @@ impl Default for CreditsConfig {
             extension_widget: None,
             custom_sections: vec![],
             dismiss_button_text: "← Back".to_string(),
+            auto_dismiss: None,
         }
     }
 }
@@ -194,6 +210,13 @@ impl CreditsConfig {
         self
     }
 
+    /// Dismiss the screen after the given seconds without input, e.g. to
+    /// return a kiosk to the menu
+    pub fn with_auto_dismiss(mut self, seconds: f32) -> Self {
+        self.auto_dismiss = Some(seconds);
+        self
+    }
+
     /// Create a default game-focused credits config
     pub fn for_game(title: impl Into<String>) -> Self {
         Self {
@@ -260,6 +283,14 @@ pub struct CreditsDismissed {
     pub entity: Entity,
 }
 
+impl ScreenMessage for CreditsDismissed {
+    const KIND: ScreenKind = ScreenKind::Credits;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        Some((ScreenAction::Dismissed, self.entity))
+    }
+}
+
 /// System to check for new credits configurations and set them up
 #[allow(clippy::type_complexity)]
 fn check_credits_config(
@@ -285,10 +316,12 @@ fn check_credits_config(
             nav_state.max_index += 1;
         }
 
-        commands.entity(entity).insert(ActiveCredits {
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.insert(ActiveCredits {
             config: config.clone(),
             navigation_state: nav_state,
         });
+        insert_inactivity_timeout(&mut entity_commands, config.auto_dismiss);
     }
 }
 
@@ -300,6 +333,7 @@ fn render_credits_ui(
     mut query: Query<(Entity, &mut ActiveCredits)>,
     mut dismiss_events: MessageWriter<CreditsDismissed>,
     input: Res<ButtonInput<KeyCode>>,
+    background: Option<Res<MenuBackgroundState>>,
 ) {
     if query.is_empty() {
         return;
@@ -325,15 +359,20 @@ fn render_credits_ui(
             egui::CentralPanel::default()
                 .frame(egui::Frame::NONE.fill(theme.base_100))
                 .show(ctx, |ui| {
-                    render_credits_content(
-                        ui,
-                        config,
-                        navigation_state,
-                        &theme,
-                        &responsive,
-                        entity,
-                        &mut dismiss_events,
-                    );
+                    scoped_ui(ui, (entity, "credits"), |ui| {
+                        if let Some(background) = &background {
+                            background.paint(ui.painter(), ui.max_rect(), &theme);
+                        }
+                        render_credits_content(
+                            ui,
+                            config,
+                            navigation_state,
+                            &theme,
+                            &responsive,
+                            entity,
+                            &mut dismiss_events,
+                        );
+                    })
                 });
         }
     }
@@ -0,0 +1,90 @@
+use bevy::prelude::*;
+
+/// Screens of this crate
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScreenKind {
+    Splash,
+    About,
+    Credits,
+    Inbox,
+    Marketplace,
+    RedeemCode,
+    Settings,
+    Avatar,
+    Survey,
+    ClassReport,
+    Classroom,
+    Lobby,
+    HotSeat,
+    ReplayViewer,
+    Crash,
+    SetupWizard,
+    ChallengeEditor,
+    LevelEditor,
+    AssetInspector,
+    SessionSummary,
+    MainMenu,
+}
+
+/// What happened on a screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScreenAction {
+    /// The screen was dismissed
+    Dismissed,
+    /// The user confirmed the screen, e.g. submitted a code or saved an avatar
+    Submitted,
+    /// Something on the screen changed, e.g. a setting value
+    Changed,
+}
+
+/// Crate-level event sent alongside the per-screen messages.
+///
+/// Lets apps handle e.g. "any screen dismissed" in one system, the per-screen
+/// messages carry the details.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScreenEvent {
+    pub kind: ScreenKind,
+    pub action: ScreenAction,
+    pub entity: Entity,
+}
+
+impl ScreenEvent {
+    pub fn new(kind: ScreenKind, action: ScreenAction, entity: Entity) -> Self {
+        Self {
+            kind,
+            action,
+            entity,
+        }
+    }
+
+    pub fn is_dismissed(&self) -> bool {
+        self.action == ScreenAction::Dismissed
+    }
+}
+
+/// Per-screen message that is forwarded as [`ScreenEvent`]
+pub trait ScreenMessage: Message {
+    const KIND: ScreenKind;
+
+    /// Action and screen entity of the message, `None` if it is not forwarded
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)>;
+}
+
+/// Forward the screen messages `M` as [`ScreenEvent`]s
+pub fn forward_screen_events<M: ScreenMessage>(app: &mut App) {
+    app.add_message::<M>()
+        .add_message::<ScreenEvent>()
+        .add_systems(Last, send_screen_events::<M>);
+}
+
+fn send_screen_events<M: ScreenMessage>(
+    mut messages: MessageReader<M>,
+    mut screen_events: MessageWriter<ScreenEvent>,
+) {
+    for message in messages.read() {
+        if let Some((action, entity)) = message.screen_action() {
+            screen_events.write(ScreenEvent::new(M::KIND, action, entity));
+        }
+    }
+}
@@ -0,0 +1,373 @@
+use super::{forward_screen_events, track_active_screen, ScreenAction, ScreenKind, ScreenMessage};
+use crate::{
+    input::{InputController, PlayerStyle},
+    theme::KonnektorenTheme,
+    ui::{
+        responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
+        widgets::{ResponsiveText, ThemedButton},
+    },
+};
+use bevy::prelude::*;
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+use std::collections::HashMap;
+
+/// Plugin for turn-based games sharing one device.
+///
+/// The turn rotates among the players of all [`InputController`]s, in the
+/// order of their player ids. Only the controller of the [`ActivePlayer`] is
+/// enabled. When the game sends [`EndTurn`], a "Pass the device" screen is
+/// shown until the next player confirms, then [`TurnStarted`] is sent.
+///
+/// Not part of the [`ScreensPlugin`](super::ScreensPlugin), since it takes
+/// over enabling the controllers.
+#[derive(Default)]
+pub struct HotSeatPlugin {
+    pub config: HotSeatConfig,
+}
+
+impl HotSeatPlugin {
+    /// Name shown for a player instead of the one in the [`PlayerStyle`]
+    pub fn player_name(mut self, player_id: u32, name: impl Into<String>) -> Self {
+        self.config.player_names.insert(player_id, name.into());
+        self
+    }
+
+    /// Switch players right away, without the pass the device screen
+    pub fn without_interstitial(mut self) -> Self {
+        self.config.show_interstitial = false;
+        self
+    }
+}
+
+impl Plugin for HotSeatPlugin {
+    fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveHotSeatHandover>(app);
+        forward_screen_events::<HotSeatEvent>(app);
+
+        app.insert_resource(self.config.clone())
+            .init_resource::<ActivePlayer>()
+            .add_message::<EndTurn>()
+            .add_message::<TurnStarted>()
+            .add_systems(
+                Update,
+                (
+                    begin_hot_seat,
+                    handle_end_turn,
+                    handle_hot_seat_events,
+                    finish_handover,
+                    sync_hot_seat_controllers,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_hot_seat_handover_ui.run_if(any_with_component::<ActiveHotSeatHandover>),
+            );
+    }
+}
+
+/// Texts and player names of the hot-seat mode
+#[derive(Resource, Debug, Clone)]
+pub struct HotSeatConfig {
+    pub player_names: HashMap<u32, String>,
+    /// Show the pass the device screen between turns
+    pub show_interstitial: bool,
+    /// Title of the pass the device screen, `{player}` is replaced by the
+    /// name of the next player
+    pub pass_device_text: String,
+    pub ready_button_text: String,
+}
+
+impl Default for HotSeatConfig {
+    fn default() -> Self {
+        Self {
+            player_names: HashMap::new(),
+            show_interstitial: true,
+            pass_device_text: "Pass the device to {player}".to_string(),
+            ready_button_text: "I'm ready".to_string(),
+        }
+    }
+}
+
+impl HotSeatConfig {
+    /// Name of a player, from the [`PlayerStyle`] unless set here
+    pub fn player_name(&self, player_id: u32, player_style: &PlayerStyle) -> String {
+        self.player_names
+            .get(&player_id)
+            .cloned()
+            .unwrap_or_else(|| player_style.name(player_id))
+    }
+}
+
+/// Resource with the player whose turn it is
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActivePlayer {
+    pub player_id: u32,
+    /// Number of the current turn, 0 before the first turn
+    pub turn: u32,
+    /// Next player while the device is passed on
+    pub passing_to: Option<u32>,
+}
+
+impl ActivePlayer {
+    /// Whether a player is on turn, false while the device is passed on
+    pub fn is_playing(&self) -> bool {
+        self.turn > 0 && self.passing_to.is_none()
+    }
+}
+
+/// Message to end the turn of the active player
+#[derive(Message, Debug, Clone, Copy, Default)]
+pub struct EndTurn;
+
+/// Message sent when a player's turn starts
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TurnStarted {
+    pub player_id: u32,
+    pub turn: u32,
+}
+
+/// Component marking the active pass the device screen
+#[derive(Component)]
+pub struct ActiveHotSeatHandover {
+    next_player: u32,
+}
+
+/// Events of the pass the device screen
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HotSeatEvent {
+    /// The next player took the device
+    Ready { entity: Entity },
+}
+
+impl ScreenMessage for HotSeatEvent {
+    const KIND: ScreenKind = ScreenKind::HotSeat;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::Ready { entity } => Some((ScreenAction::Submitted, *entity)),
+        }
+    }
+}
+
+/// Sorted ids of the players taking turns
+fn player_ids<'a>(controllers: impl Iterator<Item = &'a InputController>) -> Vec<u32> {
+    let mut ids: Vec<u32> = controllers.map(|controller| controller.player_id).collect();
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
+
+/// System to give the first turn to the lowest player id once controllers exist
+fn begin_hot_seat(
+    mut active: ResMut<ActivePlayer>,
+    controllers: Query<&InputController>,
+    mut turns: MessageWriter<TurnStarted>,
+) {
+    if active.turn > 0 {
+        return;
+    }
+    let Some(first) = player_ids(controllers.iter()).first().copied() else {
+        return;
+    };
+
+    active.player_id = first;
+    active.turn = 1;
+    turns.write(TurnStarted {
+        player_id: first,
+        turn: 1,
+    });
+}
+
+/// System to pass the turn on to the next player
+fn handle_end_turn(
+    mut commands: Commands,
+    mut end_turns: MessageReader<EndTurn>,
+    config: Res<HotSeatConfig>,
+    mut active: ResMut<ActivePlayer>,
+    controllers: Query<&InputController>,
+    mut turns: MessageWriter<TurnStarted>,
+) {
+    if end_turns.read().last().is_none() || !active.is_playing() {
+        return;
+    }
+
+    let ids = player_ids(controllers.iter());
+    let Some(next) = ids
+        .iter()
+        .find(|id| **id > active.player_id)
+        .or(ids.first())
+        .copied()
+    else {
+        return;
+    };
+
+    if config.show_interstitial {
+        active.passing_to = Some(next);
+        commands.spawn((
+            Name::new("Hot Seat Handover Screen"),
+            ActiveHotSeatHandover { next_player: next },
+        ));
+    } else {
+        active.player_id = next;
+        active.turn += 1;
+        turns.write(TurnStarted {
+            player_id: next,
+            turn: active.turn,
+        });
+    }
+}
+
+/// System to close the pass the device screen once the next player is ready
+fn handle_hot_seat_events(mut commands: Commands, mut events: MessageReader<HotSeatEvent>) {
+    for event in events.read() {
+        let HotSeatEvent::Ready { entity } = event;
+        commands.entity(*entity).despawn();
+    }
+}
+
+/// System to start the next turn when the pass the device screen is gone
+fn finish_handover(
+    mut removed: RemovedComponents<ActiveHotSeatHandover>,
+    mut active: ResMut<ActivePlayer>,
+    mut turns: MessageWriter<TurnStarted>,
+) {
+    if removed.read().last().is_none() {
+        return;
+    }
+    let Some(next) = active.passing_to.take() else {
+        return;
+    };
+
+    active.player_id = next;
+    active.turn += 1;
+    turns.write(TurnStarted {
+        player_id: next,
+        turn: active.turn,
+    });
+}
+
+/// System to enable only the controller of the active player
+fn sync_hot_seat_controllers(
+    active: Res<ActivePlayer>,
+    mut controllers: Query<&mut InputController>,
+) {
+    let added = controllers
+        .iter_mut()
+        .any(|controller| controller.is_added());
+    if !active.is_changed() && !added {
+        return;
+    }
+
+    for mut controller in controllers.iter_mut() {
+        let enabled = active.is_playing() && controller.player_id == active.player_id;
+        if controller.enabled != enabled {
+            controller.enabled = enabled;
+            controller.clear();
+        }
+    }
+}
+
+/// System to render the pass the device screen
+#[allow(clippy::too_many_arguments)]
+fn render_hot_seat_handover_ui(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    config: Res<HotSeatConfig>,
+    player_style: Option<Res<PlayerStyle>>,
+    active: Res<ActivePlayer>,
+    query: Query<(Entity, &ActiveHotSeatHandover)>,
+    mut events: MessageWriter<HotSeatEvent>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    let Some((entity, handover)) = query.iter().next() else {
+        return;
+    };
+
+    let player_style = player_style.as_deref().cloned().unwrap_or_default();
+    let player = config.player_name(handover.next_player, &player_style);
+    let title = config.pass_device_text.replace("{player}", &player);
+    let player_color = player_style.color(handover.next_player, &theme);
+
+    egui::CentralPanel::default()
+        .frame(egui::Frame::NONE.fill(theme.base_100))
+        .show(ctx, |ui| {
+            scoped_ui(ui, (entity, "hot_seat"), |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(ui.available_height() * 0.3);
+                    ResponsiveText::new(&title, ResponsiveFontSize::Title, player_color)
+                        .responsive(&responsive)
+                        .strong()
+                        .ui(ui);
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                    ResponsiveText::new(
+                        &format!("Turn {}", active.turn + 1),
+                        ResponsiveFontSize::Medium,
+                        theme.base_content,
+                    )
+                    .responsive(&responsive)
+                    .ui(ui);
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::XLarge));
+
+                    let ready = ThemedButton::new(&config.ready_button_text, &theme)
+                        .responsive(&responsive)
+                        .width(if responsive.is_mobile() { 200.0 } else { 250.0 });
+                    let confirmed = ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    if ui.add(ready).clicked() || confirmed {
+                        events.write(HotSeatEvent::Ready { entity });
+                    }
+                });
+            })
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+
+    fn enabled_players(harness: &mut TestHarness) -> Vec<u32> {
+        let mut query = harness.world_mut().query::<&InputController>();
+        let mut ids: Vec<u32> = query
+            .iter(harness.world())
+            .filter(|controller| controller.enabled)
+            .map(|controller| controller.player_id)
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    #[test]
+    fn test_hot_seat_rotation() {
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            HotSeatPlugin::default().player_name(1, "Grace"),
+        ));
+        harness
+            .world_mut()
+            .spawn_batch([InputController::new(0), InputController::new(1)]);
+        harness.run_frames(2);
+        assert_eq!(harness.world().resource::<ActivePlayer>().player_id, 0);
+        assert_eq!(enabled_players(&mut harness), vec![0]);
+
+        harness.world_mut().write_message(EndTurn);
+        harness.run_frames(2);
+        assert!(harness.has_widget("Pass the device to Grace"));
+        assert!(enabled_players(&mut harness).is_empty());
+
+        harness.click("I'm ready");
+        harness.step();
+        let active = harness.world().resource::<ActivePlayer>().clone();
+        assert_eq!((active.player_id, active.turn), (1, 2));
+        assert_eq!(enabled_players(&mut harness), vec![1]);
+        assert!(!harness.has_widget("Pass the device to Grace"));
+    }
+}
@@ -0,0 +1,161 @@
+use bevy::{
+    ecs::system::SystemParam,
+    input::{
+        mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll},
+        touch::Touches,
+    },
+    prelude::*,
+};
+
+/// Timer dismissing a screen after a time without any input, e.g. to return
+/// a kiosk to the menu.
+///
+/// Inserted next to the active component of screens configured with an auto
+/// dismiss time and reset by every key, button, pointer or touch input.
+#[derive(Component, Debug, Clone)]
+pub struct InactivityTimeout {
+    timer: Timer,
+}
+
+impl InactivityTimeout {
+    /// Timeout after `seconds`, negative or NaN times dismiss on the next frame
+    pub fn from_seconds(seconds: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(seconds.max(0.0), TimerMode::Once),
+        }
+    }
+
+    /// Start the timeout over
+    pub fn reset(&mut self) {
+        self.timer.reset();
+    }
+
+    /// Seconds left until the screen is dismissed
+    pub fn remaining_secs(&self) -> f32 {
+        self.timer.remaining_secs()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.timer.is_finished()
+    }
+}
+
+/// Whether the user gave any input in this frame
+#[derive(SystemParam)]
+pub struct UserActivity<'w> {
+    keys: Option<Res<'w, ButtonInput<KeyCode>>>,
+    mouse_buttons: Option<Res<'w, ButtonInput<MouseButton>>>,
+    mouse_motion: Option<Res<'w, AccumulatedMouseMotion>>,
+    mouse_scroll: Option<Res<'w, AccumulatedMouseScroll>>,
+    touches: Option<Res<'w, Touches>>,
+    gamepad_buttons: Option<Res<'w, ButtonInput<GamepadButton>>>,
+}
+
+impl UserActivity<'_> {
+    pub fn any(&self) -> bool {
+        self.keys
+            .as_ref()
+            .is_some_and(|keys| keys.get_pressed().next().is_some())
+            || self
+                .mouse_buttons
+                .as_ref()
+                .is_some_and(|buttons| buttons.get_pressed().next().is_some())
+            || self
+                .mouse_motion
+                .as_ref()
+                .is_some_and(|motion| motion.delta != Vec2::ZERO)
+            || self
+                .mouse_scroll
+                .as_ref()
+                .is_some_and(|scroll| scroll.delta != Vec2::ZERO)
+            || self
+                .touches
+                .as_ref()
+                .is_some_and(|touches| touches.iter().next().is_some())
+            || self
+                .gamepad_buttons
+                .as_ref()
+                .is_some_and(|buttons| buttons.get_pressed().next().is_some())
+    }
+}
+
+/// Dismiss screens with the active component `T` and an [`InactivityTimeout`]
+/// by sending the message `dismissed` creates, the same one their back button
+/// sends, so navigation stays consistent
+pub fn dismiss_on_inactivity<T: Component, M: Message>(app: &mut App, dismissed: fn(Entity) -> M) {
+    app.add_message::<M>().add_systems(
+        Update,
+        move |time: Res<Time>,
+              activity: UserActivity,
+              mut query: Query<(Entity, &mut InactivityTimeout), With<T>>,
+              mut messages: MessageWriter<M>| {
+            let active = activity.any();
+            for (entity, mut timeout) in query.iter_mut() {
+                if active {
+                    timeout.reset();
+                    continue;
+                }
+
+                timeout.timer.tick(time.delta());
+                if timeout.timer.just_finished() {
+                    info!("Dismissing inactive screen {:?}", entity);
+                    messages.write(dismissed(entity));
+                }
+            }
+        },
+    );
+}
+
+/// Insert or remove the [`InactivityTimeout`] of a screen being activated
+pub(crate) fn insert_inactivity_timeout(entity: &mut EntityCommands, seconds: Option<f32>) {
+    match seconds {
+        Some(seconds) => entity.insert(InactivityTimeout::from_seconds(seconds)),
+        None => entity.remove::<InactivityTimeout>(),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::screens::{AboutConfig, AboutPlugin, ActiveAbout};
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    #[test]
+    fn test_auto_dismiss_after_inactivity() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AboutPlugin))
+            .init_resource::<ButtonInput<KeyCode>>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                250,
+            )));
+        let about = app
+            .world_mut()
+            .spawn(AboutConfig::default().with_auto_dismiss(3.0))
+            .id();
+
+        // Holding a key keeps the screen open
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        for _ in 0..20 {
+            app.update();
+        }
+        assert!(app.world().entity(about).contains::<ActiveAbout>());
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .release(KeyCode::Space);
+        for _ in 0..20 {
+            app.update();
+        }
+        assert!(!app.world().entity(about).contains::<ActiveAbout>());
+    }
+    #[test]
+    fn test_invalid_inactivity_timeout() {
+        for seconds in [-1.0, f32::NAN] {
+            let timeout = InactivityTimeout::from_seconds(seconds);
+            assert_eq!(timeout.remaining_secs(), 0.0);
+        }
+    }
+}
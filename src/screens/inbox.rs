@@ -0,0 +1,772 @@
+use super::{forward_screen_events, track_active_screen, ScreenAction, ScreenKind, ScreenMessage};
+#[cfg(feature = "storage")]
+use crate::storage::{PersistenceScheduler, Storage};
+use crate::{
+    theme::KonnektorenTheme,
+    ui::{
+        notifications::Notification,
+        responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
+        widgets::{ResponsiveText, ThemedButton},
+    },
+};
+use bevy::prelude::*;
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+use chrono::{TimeZone, Utc};
+use std::collections::HashMap;
+
+/// Storage key of the persisted inbox
+pub const INBOX_STORAGE_KEY: &str = "inbox";
+
+/// Plugin for the notification inbox and its screen
+pub struct InboxPlugin;
+
+impl Plugin for InboxPlugin {
+    fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveInbox>(app);
+        forward_screen_events::<InboxEvent>(app);
+
+        app.init_resource::<Inbox>()
+            .add_message::<InboxEvent>()
+            .add_message::<AddInboxMessage>()
+            .add_message::<Notification>()
+            .add_systems(
+                Update,
+                (
+                    check_inbox_config,
+                    receive_inbox_messages,
+                    handle_inbox_events,
+                ),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_inbox_ui.run_if(any_with_component::<ActiveInbox>),
+            );
+
+        #[cfg(feature = "storage")]
+        {
+            app.add_systems(Startup, load_inbox)
+                .add_systems(PostUpdate, save_inbox);
+        }
+    }
+}
+
+/// Kind of an inbox message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub enum InboxMessageKind {
+    #[default]
+    Announcement,
+    Unlock,
+    Teacher,
+}
+
+impl InboxMessageKind {
+    pub fn icon(&self) -> &'static str {
+        match self {
+            InboxMessageKind::Announcement => "📢",
+            InboxMessageKind::Unlock => "🔓",
+            InboxMessageKind::Teacher => "🧑‍🏫",
+        }
+    }
+}
+
+/// A message stored in the inbox
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub struct InboxMessage {
+    pub id: String,
+    pub kind: InboxMessageKind,
+    pub title: String,
+    pub body: String,
+    /// Unix timestamp in seconds
+    pub timestamp: i64,
+    pub read: bool,
+}
+
+impl InboxMessage {
+    pub fn new(
+        id: impl Into<String>,
+        kind: InboxMessageKind,
+        title: impl Into<String>,
+        body: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            kind,
+            title: title.into(),
+            body: body.into(),
+            timestamp: Utc::now().timestamp(),
+            read: false,
+        }
+    }
+
+    pub fn announcement(
+        id: impl Into<String>,
+        title: impl Into<String>,
+        body: impl Into<String>,
+    ) -> Self {
+        Self::new(id, InboxMessageKind::Announcement, title, body)
+    }
+
+    pub fn unlock(
+        id: impl Into<String>,
+        title: impl Into<String>,
+        body: impl Into<String>,
+    ) -> Self {
+        Self::new(id, InboxMessageKind::Unlock, title, body)
+    }
+
+    pub fn teacher(
+        id: impl Into<String>,
+        title: impl Into<String>,
+        body: impl Into<String>,
+    ) -> Self {
+        Self::new(id, InboxMessageKind::Teacher, title, body)
+    }
+
+    pub fn with_timestamp(mut self, timestamp: i64) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Formatted date of the message
+    pub fn formatted_date(&self) -> String {
+        Utc.timestamp_opt(self.timestamp, 0)
+            .single()
+            .map(|date| date.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default()
+    }
+}
+
+/// Resource holding all inbox messages, newest first
+#[derive(Resource, Debug, Default, Clone)]
+pub struct Inbox {
+    pub messages: Vec<InboxMessage>,
+}
+
+impl Inbox {
+    /// Add a message, replacing an existing one with the same ID
+    pub fn add(&mut self, message: InboxMessage) {
+        self.messages.retain(|m| m.id != message.id);
+        self.messages.insert(0, message);
+    }
+
+    pub fn get(&self, id: &str) -> Option<&InboxMessage> {
+        self.messages.iter().find(|m| m.id == id)
+    }
+
+    pub fn remove(&mut self, id: &str) {
+        self.messages.retain(|m| m.id != id);
+    }
+
+    pub fn mark_read(&mut self, id: &str) {
+        if let Some(message) = self.messages.iter_mut().find(|m| m.id == id) {
+            message.read = true;
+        }
+    }
+
+    pub fn mark_all_read(&mut self) {
+        for message in self.messages.iter_mut() {
+            message.read = true;
+        }
+    }
+
+    pub fn unread_count(&self) -> usize {
+        self.messages.iter().filter(|m| !m.read).count()
+    }
+}
+
+/// Message to deliver a new message into the inbox
+#[derive(Message, Debug, Clone)]
+pub struct AddInboxMessage {
+    pub message: InboxMessage,
+    /// Also show the message as notification toast
+    pub notify: bool,
+}
+
+impl AddInboxMessage {
+    pub fn new(message: InboxMessage) -> Self {
+        Self {
+            message,
+            notify: true,
+        }
+    }
+
+    pub fn silent(message: InboxMessage) -> Self {
+        Self {
+            message,
+            notify: false,
+        }
+    }
+}
+
+/// Configuration for the inbox screen
+#[derive(Component, Clone)]
+pub struct InboxConfig {
+    pub title: String,
+    pub empty_message: String,
+    pub dismiss_button_text: String,
+    pub mark_all_read_text: String,
+    /// Allow manual dismissal (back button/escape)
+    pub manual_dismissal: bool,
+    /// Allow swiping messages away on touch devices
+    pub swipe_to_dismiss: bool,
+}
+
+impl Default for InboxConfig {
+    fn default() -> Self {
+        Self {
+            title: "Inbox".to_string(),
+            empty_message: "No messages yet.".to_string(),
+            dismiss_button_text: "← Back".to_string(),
+            mark_all_read_text: "Mark all as read".to_string(),
+            manual_dismissal: true,
+            swipe_to_dismiss: true,
+        }
+    }
+}
+
+impl InboxConfig {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_empty_message(mut self, message: impl Into<String>) -> Self {
+        self.empty_message = message.into();
+        self
+    }
+
+    pub fn with_dismiss_button_text(mut self, text: impl Into<String>) -> Self {
+        self.dismiss_button_text = text.into();
+        self
+    }
+
+    pub fn with_mark_all_read_text(mut self, text: impl Into<String>) -> Self {
+        self.mark_all_read_text = text.into();
+        self
+    }
+
+    pub fn with_manual_dismissal(mut self, manual_dismissal: bool) -> Self {
+        self.manual_dismissal = manual_dismissal;
+        self
+    }
+
+    pub fn with_swipe_to_dismiss(mut self, swipe_to_dismiss: bool) -> Self {
+        self.swipe_to_dismiss = swipe_to_dismiss;
+        self
+    }
+}
+
+/// Component marking an active inbox screen
+#[derive(Component)]
+pub struct ActiveInbox {
+    config: InboxConfig,
+    expanded: Option<String>,
+    swipe_offsets: HashMap<String, f32>,
+}
+
+/// Events for inbox screen interactions
+#[derive(Message, Debug, Clone)]
+pub enum InboxEvent {
+    /// A message was opened (and marked as read)
+    MessageOpened { entity: Entity, id: String },
+    /// A message was deleted
+    MessageDeleted { entity: Entity, id: String },
+    /// All messages were marked as read
+    MarkAllRead { entity: Entity },
+    /// Inbox screen dismissed
+    Dismissed { entity: Entity },
+}
+
+impl ScreenMessage for InboxEvent {
+    const KIND: ScreenKind = ScreenKind::Inbox;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::Dismissed { entity } => Some((ScreenAction::Dismissed, *entity)),
+            Self::MessageOpened { entity, .. }
+            | Self::MessageDeleted { entity, .. }
+            | Self::MarkAllRead { entity } => Some((ScreenAction::Changed, *entity)),
+        }
+    }
+}
+
+/// System to check for new inbox configurations and set them up
+#[allow(clippy::type_complexity)]
+fn check_inbox_config(
+    mut commands: Commands,
+    query: Query<(Entity, &InboxConfig), (Without<ActiveInbox>, Changed<InboxConfig>)>,
+    existing: Query<Entity, With<ActiveInbox>>,
+) {
+    for (entity, config) in query.iter() {
+        info!("Setting up inbox screen for entity {:?}", entity);
+
+        // Clean up any existing inbox screens first
+        for existing_entity in existing.iter() {
+            commands.entity(existing_entity).remove::<ActiveInbox>();
+        }
+
+        commands.entity(entity).insert(ActiveInbox {
+            config: config.clone(),
+            expanded: None,
+            swipe_offsets: HashMap::new(),
+        });
+    }
+}
+
+/// System to deliver new messages into the inbox
+fn receive_inbox_messages(
+    mut add_events: MessageReader<AddInboxMessage>,
+    mut inbox: ResMut<Inbox>,
+    mut notifications: MessageWriter<Notification>,
+) {
+    for event in add_events.read() {
+        if event.notify {
+            notifications.write(Notification::info(
+                format!("{} {}", event.message.kind.icon(), event.message.title),
+                event.message.body.clone(),
+            ));
+        }
+        inbox.add(event.message.clone());
+    }
+}
+
+/// System to handle inbox screen events
+fn handle_inbox_events(
+    mut commands: Commands,
+    mut events: MessageReader<InboxEvent>,
+    mut inbox: ResMut<Inbox>,
+) {
+    for event in events.read() {
+        match event {
+            InboxEvent::MessageOpened { id, .. } => inbox.mark_read(id),
+            InboxEvent::MessageDeleted { id, .. } => inbox.remove(id),
+            InboxEvent::MarkAllRead { .. } => inbox.mark_all_read(),
+            InboxEvent::Dismissed { entity } => {
+                info!("Dismissing inbox screen for entity {:?}", entity);
+                commands.entity(*entity).remove::<ActiveInbox>();
+            }
+        }
+    }
+}
+
+/// System to restore the inbox from storage
+#[cfg(feature = "storage")]
+fn load_inbox(storage: Option<Res<Storage>>, mut inbox: ResMut<Inbox>) {
+    let Some(storage) = storage else {
+        return;
+    };
+
+    match storage.load::<Vec<InboxMessage>>(INBOX_STORAGE_KEY) {
+        Ok(Some(messages)) => {
+            info!("Loaded {} inbox messages", messages.len());
+            inbox.messages = messages;
+        }
+        Ok(None) => {}
+        Err(e) => warn!("Failed to load inbox: {}", e),
+    }
+}
+
+/// System to persist the inbox whenever it changes
+#[cfg(feature = "storage")]
+fn save_inbox(scheduler: Option<ResMut<PersistenceScheduler>>, inbox: Res<Inbox>) {
+    if !inbox.is_changed() {
+        return;
+    }
+
+    if let Some(mut scheduler) = scheduler {
+        scheduler.schedule(INBOX_STORAGE_KEY, &inbox.messages);
+    }
+}
+
+/// System to render the inbox UI
+fn render_inbox_ui(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    inbox: Res<Inbox>,
+    mut query: Query<(Entity, &mut ActiveInbox)>,
+    mut events: MessageWriter<InboxEvent>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    if query.is_empty() {
+        return;
+    }
+
+    if let Ok(ctx) = contexts.ctx_mut() {
+        // Only render the first (most recent) inbox to avoid widget ID conflicts
+        if let Some((entity, mut active)) = query.iter_mut().next() {
+            if active.config.manual_dismissal && input.just_pressed(KeyCode::Escape) {
+                events.write(InboxEvent::Dismissed { entity });
+                return;
+            }
+
+            egui::CentralPanel::default()
+                .frame(egui::Frame::NONE.fill(theme.base_100))
+                .show(ctx, |ui| {
+                    scoped_ui(ui, (entity, "inbox"), |ui| {
+                        render_inbox_content(
+                            ui,
+                            &mut active,
+                            &inbox,
+                            &theme,
+                            &responsive,
+                            entity,
+                            &mut events,
+                        );
+                    })
+                });
+        }
+    }
+}
+
+/// Render inbox screen content
+fn render_inbox_content(
+    ui: &mut egui::Ui,
+    active: &mut ActiveInbox,
+    inbox: &Inbox,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    events: &mut MessageWriter<InboxEvent>,
+) {
+    let config = active.config.clone();
+
+    ui.vertical_centered(|ui| {
+        let max_width = if responsive.is_mobile() {
+            ui.available_width() * 0.95
+        } else {
+            800.0_f32.min(ui.available_width() * 0.9)
+        };
+
+        ui.set_max_width(max_width);
+
+        // Header
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+        let unread = inbox.unread_count();
+        let title = if unread > 0 {
+            format!("{} ({})", config.title, unread)
+        } else {
+            config.title.clone()
+        };
+        ResponsiveText::new(&title, ResponsiveFontSize::Title, theme.primary)
+            .responsive(responsive)
+            .strong()
+            .ui(ui);
+
+        if unread > 0 {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+            let mark_all_button =
+                ThemedButton::new(&config.mark_all_read_text, theme).responsive(responsive);
+            if ui.add(mark_all_button).clicked() {
+                events.write(InboxEvent::MarkAllRead { entity });
+            }
+        }
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+        let scroll_height = ui.available_height() - 80.0;
+        egui::ScrollArea::vertical()
+            .id_salt("inbox_scroll")
+            .max_height(scroll_height)
+            .auto_shrink([false; 2])
+            .show(ui, |ui| {
+                if inbox.messages.is_empty() {
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(responsive.spacing(ResponsiveSpacing::XLarge));
+                        ResponsiveText::new(
+                            &config.empty_message,
+                            ResponsiveFontSize::Medium,
+                            theme.accent,
+                        )
+                        .responsive(responsive)
+                        .ui(ui);
+                    });
+                }
+
+                for message in &inbox.messages {
+                    scoped_ui(ui, ("inbox_message", &message.id), |ui| {
+                        render_inbox_message(
+                            ui, message, active, theme, responsive, entity, events,
+                        );
+                    });
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                }
+            });
+
+        if config.manual_dismissal {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+            let back_button = ThemedButton::new(&config.dismiss_button_text, theme)
+                .responsive(responsive)
+                .width(if responsive.is_mobile() { 200.0 } else { 250.0 });
+
+            if ui.add(back_button).clicked() {
+                events.write(InboxEvent::Dismissed { entity });
+            }
+        }
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+    });
+}
+
+fn render_inbox_message(
+    ui: &mut egui::Ui,
+    message: &InboxMessage,
+    active: &mut ActiveInbox,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    events: &mut MessageWriter<InboxEvent>,
+) {
+    let swipe_enabled = active.config.swipe_to_dismiss && responsive.is_mobile();
+    let offset = active
+        .swipe_offsets
+        .get(&message.id)
+        .copied()
+        .unwrap_or(0.0);
+    let width = ui.available_width();
+    // Fade out while swiping
+    let opacity = 1.0 - (offset / width).clamp(0.0, 0.8);
+
+    let stroke = if message.read {
+        egui::Stroke::new(1.0, theme.accent.linear_multiply(0.3))
+    } else {
+        egui::Stroke::new(2.0, theme.primary)
+    };
+    let frame = egui::Frame {
+        inner_margin: egui::Margin::same(if responsive.is_mobile() { 12 } else { 16 }),
+        corner_radius: egui::CornerRadius::same(8),
+        fill: theme.base_200.linear_multiply(opacity),
+        stroke,
+        ..Default::default()
+    };
+
+    let expanded = active.expanded.as_deref() == Some(message.id.as_str());
+
+    let response = ui
+        .horizontal(|ui| {
+            ui.add_space(offset);
+            frame
+                .show(ui, |ui| {
+                    ui.set_width((width - offset - 32.0).max(0.0));
+                    ui.horizontal(|ui| {
+                        if !message.read {
+                            let (dot, _) = ui
+                                .allocate_exact_size(egui::vec2(10.0, 10.0), egui::Sense::hover());
+                            ui.painter().circle_filled(dot.center(), 5.0, theme.primary);
+                        }
+
+                        ResponsiveText::new(
+                            &format!("{} {}", message.kind.icon(), message.title),
+                            ResponsiveFontSize::Large,
+                            if message.read {
+                                theme.base_content
+                            } else {
+                                theme.primary
+                            },
+                        )
+                        .responsive(responsive)
+                        .strong()
+                        .ui(ui);
+
+                        if !swipe_enabled {
+                            ui.with_layout(
+                                egui::Layout::right_to_left(egui::Align::Center),
+                                |ui| {
+                                    if ui
+                                        .add(egui::Button::new("✕").frame(false))
+                                        .on_hover_text("Delete")
+                                        .clicked()
+                                    {
+                                        events.write(InboxEvent::MessageDeleted {
+                                            entity,
+                                            id: message.id.clone(),
+                                        });
+                                    }
+                                },
+                            );
+                        }
+                    });
+
+                    ResponsiveText::new(
+                        &message.formatted_date(),
+                        ResponsiveFontSize::Small,
+                        theme.accent,
+                    )
+                    .responsive(responsive)
+                    .ui(ui);
+
+                    if expanded {
+                        ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                        ResponsiveText::new(
+                            &message.body,
+                            ResponsiveFontSize::Medium,
+                            theme.base_content,
+                        )
+                        .responsive(responsive)
+                        .ui(ui);
+                    }
+                })
+                .response
+        })
+        .inner;
+
+    let sense = if swipe_enabled {
+        egui::Sense::click_and_drag()
+    } else {
+        egui::Sense::click()
+    };
+    let response = response.interact(sense);
+
+    if response.clicked() {
+        active.expanded = if expanded {
+            None
+        } else {
+            Some(message.id.clone())
+        };
+        if !message.read {
+            events.write(InboxEvent::MessageOpened {
+                entity,
+                id: message.id.clone(),
+            });
+        }
+    }
+
+    if swipe_enabled {
+        if response.dragged() {
+            let new_offset = (offset + response.drag_delta().x).max(0.0);
+            active.swipe_offsets.insert(message.id.clone(), new_offset);
+        } else if response.drag_stopped() {
+            // Swiped far enough to the right: delete, otherwise snap back
+            if offset > width * 0.4 {
+                events.write(InboxEvent::MessageDeleted {
+                    entity,
+                    id: message.id.clone(),
+                });
+            }
+            active.swipe_offsets.remove(&message.id);
+        }
+    }
+}
+
+/// Helper trait for easy inbox screen setup
+pub trait InboxScreenExt {
+    /// Add an inbox screen with the given configuration
+    fn spawn_inbox(&mut self, config: InboxConfig) -> Entity;
+
+    /// Add an inbox screen with the default configuration
+    fn spawn_simple_inbox(&mut self) -> Entity;
+}
+
+impl InboxScreenExt for Commands<'_, '_> {
+    fn spawn_inbox(&mut self, config: InboxConfig) -> Entity {
+        self.spawn((Name::new("Inbox Screen"), config)).id()
+    }
+
+    fn spawn_simple_inbox(&mut self) -> Entity {
+        self.spawn_inbox(InboxConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+
+    fn inbox_harness(width: f32, height: f32) -> TestHarness {
+        let mut harness = TestHarness::new()
+            .with_screen_size(width, height)
+            .with_plugins((KonnektorenThemePlugin, UIPlugin, InboxPlugin));
+        for message in [
+            InboxMessage::announcement("welcome", "Welcome", "Have fun learning"),
+            InboxMessage::unlock("a2", "A2 unlocked", "New challenges are waiting"),
+        ] {
+            harness.write_message(AddInboxMessage::silent(message));
+        }
+        harness.world_mut().spawn(InboxConfig::default());
+        harness.run_frames(2);
+        harness
+    }
+
+    #[test]
+    fn test_inbox_mark_read() {
+        let mut harness = inbox_harness(1280.0, 800.0);
+        assert_eq!(harness.world().resource::<Inbox>().unread_count(), 2);
+        assert!(harness.has_widget("Inbox (2)"));
+
+        // Opening a message shows its body and marks it as read
+        harness.click("📢 Welcome");
+        harness.step();
+        assert!(harness.has_widget("Have fun learning"));
+        let inbox = harness.world().resource::<Inbox>();
+        assert!(inbox.get("welcome").unwrap().read);
+        assert_eq!(inbox.unread_count(), 1);
+
+        harness.click("Mark all as read");
+        harness.step();
+        assert_eq!(harness.world().resource::<Inbox>().unread_count(), 0);
+        assert!(harness.has_widget("Inbox"));
+        assert!(!harness.has_widget("Mark all as read"));
+    }
+
+    #[test]
+    fn test_inbox_swipe_to_dismiss() {
+        let mut harness = inbox_harness(400.0, 800.0);
+        let message = harness.widget_rect("🔓 A2 unlocked").unwrap();
+
+        // A short swipe snaps back
+        harness.drag(message.center(), message.center() + egui::vec2(40.0, 0.0));
+        harness.step();
+        assert!(harness.world().resource::<Inbox>().get("a2").is_some());
+
+        // Swiped far enough to the right the message is deleted
+        harness.drag(message.center(), message.center() + egui::vec2(250.0, 0.0));
+        harness.step();
+        let inbox = harness.world().resource::<Inbox>();
+        assert!(inbox.get("a2").is_none());
+        assert!(inbox.get("welcome").is_some());
+    }
+
+    #[cfg(feature = "storage")]
+    #[test]
+    fn test_inbox_storage_round_trip() {
+        let storage = Storage::memory();
+        let mut harness =
+            TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin, InboxPlugin));
+        harness.world_mut().insert_resource(storage.clone());
+        harness
+            .world_mut()
+            .insert_resource(PersistenceScheduler::new(std::time::Duration::ZERO));
+        harness.write_message(AddInboxMessage::silent(InboxMessage::teacher(
+            "homework",
+            "Homework",
+            "Finish the articles challenge",
+        )));
+        harness.run_frames(2);
+
+        harness
+            .world_mut()
+            .resource_scope(|world, mut scheduler: Mut<PersistenceScheduler>| {
+                scheduler.flush(world.resource::<Storage>()).unwrap();
+            });
+
+        // A new app restores the saved messages
+        let mut harness =
+            TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin, InboxPlugin));
+        harness.world_mut().insert_resource(storage);
+        harness.run_frames(1);
+
+        let inbox = harness.world().resource::<Inbox>();
+        let message = inbox.get("homework").unwrap();
+        assert_eq!(message.kind, InboxMessageKind::Teacher);
+        assert_eq!(message.body, "Finish the articles challenge");
+        assert_eq!(inbox.unread_count(), 1);
+    }
+}
@@ -0,0 +1,1046 @@
+use super::{
+    challenge_editor::write_asset_bytes, forward_screen_events, track_active_screen, ScreenAction,
+    ScreenKind, ScreenMessage,
+};
+use crate::{
+    assets::{
+        level_issues, validate_content, ChallengeAsset, ContentIssue, ContentKind,
+        KonnektorenAssetRegistry, LevelAsset,
+    },
+    theme::KonnektorenTheme,
+    ui::{
+        notifications::Notification,
+        responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
+        widgets::{DragSource, DropTarget, ResponsiveText, SpinnerWidget, ThemedButton},
+    },
+};
+use bevy::{
+    asset::LoadState,
+    prelude::*,
+    tasks::{block_on, futures_lite::future, Task},
+};
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+use konnektoren_core::game::{ChallengeConfig, GamePath};
+use std::path::Path;
+
+/// Plugin for the level editor screen
+pub struct LevelEditorPlugin;
+
+impl Plugin for LevelEditorPlugin {
+    fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveLevelEditor>(app);
+        forward_screen_events::<LevelEditorEvent>(app);
+
+        app.add_message::<LevelEditorEvent>()
+            .add_message::<Notification>()
+            .add_systems(
+                Update,
+                (
+                    check_level_editor_config,
+                    load_level_drafts,
+                    start_level_saves,
+                    poll_level_saves,
+                    handle_level_editor_dismissal,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_level_editor_ui.run_if(any_with_component::<ActiveLevelEditor>),
+            );
+    }
+}
+
+/// Configuration for the level editor screen.
+///
+/// Arranges the challenges of the [`KonnektorenAssetRegistry`] into a level:
+/// challenges are dragged from the list of registered challenges into the
+/// level and dragged within it to change their order. The level is saved as
+/// YAML to `path` of the default asset source:
+///
+/// ```ignore
+/// commands.spawn(LevelEditorConfig::new_level("levels/b1.level.yml"));
+/// ```
+#[derive(Component, Clone)]
+pub struct LevelEditorConfig {
+    pub title: String,
+    /// Asset path (`*.level.yml`) the level is loaded from and saved to
+    pub path: String,
+    /// Level to edit instead of loading `path`
+    pub level: Option<GamePath>,
+    pub save_button_text: String,
+    pub dismiss_button_text: String,
+    /// Allow manual dismissal (back button/escape)
+    pub manual_dismissal: bool,
+}
+
+impl LevelEditorConfig {
+    /// Edit the level at an asset path
+    pub fn open(path: impl Into<String>) -> Self {
+        Self {
+            title: "Level Editor".to_string(),
+            path: path.into(),
+            level: None,
+            save_button_text: "Save".to_string(),
+            dismiss_button_text: "← Back".to_string(),
+            manual_dismissal: true,
+        }
+    }
+
+    /// Compose a new level, saved to an asset path
+    pub fn new_level(path: impl Into<String>) -> Self {
+        let path = path.into();
+        let level = blank_level(&path);
+        Self::open(path).with_level(level)
+    }
+
+    /// Edit a level that is already in memory
+    pub fn with_level(mut self, level: GamePath) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn with_save_button_text(mut self, text: impl Into<String>) -> Self {
+        self.save_button_text = text.into();
+        self
+    }
+
+    pub fn with_dismiss_button_text(mut self, text: impl Into<String>) -> Self {
+        self.dismiss_button_text = text.into();
+        self
+    }
+
+    pub fn with_manual_dismissal(mut self, manual_dismissal: bool) -> Self {
+        self.manual_dismissal = manual_dismissal;
+        self
+    }
+}
+
+/// Component marking an active level editor screen
+#[derive(Component)]
+pub struct ActiveLevelEditor {
+    config: LevelEditorConfig,
+    handle: Option<Handle<LevelAsset>>,
+    draft: Option<GamePath>,
+    /// Why the level at the path could not be loaded
+    load_error: Option<String>,
+    selected: Option<usize>,
+    issues: Vec<ContentIssue>,
+    /// Edited since the last save
+    dirty: bool,
+    saving: Option<Task<Result<(), String>>>,
+}
+
+impl ActiveLevelEditor {
+    /// The level as currently edited
+    pub fn draft(&self) -> Option<&GamePath> {
+        self.draft.as_ref()
+    }
+
+    /// Problems that keep the draft from being saved
+    pub fn issues(&self) -> &[ContentIssue] {
+        &self.issues
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn set_draft(&mut self, draft: GamePath) {
+        self.draft = Some(draft);
+        self.validate();
+    }
+
+    fn validate(&mut self) {
+        self.issues = self
+            .draft
+            .as_ref()
+            .map(level_draft_issues)
+            .unwrap_or_default();
+    }
+}
+
+/// Events for level editor interactions
+#[derive(Message, Debug, Clone)]
+pub enum LevelEditorEvent {
+    /// Saving the draft was requested
+    Save { entity: Entity, path: String },
+    /// The draft was written to its asset path
+    Saved { entity: Entity, path: String },
+    /// Writing the draft failed
+    SaveFailed {
+        entity: Entity,
+        path: String,
+        error: String,
+    },
+    /// Level editor screen dismissed
+    Dismissed { entity: Entity },
+}
+
+impl ScreenMessage for LevelEditorEvent {
+    const KIND: ScreenKind = ScreenKind::LevelEditor;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::Save { entity, .. } => Some((ScreenAction::Submitted, *entity)),
+            Self::Saved { entity, .. } | Self::SaveFailed { entity, .. } => {
+                Some((ScreenAction::Changed, *entity))
+            }
+            Self::Dismissed { entity } => Some((ScreenAction::Dismissed, *entity)),
+        }
+    }
+}
+
+/// What is dragged in the level editor
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LevelEditorDrag {
+    /// A challenge of the level, by its index
+    Entry(usize),
+    /// A registered challenge, by its ID
+    Challenge(String),
+}
+
+/// Empty level to start composing from, named after its file
+fn blank_level(path: &str) -> GamePath {
+    let file_name = Path::new(path)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("level");
+    let id = file_name.split('.').next().unwrap_or(file_name).to_string();
+    GamePath {
+        name: id.clone(),
+        id,
+        challenges: vec![],
+        map: None,
+    }
+}
+
+/// Problems of an edited level.
+///
+/// The rules of the asset loader, checked on the serialized draft, so a level
+/// without issues loads.
+pub fn level_draft_issues(level: &GamePath) -> Vec<ContentIssue> {
+    let mut issues = level_issues(level);
+    match serde_yaml::to_string(level) {
+        Ok(yaml) => {
+            for issue in validate_content(ContentKind::Level, yaml.as_bytes()) {
+                if !issues.contains(&issue) {
+                    issues.push(issue);
+                }
+            }
+        }
+        Err(error) => issues.push(ContentIssue::new(error.to_string())),
+    }
+    issues
+}
+
+/// Move the challenge at `from` in front of the one at `to`, or to the end
+/// for `to == challenges.len()`. Returns the new index of the moved challenge.
+pub fn move_level_challenge(
+    challenges: &mut Vec<ChallengeConfig>,
+    from: usize,
+    to: usize,
+) -> usize {
+    if from >= challenges.len() {
+        return from;
+    }
+    let entry = challenges.remove(from);
+    let to = if from < to { to - 1 } else { to }.min(challenges.len());
+    challenges.insert(to, entry);
+    to
+}
+
+/// New level entry for a registered challenge, with an ID unique in the level
+fn level_entry(level: &GamePath, challenge_id: &str, name: &str) -> ChallengeConfig {
+    let id = (1..)
+        .map(|n| format!("{}-{}", challenge_id, n))
+        .find(|id| level.challenges.iter().all(|entry| &entry.id != id))
+        .unwrap_or_else(|| challenge_id.to_string());
+    ChallengeConfig {
+        id,
+        name: name.to_string(),
+        description: String::new(),
+        challenge: challenge_id.to_string(),
+        variant: None,
+        tasks: 10,
+        unlock_points: level
+            .challenges
+            .last()
+            .map(|entry| entry.unlock_points)
+            .unwrap_or(0),
+        position: None,
+    }
+}
+
+/// System to check for new level editor configurations and set them up
+#[allow(clippy::type_complexity)]
+fn check_level_editor_config(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &LevelEditorConfig),
+        (Without<ActiveLevelEditor>, Changed<LevelEditorConfig>),
+    >,
+    existing: Query<Entity, With<ActiveLevelEditor>>,
+) {
+    for (entity, config) in query.iter() {
+        info!("Setting up level editor for entity {:?}", entity);
+
+        // Clean up any existing level editors first
+        for existing_entity in existing.iter() {
+            commands
+                .entity(existing_entity)
+                .remove::<ActiveLevelEditor>();
+        }
+
+        let mut active = ActiveLevelEditor {
+            config: config.clone(),
+            handle: None,
+            draft: None,
+            load_error: None,
+            selected: None,
+            issues: vec![],
+            dirty: false,
+            saving: None,
+        };
+        if let Some(level) = config.level.clone() {
+            active.set_draft(level);
+        }
+        commands.entity(entity).insert(active);
+    }
+}
+
+/// System to load the levels to edit into drafts
+fn load_level_drafts(
+    asset_server: Res<AssetServer>,
+    levels: Option<Res<Assets<LevelAsset>>>,
+    mut query: Query<&mut ActiveLevelEditor>,
+) {
+    // Levels are registered by the KonnektorenAssetsPlugin
+    let Some(levels) = levels else {
+        return;
+    };
+
+    for mut active in query.iter_mut() {
+        if active.draft.is_some() {
+            continue;
+        }
+
+        let Some(handle) = active.handle.clone() else {
+            info!("Loading level to edit: {}", active.config.path);
+            active.handle = Some(asset_server.load(active.config.path.clone()));
+            continue;
+        };
+
+        if let Some(level) = levels.get(&handle) {
+            let draft = level.game_path.clone();
+            active.set_draft(draft);
+        } else if let LoadState::Failed(error) = asset_server.load_state(&handle) {
+            warn!("Could not load level {}: {}", active.config.path, error);
+            active.load_error = Some(error.to_string());
+            let draft = blank_level(&active.config.path);
+            active.set_draft(draft);
+        }
+    }
+}
+
+/// System to save drafts requested by the editor
+fn start_level_saves(
+    mut events: MessageReader<LevelEditorEvent>,
+    asset_server: Res<AssetServer>,
+    mut query: Query<&mut ActiveLevelEditor>,
+) {
+    for event in events.read() {
+        let LevelEditorEvent::Save { entity, path } = event else {
+            continue;
+        };
+
+        let Ok(mut active) = query.get_mut(*entity) else {
+            continue;
+        };
+
+        if active.saving.is_some() {
+            continue;
+        }
+
+        let Some(yaml) = active
+            .draft
+            .as_ref()
+            .and_then(|draft| serde_yaml::to_string(draft).ok())
+        else {
+            continue;
+        };
+
+        info!("Saving level to {}", path);
+        active.saving = Some(write_asset_bytes(
+            &asset_server,
+            path.clone(),
+            yaml.into_bytes(),
+        ));
+    }
+}
+
+/// System to poll running saves
+fn poll_level_saves(
+    mut query: Query<(Entity, &mut ActiveLevelEditor)>,
+    mut events: MessageWriter<LevelEditorEvent>,
+    mut notifications: MessageWriter<Notification>,
+) {
+    for (entity, mut active) in query.iter_mut() {
+        let Some(task) = active.saving.as_mut() else {
+            continue;
+        };
+
+        let Some(result) = block_on(future::poll_once(task)) else {
+            continue;
+        };
+
+        active.saving = None;
+        let path = active.config.path.clone();
+
+        match result {
+            Ok(()) => {
+                notifications.write(Notification::success("Level saved", path.clone()));
+                active.dirty = false;
+                active.load_error = None;
+                events.write(LevelEditorEvent::Saved { entity, path });
+            }
+            Err(error) => {
+                warn!("Saving level to {} failed: {}", path, error);
+                notifications.write(Notification::error("Level not saved", error.clone()));
+                events.write(LevelEditorEvent::SaveFailed {
+                    entity,
+                    path,
+                    error,
+                });
+            }
+        }
+    }
+}
+
+/// System to close dismissed level editors
+fn handle_level_editor_dismissal(
+    mut commands: Commands,
+    mut events: MessageReader<LevelEditorEvent>,
+) {
+    for event in events.read() {
+        if let LevelEditorEvent::Dismissed { entity } = event {
+            info!("Dismissing level editor for entity {:?}", entity);
+            commands.entity(*entity).remove::<ActiveLevelEditor>();
+        }
+    }
+}
+
+/// System to render the level editor UI
+#[allow(clippy::too_many_arguments)]
+fn render_level_editor_ui(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    registry: Option<Res<KonnektorenAssetRegistry>>,
+    challenges: Option<Res<Assets<ChallengeAsset>>>,
+    mut query: Query<(Entity, &mut ActiveLevelEditor)>,
+    mut events: MessageWriter<LevelEditorEvent>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    if query.is_empty() {
+        return;
+    }
+
+    // Registered challenges with their names, once loaded
+    let mut available: Vec<(String, String)> = registry
+        .iter()
+        .flat_map(|registry| registry.challenges.iter())
+        .map(|(id, handle)| {
+            let name = challenges
+                .as_ref()
+                .and_then(|challenges| challenges.get(handle))
+                .map_or_else(|| id.clone(), |challenge| challenge.name().to_string());
+            (id.clone(), name)
+        })
+        .collect();
+    available.sort();
+
+    if let Ok(ctx) = contexts.ctx_mut() {
+        // Only render the first (most recent) editor to avoid widget ID conflicts
+        if let Some((entity, mut active)) = query.iter_mut().next() {
+            if active.config.manual_dismissal && input.just_pressed(KeyCode::Escape) {
+                events.write(LevelEditorEvent::Dismissed { entity });
+                return;
+            }
+
+            egui::CentralPanel::default()
+                .frame(egui::Frame::NONE.fill(theme.base_100))
+                .show(ctx, |ui| {
+                    scoped_ui(ui, (entity, "level_editor"), |ui| {
+                        render_level_editor_content(
+                            ui,
+                            &mut active,
+                            &available,
+                            &theme,
+                            &responsive,
+                            entity,
+                            &mut events,
+                        );
+                    })
+                });
+        }
+    }
+}
+
+/// Render level editor content
+fn render_level_editor_content(
+    ui: &mut egui::Ui,
+    active: &mut ActiveLevelEditor,
+    available: &[(String, String)],
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    events: &mut MessageWriter<LevelEditorEvent>,
+) {
+    let config = active.config.clone();
+
+    ui.vertical_centered(|ui| {
+        let max_width = if responsive.is_mobile() {
+            ui.available_width() * 0.95
+        } else {
+            900.0_f32.min(ui.available_width() * 0.9)
+        };
+
+        ui.set_max_width(max_width);
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+        ResponsiveText::new(&config.title, ResponsiveFontSize::Title, theme.primary)
+            .responsive(responsive)
+            .strong()
+            .ui(ui);
+        ResponsiveText::new(
+            &format!("{}{}", config.path, if active.dirty { " •" } else { "" }),
+            ResponsiveFontSize::Small,
+            theme.base_content.gamma_multiply(0.7),
+        )
+        .responsive(responsive)
+        .ui(ui);
+
+        if active.draft.is_none() {
+            // Level still loading
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+            SpinnerWidget::new(theme, 48.0)
+                .responsive(responsive)
+                .ui(ui);
+            return;
+        }
+
+        if let Some(error) = &active.load_error {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+            ResponsiveText::new(
+                &format!("Could not load the level ({error}), saving replaces the file"),
+                ResponsiveFontSize::Small,
+                theme.warning,
+            )
+            .responsive(responsive)
+            .ui(ui);
+        }
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+        let scroll_height = ui.available_height() - 200.0;
+        let mut changed = false;
+        egui::ScrollArea::vertical()
+            .id_salt("level_editor_scroll")
+            .max_height(scroll_height)
+            .auto_shrink([false; 2])
+            .show(ui, |ui| {
+                let ActiveLevelEditor {
+                    draft, selected, ..
+                } = &mut *active;
+                if let Some(level) = draft {
+                    changed =
+                        render_level_fields(ui, level, selected, available, theme, responsive);
+                }
+            });
+
+        if changed {
+            active.dirty = true;
+            active.validate();
+        }
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+        render_issues(ui, &active.issues, theme, responsive);
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+        let can_save = active.issues.is_empty() && active.saving.is_none();
+        let button_width = if responsive.is_mobile() { 200.0 } else { 250.0 };
+        ui.horizontal(|ui| {
+            let save_button = ThemedButton::new(&config.save_button_text, theme)
+                .responsive(responsive)
+                .width(button_width)
+                .enabled(can_save);
+            if ui.add(save_button).clicked() {
+                events.write(LevelEditorEvent::Save {
+                    entity,
+                    path: config.path.clone(),
+                });
+            }
+
+            if active.saving.is_some() {
+                SpinnerWidget::new(theme, 24.0)
+                    .responsive(responsive)
+                    .ui(ui);
+            }
+
+            if config.manual_dismissal {
+                let back_button = ThemedButton::new(&config.dismiss_button_text, theme)
+                    .responsive(responsive)
+                    .width(button_width);
+                if ui.add(back_button).clicked() {
+                    events.write(LevelEditorEvent::Dismissed { entity });
+                }
+            }
+        });
+    });
+}
+
+/// Render the level, its challenges and the registered challenges, returns
+/// whether anything changed
+fn render_level_fields(
+    ui: &mut egui::Ui,
+    level: &mut GamePath,
+    selected: &mut Option<usize>,
+    available: &[(String, String)],
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+) -> bool {
+    let mut changed = false;
+    let font_size = responsive.font_size(ResponsiveFontSize::Medium);
+    let field_width = ui.available_width() * 0.7;
+
+    egui::Grid::new("level_fields")
+        .num_columns(2)
+        .spacing([12.0, 8.0])
+        .show(ui, |ui| {
+            for (label, value) in [("ID", &mut level.id), ("Name", &mut level.name)] {
+                ui.label(
+                    egui::RichText::new(label)
+                        .size(font_size)
+                        .color(theme.base_content),
+                );
+                changed |= ui
+                    .add(
+                        egui::TextEdit::singleline(value)
+                            .font(egui::FontId::proportional(font_size))
+                            .desired_width(field_width),
+                    )
+                    .changed();
+                ui.end_row();
+            }
+        });
+
+    ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+    section_heading(ui, "Challenges", theme, responsive);
+    if available.is_empty() {
+        ResponsiveText::new(
+            "No challenges registered",
+            ResponsiveFontSize::Small,
+            theme.base_content.gamma_multiply(0.7),
+        )
+        .responsive(responsive)
+        .ui(ui);
+    }
+    ui.horizontal_wrapped(|ui| {
+        for (id, name) in available {
+            DragSource::new(
+                ("level_editor_challenge", id),
+                LevelEditorDrag::Challenge(id.clone()),
+                theme,
+            )
+            .show(ui, |ui| {
+                egui::Frame::NONE
+                    .fill(theme.base_200)
+                    .corner_radius(theme.radius)
+                    .inner_margin(egui::Margin::symmetric(10, 6))
+                    .show(ui, |ui| {
+                        ui.label(
+                            egui::RichText::new(name)
+                                .size(font_size)
+                                .color(theme.base_content),
+                        )
+                    });
+            });
+        }
+    });
+
+    ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+    section_heading(ui, "Level", theme, responsive);
+
+    let mut dropped = None;
+    for (index, entry) in level.challenges.iter().enumerate() {
+        scoped_ui(ui, ("level_entry", index), |ui| {
+            let (_, payload) = DropTarget::<LevelEditorDrag>::new(theme).show(ui, |ui| {
+                ui.set_min_width(ui.available_width());
+                DragSource::new(
+                    ("level_editor_entry", index),
+                    LevelEditorDrag::Entry(index),
+                    theme,
+                )
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            egui::RichText::new("≡")
+                                .size(font_size)
+                                .color(theme.base_content.gamma_multiply(0.5)),
+                        );
+                        let label = format!("{}. {}", index + 1, entry.name);
+                        if ui
+                            .selectable_label(
+                                *selected == Some(index),
+                                egui::RichText::new(label).size(font_size),
+                            )
+                            .clicked()
+                        {
+                            *selected = Some(index);
+                        }
+                        let known = available.iter().any(|(id, _)| *id == entry.challenge);
+                        ui.label(
+                            egui::RichText::new(if known {
+                                entry.challenge.clone()
+                            } else {
+                                format!("{} (not registered)", entry.challenge)
+                            })
+                            .size(font_size * 0.8)
+                            .color(if known {
+                                theme.base_content.gamma_multiply(0.7)
+                            } else {
+                                theme.warning
+                            }),
+                        );
+                        if entry.unlock_points > 0 {
+                            ui.label(
+                                egui::RichText::new(format!("🔒 {}", entry.unlock_points))
+                                    .size(font_size * 0.8)
+                                    .color(theme.accent),
+                            );
+                        }
+                    });
+                });
+            });
+            if let Some(payload) = payload {
+                dropped = Some((index, payload));
+            }
+        });
+    }
+
+    let (_, payload) = DropTarget::<LevelEditorDrag>::new(theme)
+        .min_size(egui::vec2(ui.available_width(), 40.0))
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new("Drop a challenge here")
+                    .size(font_size)
+                    .color(theme.base_content.gamma_multiply(0.6)),
+            );
+        });
+    if let Some(payload) = payload {
+        dropped = Some((level.challenges.len(), payload));
+    }
+
+    if let Some((to, payload)) = dropped {
+        match payload.as_ref() {
+            LevelEditorDrag::Entry(from) => {
+                let moved = move_level_challenge(&mut level.challenges, *from, to);
+                *selected = Some(moved);
+            }
+            LevelEditorDrag::Challenge(challenge_id) => {
+                let name = available
+                    .iter()
+                    .find(|(id, _)| id == challenge_id)
+                    .map_or(challenge_id.as_str(), |(_, name)| name.as_str());
+                let entry = level_entry(level, challenge_id, name);
+                let to = to.min(level.challenges.len());
+                level.challenges.insert(to, entry);
+                *selected = Some(to);
+            }
+        }
+        changed = true;
+    }
+
+    let mut remove = false;
+    if let Some(entry) = selected.and_then(|index| level.challenges.get_mut(index)) {
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+        scoped_ui(ui, ("level_entry_fields", *selected), |ui| {
+            changed |= render_entry_fields(ui, entry, available, theme, responsive);
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+            remove = ui
+                .button(egui::RichText::new("Remove from level").color(theme.error))
+                .clicked();
+        });
+    }
+    if remove {
+        if let Some(index) = selected.take() {
+            level.challenges.remove(index);
+            changed = true;
+        }
+    }
+
+    changed
+}
+
+/// Render the fields of a challenge in the level, returns whether anything changed
+fn render_entry_fields(
+    ui: &mut egui::Ui,
+    entry: &mut ChallengeConfig,
+    available: &[(String, String)],
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+) -> bool {
+    let mut changed = false;
+    let font_size = responsive.font_size(ResponsiveFontSize::Medium);
+    let field_width = ui.available_width() * 0.7;
+    let label = |ui: &mut egui::Ui, text: &str| {
+        ui.label(
+            egui::RichText::new(text)
+                .size(font_size)
+                .color(theme.base_content),
+        );
+    };
+
+    egui::Grid::new("level_entry_grid")
+        .num_columns(2)
+        .spacing([12.0, 8.0])
+        .show(ui, |ui| {
+            for (text, value) in [("ID", &mut entry.id), ("Name", &mut entry.name)] {
+                label(ui, text);
+                changed |= ui
+                    .add(
+                        egui::TextEdit::singleline(value)
+                            .font(egui::FontId::proportional(font_size))
+                            .desired_width(field_width),
+                    )
+                    .changed();
+                ui.end_row();
+            }
+
+            label(ui, "Description");
+            changed |= ui
+                .add(
+                    egui::TextEdit::multiline(&mut entry.description)
+                        .font(egui::FontId::proportional(font_size))
+                        .desired_rows(2)
+                        .desired_width(field_width),
+                )
+                .changed();
+            ui.end_row();
+
+            label(ui, "Challenge");
+            egui::ComboBox::from_id_salt("level_entry_challenge")
+                .selected_text(&entry.challenge)
+                .width(field_width)
+                .show_ui(ui, |ui| {
+                    for (id, name) in available {
+                        changed |= ui
+                            .selectable_value(&mut entry.challenge, id.clone(), name)
+                            .changed();
+                    }
+                });
+            ui.end_row();
+
+            label(ui, "Variant");
+            let mut variant = entry.variant.clone().unwrap_or_default();
+            if ui
+                .add(
+                    egui::TextEdit::singleline(&mut variant)
+                        .hint_text("Default")
+                        .font(egui::FontId::proportional(font_size))
+                        .desired_width(field_width),
+                )
+                .changed()
+            {
+                entry.variant = (!variant.trim().is_empty()).then_some(variant);
+                changed = true;
+            }
+            ui.end_row();
+
+            label(ui, "Tasks");
+            changed |= ui
+                .add(egui::DragValue::new(&mut entry.tasks).range(1..=100))
+                .changed();
+            ui.end_row();
+
+            label(ui, "Points to unlock");
+            changed |= ui
+                .add(egui::DragValue::new(&mut entry.unlock_points).range(0..=10_000))
+                .changed();
+            ui.end_row();
+
+            label(ui, "Map position");
+            ui.horizontal(|ui| {
+                let mut on_map = entry.position.is_some();
+                if ui.checkbox(&mut on_map, "").changed() {
+                    entry.position = on_map.then_some((0, 0));
+                    changed = true;
+                }
+                if let Some((x, y)) = entry.position.as_mut() {
+                    changed |= ui.add(egui::DragValue::new(x).prefix("x ")).changed();
+                    changed |= ui.add(egui::DragValue::new(y).prefix("y ")).changed();
+                }
+            });
+            ui.end_row();
+        });
+
+    changed
+}
+
+fn section_heading(
+    ui: &mut egui::Ui,
+    text: &str,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+) {
+    ResponsiveText::new(text, ResponsiveFontSize::Large, theme.secondary)
+        .responsive(responsive)
+        .strong()
+        .ui(ui);
+    ui.add_space(responsive.spacing(ResponsiveSpacing::XSmall));
+}
+
+/// Render the live validation result
+fn render_issues(
+    ui: &mut egui::Ui,
+    issues: &[ContentIssue],
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+) {
+    if issues.is_empty() {
+        ResponsiveText::new("✔ Ready to save", ResponsiveFontSize::Small, theme.success)
+            .responsive(responsive)
+            .ui(ui);
+        return;
+    }
+
+    for issue in issues {
+        ResponsiveText::new(
+            &format!("⚠ {}", issue),
+            ResponsiveFontSize::Small,
+            theme.error,
+        )
+        .responsive(responsive)
+        .ui(ui);
+    }
+}
+
+/// Helper trait for easy level editor screen setup
+pub trait LevelEditorScreenExt {
+    /// Add a level editor screen with the given configuration
+    fn spawn_level_editor(&mut self, config: LevelEditorConfig) -> Entity;
+}
+
+impl LevelEditorScreenExt for Commands<'_, '_> {
+    fn spawn_level_editor(&mut self, config: LevelEditorConfig) -> Entity {
+        self.spawn((Name::new("Level Editor Screen"), config)).id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+    use konnektoren_core::challenges::{
+        challenge_type::ChallengeType, multiple_choice::MultipleChoice,
+    };
+
+    fn challenge(id: &str, name: &str) -> ChallengeAsset {
+        ChallengeAsset {
+            challenge_type: ChallengeType::MultipleChoice(MultipleChoice {
+                id: id.to_string(),
+                name: name.to_string(),
+                lang: "de".to_string(),
+                options: vec![],
+                questions: vec![],
+            }),
+            file_path: format!("challenges/{}.yml", id),
+        }
+    }
+
+    #[test]
+    fn test_move_level_challenge() {
+        let level = blank_level("levels/b1.level.yml");
+        assert_eq!(level.id, "b1");
+        let mut challenges: Vec<_> = ["a", "b", "c"]
+            .iter()
+            .map(|id| level_entry(&level, id, id))
+            .collect();
+        let order = |challenges: &[ChallengeConfig]| {
+            challenges
+                .iter()
+                .map(|entry| entry.challenge.clone())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(move_level_challenge(&mut challenges, 2, 0), 0);
+        assert_eq!(order(&challenges), ["c", "a", "b"]);
+        assert_eq!(move_level_challenge(&mut challenges, 0, 3), 2);
+        assert_eq!(order(&challenges), ["a", "b", "c"]);
+        assert_eq!(move_level_challenge(&mut challenges, 0, 2), 1);
+        assert_eq!(order(&challenges), ["b", "a", "c"]);
+    }
+
+    #[test]
+    fn test_compose_level_by_dragging() {
+        let mut harness =
+            TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin, LevelEditorPlugin));
+        let app = harness.app_mut();
+        app.init_asset::<ChallengeAsset>()
+            .init_resource::<KonnektorenAssetRegistry>();
+        let mut registry = KonnektorenAssetRegistry::default();
+        for (id, name) in [("articles", "Artikel"), ("colors", "Farben")] {
+            let handle = app
+                .world_mut()
+                .resource_mut::<Assets<ChallengeAsset>>()
+                .add(challenge(id, name));
+            registry.register_challenge(id.to_string(), handle);
+        }
+        app.insert_resource(registry);
+
+        let entity = harness
+            .world_mut()
+            .spawn(LevelEditorConfig::new_level("levels/b1.level.yml"))
+            .id();
+        harness.run_frames(2);
+
+        for name in ["Artikel", "Farben"] {
+            let source = harness.widget_rect(name).expect("challenge shown");
+            let target = harness
+                .widget_rect("Drop a challenge here")
+                .expect("drop target shown");
+            harness.drag(source.center(), target.center());
+            harness.run_frames(1);
+        }
+
+        // Drag the second challenge in front of the first
+        let source = harness.widget_rect("2. Farben").expect("entry shown");
+        let target = harness.widget_rect("1. Artikel").expect("entry shown");
+        harness.drag(source.center(), target.center());
+        harness.run_frames(1);
+
+        let active = harness
+            .world()
+            .get::<ActiveLevelEditor>(entity)
+            .expect("editor is active");
+        let level = active.draft().expect("level draft");
+        let order: Vec<_> = level
+            .challenges
+            .iter()
+            .map(|entry| entry.id.as_str())
+            .collect();
+        assert_eq!(order, ["colors-1", "articles-1"]);
+        assert!(active.is_dirty());
+        assert!(active.issues().is_empty());
+    }
+}
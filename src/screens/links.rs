@@ -0,0 +1,69 @@
+#[cfg(feature = "settings")]
+use crate::settings::ParentalControls;
+use bevy::{ecs::system::SystemParam, prelude::*};
+#[cfg(not(feature = "settings"))]
+use std::marker::PhantomData;
+
+/// System parameter opening external links in the browser or the default
+/// application. Links are not opened while the [`ParentalControls`] block
+/// them.
+#[derive(SystemParam)]
+pub struct LinkOpener<'w> {
+    #[cfg(feature = "settings")]
+    parental_controls: Option<Res<'w, ParentalControls>>,
+    #[cfg(not(feature = "settings"))]
+    marker: PhantomData<&'w ()>,
+}
+
+impl LinkOpener<'_> {
+    /// Whether external links can be opened
+    pub fn allowed(&self) -> bool {
+        #[cfg(feature = "settings")]
+        {
+            self.parental_controls
+                .as_ref()
+                .is_none_or(|controls| controls.external_links_allowed())
+        }
+        #[cfg(not(feature = "settings"))]
+        {
+            true
+        }
+    }
+
+    /// Open a URL, returns `false` if it was blocked
+    pub fn open(&self, url: &str) -> bool {
+        if !self.allowed() {
+            info!("Opening {} is blocked by the parental controls", url);
+            return false;
+        }
+        open_url(url);
+        true
+    }
+}
+
+fn open_url(url: &str) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use wasm_bindgen::prelude::*;
+
+        #[wasm_bindgen]
+        extern "C" {
+            #[wasm_bindgen(js_namespace = ["window"], js_name = open)]
+            fn window_open(url: &str);
+        }
+
+        window_open(url);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        if let Err(e) = std::process::Command::new("xdg-open")
+            .arg(url)
+            .spawn()
+            .or_else(|_| std::process::Command::new("open").arg(url).spawn())
+            .or_else(|_| std::process::Command::new("start").arg(url).spawn())
+        {
+            error!("Failed to open URL: {}", e);
+        }
+    }
+}
@@ -0,0 +1,594 @@
+use super::{forward_screen_events, track_active_screen, ScreenAction, ScreenKind, ScreenMessage};
+use crate::{
+    theme::KonnektorenTheme,
+    ui::{
+        responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
+        texture_cache::{
+            release_texture_refs_on_removal, EguiTextureCache, EguiTextureCachePlugin,
+            EguiTextureRefs,
+        },
+        widgets::{AvatarSize, AvatarWidget, ResponsiveText, ThemedButton},
+    },
+};
+use bevy::prelude::*;
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+
+/// Plugin for the multiplayer lobby screen.
+///
+/// The screen shows the [`LobbyState`], which the game's networking layer
+/// keeps up to date. Player actions are sent as [`LobbyEvent`]s for the
+/// networking layer to forward, the screen doesn't change the state itself.
+pub struct LobbyPlugin;
+
+impl Plugin for LobbyPlugin {
+    fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveLobby>(app);
+        forward_screen_events::<LobbyEvent>(app);
+        if !app.is_plugin_added::<EguiTextureCachePlugin>() {
+            app.add_plugins(EguiTextureCachePlugin);
+        }
+
+        app.add_message::<LobbyEvent>()
+            .init_resource::<LobbyState>()
+            .add_systems(
+                Update,
+                (
+                    check_lobby_config,
+                    load_lobby_avatars,
+                    handle_lobby_completion,
+                    release_texture_refs_on_removal::<ActiveLobby>,
+                ),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_lobby_ui.run_if(any_with_component::<ActiveLobby>),
+            );
+    }
+}
+
+/// A player in the lobby
+#[derive(Debug, Clone, PartialEq)]
+pub struct LobbyPlayer {
+    pub id: String,
+    pub name: String,
+    /// Asset path of the avatar image, the initials are shown without
+    pub avatar: Option<String>,
+    pub ready: bool,
+    pub is_host: bool,
+}
+
+impl LobbyPlayer {
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            avatar: None,
+            ready: false,
+            is_host: false,
+        }
+    }
+
+    pub fn with_avatar(mut self, avatar: impl Into<String>) -> Self {
+        self.avatar = Some(avatar.into());
+        self
+    }
+
+    pub fn with_ready(mut self, ready: bool) -> Self {
+        self.ready = ready;
+        self
+    }
+
+    pub fn host(mut self) -> Self {
+        self.is_host = true;
+        self
+    }
+}
+
+/// Resource with the state of the lobby, populated by the networking layer
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct LobbyState {
+    /// Code other players join the room with
+    pub room_code: Option<String>,
+    pub players: Vec<LobbyPlayer>,
+    /// Id of the player on this device
+    pub local_player_id: Option<String>,
+    pub min_players: usize,
+    pub max_players: Option<usize>,
+}
+
+impl Default for LobbyState {
+    fn default() -> Self {
+        Self {
+            room_code: None,
+            players: vec![],
+            local_player_id: None,
+            min_players: 2,
+            max_players: None,
+        }
+    }
+}
+
+impl LobbyState {
+    pub fn player(&self, id: &str) -> Option<&LobbyPlayer> {
+        self.players.iter().find(|player| player.id == id)
+    }
+
+    /// The player on this device
+    pub fn local_player(&self) -> Option<&LobbyPlayer> {
+        self.player(self.local_player_id.as_deref()?)
+    }
+
+    /// Whether the player on this device hosts the room
+    pub fn is_local_host(&self) -> bool {
+        self.local_player().is_some_and(|player| player.is_host)
+    }
+
+    /// Players that are ready, the host counts as ready
+    pub fn ready_count(&self) -> usize {
+        self.players
+            .iter()
+            .filter(|player| player.ready || player.is_host)
+            .count()
+    }
+
+    /// Whether enough players are in the room and all of them are ready
+    pub fn can_start(&self) -> bool {
+        self.players.len() >= self.min_players && self.ready_count() == self.players.len()
+    }
+}
+
+/// Configuration for the lobby screen
+#[derive(Component, Clone)]
+pub struct LobbyConfig {
+    pub title: String,
+    pub start_button_text: String,
+    pub dismiss_button_text: String,
+    /// Allow manual dismissal (leave button/escape)
+    pub manual_dismissal: bool,
+}
+
+impl Default for LobbyConfig {
+    fn default() -> Self {
+        Self {
+            title: "Lobby".to_string(),
+            start_button_text: "Start game".to_string(),
+            dismiss_button_text: "Leave".to_string(),
+            manual_dismissal: true,
+        }
+    }
+}
+
+impl LobbyConfig {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..default()
+        }
+    }
+
+    pub fn with_start_button_text(mut self, text: impl Into<String>) -> Self {
+        self.start_button_text = text.into();
+        self
+    }
+
+    pub fn with_dismiss_button_text(mut self, text: impl Into<String>) -> Self {
+        self.dismiss_button_text = text.into();
+        self
+    }
+
+    pub fn with_manual_dismissal(mut self, manual_dismissal: bool) -> Self {
+        self.manual_dismissal = manual_dismissal;
+        self
+    }
+}
+
+/// Component marking an active lobby screen
+#[derive(Component)]
+pub struct ActiveLobby {
+    config: LobbyConfig,
+}
+
+/// Events for lobby interactions, for the networking layer to act on
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub enum LobbyEvent {
+    /// The local player is ready
+    Ready { entity: Entity, player_id: String },
+    /// The local player is no longer ready
+    Unready { entity: Entity, player_id: String },
+    /// The host removes a player from the room
+    Kick { entity: Entity, player_id: String },
+    /// The host starts the game
+    Start { entity: Entity },
+    /// Lobby screen dismissed, i.e. the local player leaves
+    Dismissed { entity: Entity },
+}
+
+impl ScreenMessage for LobbyEvent {
+    const KIND: ScreenKind = ScreenKind::Lobby;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::Ready { entity, .. }
+            | Self::Unready { entity, .. }
+            | Self::Kick { entity, .. } => Some((ScreenAction::Changed, *entity)),
+            Self::Start { entity } => Some((ScreenAction::Submitted, *entity)),
+            Self::Dismissed { entity } => Some((ScreenAction::Dismissed, *entity)),
+        }
+    }
+}
+
+/// System to check for new lobby configurations and set them up
+#[allow(clippy::type_complexity)]
+fn check_lobby_config(
+    mut commands: Commands,
+    query: Query<(Entity, &LobbyConfig), (Without<ActiveLobby>, Changed<LobbyConfig>)>,
+    existing: Query<Entity, With<ActiveLobby>>,
+) {
+    for (entity, config) in query.iter() {
+        info!("Setting up lobby screen for entity {:?}", entity);
+
+        for existing_entity in existing.iter() {
+            commands.entity(existing_entity).remove::<ActiveLobby>();
+        }
+
+        commands.entity(entity).insert(ActiveLobby {
+            config: config.clone(),
+        });
+    }
+}
+
+/// System to load the avatars of the players through the shared texture cache
+fn load_lobby_avatars(
+    mut commands: Commands,
+    lobby: Res<LobbyState>,
+    asset_server: Res<AssetServer>,
+    mut texture_cache: ResMut<EguiTextureCache>,
+    mut query: Query<(Entity, Option<&mut EguiTextureRefs>), With<ActiveLobby>>,
+) {
+    let avatars = || {
+        lobby
+            .players
+            .iter()
+            .filter_map(|player| player.avatar.as_deref())
+    };
+    for (entity, refs) in query.iter_mut() {
+        match refs {
+            Some(mut refs) => {
+                for path in avatars() {
+                    refs.add(path, &mut texture_cache, &asset_server);
+                }
+            }
+            None => {
+                let refs = EguiTextureRefs::acquire(avatars(), &mut texture_cache, &asset_server);
+                commands.entity(entity).insert(refs);
+            }
+        }
+    }
+}
+
+/// System to handle dismissal of the lobby screen
+fn handle_lobby_completion(mut commands: Commands, mut events: MessageReader<LobbyEvent>) {
+    for event in events.read() {
+        if let LobbyEvent::Dismissed { entity } = event {
+            info!("Dismissing lobby screen for entity {:?}", entity);
+            commands.entity(*entity).remove::<ActiveLobby>();
+        }
+    }
+}
+
+/// System to render the lobby UI
+#[allow(clippy::too_many_arguments)]
+fn render_lobby_ui(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    lobby: Res<LobbyState>,
+    texture_cache: Res<EguiTextureCache>,
+    query: Query<(Entity, &ActiveLobby)>,
+    mut events: MessageWriter<LobbyEvent>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    // Only render the first (most recent) screen to avoid widget ID conflicts
+    if let Some((entity, active)) = query.iter().next() {
+        if active.config.manual_dismissal && input.just_pressed(KeyCode::Escape) {
+            events.write(LobbyEvent::Dismissed { entity });
+            return;
+        }
+
+        egui::CentralPanel::default()
+            .frame(egui::Frame::NONE.fill(theme.base_100))
+            .show(ctx, |ui| {
+                scoped_ui(ui, (entity, "lobby"), |ui| {
+                    render_lobby_content(
+                        ui,
+                        &active.config,
+                        &lobby,
+                        &texture_cache,
+                        &theme,
+                        &responsive,
+                        entity,
+                        &mut events,
+                    );
+                })
+            });
+    }
+}
+
+/// Render the room code, player list and controls
+#[allow(clippy::too_many_arguments)]
+fn render_lobby_content(
+    ui: &mut egui::Ui,
+    config: &LobbyConfig,
+    lobby: &LobbyState,
+    texture_cache: &EguiTextureCache,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    events: &mut MessageWriter<LobbyEvent>,
+) {
+    let max_width = if responsive.is_mobile() {
+        ui.available_width() * 0.95
+    } else {
+        500.0_f32.min(ui.available_width() * 0.9)
+    };
+    let button_width = if responsive.is_mobile() { 200.0 } else { 250.0 };
+    let is_host = lobby.is_local_host();
+
+    ui.vertical_centered(|ui| {
+        ui.set_max_width(max_width);
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::XLarge));
+        ResponsiveText::new(&config.title, ResponsiveFontSize::Title, theme.primary)
+            .responsive(responsive)
+            .strong()
+            .ui(ui);
+
+        if let Some(room_code) = &lobby.room_code {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+            ResponsiveText::new("Room code", ResponsiveFontSize::Small, theme.base_content)
+                .responsive(responsive)
+                .ui(ui);
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(room_code)
+                        .font(egui::FontId::monospace(
+                            responsive.font_size(ResponsiveFontSize::Header),
+                        ))
+                        .color(theme.primary)
+                        .strong(),
+                );
+                if ui.small_button("Copy").clicked() {
+                    ui.ctx().copy_text(room_code.clone());
+                }
+            });
+        }
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+        let count = match lobby.max_players {
+            Some(max) => format!("Players ({}/{})", lobby.players.len(), max),
+            None => format!("Players ({})", lobby.players.len()),
+        };
+        ResponsiveText::new(&count, ResponsiveFontSize::Medium, theme.base_content)
+            .responsive(responsive)
+            .strong()
+            .ui(ui);
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+
+        for player in &lobby.players {
+            render_lobby_player(
+                ui,
+                player,
+                lobby,
+                is_host,
+                texture_cache,
+                theme,
+                responsive,
+                entity,
+                events,
+            );
+        }
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+        ResponsiveText::new(
+            &format!("{}/{} ready", lobby.ready_count(), lobby.players.len()),
+            ResponsiveFontSize::Small,
+            theme.base_content,
+        )
+        .responsive(responsive)
+        .ui(ui);
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+
+        if let Some(local) = lobby.local_player().filter(|player| !player.is_host) {
+            let (label, event) = if local.ready {
+                (
+                    "Not ready",
+                    LobbyEvent::Unready {
+                        entity,
+                        player_id: local.id.clone(),
+                    },
+                )
+            } else {
+                (
+                    "Ready",
+                    LobbyEvent::Ready {
+                        entity,
+                        player_id: local.id.clone(),
+                    },
+                )
+            };
+            let button = ThemedButton::new(label, theme)
+                .responsive(responsive)
+                .width(button_width);
+            if ui.add(button).clicked() {
+                events.write(event);
+            }
+        }
+
+        if is_host {
+            let start = ThemedButton::new(&config.start_button_text, theme)
+                .responsive(responsive)
+                .width(button_width)
+                .enabled(lobby.can_start());
+            if ui.add(start).clicked() {
+                events.write(LobbyEvent::Start { entity });
+            }
+            if lobby.players.len() < lobby.min_players {
+                ResponsiveText::new(
+                    &format!("Waiting for at least {} players", lobby.min_players),
+                    ResponsiveFontSize::Small,
+                    theme.base_content,
+                )
+                .responsive(responsive)
+                .ui(ui);
+            }
+        } else {
+            ResponsiveText::new(
+                "Waiting for the host to start",
+                ResponsiveFontSize::Small,
+                theme.base_content,
+            )
+            .responsive(responsive)
+            .ui(ui);
+        }
+
+        if config.manual_dismissal {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+            let leave = ThemedButton::new(&config.dismiss_button_text, theme)
+                .responsive(responsive)
+                .width(button_width);
+            if ui.add(leave).clicked() {
+                events.write(LobbyEvent::Dismissed { entity });
+            }
+        }
+    });
+}
+
+/// Render one row of the player list
+#[allow(clippy::too_many_arguments)]
+fn render_lobby_player(
+    ui: &mut egui::Ui,
+    player: &LobbyPlayer,
+    lobby: &LobbyState,
+    is_host: bool,
+    texture_cache: &EguiTextureCache,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    events: &mut MessageWriter<LobbyEvent>,
+) {
+    let is_local = lobby.local_player_id.as_deref() == Some(player.id.as_str());
+    let font_size = responsive.font_size(ResponsiveFontSize::Medium);
+
+    egui::Frame::NONE
+        .fill(theme.base_200)
+        .corner_radius(egui::CornerRadius::same(8))
+        .inner_margin(egui::Margin::symmetric(12, 8))
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                let image = player
+                    .avatar
+                    .as_deref()
+                    .and_then(|path| texture_cache.texture(path));
+                AvatarWidget::from_name(theme, &player.name)
+                    .image(image)
+                    .size_preset(AvatarSize::Small)
+                    .responsive(responsive)
+                    .ui(ui);
+
+                let mut name = player.name.clone();
+                if player.is_host {
+                    name.push_str(" (Host)");
+                }
+                if is_local {
+                    name.push_str(" (You)");
+                }
+                ui.label(
+                    egui::RichText::new(name)
+                        .size(font_size)
+                        .color(theme.base_content),
+                );
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if is_host && !is_local {
+                        let kick = ThemedButton::new("Kick", theme).responsive(responsive);
+                        if ui.add(kick).clicked() {
+                            events.write(LobbyEvent::Kick {
+                                entity,
+                                player_id: player.id.clone(),
+                            });
+                        }
+                    }
+
+                    let (state, color) = if player.ready || player.is_host {
+                        ("✔ Ready", theme.success)
+                    } else {
+                        ("Not ready", theme.base_content)
+                    };
+                    ui.label(egui::RichText::new(state).size(font_size).color(color));
+                });
+            });
+        });
+    ui.add_space(responsive.spacing(ResponsiveSpacing::XSmall));
+}
+
+/// Helper trait for easy lobby screen setup
+pub trait LobbyScreenExt {
+    /// Add a lobby screen with the given configuration
+    fn spawn_lobby(&mut self, config: LobbyConfig) -> Entity;
+}
+
+impl LobbyScreenExt for Commands<'_, '_> {
+    fn spawn_lobby(&mut self, config: LobbyConfig) -> Entity {
+        self.spawn((Name::new("Lobby Screen"), config)).id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+
+    #[test]
+    fn test_lobby_host_controls() {
+        let mut harness =
+            TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin, LobbyPlugin));
+        harness.world_mut().insert_resource(LobbyState {
+            room_code: Some("ROOM42".to_string()),
+            players: vec![
+                LobbyPlayer::new("host", "Ada").host(),
+                LobbyPlayer::new("guest", "Grace"),
+            ],
+            local_player_id: Some("host".to_string()),
+            ..default()
+        });
+        let entity = harness.world_mut().spawn(LobbyConfig::default()).id();
+        harness.run_frames(2);
+        assert!(harness.has_widget("ROOM42"));
+        assert!(!harness.world().resource::<LobbyState>().can_start());
+
+        harness.click("Kick");
+        assert_eq!(
+            harness.read_messages::<LobbyEvent>(),
+            vec![LobbyEvent::Kick {
+                entity,
+                player_id: "guest".to_string()
+            }]
+        );
+
+        harness.world_mut().resource_mut::<LobbyState>().players[1].ready = true;
+        harness.step();
+        harness.click("Start game");
+        assert_eq!(
+            harness.read_messages::<LobbyEvent>(),
+            vec![LobbyEvent::Start { entity }]
+        );
+    }
+}
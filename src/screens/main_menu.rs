@@ -0,0 +1,471 @@
+use super::{
+    forward_screen_events, splash::render_logo_enhanced, track_active_screen, AnyScreenActive,
+    LogoDisplay, ScreenAction, ScreenKind, ScreenMessage,
+};
+use crate::{
+    theme::KonnektorenTheme,
+    ui::{
+        menu_background::MenuBackgroundState,
+        responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
+        texture_cache::{
+            release_texture_refs_on_removal, EguiTextureCache, EguiTextureCachePlugin,
+            EguiTextureRefs,
+        },
+        widgets::ThemedButton,
+    },
+};
+use bevy::prelude::*;
+use bevy_egui::{egui, input::EguiWantsInput, EguiContexts, EguiPrimaryContextPass};
+
+/// Plugin for a reusable main menu screen.
+///
+/// Shows a logo, the title and a column of [`MenuEntry`] buttons. Choosing
+/// an entry with the mouse, touch, the arrow keys and Enter or a gamepad
+/// sends [`MainMenuEvent::Selected`] with the ID of the entry, the game opens
+/// the matching screen:
+///
+/// ```ignore
+/// commands.spawn_main_menu(
+///     MainMenuConfig::new("Konnektoren")
+///         .with_entry(MenuEntry::new("play", "Play").with_icon("▶"))
+///         .with_entry(MenuEntry::new("settings", "Settings"))
+///         .with_entry(MenuEntry::new("quit", "Quit")),
+/// );
+///
+/// fn handle_menu(mut events: MessageReader<MainMenuEvent>, mut commands: Commands) {
+///     for MainMenuEvent::Selected { id, .. } in events.read() {
+///         if id == "settings" {
+///             commands.spawn_settings_screen(SettingsScreenConfig::default());
+///         }
+///     }
+/// }
+/// ```
+///
+/// The menu stays active while other screens are opened on top of it and
+/// is shown again once they are closed.
+pub struct MainMenuPlugin;
+
+impl Plugin for MainMenuPlugin {
+    fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveMainMenu>(app);
+        forward_screen_events::<MainMenuEvent>(app);
+        if !app.is_plugin_added::<EguiTextureCachePlugin>() {
+            app.add_plugins(EguiTextureCachePlugin);
+        }
+
+        app.add_message::<MainMenuEvent>()
+            .add_systems(
+                Update,
+                (
+                    (check_main_menu_config, navigate_main_menu).chain(),
+                    load_main_menu_images,
+                    release_texture_refs_on_removal::<ActiveMainMenu>,
+                ),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_main_menu_ui.run_if(any_with_component::<ActiveMainMenu>),
+            );
+    }
+}
+
+/// An entry of the main menu
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MenuEntry {
+    /// ID sent with [`MainMenuEvent::Selected`]
+    pub id: String,
+    pub label: String,
+    /// Emoji shown before the label
+    pub icon: Option<String>,
+    /// Disabled entries are shown but can not be chosen
+    pub enabled: bool,
+}
+
+impl MenuEntry {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            icon: None,
+            enabled: true,
+        }
+    }
+
+    pub fn with_icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Label with the icon
+    pub fn display_label(&self) -> String {
+        match &self.icon {
+            Some(icon) => format!("{} {}", icon, self.label),
+            None => self.label.clone(),
+        }
+    }
+}
+
+/// Configuration for the main menu screen
+#[derive(Component, Clone)]
+pub struct MainMenuConfig {
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub logo: LogoDisplay,
+    pub entries: Vec<MenuEntry>,
+    /// Background color, the menu background or the theme default if `None`
+    pub background_color: Option<egui::Color32>,
+    /// Small text at the bottom, e.g. the version
+    pub footer: Option<String>,
+}
+
+impl Default for MainMenuConfig {
+    fn default() -> Self {
+        Self {
+            title: "Main Menu".to_string(),
+            subtitle: None,
+            logo: LogoDisplay::None,
+            entries: vec![],
+            background_color: None,
+            footer: None,
+        }
+    }
+}
+
+impl MainMenuConfig {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..default()
+        }
+    }
+
+    pub fn with_subtitle(mut self, subtitle: impl Into<String>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    pub fn with_logo(mut self, logo: LogoDisplay) -> Self {
+        self.logo = logo;
+        self
+    }
+
+    pub fn with_entry(mut self, entry: MenuEntry) -> Self {
+        self.entries.push(entry);
+        self
+    }
+
+    pub fn with_entries(mut self, entries: impl IntoIterator<Item = MenuEntry>) -> Self {
+        self.entries.extend(entries);
+        self
+    }
+
+    pub fn with_background_color(mut self, color: egui::Color32) -> Self {
+        self.background_color = Some(color);
+        self
+    }
+
+    pub fn with_footer(mut self, footer: impl Into<String>) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+}
+
+/// Component marking an active main menu
+#[derive(Component)]
+pub struct ActiveMainMenu {
+    config: MainMenuConfig,
+    /// Entry focused by keyboard or gamepad navigation
+    focused: Option<usize>,
+}
+
+impl ActiveMainMenu {
+    pub fn focused(&self) -> Option<&MenuEntry> {
+        self.focused
+            .and_then(|index| self.config.entries.get(index))
+    }
+
+    /// Move the focus to the next enabled entry in the direction of `step`
+    fn move_focus(&mut self, step: isize) {
+        let count = self.config.entries.len() as isize;
+        let mut index = match self.focused {
+            Some(index) => index as isize,
+            // The first step focuses the first or last entry
+            None if step > 0 => -1,
+            None => count,
+        };
+        for _ in 0..count {
+            index = (index + step).rem_euclid(count);
+            if self.config.entries[index as usize].enabled {
+                self.focused = Some(index as usize);
+                return;
+            }
+        }
+    }
+}
+
+/// Events of the main menu
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub enum MainMenuEvent {
+    /// An entry was chosen
+    Selected { entity: Entity, id: String },
+}
+
+impl ScreenMessage for MainMenuEvent {
+    const KIND: ScreenKind = ScreenKind::MainMenu;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::Selected { entity, .. } => Some((ScreenAction::Submitted, *entity)),
+        }
+    }
+}
+
+/// System to check for new main menu configurations
+#[allow(clippy::type_complexity)]
+fn check_main_menu_config(
+    mut commands: Commands,
+    query: Query<(Entity, &MainMenuConfig), (Without<ActiveMainMenu>, Changed<MainMenuConfig>)>,
+    existing: Query<Entity, With<ActiveMainMenu>>,
+) {
+    for (entity, config) in query.iter() {
+        info!("Setting up main menu for entity {:?}", entity);
+
+        // Clean up any existing main menus first
+        for existing_entity in existing.iter() {
+            commands
+                .entity(existing_entity)
+                .remove::<ActiveMainMenu>()
+                .remove::<EguiTextureRefs>();
+        }
+
+        commands.entity(entity).insert(ActiveMainMenu {
+            config: config.clone(),
+            focused: None,
+        });
+    }
+}
+
+/// System to take references to the logo images of new main menus
+fn load_main_menu_images(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_cache: ResMut<EguiTextureCache>,
+    query: Query<(Entity, &ActiveMainMenu), Without<EguiTextureRefs>>,
+) {
+    for (entity, menu) in query.iter() {
+        if let LogoDisplay::Image(path) = &menu.config.logo {
+            let refs = EguiTextureRefs::acquire([path.as_str()], &mut texture_cache, &asset_server);
+            commands.entity(entity).insert(refs);
+        }
+    }
+}
+
+/// System to move the focus with the arrow keys or D-pad and choose the
+/// focused entry with Enter or the South button
+fn navigate_main_menu(
+    input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    wants_input: Option<Res<EguiWantsInput>>,
+    screens: Res<AnyScreenActive>,
+    mut query: Query<(Entity, &mut ActiveMainMenu)>,
+    mut events: MessageWriter<MainMenuEvent>,
+) {
+    // Screens opened on top of the menu get the input
+    let Some((entity, mut menu)) = query
+        .iter_mut()
+        .find(|(entity, _)| screens.top() == Some(*entity))
+    else {
+        return;
+    };
+
+    let keyboard = !wants_input.is_some_and(|wants_input| wants_input.wants_any_keyboard_input());
+    let key_pressed = |key| keyboard && input.just_pressed(key);
+    let gamepad_pressed = |button| gamepads.iter().any(|gamepad| gamepad.just_pressed(button));
+
+    if key_pressed(KeyCode::ArrowUp) || gamepad_pressed(GamepadButton::DPadUp) {
+        menu.move_focus(-1);
+    }
+    if key_pressed(KeyCode::ArrowDown) || gamepad_pressed(GamepadButton::DPadDown) {
+        menu.move_focus(1);
+    }
+    if key_pressed(KeyCode::Enter) || gamepad_pressed(GamepadButton::South) {
+        if let Some(entry) = menu.focused().filter(|entry| entry.enabled) {
+            events.write(MainMenuEvent::Selected {
+                entity,
+                id: entry.id.clone(),
+            });
+        }
+    }
+}
+
+/// System to render the main menu UI
+#[allow(clippy::too_many_arguments)]
+fn render_main_menu_ui(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    texture_cache: Res<EguiTextureCache>,
+    screens: Res<AnyScreenActive>,
+    query: Query<(Entity, &ActiveMainMenu)>,
+    mut events: MessageWriter<MainMenuEvent>,
+    background: Option<Res<MenuBackgroundState>>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    // Screens opened on top of the menu cover it
+    let Some((entity, menu)) = query
+        .iter()
+        .find(|(entity, _)| screens.top() == Some(*entity))
+    else {
+        return;
+    };
+    let config = &menu.config;
+
+    egui::CentralPanel::default()
+        .frame(egui::Frame::NONE.fill(config.background_color.unwrap_or(theme.base_100)))
+        .show(ctx, |ui| {
+            scoped_ui(ui, (entity, "main_menu"), |ui| {
+                // An explicit background color wins over the menu background
+                if let (None, Some(background)) = (config.background_color, &background) {
+                    background.paint(ui.painter(), ui.max_rect(), &theme);
+                }
+                render_main_menu_content(
+                    ui,
+                    menu,
+                    &theme,
+                    &responsive,
+                    &texture_cache,
+                    entity,
+                    &mut events,
+                );
+            })
+        });
+}
+
+/// Render the logo, title and entries
+fn render_main_menu_content(
+    ui: &mut egui::Ui,
+    menu: &ActiveMainMenu,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    texture_cache: &EguiTextureCache,
+    entity: Entity,
+    events: &mut MessageWriter<MainMenuEvent>,
+) {
+    let config = &menu.config;
+
+    egui::ScrollArea::vertical().show(ui, |ui| {
+        ui.vertical_centered(|ui| {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::XLarge));
+            render_logo_enhanced(ui, &config.logo, theme, responsive, 1.0, texture_cache);
+
+            ui.heading(
+                egui::RichText::new(&config.title)
+                    .color(theme.primary)
+                    .size(responsive.font_size(ResponsiveFontSize::Title))
+                    .strong(),
+            );
+            if let Some(subtitle) = &config.subtitle {
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                ui.label(
+                    egui::RichText::new(subtitle)
+                        .color(theme.base_content)
+                        .size(responsive.font_size(ResponsiveFontSize::Large)),
+                );
+            }
+            ui.add_space(responsive.spacing(ResponsiveSpacing::XLarge));
+
+            let button_width = if responsive.is_mobile() {
+                ui.available_width() * 0.8
+            } else {
+                280.0
+            };
+            for (index, entry) in config.entries.iter().enumerate() {
+                let label = entry.display_label();
+                let focused = menu.focused == Some(index);
+                let mut button = ThemedButton::new(&label, theme)
+                    .responsive(responsive)
+                    .width(button_width)
+                    .enabled(entry.enabled);
+                if focused {
+                    button = button
+                        .with_style(|button| button.stroke(egui::Stroke::new(3.0, theme.accent)));
+                }
+
+                let response = ui.add(button);
+                if focused {
+                    response.scroll_to_me(None);
+                }
+                if response.clicked() {
+                    events.write(MainMenuEvent::Selected {
+                        entity,
+                        id: entry.id.clone(),
+                    });
+                }
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+            }
+
+            if let Some(footer) = &config.footer {
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+                ui.label(
+                    egui::RichText::new(footer)
+                        .color(theme.base_content.gamma_multiply(0.7))
+                        .size(responsive.font_size(ResponsiveFontSize::Small)),
+                );
+            }
+        });
+    });
+}
+
+/// Helper trait to easily spawn a main menu
+pub trait MainMenuScreenExt {
+    /// Spawn a main menu with the given configuration
+    fn spawn_main_menu(&mut self, config: MainMenuConfig) -> Entity;
+}
+
+impl MainMenuScreenExt for Commands<'_, '_> {
+    fn spawn_main_menu(&mut self, config: MainMenuConfig) -> Entity {
+        self.spawn((Name::new("Main Menu Screen"), config)).id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+
+    #[test]
+    fn test_main_menu() {
+        let mut harness =
+            TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin, MainMenuPlugin));
+        harness.world_mut().commands().spawn_main_menu(
+            MainMenuConfig::new("Konnektoren")
+                .with_entry(MenuEntry::new("play", "Play").with_icon("▶"))
+                .with_entry(MenuEntry::new("online", "Online").with_enabled(false))
+                .with_entry(MenuEntry::new("settings", "Settings")),
+        );
+        harness.run_frames(2);
+        assert!(harness.has_widget("Konnektoren"));
+
+        harness.click("▶ Play");
+        harness.step();
+        let events = harness.read_messages::<MainMenuEvent>();
+        assert!(matches!(&events[..], [MainMenuEvent::Selected { id, .. }] if id == "play"));
+
+        // The disabled entry is skipped by the keyboard navigation
+        harness.press_key_code(KeyCode::ArrowDown);
+        harness.press_key_code(KeyCode::ArrowDown);
+        harness.press_key_code(KeyCode::Enter);
+        harness.step();
+        let events = harness.read_messages::<MainMenuEvent>();
+        assert!(matches!(&events[..], [MainMenuEvent::Selected { id, .. }] if id == "settings"));
+    }
+}
@@ -0,0 +1,1083 @@
+use super::{forward_screen_events, track_active_screen, ScreenAction, ScreenKind, ScreenMessage};
+#[cfg(feature = "assets")]
+use crate::assets::{
+    AssetPack, AssetPackEvent, AssetPackManifest, AssetPackStatus, InstallAssetPack,
+    InstalledAssetPacks,
+};
+use crate::{
+    theme::KonnektorenTheme,
+    ui::{
+        responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
+        texture_cache::{
+            release_texture_refs_on_removal, EguiTextureCache, EguiTextureCachePlugin,
+            EguiTextureRefs,
+        },
+        widgets::{ResponsiveText, SpinnerWidget, ThemedButton, ThemedPagination},
+    },
+};
+#[cfg(feature = "assets")]
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+use bevy_egui::{
+    egui::{self, TextureId, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+use std::collections::HashMap;
+
+/// Plugin for the marketplace screen listing installable content packs
+pub struct MarketplacePlugin;
+
+impl Plugin for MarketplacePlugin {
+    fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveMarketplace>(app);
+        forward_screen_events::<MarketplaceEvent>(app);
+        if !app.is_plugin_added::<EguiTextureCachePlugin>() {
+            app.add_plugins(EguiTextureCachePlugin);
+        }
+
+        app.add_message::<MarketplaceEvent>()
+            .add_message::<MarketplacePackStatus>()
+            .add_systems(
+                Update,
+                (
+                    check_marketplace_config,
+                    load_marketplace_thumbnails,
+                    handle_marketplace_events,
+                    apply_marketplace_pack_status,
+                    release_texture_refs_on_removal::<ActiveMarketplace>,
+                ),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_marketplace_ui.run_if(any_with_component::<ActiveMarketplace>),
+            );
+
+        // Drive installation through the asset pack system if assets are enabled
+        #[cfg(feature = "assets")]
+        {
+            app.add_message::<InstallAssetPack>()
+                .add_message::<AssetPackEvent>()
+                .add_systems(
+                    Update,
+                    (
+                        load_marketplace_manifest,
+                        forward_marketplace_install_requests.after(handle_marketplace_events),
+                        forward_asset_pack_events,
+                    )
+                        .before(apply_marketplace_pack_status),
+                );
+        }
+    }
+}
+
+/// A content pack listed on the marketplace
+#[derive(Clone, Debug)]
+pub struct MarketplaceItem {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    /// Thumbnail image path (loaded through the asset server)
+    pub thumbnail: Option<String>,
+    pub version: Option<String>,
+    pub tags: Vec<String>,
+}
+
+impl MarketplaceItem {
+    pub fn new(
+        id: impl Into<String>,
+        title: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            description: description.into(),
+            thumbnail: None,
+            version: None,
+            tags: vec![],
+        }
+    }
+
+    pub fn with_thumbnail(mut self, path: impl Into<String>) -> Self {
+        self.thumbnail = Some(path.into());
+        self
+    }
+
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    pub fn add_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+}
+
+#[cfg(feature = "assets")]
+impl From<&AssetPack> for MarketplaceItem {
+    fn from(pack: &AssetPack) -> Self {
+        Self {
+            id: pack.id.clone(),
+            title: pack.name.clone(),
+            description: pack.description.clone(),
+            thumbnail: pack.thumbnail.clone(),
+            version: pack.version.clone(),
+            tags: pack.tags.clone(),
+        }
+    }
+}
+
+/// Install state of a marketplace item
+#[derive(Clone, Debug, PartialEq, Default)]
+pub enum PackInstallState {
+    #[default]
+    Available,
+    Installing,
+    Installed,
+    Failed(String),
+}
+
+/// Configuration for the marketplace screen
+#[derive(Component, Clone)]
+pub struct MarketplaceConfig {
+    pub title: String,
+    pub subtitle: Option<String>,
+    /// Path or URL of an asset pack manifest (`*.packs.yml`), requires the `assets` feature
+    pub manifest_path: Option<String>,
+    /// Items shown in addition to the ones from the manifest
+    pub items: Vec<MarketplaceItem>,
+    /// Allow manual dismissal (back button/escape)
+    pub manual_dismissal: bool,
+    pub dismiss_button_text: String,
+    pub install_button_text: String,
+    /// Message shown when no items are available
+    pub empty_message: String,
+    /// Items per page, 0 shows all items in one scrolling list
+    pub items_per_page: usize,
+}
+
+impl Default for MarketplaceConfig {
+    fn default() -> Self {
+        Self {
+            title: "Marketplace".to_string(),
+            subtitle: Some("Discover more challenges".to_string()),
+            manifest_path: None,
+            items: vec![],
+            manual_dismissal: true,
+            dismiss_button_text: "← Back".to_string(),
+            install_button_text: "Install".to_string(),
+            empty_message: "No content packs available yet.".to_string(),
+            items_per_page: 10,
+        }
+    }
+}
+
+impl MarketplaceConfig {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_subtitle(mut self, subtitle: impl Into<String>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    pub fn with_manifest(mut self, path: impl Into<String>) -> Self {
+        self.manifest_path = Some(path.into());
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<MarketplaceItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn add_item(mut self, item: MarketplaceItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn with_manual_dismissal(mut self, manual_dismissal: bool) -> Self {
+        self.manual_dismissal = manual_dismissal;
+        self
+    }
+
+    pub fn with_dismiss_button_text(mut self, text: impl Into<String>) -> Self {
+        self.dismiss_button_text = text.into();
+        self
+    }
+
+    pub fn with_install_button_text(mut self, text: impl Into<String>) -> Self {
+        self.install_button_text = text.into();
+        self
+    }
+
+    pub fn with_empty_message(mut self, message: impl Into<String>) -> Self {
+        self.empty_message = message.into();
+        self
+    }
+
+    pub fn with_items_per_page(mut self, items_per_page: usize) -> Self {
+        self.items_per_page = items_per_page;
+        self
+    }
+
+    /// Create a Konnektoren-branded marketplace loading packs from a manifest
+    pub fn konnektoren(manifest_path: impl Into<String>) -> Self {
+        Self::new("Konnektoren Marketplace")
+            .with_subtitle("Extend your game with new challenge packs")
+            .with_manifest(manifest_path)
+    }
+}
+
+/// Component marking an active marketplace screen
+#[derive(Component)]
+pub struct ActiveMarketplace {
+    config: MarketplaceConfig,
+    items: Vec<MarketplaceItem>,
+    install_states: HashMap<String, PackInstallState>,
+    // Thumbnails resolved from the shared texture cache
+    thumbnail_textures: HashMap<String, TextureId>,
+    /// Shown page, zero-based
+    page: usize,
+    /// Why the manifest could not be loaded
+    load_error: Option<String>,
+    #[cfg(feature = "assets")]
+    manifest: Option<Handle<AssetPackManifest>>,
+    #[cfg(feature = "assets")]
+    packs: HashMap<String, AssetPack>,
+}
+
+impl ActiveMarketplace {
+    /// Get the install state of an item
+    pub fn install_state(&self, pack_id: &str) -> PackInstallState {
+        self.install_states
+            .get(pack_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Shown page, zero-based
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Error if the manifest could not be loaded
+    pub fn load_error(&self) -> Option<&str> {
+        self.load_error.as_deref()
+    }
+}
+
+/// Events for marketplace interactions
+#[derive(Message, Debug, Clone)]
+pub enum MarketplaceEvent {
+    /// The install button of an item was clicked
+    InstallRequested { entity: Entity, pack_id: String },
+    /// Marketplace screen dismissed
+    Dismissed { entity: Entity },
+}
+
+impl ScreenMessage for MarketplaceEvent {
+    const KIND: ScreenKind = ScreenKind::Marketplace;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::InstallRequested { entity, .. } => Some((ScreenAction::Submitted, *entity)),
+            Self::Dismissed { entity } => Some((ScreenAction::Dismissed, *entity)),
+        }
+    }
+}
+
+/// Message reporting install progress back to the marketplace screen.
+///
+/// Written automatically when the `assets` feature drives installation,
+/// or by the app when it installs packs itself.
+#[derive(Message, Debug, Clone)]
+pub struct MarketplacePackStatus {
+    pub pack_id: String,
+    pub state: PackInstallState,
+}
+
+/// System to check for new marketplace configurations and set them up
+#[allow(clippy::type_complexity)]
+fn check_marketplace_config(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &MarketplaceConfig),
+        (Without<ActiveMarketplace>, Changed<MarketplaceConfig>),
+    >,
+    existing_marketplace: Query<Entity, With<ActiveMarketplace>>,
+) {
+    for (entity, config) in query.iter() {
+        info!("Setting up marketplace screen for entity {:?}", entity);
+
+        // Clean up any existing marketplace screens first
+        for existing_entity in existing_marketplace.iter() {
+            info!(
+                "Cleaning up existing marketplace screen: {:?}",
+                existing_entity
+            );
+            commands
+                .entity(existing_entity)
+                .remove::<ActiveMarketplace>();
+        }
+
+        commands.entity(entity).insert(ActiveMarketplace {
+            config: config.clone(),
+            items: config.items.clone(),
+            install_states: HashMap::new(),
+            thumbnail_textures: HashMap::new(),
+            page: 0,
+            load_error: None,
+            #[cfg(feature = "assets")]
+            manifest: None,
+            #[cfg(feature = "assets")]
+            packs: HashMap::new(),
+        });
+    }
+}
+
+/// System to load the pack manifest and fill the marketplace items
+#[cfg(feature = "assets")]
+fn load_marketplace_manifest(
+    asset_server: Res<AssetServer>,
+    manifests: Option<Res<Assets<AssetPackManifest>>>,
+    installed: Option<Res<InstalledAssetPacks>>,
+    mut query: Query<&mut ActiveMarketplace>,
+) {
+    for mut marketplace in query.iter_mut() {
+        if marketplace.load_error.is_some() {
+            continue;
+        }
+        let Some(path) = marketplace.config.manifest_path.clone() else {
+            continue;
+        };
+
+        // Without the manifest loader the manifest would never arrive
+        let Some(manifests) = manifests.as_ref() else {
+            warn!(
+                "Cannot load marketplace manifest {path}, the KonnektorenAssetsPlugin is missing"
+            );
+            marketplace.load_error = Some("Asset packs are not available".to_string());
+            continue;
+        };
+
+        let Some(handle) = marketplace.manifest.clone() else {
+            info!("Loading marketplace manifest: {}", path);
+            marketplace.manifest = Some(asset_server.load(path));
+            continue;
+        };
+
+        if !marketplace.packs.is_empty() {
+            continue;
+        }
+
+        let Some(manifest) = manifests.get(&handle) else {
+            if let LoadState::Failed(error) = asset_server.load_state(&handle) {
+                warn!("Could not load marketplace manifest {path}: {error}");
+                marketplace.load_error = Some(error.to_string());
+            }
+            continue;
+        };
+
+        for pack in &manifest.packs {
+            if !marketplace.items.iter().any(|item| item.id == pack.id) {
+                marketplace.items.push(MarketplaceItem::from(pack));
+            }
+
+            let state = match installed
+                .as_ref()
+                .and_then(|installed| installed.get_status(&pack.id))
+            {
+                Some(AssetPackStatus::Installed) => PackInstallState::Installed,
+                Some(AssetPackStatus::Installing) => PackInstallState::Installing,
+                Some(AssetPackStatus::Failed(error)) => PackInstallState::Failed(error.clone()),
+                None => PackInstallState::Available,
+            };
+            marketplace.install_states.insert(pack.id.clone(), state);
+            marketplace.packs.insert(pack.id.clone(), pack.clone());
+        }
+    }
+}
+
+/// System to load item thumbnails through the shared texture cache
+fn load_marketplace_thumbnails(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_cache: ResMut<EguiTextureCache>,
+    mut query: Query<(Entity, &mut ActiveMarketplace, Option<&mut EguiTextureRefs>)>,
+) {
+    for (entity, mut marketplace, refs) in query.iter_mut() {
+        let ActiveMarketplace {
+            items,
+            thumbnail_textures,
+            ..
+        } = &mut *marketplace;
+
+        let thumbnails = items.iter().filter_map(|item| item.thumbnail.as_deref());
+        match refs {
+            Some(mut refs) => {
+                for path in thumbnails {
+                    refs.add(path, &mut texture_cache, &asset_server);
+                }
+            }
+            None => {
+                let refs = EguiTextureRefs::acquire(thumbnails, &mut texture_cache, &asset_server);
+                commands.entity(entity).insert(refs);
+            }
+        }
+
+        for path in items.iter().filter_map(|item| item.thumbnail.as_ref()) {
+            if thumbnail_textures.contains_key(path) {
+                continue;
+            }
+            if let Some(texture_id) = texture_cache.texture(path) {
+                thumbnail_textures.insert(path.clone(), texture_id);
+            }
+        }
+    }
+}
+
+/// System to handle marketplace events
+fn handle_marketplace_events(
+    mut commands: Commands,
+    mut marketplace_events: MessageReader<MarketplaceEvent>,
+    mut query: Query<&mut ActiveMarketplace>,
+) {
+    for event in marketplace_events.read() {
+        match event {
+            MarketplaceEvent::Dismissed { entity } => {
+                info!("Dismissing marketplace screen for entity {:?}", entity);
+                commands.entity(*entity).remove::<ActiveMarketplace>();
+            }
+            MarketplaceEvent::InstallRequested { entity, pack_id } => {
+                info!("Install requested for pack '{}'", pack_id);
+                if let Ok(mut marketplace) = query.get_mut(*entity) {
+                    marketplace
+                        .install_states
+                        .insert(pack_id.clone(), PackInstallState::Installing);
+                }
+            }
+        }
+    }
+}
+
+/// System to apply install status updates to active marketplace screens
+fn apply_marketplace_pack_status(
+    mut status_events: MessageReader<MarketplacePackStatus>,
+    mut query: Query<&mut ActiveMarketplace>,
+) {
+    for event in status_events.read() {
+        for mut marketplace in query.iter_mut() {
+            marketplace
+                .install_states
+                .insert(event.pack_id.clone(), event.state.clone());
+        }
+    }
+}
+
+/// System to turn install requests into asset pack installations.
+///
+/// Packs fail right away when no installer is registered, items that are not
+/// from the manifest are left to the app.
+#[cfg(feature = "assets")]
+fn forward_marketplace_install_requests(
+    mut marketplace_events: MessageReader<MarketplaceEvent>,
+    query: Query<&ActiveMarketplace>,
+    installer: Option<Res<InstalledAssetPacks>>,
+    mut install_events: MessageWriter<InstallAssetPack>,
+    mut status_events: MessageWriter<MarketplacePackStatus>,
+) {
+    for event in marketplace_events.read() {
+        if let MarketplaceEvent::InstallRequested { entity, pack_id } = event {
+            let pack = query
+                .get(*entity)
+                .ok()
+                .and_then(|marketplace| marketplace.packs.get(pack_id));
+
+            let Some(pack) = pack else {
+                continue;
+            };
+            if installer.is_some() {
+                install_events.write(InstallAssetPack { pack: pack.clone() });
+            } else {
+                warn!("Cannot install pack '{pack_id}', the KonnektorenAssetsPlugin is missing");
+                status_events.write(MarketplacePackStatus {
+                    pack_id: pack_id.clone(),
+                    state: PackInstallState::Failed("No asset pack installer".to_string()),
+                });
+            }
+        }
+    }
+}
+
+/// System to report asset pack progress to the marketplace screen
+#[cfg(feature = "assets")]
+fn forward_asset_pack_events(
+    mut pack_events: MessageReader<AssetPackEvent>,
+    mut status_events: MessageWriter<MarketplacePackStatus>,
+) {
+    for event in pack_events.read() {
+        let status = match event {
+            AssetPackEvent::Installed { pack_id } => MarketplacePackStatus {
+                pack_id: pack_id.clone(),
+                state: PackInstallState::Installed,
+            },
+            AssetPackEvent::Failed { pack_id, error } => MarketplacePackStatus {
+                pack_id: pack_id.clone(),
+                state: PackInstallState::Failed(error.clone()),
+            },
+        };
+        status_events.write(status);
+    }
+}
+
+/// System to render the marketplace UI
+fn render_marketplace_ui(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    mut query: Query<(Entity, &mut ActiveMarketplace)>,
+    mut marketplace_events: MessageWriter<MarketplaceEvent>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    if query.is_empty() {
+        return;
+    }
+
+    if let Ok(ctx) = contexts.ctx_mut() {
+        // Only render the first (most recent) marketplace to avoid widget ID conflicts
+        if let Some((entity, mut marketplace)) = query.iter_mut().next() {
+            if marketplace.config.manual_dismissal && input.just_pressed(KeyCode::Escape) {
+                marketplace_events.write(MarketplaceEvent::Dismissed { entity });
+                return;
+            }
+
+            let mut page = marketplace.page;
+            egui::CentralPanel::default()
+                .frame(egui::Frame::NONE.fill(theme.base_100))
+                .show(ctx, |ui| {
+                    scoped_ui(ui, (entity, "marketplace"), |ui| {
+                        render_marketplace_content(
+                            ui,
+                            &marketplace,
+                            &mut page,
+                            &theme,
+                            &responsive,
+                            entity,
+                            &mut marketplace_events,
+                        );
+                    })
+                });
+            if page != marketplace.page {
+                marketplace.page = page;
+            }
+        }
+    }
+}
+
+/// Render marketplace screen content
+fn render_marketplace_content(
+    ui: &mut egui::Ui,
+    marketplace: &ActiveMarketplace,
+    page: &mut usize,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    marketplace_events: &mut MessageWriter<MarketplaceEvent>,
+) {
+    let config = &marketplace.config;
+    let items_per_page = if config.items_per_page == 0 {
+        marketplace.items.len().max(1)
+    } else {
+        config.items_per_page
+    };
+    let page_count = ThemedPagination::page_count(marketplace.items.len(), items_per_page);
+    *page = (*page).min(page_count - 1);
+
+    ui.vertical_centered(|ui| {
+        let max_width = if responsive.is_mobile() {
+            ui.available_width() * 0.95
+        } else {
+            800.0_f32.min(ui.available_width() * 0.9)
+        };
+
+        ui.set_max_width(max_width);
+
+        // Header
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+        ResponsiveText::new(&config.title, ResponsiveFontSize::Title, theme.primary)
+            .responsive(responsive)
+            .strong()
+            .ui(ui);
+
+        if let Some(subtitle) = &config.subtitle {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+            ResponsiveText::new(subtitle, ResponsiveFontSize::Large, theme.base_content)
+                .responsive(responsive)
+                .ui(ui);
+        }
+
+        if let Some(error) = &marketplace.load_error {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+            ResponsiveText::new(
+                &format!("Could not load the content packs: {error}"),
+                ResponsiveFontSize::Medium,
+                theme.error,
+            )
+            .responsive(responsive)
+            .ui(ui);
+        }
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+        // Item list, leaving room for the pagination
+        let pagination_height = if page_count > 1 { 48.0 } else { 0.0 };
+        let scroll_height = ui.available_height() - 80.0 - pagination_height;
+        egui::ScrollArea::vertical()
+            .id_salt("marketplace_scroll")
+            .max_height(scroll_height)
+            .auto_shrink([false; 2])
+            .show(ui, |ui| {
+                if marketplace.items.is_empty() {
+                    render_empty_marketplace(ui, marketplace, theme, responsive);
+                }
+
+                for item in marketplace
+                    .items
+                    .iter()
+                    .skip(*page * items_per_page)
+                    .take(items_per_page)
+                {
+                    scoped_ui(ui, ("marketplace_item", &item.id), |ui| {
+                        render_marketplace_item(
+                            ui,
+                            item,
+                            marketplace,
+                            theme,
+                            responsive,
+                            entity,
+                            marketplace_events,
+                        );
+                    });
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                }
+            });
+
+        if page_count > 1 {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+            ui.add(
+                ThemedPagination::new(page, page_count, theme)
+                    .jump_to_page(page_count > 7)
+                    .responsive(responsive),
+            );
+        }
+
+        // Back button at bottom
+        if config.manual_dismissal {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+            let back_button = ThemedButton::new(&config.dismiss_button_text, theme)
+                .responsive(responsive)
+                .width(if responsive.is_mobile() { 200.0 } else { 250.0 });
+
+            if ui.add(back_button).clicked() {
+                marketplace_events.write(MarketplaceEvent::Dismissed { entity });
+            }
+        }
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+    });
+}
+
+fn render_empty_marketplace(
+    ui: &mut egui::Ui,
+    marketplace: &ActiveMarketplace,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+) {
+    ui.vertical_centered(|ui| {
+        ui.add_space(responsive.spacing(ResponsiveSpacing::XLarge));
+
+        // Still waiting for the manifest to arrive
+        if marketplace.config.manifest_path.is_some() && marketplace.load_error.is_none() {
+            SpinnerWidget::new(theme, 32.0)
+                .responsive(responsive)
+                .ui(ui);
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+        }
+
+        ResponsiveText::new(
+            &marketplace.config.empty_message,
+            ResponsiveFontSize::Medium,
+            theme.accent,
+        )
+        .responsive(responsive)
+        .ui(ui);
+    });
+}
+
+fn render_marketplace_item(
+    ui: &mut egui::Ui,
+    item: &MarketplaceItem,
+    marketplace: &ActiveMarketplace,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    marketplace_events: &mut MessageWriter<MarketplaceEvent>,
+) {
+    let margin = if responsive.is_mobile() { 12 } else { 16 };
+    let frame = egui::Frame {
+        inner_margin: egui::Margin::same(margin),
+        corner_radius: egui::CornerRadius::same(8),
+        fill: theme.base_200,
+        stroke: egui::Stroke::new(1.0, theme.accent.linear_multiply(0.3)),
+        ..Default::default()
+    };
+
+    let thumbnail_size = if responsive.is_mobile() { 64.0 } else { 96.0 };
+    let texture = item
+        .thumbnail
+        .as_ref()
+        .and_then(|path| marketplace.thumbnail_textures.get(path));
+    let state = marketplace.install_state(&item.id);
+
+    frame.show(ui, |ui| {
+        ui.set_min_width(ui.available_width());
+
+        if responsive.is_mobile() {
+            ui.vertical_centered(|ui| {
+                render_thumbnail(ui, texture, thumbnail_size, theme);
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                render_item_details(ui, item, theme, responsive);
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                render_install_button(
+                    ui,
+                    item,
+                    &state,
+                    &marketplace.config,
+                    theme,
+                    responsive,
+                    entity,
+                    marketplace_events,
+                );
+            });
+        } else {
+            ui.horizontal(|ui| {
+                render_thumbnail(ui, texture, thumbnail_size, theme);
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                ui.vertical(|ui| {
+                    render_item_details(ui, item, theme, responsive);
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                    render_install_button(
+                        ui,
+                        item,
+                        &state,
+                        &marketplace.config,
+                        theme,
+                        responsive,
+                        entity,
+                        marketplace_events,
+                    );
+                });
+            });
+        }
+    });
+}
+
+fn render_thumbnail(
+    ui: &mut egui::Ui,
+    texture: Option<&TextureId>,
+    size: f32,
+    theme: &KonnektorenTheme,
+) {
+    if let Some(texture_id) = texture {
+        let image = egui::Image::from_texture((*texture_id, egui::vec2(size, size)))
+            .fit_to_exact_size(egui::vec2(size, size))
+            .corner_radius(egui::CornerRadius::same(8));
+        ui.add(image);
+    } else {
+        // Placeholder while the thumbnail loads (or if there is none)
+        let (rect, _) = ui.allocate_exact_size(egui::vec2(size, size), egui::Sense::hover());
+        ui.painter()
+            .rect_filled(rect, egui::CornerRadius::same(8), theme.base_300);
+        ui.painter().text(
+            rect.center(),
+            egui::Align2::CENTER_CENTER,
+            "📦",
+            egui::FontId::proportional(size * 0.5),
+            theme.base_content,
+        );
+    }
+}
+
+fn render_item_details(
+    ui: &mut egui::Ui,
+    item: &MarketplaceItem,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+) {
+    ui.horizontal_wrapped(|ui| {
+        ResponsiveText::new(&item.title, ResponsiveFontSize::Large, theme.primary)
+            .responsive(responsive)
+            .strong()
+            .ui(ui);
+
+        if let Some(version) = &item.version {
+            let badge_frame = egui::Frame {
+                inner_margin: egui::Margin::symmetric(8, 2),
+                corner_radius: egui::CornerRadius::same(8),
+                fill: theme.secondary,
+                ..Default::default()
+            };
+
+            badge_frame.show(ui, |ui| {
+                ResponsiveText::new(version, ResponsiveFontSize::Small, theme.secondary_content)
+                    .responsive(responsive)
+                    .ui(ui);
+            });
+        }
+    });
+
+    ResponsiveText::new(
+        &item.description,
+        ResponsiveFontSize::Medium,
+        theme.base_content,
+    )
+    .responsive(responsive)
+    .ui(ui);
+
+    if !item.tags.is_empty() {
+        ui.horizontal_wrapped(|ui| {
+            for tag in &item.tags {
+                let tag_frame = egui::Frame {
+                    inner_margin: egui::Margin::symmetric(6, 2),
+                    corner_radius: egui::CornerRadius::same(4),
+                    fill: theme.info,
+                    ..Default::default()
+                };
+
+                tag_frame.show(ui, |ui| {
+                    ResponsiveText::new(tag, ResponsiveFontSize::Small, theme.primary_content)
+                        .responsive(responsive)
+                        .ui(ui);
+                });
+            }
+        });
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_install_button(
+    ui: &mut egui::Ui,
+    item: &MarketplaceItem,
+    state: &PackInstallState,
+    config: &MarketplaceConfig,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    marketplace_events: &mut MessageWriter<MarketplaceEvent>,
+) {
+    match state {
+        PackInstallState::Available => {
+            let button =
+                ThemedButton::new(&config.install_button_text, theme).responsive(responsive);
+
+            if ui.add(button).clicked() {
+                marketplace_events.write(MarketplaceEvent::InstallRequested {
+                    entity,
+                    pack_id: item.id.clone(),
+                });
+            }
+        }
+        PackInstallState::Installing => {
+            ui.horizontal(|ui| {
+                SpinnerWidget::new(theme, 20.0)
+                    .responsive(responsive)
+                    .ui(ui);
+                ResponsiveText::new("Installing...", ResponsiveFontSize::Medium, theme.accent)
+                    .responsive(responsive)
+                    .ui(ui);
+            });
+        }
+        PackInstallState::Installed => {
+            ResponsiveText::new("✔ Installed", ResponsiveFontSize::Medium, theme.success)
+                .responsive(responsive)
+                .strong()
+                .ui(ui);
+        }
+        PackInstallState::Failed(error) => {
+            ResponsiveText::new(
+                &format!("Installation failed: {}", error),
+                ResponsiveFontSize::Small,
+                theme.error,
+            )
+            .responsive(responsive)
+            .ui(ui);
+
+            let button = ThemedButton::new("Retry", theme).responsive(responsive);
+
+            if ui.add(button).clicked() {
+                marketplace_events.write(MarketplaceEvent::InstallRequested {
+                    entity,
+                    pack_id: item.id.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// Helper trait for easy marketplace screen setup
+pub trait MarketplaceScreenExt {
+    /// Add a marketplace screen with the given configuration
+    fn spawn_marketplace(&mut self, config: MarketplaceConfig) -> Entity;
+
+    /// Add a marketplace screen listing the packs of a manifest
+    fn spawn_marketplace_from_manifest(&mut self, manifest_path: impl Into<String>) -> Entity;
+}
+
+impl MarketplaceScreenExt for Commands<'_, '_> {
+    fn spawn_marketplace(&mut self, config: MarketplaceConfig) -> Entity {
+        self.spawn((Name::new("Marketplace Screen"), config)).id()
+    }
+
+    fn spawn_marketplace_from_manifest(&mut self, manifest_path: impl Into<String>) -> Entity {
+        self.spawn_marketplace(MarketplaceConfig::konnektoren(manifest_path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+
+    #[test]
+    fn test_marketplace_pagination() {
+        let items = (1..=25)
+            .map(|index| {
+                MarketplaceItem::new(format!("pack-{}", index), format!("Pack {}", index), "")
+            })
+            .collect();
+        let mut harness =
+            TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin, MarketplacePlugin));
+        let marketplace = harness
+            .world_mut()
+            .spawn(MarketplaceConfig::new("Market").with_items(items))
+            .id();
+        harness.run_frames(2);
+
+        assert!(harness.has_widget("Pack 1"));
+        assert!(!harness.has_widget("Pack 11"));
+
+        harness.click("3");
+        assert_eq!(
+            harness
+                .world()
+                .get::<ActiveMarketplace>(marketplace)
+                .map(|marketplace| marketplace.page()),
+            Some(2)
+        );
+        assert!(harness.has_widget("Pack 21"));
+        assert!(!harness.has_widget("Pack 1"));
+    }
+
+    #[cfg(feature = "assets")]
+    fn run_until(harness: &mut TestHarness, done: impl Fn(&TestHarness) -> bool) {
+        for _ in 0..200 {
+            harness.step();
+            if done(harness) {
+                return;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        panic!("marketplace did not reach the expected state");
+    }
+
+    #[cfg(feature = "assets")]
+    #[test]
+    fn test_marketplace_installs_pack() {
+        use crate::assets::KonnektorenAssetsPlugin;
+
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            KonnektorenAssetsPlugin,
+            MarketplacePlugin,
+        ));
+        let marketplace = harness
+            .world_mut()
+            .spawn(MarketplaceConfig::konnektoren("starter.packs.yml"))
+            .id();
+        run_until(&mut harness, |harness| harness.has_widget("Starter Pack"));
+
+        harness.click("Install");
+        run_until(&mut harness, |harness| harness.has_widget("✔ Installed"));
+        let state = harness
+            .world()
+            .get::<ActiveMarketplace>(marketplace)
+            .map(|marketplace| marketplace.install_state("starter"));
+        assert_eq!(state, Some(PackInstallState::Installed));
+    }
+
+    #[cfg(feature = "assets")]
+    #[test]
+    fn test_marketplace_without_installer() {
+        let mut harness =
+            TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin, MarketplacePlugin));
+        let pack = AssetPack::new("starter", "Starter Pack");
+        let marketplace = harness
+            .world_mut()
+            .spawn(MarketplaceConfig::new("Market"))
+            .id();
+        harness.step();
+        {
+            let mut entity = harness.world_mut().entity_mut(marketplace);
+            let mut active = entity.get_mut::<ActiveMarketplace>().unwrap();
+            active.items.push(MarketplaceItem::from(&pack));
+            active.packs.insert(pack.id.clone(), pack);
+        }
+        harness.run_frames(2);
+
+        harness.click("Install");
+        harness.run_frames(2);
+        let state = harness
+            .world()
+            .get::<ActiveMarketplace>(marketplace)
+            .map(|marketplace| marketplace.install_state("starter"));
+        assert!(matches!(state, Some(PackInstallState::Failed(_))));
+        assert!(harness.has_widget("Retry"));
+    }
+
+    #[cfg(feature = "assets")]
+    #[test]
+    fn test_marketplace_manifest_load_failure() {
+        use crate::assets::KonnektorenAssetsPlugin;
+
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            KonnektorenAssetsPlugin,
+            MarketplacePlugin,
+        ));
+        let marketplace = harness
+            .world_mut()
+            .spawn(MarketplaceConfig::konnektoren("missing.packs.yml"))
+            .id();
+        run_until(&mut harness, |harness| {
+            harness
+                .world()
+                .get::<ActiveMarketplace>(marketplace)
+                .is_some_and(|marketplace| marketplace.load_error().is_some())
+        });
+
+        harness.step();
+        assert!(harness
+            .widget_labels()
+            .iter()
+            .any(|label| label.starts_with("Could not load the content packs")));
+    }
+}
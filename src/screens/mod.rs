@@ -1,14 +1,85 @@
 pub mod about;
+#[cfg(feature = "asset-inspector")]
+pub mod asset_inspector;
+pub mod auto_pause;
+#[cfg(all(feature = "assets", feature = "profile"))]
+pub mod avatar;
+#[cfg(feature = "editor")]
+pub mod challenge_editor;
+#[cfg(feature = "profile")]
+pub mod class_report;
+#[cfg(feature = "profile")]
+pub mod classroom;
+pub mod crash;
 pub mod credits;
+pub mod events;
+#[cfg(feature = "input")]
+pub mod hot_seat;
+pub mod inactivity;
+pub mod inbox;
+#[cfg(feature = "editor")]
+pub mod level_editor;
+pub mod links;
+pub mod lobby;
+pub mod main_menu;
+pub mod marketplace;
+pub mod redeem_code;
+#[cfg(feature = "profile")]
+pub mod replay_viewer;
+pub mod session_summary;
 pub mod settings;
+#[cfg(feature = "profile")]
+pub mod setup_wizard;
+pub mod shell;
 pub mod splash;
+pub mod state;
+#[cfg(feature = "assets")]
+pub mod survey;
+pub mod window_branding;
+pub mod world_space;
 
 pub use about::*;
+#[cfg(feature = "asset-inspector")]
+pub use asset_inspector::*;
+pub use auto_pause::*;
+#[cfg(all(feature = "assets", feature = "profile"))]
+pub use avatar::*;
+#[cfg(feature = "editor")]
+pub use challenge_editor::*;
+#[cfg(feature = "profile")]
+pub use class_report::*;
+#[cfg(feature = "profile")]
+pub use classroom::*;
+pub use crash::*;
 pub use credits::*;
+pub use events::*;
+#[cfg(feature = "input")]
+pub use hot_seat::*;
+pub use inactivity::*;
+pub use inbox::*;
+#[cfg(feature = "editor")]
+pub use level_editor::*;
+pub use links::*;
+pub use lobby::*;
+pub use main_menu::*;
+pub use marketplace::*;
+pub use redeem_code::*;
+#[cfg(feature = "profile")]
+pub use replay_viewer::*;
+pub use session_summary::*;
 pub use settings::*;
+#[cfg(feature = "profile")]
+pub use setup_wizard::*;
+pub use shell::*;
 pub use splash::*;
+pub use state::*;
+#[cfg(feature = "assets")]
+pub use survey::*;
+pub use window_branding::*;
+pub use world_space::*;
 
-use bevy::prelude::*;
+use bevy::{ecs::system::EntityCommands, prelude::*};
+use std::{any::TypeId, collections::HashMap};
 
 /// Main screens plugin that includes all screen functionality
 pub struct ScreensPlugin;
@@ -18,11 +89,309 @@ impl Plugin for ScreensPlugin {
         app.add_plugins(SplashPlugin)
             .add_plugins(AboutPlugin)
             .add_plugins(CreditsPlugin)
+            .add_plugins(InboxPlugin)
+            .add_plugins(MarketplacePlugin)
+            .add_plugins(RedeemCodePlugin)
+            .add_plugins(LobbyPlugin)
+            .add_plugins(MainMenuPlugin)
+            .add_plugins(SessionSummaryPlugin)
             .add_plugins(SettingsScreenPlugin)
+            .add_plugins(WorldSpaceScreenPlugin)
             .add_message::<SplashDismissed>()
             .add_message::<CreditsDismissed>()
             .add_message::<AboutDismissed>();
 
-        info!("ScreensPlugin loaded with splash, about, and settings screen support");
+        #[cfg(all(feature = "assets", feature = "profile"))]
+        app.add_plugins(AvatarPlugin);
+
+        #[cfg(feature = "profile")]
+        app.add_plugins((
+            ClassReportPlugin,
+            ClassroomPlugin,
+            ReplayViewerPlugin,
+            SetupWizardPlugin,
+        ));
+
+        #[cfg(feature = "assets")]
+        app.add_plugins(SurveyPlugin);
+
+        #[cfg(feature = "editor")]
+        app.add_plugins((ChallengeEditorPlugin, LevelEditorPlugin));
+
+        #[cfg(feature = "asset-inspector")]
+        app.add_plugins(AssetInspectorPlugin);
+
+        app.init_resource::<AnyScreenActive>()
+            .add_message::<ScreenEvent>();
+
+        info!("ScreensPlugin loaded with splash, about, inbox, marketplace, redeem code, lobby and settings screen support");
+    }
+}
+
+/// Resource tracking the currently active screens.
+///
+/// Maintained by observers registered with [`track_active_screen`], so it
+/// costs nothing on frames where no screen is shown. The screens are kept in
+/// the order they were opened, which makes it the navigation stack that
+/// [`NavigateBack`] works on.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnyScreenActive {
+    screens: Vec<ActiveScreen>,
+}
+
+/// Entity of an active screen with the type and name of its active component
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ActiveScreen {
+    entity: Entity,
+    component: TypeId,
+    name: &'static str,
+}
+
+impl AnyScreenActive {
+    /// Whether any screen is active
+    pub fn is_active(&self) -> bool {
+        !self.screens.is_empty()
+    }
+
+    /// Number of active screens
+    pub fn count(&self) -> usize {
+        self.screens.len()
+    }
+
+    /// Entities of the active screens with the name of their active component
+    pub fn screens(&self) -> impl Iterator<Item = (Entity, &'static str)> + '_ {
+        self.screens
+            .iter()
+            .map(|screen| (screen.entity, screen.name))
+    }
+
+    /// The most recently opened screen
+    pub fn top(&self) -> Option<Entity> {
+        self.screens.last().map(|screen| screen.entity)
+    }
+
+    /// The screen opened before the given one, i.e. where going back leads
+    pub fn previous(&self, entity: Entity) -> Option<Entity> {
+        let index = self.screens.iter().position(|s| s.entity == entity)?;
+        index
+            .checked_sub(1)
+            .map(|previous| self.screens[previous].entity)
+    }
+}
+
+/// Message to go back in the screen navigation stack.
+///
+/// Closes every screen opened after `to`, or all screens when `to` is `None`,
+/// e.g. when the "Home" segment of [`ThemedBreadcrumbs`] is clicked.
+///
+/// [`ThemedBreadcrumbs`]: crate::ui::ThemedBreadcrumbs
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NavigateBack {
+    pub to: Option<Entity>,
+}
+
+impl NavigateBack {
+    pub fn to(entity: Entity) -> Self {
+        Self { to: Some(entity) }
+    }
+
+    pub fn home() -> Self {
+        Self { to: None }
+    }
+}
+
+/// How to close each tracked screen, by the type of its active component
+#[derive(Resource, Default)]
+struct ScreenClosers(HashMap<TypeId, fn(&mut EntityCommands)>);
+
+fn close_screen<T: Component>(entity: &mut EntityCommands) {
+    entity.remove::<T>();
+}
+
+/// System to close the screens above the target of [`NavigateBack`]
+fn navigate_back(
+    mut commands: Commands,
+    mut messages: MessageReader<NavigateBack>,
+    screens: Res<AnyScreenActive>,
+    closers: Res<ScreenClosers>,
+) {
+    let Some(message) = messages.read().last() else {
+        return;
+    };
+
+    let keep = match message.to {
+        Some(target) => match screens.screens.iter().position(|s| s.entity == target) {
+            Some(index) => index + 1,
+            None => {
+                warn!(
+                    "Cannot navigate back to {:?}, it is not an active screen",
+                    target
+                );
+                return;
+            }
+        },
+        None => 0,
+    };
+
+    for screen in screens.screens[keep..].iter().rev() {
+        if let (Some(close), Ok(mut entity)) = (
+            closers.0.get(&screen.component),
+            commands.get_entity(screen.entity),
+        ) {
+            close(&mut entity);
+        }
+    }
+}
+
+/// Run condition that is true while any screen is active
+pub fn any_screen_active(screens: Option<Res<AnyScreenActive>>) -> bool {
+    screens.is_some_and(|screens| screens.is_active())
+}
+
+/// Count the entities with the active component `T` of a screen in
+/// [`AnyScreenActive`], tracking the same component again has no effect
+pub fn track_active_screen<T: Component>(app: &mut App) {
+    if !app.world().contains_resource::<ScreenClosers>() {
+        app.init_resource::<ScreenClosers>()
+            .add_message::<NavigateBack>()
+            .add_systems(PreUpdate, navigate_back);
+    }
+    let mut closers = app.world_mut().resource_mut::<ScreenClosers>();
+    if closers.0.contains_key(&TypeId::of::<T>()) {
+        return;
+    }
+    closers.0.insert(TypeId::of::<T>(), close_screen::<T>);
+
+    app.init_resource::<AnyScreenActive>()
+        .add_observer(|add: On<Add, T>, mut screens: ResMut<AnyScreenActive>| {
+            screens.screens.push(ActiveScreen {
+                entity: add.entity,
+                component: TypeId::of::<T>(),
+                name: screen_name::<T>(),
+            });
+        })
+        .add_observer(
+            |remove: On<Remove, T>, mut screens: ResMut<AnyScreenActive>| {
+                let screen = ActiveScreen {
+                    entity: remove.entity,
+                    component: TypeId::of::<T>(),
+                    name: screen_name::<T>(),
+                };
+                if let Some(index) = screens.screens.iter().position(|s| *s == screen) {
+                    screens.screens.remove(index);
+                }
+            },
+        );
+}
+
+fn screen_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component)]
+    struct ActiveTestScreen;
+
+    #[test]
+    fn test_any_screen_active_tracking() {
+        let mut app = App::new();
+        track_active_screen::<ActiveTestScreen>(&mut app);
+        assert!(!app.world().resource::<AnyScreenActive>().is_active());
+
+        let entity = app.world_mut().spawn(ActiveTestScreen).id();
+        assert_eq!(app.world().resource::<AnyScreenActive>().count(), 1);
+
+        app.world_mut()
+            .entity_mut(entity)
+            .remove::<ActiveTestScreen>();
+        assert!(!app.world().resource::<AnyScreenActive>().is_active());
+    }
+
+    #[derive(Component)]
+    struct ActiveOtherScreen;
+
+    mod other {
+        use bevy::prelude::*;
+
+        /// Same name as the screen component of the tests module
+        #[derive(Component)]
+        #[component(on_add = on_add)]
+        pub struct ActiveTestScreen;
+
+        fn on_add(
+            _world: bevy::ecs::world::DeferredWorld,
+            _context: bevy::ecs::lifecycle::HookContext,
+        ) {
+        }
+    }
+
+    #[test]
+    fn test_track_active_screen_by_type() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        track_active_screen::<ActiveTestScreen>(&mut app);
+        // Components with hooks and tracking twice work too
+        track_active_screen::<other::ActiveTestScreen>(&mut app);
+        track_active_screen::<other::ActiveTestScreen>(&mut app);
+
+        let first = app.world_mut().spawn(ActiveTestScreen).id();
+        let second = app.world_mut().spawn(other::ActiveTestScreen).id();
+        assert_eq!(app.world().resource::<AnyScreenActive>().count(), 2);
+
+        // Going back closes the screen by its component type
+        app.world_mut().write_message(NavigateBack::to(first));
+        app.update();
+        assert!(!app
+            .world()
+            .entity(second)
+            .contains::<other::ActiveTestScreen>());
+        assert!(app.world().entity(first).contains::<ActiveTestScreen>());
+        assert_eq!(app.world().resource::<AnyScreenActive>().count(), 1);
+    }
+
+    #[test]
+    fn test_navigate_back() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        track_active_screen::<ActiveTestScreen>(&mut app);
+        track_active_screen::<ActiveOtherScreen>(&mut app);
+
+        let first = app.world_mut().spawn(ActiveTestScreen).id();
+        let second = app.world_mut().spawn(ActiveOtherScreen).id();
+        let third = app.world_mut().spawn(ActiveTestScreen).id();
+        let screens = app.world().resource::<AnyScreenActive>();
+        assert_eq!(screens.top(), Some(third));
+        assert_eq!(screens.previous(third), Some(second));
+
+        app.world_mut().write_message(NavigateBack::to(first));
+        app.update();
+        let screens = app.world().resource::<AnyScreenActive>();
+        assert_eq!(screens.top(), Some(first));
+        assert!(!app.world().entity(second).contains::<ActiveOtherScreen>());
+
+        app.world_mut().write_message(NavigateBack::home());
+        app.update();
+        assert!(!app.world().resource::<AnyScreenActive>().is_active());
+    }
+
+    #[test]
+    fn test_screen_events_forwarded() {
+        let mut app = App::new();
+        forward_screen_events::<AboutDismissed>(&mut app);
+
+        let entity = app.world_mut().spawn_empty().id();
+        app.world_mut().write_message(AboutDismissed { entity });
+        app.update();
+
+        let events = app.world().resource::<Messages<ScreenEvent>>();
+        let event = events.iter_current_update_messages().next().unwrap();
+        assert_eq!(
+            *event,
+            ScreenEvent::new(ScreenKind::About, ScreenAction::Dismissed, entity)
+        );
     }
 }
@@ -0,0 +1,698 @@
+use super::{forward_screen_events, track_active_screen, ScreenAction, ScreenKind, ScreenMessage};
+use crate::{
+    theme::KonnektorenTheme,
+    ui::{
+        notifications::Notification,
+        responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
+        widgets::{ResponsiveText, SpinnerWidget, ThemedButton},
+    },
+};
+use bevy::{
+    prelude::*,
+    tasks::{block_on, futures_lite::future, BoxedFuture, IoTaskPool, Task},
+};
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+use std::{collections::HashMap, sync::Arc};
+
+/// Plugin for the redeem code screen
+pub struct RedeemCodePlugin;
+
+impl Plugin for RedeemCodePlugin {
+    fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveRedeemCode>(app);
+        forward_screen_events::<RedeemCodeEvent>(app);
+
+        app.add_message::<RedeemCodeEvent>()
+            .add_message::<Notification>()
+            .init_resource::<CodeRedeemerResource>()
+            .add_systems(
+                Update,
+                (
+                    check_redeem_code_config,
+                    start_code_redemption,
+                    poll_code_redemption,
+                    handle_redeem_code_completion,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_redeem_code_ui.run_if(any_with_component::<ActiveRedeemCode>),
+            );
+    }
+}
+
+/// Something a code unlocks
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RedeemReward {
+    /// Unlocks a content pack by ID
+    ContentPack(String),
+    /// Unlocks an achievement by ID
+    Achievement(String),
+    /// Game specific reward
+    Custom(String),
+}
+
+impl RedeemReward {
+    /// Human readable description of the reward
+    pub fn description(&self) -> String {
+        match self {
+            RedeemReward::ContentPack(id) => format!("Content pack \"{}\" unlocked", id),
+            RedeemReward::Achievement(id) => format!("Achievement \"{}\" unlocked", id),
+            RedeemReward::Custom(name) => name.clone(),
+        }
+    }
+}
+
+/// Result of a code redemption
+pub type RedeemResult = Result<Vec<RedeemReward>, String>;
+
+/// Backend that redeems codes, e.g. by asking a server
+pub trait CodeRedeemer: Send + Sync + 'static {
+    /// Redeem a (normalized) code. Runs on the IO task pool.
+    fn redeem(&self, code: String) -> BoxedFuture<'static, RedeemResult>;
+}
+
+/// Code redeemer backed by a fixed table of codes, useful for offline games and tests
+#[derive(Debug, Clone, Default)]
+pub struct StaticCodeRedeemer {
+    codes: HashMap<String, Vec<RedeemReward>>,
+}
+
+impl StaticCodeRedeemer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a code with its rewards
+    pub fn add_code(mut self, code: impl Into<String>, rewards: Vec<RedeemReward>) -> Self {
+        self.codes.insert(normalize_code(&code.into()), rewards);
+        self
+    }
+}
+
+impl CodeRedeemer for StaticCodeRedeemer {
+    fn redeem(&self, code: String) -> BoxedFuture<'static, RedeemResult> {
+        let result = self
+            .codes
+            .get(&code)
+            .cloned()
+            .ok_or_else(|| "This code is not valid".to_string());
+        Box::pin(async move { result })
+    }
+}
+
+/// Resource holding the active code redeemer
+#[derive(Resource, Clone)]
+pub struct CodeRedeemerResource(pub Arc<dyn CodeRedeemer>);
+
+impl Default for CodeRedeemerResource {
+    fn default() -> Self {
+        Self(Arc::new(StaticCodeRedeemer::new()))
+    }
+}
+
+impl CodeRedeemerResource {
+    pub fn new(redeemer: impl CodeRedeemer) -> Self {
+        Self(Arc::new(redeemer))
+    }
+}
+
+/// Validation callback for entered codes, returns an error message for invalid codes
+pub type CodeValidator = Arc<dyn Fn(&str) -> Result<(), String> + Send + Sync>;
+
+/// Configuration for the redeem code screen
+#[derive(Component, Clone)]
+pub struct RedeemCodeConfig {
+    pub title: String,
+    pub description: Option<String>,
+    pub placeholder: String,
+    /// Number of characters of a complete code (without separators)
+    pub code_length: usize,
+    /// Characters per group when formatting, 0 disables grouping
+    pub group_size: usize,
+    pub separator: char,
+    pub submit_button_text: String,
+    pub dismiss_button_text: String,
+    /// Allow manual dismissal (back button/escape)
+    pub manual_dismissal: bool,
+    /// Dismiss the screen after a successful redemption
+    pub dismiss_on_success: bool,
+    /// Additional validation of the normalized code
+    pub validator: Option<CodeValidator>,
+}
+
+impl Default for RedeemCodeConfig {
+    fn default() -> Self {
+        Self {
+            title: "Redeem Code".to_string(),
+            description: Some("Enter your code to unlock new content".to_string()),
+            placeholder: "XXXX-XXXX-XXXX".to_string(),
+            code_length: 12,
+            group_size: 4,
+            separator: '-',
+            submit_button_text: "Redeem".to_string(),
+            dismiss_button_text: "← Back".to_string(),
+            manual_dismissal: true,
+            dismiss_on_success: false,
+            validator: None,
+        }
+    }
+}
+
+impl RedeemCodeConfig {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set the code format, e.g. `with_format(12, 4, '-')` for `XXXX-XXXX-XXXX`
+    pub fn with_format(mut self, code_length: usize, group_size: usize, separator: char) -> Self {
+        self.code_length = code_length;
+        self.group_size = group_size;
+        self.separator = separator;
+        self
+    }
+
+    pub fn with_submit_button_text(mut self, text: impl Into<String>) -> Self {
+        self.submit_button_text = text.into();
+        self
+    }
+
+    pub fn with_dismiss_button_text(mut self, text: impl Into<String>) -> Self {
+        self.dismiss_button_text = text.into();
+        self
+    }
+
+    pub fn with_manual_dismissal(mut self, manual_dismissal: bool) -> Self {
+        self.manual_dismissal = manual_dismissal;
+        self
+    }
+
+    pub fn with_dismiss_on_success(mut self, dismiss_on_success: bool) -> Self {
+        self.dismiss_on_success = dismiss_on_success;
+        self
+    }
+
+    pub fn with_validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.validator = Some(Arc::new(validator));
+        self
+    }
+
+    /// Format raw user input into the grouped display form
+    pub fn format_code(&self, input: &str) -> String {
+        let mut normalized = normalize_code(input);
+        if self.code_length > 0 {
+            normalized = normalized.chars().take(self.code_length).collect();
+        }
+
+        if self.group_size == 0 {
+            return normalized;
+        }
+
+        let mut formatted = String::with_capacity(normalized.len() * 2);
+        for (i, c) in normalized.chars().enumerate() {
+            if i > 0 && i % self.group_size == 0 {
+                formatted.push(self.separator);
+            }
+            formatted.push(c);
+        }
+        formatted
+    }
+
+    /// Validate a normalized code
+    pub fn validate(&self, code: &str) -> Result<(), String> {
+        if code.is_empty() {
+            return Err("Please enter a code".to_string());
+        }
+
+        if self.code_length > 0 && code.chars().count() != self.code_length {
+            return Err(format!(
+                "The code must have {} characters",
+                self.code_length
+            ));
+        }
+
+        match &self.validator {
+            Some(validator) => validator(code),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Normalize a code by removing separators/whitespace and uppercasing it
+pub fn normalize_code(input: &str) -> String {
+    input
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Component marking an active redeem code screen
+#[derive(Component)]
+pub struct ActiveRedeemCode {
+    config: RedeemCodeConfig,
+    input: String,
+    error: Option<String>,
+    rewards: Vec<RedeemReward>,
+    pending: Option<Task<RedeemResult>>,
+    submitted_code: Option<String>,
+}
+
+impl ActiveRedeemCode {
+    /// Check if a redemption is in progress
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+}
+
+/// Events for redeem code interactions
+#[derive(Message, Debug, Clone)]
+pub enum RedeemCodeEvent {
+    /// A valid code was submitted
+    Submitted { entity: Entity, code: String },
+    /// The code was redeemed successfully
+    Redeemed {
+        entity: Entity,
+        code: String,
+        rewards: Vec<RedeemReward>,
+    },
+    /// Redemption failed
+    Failed {
+        entity: Entity,
+        code: String,
+        error: String,
+    },
+    /// Redeem code screen dismissed
+    Dismissed { entity: Entity },
+}
+
+impl ScreenMessage for RedeemCodeEvent {
+    const KIND: ScreenKind = ScreenKind::RedeemCode;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::Submitted { entity, .. } => Some((ScreenAction::Submitted, *entity)),
+            Self::Redeemed { entity, .. } | Self::Failed { entity, .. } => {
+                Some((ScreenAction::Changed, *entity))
+            }
+            Self::Dismissed { entity } => Some((ScreenAction::Dismissed, *entity)),
+        }
+    }
+}
+
+/// System to check for new redeem code configurations and set them up
+#[allow(clippy::type_complexity)]
+fn check_redeem_code_config(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &RedeemCodeConfig),
+        (Without<ActiveRedeemCode>, Changed<RedeemCodeConfig>),
+    >,
+    existing: Query<Entity, With<ActiveRedeemCode>>,
+) {
+    for (entity, config) in query.iter() {
+        info!("Setting up redeem code screen for entity {:?}", entity);
+
+        // Clean up any existing redeem code screens first
+        for existing_entity in existing.iter() {
+            commands
+                .entity(existing_entity)
+                .remove::<ActiveRedeemCode>();
+        }
+
+        commands.entity(entity).insert(ActiveRedeemCode {
+            config: config.clone(),
+            input: String::new(),
+            error: None,
+            rewards: vec![],
+            pending: None,
+            submitted_code: None,
+        });
+    }
+}
+
+/// System to start the redemption of submitted codes
+fn start_code_redemption(
+    mut events: MessageReader<RedeemCodeEvent>,
+    redeemer: Res<CodeRedeemerResource>,
+    mut query: Query<&mut ActiveRedeemCode>,
+) {
+    for event in events.read() {
+        let RedeemCodeEvent::Submitted { entity, code } = event else {
+            continue;
+        };
+
+        let Ok(mut active) = query.get_mut(*entity) else {
+            continue;
+        };
+
+        if active.pending.is_some() {
+            continue;
+        }
+
+        info!("Redeeming code for entity {:?}", entity);
+        let future = redeemer.0.redeem(code.clone());
+        active.pending = Some(IoTaskPool::get().spawn(future));
+        active.submitted_code = Some(code.clone());
+        active.error = None;
+    }
+}
+
+/// System to poll running redemptions
+fn poll_code_redemption(
+    mut query: Query<(Entity, &mut ActiveRedeemCode)>,
+    mut events: MessageWriter<RedeemCodeEvent>,
+    mut notifications: MessageWriter<Notification>,
+) {
+    for (entity, mut active) in query.iter_mut() {
+        let Some(task) = active.pending.as_mut() else {
+            continue;
+        };
+
+        let Some(result) = block_on(future::poll_once(task)) else {
+            continue;
+        };
+
+        active.pending = None;
+        let code = active.submitted_code.take().unwrap_or_default();
+
+        match result {
+            Ok(rewards) => {
+                let message = rewards
+                    .iter()
+                    .map(RedeemReward::description)
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                notifications.write(Notification::success("Code redeemed", message));
+
+                active.input.clear();
+                active.rewards = rewards.clone();
+                events.write(RedeemCodeEvent::Redeemed {
+                    entity,
+                    code,
+                    rewards,
+                });
+            }
+            Err(error) => {
+                warn!("Code redemption failed: {}", error);
+                notifications.write(Notification::error("Code not redeemed", error.clone()));
+
+                active.error = Some(error.clone());
+                events.write(RedeemCodeEvent::Failed {
+                    entity,
+                    code,
+                    error,
+                });
+            }
+        }
+    }
+}
+
+/// System to handle dismissal of the redeem code screen
+fn handle_redeem_code_completion(
+    mut commands: Commands,
+    mut events: MessageReader<RedeemCodeEvent>,
+    query: Query<&ActiveRedeemCode>,
+) {
+    for event in events.read() {
+        match event {
+            RedeemCodeEvent::Dismissed { entity } => {
+                info!("Dismissing redeem code screen for entity {:?}", entity);
+                commands.entity(*entity).remove::<ActiveRedeemCode>();
+            }
+            RedeemCodeEvent::Redeemed { entity, .. }
+                if query
+                    .get(*entity)
+                    .is_ok_and(|active| active.config.dismiss_on_success) =>
+            {
+                commands.entity(*entity).remove::<ActiveRedeemCode>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// System to render the redeem code UI
+fn render_redeem_code_ui(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    mut query: Query<(Entity, &mut ActiveRedeemCode)>,
+    mut events: MessageWriter<RedeemCodeEvent>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    if query.is_empty() {
+        return;
+    }
+
+    if let Ok(ctx) = contexts.ctx_mut() {
+        // Only render the first (most recent) screen to avoid widget ID conflicts
+        if let Some((entity, mut active)) = query.iter_mut().next() {
+            if active.config.manual_dismissal && input.just_pressed(KeyCode::Escape) {
+                events.write(RedeemCodeEvent::Dismissed { entity });
+                return;
+            }
+
+            egui::CentralPanel::default()
+                .frame(egui::Frame::NONE.fill(theme.base_100))
+                .show(ctx, |ui| {
+                    scoped_ui(ui, (entity, "redeem_code"), |ui| {
+                        render_redeem_code_content(
+                            ui,
+                            &mut active,
+                            &theme,
+                            &responsive,
+                            entity,
+                            &mut events,
+                        );
+                    })
+                });
+        }
+    }
+}
+
+/// Render redeem code screen content
+fn render_redeem_code_content(
+    ui: &mut egui::Ui,
+    active: &mut ActiveRedeemCode,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    events: &mut MessageWriter<RedeemCodeEvent>,
+) {
+    let config = active.config.clone();
+
+    ui.vertical_centered(|ui| {
+        let max_width = if responsive.is_mobile() {
+            ui.available_width() * 0.95
+        } else {
+            500.0_f32.min(ui.available_width() * 0.9)
+        };
+
+        ui.set_max_width(max_width);
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::XLarge));
+        ResponsiveText::new(&config.title, ResponsiveFontSize::Title, theme.primary)
+            .responsive(responsive)
+            .strong()
+            .ui(ui);
+
+        if let Some(description) = &config.description {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+            ResponsiveText::new(description, ResponsiveFontSize::Medium, theme.base_content)
+                .responsive(responsive)
+                .ui(ui);
+        }
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+        // Code entry field
+        let font_size = responsive.font_size(ResponsiveFontSize::Large);
+        let text_edit = egui::TextEdit::singleline(&mut active.input)
+            .id_salt("redeem_code_input")
+            .hint_text(&config.placeholder)
+            .font(egui::FontId::monospace(font_size))
+            .horizontal_align(egui::Align::Center)
+            .desired_width(max_width * 0.8)
+            .margin(egui::Margin::symmetric(12, 10))
+            .interactive(active.pending.is_none());
+
+        let response = ui.add(text_edit);
+        if response.changed() {
+            active.input = config.format_code(&active.input);
+            active.error = None;
+        }
+
+        let submit_with_enter =
+            response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+
+        // Inline feedback
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+        if let Some(error) = &active.error {
+            ResponsiveText::new(error, ResponsiveFontSize::Small, theme.error)
+                .responsive(responsive)
+                .ui(ui);
+        } else if !active.rewards.is_empty() {
+            for reward in &active.rewards {
+                ResponsiveText::new(
+                    &format!("✔ {}", reward.description()),
+                    ResponsiveFontSize::Small,
+                    theme.success,
+                )
+                .responsive(responsive)
+                .ui(ui);
+            }
+        }
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+        if active.pending.is_some() {
+            SpinnerWidget::new(theme, 32.0)
+                .responsive(responsive)
+                .ui(ui);
+        } else {
+            let submit_button = ThemedButton::new(&config.submit_button_text, theme)
+                .responsive(responsive)
+                .width(if responsive.is_mobile() { 200.0 } else { 250.0 })
+                .enabled(!active.input.is_empty());
+
+            if ui.add(submit_button).clicked() || submit_with_enter {
+                let code = normalize_code(&active.input);
+                match config.validate(&code) {
+                    Ok(()) => {
+                        active.rewards.clear();
+                        events.write(RedeemCodeEvent::Submitted { entity, code });
+                    }
+                    Err(error) => active.error = Some(error),
+                }
+            }
+        }
+
+        if config.manual_dismissal {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+            let back_button = ThemedButton::new(&config.dismiss_button_text, theme)
+                .responsive(responsive)
+                .width(if responsive.is_mobile() { 200.0 } else { 250.0 });
+
+            if ui.add(back_button).clicked() {
+                events.write(RedeemCodeEvent::Dismissed { entity });
+            }
+        }
+    });
+}
+
+/// Helper trait for easy redeem code screen setup
+pub trait RedeemCodeScreenExt {
+    /// Add a redeem code screen with the given configuration
+    fn spawn_redeem_code(&mut self, config: RedeemCodeConfig) -> Entity;
+
+    /// Add a redeem code screen with the default configuration
+    fn spawn_simple_redeem_code(&mut self) -> Entity;
+}
+
+impl RedeemCodeScreenExt for Commands<'_, '_> {
+    fn spawn_redeem_code(&mut self, config: RedeemCodeConfig) -> Entity {
+        self.spawn((Name::new("Redeem Code Screen"), config)).id()
+    }
+
+    fn spawn_simple_redeem_code(&mut self) -> Entity {
+        self.spawn_redeem_code(RedeemCodeConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+    use egui_kittest::kittest::Queryable;
+
+    fn redeem(harness: &mut TestHarness, code: &str) -> RedeemCodeEvent {
+        let field = harness
+            .kittest()
+            .get_by_role(egui::accesskit::Role::TextInput)
+            .rect();
+        harness.click_at(field.center());
+        harness.type_text(code);
+        harness.click("Redeem");
+
+        // The redeemer runs on the IO task pool
+        for _ in 0..200 {
+            let result = harness
+                .read_messages::<RedeemCodeEvent>()
+                .into_iter()
+                .find(|event| {
+                    matches!(
+                        event,
+                        RedeemCodeEvent::Redeemed { .. } | RedeemCodeEvent::Failed { .. }
+                    )
+                });
+            if let Some(event) = result {
+                harness.step();
+                return event;
+            }
+            harness.step();
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        panic!("code was not redeemed");
+    }
+
+    #[test]
+    fn test_redeem_code() {
+        let mut harness =
+            TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin, RedeemCodePlugin));
+        harness
+            .world_mut()
+            .insert_resource(CodeRedeemerResource::new(
+                StaticCodeRedeemer::new().add_code(
+                    "ABCD-EFGH-IJKL",
+                    vec![RedeemReward::ContentPack("starter".to_string())],
+                ),
+            ));
+        harness.world_mut().spawn(RedeemCodeConfig::default());
+        harness.run_frames(2);
+
+        let event = redeem(&mut harness, "abcdefghijkl");
+        assert!(matches!(
+            event,
+            RedeemCodeEvent::Redeemed { code, rewards, .. }
+                if code == "ABCDEFGHIJKL"
+                    && rewards == vec![RedeemReward::ContentPack("starter".to_string())]
+        ));
+        assert!(harness.has_widget("✔ Content pack \"starter\" unlocked"));
+        let notifications = harness.read_messages::<Notification>();
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].title, "Code redeemed");
+
+        let event = redeem(&mut harness, "zzzzzzzzzzzz");
+        assert!(matches!(
+            event,
+            RedeemCodeEvent::Failed { code, error, .. }
+                if code == "ZZZZZZZZZZZZ" && error == "This code is not valid"
+        ));
+        assert!(harness.has_widget("This code is not valid"));
+        let notifications = harness.read_messages::<Notification>();
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].title, "Code not redeemed");
+    }
+}
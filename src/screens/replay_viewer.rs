@@ -0,0 +1,497 @@
+use super::{forward_screen_events, track_active_screen, ScreenAction, ScreenKind, ScreenMessage};
+use crate::{
+    profile::{ChallengeRuntimeEvent, Replay},
+    theme::KonnektorenTheme,
+    ui::{
+        responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
+        widgets::{ResponsiveText, ThemedButton},
+    },
+};
+use bevy::prelude::*;
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+
+/// Plugin for the replay viewer screen
+pub struct ReplayViewerPlugin;
+
+impl Plugin for ReplayViewerPlugin {
+    fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveReplayViewer>(app);
+        forward_screen_events::<ReplayViewerEvent>(app);
+
+        app.add_message::<ReplayViewerEvent>()
+            .add_systems(
+                Update,
+                (
+                    check_replay_viewer_config,
+                    handle_replay_viewer_events,
+                    advance_replay_playback,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_replay_viewer_ui.run_if(any_with_component::<ActiveReplayViewer>),
+            );
+    }
+}
+
+/// Configuration for the replay viewer screen
+#[derive(Component, Clone)]
+pub struct ReplayViewerConfig {
+    pub title: String,
+    pub replay: Replay,
+    /// Start playing right away
+    pub autoplay: bool,
+    pub manual_dismissal: bool,
+    pub dismiss_button_text: String,
+}
+
+impl ReplayViewerConfig {
+    pub fn new(replay: Replay) -> Self {
+        Self {
+            title: "Replay".to_string(),
+            replay,
+            autoplay: false,
+            manual_dismissal: true,
+            dismiss_button_text: "Back".to_string(),
+        }
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn with_autoplay(mut self, autoplay: bool) -> Self {
+        self.autoplay = autoplay;
+        self
+    }
+
+    pub fn with_manual_dismissal(mut self, manual_dismissal: bool) -> Self {
+        self.manual_dismissal = manual_dismissal;
+        self
+    }
+
+    pub fn with_dismiss_button_text(mut self, text: impl Into<String>) -> Self {
+        self.dismiss_button_text = text.into();
+        self
+    }
+}
+
+/// Component marking an active replay viewer screen
+#[derive(Component)]
+pub struct ActiveReplayViewer {
+    config: ReplayViewerConfig,
+    /// Playback position in seconds since the challenge started
+    pub position_secs: f32,
+    pub playing: bool,
+}
+
+impl ActiveReplayViewer {
+    pub fn replay(&self) -> &Replay {
+        &self.config.replay
+    }
+
+    /// Number of events shown at the current position
+    pub fn shown_events(&self) -> usize {
+        self.config.replay.events_until(self.position_secs)
+    }
+}
+
+/// Events for replay viewer interactions
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub enum ReplayViewerEvent {
+    Play {
+        entity: Entity,
+    },
+    Pause {
+        entity: Entity,
+    },
+    /// Jump to a position in seconds
+    Seek {
+        entity: Entity,
+        position_secs: f32,
+    },
+    /// Jump to the previous event
+    StepBack {
+        entity: Entity,
+    },
+    /// Jump to the next event
+    StepForward {
+        entity: Entity,
+    },
+    /// Replay viewer screen dismissed
+    Dismissed {
+        entity: Entity,
+    },
+}
+
+impl ScreenMessage for ReplayViewerEvent {
+    const KIND: ScreenKind = ScreenKind::ReplayViewer;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::Dismissed { entity } => Some((ScreenAction::Dismissed, *entity)),
+            _ => None,
+        }
+    }
+}
+
+/// System to check for new replay viewer configurations and set them up
+#[allow(clippy::type_complexity)]
+fn check_replay_viewer_config(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &ReplayViewerConfig),
+        (Without<ActiveReplayViewer>, Changed<ReplayViewerConfig>),
+    >,
+    existing: Query<Entity, With<ActiveReplayViewer>>,
+) {
+    for (entity, config) in query.iter() {
+        info!("Setting up replay viewer screen for entity {:?}", entity);
+
+        for existing_entity in existing.iter() {
+            commands
+                .entity(existing_entity)
+                .remove::<ActiveReplayViewer>();
+        }
+
+        commands.entity(entity).insert(ActiveReplayViewer {
+            config: config.clone(),
+            position_secs: 0.0,
+            playing: config.autoplay,
+        });
+    }
+}
+
+/// System to handle the playback controls and dismissal
+fn handle_replay_viewer_events(
+    mut commands: Commands,
+    mut events: MessageReader<ReplayViewerEvent>,
+    mut query: Query<&mut ActiveReplayViewer>,
+) {
+    for event in events.read() {
+        if let ReplayViewerEvent::Dismissed { entity } = event {
+            info!("Dismissing replay viewer screen for entity {:?}", entity);
+            commands.entity(*entity).remove::<ActiveReplayViewer>();
+            continue;
+        }
+
+        let (ReplayViewerEvent::Play { entity }
+        | ReplayViewerEvent::Pause { entity }
+        | ReplayViewerEvent::Seek { entity, .. }
+        | ReplayViewerEvent::StepBack { entity }
+        | ReplayViewerEvent::StepForward { entity }
+        | ReplayViewerEvent::Dismissed { entity }) = event;
+        let Ok(mut active) = query.get_mut(*entity) else {
+            continue;
+        };
+
+        let duration = active.replay().duration_secs();
+        match event {
+            ReplayViewerEvent::Play { .. } => {
+                // Start over when the end was reached
+                if active.position_secs >= duration {
+                    active.position_secs = 0.0;
+                }
+                active.playing = true;
+            }
+            ReplayViewerEvent::Pause { .. } => active.playing = false,
+            ReplayViewerEvent::Seek { position_secs, .. } => {
+                active.position_secs = position_secs.clamp(0.0, duration);
+            }
+            ReplayViewerEvent::StepBack { .. } => {
+                active.playing = false;
+                let shown = active.shown_events();
+                active.position_secs = shown
+                    .checked_sub(2)
+                    .map_or(0.0, |index| active.replay().events[index].time_secs);
+            }
+            ReplayViewerEvent::StepForward { .. } => {
+                active.playing = false;
+                let shown = active.shown_events();
+                if let Some(next) = active.replay().events.get(shown) {
+                    active.position_secs = next.time_secs;
+                }
+            }
+            ReplayViewerEvent::Dismissed { .. } => {}
+        }
+    }
+}
+
+/// System to advance playing replays in real time
+fn advance_replay_playback(time: Res<Time>, mut query: Query<&mut ActiveReplayViewer>) {
+    for mut active in query.iter_mut() {
+        if !active.playing {
+            continue;
+        }
+        let duration = active.replay().duration_secs();
+        active.position_secs = (active.position_secs + time.delta_secs()).min(duration);
+        if active.position_secs >= duration {
+            active.playing = false;
+        }
+    }
+}
+
+/// System to render the replay viewer UI
+fn render_replay_viewer_ui(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    query: Query<(Entity, &ActiveReplayViewer)>,
+    mut events: MessageWriter<ReplayViewerEvent>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    // Only render the first (most recent) screen to avoid widget ID conflicts
+    if let Some((entity, active)) = query.iter().next() {
+        if active.config.manual_dismissal && input.just_pressed(KeyCode::Escape) {
+            events.write(ReplayViewerEvent::Dismissed { entity });
+            return;
+        }
+
+        egui::CentralPanel::default()
+            .frame(egui::Frame::NONE.fill(theme.base_100))
+            .show(ctx, |ui| {
+                scoped_ui(ui, (entity, "replay_viewer"), |ui| {
+                    render_replay_viewer_content(
+                        ui,
+                        active,
+                        &theme,
+                        &responsive,
+                        entity,
+                        &mut events,
+                    );
+                })
+            });
+    }
+}
+
+/// Render the playback controls and the events up to the playback position
+fn render_replay_viewer_content(
+    ui: &mut egui::Ui,
+    active: &ActiveReplayViewer,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    events: &mut MessageWriter<ReplayViewerEvent>,
+) {
+    let config = &active.config;
+    let replay = &config.replay;
+    let duration = replay.duration_secs();
+
+    ui.vertical_centered(|ui| {
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+        ResponsiveText::new(&config.title, ResponsiveFontSize::Title, theme.primary)
+            .responsive(responsive)
+            .strong()
+            .ui(ui);
+
+        let score = replay
+            .score()
+            .map(|score| format!(" · Score {}%", score))
+            .unwrap_or_default();
+        ResponsiveText::new(
+            &format!(
+                "{} · {} · {}{}",
+                replay.challenge_id,
+                replay.player,
+                replay.recorded.format("%Y-%m-%d %H:%M"),
+                score
+            ),
+            ResponsiveFontSize::Small,
+            theme.base_content,
+        )
+        .responsive(responsive)
+        .ui(ui);
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+        let mut position = active.position_secs;
+        let slider = egui::Slider::new(&mut position, 0.0..=duration.max(0.1))
+            .show_value(false)
+            .text(format!(
+                "{} / {}",
+                format_time(active.position_secs),
+                format_time(duration)
+            ));
+        if ui.add(slider).changed() {
+            events.write(ReplayViewerEvent::Seek {
+                entity,
+                position_secs: position,
+            });
+        }
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+    });
+
+    let button_width = if responsive.is_mobile() { 100.0 } else { 140.0 };
+    egui::TopBottomPanel::bottom("replay_viewer_actions")
+        .frame(egui::Frame::NONE.fill(theme.base_100))
+        .show_inside(ui, |ui| {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+            ui.horizontal_wrapped(|ui| {
+                let shown = active.shown_events();
+                let previous = ThemedButton::new("Previous", theme)
+                    .responsive(responsive)
+                    .width(button_width)
+                    .enabled(active.position_secs > 0.0);
+                if ui.add(previous).clicked() {
+                    events.write(ReplayViewerEvent::StepBack { entity });
+                }
+
+                let (label, event) = if active.playing {
+                    ("Pause", ReplayViewerEvent::Pause { entity })
+                } else {
+                    ("Play", ReplayViewerEvent::Play { entity })
+                };
+                let play = ThemedButton::new(label, theme)
+                    .responsive(responsive)
+                    .width(button_width)
+                    .enabled(!replay.events.is_empty());
+                if ui.add(play).clicked() {
+                    events.write(event);
+                }
+
+                let next = ThemedButton::new("Next", theme)
+                    .responsive(responsive)
+                    .width(button_width)
+                    .enabled(shown < replay.events.len());
+                if ui.add(next).clicked() {
+                    events.write(ReplayViewerEvent::StepForward { entity });
+                }
+
+                if config.manual_dismissal {
+                    let back_button = ThemedButton::new(&config.dismiss_button_text, theme)
+                        .responsive(responsive)
+                        .width(button_width);
+                    if ui.add(back_button).clicked() {
+                        events.write(ReplayViewerEvent::Dismissed { entity });
+                    }
+                }
+            });
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+        });
+
+    let font_size = responsive.font_size(ResponsiveFontSize::Small);
+    egui::ScrollArea::vertical()
+        .stick_to_bottom(true)
+        .show(ui, |ui| {
+            for recorded in &replay.events[..active.shown_events()] {
+                let (text, color) = match &recorded.event {
+                    ChallengeRuntimeEvent::Started { challenge_id } => {
+                        (format!("Started {}", challenge_id), theme.info)
+                    }
+                    ChallengeRuntimeEvent::QuestionShown { index, question } => (
+                        format!("Question {}: {}", index + 1, question),
+                        theme.base_content,
+                    ),
+                    ChallengeRuntimeEvent::AnswerChosen {
+                        index,
+                        answer,
+                        correct,
+                    } => {
+                        let (mark, color) = match correct {
+                            Some(true) => (" ✔", theme.success),
+                            Some(false) => (" ✘", theme.error),
+                            None => ("", theme.secondary),
+                        };
+                        (format!("Answer {}: {}{}", index + 1, answer, mark), color)
+                    }
+                    ChallengeRuntimeEvent::AudioPlayed { index, clip } => {
+                        (format!("Audio {}: {}", index + 1, clip), theme.accent)
+                    }
+                    ChallengeRuntimeEvent::Finished { score } => {
+                        (format!("Finished with {}%", score), theme.primary)
+                    }
+                };
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(format_time(recorded.time_secs))
+                            .size(font_size)
+                            .color(theme.base_content)
+                            .monospace(),
+                    );
+                    ui.label(egui::RichText::new(text).size(font_size).color(color));
+                });
+            }
+        });
+}
+
+/// Format seconds as e.g. "1:05.3"
+fn format_time(secs: f32) -> String {
+    let tenths = (secs.max(0.0) * 10.0).round() as u32;
+    format!("{}:{:02}.{}", tenths / 600, tenths / 10 % 60, tenths % 10)
+}
+
+/// Helper trait for easy replay viewer screen setup
+pub trait ReplayViewerScreenExt {
+    /// Add a replay viewer screen with the given configuration
+    fn spawn_replay_viewer(&mut self, config: ReplayViewerConfig) -> Entity;
+}
+
+impl ReplayViewerScreenExt for Commands<'_, '_> {
+    fn spawn_replay_viewer(&mut self, config: ReplayViewerConfig) -> Entity {
+        self.spawn((Name::new("Replay Viewer Screen"), config)).id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        profile::{ReplayRecorded, ReplayRecorderPlugin},
+        test_utils::TestHarness,
+        theme::KonnektorenThemePlugin,
+        ui::UIPlugin,
+    };
+
+    #[test]
+    fn test_record_and_view_replay() {
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            ReplayRecorderPlugin,
+            ReplayViewerPlugin,
+        ));
+        for event in [
+            ChallengeRuntimeEvent::Started {
+                challenge_id: "articles".to_string(),
+            },
+            ChallengeRuntimeEvent::QuestionShown {
+                index: 0,
+                question: "__ Haus".to_string(),
+            },
+            ChallengeRuntimeEvent::AnswerChosen {
+                index: 0,
+                answer: "der".to_string(),
+                correct: Some(false),
+            },
+            ChallengeRuntimeEvent::Finished { score: 0 },
+        ] {
+            harness.world_mut().write_message(event);
+            harness.step();
+        }
+
+        let recorded = harness.read_messages::<ReplayRecorded>();
+        assert_eq!(recorded.len(), 1);
+        let replay = recorded[0].replay.clone();
+        assert_eq!(replay.events.len(), 4);
+        assert_eq!(replay.score(), Some(0));
+
+        harness.world_mut().spawn(ReplayViewerConfig::new(replay));
+        harness.run_frames(2);
+        assert!(harness.has_widget("Started articles"));
+        assert!(!harness.has_widget("Question 1: __ Haus"));
+
+        harness.click("Next");
+        harness.run_frames(2);
+        assert!(harness.has_widget("Question 1: __ Haus"));
+        assert!(!harness.has_widget("Answer 1: der ✘"));
+    }
+}
@@ -0,0 +1,583 @@
+use super::{forward_screen_events, track_active_screen, ScreenAction, ScreenKind, ScreenMessage};
+#[cfg(feature = "profile")]
+use crate::profile::PlayerProfile;
+#[cfg(feature = "storage")]
+use crate::storage::ExitRequested;
+use crate::{
+    theme::KonnektorenTheme,
+    ui::{
+        celebration::ChallengeFinished,
+        responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
+        widgets::{ResponsiveText, ThemedButton},
+    },
+};
+use bevy::{prelude::*, window::WindowCloseRequested};
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+
+/// Plugin for the end-of-session summary screen.
+///
+/// Tracks the [`SessionStats`] of the running session and shows them when the
+/// player quits: spawn the screen with
+/// [`SessionSummaryScreenExt::spawn_session_summary`] when quitting to the
+/// menu. The screen counts down a grace period in which quitting can be
+/// cancelled, then sends [`SessionSummaryEvent::Quit`].
+///
+/// A [`WindowCloseRequested`] shows the summary before the app exits. With the
+/// `storage` feature and the `StoragePlugin`, the exit waits until the
+/// persisted state is flushed (see `ExitRequested`). Bevy closes the window
+/// right away unless the game turns that off:
+///
+/// ```ignore
+/// app.add_plugins(DefaultPlugins.set(WindowPlugin {
+///     close_when_requested: false,
+///     ..default()
+/// }));
+/// ```
+pub struct SessionSummaryPlugin;
+
+impl Plugin for SessionSummaryPlugin {
+    fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveSessionSummary>(app);
+        forward_screen_events::<SessionSummaryEvent>(app);
+
+        app.init_resource::<SessionStats>()
+            .add_message::<SessionSummaryEvent>()
+            .add_message::<ChallengeFinished>()
+            .add_message::<XpGained>()
+            .add_message::<WindowCloseRequested>()
+            .add_message::<AppExit>()
+            .add_systems(
+                Update,
+                (
+                    track_session_stats,
+                    show_summary_on_close_request,
+                    check_session_summary_config,
+                    count_down_session_summary,
+                    handle_session_summary_events,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_session_summary_ui.run_if(any_with_component::<ActiveSessionSummary>),
+            );
+    }
+}
+
+/// Message sent by games when the player gained experience points
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XpGained {
+    pub amount: u32,
+}
+
+/// Resource with the progress of the running session
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct SessionStats {
+    /// Elapsed app time when the session started, in seconds
+    pub started_secs: f64,
+    pub challenges_completed: u32,
+    pub xp_gained: u32,
+}
+
+impl SessionStats {
+    /// Seconds practiced until `now_secs`
+    pub fn duration_secs(&self, now_secs: f64) -> u64 {
+        (now_secs - self.started_secs).max(0.0) as u64
+    }
+
+    /// Start a new session
+    pub fn restart(&mut self, now_secs: f64) {
+        *self = Self {
+            started_secs: now_secs,
+            ..default()
+        };
+    }
+}
+
+/// Where the player goes after the session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuitReason {
+    /// Back to the main menu, the game keeps running
+    #[default]
+    ToMenu,
+    /// The app closes
+    CloseApp,
+}
+
+/// Practice streak of the player at the end of the session
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreakStatus {
+    pub days: u32,
+    /// Whether the streak already counts today
+    pub practiced_today: bool,
+}
+
+impl StreakStatus {
+    pub fn label(&self) -> String {
+        match (self.days, self.practiced_today) {
+            (0, _) => "Practice tomorrow to start a streak".to_string(),
+            (days, true) => format!("{} day streak, see you tomorrow!", days),
+            (days, false) => format!("Practice today to keep your {} day streak", days),
+        }
+    }
+}
+
+/// Snapshot of the session shown on the summary screen
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionSummary {
+    pub duration_secs: u64,
+    pub challenges_completed: u32,
+    pub xp_gained: u32,
+    /// Streak of the [`PlayerProfile`], with the `profile` feature
+    pub streak: Option<StreakStatus>,
+}
+
+/// Format a duration as e.g. "45 s", "12 min" or "1 h 5 min"
+pub fn format_session_duration(secs: u64) -> String {
+    match secs {
+        0..60 => format!("{} s", secs),
+        60..3600 => format!("{} min", secs / 60),
+        _ => format!("{} h {} min", secs / 3600, secs % 3600 / 60),
+    }
+}
+
+/// Configuration for the session summary screen
+#[derive(Component, Clone)]
+pub struct SessionSummaryConfig {
+    pub title: String,
+    pub reason: QuitReason,
+    /// Seconds to cancel quitting before the [`SessionSummaryEvent::Quit`]
+    pub grace_secs: f32,
+    pub quit_button_text: String,
+    pub cancel_button_text: String,
+}
+
+impl Default for SessionSummaryConfig {
+    fn default() -> Self {
+        Self {
+            title: "Well done!".to_string(),
+            reason: QuitReason::ToMenu,
+            grace_secs: 5.0,
+            quit_button_text: "Quit now".to_string(),
+            cancel_button_text: "Keep playing".to_string(),
+        }
+    }
+}
+
+impl SessionSummaryConfig {
+    pub fn new(reason: QuitReason) -> Self {
+        Self {
+            reason,
+            ..default()
+        }
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn with_grace_secs(mut self, seconds: f32) -> Self {
+        self.grace_secs = seconds;
+        self
+    }
+
+    pub fn with_quit_button_text(mut self, text: impl Into<String>) -> Self {
+        self.quit_button_text = text.into();
+        self
+    }
+
+    pub fn with_cancel_button_text(mut self, text: impl Into<String>) -> Self {
+        self.cancel_button_text = text.into();
+        self
+    }
+}
+
+/// Component marking an active session summary screen
+#[derive(Component)]
+pub struct ActiveSessionSummary {
+    config: SessionSummaryConfig,
+    summary: SessionSummary,
+    /// Time left to cancel quitting
+    grace: Timer,
+}
+
+impl ActiveSessionSummary {
+    pub fn summary(&self) -> &SessionSummary {
+        &self.summary
+    }
+}
+
+/// Events for session summary interactions
+#[derive(Message, Debug, Clone, PartialEq)]
+pub enum SessionSummaryEvent {
+    /// The grace period ended or the player quit right away. The app exits
+    /// on its own for [`QuitReason::CloseApp`], the game shows its menu for
+    /// [`QuitReason::ToMenu`]
+    Quit { entity: Entity, reason: QuitReason },
+    /// The player keeps playing
+    Cancelled { entity: Entity },
+}
+
+impl ScreenMessage for SessionSummaryEvent {
+    const KIND: ScreenKind = ScreenKind::SessionSummary;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::Quit { entity, .. } => Some((ScreenAction::Submitted, *entity)),
+            Self::Cancelled { entity } => Some((ScreenAction::Dismissed, *entity)),
+        }
+    }
+}
+
+/// System to count the completed challenges and gained XP
+fn track_session_stats(
+    mut finished: MessageReader<ChallengeFinished>,
+    mut xp: MessageReader<XpGained>,
+    mut stats: ResMut<SessionStats>,
+) {
+    for _ in finished.read() {
+        stats.challenges_completed += 1;
+    }
+    for gained in xp.read() {
+        stats.xp_gained += gained.amount;
+    }
+}
+
+/// System to show the summary before the app closes. Another close request
+/// while a summary is shown closes the app right away
+fn show_summary_on_close_request(
+    mut commands: Commands,
+    mut close_requests: MessageReader<WindowCloseRequested>,
+    active: Query<Entity, With<ActiveSessionSummary>>,
+    mut events: MessageWriter<SessionSummaryEvent>,
+) {
+    if close_requests.read().count() == 0 {
+        return;
+    }
+    if let Some(entity) = active.iter().next() {
+        events.write(SessionSummaryEvent::Quit {
+            entity,
+            reason: QuitReason::CloseApp,
+        });
+        return;
+    }
+    info!("Close requested, showing the session summary");
+    commands.spawn_session_summary(SessionSummaryConfig::new(QuitReason::CloseApp));
+}
+
+/// System to check for new session summary configurations and take the
+/// snapshot of the session
+#[allow(clippy::type_complexity)]
+fn check_session_summary_config(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &SessionSummaryConfig),
+        (Without<ActiveSessionSummary>, Changed<SessionSummaryConfig>),
+    >,
+    existing: Query<Entity, With<ActiveSessionSummary>>,
+    stats: Res<SessionStats>,
+    time: Res<Time>,
+    #[cfg(feature = "profile")] profile: Option<Res<PlayerProfile>>,
+) {
+    for (entity, config) in query.iter() {
+        info!("Setting up session summary for entity {:?}", entity);
+
+        // Clean up any existing session summaries first
+        for existing_entity in existing.iter() {
+            commands
+                .entity(existing_entity)
+                .remove::<ActiveSessionSummary>();
+        }
+
+        #[cfg(feature = "profile")]
+        let streak = profile.as_ref().map(|profile| {
+            let today = chrono::Local::now().date_naive();
+            StreakStatus {
+                days: profile.history.current_streak(today),
+                practiced_today: profile.history.count(today) > 0,
+            }
+        });
+        #[cfg(not(feature = "profile"))]
+        let streak = None;
+
+        commands.entity(entity).insert(ActiveSessionSummary {
+            config: config.clone(),
+            summary: SessionSummary {
+                duration_secs: stats.duration_secs(time.elapsed_secs_f64()),
+                challenges_completed: stats.challenges_completed,
+                xp_gained: stats.xp_gained,
+                streak,
+            },
+            grace: Timer::from_seconds(config.grace_secs.max(0.0), TimerMode::Once),
+        });
+    }
+}
+
+/// System to quit once the grace period is over
+fn count_down_session_summary(
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut ActiveSessionSummary)>,
+    mut events: MessageWriter<SessionSummaryEvent>,
+) {
+    for (entity, mut active) in query.iter_mut() {
+        if active.grace.tick(time.delta()).just_finished() {
+            events.write(SessionSummaryEvent::Quit {
+                entity,
+                reason: active.config.reason,
+            });
+        }
+    }
+}
+
+/// System to quit or keep playing
+fn handle_session_summary_events(
+    mut commands: Commands,
+    mut events: MessageReader<SessionSummaryEvent>,
+    mut stats: ResMut<SessionStats>,
+    time: Res<Time>,
+    mut exit: MessageWriter<AppExit>,
+    #[cfg(feature = "storage")] mut graceful_exit: Option<ResMut<Messages<ExitRequested>>>,
+) {
+    for event in events.read() {
+        match event {
+            SessionSummaryEvent::Quit { entity, reason } => {
+                info!("Session ended ({:?})", reason);
+                commands.entity(*entity).remove::<ActiveSessionSummary>();
+                match reason {
+                    QuitReason::CloseApp => {
+                        // Let the storage save everything before exiting
+                        #[cfg(feature = "storage")]
+                        if let Some(graceful_exit) = graceful_exit.as_mut() {
+                            graceful_exit.write(ExitRequested);
+                            continue;
+                        }
+                        exit.write(AppExit::Success);
+                    }
+                    QuitReason::ToMenu => stats.restart(time.elapsed_secs_f64()),
+                }
+            }
+            SessionSummaryEvent::Cancelled { entity } => {
+                info!("Quitting cancelled, the session continues");
+                commands.entity(*entity).remove::<ActiveSessionSummary>();
+            }
+        }
+    }
+}
+
+/// System to render the session summary UI
+fn render_session_summary_ui(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    query: Query<(Entity, &ActiveSessionSummary)>,
+    mut events: MessageWriter<SessionSummaryEvent>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    // Only render the first (most recent) screen to avoid widget ID conflicts
+    if let Some((entity, active)) = query.iter().next() {
+        if input.just_pressed(KeyCode::Escape) {
+            events.write(SessionSummaryEvent::Cancelled { entity });
+            return;
+        }
+
+        egui::CentralPanel::default()
+            .frame(egui::Frame::NONE.fill(theme.base_100))
+            .show(ctx, |ui| {
+                scoped_ui(ui, (entity, "session_summary"), |ui| {
+                    render_session_summary_content(
+                        ui,
+                        active,
+                        &theme,
+                        &responsive,
+                        entity,
+                        &mut events,
+                    );
+                })
+            });
+    }
+}
+
+/// Render the session stats, the countdown and the buttons
+fn render_session_summary_content(
+    ui: &mut egui::Ui,
+    active: &ActiveSessionSummary,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    events: &mut MessageWriter<SessionSummaryEvent>,
+) {
+    let config = &active.config;
+    let summary = &active.summary;
+
+    ui.vertical_centered(|ui| {
+        ui.set_max_width(480.0_f32.min(ui.available_width() * 0.9));
+        ui.add_space(responsive.spacing(ResponsiveSpacing::XLarge));
+        ResponsiveText::new(&config.title, ResponsiveFontSize::Title, theme.primary)
+            .responsive(responsive)
+            .strong()
+            .ui(ui);
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+        let rows = [
+            (
+                "Time practiced",
+                format_session_duration(summary.duration_secs),
+            ),
+            (
+                "Challenges completed",
+                summary.challenges_completed.to_string(),
+            ),
+            ("XP gained", format!("{} XP", summary.xp_gained)),
+        ];
+        egui::Grid::new("session_summary_stats")
+            .num_columns(2)
+            .spacing([30.0, 12.0])
+            .show(ui, |ui| {
+                for (label, value) in &rows {
+                    ResponsiveText::new(label, ResponsiveFontSize::Medium, theme.base_content)
+                        .responsive(responsive)
+                        .ui(ui);
+                    ResponsiveText::new(value, ResponsiveFontSize::Medium, theme.secondary)
+                        .responsive(responsive)
+                        .strong()
+                        .ui(ui);
+                    ui.end_row();
+                }
+            });
+
+        if let Some(streak) = &summary.streak {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+            let color = if streak.practiced_today {
+                theme.success
+            } else {
+                theme.warning
+            };
+            ResponsiveText::new(&streak.label(), ResponsiveFontSize::Medium, color)
+                .responsive(responsive)
+                .ui(ui);
+        }
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+        let seconds_left = active.grace.remaining_secs().ceil() as u32;
+        let countdown = match config.reason {
+            QuitReason::ToMenu => format!("Back to the menu in {} s", seconds_left),
+            QuitReason::CloseApp => format!("Closing in {} s", seconds_left),
+        };
+        ResponsiveText::new(&countdown, ResponsiveFontSize::Small, theme.base_content)
+            .responsive(responsive)
+            .ui(ui);
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+        let button_width = if responsive.is_mobile() { 140.0 } else { 180.0 };
+        ui.horizontal(|ui| {
+            let cancel = ThemedButton::new(&config.cancel_button_text, theme)
+                .responsive(responsive)
+                .width(button_width);
+            if ui.add(cancel).clicked() {
+                events.write(SessionSummaryEvent::Cancelled { entity });
+            }
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+            let quit = ThemedButton::new(&config.quit_button_text, theme)
+                .responsive(responsive)
+                .width(button_width)
+                .with_style(|button| {
+                    button
+                        .fill(theme.base_200)
+                        .stroke(egui::Stroke::new(1.0, theme.base_300))
+                });
+            if ui.add(quit).clicked() {
+                events.write(SessionSummaryEvent::Quit {
+                    entity,
+                    reason: config.reason,
+                });
+            }
+        });
+    });
+}
+
+/// Helper trait to easily spawn a session summary
+pub trait SessionSummaryScreenExt {
+    /// Spawn a session summary screen with the given configuration
+    fn spawn_session_summary(&mut self, config: SessionSummaryConfig) -> Entity;
+}
+
+impl SessionSummaryScreenExt for Commands<'_, '_> {
+    fn spawn_session_summary(&mut self, config: SessionSummaryConfig) -> Entity {
+        self.spawn((Name::new("Session Summary Screen"), config))
+            .id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    #[test]
+    fn test_session_summary() {
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            SessionSummaryPlugin,
+        ));
+        harness
+            .world_mut()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                250,
+            )));
+        for challenge_id in ["articles", "verbs"] {
+            harness.write_message(ChallengeFinished {
+                challenge_id: challenge_id.to_string(),
+                score: 9,
+                max_score: 10,
+            });
+        }
+        harness.write_message(XpGained { amount: 30 });
+        harness.step();
+
+        // Quitting to the menu can be cancelled
+        let screen = harness
+            .world_mut()
+            .commands()
+            .spawn_session_summary(SessionSummaryConfig::new(QuitReason::ToMenu));
+        harness.run_frames(2);
+        assert!(harness.has_widget("Challenges completed"));
+        assert!(harness.has_widget("30 XP"));
+        harness.click("Keep playing");
+        harness.step();
+        assert!(harness
+            .world()
+            .get::<ActiveSessionSummary>(screen)
+            .is_none());
+        assert_eq!(harness.world().resource::<SessionStats>().xp_gained, 30);
+
+        // Closing the window quits after the grace period
+        let window = harness
+            .world_mut()
+            .query_filtered::<Entity, With<Window>>()
+            .single(harness.world())
+            .unwrap();
+        harness.write_message(WindowCloseRequested { window });
+        harness.run_frames(2);
+        assert!(harness.has_widget("Closing in 5 s"));
+        assert_eq!(harness.count_messages::<AppExit>(), 0);
+        let exits: usize = (0..24)
+            .map(|_| {
+                harness.step();
+                harness.count_messages::<AppExit>()
+            })
+            .sum();
+        assert_eq!(exits, 1);
+    }
+}
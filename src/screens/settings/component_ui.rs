@@ -1,17 +1,23 @@
 #[cfg(feature = "settings")]
-use crate::settings::{Setting, SettingChanged, SettingType, SettingValue};
+use super::ui::text_setting_edit;
+use crate::screens::{ScreenAction, ScreenKind, ScreenMessage};
+#[cfg(feature = "settings")]
 use crate::{
+    settings::{Setting, SettingChanged, SettingType, SettingValue, SettingsProfiles},
     theme::KonnektorenTheme,
     ui::{
         responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
-        widgets::{ResponsiveText, ThemedButton},
+        scope::scoped_ui,
+        widgets::{ResponsiveText, ThemedButton, ThemedStepper},
     },
 };
 use bevy::prelude::*;
+#[cfg(feature = "settings")]
 use bevy_egui::{
     egui::{self, Widget},
     EguiContexts,
 };
+#[cfg(feature = "settings")]
 use std::collections::HashMap;
 
 /// Events for component-based settings
@@ -21,6 +27,16 @@ pub enum ComponentSettingsEvent {
     Dismissed { entity: Entity },
 }
 
+impl ScreenMessage for ComponentSettingsEvent {
+    const KIND: ScreenKind = ScreenKind::Settings;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::Dismissed { entity } => Some((ScreenAction::Dismissed, *entity)),
+        }
+    }
+}
+
 /// Component that marks an active component-based settings screen
 #[derive(Component)]
 pub struct ActiveComponentSettings {
@@ -49,11 +65,19 @@ impl Default for ComponentSettingsNavigationState {
 }
 
 /// Component to mark a setting that needs to be updated
+#[cfg(feature = "settings")]
 #[derive(Component)]
 pub struct PendingSettingUpdate {
     new_value: SettingValue,
 }
 
+#[cfg(feature = "settings")]
+impl PendingSettingUpdate {
+    pub fn new(new_value: SettingValue) -> Self {
+        Self { new_value }
+    }
+}
+
 #[cfg(feature = "settings")]
 pub fn process_pending_setting_updates(
     mut settings_query: Query<(Entity, &mut Setting, &PendingSettingUpdate)>,
@@ -107,50 +131,54 @@ pub fn render_component_settings_ui(
     mut contexts: EguiContexts,
     theme: Res<KonnektorenTheme>,
     responsive: Res<ResponsiveInfo>,
-    mut config_query: Query<&mut ActiveComponentSettings>,
+    mut config_query: Query<(Entity, &mut ActiveComponentSettings)>,
     settings_query: Query<(Entity, &Setting)>,
     mut settings_events: MessageWriter<ComponentSettingsEvent>,
     mut commands: Commands,
     input: Res<ButtonInput<KeyCode>>,
+    mut profiles: Option<ResMut<SettingsProfiles>>,
 ) {
     if config_query.is_empty() {
         return;
     }
 
     if let Ok(ctx) = contexts.ctx_mut() {
-        if let Ok(mut config) = config_query.single_mut() {
+        if let Ok((entity, mut config)) = config_query.single_mut() {
             let should_dismiss = config.allow_dismissal && input.just_pressed(KeyCode::Escape);
             if should_dismiss {
-                settings_events.write(ComponentSettingsEvent::Dismissed {
-                    entity: Entity::PLACEHOLDER,
-                });
+                settings_events.write(ComponentSettingsEvent::Dismissed { entity });
                 return;
             }
 
             egui::CentralPanel::default()
                 .frame(egui::Frame::NONE.fill(theme.base_100))
                 .show(ctx, |ui| {
-                    render_component_settings_content(
-                        ui,
-                        &mut config,
-                        &theme,
-                        &responsive,
-                        &settings_query,
-                        &mut settings_events,
-                        &mut commands,
-                    );
+                    scoped_ui(ui, (entity, "component_settings"), |ui| {
+                        render_component_settings_content(
+                            ui,
+                            &mut config,
+                            &theme,
+                            &responsive,
+                            &settings_query,
+                            profiles.as_deref_mut(),
+                            &mut settings_events,
+                            &mut commands,
+                        );
+                    })
                 });
         }
     }
 }
 
 #[cfg(feature = "settings")]
+#[allow(clippy::too_many_arguments)]
 fn render_component_settings_content(
     ui: &mut egui::Ui,
     config: &mut ActiveComponentSettings,
     theme: &KonnektorenTheme,
     responsive: &ResponsiveInfo,
     settings_query: &Query<(Entity, &Setting)>,
+    mut profiles: Option<&mut SettingsProfiles>,
     settings_events: &mut MessageWriter<ComponentSettingsEvent>,
     commands: &mut Commands,
 ) {
@@ -193,6 +221,10 @@ fn render_component_settings_content(
             .max_height(scroll_height)
             .auto_shrink([false; 2])
             .show(ui, |ui| {
+                if let Some(profiles) = profiles.as_mut() {
+                    render_settings_profiles(ui, theme, responsive, profiles);
+                }
+
                 if responsive.is_mobile() {
                     render_mobile_component_layout(
                         ui,
@@ -230,6 +262,71 @@ fn render_component_settings_content(
     });
 }
 
+/// Section to switch, save and delete the [`SettingsProfiles`]
+#[cfg(feature = "settings")]
+fn render_settings_profiles(
+    ui: &mut egui::Ui,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    profiles: &mut SettingsProfiles,
+) {
+    ResponsiveText::new("Profiles", ResponsiveFontSize::Large, theme.secondary)
+        .responsive(responsive)
+        .strong()
+        .ui(ui);
+    ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+    let mut switch_to = None;
+    ui.horizontal_wrapped(|ui| {
+        for profile in profiles.profiles() {
+            let is_active = profiles.is_active(&profile.name);
+            let mut button = ThemedButton::new(&profile.name, theme).responsive(responsive);
+            if is_active {
+                button = button.with_style(|btn| {
+                    btn.fill(theme.primary)
+                        .stroke(egui::Stroke::new(2.0, theme.primary))
+                });
+            }
+            if ui.add(button).clicked() && !is_active {
+                switch_to = Some(profile.name.clone());
+            }
+        }
+    });
+    if let Some(name) = switch_to {
+        profiles.switch(name);
+    }
+
+    ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+    let name_id = ui.id().with("new_settings_profile_name");
+    let mut name = ui
+        .data_mut(|data| data.get_temp::<String>(name_id))
+        .unwrap_or_default();
+    ui.horizontal_wrapped(|ui| {
+        ui.add(egui::TextEdit::singleline(&mut name).hint_text("Profile name"));
+
+        let save = ThemedButton::new("Save as profile", theme).responsive(responsive);
+        if ui.add_enabled(!name.trim().is_empty(), save).clicked() {
+            profiles.create(name.trim());
+            name.clear();
+        }
+
+        if let Some(active) = profiles.active().map(|profile| profile.name.clone()) {
+            let label = format!("Delete {}", active);
+            let delete = ThemedButton::new(&label, theme)
+                .responsive(responsive)
+                .with_style(|btn| btn.fill(theme.error));
+            if ui.add(delete).clicked() {
+                profiles.delete(&active);
+            }
+        }
+    });
+    ui.data_mut(|data| data.insert_temp(name_id, name));
+
+    ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+    ui.separator();
+    ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+}
+
 #[cfg(feature = "settings")]
 fn render_mobile_component_layout(
     ui: &mut egui::Ui,
@@ -374,89 +471,33 @@ fn render_component_setting_control(
 
         SettingType::FloatRange { min, max, step } => {
             if let Some(current_value) = setting.value.as_float() {
-                ui.horizontal(|ui| {
-                    let dec_button = ThemedButton::new("-", theme)
-                        .responsive(responsive)
-                        .width(30.0);
-
-                    if ui.add(dec_button).clicked() {
-                        let new_value = (current_value - step).max(*min);
-                        update_component_setting_value(
-                            entity,
-                            SettingValue::Float(new_value),
-                            commands,
-                        );
-                    }
-
-                    ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
-
-                    ResponsiveText::new(
-                        &format!("{:.1}", current_value),
-                        ResponsiveFontSize::Medium,
-                        theme.base_content,
-                    )
+                let stepper = ThemedStepper::new(&format!("{:.1}", current_value), theme)
                     .responsive(responsive)
-                    .ui(ui);
-
-                    ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
-
-                    let inc_button = ThemedButton::new("+", theme)
-                        .responsive(responsive)
-                        .width(30.0);
-
-                    if ui.add(inc_button).clicked() {
-                        let new_value = (current_value + step).min(*max);
-                        update_component_setting_value(
-                            entity,
-                            SettingValue::Float(new_value),
-                            commands,
-                        );
-                    }
-                });
+                    .show(ui);
+
+                let new_value = (current_value + stepper.steps as f32 * step)
+                    .max(*min)
+                    .min(*max);
+                if stepper.steps != 0 && new_value != current_value {
+                    update_component_setting_value(
+                        entity,
+                        SettingValue::Float(new_value),
+                        commands,
+                    );
+                }
             }
         }
 
         SettingType::IntRange { min, max, step } => {
             if let Some(current_value) = setting.value.as_int() {
-                ui.horizontal(|ui| {
-                    let dec_button = ThemedButton::new("-", theme)
-                        .responsive(responsive)
-                        .width(30.0);
-
-                    if ui.add(dec_button).clicked() {
-                        let new_value = (current_value - step).max(*min);
-                        update_component_setting_value(
-                            entity,
-                            SettingValue::Int(new_value),
-                            commands,
-                        );
-                    }
-
-                    ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
-
-                    ResponsiveText::new(
-                        &current_value.to_string(),
-                        ResponsiveFontSize::Medium,
-                        theme.base_content,
-                    )
+                let stepper = ThemedStepper::new(&current_value.to_string(), theme)
                     .responsive(responsive)
-                    .ui(ui);
-
-                    ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                    .show(ui);
 
-                    let inc_button = ThemedButton::new("+", theme)
-                        .responsive(responsive)
-                        .width(30.0);
-
-                    if ui.add(inc_button).clicked() {
-                        let new_value = (current_value + step).min(*max);
-                        update_component_setting_value(
-                            entity,
-                            SettingValue::Int(new_value),
-                            commands,
-                        );
-                    }
-                });
+                let new_value = (current_value + stepper.steps * step).max(*min).min(*max);
+                if stepper.steps != 0 && new_value != current_value {
+                    update_component_setting_value(entity, SettingValue::Int(new_value), commands);
+                }
             }
         }
 
@@ -535,10 +576,9 @@ fn render_component_setting_control(
             }
         }
 
-        SettingType::Text { max_length: _ } => {
+        SettingType::Text { max_length } => {
             if let Some(current_text) = setting.value.as_string() {
-                let mut text = current_text.to_string();
-                if ui.text_edit_singleline(&mut text).changed() {
+                if let Some(text) = text_setting_edit(ui, &setting.id, current_text, *max_length) {
                     update_component_setting_value(entity, SettingValue::String(text), commands);
                 }
             }
@@ -1,5 +1,16 @@
+use crate::screens::{ScreenAction, ScreenKind, ScreenMessage};
 #[cfg(feature = "settings")]
-use crate::settings::{Setting, SettingType, SettingValue};
+use crate::settings::{
+    session_time_limit_labels, Setting, SettingType, SettingValue, BLOCK_EXTERNAL_LINKS_SETTING_ID,
+    BLOCK_SHARING_SETTING_ID, FULLSCREEN_SETTING_ID, PARENTAL_CONTROLS_SETTING_ID,
+    PARENTAL_PIN_MAX_LENGTH, PARENTAL_PIN_SETTING_ID, POINTER_LOCK_SETTING_ID,
+    SESSION_TIME_LIMIT_SETTING_ID,
+};
+#[cfg(feature = "settings")]
+use crate::theme::{
+    ColorVisionMode, COLOR_VISION_SETTING_ID, DYSLEXIA_FRIENDLY_SETTING_ID,
+    HIGH_VISIBILITY_SETTING_ID, SHAPE_CUES_SETTING_ID,
+};
 use bevy::prelude::*;
 
 /// Configuration for screen-based settings (not component-based)
@@ -11,6 +22,11 @@ pub struct SettingsScreenConfig {
     pub back_button_text: String,
     pub navigation_enabled: bool,
     pub mobile_layout: bool,
+    /// Keep the sections in sync with the `Setting` entities while the
+    /// screen is open and write value changes back to them
+    pub live_components: bool,
+    /// Dismiss the screen after this many seconds without input
+    pub auto_dismiss: Option<f32>,
 }
 
 impl Default for SettingsScreenConfig {
@@ -22,6 +38,8 @@ impl Default for SettingsScreenConfig {
             back_button_text: "Back".to_string(),
             navigation_enabled: true,
             mobile_layout: false,
+            live_components: false,
+            auto_dismiss: None,
         }
     }
 }
@@ -63,6 +81,21 @@ impl SettingsScreenConfig {
         self.allow_dismissal = false;
         self
     }
+
+    /// Dismiss the screen after the given seconds without input, e.g. to
+    /// return a kiosk to the menu
+    pub fn with_auto_dismiss(mut self, seconds: f32) -> Self {
+        self.auto_dismiss = Some(seconds);
+        self
+    }
+
+    /// Keep the sections in sync with the `Setting` entities while the screen
+    /// is open, see [`SettingsScreenConfig::live_component_settings`]
+    #[cfg(feature = "settings")]
+    pub fn with_live_components(mut self) -> Self {
+        self.live_components = true;
+        self
+    }
 }
 
 /// A section in the settings screen
@@ -106,6 +139,67 @@ pub struct ScreenSettingsItem {
     #[cfg(not(feature = "settings"))]
     pub current_value: ScreenSettingValue,
     pub navigation_index: Option<usize>,
+    /// Display format of slider values, parsed once when the item is created
+    pub display_format: SliderFormat,
+}
+
+/// Precompiled display format of a slider value
+#[derive(Debug, Clone, PartialEq)]
+pub enum SliderFormat {
+    /// Value shown as a percentage, e.g. `0.8` as `80%`
+    Percent { precision: usize },
+    /// Value shown with a fixed precision followed by a suffix
+    Fixed { precision: usize, suffix: String },
+}
+
+impl Default for SliderFormat {
+    fn default() -> Self {
+        Self::Fixed {
+            precision: 1,
+            suffix: String::new(),
+        }
+    }
+}
+
+impl SliderFormat {
+    /// Parse a format string like `"{:.1}"`, `"{:.0}%"` or `"{:.2} s"`.
+    ///
+    /// A `%` suffix shows the value scaled to a percentage.
+    pub fn parse(format: &str) -> Self {
+        let (precision, suffix) = match format
+            .strip_prefix("{:.")
+            .and_then(|rest| rest.split_once('}'))
+        {
+            Some((precision, suffix)) => (precision.parse().unwrap_or(1), suffix),
+            None => (1, format.strip_prefix("{}").unwrap_or(format)),
+        };
+
+        if suffix == "%" {
+            Self::Percent { precision }
+        } else {
+            Self::Fixed {
+                precision,
+                suffix: suffix.to_string(),
+            }
+        }
+    }
+
+    /// Default format of a setting, volume settings are shown as percentage
+    pub fn for_setting(id: &str) -> Self {
+        if id.contains("volume") {
+            Self::Percent { precision: 0 }
+        } else {
+            Self::default()
+        }
+    }
+
+    /// Format a value for display
+    pub fn format(&self, value: f32) -> String {
+        match self {
+            Self::Percent { precision } => format!("{:.*}%", precision, value * 100.0),
+            Self::Fixed { precision, suffix } => format!("{:.*}{}", precision, value, suffix),
+        }
+    }
 }
 
 /// Fallback setting type when core settings feature is disabled
@@ -153,8 +247,10 @@ impl ScreenSettingsItem {
         setting_type: SettingType,
         current_value: SettingValue,
     ) -> Self {
+        let id = id.into();
         Self {
-            id: id.into(),
+            display_format: SliderFormat::for_setting(&id),
+            id,
             label: label.into(),
             setting_type,
             current_value,
@@ -169,8 +265,10 @@ impl ScreenSettingsItem {
         setting_type: ScreenOnlySettingType,
         current_value: ScreenSettingValue,
     ) -> Self {
+        let id = id.into();
         Self {
-            id: id.into(),
+            display_format: SliderFormat::for_setting(&id),
+            id,
             label: label.into(),
             setting_type,
             current_value,
@@ -183,6 +281,12 @@ impl ScreenSettingsItem {
         self
     }
 
+    /// Set the slider display format, see [`SliderFormat::parse`]
+    pub fn with_display_format(mut self, format: &str) -> Self {
+        self.display_format = SliderFormat::parse(format);
+        self
+    }
+
     /// Update the current value
     #[cfg(feature = "settings")]
     pub fn with_value(mut self, value: SettingValue) -> Self {
@@ -195,6 +299,17 @@ impl ScreenSettingsItem {
         self.current_value = value;
         self
     }
+
+    /// Create a custom setting type
+    #[cfg(not(feature = "settings"))]
+    pub fn custom(
+        id: impl Into<String>,
+        label: impl Into<String>,
+        current_value: ScreenSettingValue,
+        setting_type: ScreenOnlySettingType,
+    ) -> Self {
+        Self::new(id, label, setting_type, current_value)
+    }
 }
 
 /// Conversion from core Setting to ScreenSettingsItem
@@ -207,6 +322,7 @@ impl From<&Setting> for ScreenSettingsItem {
             setting_type: setting.setting_type.clone(),
             current_value: setting.value.clone(),
             navigation_index: setting.tab_index,
+            display_format: SliderFormat::for_setting(&setting.id),
         }
     }
 }
@@ -300,7 +416,7 @@ impl ScreenSettingsItem {
 }
 
 /// Events for settings screen interactions
-#[derive(Message)]
+#[derive(Message, Debug, Clone)]
 pub enum SettingsScreenEvent {
     /// A setting value changed
     ValueChanged {
@@ -317,6 +433,18 @@ pub enum SettingsScreenEvent {
     Navigate { direction: NavigationDirection },
 }
 
+impl ScreenMessage for SettingsScreenEvent {
+    const KIND: ScreenKind = ScreenKind::Settings;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::ValueChanged { entity, .. } => Some((ScreenAction::Changed, *entity)),
+            Self::Dismissed { entity } => Some((ScreenAction::Dismissed, *entity)),
+            Self::Navigate { .. } => None,
+        }
+    }
+}
+
 /// Navigation directions
 #[derive(Debug, Clone)]
 pub enum NavigationDirection {
@@ -428,18 +556,95 @@ impl SettingsSection {
             .add_setting(ScreenSettingsItem::toggle("vsync", "V-Sync", true))
     }
 
+    /// Create a display section whose toggles are applied by `DisplaySettingsPlugin`
+    #[cfg(feature = "settings")]
+    pub fn display_section() -> Self {
+        Self::new("Display Settings")
+            .add_setting(ScreenSettingsItem::toggle(
+                FULLSCREEN_SETTING_ID,
+                "Fullscreen",
+                false,
+            ))
+            .add_setting(ScreenSettingsItem::toggle(
+                POINTER_LOCK_SETTING_ID,
+                "Lock Pointer",
+                false,
+            ))
+    }
+
+    /// Create an appearance section whose options are applied to the `ColorAssistance`
+    /// and `AccessibilityOptions`
+    #[cfg(feature = "settings")]
+    pub fn appearance_section() -> Self {
+        Self::new("Appearance")
+            .add_setting(ScreenSettingsItem::selection(
+                COLOR_VISION_SETTING_ID,
+                "Color Vision",
+                ColorVisionMode::ALL
+                    .iter()
+                    .map(|mode| mode.name().to_string())
+                    .collect(),
+                0,
+            ))
+            .add_setting(ScreenSettingsItem::toggle(
+                SHAPE_CUES_SETTING_ID,
+                "Shape Cues",
+                false,
+            ))
+            .add_setting(ScreenSettingsItem::toggle(
+                HIGH_VISIBILITY_SETTING_ID,
+                "High Visibility",
+                false,
+            ))
+            .add_setting(ScreenSettingsItem::toggle(
+                DYSLEXIA_FRIENDLY_SETTING_ID,
+                "Dyslexia-Friendly Text",
+                false,
+            ))
+    }
+
+    /// Create a parental controls section whose options are applied to the
+    /// `ParentalControls`. Once enabled with a PIN, the settings screen asks
+    /// for the PIN before showing the settings.
+    #[cfg(feature = "settings")]
+    pub fn parental_controls_section() -> Self {
+        Self::new("Parental Controls")
+            .add_setting(ScreenSettingsItem::toggle(
+                PARENTAL_CONTROLS_SETTING_ID,
+                "Parental Controls",
+                false,
+            ))
+            .add_setting(ScreenSettingsItem::text(
+                PARENTAL_PIN_SETTING_ID,
+                "PIN",
+                String::new(),
+                Some(PARENTAL_PIN_MAX_LENGTH),
+            ))
+            .add_setting(ScreenSettingsItem::toggle(
+                BLOCK_EXTERNAL_LINKS_SETTING_ID,
+                "Block External Links",
+                true,
+            ))
+            .add_setting(ScreenSettingsItem::toggle(
+                BLOCK_SHARING_SETTING_ID,
+                "Block Chat and Sharing",
+                true,
+            ))
+            .add_setting(ScreenSettingsItem::selection(
+                SESSION_TIME_LIMIT_SETTING_ID,
+                "Session Time Limit",
+                session_time_limit_labels(),
+                0,
+            ))
+    }
+
     #[cfg(feature = "settings")]
     pub fn gameplay_section() -> Self {
         Self::new("Gameplay Settings")
             .add_setting(ScreenSettingsItem::selection(
                 "difficulty",
                 "Difficulty",
-                vec![
-                    "Easy".to_string(),
-                    "Normal".to_string(),
-                    "Hard".to_string(),
-                    "Expert".to_string(),
-                ],
+                difficulty_options(),
                 1,
             ))
             .add_setting(ScreenSettingsItem::toggle("auto_save", "Auto Save", true))
@@ -456,6 +661,21 @@ impl SettingsSection {
     }
 }
 
+/// Options of the gameplay difficulty selection, with "Adaptive" when the
+/// [`DifficultyPlugin`](crate::profile::DifficultyPlugin) is available
+#[cfg(feature = "settings")]
+fn difficulty_options() -> Vec<String> {
+    #[cfg(feature = "profile")]
+    let options = crate::profile::difficulty_labels();
+
+    #[cfg(not(feature = "profile"))]
+    let options = ["Easy", "Normal", "Hard", "Expert"]
+        .map(String::from)
+        .to_vec();
+
+    options
+}
+
 // Pre-built configurations
 impl SettingsScreenConfig {
     /// Create a complete game settings configuration with common sections
@@ -513,27 +733,92 @@ impl SettingsScreenConfig {
             .add_section(SettingsSection::gameplay_section())
     }
 
-    /// Create from component-based settings grouped by category
+    /// Create from component-based settings grouped by category, with the
+    /// sections in the order their categories first appear
     #[cfg(feature = "settings")]
     pub fn from_component_settings(title: impl Into<String>, settings: &[&Setting]) -> Self {
-        use std::collections::HashMap;
+        let mut config = Self::new(title);
+        config.merge_component_settings(settings);
+        config
+    }
 
-        let mut categories: HashMap<String, Vec<&Setting>> = HashMap::new();
+    /// Create a settings screen generated from all `Setting` entities that
+    /// follows them while open.
+    ///
+    /// Added settings appear in the section of their category, removed ones
+    /// disappear and existing sections and items keep their position. Value
+    /// changes made on the screen are written back to the `Setting`
+    /// components, so they emit `SettingChangedEvent` like any other change.
+    #[cfg(feature = "settings")]
+    pub fn live_component_settings(title: impl Into<String>) -> Self {
+        Self::new(title).with_live_components()
+    }
 
-        for setting in settings {
-            let category = setting
-                .category
-                .clone()
-                .unwrap_or_else(|| "General".to_string());
-            categories.entry(category).or_default().push(setting);
+    /// Update the sections to match the given settings.
+    ///
+    /// Items of settings that no longer exist are removed together with
+    /// sections left empty, the other items are refreshed in place and new
+    /// settings are appended to the section of their category.
+    #[cfg(feature = "settings")]
+    pub fn merge_component_settings(&mut self, settings: &[&Setting]) {
+        use std::collections::HashMap;
+
+        let by_id: HashMap<&str, &Setting> = settings
+            .iter()
+            .map(|setting| (setting.id.as_str(), *setting))
+            .collect();
+
+        for section in &mut self.sections {
+            section
+                .settings
+                .retain_mut(|item| match by_id.get(item.id.as_str()) {
+                    Some(setting) => {
+                        *item = ScreenSettingsItem::from_setting(setting);
+                        true
+                    }
+                    None => false,
+                });
         }
+        self.sections.retain(|section| !section.settings.is_empty());
 
-        let mut config = Self::new(title);
-        for (category_name, category_settings) in categories {
-            let section = SettingsSection::from_settings(category_name, &category_settings);
-            config = config.add_section(section);
+        for setting in settings {
+            if self
+                .sections
+                .iter()
+                .any(|section| section.settings.iter().any(|item| item.id == setting.id))
+            {
+                continue;
+            }
+
+            let category = setting.category.as_deref().unwrap_or("General");
+            let item = ScreenSettingsItem::from_setting(setting);
+            match self
+                .sections
+                .iter_mut()
+                .find(|section| section.title == category)
+            {
+                Some(section) => section.settings.push(item),
+                None => self
+                    .sections
+                    .push(SettingsSection::new(category).add_setting(item)),
+            }
         }
+    }
+}
 
-        config
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slider_format_parse() {
+        assert_eq!(
+            SliderFormat::parse("{:.0}%"),
+            SliderFormat::Percent { precision: 0 }
+        );
+        assert_eq!(SliderFormat::parse("{:.0}%").format(0.8), "80%");
+        assert_eq!(SliderFormat::parse("{:.2} s").format(1.5), "1.50 s");
+        assert_eq!(SliderFormat::parse("{}").format(0.25), "0.2");
+        assert_eq!(SliderFormat::for_setting("music_volume").format(0.5), "50%");
     }
 }
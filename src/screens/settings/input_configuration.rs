@@ -1,18 +1,28 @@
 use crate::{
     input::{
-        components::{InputController, InputDeviceAssignment, InputEvent},
-        device::{AvailableInputDevices, InputDevice},
+        components::{
+            GamepadAction, GamepadBindings, InputController, InputDeviceAssignment, InputEvent,
+        },
+        device::{
+            AvailableInputDevices, CustomKeyboardScheme, CustomSchemes, DevicesChanged,
+            InputDevice, KeyboardScheme,
+        },
+        player_style::PlayerStyle,
     },
+    screens::{track_active_screen, NavigateBack},
     theme::KonnektorenTheme,
     ui::{
+        coach_marks::CoachMarkExt,
+        notifications::Notification,
         responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
-        widgets::{ResponsiveText, ThemedButton},
+        scope::scoped_ui,
+        widgets::{ResponsiveText, ThemedBreadcrumbs, ThemedButton},
     },
 };
 use bevy::prelude::*;
 use bevy_egui::{
-    egui::{self, Id, Widget},
-    EguiContexts,
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
 };
 
 /// Plugin for input configuration within settings
@@ -20,14 +30,25 @@ pub struct InputConfigurationPlugin;
 
 impl Plugin for InputConfigurationPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<InputConfigurationEvent>().add_systems(
-            Update,
-            (
-                handle_input_configuration_events,
-                cleanup_input_configuration,
-                render_input_configuration_ui,
-            ),
-        );
+        track_active_screen::<ActiveInputConfiguration>(app);
+
+        app.add_message::<InputConfigurationEvent>()
+            .add_message::<DevicesChanged>()
+            .add_message::<Notification>()
+            .add_systems(
+                Update,
+                (
+                    handle_input_configuration_events,
+                    capture_keyboard_scheme_keys,
+                    refresh_input_configuration_devices,
+                    cleanup_input_configuration,
+                ),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_input_configuration_ui
+                    .run_if(any_with_component::<ActiveInputConfiguration>),
+            );
     }
 }
 
@@ -42,21 +63,273 @@ pub enum InputConfigurationEvent {
     DeviceAssigned { player_id: u32, device: InputDevice },
     /// Player device unassigned
     DeviceUnassigned { player_id: u32 },
+    /// Start creating a custom keyboard scheme
+    StartCustomScheme,
+    /// Custom keyboard scheme created, to be added to the [`CustomSchemes`]
+    CustomSchemeCreated(CustomKeyboardScheme),
+    /// Start capturing a key of the player's keyboard scheme, `binding` in
+    /// the order of [`CustomKeyboardScheme::keys`]
+    StartKeyRebind { player_id: u32, binding: usize },
+    /// Key rebound, the scheme is saved to the [`CustomSchemes`] and assigned
+    /// to the player
+    KeyRebound {
+        player_id: u32,
+        scheme: CustomKeyboardScheme,
+    },
+    /// Start capturing a button of the player's gamepad for the action
+    StartGamepadRebind {
+        player_id: u32,
+        action: GamepadAction,
+    },
+    /// Gamepad button rebound, the bindings are stored for the player in the
+    /// [`InputDeviceAssignment`]
+    GamepadRebound {
+        player_id: u32,
+        bindings: GamepadBindings,
+    },
 }
 
 /// Component marking an active input configuration screen
 #[derive(Component)]
+#[require(
+    InputConfigurationFocus,
+    KeyboardSchemeCapture,
+    KeyRebindCapture,
+    GamepadRebindCapture
+)]
 pub struct ActiveInputConfiguration {
     pub max_players: u32,
     pub current_players: u32,
 }
 
+/// Keyboard/gamepad focus on the input configuration screen.
+///
+/// Up/Down (d-pad) move through the devices of the focused player,
+/// Left/Right (shoulder buttons) switch the player column and Enter/Space
+/// (A) assigns the focused device, or unassigns it when it is the player's
+/// current device.
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+pub struct InputConfigurationFocus {
+    /// Player whose panel has the focus
+    pub player: u32,
+    /// Index of the focused device in the player's device list
+    pub device: usize,
+    /// Focus moved in this frame, the focused device scrolls into view
+    pub moved: bool,
+}
+
+/// Keys captured for a custom keyboard scheme, in this order
+const SCHEME_KEY_STEPS: [&str; 6] = ["Up", "Down", "Left", "Right", "Confirm", "Back"];
+
+/// Custom keyboard scheme being created on the input configuration screen.
+///
+/// While active the next pressed keys are captured for up, down, left,
+/// right, confirm and back, then the scheme is named and saved to the
+/// [`CustomSchemes`]. Escape cancels.
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyboardSchemeCapture {
+    pub active: bool,
+    /// Keys captured so far
+    pub keys: Vec<KeyCode>,
+    pub name: String,
+}
+
+impl KeyboardSchemeCapture {
+    pub fn start(&mut self, name: impl Into<String>) {
+        *self = Self {
+            active: true,
+            keys: Vec::new(),
+            name: name.into(),
+        };
+    }
+
+    pub fn cancel(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Capture a pressed key, keys already used in the scheme are ignored
+    pub fn record(&mut self, key: KeyCode) {
+        if self.active && !self.is_complete() && !self.keys.contains(&key) {
+            self.keys.push(key);
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.keys.len() == SCHEME_KEY_STEPS.len()
+    }
+
+    /// Name of the direction or action whose key is captured next
+    pub fn next_step(&self) -> Option<&'static str> {
+        SCHEME_KEY_STEPS.get(self.keys.len()).copied()
+    }
+
+    /// The scheme, once all keys are captured and it has a name
+    pub fn scheme(&self) -> Option<CustomKeyboardScheme> {
+        let [up, down, left, right, primary, secondary] = self.keys[..] else {
+            return None;
+        };
+        let name = self.name.trim();
+        if name.is_empty() {
+            return None;
+        }
+
+        Some(CustomKeyboardScheme {
+            name: name.to_string(),
+            up,
+            down,
+            left,
+            right,
+            primary,
+            secondary,
+        })
+    }
+}
+
+/// Key binding of a player's keyboard scheme waiting for a key press.
+///
+/// Clicking a binding in a player panel starts the capture. The next pressed
+/// key is bound unless another player's scheme or another binding of the
+/// same scheme uses it. Rebinding a built-in scheme copies it to a custom
+/// scheme named after the player. Escape cancels.
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyRebindCapture {
+    /// Player and binding, in the order of [`CustomKeyboardScheme::keys`]
+    pub target: Option<(u32, usize)>,
+    /// Why the last pressed key was not bound
+    pub conflict: Option<String>,
+}
+
+impl KeyRebindCapture {
+    pub fn start(&mut self, player_id: u32, binding: usize) {
+        *self = Self {
+            target: Some((player_id, binding)),
+            conflict: None,
+        };
+    }
+
+    pub fn cancel(&mut self) {
+        *self = Self::default();
+    }
+
+    /// The player's scheme with the key bound, or why the key can't be bound
+    pub fn rebind(
+        &self,
+        key: KeyCode,
+        assignment: &InputDeviceAssignment,
+        player_style: &PlayerStyle,
+    ) -> Option<Result<CustomKeyboardScheme, String>> {
+        let (player_id, binding) = self.target?;
+        let Some(InputDevice::Keyboard(scheme)) = assignment.get_device_for_player(player_id)
+        else {
+            return None;
+        };
+
+        if let Some(other) = assignment.get_player_for_key(key, player_id) {
+            return Some(Err(format!(
+                "{:?} is used by {}",
+                key,
+                player_style.name(other)
+            )));
+        }
+        let mut rebound = match scheme {
+            KeyboardScheme::UserDefined(scheme) => scheme.clone(),
+            scheme => CustomKeyboardScheme::from_scheme(
+                format!("{} keys", player_style.name(player_id)),
+                scheme,
+            ),
+        };
+        if let Some(index) = rebound
+            .keys()
+            .iter()
+            .position(|bound| *bound == key)
+            .filter(|index| *index != binding)
+        {
+            return Some(Err(format!(
+                "{:?} is already used for {}",
+                key, SCHEME_KEY_STEPS[index]
+            )));
+        }
+        rebound.set_key(binding, key);
+        Some(Ok(rebound))
+    }
+}
+
+/// Gamepad action of a player waiting for a button press.
+///
+/// Clicking an action in the panel of a player with a gamepad starts the
+/// capture, the next button pressed on that gamepad is bound unless it moves
+/// the player or is used for the other action. Escape cancels.
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+pub struct GamepadRebindCapture {
+    pub target: Option<(u32, GamepadAction)>,
+    /// Why the last pressed button was not bound
+    pub conflict: Option<String>,
+}
+
+impl GamepadRebindCapture {
+    pub fn start(&mut self, player_id: u32, action: GamepadAction) {
+        *self = Self {
+            target: Some((player_id, action)),
+            conflict: None,
+        };
+    }
+
+    pub fn cancel(&mut self) {
+        *self = Self::default();
+    }
+
+    /// The bindings with the button bound, or why it can't be bound
+    pub fn rebind(
+        &self,
+        button: GamepadButton,
+        bindings: &GamepadBindings,
+    ) -> Option<Result<GamepadBindings, String>> {
+        let (_, action) = self.target?;
+        if matches!(
+            button,
+            GamepadButton::DPadUp
+                | GamepadButton::DPadDown
+                | GamepadButton::DPadLeft
+                | GamepadButton::DPadRight
+        ) {
+            return Some(Err(format!("{:?} moves the player", button)));
+        }
+        if let Some(other) = bindings.action_for(button).filter(|other| *other != action) {
+            return Some(Err(format!(
+                "{:?} is already used for {}",
+                button,
+                other.name()
+            )));
+        }
+
+        let mut rebound = bindings.clone();
+        rebound.set_button(action, button);
+        Some(Ok(rebound))
+    }
+}
+
+/// Devices in the order they are listed in the player panels
+fn ordered_devices(available_devices: &AvailableInputDevices) -> Vec<InputDevice> {
+    let mut devices = available_devices.get_available_devices();
+    devices.sort_by_key(|device| device.category().order());
+    devices
+}
+
 /// System to handle input configuration events
 pub fn handle_input_configuration_events(
     mut config_events: MessageReader<InputConfigurationEvent>,
     assignment: Option<ResMut<InputDeviceAssignment>>,
     mut input_events: MessageWriter<InputEvent>,
     available_devices: Option<Res<AvailableInputDevices>>,
+    mut custom_schemes: Option<ResMut<CustomSchemes>>,
+    mut captures: Query<
+        (
+            &mut KeyboardSchemeCapture,
+            &mut KeyRebindCapture,
+            &mut GamepadRebindCapture,
+        ),
+        With<ActiveInputConfiguration>,
+    >,
 ) {
     // Early return if input resources aren't available
     let (mut assignment, available_devices) = match (assignment, available_devices) {
@@ -120,6 +393,129 @@ pub fn handle_input_configuration_events(
 
                 info!("Unassigned device from player {}", player_id + 1);
             }
+            InputConfigurationEvent::StartCustomScheme => {
+                let count = custom_schemes
+                    .as_ref()
+                    .map_or(0, |custom_schemes| custom_schemes.schemes.len());
+                for (mut capture, _, _) in captures.iter_mut() {
+                    capture.start(format!("Custom {}", count + 1));
+                }
+            }
+            InputConfigurationEvent::CustomSchemeCreated(scheme) => {
+                let Some(custom_schemes) = custom_schemes.as_mut() else {
+                    warn!("Custom keyboard scheme created but InputPlugin not loaded");
+                    continue;
+                };
+
+                info!("Created custom keyboard scheme {}", scheme.name);
+                custom_schemes.add(scheme.clone());
+                for (mut capture, _, _) in captures.iter_mut() {
+                    capture.cancel();
+                }
+            }
+            InputConfigurationEvent::StartKeyRebind { player_id, binding } => {
+                for (_, mut rebind, _) in captures.iter_mut() {
+                    rebind.start(*player_id, *binding);
+                }
+            }
+            InputConfigurationEvent::KeyRebound { player_id, scheme } => {
+                let Some(custom_schemes) = custom_schemes.as_mut() else {
+                    warn!("Key rebound but InputPlugin not loaded");
+                    continue;
+                };
+
+                info!(
+                    "Rebound keys of player {} in {}",
+                    player_id + 1,
+                    scheme.name
+                );
+                custom_schemes.add(scheme.clone());
+                let device = InputDevice::Keyboard(KeyboardScheme::UserDefined(scheme.clone()));
+                assignment.assign_device(*player_id, device.clone());
+                input_events.write(InputEvent::DeviceAssigned {
+                    player_id: *player_id,
+                    device,
+                });
+            }
+            InputConfigurationEvent::StartGamepadRebind { player_id, action } => {
+                for (_, _, mut rebind) in captures.iter_mut() {
+                    rebind.start(*player_id, *action);
+                }
+            }
+            InputConfigurationEvent::GamepadRebound {
+                player_id,
+                bindings,
+            } => {
+                info!(
+                    "Rebound gamepad of player {} to {:?}",
+                    player_id + 1,
+                    bindings
+                );
+                assignment.set_gamepad_bindings(*player_id, bindings.clone());
+            }
+        }
+    }
+}
+
+/// System to capture the keys of a custom keyboard scheme being created
+fn capture_keyboard_scheme_keys(
+    input: Res<ButtonInput<KeyCode>>,
+    mut query: Query<&mut KeyboardSchemeCapture>,
+) {
+    for mut capture in query.iter_mut() {
+        if !capture.active || capture.is_complete() {
+            continue;
+        }
+
+        // Escape is handled with the screen, it cancels the capture
+        if let Some(key) = input
+            .get_just_pressed()
+            .find(|key| **key != KeyCode::Escape)
+        {
+            capture.record(*key);
+        }
+    }
+}
+
+/// System to keep the focus on the list of devices and tell the user when
+/// devices were connected or disconnected while the screen is open
+fn refresh_input_configuration_devices(
+    mut devices_changed: MessageReader<DevicesChanged>,
+    available_devices: Option<Res<AvailableInputDevices>>,
+    mut query: Query<&mut InputConfigurationFocus, With<ActiveInputConfiguration>>,
+    mut notifications: MessageWriter<Notification>,
+) {
+    let Some(available_devices) = available_devices else {
+        devices_changed.clear();
+        return;
+    };
+
+    for changed in devices_changed.read() {
+        let Ok(mut focus) = query.single_mut() else {
+            continue;
+        };
+
+        let device_count = ordered_devices(&available_devices).len();
+        focus.device = focus.device.min(device_count.saturating_sub(1));
+
+        let names = |devices: &[InputDevice]| {
+            devices
+                .iter()
+                .map(InputDevice::name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        if !changed.added.is_empty() {
+            notifications.write(Notification::info(
+                "Device connected",
+                names(&changed.added),
+            ));
+        }
+        if !changed.removed.is_empty() {
+            notifications.write(Notification::warning(
+                "Device disconnected",
+                names(&changed.removed),
+            ));
         }
     }
 }
@@ -130,17 +526,29 @@ pub fn render_input_configuration_ui(
     mut contexts: EguiContexts,
     theme: Res<KonnektorenTheme>,
     responsive: Res<ResponsiveInfo>,
-    query: Query<(Entity, &ActiveInputConfiguration)>,
+    mut query: Query<(
+        Entity,
+        &ActiveInputConfiguration,
+        &mut InputConfigurationFocus,
+        &mut KeyboardSchemeCapture,
+        &mut KeyRebindCapture,
+        &mut GamepadRebindCapture,
+    )>,
     assignment: Option<Res<InputDeviceAssignment>>,
     available_devices: Option<Res<AvailableInputDevices>>,
     mut config_events: MessageWriter<InputConfigurationEvent>,
     input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut navigate_events: MessageWriter<NavigateBack>,
+    player_style: Option<Res<PlayerStyle>>,
 ) {
     if query.is_empty() {
         return;
     }
 
-    let Ok((_screen_entity, config)) = query.single() else {
+    let Ok((screen_entity, config, mut focus, mut capture, mut rebind, mut gamepad_rebind)) =
+        query.single_mut()
+    else {
         return;
     };
 
@@ -151,6 +559,7 @@ pub fn render_input_configuration_ui(
             // If input resources aren't available, show error message
             render_input_unavailable_ui(
                 &mut contexts,
+                screen_entity,
                 &theme,
                 &responsive,
                 &mut config_events,
@@ -160,32 +569,205 @@ pub fn render_input_configuration_ui(
         }
     };
 
-    // Handle escape to close
-    if input.just_pressed(KeyCode::Escape) {
-        config_events.write(InputConfigurationEvent::Close);
-        return;
+    let default_style = PlayerStyle::default();
+    let player_style = player_style.as_deref().unwrap_or(&default_style);
+
+    let gamepad_pressed = |button| gamepads.iter().any(|gamepad| gamepad.just_pressed(button));
+
+    // Handle escape (or B) to cancel a custom scheme or rebinding, or close.
+    // B can be bound to a gamepad action, only Escape cancels its capture
+    if input.just_pressed(KeyCode::Escape)
+        || (gamepad_pressed(GamepadButton::East) && gamepad_rebind.target.is_none())
+    {
+        if gamepad_rebind.target.is_some() {
+            gamepad_rebind.cancel();
+        } else if rebind.target.is_some() {
+            rebind.cancel();
+        } else if capture.active {
+            capture.cancel();
+        } else {
+            config_events.write(InputConfigurationEvent::Close);
+            return;
+        }
+    }
+
+    // Keys pressed while creating a custom scheme or rebinding a key are
+    // captured for it, captured here so they don't navigate in the same frame
+    if rebind.target.is_some() {
+        let pressed = input
+            .get_just_pressed()
+            .find(|key| **key != KeyCode::Escape);
+        match pressed.and_then(|key| rebind.rebind(*key, &assignment, player_style)) {
+            Some(Ok(scheme)) => {
+                let (player_id, _) = rebind.target.unwrap_or_default();
+                config_events.write(InputConfigurationEvent::KeyRebound { player_id, scheme });
+                rebind.cancel();
+            }
+            Some(Err(conflict)) => rebind.conflict = Some(conflict),
+            None => {}
+        }
+    } else if let Some((player_id, _)) = gamepad_rebind.target {
+        let gamepad = match assignment.get_device_for_player(player_id) {
+            Some(InputDevice::Gamepad(id)) => gamepads.iter().nth(*id as usize),
+            _ => None,
+        };
+        let pressed = gamepad.and_then(|gamepad| gamepad.get_just_pressed().next().copied());
+        let bindings = assignment.get_gamepad_bindings(player_id);
+        match pressed.and_then(|button| gamepad_rebind.rebind(button, &bindings)) {
+            Some(Ok(bindings)) => {
+                config_events.write(InputConfigurationEvent::GamepadRebound {
+                    player_id,
+                    bindings,
+                });
+                gamepad_rebind.cancel();
+            }
+            Some(Err(conflict)) => gamepad_rebind.conflict = Some(conflict),
+            None => {}
+        }
+    } else if !capture.active {
+        navigate_input_configuration(
+            config,
+            &mut focus,
+            &assignment,
+            &available_devices,
+            &mut config_events,
+            &input,
+            &gamepad_pressed,
+        );
     }
 
     if let Ok(ctx) = contexts.ctx_mut() {
         egui::CentralPanel::default()
             .frame(egui::Frame::NONE.fill(theme.base_100))
             .show(ctx, |ui| {
-                render_input_configuration_content(
-                    ui,
-                    config,
-                    &theme,
-                    &responsive,
-                    &assignment,
-                    &available_devices,
-                    &mut config_events,
-                );
+                scoped_ui(ui, (screen_entity, "input_configuration"), |ui| {
+                    render_input_configuration_breadcrumbs(
+                        ui,
+                        &theme,
+                        &responsive,
+                        &mut config_events,
+                        &mut navigate_events,
+                    );
+                    if capture.active {
+                        render_keyboard_scheme_capture(
+                            ui,
+                            &mut capture,
+                            &theme,
+                            &responsive,
+                            &mut config_events,
+                        );
+                    } else {
+                        render_input_configuration_content(
+                            ui,
+                            config,
+                            &focus,
+                            &rebind,
+                            &gamepad_rebind,
+                            &theme,
+                            &responsive,
+                            &assignment,
+                            &available_devices,
+                            player_style,
+                            &mut config_events,
+                        );
+                    }
+                })
             });
     }
 }
 
+/// Move the focus and assign the focused device from keyboard or gamepad input
+fn navigate_input_configuration(
+    config: &ActiveInputConfiguration,
+    focus: &mut InputConfigurationFocus,
+    assignment: &InputDeviceAssignment,
+    available_devices: &AvailableInputDevices,
+    config_events: &mut MessageWriter<InputConfigurationEvent>,
+    input: &ButtonInput<KeyCode>,
+    gamepad_pressed: &impl Fn(GamepadButton) -> bool,
+) {
+    let devices = ordered_devices(available_devices);
+    let players = config.current_players.max(1);
+    let previous = focus.clone();
+
+    if input.just_pressed(KeyCode::ArrowDown) || gamepad_pressed(GamepadButton::DPadDown) {
+        focus.device += 1;
+    } else if input.just_pressed(KeyCode::ArrowUp) || gamepad_pressed(GamepadButton::DPadUp) {
+        focus.device = focus
+            .device
+            .checked_sub(1)
+            .unwrap_or(devices.len().saturating_sub(1));
+    }
+    if input.just_pressed(KeyCode::ArrowRight)
+        || gamepad_pressed(GamepadButton::DPadRight)
+        || gamepad_pressed(GamepadButton::RightTrigger)
+    {
+        focus.player = (focus.player + 1) % players;
+    } else if input.just_pressed(KeyCode::ArrowLeft)
+        || gamepad_pressed(GamepadButton::DPadLeft)
+        || gamepad_pressed(GamepadButton::LeftTrigger)
+    {
+        focus.player = (focus.player + players - 1) % players;
+    }
+    focus.player = focus.player.min(players - 1);
+    if devices.is_empty() {
+        focus.device = 0;
+    } else {
+        focus.device %= devices.len();
+    }
+    focus.moved = focus.player != previous.player || focus.device != previous.device;
+
+    let select = input.just_pressed(KeyCode::Enter)
+        || input.just_pressed(KeyCode::Space)
+        || gamepad_pressed(GamepadButton::South);
+    let Some(device) = devices.get(focus.device).filter(|_| select) else {
+        return;
+    };
+
+    let player_id = focus.player;
+    if assignment.get_device_for_player(player_id) == Some(device) {
+        config_events.write(InputConfigurationEvent::DeviceUnassigned { player_id });
+    } else if device.is_available(available_devices)
+        && assignment
+            .get_player_for_device(device)
+            .is_none_or(|player| player == player_id)
+    {
+        config_events.write(InputConfigurationEvent::DeviceAssigned {
+            player_id,
+            device: device.clone(),
+        });
+    }
+}
+
+/// Render the trail back to the settings screen and the game
+fn render_input_configuration_breadcrumbs(
+    ui: &mut egui::Ui,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    config_events: &mut MessageWriter<InputConfigurationEvent>,
+    navigate_events: &mut MessageWriter<NavigateBack>,
+) {
+    ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+    let clicked = ThemedBreadcrumbs::new(&["Home", "Settings", "Input"], theme)
+        .responsive(responsive)
+        .show(ui)
+        .inner;
+
+    match clicked {
+        Some(0) => {
+            navigate_events.write(NavigateBack::home());
+        }
+        Some(_) => {
+            config_events.write(InputConfigurationEvent::Close);
+        }
+        None => {}
+    }
+}
+
 /// Render UI when input resources are not available
 fn render_input_unavailable_ui(
     contexts: &mut EguiContexts,
+    screen_entity: Entity,
     theme: &KonnektorenTheme,
     responsive: &ResponsiveInfo,
     config_events: &mut MessageWriter<InputConfigurationEvent>,
@@ -201,6 +783,7 @@ fn render_input_unavailable_ui(
         egui::CentralPanel::default()
             .frame(egui::Frame::NONE.fill(theme.base_100))
             .show(ctx, |ui| {
+                scoped_ui(ui, (screen_entity, "input_unavailable"), |ui| {
                 ui.vertical_centered(|ui| {
                     let max_width = if responsive.is_mobile() {
                         ui.available_width() * 0.95
@@ -244,32 +827,36 @@ fn render_input_unavailable_ui(
 
                     ui.add_space(responsive.spacing(ResponsiveSpacing::XLarge));
 
-                    // Back button with unique ID using scope and push_id
-                    ui.scope(|ui| {
-                        ui.push_id(Id::new("input_unavailable_back_button"), |ui| {
-                            let back_button = ThemedButton::new("← Back to Settings", theme)
-                                .responsive(responsive)
-                                .width(if responsive.is_mobile() { 200.0 } else { 180.0 });
+                    // Back button
+                    scoped_ui(ui, "input_unavailable_back_button", |ui| {
+                        let back_button = ThemedButton::new("← Back to Settings", theme)
+                            .responsive(responsive)
+                            .width(if responsive.is_mobile() { 200.0 } else { 180.0 });
 
-                            if ui.add(back_button).clicked() {
-                                config_events.write(InputConfigurationEvent::Close);
-                            }
-                        });
+                        if ui.add(back_button).clicked() {
+                            config_events.write(InputConfigurationEvent::Close);
+                        }
                     });
 
                     ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
                 });
+            })
             });
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn render_input_configuration_content(
     ui: &mut egui::Ui,
     config: &ActiveInputConfiguration,
+    focus: &InputConfigurationFocus,
+    rebind: &KeyRebindCapture,
+    gamepad_rebind: &GamepadRebindCapture,
     theme: &KonnektorenTheme,
     responsive: &ResponsiveInfo,
     assignment: &InputDeviceAssignment,
     available_devices: &AvailableInputDevices,
+    player_style: &PlayerStyle,
     config_events: &mut MessageWriter<InputConfigurationEvent>,
 ) {
     ui.vertical_centered(|ui| {
@@ -306,10 +893,8 @@ fn render_input_configuration_content(
         ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
 
         // Device status section with unique ID
-        ui.scope(|ui| {
-            ui.push_id("device_status_section", |ui| {
-                render_device_status_section(ui, theme, responsive, available_devices);
-            });
+        scoped_ui(ui, "device_status_section", |ui| {
+            render_device_status_section(ui, theme, responsive, available_devices);
         });
 
         ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
@@ -320,40 +905,139 @@ fn render_input_configuration_content(
             .id_salt("input_config_scroll")
             .max_height(scroll_height)
             .show(ui, |ui| {
-                ui.scope(|ui| {
-                    ui.push_id("player_config_grid", |ui| {
-                        render_player_configuration_grid(
-                            ui,
-                            config,
-                            theme,
-                            responsive,
-                            assignment,
-                            available_devices,
-                            config_events,
-                        );
-                    });
+                scoped_ui(ui, "player_config_grid", |ui| {
+                    render_player_configuration_grid(
+                        ui,
+                        config,
+                        focus,
+                        rebind,
+                        gamepad_rebind,
+                        theme,
+                        responsive,
+                        assignment,
+                        available_devices,
+                        player_style,
+                        config_events,
+                    );
                 });
             });
 
         // Footer with back button
         ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
 
-        ui.scope(|ui| {
-            ui.push_id("input_config_back_button", |ui| {
-                let back_button = ThemedButton::new("← Back to Settings", theme)
-                    .responsive(responsive)
-                    .width(if responsive.is_mobile() { 200.0 } else { 180.0 });
+        scoped_ui(ui, "create_custom_scheme_button", |ui| {
+            let create_button = ThemedButton::new("Create custom scheme…", theme)
+                .responsive(responsive)
+                .width(if responsive.is_mobile() { 200.0 } else { 220.0 });
 
-                if ui.add(back_button).clicked() {
-                    config_events.write(InputConfigurationEvent::Close);
-                }
-            });
+            if ui.add(create_button).clicked() {
+                config_events.write(InputConfigurationEvent::StartCustomScheme);
+            }
+        });
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+
+        scoped_ui(ui, "input_config_back_button", |ui| {
+            let back_button = ThemedButton::new("← Back to Settings", theme)
+                .responsive(responsive)
+                .width(if responsive.is_mobile() { 200.0 } else { 180.0 });
+
+            if ui.add(back_button).clicked() {
+                config_events.write(InputConfigurationEvent::Close);
+            }
         });
 
         ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
     });
 }
 
+/// Render the steps of creating a custom keyboard scheme: the captured keys,
+/// then the name of the scheme
+fn render_keyboard_scheme_capture(
+    ui: &mut egui::Ui,
+    capture: &mut KeyboardSchemeCapture,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    config_events: &mut MessageWriter<InputConfigurationEvent>,
+) {
+    ui.vertical_centered(|ui| {
+        ui.set_max_width(600.0_f32.min(ui.available_width() * 0.9));
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+        ResponsiveText::new(
+            "Create Custom Scheme",
+            ResponsiveFontSize::Header,
+            theme.primary,
+        )
+        .responsive(responsive)
+        .strong()
+        .ui(ui);
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+        let instructions = match capture.next_step() {
+            Some(step) => format!("Press the key for {}", step),
+            None => "Name your scheme".to_string(),
+        };
+        ResponsiveText::new(
+            &instructions,
+            ResponsiveFontSize::Medium,
+            theme.base_content,
+        )
+        .responsive(responsive)
+        .ui(ui);
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+        for (index, step) in SCHEME_KEY_STEPS.iter().enumerate() {
+            let (key, color) = match capture.keys.get(index) {
+                Some(key) => (format!("{:?}", key), theme.success),
+                None if index == capture.keys.len() => ("…".to_string(), theme.accent),
+                None => ("-".to_string(), theme.base_content.gamma_multiply(0.5)),
+            };
+            ResponsiveText::new(
+                &format!("{}: {}", step, key),
+                ResponsiveFontSize::Medium,
+                color,
+            )
+            .responsive(responsive)
+            .ui(ui);
+        }
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+        if capture.is_complete() {
+            scoped_ui(ui, "custom_scheme_name", |ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut capture.name)
+                        .hint_text("Scheme name")
+                        .desired_width(250.0),
+                );
+            });
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+            let scheme = capture.scheme();
+            let save_button = ThemedButton::new("Save scheme", theme)
+                .responsive(responsive)
+                .width(180.0)
+                .enabled(scheme.is_some());
+            if ui.add(save_button).clicked() {
+                if let Some(scheme) = scheme {
+                    config_events.write(InputConfigurationEvent::CustomSchemeCreated(scheme));
+                }
+            }
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+        }
+
+        let cancel_button = ThemedButton::new("Cancel", theme)
+            .responsive(responsive)
+            .width(180.0);
+        if ui.add(cancel_button).clicked() {
+            capture.cancel();
+        }
+    });
+}
+
 fn render_device_status_section(
     ui: &mut egui::Ui,
     theme: &KonnektorenTheme,
@@ -368,7 +1052,7 @@ fn render_device_status_section(
         ..Default::default()
     };
 
-    frame.show(ui, |ui| {
+    let devices = frame.show(ui, |ui| {
         ResponsiveText::new(
             "Available Devices",
             ResponsiveFontSize::Large,
@@ -437,20 +1121,17 @@ fn render_device_status_section(
                         ..Default::default()
                     };
 
-                    ui.push_id(
-                        format!("device_status_{}_{}", category.name(), device_index),
-                        |ui| {
-                            device_frame.show(ui, |ui| {
-                                ResponsiveText::new(
-                                    &device.name(),
-                                    ResponsiveFontSize::Small,
-                                    text_color,
-                                )
-                                .responsive(responsive)
-                                .ui(ui);
-                            });
-                        },
-                    );
+                    scoped_ui(ui, ("device_status", category.name(), device_index), |ui| {
+                        device_frame.show(ui, |ui| {
+                            ResponsiveText::new(
+                                &device.name(),
+                                ResponsiveFontSize::Small,
+                                text_color,
+                            )
+                            .responsive(responsive)
+                            .ui(ui);
+                        });
+                    });
 
                     ui.add_space(responsive.spacing(ResponsiveSpacing::XSmall));
                 }
@@ -459,15 +1140,21 @@ fn render_device_status_section(
             ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
         }
     });
+    devices.response.coach_mark("input.devices");
 }
 
+#[allow(clippy::too_many_arguments)]
 fn render_player_configuration_grid(
     ui: &mut egui::Ui,
     config: &ActiveInputConfiguration,
+    focus: &InputConfigurationFocus,
+    rebind: &KeyRebindCapture,
+    gamepad_rebind: &GamepadRebindCapture,
     theme: &KonnektorenTheme,
     responsive: &ResponsiveInfo,
     assignment: &InputDeviceAssignment,
     available_devices: &AvailableInputDevices,
+    player_style: &PlayerStyle,
     config_events: &mut MessageWriter<InputConfigurationEvent>,
 ) {
     let panel_width = if responsive.is_mobile() {
@@ -480,18 +1167,22 @@ fn render_player_configuration_grid(
 
     while current_player < config.current_players {
         // Add unique ID salt for each row of players
-        ui.push_id(format!("player_row_{}", current_player / 2), |ui| {
+        scoped_ui(ui, ("player_row", current_player / 2), |ui| {
             if responsive.is_mobile() {
                 // Mobile: one column
-                ui.push_id(format!("player_panel_mobile_{}", current_player), |ui| {
+                scoped_ui(ui, ("player_panel_mobile", current_player), |ui| {
                     render_player_panel(
                         ui,
                         current_player,
+                        focus,
+                        rebind,
+                        gamepad_rebind,
                         panel_width,
                         theme,
                         responsive,
                         assignment,
                         available_devices,
+                        player_style,
                         config_events,
                     );
                 });
@@ -499,30 +1190,38 @@ fn render_player_configuration_grid(
             } else {
                 // Desktop: two columns
                 ui.horizontal(|ui| {
-                    ui.push_id(format!("player_panel_left_{}", current_player), |ui| {
+                    scoped_ui(ui, ("player_panel_left", current_player), |ui| {
                         render_player_panel(
                             ui,
                             current_player,
+                            focus,
+                            rebind,
+                            gamepad_rebind,
                             panel_width,
                             theme,
                             responsive,
                             assignment,
                             available_devices,
+                            player_style,
                             config_events,
                         );
                     });
 
                     if current_player + 1 < config.current_players {
                         ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
-                        ui.push_id(format!("player_panel_right_{}", current_player + 1), |ui| {
+                        scoped_ui(ui, ("player_panel_right", current_player + 1), |ui| {
                             render_player_panel(
                                 ui,
                                 current_player + 1,
+                                focus,
+                                rebind,
+                                gamepad_rebind,
                                 panel_width,
                                 theme,
                                 responsive,
                                 assignment,
                                 available_devices,
+                                player_style,
                                 config_events,
                             );
                         });
@@ -542,34 +1241,46 @@ fn render_player_configuration_grid(
 fn render_player_panel(
     ui: &mut egui::Ui,
     player_id: u32,
+    focus: &InputConfigurationFocus,
+    rebind: &KeyRebindCapture,
+    gamepad_rebind: &GamepadRebindCapture,
     width: f32,
     theme: &KonnektorenTheme,
     responsive: &ResponsiveInfo,
     assignment: &InputDeviceAssignment,
     available_devices: &AvailableInputDevices,
+    player_style: &PlayerStyle,
     config_events: &mut MessageWriter<InputConfigurationEvent>,
 ) {
     let current_device = assignment.get_device_for_player(player_id);
+    let player_color = player_style.color(player_id, theme);
 
     let frame = egui::Frame {
         inner_margin: responsive.margin_all(crate::ui::responsive::ResponsiveMargin::Medium),
         corner_radius: egui::CornerRadius::same(8),
         fill: theme.base_200,
-        stroke: egui::Stroke::new(2.0, theme.primary.linear_multiply(0.5)),
+        stroke: egui::Stroke::new(
+            2.0,
+            if focus.player == player_id {
+                player_color
+            } else {
+                player_color.linear_multiply(0.5)
+            },
+        ),
         ..Default::default()
     };
 
     // Use player-specific ID for the entire panel
-    ui.push_id(format!("player_panel_content_{}", player_id), |ui| {
-        frame.show(ui, |ui| {
+    scoped_ui(ui, ("player_panel_content", player_id), |ui| {
+        let panel = frame.show(ui, |ui| {
             ui.set_min_width(width);
 
             ui.vertical(|ui| {
                 // Player header
                 ResponsiveText::new(
-                    &format!("Player {}", player_id + 1),
+                    &player_style.name(player_id),
                     ResponsiveFontSize::Large,
-                    theme.primary,
+                    player_color,
                 )
                 .responsive(responsive)
                 .strong()
@@ -578,7 +1289,7 @@ fn render_player_panel(
                 ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
 
                 // Current device display with unique ID
-                ui.push_id(format!("current_device_display_{}", player_id), |ui| {
+                scoped_ui(ui, ("current_device_display", player_id), |ui| {
                     let (device_text, device_desc) = if let Some(device) = current_device {
                         (device.name(), device.description())
                     } else {
@@ -613,10 +1324,39 @@ fn render_player_panel(
                     }
                 });
 
+                if let Some(InputDevice::Keyboard(scheme)) = current_device {
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                    scoped_ui(ui, ("key_bindings", player_id), |ui| {
+                        render_key_bindings(
+                            ui,
+                            player_id,
+                            scheme,
+                            rebind,
+                            theme,
+                            responsive,
+                            config_events,
+                        );
+                    });
+                }
+                if let Some(InputDevice::Gamepad(_)) = current_device {
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                    scoped_ui(ui, ("gamepad_bindings", player_id), |ui| {
+                        render_gamepad_bindings(
+                            ui,
+                            player_id,
+                            &assignment.get_gamepad_bindings(player_id),
+                            gamepad_rebind,
+                            theme,
+                            responsive,
+                            config_events,
+                        );
+                    });
+                }
+
                 ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
 
                 // Device selection section with unique ID
-                ui.push_id(format!("device_selection_{}", player_id), |ui| {
+                scoped_ui(ui, ("device_selection", player_id), |ui| {
                     ResponsiveText::new(
                         "Available Devices:",
                         ResponsiveFontSize::Medium,
@@ -630,6 +1370,7 @@ fn render_player_panel(
                     render_device_categories_for_player(
                         ui,
                         player_id,
+                        focus,
                         width,
                         theme,
                         responsive,
@@ -643,7 +1384,7 @@ fn render_player_panel(
                 if current_device.is_some() {
                     ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
 
-                    ui.push_id(format!("unassign_section_{}", player_id), |ui| {
+                    scoped_ui(ui, ("unassign_section", player_id), |ui| {
                         let unassign_button = ThemedButton::new("Unassign Device", theme)
                             .responsive(responsive)
                             .width(width - 40.0)
@@ -657,12 +1398,104 @@ fn render_player_panel(
                 }
             });
         });
+        panel
+            .response
+            .coach_mark(&format!("input.player.{}", player_id));
+    });
+}
+
+/// Render the keys of a player's keyboard scheme, clicking one rebinds it
+fn render_key_bindings(
+    ui: &mut egui::Ui,
+    player_id: u32,
+    scheme: &KeyboardScheme,
+    rebind: &KeyRebindCapture,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    config_events: &mut MessageWriter<InputConfigurationEvent>,
+) {
+    let keys = CustomKeyboardScheme::from_scheme(scheme.name(), scheme).keys();
+    let capturing = rebind
+        .target
+        .filter(|(target, _)| *target == player_id)
+        .map(|(_, binding)| binding);
+
+    ui.horizontal_wrapped(|ui| {
+        for (binding, (step, key)) in SCHEME_KEY_STEPS.iter().zip(keys).enumerate() {
+            let label = if capturing == Some(binding) {
+                format!("{}: press a key…", step)
+            } else {
+                format!("{}: {:?}", step, key)
+            };
+            let mut button = ThemedButton::new(&label, theme).responsive(responsive);
+            if capturing == Some(binding) {
+                button = button.with_style(|btn| btn.stroke(egui::Stroke::new(3.0, theme.accent)));
+            }
+
+            scoped_ui(ui, ("key_binding", binding), |ui| {
+                if ui.add(button).clicked() {
+                    config_events
+                        .write(InputConfigurationEvent::StartKeyRebind { player_id, binding });
+                }
+            });
+        }
     });
+
+    if let Some(conflict) = rebind.conflict.as_ref().filter(|_| capturing.is_some()) {
+        ResponsiveText::new(conflict, ResponsiveFontSize::Small, theme.error)
+            .responsive(responsive)
+            .ui(ui);
+    }
 }
 
+/// Render the buttons of a player's gamepad actions, clicking one rebinds it
+fn render_gamepad_bindings(
+    ui: &mut egui::Ui,
+    player_id: u32,
+    bindings: &GamepadBindings,
+    rebind: &GamepadRebindCapture,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    config_events: &mut MessageWriter<InputConfigurationEvent>,
+) {
+    let capturing = rebind
+        .target
+        .filter(|(target, _)| *target == player_id)
+        .map(|(_, action)| action);
+
+    ui.horizontal_wrapped(|ui| {
+        for action in GamepadAction::ALL {
+            let label = if capturing == Some(action) {
+                format!("{}: press a button on your controller…", action.name())
+            } else {
+                format!("{}: {:?}", action.name(), bindings.button(action))
+            };
+            let mut button = ThemedButton::new(&label, theme).responsive(responsive);
+            if capturing == Some(action) {
+                button = button.with_style(|btn| btn.stroke(egui::Stroke::new(3.0, theme.accent)));
+            }
+
+            scoped_ui(ui, ("gamepad_binding", action.name()), |ui| {
+                if ui.add(button).clicked() {
+                    config_events
+                        .write(InputConfigurationEvent::StartGamepadRebind { player_id, action });
+                }
+            });
+        }
+    });
+
+    if let Some(conflict) = rebind.conflict.as_ref().filter(|_| capturing.is_some()) {
+        ResponsiveText::new(conflict, ResponsiveFontSize::Small, theme.error)
+            .responsive(responsive)
+            .ui(ui);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn render_device_categories_for_player(
     ui: &mut egui::Ui,
     player_id: u32,
+    focus: &InputConfigurationFocus,
     width: f32,
     theme: &KonnektorenTheme,
     responsive: &ResponsiveInfo,
@@ -671,6 +1504,10 @@ fn render_device_categories_for_player(
     config_events: &mut MessageWriter<InputConfigurationEvent>,
 ) {
     let devices = available_devices.get_available_devices();
+    let focused_device = ordered_devices(available_devices)
+        .into_iter()
+        .nth(focus.device)
+        .filter(|_| focus.player == player_id);
 
     // Group devices by category for organized display
     let mut categories = std::collections::HashMap::new();
@@ -688,69 +1525,75 @@ fn render_device_categories_for_player(
 
     // Render each category with unique IDs
     for (category, devices_in_category) in sorted_categories {
-        ui.push_id(
-            format!("category_{}_{}", category.name(), player_id),
-            |ui| {
-                // Category header
-                ResponsiveText::new(
-                    &format!("{} {}", category.icon(), category.name()),
-                    ResponsiveFontSize::Small,
-                    theme.secondary,
-                )
-                .responsive(responsive)
-                .ui(ui);
-
-                ui.add_space(responsive.spacing(ResponsiveSpacing::XSmall));
-
-                // Devices in this category with unique IDs
-                ui.push_id(
-                    format!("devices_in_category_{}_{}", category.name(), player_id),
-                    |ui| {
-                        for (device_index, device) in devices_in_category {
-                            let is_selected =
-                                assignment.get_device_for_player(player_id) == Some(device);
-                            let is_available = device.is_available(available_devices);
-                            let is_used_by_other = assignment.is_device_assigned(device)
-                                && assignment.get_player_for_device(device) != Some(player_id);
-
-                            let device_name = device.name();
-                            let mut button = ThemedButton::new(&device_name, theme)
-                                .responsive(responsive)
-                                .width(width - 40.0);
-
-                            // Style the button based on state
-                            if is_selected {
-                                button = button.with_style(|btn| btn.fill(theme.success));
-                            } else if !is_available || is_used_by_other {
-                                button = button.enabled(false).opacity(0.5);
-                            }
+        scoped_ui(ui, ("category", category.name(), player_id), |ui| {
+            // Category header
+            ResponsiveText::new(
+                &format!("{} {}", category.icon(), category.name()),
+                ResponsiveFontSize::Small,
+                theme.secondary,
+            )
+            .responsive(responsive)
+            .ui(ui);
 
-                            // Use comprehensive unique ID for each device button
-                            let button_id = format!(
-                                "device_btn_p{}_cat{}_dev{}_idx{}",
-                                player_id,
-                                category.name(),
-                                device_index,
-                                device.name().replace(' ', "_")
-                            );
+            ui.add_space(responsive.spacing(ResponsiveSpacing::XSmall));
 
-                            ui.push_id(button_id, |ui| {
-                                if ui.add(button).clicked() && is_available && !is_used_by_other {
-                                    config_events.write(InputConfigurationEvent::DeviceAssigned {
-                                        player_id,
-                                        device: device.clone(),
-                                    });
+            // Devices in this category with unique IDs
+            scoped_ui(
+                ui,
+                ("devices_in_category", category.name(), player_id),
+                |ui| {
+                    for (device_index, device) in devices_in_category {
+                        let is_selected =
+                            assignment.get_device_for_player(player_id) == Some(device);
+                        let is_available = device.is_available(available_devices);
+                        let is_used_by_other = assignment.is_device_assigned(device)
+                            && assignment.get_player_for_device(device) != Some(player_id);
+
+                        let device_name = device.name();
+                        let mut button = ThemedButton::new(&device_name, theme)
+                            .responsive(responsive)
+                            .width(width - 40.0);
+
+                        // Style the button based on state
+                        let is_focused = focused_device.as_ref() == Some(device);
+                        if is_selected || is_focused {
+                            button = button.with_style(move |btn| {
+                                let btn = if is_selected {
+                                    btn.fill(theme.success)
+                                } else {
+                                    btn
+                                };
+                                if is_focused {
+                                    btn.stroke(egui::Stroke::new(3.0, theme.accent))
+                                } else {
+                                    btn
                                 }
                             });
-
-                            ui.add_space(responsive.spacing(ResponsiveSpacing::XSmall));
                         }
-                    },
-                );
+                        if !is_selected && (!is_available || is_used_by_other) {
+                            button = button.enabled(false).opacity(0.5);
+                        }
 
-                ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
-            },
-        );
+                        scoped_ui(ui, ("device_button", device_index), |ui| {
+                            let response = ui.add(button);
+                            if is_focused && focus.moved {
+                                response.scroll_to_me(None);
+                            }
+                            if response.clicked() && is_available && !is_used_by_other {
+                                config_events.write(InputConfigurationEvent::DeviceAssigned {
+                                    player_id,
+                                    device: device.clone(),
+                                });
+                            }
+                        });
+
+                        ui.add_space(responsive.spacing(ResponsiveSpacing::XSmall));
+                    }
+                },
+            );
+
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+        });
     }
 }
 
@@ -787,3 +1630,270 @@ pub fn spawn_input_configuration_screen(
         ))
         .id()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+
+    #[test]
+    fn test_keyboard_device_assignment() {
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            InputConfigurationPlugin,
+        ));
+        harness
+            .app_mut()
+            .add_message::<InputEvent>()
+            .insert_resource(InputDeviceAssignment::new(2))
+            .insert_resource(AvailableInputDevices {
+                keyboard: true,
+                ..Default::default()
+            });
+        harness.world_mut().spawn(ActiveInputConfiguration {
+            max_players: 2,
+            current_players: 2,
+        });
+        harness.run_frames(2);
+
+        // Player 1 takes the first keyboard scheme
+        harness.press_key_code(KeyCode::Enter);
+        // Player 2 can't take it as well, and gets the next one
+        harness.press_key_code(KeyCode::ArrowRight);
+        harness.press_key_code(KeyCode::Enter);
+        harness.press_key_code(KeyCode::ArrowDown);
+        harness.press_key_code(KeyCode::Enter);
+
+        let assignment = harness.world().resource::<InputDeviceAssignment>();
+        assert_eq!(
+            assignment.get_device_for_player(0),
+            Some(&InputDevice::Keyboard(KeyboardScheme::WASD))
+        );
+        assert_eq!(
+            assignment.get_device_for_player(1),
+            Some(&InputDevice::Keyboard(KeyboardScheme::Arrows))
+        );
+
+        // Selecting the current device again unassigns it
+        harness.press_key_code(KeyCode::Enter);
+        let assignment = harness.world().resource::<InputDeviceAssignment>();
+        assert_eq!(assignment.get_device_for_player(1), None);
+    }
+
+    #[test]
+    fn test_device_focus_wraps_around() {
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            InputConfigurationPlugin,
+        ));
+        let available_devices = AvailableInputDevices {
+            keyboard: true,
+            ..Default::default()
+        };
+        let devices = ordered_devices(&available_devices).len();
+        assert!(devices > 1);
+        harness
+            .app_mut()
+            .add_message::<InputEvent>()
+            .insert_resource(InputDeviceAssignment::new(1))
+            .insert_resource(available_devices);
+        let screen = harness
+            .world_mut()
+            .spawn(ActiveInputConfiguration {
+                max_players: 1,
+                current_players: 1,
+            })
+            .id();
+        harness.run_frames(2);
+
+        let focused_device = |harness: &TestHarness| {
+            harness
+                .world()
+                .get::<InputConfigurationFocus>(screen)
+                .map(|focus| focus.device)
+        };
+
+        // Up from the first device focuses the last one
+        harness.press_key_code(KeyCode::ArrowUp);
+        assert_eq!(focused_device(&harness), Some(devices - 1));
+
+        // Down from the last device focuses the first one
+        harness.press_key_code(KeyCode::ArrowDown);
+        assert_eq!(focused_device(&harness), Some(0));
+    }
+
+    #[test]
+    fn test_create_custom_keyboard_scheme() {
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            InputConfigurationPlugin,
+        ));
+        harness
+            .app_mut()
+            .add_message::<InputEvent>()
+            .init_resource::<CustomSchemes>()
+            .insert_resource(InputDeviceAssignment::new(1))
+            .insert_resource(AvailableInputDevices {
+                keyboard: true,
+                ..Default::default()
+            });
+        harness.world_mut().spawn(ActiveInputConfiguration {
+            max_players: 1,
+            current_players: 1,
+        });
+        harness.run_frames(2);
+
+        harness.click("Create custom scheme…");
+        harness.run_frames(2);
+        assert!(!harness.has_widget("Save scheme"));
+
+        // A key already in the scheme is not taken twice
+        for key in [
+            KeyCode::KeyT,
+            KeyCode::KeyT,
+            KeyCode::KeyG,
+            KeyCode::KeyF,
+            KeyCode::KeyH,
+            KeyCode::KeyR,
+            KeyCode::KeyY,
+        ] {
+            harness.press_key_code(key);
+        }
+        harness.run_frames(2);
+
+        harness.click("Save scheme");
+        harness.run_frames(2);
+
+        let custom_schemes = harness.world().resource::<CustomSchemes>();
+        assert_eq!(
+            custom_schemes.get("Custom 1"),
+            Some(&CustomKeyboardScheme {
+                name: "Custom 1".to_string(),
+                up: KeyCode::KeyT,
+                down: KeyCode::KeyG,
+                left: KeyCode::KeyF,
+                right: KeyCode::KeyH,
+                primary: KeyCode::KeyR,
+                secondary: KeyCode::KeyY,
+            })
+        );
+        assert!(harness.has_widget("Create custom scheme…"));
+    }
+
+    #[test]
+    fn test_rebind_key() {
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            InputConfigurationPlugin,
+        ));
+        let mut assignment = InputDeviceAssignment::new(2);
+        assignment.assign_device(0, InputDevice::Keyboard(KeyboardScheme::WASD));
+        assignment.assign_device(1, InputDevice::Keyboard(KeyboardScheme::Arrows));
+        harness
+            .app_mut()
+            .add_message::<InputEvent>()
+            .init_resource::<CustomSchemes>()
+            .insert_resource(assignment)
+            .insert_resource(AvailableInputDevices {
+                keyboard: true,
+                ..Default::default()
+            });
+        harness.world_mut().spawn(ActiveInputConfiguration {
+            max_players: 2,
+            current_players: 2,
+        });
+        harness.run_frames(2);
+
+        harness.click("Up: KeyW");
+        harness.run_frames(2);
+        assert!(harness.has_widget("Up: press a key…"));
+
+        // Keys of other players and of the scheme itself are not taken
+        harness.press_key_code(KeyCode::ArrowUp);
+        assert!(harness.has_widget("ArrowUp is used by Player 2"));
+        harness.press_key_code(KeyCode::KeyS);
+        assert!(harness.has_widget("KeyS is already used for Down"));
+
+        harness.press_key_code(KeyCode::KeyT);
+        harness.run_frames(2);
+
+        let scheme = CustomKeyboardScheme {
+            name: "Player 1 keys".to_string(),
+            up: KeyCode::KeyT,
+            down: KeyCode::KeyS,
+            left: KeyCode::KeyA,
+            right: KeyCode::KeyD,
+            primary: KeyCode::Space,
+            secondary: KeyCode::Escape,
+        };
+        let custom_schemes = harness.world().resource::<CustomSchemes>();
+        assert_eq!(custom_schemes.get("Player 1 keys"), Some(&scheme));
+        let assignment = harness.world().resource::<InputDeviceAssignment>();
+        assert_eq!(
+            assignment.get_device_for_player(0),
+            Some(&InputDevice::Keyboard(KeyboardScheme::UserDefined(scheme)))
+        );
+        assert!(harness.has_widget("Up: KeyT"));
+    }
+
+    #[test]
+    fn test_rebind_gamepad_button() {
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            InputConfigurationPlugin,
+        ));
+        let gamepad = harness.world_mut().spawn(Gamepad::default()).id();
+        let mut assignment = InputDeviceAssignment::new(1);
+        assignment.assign_device(0, InputDevice::Gamepad(0));
+        harness
+            .app_mut()
+            .add_message::<InputEvent>()
+            .insert_resource(assignment)
+            .insert_resource(AvailableInputDevices {
+                gamepads: vec![gamepad],
+                ..Default::default()
+            });
+        harness.world_mut().spawn(ActiveInputConfiguration {
+            max_players: 1,
+            current_players: 1,
+        });
+        harness.run_frames(2);
+
+        harness.click("Confirm: South");
+        harness.run_frames(2);
+        assert!(harness.has_widget("Confirm: press a button on your controller…"));
+
+        let press = |harness: &mut TestHarness, button| {
+            let mut buttons = harness.world_mut().get_mut::<Gamepad>(gamepad).unwrap();
+            buttons.digital_mut().press(button);
+            harness.step();
+            let mut buttons = harness.world_mut().get_mut::<Gamepad>(gamepad).unwrap();
+            buttons.digital_mut().release(button);
+            buttons.digital_mut().clear();
+            harness.step();
+        };
+
+        // Movement buttons and the buttons of other actions are not taken
+        press(&mut harness, GamepadButton::DPadUp);
+        assert!(harness.has_widget("DPadUp moves the player"));
+        press(&mut harness, GamepadButton::East);
+        assert!(harness.has_widget("East is already used for Back"));
+
+        press(&mut harness, GamepadButton::North);
+        harness.run_frames(2);
+        let assignment = harness.world().resource::<InputDeviceAssignment>();
+        assert_eq!(
+            assignment.get_gamepad_bindings(0),
+            GamepadBindings {
+                primary: GamepadButton::North,
+                secondary: GamepadButton::East,
+            }
+        );
+        assert!(harness.has_widget("Confirm: North"));
+    }
+}
@@ -1,4 +1,9 @@
 use super::*;
+use crate::screens::{
+    dismiss_on_inactivity, forward_screen_events, track_active_screen, WorldScreenContextPass,
+};
+#[cfg(feature = "settings")]
+use crate::settings::{update_settings_from_components, DisplayModeChanged};
 use bevy::prelude::*;
 use bevy_egui::EguiPrimaryContextPass;
 
@@ -7,29 +12,64 @@ pub struct SettingsScreenPlugin;
 
 impl Plugin for SettingsScreenPlugin {
     fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveSettingsScreen>(app);
+        track_active_screen::<ActiveComponentSettings>(app);
+        forward_screen_events::<SettingsScreenEvent>(app);
+        forward_screen_events::<ComponentSettingsEvent>(app);
+        dismiss_on_inactivity::<ActiveSettingsScreen, _>(app, |entity| {
+            SettingsScreenEvent::Dismissed { entity }
+        });
+
         app.add_message::<SettingsScreenEvent>()
             .add_message::<ComponentSettingsEvent>()
             .add_systems(
                 Update,
                 (
                     check_settings_screen_config,
+                    send_settings_navigation_input,
+                    apply_settings_navigation.after(send_settings_navigation_input),
                     handle_settings_screen_events,
-                    update_settings_screen_values,
+                    update_settings_screen_values.after(apply_settings_navigation),
                     cleanup_component_settings,
                 ),
             )
-            .add_systems(EguiPrimaryContextPass, render_settings_screen_ui)
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_settings_screen_ui.run_if(any_with_component::<ActiveSettingsScreen>),
+            )
+            .add_systems(
+                WorldScreenContextPass,
+                render_settings_screen_ui.run_if(any_with_component::<ActiveSettingsScreen>),
+            )
             // Add input configuration plugin
             .add_plugins(InputConfigurationPlugin);
 
         // Add component-based settings systems if settings feature is enabled
         #[cfg(feature = "settings")]
         {
-            app.add_systems(
-                Update,
-                (check_component_settings, process_pending_setting_updates),
-            )
-            .add_systems(EguiPrimaryContextPass, render_component_settings_ui);
+            app.add_message::<DisplayModeChanged>()
+                .add_systems(
+                    Update,
+                    (
+                        check_component_settings,
+                        // The changed marker has to be in place when the
+                        // settings plugin reports the change
+                        process_pending_setting_updates.before(update_settings_from_components),
+                        forward_display_screen_values,
+                        sync_display_screen_values,
+                        forward_appearance_screen_values,
+                        forward_parental_screen_values,
+                        // Sync after pending updates were written to the settings
+                        sync_live_settings_screens.after(update_settings_from_components),
+                        route_live_setting_values.after(apply_settings_navigation),
+                        handle_settings_undo_keys,
+                    ),
+                )
+                .add_systems(
+                    EguiPrimaryContextPass,
+                    render_component_settings_ui
+                        .run_if(any_with_component::<ActiveComponentSettings>),
+                );
         }
     }
 }
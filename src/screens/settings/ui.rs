@@ -1,34 +1,86 @@
 use super::config::*;
 use super::input_configuration::{ActiveInputConfiguration, InputConfigurationEvent};
 use crate::{
+    screens::{insert_inactivity_timeout, ScreenContexts},
     theme::KonnektorenTheme,
     ui::{
+        announcer::{Announcer, Politeness},
+        coach_marks::{register_coach_mark, CoachMarkExt},
         responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
         widgets::{ResponsiveText, ThemedButton},
     },
 };
 use bevy::prelude::*;
 use bevy_egui::{
     egui::{self, Widget},
-    EguiContexts,
+    input::EguiWantsInput,
 };
 
 #[cfg(feature = "settings")]
-use crate::settings::{SettingType, SettingValue};
+use crate::ui::widgets::ThemedStepper;
+
+#[cfg(feature = "settings")]
+use super::component_ui::{ActiveComponentSettings, PendingSettingUpdate};
+#[cfg(feature = "settings")]
+use crate::settings::{
+    apply_parental_setting, DisplayModeChanged, DisplayState, EditHistory, ParentalControls,
+    Setting, SettingType, SettingValue, SettingsIndex, FULLSCREEN_SETTING_ID,
+    PARENTAL_PIN_MAX_LENGTH, PARENTAL_PIN_SETTING_ID, POINTER_LOCK_SETTING_ID,
+};
+#[cfg(feature = "settings")]
+use crate::theme::{
+    AccessibilityOptions, ColorAssistance, ColorVisionMode, COLOR_VISION_SETTING_ID,
+    DYSLEXIA_FRIENDLY_SETTING_ID, HIGH_VISIBILITY_SETTING_ID, SHAPE_CUES_SETTING_ID,
+};
 
 /// Component marking an active settings screen
 #[derive(Component)]
 pub struct ActiveSettingsScreen {
     config: SettingsScreenConfig,
     navigation_state: SettingsNavigationState,
+    #[cfg(feature = "settings")]
+    pin_gate: PinGate,
+}
+
+/// PIN prompt protecting the settings screen under parental controls
+#[cfg(feature = "settings")]
+#[derive(Default)]
+struct PinGate {
+    unlocked: bool,
+    pin: String,
+    failed: bool,
 }
 
-/// Navigation state for settings
+#[cfg(feature = "settings")]
+impl PinGate {
+    /// Whether the PIN still has to be entered
+    fn locked(&self, controls: Option<&ParentalControls>) -> bool {
+        controls.is_some_and(ParentalControls::requires_pin) && !self.unlocked
+    }
+
+    /// Unlock the gate while no PIN is required, so a PIN set on the open
+    /// screen does not lock it, and return whether it is locked
+    fn update(&mut self, controls: Option<&ParentalControls>) -> bool {
+        if !controls.is_some_and(ParentalControls::requires_pin) {
+            self.unlocked = true;
+        }
+        !self.unlocked
+    }
+}
+
+/// Navigation state for keyboard/gamepad support.
+///
+/// The settings have the indices `0..max_index`, first those with a
+/// configured `navigation_index` in that order, then the others in the order
+/// they are shown. The back button comes last.
 #[derive(Clone)]
 pub struct SettingsNavigationState {
     pub current_index: usize,
     pub max_index: usize,
     pub enabled: bool,
+    /// Focus moved in this frame, the focused setting scrolls into view
+    pub focus_moved: bool,
 }
 
 impl Default for SettingsNavigationState {
@@ -37,10 +89,25 @@ impl Default for SettingsNavigationState {
             current_index: 0,
             max_index: 0,
             enabled: true,
+            focus_moved: false,
         }
     }
 }
 
+impl SettingsNavigationState {
+    /// Move the focus by `step` elements, wrapping around at the ends
+    pub fn move_focus(&mut self, step: isize) {
+        let count = self.max_index as isize + 1;
+        self.current_index = (self.current_index as isize + step).rem_euclid(count) as usize;
+        self.focus_moved = true;
+    }
+
+    /// Whether the element with the index has the focus
+    pub fn is_focused(&self, index: usize) -> bool {
+        self.enabled && self.current_index == index
+    }
+}
+
 /// System to check for new settings configurations
 #[allow(clippy::type_complexity)]
 pub fn check_settings_screen_config(
@@ -50,6 +117,7 @@ pub fn check_settings_screen_config(
         (Without<ActiveSettingsScreen>, Changed<SettingsScreenConfig>),
     >,
     existing_settings: Query<Entity, With<ActiveSettingsScreen>>,
+    #[cfg(feature = "settings")] parental_controls: Option<Res<ParentalControls>>,
 ) {
     for (entity, config) in query.iter() {
         info!("Setting up settings screen for entity {:?}", entity);
@@ -65,28 +133,359 @@ pub fn check_settings_screen_config(
                 .remove::<ActiveSettingsScreen>();
         }
 
-        // Calculate max navigation index
-        let mut max_index = 0;
-        for section in &config.sections {
-            for setting in &section.settings {
-                if let Some(nav_index) = setting.navigation_index {
-                    max_index = max_index.max(nav_index);
-                }
-            }
-        }
-        if config.allow_dismissal {
-            max_index += 1; // For back button
-        }
-
         let nav_state = SettingsNavigationState {
-            max_index,
+            max_index: max_navigation_index(config),
+            enabled: config.navigation_enabled,
             ..Default::default()
         };
 
-        commands.entity(entity).insert(ActiveSettingsScreen {
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.insert(ActiveSettingsScreen {
             config: config.clone(),
             navigation_state: nav_state,
+            #[cfg(feature = "settings")]
+            pin_gate: PinGate {
+                unlocked: !parental_controls
+                    .as_deref()
+                    .is_some_and(ParentalControls::requires_pin),
+                ..Default::default()
+            },
         });
+        insert_inactivity_timeout(&mut entity_commands, config.auto_dismiss);
+    }
+}
+
+/// Highest navigation index of a configuration, the settings in navigation
+/// order followed by the back button
+fn max_navigation_index(config: &SettingsScreenConfig) -> usize {
+    (navigable_settings(config).len() + usize::from(config.allow_dismissal)).saturating_sub(1)
+}
+
+/// Settings of a configuration in navigation order, see
+/// [`SettingsNavigationState`]
+fn navigable_settings(config: &SettingsScreenConfig) -> Vec<&ScreenSettingsItem> {
+    let mut settings: Vec<&ScreenSettingsItem> = config
+        .sections
+        .iter()
+        .flat_map(|section| &section.settings)
+        .collect();
+    // Stable, so settings with the same or without index keep their order
+    settings.sort_by_key(|setting| (setting.navigation_index.is_none(), setting.navigation_index));
+    settings
+}
+
+/// Navigation index of a shown setting
+fn navigation_position(order: &[&ScreenSettingsItem], setting: &ScreenSettingsItem) -> usize {
+    order
+        .iter()
+        .position(|other| std::ptr::eq(*other, setting))
+        .unwrap_or_default()
+}
+
+/// System to turn the arrow keys, Enter and the gamepad d-pad and (A) button
+/// into [`SettingsScreenEvent::Navigate`] while a settings screen is open
+pub fn send_settings_navigation_input(
+    input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    wants_input: Option<Res<EguiWantsInput>>,
+    screens: Query<(), With<ActiveSettingsScreen>>,
+    mut settings_events: MessageWriter<SettingsScreenEvent>,
+) {
+    if screens.is_empty() {
+        return;
+    }
+
+    // Arrow keys and Enter belong to a text field being edited
+    let keyboard = !wants_input.is_some_and(|wants_input| wants_input.wants_any_keyboard_input());
+    let key_pressed = |keys: &[KeyCode]| keyboard && input.any_just_pressed(keys.iter().copied());
+    let gamepad_pressed = |button| gamepads.iter().any(|gamepad| gamepad.just_pressed(button));
+
+    let directions = [
+        (
+            NavigationDirection::Up,
+            key_pressed(&[KeyCode::ArrowUp]) || gamepad_pressed(GamepadButton::DPadUp),
+        ),
+        (
+            NavigationDirection::Down,
+            key_pressed(&[KeyCode::ArrowDown]) || gamepad_pressed(GamepadButton::DPadDown),
+        ),
+        (
+            NavigationDirection::Left,
+            key_pressed(&[KeyCode::ArrowLeft]) || gamepad_pressed(GamepadButton::DPadLeft),
+        ),
+        (
+            NavigationDirection::Right,
+            key_pressed(&[KeyCode::ArrowRight]) || gamepad_pressed(GamepadButton::DPadRight),
+        ),
+        (
+            NavigationDirection::Select,
+            key_pressed(&[KeyCode::Enter, KeyCode::NumpadEnter])
+                || gamepad_pressed(GamepadButton::South),
+        ),
+    ];
+    for (direction, pressed) in directions {
+        if pressed {
+            settings_events.write(SettingsScreenEvent::Navigate { direction });
+        }
+    }
+}
+
+/// System to move the focus of settings screens and change the focused
+/// setting on [`SettingsScreenEvent::Navigate`].
+///
+/// Up/Down move the focus, Left/Right adjust sliders and selections, Select
+/// toggles, cycles selections and activates buttons.
+pub fn apply_settings_navigation(
+    mut settings_events: ParamSet<(
+        MessageReader<SettingsScreenEvent>,
+        MessageWriter<SettingsScreenEvent>,
+    )>,
+    mut query: Query<(Entity, &mut ActiveSettingsScreen)>,
+    #[cfg(feature = "settings")] parental_controls: Option<Res<ParentalControls>>,
+) {
+    let directions: Vec<NavigationDirection> = settings_events
+        .p0()
+        .read()
+        .filter_map(|event| match event {
+            SettingsScreenEvent::Navigate { direction } => Some(direction.clone()),
+            _ => None,
+        })
+        .collect();
+    if directions.is_empty() {
+        return;
+    }
+
+    let mut results = vec![];
+    for (entity, mut settings) in query.iter_mut() {
+        let ActiveSettingsScreen {
+            config,
+            navigation_state,
+            #[cfg(feature = "settings")]
+            pin_gate,
+        } = &mut *settings;
+        if !navigation_state.enabled {
+            continue;
+        }
+        #[cfg(feature = "settings")]
+        if pin_gate.update(parental_controls.as_deref()) {
+            continue;
+        }
+
+        for direction in &directions {
+            match direction {
+                NavigationDirection::Up => navigation_state.move_focus(-1),
+                NavigationDirection::Down => navigation_state.move_focus(1),
+                NavigationDirection::Left
+                | NavigationDirection::Right
+                | NavigationDirection::Select => {
+                    match navigable_settings(config).get(navigation_state.current_index) {
+                        Some(setting) => {
+                            if let Some(value) = navigated_value(setting, direction) {
+                                results.push(SettingsScreenEvent::ValueChanged {
+                                    entity,
+                                    setting_id: setting.id.clone(),
+                                    value,
+                                });
+                            }
+                        }
+                        None => {
+                            if config.allow_dismissal
+                                && matches!(direction, NavigationDirection::Select)
+                            {
+                                results.push(SettingsScreenEvent::Dismissed { entity });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut writer = settings_events.p1();
+    for result in results {
+        writer.write(result);
+    }
+}
+
+/// Value of a setting after a Left, Right or Select navigation, `None` if the
+/// setting does not change
+#[cfg(feature = "settings")]
+fn navigated_value(
+    setting: &ScreenSettingsItem,
+    direction: &NavigationDirection,
+) -> Option<SettingValue> {
+    let step = match direction {
+        NavigationDirection::Left => -1,
+        NavigationDirection::Right => 1,
+        NavigationDirection::Select => 0,
+        NavigationDirection::Up | NavigationDirection::Down => return None,
+    };
+
+    match &setting.setting_type {
+        SettingType::Toggle => {
+            let value = setting.current_value.as_bool()?;
+            (step == 0).then_some(SettingValue::Bool(!value))
+        }
+        SettingType::FloatRange {
+            min,
+            max,
+            step: size,
+        } => {
+            let value = setting.current_value.as_float()?;
+            let new_value = (value + step as f32 * size).max(*min).min(*max);
+            (new_value != value).then_some(SettingValue::Float(new_value))
+        }
+        SettingType::IntRange {
+            min,
+            max,
+            step: size,
+        } => {
+            let value = setting.current_value.as_int()?;
+            let new_value = (value + step * size).max(*min).min(*max);
+            (new_value != value).then_some(SettingValue::Int(new_value))
+        }
+        SettingType::Selection { options } => {
+            let index = setting.current_value.as_selection()?;
+            if options.len() < 2 {
+                return None;
+            }
+            // Select cycles through the options like Right
+            let step = if step == 0 { 1 } else { step };
+            let new_index = (index as i32 + step).rem_euclid(options.len() as i32) as usize;
+            Some(SettingValue::Selection(new_index))
+        }
+        SettingType::Custom { .. } => {
+            (step == 0 && setting.id == "configure_players").then(|| setting.current_value.clone())
+        }
+        SettingType::Text { .. } => None,
+    }
+}
+
+/// Value of a setting after a Left, Right or Select navigation, `None` if the
+/// setting does not change
+#[cfg(not(feature = "settings"))]
+fn navigated_value(
+    setting: &ScreenSettingsItem,
+    direction: &NavigationDirection,
+) -> Option<ScreenSettingValue> {
+    if !matches!(direction, NavigationDirection::Select) {
+        return None;
+    }
+
+    match (&setting.setting_type, &setting.current_value) {
+        (ScreenOnlySettingType::Toggle, ScreenSettingValue::Bool(value)) => {
+            Some(ScreenSettingValue::Bool(!value))
+        }
+        (ScreenOnlySettingType::Custom { .. }, value) if setting.id == "configure_players" => {
+            Some(value.clone())
+        }
+        _ => None,
+    }
+}
+
+/// System to keep live settings screens in sync with the `Setting` entities
+#[cfg(feature = "settings")]
+pub fn sync_live_settings_screens(
+    mut active_settings_query: Query<&mut ActiveSettingsScreen>,
+    settings_query: Query<(Entity, &Setting)>,
+    changed_settings: Query<(), Changed<Setting>>,
+    mut removed_settings: RemovedComponents<Setting>,
+) {
+    let settings_changed = removed_settings.read().count() > 0 || !changed_settings.is_empty();
+
+    for mut active_settings in active_settings_query.iter_mut() {
+        if !active_settings.config.live_components
+            || !(settings_changed || active_settings.is_added())
+        {
+            continue;
+        }
+
+        // Spawn order decides where new settings of the same frame appear
+        let mut settings: Vec<(Entity, &Setting)> = settings_query.iter().collect();
+        settings.sort_by_key(|(entity, _)| *entity);
+        let settings: Vec<&Setting> = settings.into_iter().map(|(_, setting)| setting).collect();
+
+        let active_settings = active_settings.as_mut();
+        active_settings.config.merge_component_settings(&settings);
+        let max_index = max_navigation_index(&active_settings.config);
+        let navigation_state = &mut active_settings.navigation_state;
+        navigation_state.max_index = max_index;
+        navigation_state.current_index = navigation_state.current_index.min(max_index);
+    }
+}
+
+/// System to write value changes of live settings screens back to the
+/// `Setting` entities
+#[cfg(feature = "settings")]
+pub fn route_live_setting_values(
+    mut commands: Commands,
+    mut settings_events: MessageReader<SettingsScreenEvent>,
+    active_settings_query: Query<&ActiveSettingsScreen>,
+    settings_index: Option<Res<SettingsIndex>>,
+) {
+    let Some(settings_index) = settings_index else {
+        return;
+    };
+
+    for event in settings_events.read() {
+        let SettingsScreenEvent::ValueChanged {
+            entity,
+            setting_id,
+            value,
+        } = event
+        else {
+            continue;
+        };
+
+        let is_live = active_settings_query
+            .get(*entity)
+            .is_ok_and(|active_settings| active_settings.config.live_components);
+        if !is_live {
+            continue;
+        }
+
+        if let Some(setting_entity) = settings_index.get(setting_id) {
+            commands
+                .entity(setting_entity)
+                .insert(PendingSettingUpdate::new(value.clone()));
+        }
+    }
+}
+
+/// System to undo setting edits with Ctrl+Z and redo them with Ctrl+Y or
+/// Ctrl+Shift+Z while a settings screen is open and not locked by the
+/// parental PIN
+#[cfg(feature = "settings")]
+#[allow(clippy::type_complexity)]
+pub fn handle_settings_undo_keys(
+    input: Res<ButtonInput<KeyCode>>,
+    history: Option<ResMut<EditHistory>>,
+    screens: Query<(), Or<(With<ActiveSettingsScreen>, With<ActiveComponentSettings>)>>,
+    settings_screens: Query<&ActiveSettingsScreen>,
+    parental_controls: Option<Res<ParentalControls>>,
+) {
+    let Some(mut history) = history else {
+        return;
+    };
+    if screens.is_empty()
+        || settings_screens
+            .iter()
+            .any(|screen| screen.pin_gate.locked(parental_controls.as_deref()))
+        || !input.any_pressed([
+            KeyCode::ControlLeft,
+            KeyCode::ControlRight,
+            KeyCode::SuperLeft,
+            KeyCode::SuperRight,
+        ])
+    {
+        return;
+    }
+
+    let shift = input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    if input.just_pressed(KeyCode::KeyY) || (shift && input.just_pressed(KeyCode::KeyZ)) {
+        history.redo();
+    } else if input.just_pressed(KeyCode::KeyZ) {
+        history.undo();
     }
 }
 
@@ -94,6 +493,7 @@ pub fn check_settings_screen_config(
 pub fn update_settings_screen_values(
     mut settings_events: MessageReader<SettingsScreenEvent>,
     mut active_settings_query: Query<&mut ActiveSettingsScreen>,
+    mut announcer: Option<ResMut<Announcer>>,
 ) {
     for event in settings_events.read() {
         if let SettingsScreenEvent::ValueChanged {
@@ -121,6 +521,19 @@ pub fn update_settings_screen_values(
                                 updated = true;
                                 info!("Updated active setting '{}' to {:?}", setting_id, value);
                             }
+                            // Repeated changes of a setting are read once, the
+                            // PIN never
+                            #[cfg(feature = "settings")]
+                            let announce = setting.id != PARENTAL_PIN_SETTING_ID;
+                            #[cfg(not(feature = "settings"))]
+                            let announce = true;
+                            if let Some(announcer) = announcer.as_mut().filter(|_| announce) {
+                                announcer.announce_keyed(
+                                    setting_id.clone(),
+                                    format!("{} saved", setting.label),
+                                    Politeness::Polite,
+                                );
+                            }
                             break;
                         }
                     }
@@ -133,23 +546,147 @@ pub fn update_settings_screen_values(
     }
 }
 
+/// System to forward display toggles of the settings screen to `DisplaySettingsPlugin`
+#[cfg(feature = "settings")]
+pub fn forward_display_screen_values(
+    mut settings_events: MessageReader<SettingsScreenEvent>,
+    display_state: Option<ResMut<DisplayState>>,
+) {
+    let Some(mut display_state) = display_state else {
+        return;
+    };
+
+    for event in settings_events.read() {
+        if let SettingsScreenEvent::ValueChanged {
+            setting_id, value, ..
+        } = event
+        {
+            let Some(value) = value.as_bool() else {
+                continue;
+            };
+
+            if setting_id == FULLSCREEN_SETTING_ID && display_state.fullscreen != value {
+                display_state.fullscreen = value;
+            } else if setting_id == POINTER_LOCK_SETTING_ID && display_state.pointer_lock != value {
+                display_state.pointer_lock = value;
+            }
+        }
+    }
+}
+
+/// System to apply appearance options of the settings screen to the
+/// [`ColorAssistance`] and [`AccessibilityOptions`]
+#[cfg(feature = "settings")]
+pub fn forward_appearance_screen_values(
+    mut settings_events: MessageReader<SettingsScreenEvent>,
+    assistance: Option<ResMut<ColorAssistance>>,
+    options: Option<ResMut<AccessibilityOptions>>,
+) {
+    let (Some(mut assistance), Some(mut options)) = (assistance, options) else {
+        return;
+    };
+
+    for event in settings_events.read() {
+        let SettingsScreenEvent::ValueChanged {
+            setting_id, value, ..
+        } = event
+        else {
+            continue;
+        };
+
+        if setting_id == COLOR_VISION_SETTING_ID {
+            if let Some(index) = value.as_selection() {
+                let mode = ColorVisionMode::from_index(index);
+                if assistance.mode != mode {
+                    assistance.mode = mode;
+                }
+            }
+        } else if setting_id == SHAPE_CUES_SETTING_ID {
+            if let Some(value) = value.as_bool() {
+                if assistance.shape_cues != value {
+                    assistance.shape_cues = value;
+                }
+            }
+        } else if setting_id == HIGH_VISIBILITY_SETTING_ID {
+            if let Some(value) = value.as_bool() {
+                if options.high_visibility != value {
+                    options.high_visibility = value;
+                }
+            }
+        } else if setting_id == DYSLEXIA_FRIENDLY_SETTING_ID {
+            if let Some(value) = value.as_bool() {
+                if options.dyslexia_friendly != value {
+                    options.dyslexia_friendly = value;
+                }
+            }
+        }
+    }
+}
+
+/// System to apply parental controls options of the settings screen to the
+/// [`ParentalControls`]
+#[cfg(feature = "settings")]
+pub fn forward_parental_screen_values(
+    mut settings_events: MessageReader<SettingsScreenEvent>,
+    controls: Option<ResMut<ParentalControls>>,
+) {
+    let Some(mut controls) = controls else {
+        return;
+    };
+
+    for event in settings_events.read() {
+        if let SettingsScreenEvent::ValueChanged {
+            setting_id, value, ..
+        } = event
+        {
+            apply_parental_setting(&mut controls, setting_id, value);
+        }
+    }
+}
+
+/// System to update the display toggles when the platform left fullscreen on its own
+#[cfg(feature = "settings")]
+pub fn sync_display_screen_values(
+    mut display_events: MessageReader<DisplayModeChanged>,
+    active_settings_query: Query<Entity, With<ActiveSettingsScreen>>,
+    mut settings_events: MessageWriter<SettingsScreenEvent>,
+) {
+    for event in display_events.read() {
+        for entity in active_settings_query.iter() {
+            settings_events.write(SettingsScreenEvent::ValueChanged {
+                entity,
+                setting_id: FULLSCREEN_SETTING_ID.to_string(),
+                value: SettingValue::Bool(event.fullscreen),
+            });
+            settings_events.write(SettingsScreenEvent::ValueChanged {
+                entity,
+                setting_id: POINTER_LOCK_SETTING_ID.to_string(),
+                value: SettingValue::Bool(event.pointer_lock),
+            });
+        }
+    }
+}
+
 // At the top, change the egui context handling:
 pub fn render_settings_screen_ui(
-    mut contexts: EguiContexts,
+    mut contexts: ScreenContexts,
     theme: Res<KonnektorenTheme>,
     responsive: Res<ResponsiveInfo>,
     mut query: Query<(Entity, &mut ActiveSettingsScreen)>,
     mut settings_events: MessageWriter<SettingsScreenEvent>,
     input: Res<ButtonInput<KeyCode>>,
+    #[cfg(feature = "settings")] parental_controls: Option<Res<ParentalControls>>,
 ) {
     if query.is_empty() {
         return;
     }
 
-    // Get the egui context directly
-    if let Ok(ctx) = contexts.ctx_mut() {
-        // Only render the first (most recent) settings screen
-        if let Some((entity, mut settings)) = query.iter_mut().next() {
+    // Only render the first (most recent) settings screen of this pass
+    if let Some((entity, mut settings)) = query
+        .iter_mut()
+        .find(|(entity, _)| contexts.renders(*entity))
+    {
+        if let Some(ctx) = contexts.ctx_for(entity) {
             // Check for escape key dismissal
             let should_dismiss =
                 settings.config.allow_dismissal && input.just_pressed(KeyCode::Escape);
@@ -162,25 +699,172 @@ pub fn render_settings_screen_ui(
             let ActiveSettingsScreen {
                 config,
                 navigation_state,
+                #[cfg(feature = "settings")]
+                pin_gate,
             } = &mut *settings;
 
+            // The PIN has to be entered before the settings are shown
+            #[cfg(feature = "settings")]
+            if let Some(controls) = parental_controls
+                .as_deref()
+                .filter(|controls| pin_gate.update(Some(controls)))
+            {
+                egui::CentralPanel::default()
+                    .frame(egui::Frame::NONE.fill(theme.base_100))
+                    .show(ctx, |ui| {
+                        scoped_ui(ui, (entity, "settings_pin_gate"), |ui| {
+                            render_pin_gate(
+                                ui,
+                                config,
+                                pin_gate,
+                                controls,
+                                &theme,
+                                &responsive,
+                                entity,
+                                &mut settings_events,
+                            );
+                        })
+                    });
+                return;
+            }
+
             egui::CentralPanel::default()
                 .frame(egui::Frame::NONE.fill(theme.base_100))
                 .show(ctx, |ui| {
-                    render_settings_content(
-                        ui,
-                        config,
-                        navigation_state,
-                        &theme,
-                        &responsive,
-                        entity,
-                        &mut settings_events,
-                    );
+                    scoped_ui(ui, (entity, "settings"), |ui| {
+                        render_settings_content(
+                            ui,
+                            config,
+                            navigation_state,
+                            &theme,
+                            &responsive,
+                            entity,
+                            &mut settings_events,
+                        );
+                    })
                 });
+            navigation_state.focus_moved = false;
         }
     }
 }
 
+/// Render the PIN prompt shown instead of the settings under parental controls
+#[cfg(feature = "settings")]
+#[allow(clippy::too_many_arguments)]
+fn render_pin_gate(
+    ui: &mut egui::Ui,
+    config: &SettingsScreenConfig,
+    gate: &mut PinGate,
+    controls: &ParentalControls,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    settings_events: &mut MessageWriter<SettingsScreenEvent>,
+) {
+    ui.vertical_centered(|ui| {
+        ui.set_max_width(320.0_f32.min(ui.available_width() * 0.9));
+        ui.add_space(responsive.spacing(ResponsiveSpacing::XLarge));
+
+        ResponsiveText::new(&config.title, ResponsiveFontSize::Header, theme.primary)
+            .responsive(responsive)
+            .strong()
+            .ui(ui);
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+        ResponsiveText::new(
+            "Ask a parent or teacher to enter the PIN",
+            ResponsiveFontSize::Medium,
+            theme.base_content,
+        )
+        .responsive(responsive)
+        .ui(ui);
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+        let pin_edit = ui.add(
+            egui::TextEdit::singleline(&mut gate.pin)
+                .password(true)
+                .char_limit(PARENTAL_PIN_MAX_LENGTH)
+                .hint_text("PIN")
+                .desired_width(160.0),
+        );
+        if !pin_edit.has_focus() && !pin_edit.lost_focus() {
+            pin_edit.request_focus();
+        }
+        if pin_edit.changed() {
+            gate.failed = false;
+        }
+        let submitted = pin_edit.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+
+        if gate.failed {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+            ui.label(egui::RichText::new("Wrong PIN, try again").color(theme.error));
+        }
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+        let unlock = ThemedButton::new("Unlock", theme).responsive(responsive);
+        if ui.add(unlock).clicked() || submitted {
+            if controls.verify_pin(&gate.pin) {
+                gate.unlocked = true;
+            } else {
+                gate.failed = true;
+            }
+            gate.pin.clear();
+        }
+
+        if config.allow_dismissal {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+            let back_button = ThemedButton::new(&config.back_button_text, theme)
+                .responsive(responsive)
+                .with_style(|button| {
+                    button
+                        .fill(theme.base_200)
+                        .stroke(egui::Stroke::new(1.0, theme.base_300))
+                });
+            if ui.add(back_button).clicked() {
+                settings_events.write(SettingsScreenEvent::Dismissed { entity });
+            }
+        }
+    });
+}
+
+/// Text field of a text setting, returns the text to apply.
+///
+/// Other texts apply on every change, the parental PIN only when confirmed
+/// with Enter or when the field loses focus. Until then the draft is kept in
+/// the egui memory, a half typed PIN would otherwise lock the screen.
+#[cfg(feature = "settings")]
+pub(super) fn text_setting_edit(
+    ui: &mut egui::Ui,
+    setting_id: &str,
+    current_text: &str,
+    max_length: Option<usize>,
+) -> Option<String> {
+    let is_pin = setting_id == PARENTAL_PIN_SETTING_ID;
+    let draft_id = ui.id().with((setting_id, "draft"));
+    let mut text = is_pin
+        .then(|| ui.data(|data| data.get_temp::<String>(draft_id)))
+        .flatten()
+        .unwrap_or_else(|| current_text.to_string());
+
+    let mut text_edit = egui::TextEdit::singleline(&mut text).password(is_pin);
+    if let Some(max_len) = max_length {
+        text_edit = text_edit.char_limit(max_len);
+    }
+    let response = ui.add(text_edit);
+
+    if !is_pin {
+        return response.changed().then_some(text);
+    }
+    if response.lost_focus() {
+        ui.data_mut(|data| data.remove::<String>(draft_id));
+        (text != current_text).then_some(text)
+    } else {
+        if response.changed() {
+            ui.data_mut(|data| data.insert_temp(draft_id, text));
+        }
+        None
+    }
+}
+
 /// Render main settings content
 fn render_settings_content(
     ui: &mut egui::Ui,
@@ -211,7 +895,7 @@ fn render_settings_content(
 
         // Main content scrollable area
         let scroll_height = ui.available_height() - 80.0;
-        egui::ScrollArea::vertical()
+        let content = egui::ScrollArea::vertical()
             .max_height(scroll_height)
             .auto_shrink([false; 2])
             .show(ui, |ui| {
@@ -237,15 +921,25 @@ fn render_settings_content(
                     );
                 }
             });
+        register_coach_mark(ui.ctx(), "settings.content", content.inner_rect);
 
         // Back button
         if config.allow_dismissal {
             ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
-            let back_button = ThemedButton::new(&config.back_button_text, theme)
+            let is_focused = nav_state.is_focused(nav_state.max_index);
+            let mut back_button = ThemedButton::new(&config.back_button_text, theme)
                 .responsive(responsive)
                 .width(if responsive.is_mobile() { 200.0 } else { 150.0 });
+            if is_focused {
+                back_button = back_button
+                    .with_style(|button| button.stroke(egui::Stroke::new(3.0, theme.accent)));
+            }
 
-            if ui.add(back_button).clicked() {
+            let response = ui.add(back_button).coach_mark("settings.back");
+            if is_focused && nav_state.focus_moved {
+                response.scroll_to_me(None);
+            }
+            if response.clicked() {
                 settings_events.write(SettingsScreenEvent::Dismissed { entity });
             }
         }
@@ -259,12 +953,13 @@ fn render_settings_content(
 fn render_mobile_settings_layout(
     ui: &mut egui::Ui,
     config: &SettingsScreenConfig,
-    _nav_state: &SettingsNavigationState,
+    nav_state: &SettingsNavigationState,
     theme: &KonnektorenTheme,
     responsive: &ResponsiveInfo,
     entity: Entity,
     settings_events: &mut MessageWriter<SettingsScreenEvent>,
 ) {
+    let navigation_order = navigable_settings(config);
     let section_spacing = responsive.spacing(ResponsiveSpacing::Large);
 
     for section in &config.sections {
@@ -278,7 +973,10 @@ fn render_mobile_settings_layout(
 
         // Section settings
         for setting in &section.settings {
-            render_mobile_setting_item(ui, setting, theme, responsive, entity, settings_events);
+            let nav_index = navigation_position(&navigation_order, setting);
+            render_focus_frame(ui, nav_state, nav_index, theme, |ui| {
+                render_mobile_setting_item(ui, setting, theme, responsive, entity, settings_events);
+            });
             ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
         }
 
@@ -293,12 +991,13 @@ fn render_mobile_settings_layout(
 fn render_desktop_settings_layout(
     ui: &mut egui::Ui,
     config: &SettingsScreenConfig,
-    _nav_state: &SettingsNavigationState,
+    nav_state: &SettingsNavigationState,
     theme: &KonnektorenTheme,
     responsive: &ResponsiveInfo,
     entity: Entity,
     settings_events: &mut MessageWriter<SettingsScreenEvent>,
 ) {
+    let navigation_order = navigable_settings(config);
     for section in &config.sections {
         // Section header
         ResponsiveText::new(&section.title, ResponsiveFontSize::Large, theme.secondary)
@@ -324,14 +1023,17 @@ fn render_desktop_settings_layout(
                     .ui(ui);
 
                     // Control column
-                    render_desktop_setting_control(
-                        ui,
-                        setting,
-                        theme,
-                        responsive,
-                        entity,
-                        settings_events,
-                    );
+                    let nav_index = navigation_position(&navigation_order, setting);
+                    render_focus_frame(ui, nav_state, nav_index, theme, |ui| {
+                        render_desktop_setting_control(
+                            ui,
+                            setting,
+                            theme,
+                            responsive,
+                            entity,
+                            settings_events,
+                        );
+                    });
                     ui.end_row();
                 }
             });
@@ -340,6 +1042,34 @@ fn render_desktop_settings_layout(
     }
 }
 
+/// Render a setting inside a frame that is outlined while the setting has the
+/// navigation focus
+fn render_focus_frame(
+    ui: &mut egui::Ui,
+    nav_state: &SettingsNavigationState,
+    nav_index: usize,
+    theme: &KonnektorenTheme,
+    add_contents: impl FnOnce(&mut egui::Ui),
+) {
+    let is_focused = nav_state.is_focused(nav_index);
+    let response = egui::Frame::NONE
+        .inner_margin(egui::Margin::same(4))
+        .corner_radius(egui::CornerRadius::same(6))
+        .stroke(egui::Stroke::new(
+            2.0,
+            if is_focused {
+                theme.accent
+            } else {
+                egui::Color32::TRANSPARENT
+            },
+        ))
+        .show(ui, add_contents)
+        .response;
+    if is_focused && nav_state.focus_moved {
+        response.scroll_to_me(None);
+    }
+}
+
 /// Render mobile setting item (vertical layout)
 fn render_mobile_setting_item(
     ui: &mut egui::Ui,
@@ -406,52 +1136,21 @@ fn render_setting_control(
 
             SettingType::FloatRange { min, max, step } => {
                 if let Some(current_value) = setting.current_value.as_float() {
-                    ui.horizontal(|ui| {
-                        let dec_button = ThemedButton::new("-", theme)
-                            .responsive(responsive)
-                            .width(30.0);
-
-                        if ui.add(dec_button).clicked() {
-                            let new_value = (current_value - step).max(*min);
-                            settings_events.write(SettingsScreenEvent::ValueChanged {
-                                entity,
-                                setting_id: setting.id.clone(),
-                                value: SettingValue::Float(new_value),
-                            });
-                        }
-
-                        ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
-
-                        // Display current value as percentage for volume controls
-                        let display_text = if setting.id.contains("volume") {
-                            format!("{:.0}%", current_value * 100.0)
-                        } else {
-                            format!("{:.1}", current_value)
-                        };
-
-                        ResponsiveText::new(
-                            &display_text,
-                            ResponsiveFontSize::Medium,
-                            theme.base_content,
-                        )
+                    let display_text = setting.display_format.format(current_value);
+                    let stepper = ThemedStepper::new(&display_text, theme)
                         .responsive(responsive)
-                        .ui(ui);
-
-                        ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
-
-                        let inc_button = ThemedButton::new("+", theme)
-                            .responsive(responsive)
-                            .width(30.0);
+                        .show(ui);
 
-                        if ui.add(inc_button).clicked() {
-                            let new_value = (current_value + step).min(*max);
-                            settings_events.write(SettingsScreenEvent::ValueChanged {
-                                entity,
-                                setting_id: setting.id.clone(),
-                                value: SettingValue::Float(new_value),
-                            });
-                        }
-                    });
+                    let new_value = (current_value + stepper.steps as f32 * step)
+                        .max(*min)
+                        .min(*max);
+                    if stepper.steps != 0 && new_value != current_value {
+                        settings_events.write(SettingsScreenEvent::ValueChanged {
+                            entity,
+                            setting_id: setting.id.clone(),
+                            value: SettingValue::Float(new_value),
+                        });
+                    }
                 }
             }
 
@@ -532,59 +1231,26 @@ fn render_setting_control(
 
             SettingType::IntRange { min, max, step } => {
                 if let Some(current_value) = setting.current_value.as_int() {
-                    ui.horizontal(|ui| {
-                        let dec_button = ThemedButton::new("-", theme)
-                            .responsive(responsive)
-                            .width(30.0);
-
-                        if ui.add(dec_button).clicked() {
-                            let new_value = (current_value - step).max(*min);
-                            settings_events.write(SettingsScreenEvent::ValueChanged {
-                                entity,
-                                setting_id: setting.id.clone(),
-                                value: SettingValue::Int(new_value),
-                            });
-                        }
-
-                        ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
-
-                        ResponsiveText::new(
-                            &current_value.to_string(),
-                            ResponsiveFontSize::Medium,
-                            theme.base_content,
-                        )
+                    let stepper = ThemedStepper::new(&current_value.to_string(), theme)
                         .responsive(responsive)
-                        .ui(ui);
+                        .show(ui);
 
-                        ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
-
-                        let inc_button = ThemedButton::new("+", theme)
-                            .responsive(responsive)
-                            .width(30.0);
-
-                        if ui.add(inc_button).clicked() {
-                            let new_value = (current_value + step).min(*max);
-                            settings_events.write(SettingsScreenEvent::ValueChanged {
-                                entity,
-                                setting_id: setting.id.clone(),
-                                value: SettingValue::Int(new_value),
-                            });
-                        }
-                    });
+                    let new_value = (current_value + stepper.steps * step).max(*min).min(*max);
+                    if stepper.steps != 0 && new_value != current_value {
+                        settings_events.write(SettingsScreenEvent::ValueChanged {
+                            entity,
+                            setting_id: setting.id.clone(),
+                            value: SettingValue::Int(new_value),
+                        });
+                    }
                 }
             }
 
             SettingType::Text { max_length } => {
                 if let Some(current_text) = setting.current_value.as_string() {
-                    let mut text = current_text.to_string();
-                    let text_edit = egui::TextEdit::singleline(&mut text);
-
-                    if ui.add(text_edit).changed() {
-                        // Apply max_length if specified
-                        if let Some(max_len) = max_length {
-                            text.truncate(*max_len);
-                        }
-
+                    if let Some(text) =
+                        text_setting_edit(ui, &setting.id, current_text, *max_length)
+                    {
                         settings_events.write(SettingsScreenEvent::ValueChanged {
                             entity,
                             setting_id: setting.id.clone(),
@@ -0,0 +1,560 @@
+#[cfg(feature = "input")]
+use super::ActiveInputConfiguration;
+#[cfg(feature = "settings")]
+use super::PendingSettingUpdate;
+use super::{
+    forward_screen_events, track_active_screen, AnyScreenActive, ScreenAction, ScreenKind,
+    ScreenMessage,
+};
+#[cfg(feature = "assets")]
+use super::{AvatarBuilderConfig, AvatarScreenExt};
+#[cfg(feature = "settings")]
+use crate::settings::{SettingValue, SettingsIndex, MASTER_VOLUME_SETTING_ID};
+#[cfg(feature = "storage")]
+use crate::storage::Storage;
+use crate::{
+    profile::{PlayerProfile, ProfilePlugin},
+    theme::KonnektorenTheme,
+    ui::{
+        responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
+        widgets::{ResponsiveText, ThemedButton},
+        wizard::{WizardFlow, WizardResponse},
+    },
+};
+use bevy::prelude::*;
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+use std::cell::RefCell;
+
+/// Storage key remembering that the setup was completed
+pub const SETUP_COMPLETED_STORAGE_KEY: &str = "setup_completed";
+
+/// Plugin for the first-run setup wizard and the [`FirstRun`] resource
+pub struct SetupWizardPlugin;
+
+impl Plugin for SetupWizardPlugin {
+    fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveSetupWizard>(app);
+        forward_screen_events::<SetupWizardEvent>(app);
+
+        // The name is stored in the player profile
+        if !app.is_plugin_added::<ProfilePlugin>() {
+            app.add_plugins(ProfilePlugin);
+        }
+
+        app.init_resource::<FirstRun>()
+            .add_message::<SetupWizardEvent>()
+            .add_message::<SetupCompleted>()
+            .add_systems(
+                Update,
+                (check_setup_wizard_config, handle_setup_wizard_events).chain(),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_setup_wizard_ui.run_if(any_with_component::<ActiveSetupWizard>),
+            );
+    }
+
+    #[cfg(feature = "storage")]
+    fn finish(&self, app: &mut App) {
+        // Known before `Startup`, so startup systems can open the wizard
+        let Some(storage) = app.world().get_resource::<Storage>() else {
+            return;
+        };
+
+        let is_first_run = match storage.load::<bool>(SETUP_COMPLETED_STORAGE_KEY) {
+            Ok(completed) => !completed.unwrap_or(false),
+            Err(e) => {
+                warn!("Failed to read the setup state: {}", e);
+                true
+            }
+        };
+        app.insert_resource(FirstRun { is_first_run });
+    }
+}
+
+/// Resource telling whether the setup was never completed on this device.
+///
+/// Read from the [`Storage`] when the app starts, without storage every start
+/// is a first run.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FirstRun {
+    pub is_first_run: bool,
+}
+
+impl Default for FirstRun {
+    fn default() -> Self {
+        Self { is_first_run: true }
+    }
+}
+
+/// Run condition that is true until the setup was completed
+pub fn is_first_run(first_run: Option<Res<FirstRun>>) -> bool {
+    first_run.is_some_and(|first_run| first_run.is_first_run)
+}
+
+/// Configuration for the setup wizard
+#[derive(Component, Clone)]
+pub struct SetupWizardConfig {
+    pub title: String,
+    /// Languages to choose from, the language step is skipped if empty
+    pub languages: Vec<String>,
+    /// Asset path of the avatar part set (`*.avatar.yml`), no avatar button if `None`
+    #[cfg(feature = "assets")]
+    pub avatar_part_set: Option<String>,
+    /// Offer a test sound in the audio check, played by the game on
+    /// [`SetupWizardEvent::PlayTestSound`]
+    pub test_sound: bool,
+    /// Show the input configuration step with this many players, skipped if `None`
+    #[cfg(feature = "input")]
+    pub max_players: Option<u32>,
+    pub finish_button_text: String,
+}
+
+impl Default for SetupWizardConfig {
+    fn default() -> Self {
+        Self {
+            title: "Welcome!".to_string(),
+            languages: vec![],
+            #[cfg(feature = "assets")]
+            avatar_part_set: None,
+            test_sound: true,
+            #[cfg(feature = "input")]
+            max_players: Some(1),
+            finish_button_text: "Let's go!".to_string(),
+        }
+    }
+}
+
+impl SetupWizardConfig {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_languages(mut self, languages: Vec<String>) -> Self {
+        self.languages = languages;
+        self
+    }
+
+    #[cfg(feature = "assets")]
+    pub fn with_avatar(mut self, part_set_path: impl Into<String>) -> Self {
+        self.avatar_part_set = Some(part_set_path.into());
+        self
+    }
+
+    pub fn with_test_sound(mut self, test_sound: bool) -> Self {
+        self.test_sound = test_sound;
+        self
+    }
+
+    #[cfg(feature = "input")]
+    pub fn with_input_configuration(mut self, max_players: Option<u32>) -> Self {
+        self.max_players = max_players;
+        self
+    }
+
+    pub fn with_finish_button_text(mut self, text: impl Into<String>) -> Self {
+        self.finish_button_text = text.into();
+        self
+    }
+}
+
+/// Choices made in the wizard so far
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetupChoices {
+    pub language: Option<String>,
+    pub name: String,
+    /// Master volume, 0.0 to 1.0
+    pub volume: f32,
+}
+
+/// Component marking an active setup wizard
+#[derive(Component)]
+pub struct ActiveSetupWizard {
+    config: SetupWizardConfig,
+    step: usize,
+    choices: SetupChoices,
+}
+
+impl ActiveSetupWizard {
+    pub fn choices(&self) -> &SetupChoices {
+        &self.choices
+    }
+}
+
+/// Events for setup wizard interactions
+#[derive(Message, Debug, Clone)]
+pub enum SetupWizardEvent {
+    /// The avatar builder should be opened
+    #[cfg(feature = "assets")]
+    OpenAvatarBuilder { entity: Entity },
+    /// The volume of the audio check changed
+    VolumeChanged { entity: Entity, volume: f32 },
+    /// The game should play a test sound
+    PlayTestSound { entity: Entity },
+    /// The input configuration should be opened
+    #[cfg(feature = "input")]
+    OpenInputConfiguration { entity: Entity },
+    /// Finish was clicked on the last step
+    Finished { entity: Entity },
+}
+
+impl ScreenMessage for SetupWizardEvent {
+    const KIND: ScreenKind = ScreenKind::SetupWizard;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::VolumeChanged { entity, .. } => Some((ScreenAction::Changed, *entity)),
+            Self::Finished { entity } => Some((ScreenAction::Submitted, *entity)),
+            _ => None,
+        }
+    }
+}
+
+/// Message sent once the setup was completed
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct SetupCompleted {
+    pub choices: SetupChoices,
+}
+
+/// System to check for new setup wizard configurations and set them up
+#[allow(clippy::type_complexity)]
+fn check_setup_wizard_config(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &SetupWizardConfig),
+        (Without<ActiveSetupWizard>, Changed<SetupWizardConfig>),
+    >,
+    existing: Query<Entity, With<ActiveSetupWizard>>,
+) {
+    for (entity, config) in query.iter() {
+        info!("Setting up setup wizard for entity {:?}", entity);
+
+        // Clean up any existing wizards first
+        for existing_entity in existing.iter() {
+            commands
+                .entity(existing_entity)
+                .remove::<ActiveSetupWizard>();
+        }
+
+        commands.entity(entity).insert(ActiveSetupWizard {
+            config: config.clone(),
+            step: 0,
+            choices: SetupChoices {
+                language: config.languages.first().cloned(),
+                name: String::new(),
+                volume: 1.0,
+            },
+        });
+    }
+}
+
+/// System to open the screens of the wizard steps and complete the setup
+#[allow(clippy::too_many_arguments)]
+fn handle_setup_wizard_events(
+    mut commands: Commands,
+    mut events: MessageReader<SetupWizardEvent>,
+    mut completed: MessageWriter<SetupCompleted>,
+    wizards: Query<&ActiveSetupWizard>,
+    mut profile: ResMut<PlayerProfile>,
+    mut first_run: ResMut<FirstRun>,
+    #[cfg(feature = "settings")] settings_index: Option<Res<SettingsIndex>>,
+    #[cfg(feature = "storage")] storage: Option<Res<Storage>>,
+) {
+    for event in events.read() {
+        match event {
+            #[cfg(feature = "assets")]
+            SetupWizardEvent::OpenAvatarBuilder { entity } => {
+                let part_set = wizards
+                    .get(*entity)
+                    .ok()
+                    .and_then(|wizard| wizard.config.avatar_part_set.clone());
+                if let Some(part_set) = part_set {
+                    commands.spawn_avatar_builder(AvatarBuilderConfig::new(part_set));
+                }
+            }
+            #[cfg(feature = "input")]
+            SetupWizardEvent::OpenInputConfiguration { entity } => {
+                let max_players = wizards
+                    .get(*entity)
+                    .ok()
+                    .and_then(|wizard| wizard.config.max_players);
+                if let Some(max_players) = max_players {
+                    commands.spawn((
+                        Name::new("Input Configuration Screen"),
+                        ActiveInputConfiguration {
+                            max_players,
+                            current_players: max_players,
+                        },
+                    ));
+                }
+            }
+            #[cfg(feature = "settings")]
+            SetupWizardEvent::VolumeChanged { volume, .. } => {
+                let setting = settings_index
+                    .as_ref()
+                    .and_then(|index| index.get(MASTER_VOLUME_SETTING_ID));
+                if let Some(setting) = setting {
+                    commands
+                        .entity(setting)
+                        .insert(PendingSettingUpdate::new(SettingValue::Float(*volume)));
+                }
+            }
+            #[cfg(not(feature = "settings"))]
+            SetupWizardEvent::VolumeChanged { .. } => {}
+            SetupWizardEvent::PlayTestSound { .. } => {}
+            SetupWizardEvent::Finished { entity } => {
+                let Ok(wizard) = wizards.get(*entity) else {
+                    continue;
+                };
+                info!("Setup completed by '{}'", wizard.choices.name);
+
+                profile.name = wizard.choices.name.trim().to_string();
+                first_run.is_first_run = false;
+                #[cfg(feature = "storage")]
+                if let Some(storage) = storage.as_ref() {
+                    if let Err(e) = storage.save(SETUP_COMPLETED_STORAGE_KEY, &true) {
+                        warn!("Failed to save the setup state: {}", e);
+                    }
+                }
+
+                completed.write(SetupCompleted {
+                    choices: wizard.choices.clone(),
+                });
+                commands.entity(*entity).remove::<ActiveSetupWizard>();
+            }
+        }
+    }
+}
+
+/// System to render the setup wizard UI
+fn render_setup_wizard_ui(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    screens: Option<Res<AnyScreenActive>>,
+    mut query: Query<(Entity, &mut ActiveSetupWizard)>,
+    mut events: MessageWriter<SetupWizardEvent>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    // Only render the first (most recent) wizard to avoid widget ID conflicts
+    let Some((entity, mut wizard)) = query.iter_mut().next() else {
+        return;
+    };
+
+    // The screens opened from a step are shown on top of the wizard
+    if screens.is_some_and(|screens| screens.top() != Some(entity)) {
+        return;
+    }
+
+    let ActiveSetupWizard {
+        config,
+        step,
+        choices,
+    } = &mut *wizard;
+    let previous_volume = choices.volume;
+
+    egui::CentralPanel::default()
+        .frame(egui::Frame::NONE.fill(theme.base_100))
+        .show(ctx, |ui| {
+            scoped_ui(ui, (entity, "setup_wizard"), |ui| {
+                let max_width = if responsive.is_mobile() {
+                    ui.available_width() * 0.95
+                } else {
+                    600.0_f32.min(ui.available_width() * 0.9)
+                };
+
+                ui.vertical_centered(|ui| {
+                    ui.set_max_width(max_width);
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::XLarge));
+                    ResponsiveText::new(&config.title, ResponsiveFontSize::Title, theme.primary)
+                        .responsive(&responsive)
+                        .strong()
+                        .ui(ui);
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+                    let response = setup_wizard_flow(
+                        ui,
+                        config,
+                        step,
+                        choices,
+                        &theme,
+                        &responsive,
+                        entity,
+                        &mut events,
+                    );
+                    if response.finished {
+                        events.write(SetupWizardEvent::Finished { entity });
+                    }
+                });
+            })
+        });
+
+    if choices.volume != previous_volume {
+        events.write(SetupWizardEvent::VolumeChanged {
+            entity,
+            volume: choices.volume,
+        });
+    }
+}
+
+/// Show the steps of the wizard, skipping the ones the configuration leaves out
+#[allow(clippy::too_many_arguments)]
+fn setup_wizard_flow(
+    ui: &mut egui::Ui,
+    config: &SetupWizardConfig,
+    step: &mut usize,
+    choices: &mut SetupChoices,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    events: &mut MessageWriter<SetupWizardEvent>,
+) -> WizardResponse {
+    // Buttons of several steps request screens, collected for after the flow
+    let requested = RefCell::new(Vec::new());
+    let mut wizard = WizardFlow::new(step, theme)
+        .responsive(responsive)
+        .button_texts("← Back", "Next →", &config.finish_button_text);
+
+    if !config.languages.is_empty() {
+        wizard = wizard.step("Language", |ui, choices: &mut SetupChoices| {
+            ui.label("Which language do you speak?");
+            for language in &config.languages {
+                ui.radio_value(&mut choices.language, Some(language.clone()), language);
+            }
+            match choices.language {
+                Some(_) => Ok(()),
+                None => Err("Choose a language".to_string()),
+            }
+        });
+    }
+
+    wizard = wizard.step("Name", |ui, choices: &mut SetupChoices| {
+        ui.label("What is your name?");
+        ui.add(egui::TextEdit::singleline(&mut choices.name).hint_text("Name"));
+
+        #[cfg(feature = "assets")]
+        if config.avatar_part_set.is_some() {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+            if ThemedButton::new("Customize avatar", theme)
+                .responsive(responsive)
+                .ui(ui)
+                .clicked()
+            {
+                requested
+                    .borrow_mut()
+                    .push(SetupWizardEvent::OpenAvatarBuilder { entity });
+            }
+        }
+
+        if choices.name.trim().is_empty() {
+            Err("Enter your name".to_string())
+        } else {
+            Ok(())
+        }
+    });
+
+    wizard = wizard.step("Audio", |ui, choices: &mut SetupChoices| {
+        ui.label("Set the volume so you can hear the game well.");
+        ui.add(egui::Slider::new(&mut choices.volume, 0.0..=1.0).text("Volume"));
+        if config.test_sound {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+            if ThemedButton::new("Play test sound", theme)
+                .responsive(responsive)
+                .ui(ui)
+                .clicked()
+            {
+                requested
+                    .borrow_mut()
+                    .push(SetupWizardEvent::PlayTestSound { entity });
+            }
+        }
+        Ok(())
+    });
+
+    #[cfg(feature = "input")]
+    if config.max_players.is_some() {
+        wizard = wizard.step("Controls", |ui, _choices: &mut SetupChoices| {
+            ui.label("Choose a keyboard or controller for each player.");
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+            if ThemedButton::new("Configure controls", theme)
+                .responsive(responsive)
+                .ui(ui)
+                .clicked()
+            {
+                requested
+                    .borrow_mut()
+                    .push(SetupWizardEvent::OpenInputConfiguration { entity });
+            }
+            Ok(())
+        });
+    }
+
+    let response = wizard.show(ui, choices);
+    events.write_batch(requested.into_inner());
+    response
+}
+
+/// Helper trait for easy setup wizard setup
+pub trait SetupWizardExt {
+    /// Add a setup wizard with the given configuration
+    fn spawn_setup_wizard(&mut self, config: SetupWizardConfig) -> Entity;
+}
+
+impl SetupWizardExt for Commands<'_, '_> {
+    fn spawn_setup_wizard(&mut self, config: SetupWizardConfig) -> Entity {
+        self.spawn((Name::new("Setup Wizard Screen"), config)).id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+
+    #[test]
+    fn test_setup_wizard_completes() {
+        let mut harness =
+            TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin, SetupWizardPlugin));
+        harness.world_mut().spawn(
+            SetupWizardConfig::new("Welcome!")
+                .with_languages(vec!["English".to_string(), "Deutsch".to_string()]),
+        );
+        harness.run_frames(2);
+        assert!(harness.world().resource::<FirstRun>().is_first_run);
+
+        harness.click("Deutsch");
+        harness.click("Next →");
+        assert!(harness.has_widget("Enter your name"));
+
+        let mut wizards = harness.world_mut().query::<&mut ActiveSetupWizard>();
+        let world = harness.world_mut();
+        wizards.single_mut(world).unwrap().choices.name = "Ada".to_string();
+        harness.step();
+        harness.click("Next →");
+        harness.click("Play test sound");
+        assert!(harness
+            .read_messages::<SetupWizardEvent>()
+            .iter()
+            .any(|event| matches!(event, SetupWizardEvent::PlayTestSound { .. })));
+
+        harness.click("Next →");
+        assert!(harness.has_widget("Configure controls"));
+        harness.click("Let's go!");
+
+        let completed = harness.read_messages::<SetupCompleted>();
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].choices.language.as_deref(), Some("Deutsch"));
+        assert_eq!(harness.world().resource::<PlayerProfile>().name, "Ada");
+        assert!(!harness.world().resource::<FirstRun>().is_first_run);
+    }
+}
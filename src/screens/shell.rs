@@ -0,0 +1,241 @@
+use super::{SettingsScreenConfig, SettingsScreenEvent, SplashConfig, SplashDismissed};
+use bevy::{prelude::*, state::state::FreelyMutableState};
+use std::{marker::PhantomData, sync::Arc};
+
+/// Game state driven by the [`KonnektorenAppShell`]
+///
+/// ```ignore
+/// #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// enum GameState {
+///     #[default]
+///     Splash,
+///     Menu,
+///     Settings,
+///     Playing,
+/// }
+///
+/// impl AppShellState for GameState {
+///     const SPLASH: Self = GameState::Splash;
+///     const MAIN_MENU: Self = GameState::Menu;
+///     const SETTINGS: Option<Self> = Some(GameState::Settings);
+/// }
+/// ```
+pub trait AppShellState: FreelyMutableState + Default {
+    /// State showing the splash screen
+    const SPLASH: Self;
+    /// State showing the main menu, entered when the splash is dismissed
+    const MAIN_MENU: Self;
+    /// State showing the settings screen, left for the main menu on dismissal
+    const SETTINGS: Option<Self> = None;
+}
+
+type MenuSpawner = Arc<dyn Fn(&mut Commands) -> Entity + Send + Sync>;
+
+/// Plugin wiring the splash → main menu → settings flow of a game.
+///
+/// Screens are spawned when their state is entered and despawned when it is
+/// left. Dismissing the splash enters the main menu and dismissing the
+/// settings returns to it. The game itself moves to its own states from the
+/// main menu. Add it next to the [`ScreensPlugin`](super::ScreensPlugin).
+///
+/// ```ignore
+/// app.add_plugins(KonnektorenPlugins).add_plugins(
+///     KonnektorenAppShell::<GameState>::new()
+///         .with_splash(SplashConfig::konnektoren())
+///         .with_main_menu(MyMenu::default())
+///         .with_settings(SettingsScreenConfig::new("Settings")),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct KonnektorenAppShell<S: AppShellState> {
+    splash: Option<SplashConfig>,
+    main_menu: Option<MenuSpawner>,
+    settings: Option<SettingsScreenConfig>,
+    state: PhantomData<S>,
+}
+
+impl<S: AppShellState> Default for KonnektorenAppShell<S> {
+    fn default() -> Self {
+        Self {
+            splash: None,
+            main_menu: None,
+            settings: None,
+            state: PhantomData,
+        }
+    }
+}
+
+impl<S: AppShellState> KonnektorenAppShell<S> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Splash screen shown in [`AppShellState::SPLASH`]
+    pub fn with_splash(mut self, config: SplashConfig) -> Self {
+        self.splash = Some(config);
+        self
+    }
+
+    /// Menu bundle spawned in [`AppShellState::MAIN_MENU`]
+    pub fn with_main_menu(mut self, config: impl Bundle + Clone) -> Self {
+        self.main_menu = Some(Arc::new(move |commands: &mut Commands| {
+            commands
+                .spawn((Name::new("Main Menu"), config.clone()))
+                .id()
+        }));
+        self
+    }
+
+    /// Settings screen shown in [`AppShellState::SETTINGS`]
+    pub fn with_settings(mut self, config: SettingsScreenConfig) -> Self {
+        self.settings = Some(config);
+        self
+    }
+}
+
+impl<S: AppShellState> Plugin for KonnektorenAppShell<S> {
+    fn build(&self, app: &mut App) {
+        app.add_message::<SplashDismissed>()
+            .add_message::<SettingsScreenEvent>()
+            .insert_resource(AppShellScreens {
+                splash: self.splash.clone(),
+                main_menu: self.main_menu.clone(),
+                settings: self.settings.clone(),
+            });
+
+        install_app_shell::<S>(app);
+
+        info!("KonnektorenAppShell loaded");
+    }
+}
+
+/// Screens spawned by the app shell
+#[derive(Resource)]
+struct AppShellScreens {
+    splash: Option<SplashConfig>,
+    main_menu: Option<MenuSpawner>,
+    settings: Option<SettingsScreenConfig>,
+}
+
+fn install_app_shell<S: AppShellState>(app: &mut App) {
+    app.init_state::<S>()
+        .add_systems(OnEnter(S::SPLASH), spawn_shell_splash::<S>)
+        .add_systems(OnEnter(S::MAIN_MENU), spawn_shell_main_menu::<S>)
+        .add_systems(Update, advance_shell_state::<S>);
+
+    if let Some(settings_state) = S::SETTINGS {
+        app.add_systems(OnEnter(settings_state), spawn_shell_settings::<S>);
+    }
+}
+
+fn spawn_shell_splash<S: AppShellState>(
+    mut commands: Commands,
+    screens: Res<AppShellScreens>,
+    mut next_state: ResMut<NextState<S>>,
+) {
+    match &screens.splash {
+        Some(config) => {
+            commands.spawn((
+                Name::new("Splash Screen"),
+                config.clone(),
+                DespawnOnExit(S::SPLASH),
+            ));
+        }
+        // Nothing to show, go straight to the menu
+        None => next_state.set(S::MAIN_MENU),
+    }
+}
+
+fn spawn_shell_main_menu<S: AppShellState>(mut commands: Commands, screens: Res<AppShellScreens>) {
+    if let Some(spawn_menu) = &screens.main_menu {
+        let entity = spawn_menu(&mut commands);
+        commands.entity(entity).insert(DespawnOnExit(S::MAIN_MENU));
+    }
+}
+
+fn spawn_shell_settings<S: AppShellState>(mut commands: Commands, screens: Res<AppShellScreens>) {
+    let (Some(config), Some(settings_state)) = (&screens.settings, S::SETTINGS) else {
+        return;
+    };
+
+    commands.spawn((
+        Name::new("Settings Screen"),
+        config.clone(),
+        DespawnOnExit(settings_state),
+    ));
+}
+
+/// System to turn screen dismissals into state changes
+fn advance_shell_state<S: AppShellState>(
+    state: Res<State<S>>,
+    mut next_state: ResMut<NextState<S>>,
+    mut splash_events: MessageReader<SplashDismissed>,
+    mut settings_events: MessageReader<SettingsScreenEvent>,
+) {
+    let splash_dismissed = splash_events.read().last().is_some();
+    let settings_dismissed = settings_events
+        .read()
+        .any(|event| matches!(event, SettingsScreenEvent::Dismissed { .. }));
+
+    let state = state.get();
+    if (splash_dismissed && *state == S::SPLASH)
+        || (settings_dismissed && S::SETTINGS.as_ref() == Some(state))
+    {
+        next_state.set(S::MAIN_MENU);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+
+    #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    enum TestState {
+        #[default]
+        Splash,
+        Menu,
+    }
+
+    impl AppShellState for TestState {
+        const SPLASH: Self = TestState::Splash;
+        const MAIN_MENU: Self = TestState::Menu;
+    }
+
+    #[derive(Component, Clone)]
+    struct TestMenu;
+
+    #[test]
+    fn test_app_shell_splash_to_menu() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin)).add_plugins(
+            KonnektorenAppShell::<TestState>::new()
+                .with_splash(SplashConfig::new("Test"))
+                .with_main_menu(TestMenu),
+        );
+        app.update();
+
+        let splash = app
+            .world_mut()
+            .query_filtered::<Entity, With<SplashConfig>>()
+            .single(app.world())
+            .unwrap();
+        app.world_mut()
+            .write_message(SplashDismissed { entity: splash });
+        app.update();
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<State<TestState>>().get(),
+            &TestState::Menu
+        );
+        assert!(app.world().get_entity(splash).is_err());
+        assert_eq!(
+            app.world_mut()
+                .query::<&TestMenu>()
+                .iter(app.world())
+                .count(),
+            1
+        );
+    }
+}
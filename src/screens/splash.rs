@@ -1,47 +1,58 @@
+use super::{forward_screen_events, track_active_screen, ScreenAction, ScreenKind, ScreenMessage};
 use crate::{
     theme::KonnektorenTheme,
-    ui::responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+    ui::{
+        menu_background::MenuBackgroundState,
+        responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
+        texture_cache::{
+            release_texture_refs_on_removal, EguiTextureCache, EguiTextureCachePlugin,
+            EguiTextureRefs,
+        },
+    },
 };
 use bevy::prelude::*;
 use bevy_egui::{
     egui::{self, StrokeKind, TextureId},
-    EguiContexts, EguiPrimaryContextPass, EguiUserTextures,
+    EguiContexts, EguiPrimaryContextPass,
 };
-use std::collections::HashMap;
 
 /// Plugin for reusable splash screen functionality
 pub struct SplashPlugin;
 
 impl Plugin for SplashPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<SplashDismissed>()
+        track_active_screen::<ActiveSplash>(app);
+        forward_screen_events::<SplashDismissed>(app);
+        if !app.is_plugin_added::<EguiTextureCachePlugin>() {
+            app.add_plugins(EguiTextureCachePlugin);
+        }
+
+        app.init_resource::<SplashQueue>()
+            .add_message::<SplashDismissed>()
+            .add_message::<SplashSequenceFinished>()
+            .add_message::<SplashAssetFailed>()
             .add_systems(
                 Update,
                 (
-                    check_splash_config,
-                    update_splash_timer,
-                    handle_splash_completion,
-                    load_splash_images,
+                    (
+                        check_splash_config,
+                        update_splash_timer,
+                        handle_splash_completion,
+                        activate_next_splash,
+                    )
+                        .chain(),
+                    (load_splash_images, handle_splash_image_failures).chain(),
+                    release_texture_refs_on_removal::<ActiveSplash>,
                 ),
             )
-            .add_systems(EguiPrimaryContextPass, render_splash_ui);
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_splash_ui.run_if(any_with_component::<ActiveSplash>),
+            );
     }
 }
 
-/// Component to track loaded textures for splash screens
-#[derive(Component)]
-pub struct LoadedTextures {
-    pub textures: HashMap<String, TextureId>,
-    // Store handles to keep assets alive
-    pub handles: HashMap<String, Handle<Image>>,
-}
-
-/// Component to track loading images (keeps handles alive)
-#[derive(Component)]
-pub struct LoadingImages {
-    pub handles: HashMap<String, Handle<Image>>,
-}
-
 /// Logo display options for the splash screen
 #[derive(Clone, Debug)]
 pub enum LogoDisplay {
@@ -86,6 +97,10 @@ pub struct SplashConfig {
     pub show_loading: bool,
     /// Logo size multiplier (1.0 = default size)
     pub logo_size_multiplier: f32,
+    /// Order among queued splash screens, higher priorities are shown first
+    pub priority: i32,
+    /// Logo shown when the image logo fails to load, the title alone if `None`
+    pub fallback_logo: Option<LogoDisplay>,
 }
 
 impl Default for SplashConfig {
@@ -101,6 +116,8 @@ impl Default for SplashConfig {
             button_text: None,
             show_loading: true,
             logo_size_multiplier: 1.0,
+            priority: 0,
+            fallback_logo: None,
         }
     }
 }
@@ -186,6 +203,19 @@ impl SplashConfig {
         self
     }
 
+    /// Set the logo shown when the image logo fails to load
+    pub fn with_fallback_logo(mut self, logo: LogoDisplay) -> Self {
+        self.fallback_logo = Some(logo);
+        self
+    }
+
+    /// Set the order among queued splash screens, higher priorities are shown
+    /// first, e.g. a legal notice before the brand before the title
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
     /// Infinite splash that requires manual dismissal
     pub fn infinite(mut self) -> Self {
         self.duration = 0.0;
@@ -207,6 +237,8 @@ impl SplashConfig {
             button_text: Some("Enter".to_string()),
             show_loading: true,
             logo_size_multiplier: 1.2,
+            priority: 0,
+            fallback_logo: None,
         }
     }
 }
@@ -216,6 +248,8 @@ impl SplashConfig {
 pub struct ActiveSplash {
     timer: Timer,
     config: SplashConfig,
+    /// The image logo failed to load, the fallback logo is shown
+    logo_failed: bool,
 }
 
 /// Event sent when splash screen should be dismissed
@@ -224,82 +258,170 @@ pub struct SplashDismissed {
     pub entity: Entity,
 }
 
-/// System to check for new splash configurations and set them up
+impl ScreenMessage for SplashDismissed {
+    const KIND: ScreenKind = ScreenKind::Splash;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        Some((ScreenAction::Dismissed, self.entity))
+    }
+}
+
+/// Event sent when the image logo of a splash screen failed to load
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct SplashAssetFailed {
+    pub entity: Entity,
+    /// Asset path of the logo
+    pub path: String,
+    /// Why loading failed
+    pub error: String,
+}
+
+/// Event sent when the last queued splash screen was dismissed
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SplashSequenceFinished;
+
+/// Resource with the splash screens waiting to be shown.
+///
+/// Only one splash screen is active at a time, the next one is the queued
+/// one with the highest [`SplashConfig::priority`], in spawn order on ties.
+#[derive(Resource, Debug, Default)]
+pub struct SplashQueue {
+    pending: Vec<Entity>,
+    running: bool,
+}
+
+impl SplashQueue {
+    /// Splash screens waiting to be shown, in spawn order
+    pub fn pending(&self) -> &[Entity] {
+        &self.pending
+    }
+
+    /// Whether a splash sequence is being shown
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+}
+
+/// System to queue new splash configurations
 #[allow(clippy::type_complexity)]
 fn check_splash_config(
-    mut commands: Commands,
-    query: Query<(Entity, &SplashConfig), (Without<ActiveSplash>, Changed<SplashConfig>)>,
+    mut queue: ResMut<SplashQueue>,
+    query: Query<
+        Entity,
+        (
+            With<SplashConfig>,
+            Without<ActiveSplash>,
+            Changed<SplashConfig>,
+        ),
+    >,
 ) {
-    for (entity, config) in query.iter() {
-        info!("Setting up splash screen for entity {:?}", entity);
-
-        // Create timer
-        let timer = if config.duration > 0.0 {
-            Timer::from_seconds(config.duration, TimerMode::Once)
-        } else {
-            Timer::from_seconds(f32::MAX, TimerMode::Once) // Infinite timer
-        };
+    for entity in query.iter() {
+        if !queue.pending.contains(&entity) {
+            info!("Queueing splash screen for entity {:?}", entity);
+            queue.pending.push(entity);
+        }
+    }
+}
 
-        // Add ActiveSplash component
-        commands.entity(entity).insert(ActiveSplash {
-            timer,
-            config: config.clone(),
-        });
+/// System to show the next queued splash screen once none is active
+fn activate_next_splash(
+    mut commands: Commands,
+    mut queue: ResMut<SplashQueue>,
+    configs: Query<&SplashConfig>,
+    active: Query<(), With<ActiveSplash>>,
+    mut finished_events: MessageWriter<SplashSequenceFinished>,
+) {
+    if !active.is_empty() {
+        return;
     }
+
+    // Splash screens despawned while waiting are dropped
+    queue.pending.retain(|entity| configs.contains(*entity));
+
+    // The first of the highest priority, max_by_key would pick the last one
+    let next = queue
+        .pending
+        .iter()
+        .enumerate()
+        .filter_map(|(index, entity)| Some((index, configs.get(*entity).ok()?)))
+        .min_by_key(|(_, config)| std::cmp::Reverse(config.priority));
+    let Some((index, config)) = next else {
+        if queue.running {
+            info!("Splash sequence finished");
+            queue.running = false;
+            finished_events.write(SplashSequenceFinished);
+        }
+        return;
+    };
+
+    let entity = queue.pending.remove(index);
+    info!("Setting up splash screen for entity {:?}", entity);
+
+    // Create timer
+    let timer = if config.duration > 0.0 {
+        Timer::from_seconds(config.duration, TimerMode::Once)
+    } else {
+        Timer::new(std::time::Duration::MAX, TimerMode::Once) // Infinite timer
+    };
+
+    // Add ActiveSplash component
+    commands.entity(entity).insert(ActiveSplash {
+        timer,
+        config: config.clone(),
+        logo_failed: false,
+    });
+    queue.running = true;
 }
 
-/// System to load images for splash screens
-#[allow(clippy::type_complexity)]
+/// System to take references to the logo images of new splash screens
 fn load_splash_images(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    images: Res<Assets<Image>>,
-    mut egui_user_textures: ResMut<EguiUserTextures>,
-    // Query for entities that need image loading
-    active_query: Query<(Entity, &ActiveSplash), (Without<LoadedTextures>, Without<LoadingImages>)>,
-    // Query for entities that are currently loading
-    mut loading_query: Query<(Entity, &ActiveSplash, &mut LoadingImages), Without<LoadedTextures>>,
+    mut texture_cache: ResMut<EguiTextureCache>,
+    query: Query<(Entity, &ActiveSplash), Without<EguiTextureRefs>>,
 ) {
-    // Start loading for new splash screens
-    for (entity, splash) in active_query.iter() {
+    for (entity, splash) in query.iter() {
         if let LogoDisplay::Image(path) = &splash.config.logo {
             info!("Starting to load image: {}", path);
 
-            // Load the image asset and store the handle
-            let image_handle: Handle<Image> = asset_server.load(path);
-
-            let mut handles = HashMap::new();
-            handles.insert(path.clone(), image_handle);
-
-            commands.entity(entity).insert(LoadingImages { handles });
+            let refs = EguiTextureRefs::acquire([path.as_str()], &mut texture_cache, &asset_server);
+            commands.entity(entity).insert(refs);
         }
     }
+}
 
-    // Check loading progress
-    for (entity, splash, mut loading_images) in loading_query.iter_mut() {
-        if let LogoDisplay::Image(path) = &splash.config.logo {
-            if let Some(image_handle) = loading_images.handles.get(path) {
-                // Check if the image is now loaded
-                if let Some(_image) = images.get(image_handle) {
-                    info!("Image loaded successfully: {}", path);
-
-                    // Fix: Use the correct API for adding textures
-                    let texture_id = egui_user_textures
-                        .add_image(bevy_egui::EguiTextureHandle::Strong(image_handle.clone()));
-
-                    let mut textures = HashMap::new();
-                    textures.insert(path.clone(), texture_id);
-
-                    // Move handles to keep them alive
-                    let handles = std::mem::take(&mut loading_images.handles);
-
-                    commands
-                        .entity(entity)
-                        .remove::<LoadingImages>()
-                        .insert(LoadedTextures { textures, handles });
-                }
-            }
+/// System to switch splash screens whose image logo failed to load to their
+/// fallback logo
+fn handle_splash_image_failures(
+    asset_server: Res<AssetServer>,
+    mut texture_cache: ResMut<EguiTextureCache>,
+    mut query: Query<(Entity, &mut ActiveSplash, Option<&mut EguiTextureRefs>)>,
+    mut failed_events: MessageWriter<SplashAssetFailed>,
+) {
+    for (entity, mut splash, refs) in query.iter_mut() {
+        if splash.logo_failed {
+            continue;
         }
+        let LogoDisplay::Image(path) = &splash.config.logo else {
+            continue;
+        };
+        let Some(error) = texture_cache.load_error(path) else {
+            continue;
+        };
+
+        warn!("Splash logo {} failed to load, using the fallback", path);
+        failed_events.write(SplashAssetFailed {
+            entity,
+            path: path.clone(),
+            error: error.to_string(),
+        });
+
+        if let (Some(LogoDisplay::Image(fallback)), Some(mut refs)) =
+            (&splash.config.fallback_logo, refs)
+        {
+            refs.add(fallback, &mut texture_cache, &asset_server);
+        }
+        splash.logo_failed = true;
     }
 }
 
@@ -322,13 +444,16 @@ fn update_splash_timer(
 }
 
 /// System to render splash UI
+#[allow(clippy::too_many_arguments)]
 fn render_splash_ui(
     mut contexts: EguiContexts,
     theme: Res<KonnektorenTheme>,
     responsive: Res<ResponsiveInfo>,
-    query: Query<(Entity, &ActiveSplash, Option<&LoadedTextures>)>,
+    texture_cache: Res<EguiTextureCache>,
+    query: Query<(Entity, &ActiveSplash)>,
     mut dismiss_events: MessageWriter<SplashDismissed>,
     input: Res<ButtonInput<KeyCode>>,
+    background: Option<Res<MenuBackgroundState>>,
 ) {
     // Early return if no active splash screens
     if query.is_empty() {
@@ -336,7 +461,8 @@ fn render_splash_ui(
     }
 
     if let Ok(ctx) = contexts.ctx_mut() {
-        for (entity, splash, loaded_textures) in query.iter() {
+        // The splash queue keeps only one splash screen active
+        if let Some((entity, splash)) = query.iter().next() {
             let config = &splash.config;
 
             // Handle keyboard dismissal
@@ -346,7 +472,7 @@ fn render_splash_ui(
                     || input.just_pressed(KeyCode::Escape))
             {
                 dismiss_events.write(SplashDismissed { entity });
-                continue;
+                return;
             }
 
             // Determine background color
@@ -355,16 +481,22 @@ fn render_splash_ui(
             egui::CentralPanel::default()
                 .frame(egui::Frame::NONE.fill(bg_color))
                 .show(ctx, |ui| {
-                    render_splash_content(
-                        ui,
-                        config,
-                        splash,
-                        &theme,
-                        &responsive,
-                        entity,
-                        &mut dismiss_events,
-                        loaded_textures,
-                    );
+                    scoped_ui(ui, (entity, "splash"), |ui| {
+                        // An explicit background color wins over the menu background
+                        if let (None, Some(background)) = (config.background_color, &background) {
+                            background.paint(ui.painter(), ui.max_rect(), &theme);
+                        }
+                        render_splash_content(
+                            ui,
+                            config,
+                            splash,
+                            &theme,
+                            &responsive,
+                            entity,
+                            &mut dismiss_events,
+                            &texture_cache,
+                        );
+                    })
                 });
         }
     }
@@ -380,20 +512,25 @@ fn render_splash_content(
     responsive: &ResponsiveInfo,
     entity: Entity,
     dismiss_events: &mut MessageWriter<SplashDismissed>,
-    loaded_textures: Option<&LoadedTextures>,
+    texture_cache: &EguiTextureCache,
 ) {
     ui.vertical_centered(|ui| {
         let top_spacing = if responsive.is_mobile() { 50.0 } else { 80.0 };
         ui.add_space(top_spacing);
 
-        // Render logo with image support
+        // Render logo with image support, or the fallback if it failed to load
+        let logo = if splash.logo_failed {
+            config.fallback_logo.as_ref().unwrap_or(&LogoDisplay::None)
+        } else {
+            &config.logo
+        };
         render_logo_enhanced(
             ui,
-            &config.logo,
+            logo,
             theme,
             responsive,
             config.logo_size_multiplier,
-            loaded_textures,
+            texture_cache,
         );
 
         // Title
@@ -469,13 +606,13 @@ fn render_splash_content(
 }
 
 /// Enhanced logo rendering with actual image support
-fn render_logo_enhanced(
+pub(super) fn render_logo_enhanced(
     ui: &mut egui::Ui,
     logo: &LogoDisplay,
     theme: &KonnektorenTheme,
     responsive: &ResponsiveInfo,
     size_multiplier: f32,
-    loaded_textures: Option<&LoadedTextures>,
+    texture_cache: &EguiTextureCache,
 ) {
     let base_size = if responsive.is_mobile() { 80.0 } else { 100.0 };
     let logo_size = base_size * size_multiplier;
@@ -517,12 +654,8 @@ fn render_logo_enhanced(
         }
         LogoDisplay::Image(path) => {
             // Try to render actual image if loaded
-            if let Some(textures) = loaded_textures {
-                if let Some(texture_id) = textures.textures.get(path) {
-                    render_actual_image(ui, texture_id, logo_size, responsive);
-                } else {
-                    render_image_loading(ui, path, theme, responsive, logo_size);
-                }
+            if let Some(texture_id) = texture_cache.texture(path) {
+                render_actual_image(ui, &texture_id, logo_size, responsive);
             } else {
                 render_image_loading(ui, path, theme, responsive, logo_size);
             }
@@ -609,12 +742,11 @@ fn handle_splash_completion(
     for event in dismiss_events.read() {
         info!("Dismissing splash screen for entity {:?}", event.entity);
 
-        // Remove all splash-related components
+        // Remove all splash-related components, releasing the logo texture
         commands
             .entity(event.entity)
             .remove::<ActiveSplash>()
-            .remove::<LoadedTextures>()
-            .remove::<LoadingImages>();
+            .remove::<EguiTextureRefs>();
     }
 }
 
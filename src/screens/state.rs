@@ -0,0 +1,199 @@
+use super::{
+    AboutConfig, AboutScreenExt, CreditsConfig, CreditsScreenExt, MainMenuConfig,
+    MainMenuScreenExt, ScreenAction, ScreenEvent, SplashConfig, SplashScreenExt,
+};
+use bevy::{prelude::*, state::state::FreelyMutableState};
+use std::marker::PhantomData;
+
+/// Helper trait tying screens to Bevy [`States`].
+///
+/// The screen is spawned when the app enters the state and despawned with
+/// [`DespawnOnExit`] when it leaves it. When a next state is given, the app
+/// moves on once the screen is dismissed or submitted. Unlike the
+/// [`KonnektorenAppShell`](super::KonnektorenAppShell) any state and screen
+/// combination can be registered:
+///
+/// ```ignore
+/// app.init_state::<GameState>()
+///     .add_splash_for_state(GameState::Splash, SplashConfig::konnektoren(), GameState::Menu)
+///     .add_main_menu_for_state(GameState::Menu, MainMenuConfig::new("Konnektoren"));
+/// ```
+pub trait ScreenStateExt {
+    /// Spawn a screen with `spawn` while in `state`, and go to `next` (if
+    /// any) when it is dismissed or submitted
+    fn add_screen_for_state<S>(
+        &mut self,
+        state: S,
+        next: Option<S>,
+        spawn: impl Fn(&mut Commands) -> Entity + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        S: States + FreelyMutableState;
+
+    /// Show a splash screen while in `state`, then go to `next`
+    fn add_splash_for_state<S>(&mut self, state: S, config: SplashConfig, next: S) -> &mut Self
+    where
+        S: States + FreelyMutableState,
+    {
+        self.add_screen_for_state(state, Some(next), move |commands| {
+            commands.spawn_splash(config.clone())
+        })
+    }
+
+    /// Show an about screen while in `state`, go to `next` when it is closed
+    fn add_about_for_state<S>(&mut self, state: S, config: AboutConfig, next: S) -> &mut Self
+    where
+        S: States + FreelyMutableState,
+    {
+        self.add_screen_for_state(state, Some(next), move |commands| {
+            commands.spawn_about(config.clone())
+        })
+    }
+
+    /// Show the credits while in `state`, go to `next` when they are closed
+    fn add_credits_for_state<S>(&mut self, state: S, config: CreditsConfig, next: S) -> &mut Self
+    where
+        S: States + FreelyMutableState,
+    {
+        self.add_screen_for_state(state, Some(next), move |commands| {
+            commands.spawn_credits(config.clone())
+        })
+    }
+
+    /// Show a main menu while in `state`, the game changes the state on
+    /// [`MainMenuEvent::Selected`](super::MainMenuEvent::Selected)
+    fn add_main_menu_for_state<S>(&mut self, state: S, config: MainMenuConfig) -> &mut Self
+    where
+        S: States + FreelyMutableState,
+    {
+        self.add_screen_for_state(state, None, move |commands| {
+            commands.spawn_main_menu(config.clone())
+        })
+    }
+}
+
+impl ScreenStateExt for App {
+    fn add_screen_for_state<S>(
+        &mut self,
+        state: S,
+        next: Option<S>,
+        spawn: impl Fn(&mut Commands) -> Entity + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        S: States + FreelyMutableState,
+    {
+        if !self
+            .world()
+            .contains_resource::<ScreenStatesRegistered<S>>()
+        {
+            self.init_resource::<ScreenStatesRegistered<S>>()
+                .add_message::<ScreenEvent>()
+                .add_systems(Update, advance_screen_state::<S>);
+        }
+
+        let scope = ScreenForState {
+            state: state.clone(),
+            next,
+        };
+        self.add_systems(OnEnter(state.clone()), move |mut commands: Commands| {
+            let entity = spawn(&mut commands);
+            commands
+                .entity(entity)
+                .insert((scope.clone(), DespawnOnExit(scope.state.clone())));
+        })
+    }
+}
+
+/// Component of screens spawned by [`ScreenStateExt`]
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct ScreenForState<S: States> {
+    /// State the screen is shown in
+    pub state: S,
+    /// State to go to when the screen is dismissed or submitted
+    pub next: Option<S>,
+}
+
+/// Marker that the systems for the state type `S` were added
+#[derive(Resource)]
+struct ScreenStatesRegistered<S>(PhantomData<S>);
+
+impl<S> Default for ScreenStatesRegistered<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// System to go to the next state when a screen of the current state closes
+fn advance_screen_state<S: States + FreelyMutableState>(
+    mut events: MessageReader<ScreenEvent>,
+    screens: Query<&ScreenForState<S>>,
+    state: Res<State<S>>,
+    mut next_state: ResMut<NextState<S>>,
+) {
+    for event in events.read() {
+        if !matches!(
+            event.action,
+            ScreenAction::Dismissed | ScreenAction::Submitted
+        ) {
+            continue;
+        }
+        let Ok(screen) = screens.get(event.entity) else {
+            continue;
+        };
+        if let (true, Some(next)) = (screen.state == *state.get(), &screen.next) {
+            info!("{:?} screen closed, going to {:?}", event.kind, next);
+            next_state.set(next.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        screens::{ActiveSplash, MainMenuPlugin, SplashPlugin},
+        test_utils::TestHarness,
+        theme::KonnektorenThemePlugin,
+        ui::UIPlugin,
+    };
+
+    #[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
+    enum GameState {
+        #[default]
+        Splash,
+        Menu,
+    }
+
+    #[test]
+    fn test_screens_for_state() {
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            SplashPlugin,
+            MainMenuPlugin,
+        ));
+        harness
+            .app_mut()
+            .init_state::<GameState>()
+            .add_splash_for_state(
+                GameState::Splash,
+                SplashConfig::new("Welcome").infinite(),
+                GameState::Menu,
+            )
+            .add_main_menu_for_state(GameState::Menu, MainMenuConfig::new("Konnektoren"));
+        harness.run_frames(2);
+        assert!(harness.has_widget("Welcome"));
+
+        harness.click("Continue");
+        harness.run_frames(3);
+        let state = harness.world().resource::<State<GameState>>();
+        assert_eq!(*state.get(), GameState::Menu);
+        assert!(harness.has_widget("Konnektoren"));
+        let splashes = harness
+            .world_mut()
+            .query_filtered::<(), Or<(With<SplashConfig>, With<ActiveSplash>)>>()
+            .iter(harness.world())
+            .count();
+        assert_eq!(splashes, 0);
+    }
+}
@@ -0,0 +1,635 @@
+use super::{forward_screen_events, track_active_screen, ScreenAction, ScreenKind, ScreenMessage};
+#[cfg(feature = "storage")]
+use crate::storage::Storage;
+use crate::{
+    assets::{SurveyAsset, SurveyQuestion, SurveyQuestionKind},
+    theme::KonnektorenTheme,
+    ui::{
+        notifications::Notification,
+        responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+        scope::scoped_ui,
+        widgets::{ResponsiveText, SpinnerWidget, ThemedButton},
+    },
+};
+use bevy::prelude::*;
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+use chrono::Utc;
+use std::{collections::HashMap, sync::Arc};
+
+/// Plugin for the survey screen
+pub struct SurveyPlugin;
+
+impl Plugin for SurveyPlugin {
+    fn build(&self, app: &mut App) {
+        track_active_screen::<ActiveSurvey>(app);
+        forward_screen_events::<SurveyEvent>(app);
+
+        app.add_message::<SurveyEvent>()
+            .add_message::<Notification>()
+            .init_resource::<SurveySinkResource>()
+            .add_systems(
+                Update,
+                (
+                    check_survey_config,
+                    load_survey_assets,
+                    handle_survey_events,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_survey_ui.run_if(any_with_component::<ActiveSurvey>),
+            );
+    }
+}
+
+/// Answer to a single survey question
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub enum SurveyAnswerValue {
+    /// Index and text of the chosen option
+    Choice {
+        index: usize,
+        option: String,
+    },
+    /// Selected value on a Likert scale (1-based)
+    Scale(u8),
+    Text(String),
+}
+
+/// Answer of a survey question
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub struct SurveyAnswer {
+    pub question_id: String,
+    pub value: SurveyAnswerValue,
+}
+
+/// Completed survey, passed to the [`SurveySink`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub struct SurveyResponse {
+    pub survey_id: String,
+    /// Unix timestamp in seconds
+    pub timestamp: i64,
+    pub answers: Vec<SurveyAnswer>,
+}
+
+/// Destination for survey responses, e.g. a research backend
+pub trait SurveySink: Send + Sync + 'static {
+    fn submit(&self, response: &SurveyResponse) -> Result<(), String>;
+}
+
+/// Survey sink that only logs the responses
+#[derive(Debug, Default, Clone)]
+pub struct LogSurveySink;
+
+impl SurveySink for LogSurveySink {
+    fn submit(&self, response: &SurveyResponse) -> Result<(), String> {
+        info!(
+            "Survey '{}' submitted with {} answers: {:?}",
+            response.survey_id,
+            response.answers.len(),
+            response.answers
+        );
+        Ok(())
+    }
+}
+
+/// Survey sink appending responses to the storage backend under `survey.<id>`
+#[cfg(feature = "storage")]
+#[derive(Clone)]
+pub struct StorageSurveySink {
+    storage: Storage,
+}
+
+#[cfg(feature = "storage")]
+impl StorageSurveySink {
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+
+#[cfg(feature = "storage")]
+impl SurveySink for StorageSurveySink {
+    fn submit(&self, response: &SurveyResponse) -> Result<(), String> {
+        let key = format!("survey.{}", response.survey_id);
+        let mut responses = self
+            .storage
+            .load::<Vec<SurveyResponse>>(&key)
+            .map_err(|e| e.to_string())?
+            .unwrap_or_default();
+        responses.push(response.clone());
+        self.storage
+            .save(&key, &responses)
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Resource holding the active survey sink
+#[derive(Resource, Clone)]
+pub struct SurveySinkResource(pub Arc<dyn SurveySink>);
+
+impl Default for SurveySinkResource {
+    fn default() -> Self {
+        Self(Arc::new(LogSurveySink))
+    }
+}
+
+impl SurveySinkResource {
+    pub fn new(sink: impl SurveySink) -> Self {
+        Self(Arc::new(sink))
+    }
+}
+
+/// Configuration for the survey screen
+#[derive(Component, Clone)]
+pub struct SurveyConfig {
+    /// Asset path of the survey (`*.survey.yml`)
+    pub survey_path: String,
+    pub submit_button_text: String,
+    pub dismiss_button_text: String,
+    pub thank_you_message: String,
+    /// Allow manual dismissal (back button/escape)
+    pub manual_dismissal: bool,
+}
+
+impl SurveyConfig {
+    pub fn new(survey_path: impl Into<String>) -> Self {
+        Self {
+            survey_path: survey_path.into(),
+            submit_button_text: "Submit".to_string(),
+            dismiss_button_text: "← Back".to_string(),
+            thank_you_message: "Thank you for your feedback!".to_string(),
+            manual_dismissal: true,
+        }
+    }
+
+    pub fn with_submit_button_text(mut self, text: impl Into<String>) -> Self {
+        self.submit_button_text = text.into();
+        self
+    }
+
+    pub fn with_dismiss_button_text(mut self, text: impl Into<String>) -> Self {
+        self.dismiss_button_text = text.into();
+        self
+    }
+
+    pub fn with_thank_you_message(mut self, message: impl Into<String>) -> Self {
+        self.thank_you_message = message.into();
+        self
+    }
+
+    pub fn with_manual_dismissal(mut self, manual_dismissal: bool) -> Self {
+        self.manual_dismissal = manual_dismissal;
+        self
+    }
+}
+
+/// Component marking an active survey screen
+#[derive(Component)]
+pub struct ActiveSurvey {
+    config: SurveyConfig,
+    survey: Option<Handle<SurveyAsset>>,
+    answers: HashMap<String, SurveyAnswerValue>,
+    /// Required questions without answer after a submit attempt
+    missing: Vec<String>,
+    submitted: bool,
+}
+
+impl ActiveSurvey {
+    /// Collect the answers in question order
+    fn response(&self, survey: &SurveyAsset) -> SurveyResponse {
+        SurveyResponse {
+            survey_id: survey.id.clone(),
+            timestamp: Utc::now().timestamp(),
+            answers: survey
+                .questions
+                .iter()
+                .filter_map(|question| {
+                    let value = self.answers.get(&question.id)?.clone();
+                    Some(SurveyAnswer {
+                        question_id: question.id.clone(),
+                        value,
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    fn missing_answers(&self, survey: &SurveyAsset) -> Vec<String> {
+        survey
+            .questions
+            .iter()
+            .filter(|question| question.required)
+            .filter(|question| match self.answers.get(&question.id) {
+                Some(SurveyAnswerValue::Text(text)) => text.trim().is_empty(),
+                Some(_) => false,
+                None => true,
+            })
+            .map(|question| question.id.clone())
+            .collect()
+    }
+}
+
+/// Events for survey interactions
+#[derive(Message, Debug, Clone)]
+pub enum SurveyEvent {
+    /// The survey was completed and submitted
+    Submitted {
+        entity: Entity,
+        response: SurveyResponse,
+    },
+    /// Survey screen dismissed
+    Dismissed { entity: Entity },
+}
+
+impl ScreenMessage for SurveyEvent {
+    const KIND: ScreenKind = ScreenKind::Survey;
+
+    fn screen_action(&self) -> Option<(ScreenAction, Entity)> {
+        match self {
+            Self::Submitted { entity, .. } => Some((ScreenAction::Submitted, *entity)),
+            Self::Dismissed { entity } => Some((ScreenAction::Dismissed, *entity)),
+        }
+    }
+}
+
+/// System to check for new survey configurations and set them up
+#[allow(clippy::type_complexity)]
+fn check_survey_config(
+    mut commands: Commands,
+    query: Query<(Entity, &SurveyConfig), (Without<ActiveSurvey>, Changed<SurveyConfig>)>,
+    existing: Query<Entity, With<ActiveSurvey>>,
+) {
+    for (entity, config) in query.iter() {
+        info!("Setting up survey screen for entity {:?}", entity);
+
+        // Clean up any existing survey screens first
+        for existing_entity in existing.iter() {
+            commands.entity(existing_entity).remove::<ActiveSurvey>();
+        }
+
+        commands.entity(entity).insert(ActiveSurvey {
+            config: config.clone(),
+            survey: None,
+            answers: HashMap::new(),
+            missing: vec![],
+            submitted: false,
+        });
+    }
+}
+
+/// System to start loading the survey assets
+fn load_survey_assets(asset_server: Res<AssetServer>, mut query: Query<&mut ActiveSurvey>) {
+    for mut active in query.iter_mut() {
+        if active.survey.is_none() {
+            info!("Loading survey: {}", active.config.survey_path);
+            active.survey = Some(asset_server.load(active.config.survey_path.clone()));
+        }
+    }
+}
+
+/// System to pass submitted surveys to the sink and handle dismissal
+fn handle_survey_events(
+    mut commands: Commands,
+    mut events: MessageReader<SurveyEvent>,
+    sink: Res<SurveySinkResource>,
+    mut notifications: MessageWriter<Notification>,
+    mut query: Query<&mut ActiveSurvey>,
+) {
+    for event in events.read() {
+        match event {
+            SurveyEvent::Submitted { entity, response } => match sink.0.submit(response) {
+                Ok(()) => {
+                    if let Ok(mut active) = query.get_mut(*entity) {
+                        active.submitted = true;
+                    }
+                }
+                Err(error) => {
+                    warn!(
+                        "Failed to submit survey '{}': {}",
+                        response.survey_id, error
+                    );
+                    notifications.write(Notification::error("Survey not sent", error));
+                }
+            },
+            SurveyEvent::Dismissed { entity } => {
+                info!("Dismissing survey screen for entity {:?}", entity);
+                commands.entity(*entity).remove::<ActiveSurvey>();
+            }
+        }
+    }
+}
+
+/// System to render the survey UI
+fn render_survey_ui(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    surveys: Option<Res<Assets<SurveyAsset>>>,
+    mut query: Query<(Entity, &mut ActiveSurvey)>,
+    mut events: MessageWriter<SurveyEvent>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    // Surveys are registered by the KonnektorenAssetsPlugin
+    let Some(surveys) = surveys else {
+        return;
+    };
+
+    if query.is_empty() {
+        return;
+    }
+
+    if let Ok(ctx) = contexts.ctx_mut() {
+        // Only render the first (most recent) survey to avoid widget ID conflicts
+        if let Some((entity, mut active)) = query.iter_mut().next() {
+            if active.config.manual_dismissal && input.just_pressed(KeyCode::Escape) {
+                events.write(SurveyEvent::Dismissed { entity });
+                return;
+            }
+
+            let survey = active
+                .survey
+                .as_ref()
+                .and_then(|handle| surveys.get(handle));
+
+            egui::CentralPanel::default()
+                .frame(egui::Frame::NONE.fill(theme.base_100))
+                .show(ctx, |ui| {
+                    scoped_ui(ui, (entity, "survey"), |ui| {
+                        render_survey_content(
+                            ui,
+                            &mut active,
+                            survey,
+                            &theme,
+                            &responsive,
+                            entity,
+                            &mut events,
+                        );
+                    })
+                });
+        }
+    }
+}
+
+/// Render survey screen content
+fn render_survey_content(
+    ui: &mut egui::Ui,
+    active: &mut ActiveSurvey,
+    survey: Option<&SurveyAsset>,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    events: &mut MessageWriter<SurveyEvent>,
+) {
+    let config = active.config.clone();
+
+    ui.vertical_centered(|ui| {
+        let max_width = if responsive.is_mobile() {
+            ui.available_width() * 0.95
+        } else {
+            800.0_f32.min(ui.available_width() * 0.9)
+        };
+
+        ui.set_max_width(max_width);
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+        let Some(survey) = survey else {
+            // Survey still loading
+            SpinnerWidget::new(theme, 48.0)
+                .responsive(responsive)
+                .ui(ui);
+            return;
+        };
+
+        ResponsiveText::new(&survey.title, ResponsiveFontSize::Title, theme.primary)
+            .responsive(responsive)
+            .strong()
+            .ui(ui);
+
+        if active.submitted {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::XLarge));
+            ResponsiveText::new(
+                &config.thank_you_message,
+                ResponsiveFontSize::Large,
+                theme.success,
+            )
+            .responsive(responsive)
+            .ui(ui);
+        } else {
+            if let Some(description) = &survey.description {
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                ResponsiveText::new(description, ResponsiveFontSize::Medium, theme.base_content)
+                    .responsive(responsive)
+                    .ui(ui);
+            }
+
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+            let scroll_height = ui.available_height() - 140.0;
+            egui::ScrollArea::vertical()
+                .id_salt("survey_scroll")
+                .max_height(scroll_height)
+                .auto_shrink([false; 2])
+                .show(ui, |ui| {
+                    for (index, question) in survey.questions.iter().enumerate() {
+                        scoped_ui(ui, ("survey_question", &question.id), |ui| {
+                            render_survey_question(ui, index, question, active, theme, responsive);
+                        });
+                        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                    }
+                });
+
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+            let submit_button = ThemedButton::new(&config.submit_button_text, theme)
+                .responsive(responsive)
+                .width(if responsive.is_mobile() { 200.0 } else { 250.0 });
+
+            if ui.add(submit_button).clicked() {
+                active.missing = active.missing_answers(survey);
+                if active.missing.is_empty() {
+                    events.write(SurveyEvent::Submitted {
+                        entity,
+                        response: active.response(survey),
+                    });
+                }
+            }
+        }
+
+        if config.manual_dismissal {
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+            let back_button = ThemedButton::new(&config.dismiss_button_text, theme)
+                .responsive(responsive)
+                .width(if responsive.is_mobile() { 200.0 } else { 250.0 });
+
+            if ui.add(back_button).clicked() {
+                events.write(SurveyEvent::Dismissed { entity });
+            }
+        }
+    });
+}
+
+/// Render a single survey question
+fn render_survey_question(
+    ui: &mut egui::Ui,
+    index: usize,
+    question: &SurveyQuestion,
+    active: &mut ActiveSurvey,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+) {
+    let is_missing = active.missing.contains(&question.id);
+    let frame = egui::Frame {
+        inner_margin: egui::Margin::same(if responsive.is_mobile() { 12 } else { 16 }),
+        corner_radius: egui::CornerRadius::same(8),
+        fill: theme.base_200,
+        stroke: if is_missing {
+            egui::Stroke::new(2.0, theme.error)
+        } else {
+            egui::Stroke::new(1.0, theme.accent.linear_multiply(0.3))
+        },
+        ..Default::default()
+    };
+
+    frame.show(ui, |ui| {
+        ui.set_width(ui.available_width());
+
+        let text = if question.required {
+            format!("{}. {} *", index + 1, question.text)
+        } else {
+            format!("{}. {}", index + 1, question.text)
+        };
+        ResponsiveText::new(&text, ResponsiveFontSize::Medium, theme.base_content)
+            .responsive(responsive)
+            .strong()
+            .ui(ui);
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+
+        let answer = active.answers.get(&question.id).cloned();
+        let mut new_answer = None;
+
+        match &question.kind {
+            SurveyQuestionKind::SingleChoice { options } => {
+                for (option_index, option) in options.iter().enumerate() {
+                    let selected = matches!(
+                        &answer,
+                        Some(SurveyAnswerValue::Choice { index, .. }) if *index == option_index
+                    );
+                    let label = egui::RichText::new(option)
+                        .size(responsive.font_size(ResponsiveFontSize::Medium))
+                        .color(theme.base_content);
+                    if ui.radio(selected, label).clicked() {
+                        new_answer = Some(SurveyAnswerValue::Choice {
+                            index: option_index,
+                            option: option.clone(),
+                        });
+                    }
+                }
+            }
+            SurveyQuestionKind::Likert {
+                scale,
+                min_label,
+                max_label,
+            } => {
+                let selected = match &answer {
+                    Some(SurveyAnswerValue::Scale(value)) => Some(*value),
+                    _ => None,
+                };
+                let button_size = responsive.min_hit_target().max(36.0);
+
+                ui.horizontal_wrapped(|ui| {
+                    if let Some(min_label) = min_label {
+                        ResponsiveText::new(min_label, ResponsiveFontSize::Small, theme.accent)
+                            .responsive(responsive)
+                            .ui(ui);
+                    }
+
+                    for value in 1..=*scale {
+                        let is_selected = selected == Some(value);
+                        let (fill, text_color) = if is_selected {
+                            (theme.primary, theme.primary_content)
+                        } else {
+                            (theme.base_300, theme.base_content)
+                        };
+                        let button = egui::Button::new(
+                            egui::RichText::new(value.to_string()).color(text_color),
+                        )
+                        .fill(fill)
+                        .min_size(egui::vec2(button_size, button_size));
+
+                        if ui.add(button).clicked() {
+                            new_answer = Some(SurveyAnswerValue::Scale(value));
+                        }
+                    }
+
+                    if let Some(max_label) = max_label {
+                        ResponsiveText::new(max_label, ResponsiveFontSize::Small, theme.accent)
+                            .responsive(responsive)
+                            .ui(ui);
+                    }
+                });
+            }
+            SurveyQuestionKind::FreeText {
+                multiline,
+                max_length,
+            } => {
+                let mut text = match answer {
+                    Some(SurveyAnswerValue::Text(text)) => text,
+                    _ => String::new(),
+                };
+
+                let mut text_edit = if *multiline {
+                    egui::TextEdit::multiline(&mut text).desired_rows(3)
+                } else {
+                    egui::TextEdit::singleline(&mut text)
+                }
+                .desired_width(f32::INFINITY);
+                if let Some(max_length) = max_length {
+                    text_edit = text_edit.char_limit(*max_length);
+                }
+
+                if ui.add(text_edit).changed() {
+                    new_answer = Some(SurveyAnswerValue::Text(text));
+                }
+            }
+        }
+
+        if is_missing {
+            ResponsiveText::new(
+                "Please answer this question",
+                ResponsiveFontSize::Small,
+                theme.error,
+            )
+            .responsive(responsive)
+            .ui(ui);
+        }
+
+        if let Some(new_answer) = new_answer {
+            active.answers.insert(question.id.clone(), new_answer);
+            active.missing.retain(|id| id != &question.id);
+        }
+    });
+}
+
+/// Helper trait for easy survey screen setup
+pub trait SurveyScreenExt {
+    /// Add a survey screen with the given configuration
+    fn spawn_survey(&mut self, config: SurveyConfig) -> Entity;
+
+    /// Add a survey screen for a survey asset path
+    fn spawn_simple_survey(&mut self, survey_path: impl Into<String>) -> Entity;
+}
+
+impl SurveyScreenExt for Commands<'_, '_> {
+    fn spawn_survey(&mut self, config: SurveyConfig) -> Entity {
+        self.spawn((Name::new("Survey Screen"), config)).id()
+    }
+
+    fn spawn_simple_survey(&mut self, survey_path: impl Into<String>) -> Entity {
+        self.spawn_survey(SurveyConfig::new(survey_path))
+    }
+}
@@ -0,0 +1,241 @@
+use super::AnyScreenActive;
+use bevy::{prelude::*, window::PrimaryWindow};
+
+/// Plugin keeping the title and icon of the primary window consistent.
+///
+/// The title follows the active screen through a template, by default
+/// "{app} — {screen}". The screen part is the [`ScreenTitle`] of the top
+/// screen, or its [`Name`] without the " Screen" suffix, so a main menu
+/// spawned with `spawn_main_menu` shows "Konnektoren — Main Menu". Without an
+/// active screen the title is the app name.
+///
+/// With the `window-icon` feature the window icon is set from an image asset
+/// on desktop platforms. Browsers take the icon from the page.
+///
+/// ```ignore
+/// app.add_plugins(WindowBrandingPlugin::new("Konnektoren").with_icon("icon.png"));
+/// ```
+pub struct WindowBrandingPlugin {
+    pub app_name: String,
+    /// Title with `{app}` and `{screen}` placeholders
+    pub template: String,
+    /// Asset path of the window icon
+    pub icon: Option<String>,
+}
+
+impl WindowBrandingPlugin {
+    pub fn new(app_name: impl Into<String>) -> Self {
+        Self {
+            app_name: app_name.into(),
+            template: "{app} — {screen}".to_string(),
+            icon: None,
+        }
+    }
+
+    pub fn with_template(mut self, template: impl Into<String>) -> Self {
+        self.template = template.into();
+        self
+    }
+
+    pub fn with_icon(mut self, path: impl Into<String>) -> Self {
+        self.icon = Some(path.into());
+        self
+    }
+}
+
+impl Plugin for WindowBrandingPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(WindowBranding {
+            app_name: self.app_name.clone(),
+            template: self.template.clone(),
+            icon: self.icon.clone(),
+        })
+        .init_resource::<AnyScreenActive>()
+        .add_systems(PostUpdate, update_window_title);
+
+        #[cfg(all(feature = "window-icon", not(target_arch = "wasm32")))]
+        app.add_systems(PostUpdate, icon::apply_window_icon);
+    }
+}
+
+/// Resource with the window branding, can be changed at runtime
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct WindowBranding {
+    pub app_name: String,
+    /// Title with `{app}` and `{screen}` placeholders
+    pub template: String,
+    /// Asset path of the window icon
+    pub icon: Option<String>,
+}
+
+impl WindowBranding {
+    /// Window title while the given screen is on top
+    pub fn title(&self, screen: Option<&str>) -> String {
+        match screen.filter(|screen| !screen.is_empty()) {
+            Some(screen) => self
+                .template
+                .replace("{app}", &self.app_name)
+                .replace("{screen}", screen),
+            None => self.app_name.clone(),
+        }
+    }
+}
+
+/// Component overriding the name of a screen in the window title
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct ScreenTitle(pub String);
+
+/// System to set the window title when the top screen changes
+#[allow(clippy::type_complexity)]
+fn update_window_title(
+    branding: Res<WindowBranding>,
+    screens: Res<AnyScreenActive>,
+    titles: Query<(Option<&ScreenTitle>, Option<&Name>)>,
+    changed_titles: Query<(), Or<(Changed<ScreenTitle>, Changed<Name>)>>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let top = screens.top();
+    let top_changed = top.is_some_and(|entity| changed_titles.contains(entity));
+    if !branding.is_changed() && !screens.is_changed() && !top_changed {
+        return;
+    }
+
+    let screen = top.and_then(|entity| match titles.get(entity) {
+        Ok((Some(title), _)) => Some(title.0.clone()),
+        Ok((None, Some(name))) => {
+            let name = name.as_str();
+            Some(name.strip_suffix(" Screen").unwrap_or(name).to_string())
+        }
+        _ => None,
+    });
+    let title = branding.title(screen.as_deref());
+
+    for mut window in windows.iter_mut() {
+        if window.title != title {
+            window.title = title.clone();
+        }
+    }
+}
+
+#[cfg(all(feature = "window-icon", not(target_arch = "wasm32")))]
+mod icon {
+    use super::WindowBranding;
+    use bevy::{
+        prelude::*, render::render_resource::TextureFormat, window::PrimaryWindow,
+        winit::WINIT_WINDOWS,
+    };
+
+    /// Icon image requested from the asset server
+    pub struct LoadingIcon {
+        path: String,
+        handle: Handle<Image>,
+        applied: bool,
+    }
+
+    /// System to load the icon image and hand it to winit once the window
+    /// exists
+    pub fn apply_window_icon(
+        branding: Res<WindowBranding>,
+        asset_server: Res<AssetServer>,
+        images: Res<Assets<Image>>,
+        windows: Query<Entity, With<PrimaryWindow>>,
+        mut loading: Local<Option<LoadingIcon>>,
+    ) {
+        let Some(path) = &branding.icon else {
+            return;
+        };
+        if loading.as_ref().is_none_or(|loading| loading.path != *path) {
+            *loading = Some(LoadingIcon {
+                path: path.clone(),
+                handle: asset_server.load(path.clone()),
+                applied: false,
+            });
+        }
+        let Some(LoadingIcon {
+            path,
+            handle,
+            applied,
+        }) = loading.as_mut()
+        else {
+            return;
+        };
+        if *applied {
+            return;
+        }
+        let (Some(image), Ok(window)) = (images.get(&*handle), windows.single()) else {
+            return;
+        };
+
+        let size = image.size();
+        let Some(rgba) = image
+            .convert(TextureFormat::Rgba8UnormSrgb)
+            .and_then(|image| image.data)
+        else {
+            warn!("Window icon {} can not be converted to RGBA", path);
+            *applied = true;
+            return;
+        };
+        let winit_icon = match winit::window::Icon::from_rgba(rgba, size.x, size.y) {
+            Ok(winit_icon) => winit_icon,
+            Err(e) => {
+                warn!("Invalid window icon {}: {}", path, e);
+                *applied = true;
+                return;
+            }
+        };
+
+        WINIT_WINDOWS.with_borrow(|winit_windows| {
+            // The window is created by winit after the entity is spawned
+            if let Some(winit_window) = winit_windows.get_window(window) {
+                winit_window.set_window_icon(Some(winit_icon));
+                *applied = true;
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        screens::{MainMenuConfig, MainMenuPlugin, MainMenuScreenExt},
+        test_utils::TestHarness,
+        theme::KonnektorenThemePlugin,
+        ui::UIPlugin,
+    };
+
+    #[test]
+    fn test_window_title_follows_screen() {
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            MainMenuPlugin,
+            WindowBrandingPlugin::new("Konnektoren"),
+        ));
+        let window = harness
+            .world_mut()
+            .query_filtered::<Entity, With<Window>>()
+            .single(harness.world())
+            .unwrap();
+        harness.world_mut().entity_mut(window).insert(PrimaryWindow);
+        let title =
+            |harness: &TestHarness| harness.world().get::<Window>(window).unwrap().title.clone();
+
+        harness.step();
+        assert_eq!(title(&harness), "Konnektoren");
+
+        let menu = harness
+            .world_mut()
+            .commands()
+            .spawn_main_menu(MainMenuConfig::new("Konnektoren"));
+        harness.run_frames(2);
+        assert_eq!(title(&harness), "Konnektoren — Main Menu");
+
+        harness
+            .world_mut()
+            .entity_mut(menu)
+            .insert(ScreenTitle("Start".to_string()));
+        harness.step();
+        assert_eq!(title(&harness), "Konnektoren — Start");
+    }
+}
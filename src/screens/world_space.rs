@@ -0,0 +1,256 @@
+use bevy::{
+    camera::{visibility::RenderLayers, RenderTarget},
+    ecs::{
+        lifecycle::HookContext, schedule::ScheduleLabel, system::SystemParam, world::DeferredWorld,
+    },
+    image::BevyDefault,
+    prelude::*,
+    render::render_resource::TextureFormat,
+};
+use bevy_egui::{egui, BevyEguiEntityCommandsExt, EguiContexts, EguiMultipassSchedule};
+use std::collections::HashMap;
+
+/// Plugin rendering screens with a [`WorldSpaceScreen`] onto quads in the world.
+///
+/// Each world-space screen gets its own egui context on a camera rendering
+/// into a texture, which is shown on an unlit quad. Pointer input on the
+/// quad is forwarded to the context. Screens render into it through
+/// [`ScreenContexts`] from the [`WorldScreenContextPass`].
+pub struct WorldSpaceScreenPlugin;
+
+impl Plugin for WorldSpaceScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WorldSpaceScreens>()
+            .init_resource::<WorldScreenPassTarget>()
+            .init_schedule(WorldScreenContextPass)
+            .add_systems(PostUpdate, setup_world_space_screens);
+    }
+}
+
+/// Schedule the render systems of screens run in for every world-space
+/// screen, next to `EguiPrimaryContextPass`
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WorldScreenContextPass;
+
+/// Egui pass of the context of one world-space screen
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+struct WorldScreenPass(Entity);
+
+/// Renders the screen on the same entity into a texture on a quad in the
+/// world instead of the window, e.g. a settings panel on a tablet prop.
+///
+/// ```ignore
+/// commands.spawn((
+///     AboutConfig::new("My Game"),
+///     WorldSpaceScreen::new(UVec2::new(1024, 768), Vec2::new(1.6, 1.2))
+///         .on_surface(blackboard)
+///         .with_transform(Transform::from_xyz(0.0, 0.0, 0.01)),
+/// ));
+/// ```
+#[derive(Component, Debug, Clone)]
+#[component(on_remove = remove_world_space_screen)]
+pub struct WorldSpaceScreen {
+    /// Resolution of the texture in pixels
+    pub resolution: UVec2,
+    /// Size of the quad in world units
+    pub size: Vec2,
+    /// Entity the quad is a child of, e.g. a blackboard
+    pub surface: Option<Entity>,
+    /// Transform of the quad, relative to the surface if any
+    pub transform: Transform,
+}
+
+impl WorldSpaceScreen {
+    pub fn new(resolution: UVec2, size: Vec2) -> Self {
+        Self {
+            resolution,
+            size,
+            surface: None,
+            transform: Transform::default(),
+        }
+    }
+
+    pub fn on_surface(mut self, surface: Entity) -> Self {
+        self.surface = Some(surface);
+        self
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+}
+
+/// Entities and texture of a world-space screen
+#[derive(Debug, Clone)]
+pub struct WorldSpaceScreenParts {
+    /// Camera with the egui context of the screen
+    pub camera: Entity,
+    /// Quad showing the texture, `None` without the PBR plugin
+    pub quad: Option<Entity>,
+    /// Texture the screen is rendered into, e.g. for a custom material
+    pub image: Handle<Image>,
+}
+
+/// Resource with the parts of the world-space screens by screen entity
+#[derive(Resource, Debug, Default)]
+pub struct WorldSpaceScreens {
+    screens: HashMap<Entity, WorldSpaceScreenParts>,
+}
+
+impl WorldSpaceScreens {
+    pub fn get(&self, screen: Entity) -> Option<&WorldSpaceScreenParts> {
+        self.screens.get(&screen)
+    }
+
+    pub fn contains(&self, screen: Entity) -> bool {
+        self.screens.contains_key(&screen)
+    }
+}
+
+/// Screen whose world-space pass is running, `None` in the primary pass
+#[derive(Resource, Debug, Default)]
+struct WorldScreenPassTarget(Option<Entity>);
+
+/// Egui contexts of screens, the window or their world-space texture.
+///
+/// Render systems of screens supporting [`WorldSpaceScreen`] run in both the
+/// `EguiPrimaryContextPass` and the [`WorldScreenContextPass`] and draw into
+/// the context returned for their screen entity:
+///
+/// ```ignore
+/// let Some((entity, screen)) = query.iter().find(|(entity, _)| contexts.renders(*entity)) else {
+///     return;
+/// };
+/// let Some(ctx) = contexts.ctx_for(entity) else {
+///     return;
+/// };
+/// ```
+#[derive(SystemParam)]
+pub struct ScreenContexts<'w, 's> {
+    contexts: EguiContexts<'w, 's>,
+    target: Option<Res<'w, WorldScreenPassTarget>>,
+    world_screens: Option<Res<'w, WorldSpaceScreens>>,
+}
+
+impl ScreenContexts<'_, '_> {
+    /// Whether the screen is rendered in the current pass
+    pub fn renders(&self, screen: Entity) -> bool {
+        match self.target.as_ref().and_then(|target| target.0) {
+            Some(target) => target == screen,
+            None => !self
+                .world_screens
+                .as_ref()
+                .is_some_and(|world_screens| world_screens.contains(screen)),
+        }
+    }
+
+    /// Context to render the screen into in the current pass
+    pub fn ctx_for(&mut self, screen: Entity) -> Option<&mut egui::Context> {
+        if !self.renders(screen) {
+            return None;
+        }
+
+        let camera = self
+            .world_screens
+            .as_ref()
+            .and_then(|world_screens| world_screens.get(screen))
+            .map(|parts| parts.camera);
+        match camera {
+            Some(camera) => self.contexts.ctx_for_entity_mut(camera).ok(),
+            None => self.contexts.ctx_mut().ok(),
+        }
+    }
+}
+
+/// System to create the camera, texture and quad of new world-space screens
+#[allow(clippy::too_many_arguments)]
+fn setup_world_space_screens(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+    mut schedules: ResMut<Schedules>,
+    mut world_screens: ResMut<WorldSpaceScreens>,
+    query: Query<(Entity, &WorldSpaceScreen), Added<WorldSpaceScreen>>,
+) {
+    let (mut meshes, mut materials) = (meshes, materials);
+
+    for (screen, world_screen) in query.iter() {
+        info!("Setting up world-space screen for entity {:?}", screen);
+
+        let image = images.add(Image::new_target_texture(
+            world_screen.resolution.x.max(1),
+            world_screen.resolution.y.max(1),
+            TextureFormat::bevy_default(),
+        ));
+
+        schedules.add_systems(WorldScreenPass(screen), move |world: &mut World| {
+            world.resource_mut::<WorldScreenPassTarget>().0 = Some(screen);
+            let _ = world.try_run_schedule(WorldScreenContextPass);
+            world.resource_mut::<WorldScreenPassTarget>().0 = None;
+        });
+
+        let camera = commands
+            .spawn((
+                Name::new("World-Space Screen Camera"),
+                Camera2d,
+                Camera {
+                    // Render the texture before the cameras showing it
+                    order: -1,
+                    target: RenderTarget::Image(image.clone().into()),
+                    clear_color: ClearColorConfig::Custom(Color::NONE),
+                    ..default()
+                },
+                RenderLayers::none(),
+                EguiMultipassSchedule::new(WorldScreenPass(screen)),
+            ))
+            .id();
+
+        let quad = match (meshes.as_mut(), materials.as_mut()) {
+            (Some(meshes), Some(materials)) => {
+                let mut quad = commands.spawn((
+                    Name::new("World-Space Screen"),
+                    Mesh3d(meshes.add(Rectangle::from_size(world_screen.size))),
+                    MeshMaterial3d(materials.add(StandardMaterial {
+                        base_color_texture: Some(image.clone()),
+                        alpha_mode: AlphaMode::Blend,
+                        unlit: true,
+                        ..default()
+                    })),
+                    world_screen.transform,
+                ));
+                quad.add_picking_observers_for_context(camera);
+                if let Some(surface) = world_screen.surface {
+                    quad.insert(ChildOf(surface));
+                }
+                Some(quad.id())
+            }
+            _ => None,
+        };
+
+        world_screens.screens.insert(
+            screen,
+            WorldSpaceScreenParts {
+                camera,
+                quad,
+                image,
+            },
+        );
+    }
+}
+
+fn remove_world_space_screen(mut world: DeferredWorld, context: HookContext) {
+    let Some(parts) = world
+        .get_resource_mut::<WorldSpaceScreens>()
+        .and_then(|mut world_screens| world_screens.screens.remove(&context.entity))
+    else {
+        return;
+    };
+
+    let mut commands = world.commands();
+    commands.entity(parts.camera).try_despawn();
+    if let Some(quad) = parts.quad {
+        commands.entity(quad).try_despawn();
+    }
+}
@@ -0,0 +1,56 @@
+use super::components::*;
+use crate::theme::{
+    AccessibilityOptions, ColorAssistance, ColorVisionMode, COLOR_VISION_SETTING_ID,
+    DYSLEXIA_FRIENDLY_SETTING_ID, HIGH_VISIBILITY_SETTING_ID, SHAPE_CUES_SETTING_ID,
+};
+use bevy::prelude::*;
+
+/// Plugin that applies the appearance settings to the [`ColorAssistance`]
+/// and [`AccessibilityOptions`] of the theme.
+pub struct AppearanceSettingsPlugin;
+
+impl Plugin for AppearanceSettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ColorAssistance>()
+            .init_resource::<AccessibilityOptions>()
+            .add_message::<SettingChangedEvent>()
+            .add_systems(Update, apply_appearance_settings);
+    }
+}
+
+/// System to update the color assistance and accessibility options from
+/// setting changes
+pub fn apply_appearance_settings(
+    mut setting_events: MessageReader<SettingChangedEvent>,
+    mut assistance: ResMut<ColorAssistance>,
+    mut options: ResMut<AccessibilityOptions>,
+) {
+    for event in setting_events.read() {
+        if event.is(COLOR_VISION_SETTING_ID) {
+            if let Some(index) = event.new_value.as_selection() {
+                let mode = ColorVisionMode::from_index(index);
+                if assistance.mode != mode {
+                    assistance.mode = mode;
+                }
+            }
+        } else if event.is(SHAPE_CUES_SETTING_ID) {
+            if let Some(value) = event.new_value.as_bool() {
+                if assistance.shape_cues != value {
+                    assistance.shape_cues = value;
+                }
+            }
+        } else if event.is(HIGH_VISIBILITY_SETTING_ID) {
+            if let Some(value) = event.new_value.as_bool() {
+                if options.high_visibility != value {
+                    options.high_visibility = value;
+                }
+            }
+        } else if event.is(DYSLEXIA_FRIENDLY_SETTING_ID) {
+            if let Some(value) = event.new_value.as_bool() {
+                if options.dyslexia_friendly != value {
+                    options.dyslexia_friendly = value;
+                }
+            }
+        }
+    }
+}
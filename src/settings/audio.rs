@@ -0,0 +1,140 @@
+use super::{
+    builders::{
+        AUDIO_ENABLED_SETTING_ID, MASTER_VOLUME_SETTING_ID, MUSIC_VOLUME_SETTING_ID,
+        SFX_VOLUME_SETTING_ID,
+    },
+    observers::SettingsAppExt,
+};
+use crate::lifecycle::AppLifecycleState;
+use bevy::{audio::Volume, prelude::*};
+
+/// Plugin that applies the audio settings to `bevy_audio`.
+///
+/// The master volume sets the [`GlobalVolume`] of new sounds. Playing sounds
+/// tagged with an [`AudioChannel`] follow the master and channel volume at
+/// once. Disabling audio, or [`AppLifecycleState::is_muted`], mutes all
+/// sounds.
+///
+/// Added by the [`SettingsPlugin`](super::SettingsPlugin) when the `audio`
+/// feature is enabled:
+///
+/// ```ignore
+/// app.add_plugins(SettingsPlugin);
+///
+/// commands.spawn((
+///     AudioPlayer::new(asset_server.load("music/theme.ogg")),
+///     PlaybackSettings::LOOP,
+///     AudioChannel::Music,
+/// ));
+/// ```
+pub struct AudioSettingsPlugin;
+
+impl Plugin for AudioSettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AudioSettings>()
+            .init_resource::<GlobalVolume>()
+            .bind_setting::<AudioSettings>(MASTER_VOLUME_SETTING_ID, |settings, value| {
+                if let Some(volume) = value.as_float() {
+                    settings.master_volume = volume.clamp(0.0, 1.0);
+                }
+            })
+            .bind_setting::<AudioSettings>(MUSIC_VOLUME_SETTING_ID, |settings, value| {
+                if let Some(volume) = value.as_float() {
+                    settings.music_volume = volume.clamp(0.0, 1.0);
+                }
+            })
+            .bind_setting::<AudioSettings>(SFX_VOLUME_SETTING_ID, |settings, value| {
+                if let Some(volume) = value.as_float() {
+                    settings.sfx_volume = volume.clamp(0.0, 1.0);
+                }
+            })
+            .bind_setting::<AudioSettings>(AUDIO_ENABLED_SETTING_ID, |settings, value| {
+                if let Some(enabled) = value.as_bool() {
+                    settings.enabled = enabled;
+                }
+            })
+            // After the settings were bound in the update
+            .add_systems(PostUpdate, apply_audio_settings);
+    }
+}
+
+/// Channel of a sound, its volume is the master volume times the volume of
+/// the channel
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AudioChannel {
+    Music,
+    #[default]
+    Sfx,
+}
+
+/// Audio settings, updated from the settings of the audio category
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct AudioSettings {
+    pub master_volume: f32,
+    pub music_volume: f32,
+    pub sfx_volume: f32,
+    pub enabled: bool,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            master_volume: 1.0,
+            music_volume: 0.8,
+            sfx_volume: 1.0,
+            enabled: true,
+        }
+    }
+}
+
+impl AudioSettings {
+    /// Volume of a channel without the master volume
+    pub fn channel_volume(&self, channel: AudioChannel) -> f32 {
+        match channel {
+            AudioChannel::Music => self.music_volume,
+            AudioChannel::Sfx => self.sfx_volume,
+        }
+    }
+}
+
+/// System to apply the audio settings to the global volume and the playing
+/// sounds
+pub fn apply_audio_settings(
+    settings: Res<AudioSettings>,
+    lifecycle: Option<Res<AppLifecycleState>>,
+    mut global_volume: ResMut<GlobalVolume>,
+    mut sinks: Query<(&mut AudioSink, Option<&AudioChannel>)>,
+) {
+    let muted = !settings.enabled
+        || lifecycle
+            .as_ref()
+            .is_some_and(|lifecycle| lifecycle.is_muted());
+    let changed = settings.is_changed()
+        || lifecycle
+            .as_ref()
+            .is_some_and(|lifecycle| lifecycle.is_changed());
+
+    if changed {
+        let volume = Volume::Linear(if muted { 0.0 } else { settings.master_volume });
+        if global_volume.volume != volume {
+            global_volume.volume = volume;
+        }
+    }
+
+    for (mut sink, channel) in &mut sinks {
+        // Sounds started since the last run still have to be muted
+        if !changed && !sink.is_added() {
+            continue;
+        }
+        if let Some(channel) = channel {
+            sink.set_volume(Volume::Linear(
+                settings.master_volume * settings.channel_volume(*channel),
+            ));
+        }
+        if muted {
+            sink.mute();
+        } else {
+            sink.unmute();
+        }
+    }
+}
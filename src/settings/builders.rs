@@ -1,6 +1,20 @@
 use super::components::*;
+use super::display::{FULLSCREEN_SETTING_ID, POINTER_LOCK_SETTING_ID};
+use super::performance::VSYNC_SETTING_ID;
 use bevy::prelude::*;
 
+/// Setting ID of the master volume of the audio category
+pub const MASTER_VOLUME_SETTING_ID: &str = "master_volume";
+
+/// Setting ID of the music volume of the audio category
+pub const MUSIC_VOLUME_SETTING_ID: &str = "music_volume";
+
+/// Setting ID of the sound effects volume of the audio category
+pub const SFX_VOLUME_SETTING_ID: &str = "sfx_volume";
+
+/// Setting ID of the audio toggle of the audio category
+pub const AUDIO_ENABLED_SETTING_ID: &str = "audio_enabled";
+
 /// Resource that provides default settings configurations
 #[derive(Resource, Default)]
 pub struct SettingsRegistry {
@@ -34,6 +48,7 @@ impl SettingsRegistry {
 
     pub fn add_category(mut self, category: SettingsCategory) -> Self {
         self.categories.push(category);
+        drop_graphics_vsync(&mut self.categories);
         self
     }
 
@@ -45,7 +60,7 @@ impl SettingsRegistry {
             description: Some("Audio and sound settings".to_string()),
             settings: vec![
                 SettingDefinition {
-                    id: "master_volume".to_string(),
+                    id: MASTER_VOLUME_SETTING_ID.to_string(),
                     label: "Master Volume".to_string(),
                     description: Some("Overall audio volume".to_string()),
                     default_value: SettingValue::Float(1.0),
@@ -57,7 +72,7 @@ impl SettingsRegistry {
                     tab_index: Some(0),
                 },
                 SettingDefinition {
-                    id: "music_volume".to_string(),
+                    id: MUSIC_VOLUME_SETTING_ID.to_string(),
                     label: "Music Volume".to_string(),
                     description: Some("Background music volume".to_string()),
                     default_value: SettingValue::Float(0.8),
@@ -69,7 +84,7 @@ impl SettingsRegistry {
                     tab_index: Some(1),
                 },
                 SettingDefinition {
-                    id: "sfx_volume".to_string(),
+                    id: SFX_VOLUME_SETTING_ID.to_string(),
                     label: "Sound Effects".to_string(),
                     description: Some("Sound effects volume".to_string()),
                     default_value: SettingValue::Float(1.0),
@@ -81,7 +96,7 @@ impl SettingsRegistry {
                     tab_index: Some(2),
                 },
                 SettingDefinition {
-                    id: "audio_enabled".to_string(),
+                    id: AUDIO_ENABLED_SETTING_ID.to_string(),
                     label: "Enable Audio".to_string(),
                     description: Some("Enable or disable all audio".to_string()),
                     default_value: SettingValue::Bool(true),
@@ -100,7 +115,7 @@ impl SettingsRegistry {
             description: Some("Visual and display settings".to_string()),
             settings: vec![
                 SettingDefinition {
-                    id: "vsync".to_string(),
+                    id: VSYNC_SETTING_ID.to_string(),
                     label: "V-Sync".to_string(),
                     description: Some("Vertical synchronization".to_string()),
                     default_value: SettingValue::Bool(true),
@@ -126,6 +141,194 @@ impl SettingsRegistry {
         }
     }
 
+    /// Create default display settings category
+    pub fn display_category() -> SettingsCategory {
+        SettingsCategory {
+            name: "display".to_string(),
+            display_name: "Display".to_string(),
+            description: Some("Fullscreen and pointer settings".to_string()),
+            settings: vec![
+                SettingDefinition {
+                    id: FULLSCREEN_SETTING_ID.to_string(),
+                    label: "Fullscreen".to_string(),
+                    description: Some("Use the whole screen".to_string()),
+                    default_value: SettingValue::Bool(false),
+                    setting_type: SettingType::Toggle,
+                    tab_index: Some(0),
+                },
+                SettingDefinition {
+                    id: POINTER_LOCK_SETTING_ID.to_string(),
+                    label: "Lock Pointer".to_string(),
+                    description: Some("Keep the pointer inside the game".to_string()),
+                    default_value: SettingValue::Bool(false),
+                    setting_type: SettingType::Toggle,
+                    tab_index: Some(1),
+                },
+            ],
+        }
+    }
+
+    /// Create performance settings category, applied by `PerformanceSettingsPlugin`.
+    ///
+    /// It has its own V-Sync toggle, added together with the graphics
+    /// category the graphics one is dropped.
+    pub fn performance_category() -> SettingsCategory {
+        use super::performance::*;
+
+        SettingsCategory {
+            name: "performance".to_string(),
+            display_name: "Performance".to_string(),
+            description: Some("Frame rate and rendering quality".to_string()),
+            settings: vec![
+                SettingDefinition {
+                    id: FPS_CAP_SETTING_ID.to_string(),
+                    label: "Frame Rate Cap".to_string(),
+                    description: Some("Lower caps save battery".to_string()),
+                    default_value: SettingValue::Selection(0),
+                    setting_type: SettingType::Selection {
+                        options: fps_cap_labels(),
+                    },
+                    tab_index: Some(0),
+                },
+                SettingDefinition {
+                    id: VSYNC_SETTING_ID.to_string(),
+                    label: "V-Sync".to_string(),
+                    description: Some("Vertical synchronization".to_string()),
+                    default_value: SettingValue::Bool(true),
+                    setting_type: SettingType::Toggle,
+                    tab_index: Some(1),
+                },
+                SettingDefinition {
+                    id: RENDER_SCALE_SETTING_ID.to_string(),
+                    label: "Render Scale".to_string(),
+                    description: Some("Render at a lower resolution on slow devices".to_string()),
+                    default_value: SettingValue::Float(1.0),
+                    setting_type: SettingType::FloatRange {
+                        min: 0.5,
+                        max: 1.0,
+                        step: 0.05,
+                    },
+                    tab_index: Some(2),
+                },
+            ],
+        }
+    }
+
+    /// Create default appearance settings category, applied by `AppearanceSettingsPlugin`
+    #[cfg(feature = "theme")]
+    pub fn appearance_category() -> SettingsCategory {
+        use crate::theme::{
+            ColorVisionMode, COLOR_VISION_SETTING_ID, DYSLEXIA_FRIENDLY_SETTING_ID,
+            HIGH_VISIBILITY_SETTING_ID, SHAPE_CUES_SETTING_ID,
+        };
+
+        SettingsCategory {
+            name: "appearance".to_string(),
+            display_name: "Appearance".to_string(),
+            description: Some("Color and readability settings".to_string()),
+            settings: vec![
+                SettingDefinition {
+                    id: COLOR_VISION_SETTING_ID.to_string(),
+                    label: "Color Vision".to_string(),
+                    description: Some("Adapt colors to color vision deficiencies".to_string()),
+                    default_value: SettingValue::Selection(0),
+                    setting_type: SettingType::Selection {
+                        options: ColorVisionMode::ALL
+                            .iter()
+                            .map(|mode| mode.name().to_string())
+                            .collect(),
+                    },
+                    tab_index: Some(0),
+                },
+                SettingDefinition {
+                    id: SHAPE_CUES_SETTING_ID.to_string(),
+                    label: "Shape Cues".to_string(),
+                    description: Some(
+                        "Show symbols and patterns in addition to colors".to_string(),
+                    ),
+                    default_value: SettingValue::Bool(false),
+                    setting_type: SettingType::Toggle,
+                    tab_index: Some(1),
+                },
+                SettingDefinition {
+                    id: HIGH_VISIBILITY_SETTING_ID.to_string(),
+                    label: "High Visibility".to_string(),
+                    description: Some("Larger buttons and thicker focus outlines".to_string()),
+                    default_value: SettingValue::Bool(false),
+                    setting_type: SettingType::Toggle,
+                    tab_index: Some(2),
+                },
+                SettingDefinition {
+                    id: DYSLEXIA_FRIENDLY_SETTING_ID.to_string(),
+                    label: "Dyslexia-Friendly Text".to_string(),
+                    description: Some(
+                        "Easier to read font with wider letter and line spacing".to_string(),
+                    ),
+                    default_value: SettingValue::Bool(false),
+                    setting_type: SettingType::Toggle,
+                    tab_index: Some(3),
+                },
+            ],
+        }
+    }
+
+    /// Create parental controls settings category, applied by `ParentalControlsPlugin`
+    pub fn parental_controls_category() -> SettingsCategory {
+        use super::parental::*;
+
+        SettingsCategory {
+            name: "parental_controls".to_string(),
+            display_name: "Parental Controls".to_string(),
+            description: Some("Restrictions for young players".to_string()),
+            settings: vec![
+                SettingDefinition {
+                    id: PARENTAL_CONTROLS_SETTING_ID.to_string(),
+                    label: "Parental Controls".to_string(),
+                    description: Some("Enable the restrictions below".to_string()),
+                    default_value: SettingValue::Bool(false),
+                    setting_type: SettingType::Toggle,
+                    tab_index: Some(0),
+                },
+                SettingDefinition {
+                    id: PARENTAL_PIN_SETTING_ID.to_string(),
+                    label: "PIN".to_string(),
+                    description: Some("Required to open the settings".to_string()),
+                    default_value: SettingValue::String(String::new()),
+                    setting_type: SettingType::Text {
+                        max_length: Some(PARENTAL_PIN_MAX_LENGTH),
+                    },
+                    tab_index: Some(1),
+                },
+                SettingDefinition {
+                    id: BLOCK_EXTERNAL_LINKS_SETTING_ID.to_string(),
+                    label: "Block External Links".to_string(),
+                    description: Some("Links to websites can't be opened".to_string()),
+                    default_value: SettingValue::Bool(true),
+                    setting_type: SettingType::Toggle,
+                    tab_index: Some(2),
+                },
+                SettingDefinition {
+                    id: BLOCK_SHARING_SETTING_ID.to_string(),
+                    label: "Block Chat and Sharing".to_string(),
+                    description: None,
+                    default_value: SettingValue::Bool(true),
+                    setting_type: SettingType::Toggle,
+                    tab_index: Some(3),
+                },
+                SettingDefinition {
+                    id: SESSION_TIME_LIMIT_SETTING_ID.to_string(),
+                    label: "Session Time Limit".to_string(),
+                    description: Some("Remind to take a break after playing".to_string()),
+                    default_value: SettingValue::Selection(0),
+                    setting_type: SettingType::Selection {
+                        options: session_time_limit_labels(),
+                    },
+                    tab_index: Some(4),
+                },
+            ],
+        }
+    }
+
     /// Create a complete game settings registry
     pub fn game_settings() -> Self {
         Self::new()
@@ -134,6 +337,26 @@ impl SettingsRegistry {
     }
 }
 
+/// Drop the V-Sync toggle of the graphics category when the performance
+/// category is used as well, so there is only one `vsync` setting
+fn drop_graphics_vsync(categories: &mut [SettingsCategory]) {
+    if !categories
+        .iter()
+        .any(|category| category.name == "performance")
+    {
+        return;
+    }
+
+    for category in categories
+        .iter_mut()
+        .filter(|category| category.name == "graphics")
+    {
+        category
+            .settings
+            .retain(|setting| setting.id != VSYNC_SETTING_ID);
+    }
+}
+
 /// Builder for creating settings entities
 #[derive(Default)]
 pub struct SettingsBuilder {
@@ -147,6 +370,7 @@ impl SettingsBuilder {
 
     pub fn add_category(mut self, category: SettingsCategory) -> Self {
         self.categories.push(category);
+        drop_graphics_vsync(&mut self.categories);
         self
     }
 
@@ -158,6 +382,25 @@ impl SettingsBuilder {
         self.add_category(SettingsRegistry::graphics_category())
     }
 
+    pub fn with_display_settings(self) -> Self {
+        self.add_category(SettingsRegistry::display_category())
+    }
+
+    /// Performance settings, their V-Sync toggle replaces the one of the
+    /// graphics settings
+    pub fn with_performance_settings(self) -> Self {
+        self.add_category(SettingsRegistry::performance_category())
+    }
+
+    #[cfg(feature = "theme")]
+    pub fn with_appearance_settings(self) -> Self {
+        self.add_category(SettingsRegistry::appearance_category())
+    }
+
+    pub fn with_parental_controls(self) -> Self {
+        self.add_category(SettingsRegistry::parental_controls_category())
+    }
+
     /// Spawn setting entities from this builder
     pub fn spawn_settings(self, commands: &mut Commands) -> Vec<Entity> {
         let mut entities = Vec::new();
@@ -201,6 +444,12 @@ pub trait SettingsExt {
     /// Spawn basic graphics settings
     fn spawn_graphics_settings(&mut self) -> Vec<Entity>;
 
+    /// Spawn display settings (fullscreen, pointer lock)
+    fn spawn_display_settings(&mut self) -> Vec<Entity>;
+
+    /// Spawn performance settings (frame rate cap, V-Sync, render scale)
+    fn spawn_performance_settings(&mut self) -> Vec<Entity>;
+
     /// Spawn complete game settings
     fn spawn_game_settings(&mut self) -> Vec<Entity>;
 }
@@ -218,6 +467,18 @@ impl SettingsExt for Commands<'_, '_> {
             .spawn_settings(self)
     }
 
+    fn spawn_display_settings(&mut self) -> Vec<Entity> {
+        SettingsBuilder::new()
+            .with_display_settings()
+            .spawn_settings(self)
+    }
+
+    fn spawn_performance_settings(&mut self) -> Vec<Entity> {
+        SettingsBuilder::new()
+            .with_performance_settings()
+            .spawn_settings(self)
+    }
+
     fn spawn_game_settings(&mut self) -> Vec<Entity> {
         SettingsBuilder::new()
             .with_audio_settings()
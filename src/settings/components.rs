@@ -1,3 +1,5 @@
+use super::id::SettingId;
+use super::index::{index_setting, unindex_setting};
 use bevy::prelude::*;
 
 /// Event sent when a setting value changes
@@ -8,8 +10,16 @@ pub struct SettingChangedEvent {
     pub new_value: SettingValue,
 }
 
+impl SettingChangedEvent {
+    /// Whether the event is about the given setting
+    pub fn is(&self, setting_id: impl SettingId) -> bool {
+        self.setting_id == setting_id.as_setting_id()
+    }
+}
+
 /// Component that defines a setting
 #[derive(Component, Debug, Clone)]
+#[component(on_insert = index_setting, on_replace = unindex_setting)]
 pub struct Setting {
     pub id: String,
     pub label: String,
@@ -63,6 +73,7 @@ impl Setting {
 
 /// Different types of setting values
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
 pub enum SettingValue {
     Bool(bool),
     Int(i32),
@@ -0,0 +1,277 @@
+use super::components::*;
+use super::index::SettingsIndex;
+use bevy::prelude::*;
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::window::{CursorGrabMode, CursorOptions, MonitorSelection, PrimaryWindow, WindowMode};
+
+/// Setting ID of the fullscreen toggle
+pub const FULLSCREEN_SETTING_ID: &str = "fullscreen";
+
+/// Setting ID of the pointer lock toggle
+pub const POINTER_LOCK_SETTING_ID: &str = "pointer_lock";
+
+/// Plugin that applies the display settings to the window.
+///
+/// On WASM the fullscreen toggle requests or exits browser fullscreen and the
+/// pointer lock toggle locks the pointer to the page. On native the window mode
+/// and cursor grab are changed instead. When the platform leaves fullscreen on
+/// its own, e.g. because the user pressed Esc, the settings are updated to match.
+pub struct DisplaySettingsPlugin;
+
+impl Plugin for DisplaySettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DisplayState>()
+            .init_resource::<SettingsIndex>()
+            .add_message::<SettingChangedEvent>()
+            .add_message::<DisplayModeChanged>()
+            .add_systems(
+                Update,
+                (
+                    request_display_from_settings,
+                    apply_display_state,
+                    sync_display_state,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Requested and observed display state
+#[derive(Resource, Debug, Clone, Default)]
+pub struct DisplayState {
+    /// Whether fullscreen is requested
+    pub fullscreen: bool,
+    /// Whether pointer lock is requested
+    pub pointer_lock: bool,
+    observed_fullscreen: bool,
+    observed_pointer_lock: bool,
+}
+
+impl DisplayState {
+    /// Whether the window or browser is currently in fullscreen
+    pub fn is_fullscreen(&self) -> bool {
+        self.observed_fullscreen
+    }
+
+    /// Whether the pointer is currently locked
+    pub fn is_pointer_locked(&self) -> bool {
+        self.observed_pointer_lock
+    }
+}
+
+/// Event sent when the platform changed the display mode on its own
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayModeChanged {
+    pub fullscreen: bool,
+    pub pointer_lock: bool,
+}
+
+/// System to request display changes from setting changes
+pub fn request_display_from_settings(
+    mut setting_events: MessageReader<SettingChangedEvent>,
+    mut state: ResMut<DisplayState>,
+) {
+    for event in setting_events.read() {
+        let Some(value) = event.new_value.as_bool() else {
+            continue;
+        };
+
+        if event.setting_id == FULLSCREEN_SETTING_ID && state.fullscreen != value {
+            state.fullscreen = value;
+        } else if event.setting_id == POINTER_LOCK_SETTING_ID && state.pointer_lock != value {
+            state.pointer_lock = value;
+        }
+    }
+}
+
+/// System to apply the requested display state to the platform
+#[cfg(not(target_arch = "wasm32"))]
+pub fn apply_display_state(
+    state: Res<DisplayState>,
+    mut windows: Query<(&mut Window, Option<&mut CursorOptions>), With<PrimaryWindow>>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+
+    let Ok((mut window, cursor_options)) = windows.single_mut() else {
+        return;
+    };
+
+    if state.fullscreen != state.observed_fullscreen {
+        info!("Setting fullscreen to {}", state.fullscreen);
+        window.mode = if state.fullscreen {
+            WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+        } else {
+            WindowMode::Windowed
+        };
+    }
+
+    if state.pointer_lock != state.observed_pointer_lock {
+        if let Some(mut cursor_options) = cursor_options {
+            info!("Setting pointer lock to {}", state.pointer_lock);
+            if state.pointer_lock {
+                cursor_options.grab_mode = CursorGrabMode::Locked;
+                cursor_options.visible = false;
+            } else {
+                cursor_options.grab_mode = CursorGrabMode::None;
+                cursor_options.visible = true;
+            }
+        }
+    }
+}
+
+/// System to apply the requested display state to the platform
+#[cfg(target_arch = "wasm32")]
+pub fn apply_display_state(state: Res<DisplayState>) {
+    if !state.is_changed() {
+        return;
+    }
+
+    if state.fullscreen != state.observed_fullscreen {
+        info!("Setting browser fullscreen to {}", state.fullscreen);
+        if let Err(err) = browser::set_fullscreen(state.fullscreen) {
+            warn!("Could not change browser fullscreen: {:?}", err);
+        }
+    }
+
+    if state.pointer_lock != state.observed_pointer_lock {
+        info!("Setting pointer lock to {}", state.pointer_lock);
+        if let Err(err) = browser::set_pointer_lock(state.pointer_lock) {
+            warn!("Could not change pointer lock: {:?}", err);
+        }
+    }
+}
+
+/// System to keep the display settings in sync with the platform
+pub fn sync_display_state(
+    mut commands: Commands,
+    mut state: ResMut<DisplayState>,
+    settings_index: Res<SettingsIndex>,
+    mut settings: Query<&mut Setting>,
+    mut display_events: MessageWriter<DisplayModeChanged>,
+    #[cfg(not(target_arch = "wasm32"))] windows: Query<
+        (&Window, Option<&CursorOptions>),
+        With<PrimaryWindow>,
+    >,
+) {
+    #[cfg(not(target_arch = "wasm32"))]
+    let Ok((window, cursor_options)) = windows.single() else {
+        return;
+    };
+    #[cfg(not(target_arch = "wasm32"))]
+    let (fullscreen, pointer_lock) = (
+        window.mode != WindowMode::Windowed,
+        cursor_options.is_some_and(|options| options.grab_mode != CursorGrabMode::None),
+    );
+    #[cfg(target_arch = "wasm32")]
+    let (fullscreen, pointer_lock) = (browser::is_fullscreen(), browser::is_pointer_locked());
+
+    if fullscreen == state.observed_fullscreen && pointer_lock == state.observed_pointer_lock {
+        return;
+    }
+
+    let state = state.bypass_change_detection();
+    state.observed_fullscreen = fullscreen;
+    state.observed_pointer_lock = pointer_lock;
+
+    // Our own request took effect
+    if fullscreen == state.fullscreen && pointer_lock == state.pointer_lock {
+        return;
+    }
+
+    // The platform changed the mode on its own, e.g. the user pressed Esc
+    info!(
+        "Display mode changed externally (fullscreen: {}, pointer lock: {})",
+        fullscreen, pointer_lock
+    );
+    state.fullscreen = fullscreen;
+    state.pointer_lock = pointer_lock;
+
+    for (setting_id, value) in [
+        (FULLSCREEN_SETTING_ID, fullscreen),
+        (POINTER_LOCK_SETTING_ID, pointer_lock),
+    ] {
+        let Some(entity) = settings_index.get(setting_id) else {
+            continue;
+        };
+        let Ok(mut setting) = settings.get_mut(entity) else {
+            continue;
+        };
+
+        if setting.value.as_bool() != Some(value) {
+            let old_value = std::mem::replace(&mut setting.value, SettingValue::Bool(value));
+            commands.entity(entity).insert(SettingChanged { old_value });
+        }
+    }
+
+    display_events.write(DisplayModeChanged {
+        fullscreen,
+        pointer_lock,
+    });
+}
+
+#[cfg(target_arch = "wasm32")]
+mod browser {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        type Document;
+        type Element;
+
+        #[wasm_bindgen(thread_local_v2, js_name = document)]
+        static DOCUMENT: Document;
+
+        #[wasm_bindgen(method, getter, js_name = documentElement)]
+        fn document_element(this: &Document) -> Element;
+
+        #[wasm_bindgen(method, getter, js_name = fullscreenElement)]
+        fn fullscreen_element(this: &Document) -> Option<Element>;
+
+        #[wasm_bindgen(method, getter, js_name = pointerLockElement)]
+        fn pointer_lock_element(this: &Document) -> Option<Element>;
+
+        #[wasm_bindgen(catch, method, js_name = exitFullscreen)]
+        fn exit_fullscreen(this: &Document) -> Result<JsValue, JsValue>;
+
+        #[wasm_bindgen(catch, method, js_name = exitPointerLock)]
+        fn exit_pointer_lock(this: &Document) -> Result<(), JsValue>;
+
+        #[wasm_bindgen(catch, method, js_name = requestFullscreen)]
+        fn request_fullscreen(this: &Element) -> Result<JsValue, JsValue>;
+
+        #[wasm_bindgen(catch, method, js_name = requestPointerLock)]
+        fn request_pointer_lock(this: &Element) -> Result<JsValue, JsValue>;
+    }
+
+    pub fn is_fullscreen() -> bool {
+        DOCUMENT.with(|document| document.fullscreen_element().is_some())
+    }
+
+    pub fn is_pointer_locked() -> bool {
+        DOCUMENT.with(|document| document.pointer_lock_element().is_some())
+    }
+
+    pub fn set_fullscreen(enabled: bool) -> Result<(), JsValue> {
+        DOCUMENT.with(|document| {
+            if enabled {
+                document.document_element().request_fullscreen()?;
+            } else if document.fullscreen_element().is_some() {
+                document.exit_fullscreen()?;
+            }
+            Ok(())
+        })
+    }
+
+    pub fn set_pointer_lock(enabled: bool) -> Result<(), JsValue> {
+        DOCUMENT.with(|document| {
+            if enabled {
+                document.document_element().request_pointer_lock()?;
+            } else if document.pointer_lock_element().is_some() {
+                document.exit_pointer_lock()?;
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,292 @@
+use super::components::*;
+use super::index::SettingsIndex;
+use super::parental::PARENTAL_SETTING_IDS;
+use super::systems::update_settings_from_components;
+#[cfg(feature = "profile")]
+use crate::profile::PlayerProfile;
+use bevy::prelude::*;
+
+/// Number of edits kept for undo by default
+pub const EDIT_HISTORY_CAPACITY: usize = 100;
+
+/// Changes of the same setting closer together than this are undone in one
+/// step, so dragging a slider is not a hundred separate edits
+pub const EDIT_MERGE_SECS: f32 = 1.0;
+
+/// Plugin recording setting and profile edits in the [`EditHistory`] so they
+/// can be undone and redone.
+///
+/// The settings screens undo with Ctrl+Z and redo with Ctrl+Y or
+/// Ctrl+Shift+Z while the history exists.
+pub struct EditHistoryPlugin;
+
+impl Plugin for EditHistoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EditHistory>()
+            .init_resource::<SettingsIndex>()
+            .add_message::<SettingChangedEvent>()
+            .add_systems(
+                Update,
+                (
+                    apply_history_edits,
+                    record_setting_edits.after(update_settings_from_components),
+                )
+                    .chain(),
+            );
+
+        #[cfg(feature = "profile")]
+        app.add_systems(
+            Update,
+            record_profile_edits
+                .after(apply_history_edits)
+                .run_if(resource_exists::<PlayerProfile>),
+        );
+    }
+}
+
+/// One recorded edit
+#[derive(Debug, Clone, PartialEq)]
+pub enum Edit {
+    /// A setting value changed
+    Setting {
+        setting_id: String,
+        old_value: SettingValue,
+        new_value: SettingValue,
+    },
+    /// The player profile changed
+    #[cfg(feature = "profile")]
+    Profile {
+        old: Box<PlayerProfile>,
+        new: Box<PlayerProfile>,
+    },
+}
+
+impl Edit {
+    /// Merge a later change of the same setting into this edit
+    fn merge(&mut self, later: &Edit) -> bool {
+        match (self, later) {
+            (
+                Edit::Setting {
+                    setting_id,
+                    new_value,
+                    ..
+                },
+                Edit::Setting {
+                    setting_id: later_id,
+                    new_value: later_value,
+                    ..
+                },
+            ) if setting_id == later_id => {
+                *new_value = later_value.clone();
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Which way a history step goes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Undo,
+    Redo,
+}
+
+/// Resource with the undo and redo stacks of the edits.
+///
+/// [`EditHistory::undo`] and [`EditHistory::redo`] only move the edit between
+/// the stacks, the edit is applied to the world later in the frame.
+#[derive(Resource, Debug)]
+pub struct EditHistory {
+    undo: Vec<(Edit, f32)>,
+    redo: Vec<Edit>,
+    capacity: usize,
+    pending: Vec<(Edit, Step)>,
+    /// Setting changes made by the history itself, not to be recorded again
+    ignored: Vec<(String, SettingValue)>,
+    #[cfg(feature = "profile")]
+    profile_snapshot: Option<PlayerProfile>,
+}
+
+impl Default for EditHistory {
+    fn default() -> Self {
+        Self::with_capacity(EDIT_HISTORY_CAPACITY)
+    }
+}
+
+impl EditHistory {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            undo: Vec::new(),
+            redo: Vec::new(),
+            capacity,
+            pending: Vec::new(),
+            ignored: Vec::new(),
+            #[cfg(feature = "profile")]
+            profile_snapshot: None,
+        }
+    }
+
+    /// Record an edit made at `time_secs`, clearing the redo stack
+    pub fn record(&mut self, edit: Edit, time_secs: f32) {
+        self.redo.clear();
+
+        if let Some((last, last_time)) = self.undo.last_mut() {
+            if time_secs - *last_time < EDIT_MERGE_SECS && last.merge(&edit) {
+                *last_time = time_secs;
+                return;
+            }
+        }
+
+        self.undo.push((edit, time_secs));
+        if self.undo.len() > self.capacity {
+            self.undo.remove(0);
+        }
+    }
+
+    /// Undo the most recent edit, returns `false` when there is none
+    pub fn undo(&mut self) -> bool {
+        let Some((edit, _)) = self.undo.pop() else {
+            return false;
+        };
+        self.redo.push(edit.clone());
+        self.pending.push((edit, Step::Undo));
+        true
+    }
+
+    /// Redo the most recently undone edit, returns `false` when there is none
+    pub fn redo(&mut self) -> bool {
+        let Some(edit) = self.redo.pop() else {
+            return false;
+        };
+        // Redone edits must not merge with the previous one
+        self.undo.push((edit.clone(), f32::NEG_INFINITY));
+        self.pending.push((edit, Step::Redo));
+        true
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Recorded edits, oldest first
+    pub fn edits(&self) -> impl Iterator<Item = &Edit> {
+        self.undo.iter().map(|(edit, _)| edit)
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+/// System to apply undone and redone edits to the settings and the profile
+fn apply_history_edits(
+    mut commands: Commands,
+    mut history: ResMut<EditHistory>,
+    index: Res<SettingsIndex>,
+    mut settings: Query<&mut Setting>,
+    #[cfg(feature = "profile")] mut profile: Option<ResMut<PlayerProfile>>,
+) {
+    if history.pending.is_empty() {
+        return;
+    }
+
+    for (edit, step) in std::mem::take(&mut history.pending) {
+        match edit {
+            Edit::Setting {
+                setting_id,
+                old_value,
+                new_value,
+            } => {
+                let value = match step {
+                    Step::Undo => old_value,
+                    Step::Redo => new_value,
+                };
+                let Some(entity) = index.get(&setting_id) else {
+                    warn!("Can't restore missing setting '{}'", setting_id);
+                    continue;
+                };
+                let Ok(mut setting) = settings.get_mut(entity) else {
+                    continue;
+                };
+
+                let old_value = std::mem::replace(&mut setting.value, value.clone());
+                commands.entity(entity).insert(SettingChanged { old_value });
+                history.ignored.push((setting_id, value));
+            }
+            #[cfg(feature = "profile")]
+            Edit::Profile { old, new } => {
+                let Some(profile) = profile.as_mut() else {
+                    continue;
+                };
+                let restored = match step {
+                    Step::Undo => *old,
+                    Step::Redo => *new,
+                };
+                history.profile_snapshot = Some(restored.clone());
+                **profile = restored;
+            }
+        }
+    }
+}
+
+/// System to record setting changes, except for the parental controls
+fn record_setting_edits(
+    mut setting_events: MessageReader<SettingChangedEvent>,
+    mut history: ResMut<EditHistory>,
+    time: Res<Time>,
+) {
+    for event in setting_events.read() {
+        if PARENTAL_SETTING_IDS.contains(&event.setting_id.as_str()) {
+            continue;
+        }
+        if let Some(position) = history
+            .ignored
+            .iter()
+            .position(|(id, value)| event.is(id) && *value == event.new_value)
+        {
+            history.ignored.remove(position);
+            continue;
+        }
+
+        history.record(
+            Edit::Setting {
+                setting_id: event.setting_id.clone(),
+                old_value: event.old_value.clone(),
+                new_value: event.new_value.clone(),
+            },
+            time.elapsed_secs(),
+        );
+    }
+}
+
+/// System to record changes of the player profile
+#[cfg(feature = "profile")]
+fn record_profile_edits(
+    profile: Res<PlayerProfile>,
+    mut history: ResMut<EditHistory>,
+    time: Res<Time>,
+) {
+    if !profile.is_changed() {
+        return;
+    }
+
+    // The profile at startup, or after loading it, is not an edit
+    let Some(snapshot) = history.profile_snapshot.replace(profile.clone()) else {
+        return;
+    };
+    if snapshot != *profile {
+        history.record(
+            Edit::Profile {
+                old: Box::new(snapshot),
+                new: Box::new(profile.clone()),
+            },
+            time.elapsed_secs(),
+        );
+    }
+}
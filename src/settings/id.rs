@@ -0,0 +1,91 @@
+/// Identifier of a setting.
+///
+/// Implemented for string IDs and for enums declared with [`setting_ids!`],
+/// so typos in IDs are caught by the compiler. The string form is what is
+/// stored in [`Setting::id`](super::Setting::id) and used for serialization.
+///
+/// [`setting_ids!`]: crate::setting_ids
+pub trait SettingId {
+    /// String form of the ID
+    fn as_setting_id(&self) -> &str;
+}
+
+impl SettingId for str {
+    fn as_setting_id(&self) -> &str {
+        self
+    }
+}
+
+impl SettingId for String {
+    fn as_setting_id(&self) -> &str {
+        self
+    }
+}
+
+impl<T: SettingId + ?Sized> SettingId for &T {
+    fn as_setting_id(&self) -> &str {
+        (**self).as_setting_id()
+    }
+}
+
+/// Declare an enum of setting IDs implementing [`SettingId`].
+///
+/// ```ignore
+/// setting_ids! {
+///     pub enum AudioSetting {
+///         MasterVolume = "master_volume",
+///         Muted = "audio_muted",
+///     }
+/// }
+///
+/// commands.spawn(Setting::new(AudioSetting::MasterVolume, "Master Volume", ...));
+/// let volume = index.get(AudioSetting::MasterVolume);
+/// ```
+#[macro_export]
+macro_rules! setting_ids {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $id:literal),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant),*
+        }
+
+        impl $name {
+            /// All IDs of this enum
+            pub const ALL: &'static [Self] = &[$(Self::$variant),*];
+
+            /// Parse the string form of an ID
+            pub fn from_setting_id(id: &str) -> Option<Self> {
+                match id {
+                    $($id => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl $crate::settings::SettingId for $name {
+            fn as_setting_id(&self) -> &str {
+                match self {
+                    $(Self::$variant => $id),*
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str($crate::settings::SettingId::as_setting_id(self))
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                $crate::settings::SettingId::as_setting_id(&id).to_string()
+            }
+        }
+    };
+}
@@ -0,0 +1,99 @@
+use super::{components::Setting, id::SettingId};
+use bevy::{
+    ecs::{lifecycle::HookContext, world::DeferredWorld},
+    prelude::*,
+};
+use std::collections::HashMap;
+
+/// Resource mapping setting IDs to their entities.
+///
+/// Kept up to date by the component hooks of [`Setting`], so lookups by ID do
+/// not have to scan every setting entity. Setting IDs should not be changed
+/// after the component is inserted. If an ID exists more than once, the
+/// newest setting is indexed and the older one takes over when it is removed.
+#[derive(Resource, Debug, Default)]
+pub struct SettingsIndex {
+    entities: HashMap<String, Entity>,
+    // Older settings with an indexed ID, newest last
+    shadowed: HashMap<String, Vec<Entity>>,
+}
+
+impl SettingsIndex {
+    /// Get the entity of a setting by ID
+    pub fn get(&self, setting_id: impl SettingId) -> Option<Entity> {
+        self.entities.get(setting_id.as_setting_id()).copied()
+    }
+
+    pub fn contains(&self, setting_id: impl SettingId) -> bool {
+        self.entities.contains_key(setting_id.as_setting_id())
+    }
+
+    /// Iterate over all indexed setting IDs and entities
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Entity)> {
+        self.entities
+            .iter()
+            .map(|(id, entity)| (id.as_str(), *entity))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+}
+
+pub(crate) fn index_setting(mut world: DeferredWorld, context: HookContext) {
+    let Some(id) = world
+        .get::<Setting>(context.entity)
+        .map(|setting| setting.id.clone())
+    else {
+        return;
+    };
+
+    if let Some(mut index) = world.get_resource_mut::<SettingsIndex>() {
+        if let Some(previous) = index.entities.insert(id.clone(), context.entity) {
+            if previous != context.entity {
+                warn!(
+                    "Setting '{}' exists more than once, indexing {:?} instead of {:?}",
+                    id, context.entity, previous
+                );
+                index.shadowed.entry(id).or_default().push(previous);
+            }
+        }
+    }
+}
+
+pub(crate) fn unindex_setting(mut world: DeferredWorld, context: HookContext) {
+    let Some(id) = world
+        .get::<Setting>(context.entity)
+        .map(|setting| setting.id.clone())
+    else {
+        return;
+    };
+
+    let Some(mut index) = world.get_resource_mut::<SettingsIndex>() else {
+        return;
+    };
+    let index = &mut *index;
+
+    let Some(shadowed) = index.shadowed.get_mut(&id) else {
+        if index.get(&id) == Some(context.entity) {
+            index.entities.remove(&id);
+        }
+        return;
+    };
+
+    if index.entities.get(&id) == Some(&context.entity) {
+        // Fall back to the newest remaining duplicate
+        if let Some(previous) = shadowed.pop() {
+            index.entities.insert(id.clone(), previous);
+        }
+    } else {
+        shadowed.retain(|entity| *entity != context.entity);
+    }
+    if shadowed.is_empty() {
+        index.shadowed.remove(&id);
+    }
+}
@@ -1,22 +1,55 @@
+#[cfg(feature = "theme")]
+pub mod appearance;
+#[cfg(feature = "audio")]
+pub mod audio;
 pub mod builders;
 pub mod components;
+pub mod display;
+pub mod history;
+pub mod id;
+pub mod index;
+pub mod observers;
+pub mod parental;
+pub mod performance;
+pub mod profiles;
 pub mod systems;
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "theme")]
+pub use appearance::*;
+#[cfg(feature = "audio")]
+pub use audio::*;
 pub use builders::*;
 pub use components::*;
+pub use display::*;
+pub use history::*;
+pub use id::*;
+pub use index::*;
+pub use observers::*;
+pub use parental::*;
+pub use performance::*;
+pub use profiles::*;
 pub use systems::*;
 
 use bevy::prelude::*;
 
-/// Main settings plugin that provides core settings functionality
+/// Main settings plugin that provides core settings functionality.
+///
+/// With the `audio` feature it also adds the [`AudioSettingsPlugin`], so the
+/// audio settings control `bevy_audio` out of the box.
 pub struct SettingsPlugin;
 
 impl Plugin for SettingsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<SettingChangedEvent>()
+        app.init_resource::<SettingsIndex>()
+            .add_message::<SettingChangedEvent>()
             .add_systems(Update, update_settings_from_components);
+
+        #[cfg(feature = "audio")]
+        if !app.is_plugin_added::<AudioSettingsPlugin>() {
+            app.add_plugins(AudioSettingsPlugin);
+        }
     }
 }
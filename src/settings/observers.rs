@@ -0,0 +1,114 @@
+use super::components::{Setting, SettingChangedEvent, SettingValue};
+use super::id::SettingId;
+use super::systems::update_settings_from_components;
+use bevy::prelude::*;
+use std::sync::Arc;
+
+/// Observer event triggered together with every [`SettingChangedEvent`].
+///
+/// [`SettingChangedEvent`]: super::SettingChangedEvent
+#[derive(Event, Debug, Clone)]
+pub struct SettingValueChanged {
+    pub setting_id: String,
+    pub old_value: SettingValue,
+    pub new_value: SettingValue,
+}
+
+impl SettingValueChanged {
+    /// Whether the change is about the given setting
+    pub fn is(&self, setting_id: impl SettingId) -> bool {
+        self.setting_id == setting_id.as_setting_id()
+    }
+}
+
+/// Helper trait to react to changes of individual settings
+pub trait SettingsAppExt {
+    /// Call `handler` with the new value whenever the setting changes.
+    ///
+    /// ```ignore
+    /// app.on_setting_changed("master_volume", |value, world| {
+    ///     if let Some(volume) = value.as_float() {
+    ///         world.resource_mut::<GlobalVolume>().volume = Volume::Linear(volume);
+    ///     }
+    /// });
+    /// ```
+    fn on_setting_changed(
+        &mut self,
+        setting_id: impl SettingId,
+        handler: impl Fn(&SettingValue, &mut World) + Send + Sync + 'static,
+    ) -> &mut Self;
+
+    /// Keep a resource in sync with a setting.
+    ///
+    /// `apply` is called with the value of the setting when it is spawned and
+    /// whenever it changes. Nothing happens while the resource doesn't exist.
+    ///
+    /// ```ignore
+    /// app.bind_setting::<GlobalVolume>("master_volume", |volume, value| {
+    ///     if let Some(linear) = value.as_float() {
+    ///         volume.volume = Volume::Linear(linear);
+    ///     }
+    /// });
+    /// ```
+    fn bind_setting<R: Resource>(
+        &mut self,
+        setting_id: impl SettingId,
+        apply: impl Fn(&mut R, &SettingValue) + Send + Sync + 'static,
+    ) -> &mut Self;
+}
+
+impl SettingsAppExt for App {
+    fn on_setting_changed(
+        &mut self,
+        setting_id: impl SettingId,
+        handler: impl Fn(&SettingValue, &mut World) + Send + Sync + 'static,
+    ) -> &mut Self {
+        let setting_id = setting_id.as_setting_id().to_string();
+        let handler = Arc::new(handler);
+
+        self.add_observer(
+            move |changed: On<SettingValueChanged>, mut commands: Commands| {
+                if !changed.is(&setting_id) {
+                    return;
+                }
+
+                let handler = handler.clone();
+                let value = changed.new_value.clone();
+                commands.queue(move |world: &mut World| handler(&value, world));
+            },
+        )
+    }
+
+    fn bind_setting<R: Resource>(
+        &mut self,
+        setting_id: impl SettingId,
+        apply: impl Fn(&mut R, &SettingValue) + Send + Sync + 'static,
+    ) -> &mut Self {
+        let setting_id = setting_id.as_setting_id().to_string();
+
+        self.add_message::<SettingChangedEvent>().add_systems(
+            Update,
+            (move |added: Query<&Setting, Added<Setting>>,
+                   mut setting_events: MessageReader<SettingChangedEvent>,
+                   resource: Option<ResMut<R>>| {
+                let Some(mut resource) = resource else {
+                    setting_events.clear();
+                    return;
+                };
+
+                let spawned = added
+                    .iter()
+                    .filter(|setting| setting.id == setting_id)
+                    .map(|setting| &setting.value);
+                let changed = setting_events
+                    .read()
+                    .filter(|event| event.setting_id == setting_id)
+                    .map(|event| &event.new_value);
+                for value in spawned.chain(changed) {
+                    apply(&mut resource, value);
+                }
+            })
+            .after(update_settings_from_components),
+        )
+    }
+}
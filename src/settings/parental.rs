@@ -0,0 +1,214 @@
+use super::components::*;
+#[cfg(feature = "storage")]
+use crate::storage::{PersistenceScheduler, Storage};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Setting ID of the parental controls toggle
+pub const PARENTAL_CONTROLS_SETTING_ID: &str = "parental_controls";
+
+/// Setting ID of the parental controls PIN
+pub const PARENTAL_PIN_SETTING_ID: &str = "parental_pin";
+
+/// Setting ID of the toggle blocking external links
+pub const BLOCK_EXTERNAL_LINKS_SETTING_ID: &str = "block_external_links";
+
+/// Setting ID of the toggle blocking chat and sharing
+pub const BLOCK_SHARING_SETTING_ID: &str = "block_sharing";
+
+/// Setting ID of the session time limit selection
+pub const SESSION_TIME_LIMIT_SETTING_ID: &str = "session_time_limit";
+
+/// Settings of the parental controls, their edits are not recorded in the
+/// [`EditHistory`](super::EditHistory) so undo can't get past the PIN
+pub const PARENTAL_SETTING_IDS: [&str; 5] = [
+    PARENTAL_CONTROLS_SETTING_ID,
+    PARENTAL_PIN_SETTING_ID,
+    BLOCK_EXTERNAL_LINKS_SETTING_ID,
+    BLOCK_SHARING_SETTING_ID,
+    SESSION_TIME_LIMIT_SETTING_ID,
+];
+
+/// Session time limits in minutes selectable in the settings, 0 is no limit
+pub const SESSION_TIME_LIMIT_OPTIONS: [u32; 5] = [0, 15, 30, 45, 60];
+
+/// Maximum length of the parental controls PIN
+pub const PARENTAL_PIN_MAX_LENGTH: usize = 8;
+
+/// Storage key of the parental controls
+pub const PARENTAL_CONTROLS_STORAGE_KEY: &str = "parental_controls";
+
+/// Plugin that applies the parental controls settings to the
+/// [`ParentalControls`].
+///
+/// With the `storage` feature the controls are persisted, so the PIN and
+/// restrictions survive a restart.
+pub struct ParentalControlsPlugin;
+
+impl Plugin for ParentalControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ParentalControls>()
+            .add_message::<SettingChangedEvent>()
+            .add_systems(Update, apply_parental_settings);
+
+        #[cfg(feature = "storage")]
+        {
+            app.add_systems(Startup, load_parental_controls)
+                .add_systems(PostUpdate, save_parental_controls);
+        }
+    }
+}
+
+/// Kid mode restrictions, configured by a parent or teacher
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "storage",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct ParentalControls {
+    pub enabled: bool,
+    /// PIN protecting the settings, no protection if empty
+    pub pin: String,
+    pub block_external_links: bool,
+    /// Block chat and sharing features
+    pub block_sharing: bool,
+    /// Maximum continuous play time in minutes, 0 is no limit
+    pub session_limit_minutes: u32,
+}
+
+impl Default for ParentalControls {
+    fn default() -> Self {
+        // Restrictions only apply once enabled
+        Self {
+            enabled: false,
+            pin: String::new(),
+            block_external_links: true,
+            block_sharing: true,
+            session_limit_minutes: 0,
+        }
+    }
+}
+
+impl ParentalControls {
+    /// Whether the settings are protected by the PIN
+    pub fn requires_pin(&self) -> bool {
+        self.enabled && !self.pin.is_empty()
+    }
+
+    /// Check an entered PIN, always true without PIN protection
+    pub fn verify_pin(&self, pin: &str) -> bool {
+        !self.requires_pin() || self.pin == pin.trim()
+    }
+
+    pub fn external_links_allowed(&self) -> bool {
+        !(self.enabled && self.block_external_links)
+    }
+
+    pub fn sharing_allowed(&self) -> bool {
+        !(self.enabled && self.block_sharing)
+    }
+
+    /// Maximum continuous play time, if limited
+    pub fn session_limit(&self) -> Option<Duration> {
+        (self.enabled && self.session_limit_minutes > 0)
+            .then(|| Duration::from_secs(u64::from(self.session_limit_minutes) * 60))
+    }
+}
+
+/// Labels of the [`SESSION_TIME_LIMIT_OPTIONS`]
+pub fn session_time_limit_labels() -> Vec<String> {
+    SESSION_TIME_LIMIT_OPTIONS
+        .iter()
+        .map(|minutes| match minutes {
+            0 => "No limit".to_string(),
+            minutes => format!("{} min", minutes),
+        })
+        .collect()
+}
+
+/// System to update the parental controls from setting changes
+pub fn apply_parental_settings(
+    mut setting_events: MessageReader<SettingChangedEvent>,
+    mut controls: ResMut<ParentalControls>,
+) {
+    for event in setting_events.read() {
+        apply_parental_setting(&mut controls, &event.setting_id, &event.new_value);
+    }
+}
+
+/// Apply a single setting value to the parental controls, ignoring other ids
+pub fn apply_parental_setting(controls: &mut ParentalControls, id: &str, value: &SettingValue) {
+    match id {
+        PARENTAL_CONTROLS_SETTING_ID => {
+            if let Some(value) = value.as_bool() {
+                if controls.enabled != value {
+                    controls.enabled = value;
+                }
+            }
+        }
+        PARENTAL_PIN_SETTING_ID => {
+            if let Some(pin) = value.as_string() {
+                let pin: String = pin.trim().chars().take(PARENTAL_PIN_MAX_LENGTH).collect();
+                if controls.pin != pin {
+                    controls.pin = pin;
+                }
+            }
+        }
+        BLOCK_EXTERNAL_LINKS_SETTING_ID => {
+            if let Some(value) = value.as_bool() {
+                if controls.block_external_links != value {
+                    controls.block_external_links = value;
+                }
+            }
+        }
+        BLOCK_SHARING_SETTING_ID => {
+            if let Some(value) = value.as_bool() {
+                if controls.block_sharing != value {
+                    controls.block_sharing = value;
+                }
+            }
+        }
+        SESSION_TIME_LIMIT_SETTING_ID => {
+            if let Some(index) = value.as_selection() {
+                let minutes = SESSION_TIME_LIMIT_OPTIONS
+                    .get(index)
+                    .copied()
+                    .unwrap_or_default();
+                if controls.session_limit_minutes != minutes {
+                    controls.session_limit_minutes = minutes;
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// System to restore the parental controls from storage
+#[cfg(feature = "storage")]
+fn load_parental_controls(storage: Option<Res<Storage>>, mut controls: ResMut<ParentalControls>) {
+    let Some(storage) = storage else {
+        return;
+    };
+
+    match storage.load::<ParentalControls>(PARENTAL_CONTROLS_STORAGE_KEY) {
+        Ok(Some(stored)) => *controls = stored,
+        Ok(None) => {}
+        Err(e) => warn!("Failed to load parental controls: {}", e),
+    }
+}
+
+/// System to persist the parental controls whenever they change
+#[cfg(feature = "storage")]
+fn save_parental_controls(
+    scheduler: Option<ResMut<PersistenceScheduler>>,
+    controls: Res<ParentalControls>,
+) {
+    if !controls.is_changed() {
+        return;
+    }
+
+    if let Some(mut scheduler) = scheduler {
+        scheduler.schedule(PARENTAL_CONTROLS_STORAGE_KEY, &*controls);
+    }
+}
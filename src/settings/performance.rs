@@ -0,0 +1,184 @@
+use super::components::*;
+use bevy::{
+    prelude::*,
+    window::{PresentMode, PrimaryWindow},
+};
+use std::sync::Arc;
+
+/// Setting ID of the frame rate cap
+pub const FPS_CAP_SETTING_ID: &str = "fps_cap";
+
+/// Setting ID of the V-Sync toggle, shared with the graphics category
+pub const VSYNC_SETTING_ID: &str = "vsync";
+
+/// Setting ID of the render scale
+pub const RENDER_SCALE_SETTING_ID: &str = "render_scale";
+
+/// Frame rate caps of the [`FPS_CAP_SETTING_ID`] selection, `None` for unlimited
+pub const FPS_CAP_OPTIONS: [Option<u32>; 4] = [None, Some(30), Some(60), Some(120)];
+
+/// Labels of the [`FPS_CAP_OPTIONS`]
+pub fn fps_cap_labels() -> Vec<String> {
+    FPS_CAP_OPTIONS
+        .iter()
+        .map(|cap| match cap {
+            Some(fps) => format!("{} FPS", fps),
+            None => "Unlimited".to_string(),
+        })
+        .collect()
+}
+
+/// Plugin that applies the performance settings.
+///
+/// V-Sync sets the present mode of the primary window and the frame rate
+/// cap sleeps at the end of frames that were faster (on native only, browsers
+/// pace frames themselves). The render scale is passed to the
+/// [`RenderScaleHook`], by default it is stored in the [`RenderScale`]
+/// resource for the render setup of the game to pick up.
+pub struct PerformanceSettingsPlugin;
+
+impl Plugin for PerformanceSettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PerformanceSettings>()
+            .init_resource::<RenderScaleHook>()
+            .add_message::<SettingChangedEvent>()
+            .add_systems(
+                Update,
+                (
+                    request_performance_from_settings,
+                    apply_performance_settings,
+                )
+                    .chain(),
+            );
+
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(Last, limit_frame_rate);
+    }
+}
+
+/// Performance settings currently applied
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct PerformanceSettings {
+    /// Maximum frames per second, `None` for unlimited
+    pub fps_cap: Option<u32>,
+    pub vsync: bool,
+    /// Fraction of the window resolution to render at, 0.5 to 1.0
+    pub render_scale: f32,
+}
+
+impl Default for PerformanceSettings {
+    fn default() -> Self {
+        Self {
+            fps_cap: None,
+            vsync: true,
+            render_scale: 1.0,
+        }
+    }
+}
+
+/// Render scale set by the default [`RenderScaleHook`]
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct RenderScale(pub f32);
+
+/// Resource with the function applying the render scale.
+///
+/// Replace it to hook the render scale into an upscaling pass or to resize a
+/// canvas:
+///
+/// ```ignore
+/// app.insert_resource(RenderScaleHook::new(|world, scale| {
+///     world.resource_mut::<MyUpscaler>().scale = scale;
+/// }));
+/// ```
+#[derive(Resource, Clone)]
+pub struct RenderScaleHook(pub Arc<RenderScaleFn>);
+
+/// Function applying the render scale to the world
+pub type RenderScaleFn = dyn Fn(&mut World, f32) + Send + Sync;
+
+impl RenderScaleHook {
+    pub fn new(hook: impl Fn(&mut World, f32) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(hook))
+    }
+}
+
+impl Default for RenderScaleHook {
+    fn default() -> Self {
+        Self::new(|world, scale| world.insert_resource(RenderScale(scale)))
+    }
+}
+
+/// System to update the performance settings from setting changes
+pub fn request_performance_from_settings(
+    mut setting_events: MessageReader<SettingChangedEvent>,
+    mut settings: ResMut<PerformanceSettings>,
+) {
+    for event in setting_events.read() {
+        if event.is(FPS_CAP_SETTING_ID) {
+            if let Some(index) = event.new_value.as_selection() {
+                let fps_cap = FPS_CAP_OPTIONS.get(index).copied().flatten();
+                if settings.fps_cap != fps_cap {
+                    settings.fps_cap = fps_cap;
+                }
+            }
+        } else if event.is(VSYNC_SETTING_ID) {
+            if let Some(value) = event.new_value.as_bool() {
+                if settings.vsync != value {
+                    settings.vsync = value;
+                }
+            }
+        } else if event.is(RENDER_SCALE_SETTING_ID) {
+            if let Some(value) = event.new_value.as_float() {
+                let value = value.clamp(0.5, 1.0);
+                if settings.render_scale != value {
+                    settings.render_scale = value;
+                }
+            }
+        }
+    }
+}
+
+/// System to apply changed performance settings to the window and the
+/// render scale hook. The freshly added resource is skipped, so the game's
+/// own present mode stays until a performance setting changes
+pub fn apply_performance_settings(
+    mut commands: Commands,
+    settings: Res<PerformanceSettings>,
+    hook: Res<RenderScaleHook>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if !settings.is_changed() || settings.is_added() {
+        return;
+    }
+
+    let present_mode = if settings.vsync {
+        PresentMode::AutoVsync
+    } else {
+        PresentMode::AutoNoVsync
+    };
+    if let Ok(mut window) = windows.single_mut() {
+        if window.present_mode != present_mode {
+            info!("Setting present mode to {:?}", present_mode);
+            window.present_mode = present_mode;
+        }
+    }
+
+    let hook = hook.0.clone();
+    let scale = settings.render_scale;
+    commands.queue(move |world: &mut World| hook(world, scale));
+}
+
+/// System to sleep away the rest of frames faster than the frame rate cap
+#[cfg(not(target_arch = "wasm32"))]
+fn limit_frame_rate(
+    settings: Res<PerformanceSettings>,
+    mut frame_start: Local<Option<std::time::Instant>>,
+) {
+    if let (Some(fps), Some(start)) = (settings.fps_cap, *frame_start) {
+        let frame_time = std::time::Duration::from_secs_f64(1.0 / f64::from(fps.max(1)));
+        if let Some(remaining) = frame_time.checked_sub(start.elapsed()) {
+            std::thread::sleep(remaining);
+        }
+    }
+    *frame_start = Some(std::time::Instant::now());
+}
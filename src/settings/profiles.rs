@@ -0,0 +1,321 @@
+use super::components::*;
+use super::index::SettingsIndex;
+use super::systems::update_settings_from_components;
+#[cfg(feature = "profile")]
+use crate::profile::PlayerProfile;
+#[cfg(feature = "storage")]
+use crate::storage::{PersistenceScheduler, Storage};
+use bevy::prelude::*;
+use std::collections::BTreeMap;
+
+/// Storage key of the settings profiles
+pub const SETTINGS_PROFILES_STORAGE_KEY: &str = "settings_profiles";
+
+/// Plugin for named sets of setting values, e.g. "Kid mode" or one per
+/// student on a shared classroom device.
+///
+/// Changes of the settings are remembered in the active profile, switching
+/// profiles applies their values to the [`Setting`]s. A profile created for a
+/// [`PlayerProfile`](crate::profile::PlayerProfile) is switched to when that
+/// player is loaded. With the `storage` feature the profiles are persisted.
+///
+/// ```ignore
+/// app.add_plugins(SettingsProfilesPlugin);
+///
+/// fn kid_mode(mut profiles: ResMut<SettingsProfiles>) {
+///     profiles.insert(
+///         SettingsProfile::new("Kid mode").with_value("hints", SettingValue::Bool(true)),
+///     );
+///     profiles.switch("Kid mode");
+/// }
+/// ```
+pub struct SettingsProfilesPlugin;
+
+impl Plugin for SettingsProfilesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SettingsProfiles>()
+            .init_resource::<SettingsIndex>()
+            .add_message::<SettingChangedEvent>()
+            .add_systems(
+                Update,
+                (
+                    apply_settings_profiles,
+                    record_profile_settings.after(update_settings_from_components),
+                )
+                    .chain(),
+            );
+
+        #[cfg(feature = "profile")]
+        app.add_systems(
+            Update,
+            follow_player_profile
+                .before(apply_settings_profiles)
+                .run_if(resource_exists::<PlayerProfile>),
+        );
+
+        #[cfg(feature = "storage")]
+        {
+            app.add_systems(Startup, load_settings_profiles)
+                .add_systems(PostUpdate, save_settings_profiles);
+        }
+    }
+}
+
+/// Named set of setting values
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "storage",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct SettingsProfile {
+    pub name: String,
+    /// ID of the player the profile belongs to, if any
+    pub player_id: Option<String>,
+    /// Setting values by setting ID
+    pub values: BTreeMap<String, SettingValue>,
+}
+
+impl Default for SettingsProfile {
+    fn default() -> Self {
+        Self::new("Default")
+    }
+}
+
+impl SettingsProfile {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            player_id: None,
+            values: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_player(mut self, player_id: impl Into<String>) -> Self {
+        self.player_id = Some(player_id.into());
+        self
+    }
+
+    pub fn with_value(mut self, setting_id: impl Into<String>, value: SettingValue) -> Self {
+        self.values.insert(setting_id.into(), value);
+        self
+    }
+}
+
+/// Resource with the settings profiles and the active one.
+///
+/// [`SettingsProfiles::create`] and [`SettingsProfiles::switch`] take effect
+/// later in the frame, when the settings are read or written.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+#[cfg_attr(
+    feature = "storage",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct SettingsProfiles {
+    profiles: Vec<SettingsProfile>,
+    active: Option<String>,
+    #[cfg_attr(feature = "storage", serde(skip))]
+    pending: Vec<ProfileChange>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ProfileChange {
+    /// Create a profile from the current setting values
+    Create(SettingsProfile),
+    Switch(String),
+}
+
+impl SettingsProfiles {
+    /// Create or overwrite a profile with the current setting values and make
+    /// it the active one
+    pub fn create(&mut self, name: impl Into<String>) {
+        self.pending
+            .push(ProfileChange::Create(SettingsProfile::new(name)));
+    }
+
+    /// Create or overwrite a profile of a player with the current setting
+    /// values
+    pub fn create_for_player(&mut self, name: impl Into<String>, player_id: impl Into<String>) {
+        self.pending.push(ProfileChange::Create(
+            SettingsProfile::new(name).with_player(player_id),
+        ));
+    }
+
+    /// Add a preset, its values are applied when switching to it
+    pub fn insert(&mut self, profile: SettingsProfile) {
+        match self.get_mut(&profile.name) {
+            Some(existing) => *existing = profile,
+            None => self.profiles.push(profile),
+        }
+    }
+
+    /// Apply the values of a profile and make it the active one
+    pub fn switch(&mut self, name: impl Into<String>) {
+        self.pending.push(ProfileChange::Switch(name.into()));
+    }
+
+    /// Delete a profile, returns `false` if there is none with the name.
+    ///
+    /// Deleting the active profile keeps the current values.
+    pub fn delete(&mut self, name: &str) -> bool {
+        let Some(position) = self.profiles.iter().position(|p| p.name == name) else {
+            return false;
+        };
+        self.profiles.remove(position);
+        if self.active.as_deref() == Some(name) {
+            self.active = None;
+        }
+        true
+    }
+
+    pub fn get(&self, name: &str) -> Option<&SettingsProfile> {
+        self.profiles.iter().find(|profile| profile.name == name)
+    }
+
+    fn get_mut(&mut self, name: &str) -> Option<&mut SettingsProfile> {
+        self.profiles
+            .iter_mut()
+            .find(|profile| profile.name == name)
+    }
+
+    /// Profile of a player
+    pub fn for_player(&self, player_id: &str) -> Option<&SettingsProfile> {
+        self.profiles
+            .iter()
+            .find(|profile| profile.player_id.as_deref() == Some(player_id))
+    }
+
+    pub fn profiles(&self) -> &[SettingsProfile] {
+        &self.profiles
+    }
+
+    pub fn active(&self) -> Option<&SettingsProfile> {
+        self.active.as_deref().and_then(|name| self.get(name))
+    }
+
+    pub fn is_active(&self, name: &str) -> bool {
+        self.active.as_deref() == Some(name)
+    }
+}
+
+/// System to create and switch profiles
+fn apply_settings_profiles(
+    mut commands: Commands,
+    mut profiles: ResMut<SettingsProfiles>,
+    index: Res<SettingsIndex>,
+    mut settings: Query<&mut Setting>,
+) {
+    if profiles.pending.is_empty() {
+        return;
+    }
+
+    for change in std::mem::take(&mut profiles.pending) {
+        match change {
+            ProfileChange::Create(mut profile) => {
+                profile.values = settings
+                    .iter()
+                    .map(|setting| (setting.id.clone(), setting.value.clone()))
+                    .collect();
+                info!("Saved settings profile '{}'", profile.name);
+                profiles.active = Some(profile.name.clone());
+                profiles.insert(profile);
+            }
+            ProfileChange::Switch(name) => {
+                let Some(profile) = profiles.get(&name) else {
+                    warn!("Can't switch to missing settings profile '{}'", name);
+                    continue;
+                };
+
+                for (setting_id, value) in &profile.values {
+                    let Some(entity) = index.get(setting_id) else {
+                        continue;
+                    };
+                    let Ok(mut setting) = settings.get_mut(entity) else {
+                        continue;
+                    };
+                    if setting.value != *value {
+                        let old_value = std::mem::replace(&mut setting.value, value.clone());
+                        commands.entity(entity).insert(SettingChanged { old_value });
+                    }
+                }
+                info!("Switched to settings profile '{}'", name);
+                profiles.active = Some(name);
+            }
+        }
+    }
+}
+
+/// System to remember setting changes in the active profile
+fn record_profile_settings(
+    mut setting_events: MessageReader<SettingChangedEvent>,
+    mut profiles: ResMut<SettingsProfiles>,
+) {
+    for event in setting_events.read() {
+        let Some(name) = profiles.active.clone() else {
+            continue;
+        };
+        let Some(profile) = profiles.bypass_change_detection().get_mut(&name) else {
+            continue;
+        };
+        if profile.values.get(&event.setting_id) != Some(&event.new_value) {
+            profile
+                .values
+                .insert(event.setting_id.clone(), event.new_value.clone());
+            profiles.set_changed();
+        }
+    }
+}
+
+/// System to switch to the profile of the loaded player
+#[cfg(feature = "profile")]
+fn follow_player_profile(
+    player: Res<PlayerProfile>,
+    mut profiles: ResMut<SettingsProfiles>,
+    mut last_player: Local<Option<String>>,
+) {
+    if !player.is_changed() || last_player.as_deref() == Some(player.id.as_str()) {
+        return;
+    }
+    *last_player = Some(player.id.clone());
+
+    if let Some(name) = profiles.for_player(&player.id).map(|p| p.name.clone()) {
+        if !profiles.is_active(&name) {
+            profiles.switch(name);
+        }
+    }
+}
+
+/// System to restore the profiles from storage and apply the active one
+#[cfg(feature = "storage")]
+fn load_settings_profiles(storage: Option<Res<Storage>>, mut profiles: ResMut<SettingsProfiles>) {
+    let Some(storage) = storage else {
+        return;
+    };
+
+    match storage.load::<SettingsProfiles>(SETTINGS_PROFILES_STORAGE_KEY) {
+        Ok(Some(loaded)) => {
+            *profiles = loaded;
+            if let Some(active) = profiles.active.clone() {
+                profiles.switch(active);
+            }
+        }
+        Ok(None) => {}
+        Err(e) => warn!("Failed to load settings profiles: {}", e),
+    }
+}
+
+/// System to persist the profiles whenever they change
+#[cfg(feature = "storage")]
+fn save_settings_profiles(
+    scheduler: Option<ResMut<PersistenceScheduler>>,
+    profiles: Res<SettingsProfiles>,
+) {
+    if !profiles.is_changed() {
+        return;
+    }
+
+    if let Some(mut scheduler) = scheduler {
+        scheduler.schedule(SETTINGS_PROFILES_STORAGE_KEY, &*profiles);
+    }
+}
@@ -1,4 +1,7 @@
 use super::components::*;
+use super::id::SettingId;
+use super::index::SettingsIndex;
+use super::observers::SettingValueChanged;
 use bevy::prelude::*;
 
 /// System to detect and handle setting changes
@@ -9,12 +12,17 @@ pub fn update_settings_from_components(
 ) {
     for (entity, setting, changed_marker) in changed_settings.iter_mut() {
         if let Some(changed) = changed_marker {
-            // Send event about the change
+            // Send event about the change, and trigger the observers
             setting_events.write(SettingChangedEvent {
                 setting_id: setting.id.clone(),
                 old_value: changed.old_value.clone(),
                 new_value: setting.value.clone(),
             });
+            commands.trigger(SettingValueChanged {
+                setting_id: setting.id.clone(),
+                old_value: changed.old_value.clone(),
+                new_value: setting.value.clone(),
+            });
 
             // Remove the changed marker
             commands.entity(entity).remove::<SettingChanged>();
@@ -22,46 +30,45 @@ pub fn update_settings_from_components(
     }
 }
 
-/// Helper function to update a setting value by ID
+/// Helper function to update a setting value by ID, looked up in the
+/// [`SettingsIndex`]
 pub fn update_setting_by_id(
-    setting_id: &str,
+    setting_id: impl SettingId,
     new_value: SettingValue,
-    mut settings_query: Query<(Entity, &mut Setting)>,
-    mut commands: Commands,
+    index: &SettingsIndex,
+    settings_query: Query<(Entity, &mut Setting)>,
+    commands: Commands,
 ) -> bool {
-    for (entity, mut setting) in settings_query.iter_mut() {
-        if setting.id == setting_id {
-            let old_value = setting.value.clone();
-            setting.value = new_value;
-
-            commands.entity(entity).insert(SettingChanged { old_value });
-            return true;
-        }
-    }
-    false
+    update_setting_with(setting_id, index, settings_query, commands, |_| {
+        Some(new_value)
+    })
 }
 
-/// Query helper to find a setting by ID
+/// Query helper to find a setting by ID, looked up in the [`SettingsIndex`]
 pub fn find_setting_by_id<'a>(
     settings_query: &'a Query<&Setting>,
-    setting_id: &str,
+    index: &SettingsIndex,
+    setting_id: impl SettingId,
 ) -> Option<&'a Setting> {
-    settings_query
-        .iter()
-        .find(|setting| setting.id == setting_id)
+    index
+        .get(setting_id)
+        .and_then(|entity| settings_query.get(entity).ok())
 }
 
-/// Query helper to get setting value by ID
+/// Query helper to get setting value by ID, looked up in the [`SettingsIndex`]
 pub fn get_setting_value(
     settings_query: &Query<&Setting>,
-    setting_id: &str,
+    index: &SettingsIndex,
+    setting_id: impl SettingId,
 ) -> Option<SettingValue> {
-    find_setting_by_id(settings_query, setting_id).map(|setting| setting.value.clone())
+    find_setting_by_id(settings_query, index, setting_id).map(|setting| setting.value.clone())
 }
 
-/// Helper function to update a setting value using a closure
+/// Helper function to update a setting value using a closure, the setting is
+/// looked up in the [`SettingsIndex`]
 pub fn update_setting_with<F>(
-    setting_id: &str,
+    setting_id: impl SettingId,
+    index: &SettingsIndex,
     mut settings_query: Query<(Entity, &mut Setting)>,
     mut commands: Commands,
     updater: F,
@@ -69,22 +76,15 @@ pub fn update_setting_with<F>(
 where
     F: FnOnce(&SettingValue) -> Option<SettingValue>,
 {
-    let setting_info = settings_query.iter().find_map(|(entity, setting)| {
-        if setting.id == setting_id {
-            Some((entity, setting.value.clone()))
-        } else {
-            None
-        }
-    });
+    let Some(entity) = index.get(setting_id) else {
+        return false;
+    };
 
-    if let Some((entity, current_value)) = setting_info {
-        if let Some(new_value) = updater(&current_value) {
-            if let Ok((_, mut setting)) = settings_query.get_mut(entity) {
-                let old_value = setting.value.clone();
-                setting.value = new_value;
-                commands.entity(entity).insert(SettingChanged { old_value });
-                return true;
-            }
+    if let Ok((entity, mut setting)) = settings_query.get_mut(entity) {
+        if let Some(new_value) = updater(&setting.value) {
+            let old_value = std::mem::replace(&mut setting.value, new_value);
+            commands.entity(entity).insert(SettingChanged { old_value });
+            return true;
         }
     }
     false
@@ -1,4 +1,5 @@
 use super::*;
+use bevy::ecs::system::RunSystemOnce;
 use bevy::prelude::*;
 
 // Helper function to create a test app with settings plugin
@@ -166,6 +167,32 @@ fn test_settings_registry() {
     assert_eq!(master_volume.default_value, SettingValue::Float(1.0));
 }
 
+#[test]
+fn test_performance_settings_single_vsync() {
+    let vsync_count = |categories: &[SettingsCategory]| {
+        categories
+            .iter()
+            .flat_map(|category| &category.settings)
+            .filter(|setting| setting.id == VSYNC_SETTING_ID)
+            .count()
+    };
+
+    let registry =
+        SettingsRegistry::game_settings().add_category(SettingsRegistry::performance_category());
+    assert_eq!(vsync_count(&registry.categories), 1);
+
+    let builder = SettingsBuilder::new()
+        .with_performance_settings()
+        .with_graphics_settings();
+    assert_eq!(vsync_count(&builder.categories), 1);
+    let performance = builder
+        .categories
+        .iter()
+        .find(|category| category.name == "performance")
+        .unwrap();
+    assert_eq!(vsync_count(std::slice::from_ref(performance)), 1);
+}
+
 #[test]
 fn test_settings_builder() {
     let mut app = create_test_app();
@@ -345,6 +372,179 @@ fn test_setting_changed_events() {
     assert!(app.world().get::<SettingChanged>(entity).is_none());
 }
 
+#[test]
+fn test_on_setting_changed() {
+    #[derive(Resource, Default)]
+    struct Volume(f32);
+
+    let mut app = create_test_app();
+    app.init_resource::<Volume>()
+        .on_setting_changed("master_volume", |value, world| {
+            if let Some(volume) = value.as_float() {
+                world.resource_mut::<Volume>().0 = volume;
+            }
+        });
+
+    let volume = app
+        .world_mut()
+        .spawn(create_test_setting(
+            "master_volume",
+            SettingValue::Float(1.0),
+        ))
+        .id();
+    let other = app
+        .world_mut()
+        .spawn(create_test_setting(
+            "music_volume",
+            SettingValue::Float(1.0),
+        ))
+        .id();
+    app.update();
+
+    for (entity, value) in [(volume, 0.4), (other, 0.2)] {
+        app.world_mut().get_mut::<Setting>(entity).unwrap().value = SettingValue::Float(value);
+        app.world_mut().entity_mut(entity).insert(SettingChanged {
+            old_value: SettingValue::Float(1.0),
+        });
+    }
+    app.update();
+
+    assert_eq!(app.world().resource::<Volume>().0, 0.4);
+}
+
+#[test]
+fn test_bind_setting() {
+    #[derive(Resource, Default)]
+    struct Volume(f32);
+
+    let mut app = create_test_app();
+    app.init_resource::<Volume>()
+        .bind_setting::<Volume>("master_volume", |volume, value| {
+            if let Some(value) = value.as_float() {
+                volume.0 = value;
+            }
+        });
+
+    // The value of the spawned setting is applied
+    let entity = app
+        .world_mut()
+        .spawn(create_test_setting(
+            "master_volume",
+            SettingValue::Float(0.8),
+        ))
+        .id();
+    app.update();
+    assert_eq!(app.world().resource::<Volume>().0, 0.8);
+
+    app.world_mut().get_mut::<Setting>(entity).unwrap().value = SettingValue::Float(0.3);
+    app.world_mut().entity_mut(entity).insert(SettingChanged {
+        old_value: SettingValue::Float(0.8),
+    });
+    app.update();
+    assert_eq!(app.world().resource::<Volume>().0, 0.3);
+}
+
+#[test]
+fn test_edit_history_undo_redo() {
+    let mut app = create_test_app();
+    app.add_plugins(EditHistoryPlugin);
+    let volume = app
+        .world_mut()
+        .spawn(create_test_setting("volume", SettingValue::Float(1.0)))
+        .id();
+    let hints = app
+        .world_mut()
+        .spawn(create_test_setting("hints", SettingValue::Bool(true)))
+        .id();
+    app.update();
+
+    let change = |app: &mut App, entity: Entity, value: SettingValue| {
+        let old_value = std::mem::replace(
+            &mut app.world_mut().get_mut::<Setting>(entity).unwrap().value,
+            value,
+        );
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(SettingChanged { old_value });
+        app.update();
+    };
+    let value =
+        |app: &App, entity: Entity| app.world().get::<Setting>(entity).unwrap().value.clone();
+
+    // Quick changes of the same setting are one edit
+    change(&mut app, volume, SettingValue::Float(0.8));
+    change(&mut app, volume, SettingValue::Float(0.5));
+    change(&mut app, hints, SettingValue::Bool(false));
+    assert_eq!(app.world().resource::<EditHistory>().edits().count(), 2);
+
+    app.world_mut().resource_mut::<EditHistory>().undo();
+    app.update();
+    app.update();
+    assert_eq!(value(&app, hints), SettingValue::Bool(true));
+
+    app.world_mut().resource_mut::<EditHistory>().undo();
+    app.update();
+    app.update();
+    assert_eq!(value(&app, volume), SettingValue::Float(1.0));
+    // Undoing is not recorded as a new edit
+    let history = app.world().resource::<EditHistory>();
+    assert!(!history.can_undo());
+    assert!(history.can_redo());
+
+    app.world_mut().resource_mut::<EditHistory>().redo();
+    app.update();
+    app.update();
+    assert_eq!(value(&app, volume), SettingValue::Float(0.5));
+    assert_eq!(app.world().resource::<EditHistory>().edits().count(), 1);
+
+    // A new edit drops the undone ones
+    change(&mut app, volume, SettingValue::Float(0.2));
+    assert!(!app.world().resource::<EditHistory>().can_redo());
+}
+
+#[test]
+fn test_edit_history_skips_parental_controls() {
+    let mut app = create_test_app();
+    app.add_plugins(EditHistoryPlugin);
+    let parental = app
+        .world_mut()
+        .spawn(create_test_setting(
+            PARENTAL_CONTROLS_SETTING_ID,
+            SettingValue::Bool(false),
+        ))
+        .id();
+    app.update();
+
+    app.world_mut().get_mut::<Setting>(parental).unwrap().value = SettingValue::Bool(true);
+    app.world_mut().entity_mut(parental).insert(SettingChanged {
+        old_value: SettingValue::Bool(false),
+    });
+    app.update();
+
+    // Undo must not turn the parental controls off again
+    assert!(!app.world().resource::<EditHistory>().can_undo());
+}
+
+#[cfg(feature = "profile")]
+#[test]
+fn test_edit_history_profile() {
+    use crate::profile::PlayerProfile;
+
+    let mut app = create_test_app();
+    app.init_resource::<PlayerProfile>()
+        .add_plugins(EditHistoryPlugin);
+    app.update();
+
+    app.world_mut().resource_mut::<PlayerProfile>().name = "Anna".to_string();
+    app.update();
+    assert_eq!(app.world().resource::<EditHistory>().edits().count(), 1);
+
+    app.world_mut().resource_mut::<EditHistory>().undo();
+    app.update();
+    assert_eq!(app.world().resource::<PlayerProfile>().name, "Player");
+    assert!(app.world().resource::<EditHistory>().can_redo());
+}
+
 #[test]
 fn test_simple_setting_update() {
     let mut app = create_test_app();
@@ -752,3 +952,369 @@ fn test_update_setting_with_helper_alternative() {
     // The change marker should have been processed and removed
     assert!(app.world().get::<SettingChanged>(entity).is_none());
 }
+
+#[test]
+fn test_display_settings_request_fullscreen() {
+    let mut app = create_test_app();
+    app.add_plugins(DisplaySettingsPlugin);
+
+    {
+        let mut commands = app.world_mut().commands();
+        let entities = commands.spawn_display_settings();
+        assert_eq!(entities.len(), 2);
+    }
+
+    app.update();
+
+    let entity = {
+        let mut query = app.world_mut().query::<(Entity, &Setting)>();
+        query
+            .iter(app.world())
+            .find(|(_, setting)| setting.id == FULLSCREEN_SETTING_ID)
+            .map(|(entity, _)| entity)
+            .expect("Fullscreen setting should exist")
+    };
+
+    {
+        let mut setting = app.world_mut().get_mut::<Setting>(entity).unwrap();
+        setting.value = SettingValue::Bool(true);
+    }
+    app.world_mut().entity_mut(entity).insert(SettingChanged {
+        old_value: SettingValue::Bool(false),
+    });
+
+    app.update();
+    app.update();
+
+    let state = app.world().resource::<DisplayState>();
+    assert!(state.fullscreen);
+    assert!(!state.pointer_lock);
+}
+
+#[test]
+fn test_performance_settings_keep_present_mode() {
+    let mut app = create_test_app();
+    app.add_plugins(PerformanceSettingsPlugin);
+    let window = app
+        .world_mut()
+        .spawn((
+            Window {
+                present_mode: bevy::window::PresentMode::Fifo,
+                ..default()
+            },
+            bevy::window::PrimaryWindow,
+        ))
+        .id();
+    app.update();
+    app.update();
+
+    assert_eq!(
+        app.world().get::<Window>(window).unwrap().present_mode,
+        bevy::window::PresentMode::Fifo
+    );
+}
+
+#[test]
+fn test_performance_settings_applied() {
+    let mut app = create_test_app();
+    app.add_plugins(PerformanceSettingsPlugin);
+    let window = app
+        .world_mut()
+        .spawn((Window::default(), bevy::window::PrimaryWindow))
+        .id();
+
+    let entities = app.world_mut().commands().spawn_performance_settings();
+    assert_eq!(entities.len(), 3);
+    app.update();
+
+    for (id, old_value, new_value) in [
+        (
+            VSYNC_SETTING_ID,
+            SettingValue::Bool(true),
+            SettingValue::Bool(false),
+        ),
+        (
+            FPS_CAP_SETTING_ID,
+            SettingValue::Selection(0),
+            SettingValue::Selection(2),
+        ),
+        (
+            RENDER_SCALE_SETTING_ID,
+            SettingValue::Float(1.0),
+            SettingValue::Float(0.75),
+        ),
+    ] {
+        let entity = app.world().resource::<SettingsIndex>().get(id).unwrap();
+        app.world_mut().get_mut::<Setting>(entity).unwrap().value = new_value;
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(SettingChanged { old_value });
+    }
+
+    app.update();
+    app.update();
+
+    let settings = app.world().resource::<PerformanceSettings>();
+    assert_eq!(settings.fps_cap, Some(60));
+    assert!(!settings.vsync);
+    assert_eq!(
+        app.world().get::<Window>(window).unwrap().present_mode,
+        bevy::window::PresentMode::AutoNoVsync
+    );
+    assert_eq!(app.world().resource::<RenderScale>().0, 0.75);
+}
+
+#[cfg(feature = "audio")]
+#[test]
+fn test_audio_settings_applied() {
+    use crate::lifecycle::AppLifecycleState;
+    use bevy::audio::Volume;
+
+    // The settings plugin adds the audio settings plugin
+    let mut app = create_test_app();
+    assert!(app.is_plugin_added::<AudioSettingsPlugin>());
+    app.init_resource::<AppLifecycleState>();
+    app.world_mut().commands().spawn_audio_settings();
+    app.update();
+    assert_eq!(app.world().resource::<AudioSettings>().music_volume, 0.8);
+
+    let entity = app
+        .world()
+        .resource::<SettingsIndex>()
+        .get(MASTER_VOLUME_SETTING_ID)
+        .unwrap();
+    app.world_mut().get_mut::<Setting>(entity).unwrap().value = SettingValue::Float(0.5);
+    app.world_mut().entity_mut(entity).insert(SettingChanged {
+        old_value: SettingValue::Float(1.0),
+    });
+    app.update();
+    assert_eq!(
+        app.world().resource::<GlobalVolume>().volume,
+        Volume::Linear(0.5)
+    );
+
+    // Hidden apps are muted
+    app.world_mut().resource_mut::<AppLifecycleState>().muted = true;
+    app.update();
+    assert_eq!(
+        app.world().resource::<GlobalVolume>().volume,
+        Volume::Linear(0.0)
+    );
+}
+
+#[test]
+fn test_settings_index() {
+    let mut app = create_test_app();
+
+    let entity = app
+        .world_mut()
+        .spawn(create_test_setting("volume", SettingValue::Float(0.5)))
+        .id();
+    app.world_mut()
+        .spawn(create_test_setting("music", SettingValue::Bool(true)));
+
+    let index = app.world().resource::<SettingsIndex>();
+    assert_eq!(index.len(), 2);
+    assert_eq!(index.get("volume"), Some(entity));
+
+    app.world_mut().despawn(entity);
+
+    let index = app.world().resource::<SettingsIndex>();
+    assert_eq!(index.get("volume"), None);
+    assert!(index.contains("music"));
+}
+
+#[test]
+fn test_settings_index_duplicates() {
+    let mut app = create_test_app();
+
+    let first = app
+        .world_mut()
+        .spawn(create_test_setting("volume", SettingValue::Float(0.5)))
+        .id();
+    let second = app
+        .world_mut()
+        .spawn(create_test_setting("volume", SettingValue::Float(0.8)))
+        .id();
+    let third = app
+        .world_mut()
+        .spawn(create_test_setting("volume", SettingValue::Float(1.0)))
+        .id();
+    assert_eq!(
+        app.world().resource::<SettingsIndex>().get("volume"),
+        Some(third)
+    );
+
+    // Removing a shadowed duplicate keeps the indexed setting
+    app.world_mut().despawn(second);
+    assert_eq!(
+        app.world().resource::<SettingsIndex>().get("volume"),
+        Some(third)
+    );
+
+    // Removing the indexed setting falls back to the remaining one
+    app.world_mut().despawn(third);
+    assert_eq!(
+        app.world().resource::<SettingsIndex>().get("volume"),
+        Some(first)
+    );
+
+    app.world_mut().despawn(first);
+    assert!(app.world().resource::<SettingsIndex>().is_empty());
+}
+
+#[test]
+fn test_setting_helpers_use_index() {
+    #[derive(Resource, Default)]
+    struct Found(Option<SettingValue>);
+
+    let mut app = create_test_app();
+    app.init_resource::<Found>();
+    let first = app
+        .world_mut()
+        .spawn(create_test_setting("volume", SettingValue::Float(0.5)))
+        .id();
+    let second = app
+        .world_mut()
+        .spawn(create_test_setting("volume", SettingValue::Float(0.8)))
+        .id();
+    app.update();
+
+    // The helpers change and read the setting the index points to
+    app.world_mut()
+        .run_system_once(
+            |index: Res<SettingsIndex>,
+             settings: Query<(Entity, &mut Setting)>,
+             commands: Commands| {
+                assert!(update_setting_by_id(
+                    "volume",
+                    SettingValue::Float(0.2),
+                    &index,
+                    settings,
+                    commands
+                ));
+            },
+        )
+        .unwrap();
+    app.world_mut()
+        .run_system_once(
+            |index: Res<SettingsIndex>, settings: Query<&Setting>, mut found: ResMut<Found>| {
+                found.0 = get_setting_value(&settings, &index, "volume");
+            },
+        )
+        .unwrap();
+
+    assert_eq!(
+        app.world().resource::<Found>().0,
+        Some(SettingValue::Float(0.2))
+    );
+    assert_eq!(
+        app.world().get::<Setting>(second).unwrap().value,
+        SettingValue::Float(0.2)
+    );
+    assert_eq!(
+        app.world().get::<Setting>(first).unwrap().value,
+        SettingValue::Float(0.5)
+    );
+}
+
+#[test]
+fn test_settings_profiles() {
+    let mut app = create_test_app();
+    app.add_plugins(SettingsProfilesPlugin);
+    let volume = app
+        .world_mut()
+        .spawn(create_test_setting("volume", SettingValue::Float(1.0)))
+        .id();
+    let hints = app
+        .world_mut()
+        .spawn(create_test_setting("hints", SettingValue::Bool(false)))
+        .id();
+    app.update();
+
+    let change = |app: &mut App, entity: Entity, value: SettingValue| {
+        let old_value = std::mem::replace(
+            &mut app.world_mut().get_mut::<Setting>(entity).unwrap().value,
+            value,
+        );
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(SettingChanged { old_value });
+        app.update();
+    };
+    let value =
+        |app: &App, entity: Entity| app.world().get::<Setting>(entity).unwrap().value.clone();
+
+    app.world_mut()
+        .resource_mut::<SettingsProfiles>()
+        .create("Classroom");
+    app.update();
+    app.world_mut()
+        .resource_mut::<SettingsProfiles>()
+        .insert(SettingsProfile::new("Kid mode").with_value("hints", SettingValue::Bool(true)));
+
+    // Changes are remembered in the active profile
+    change(&mut app, volume, SettingValue::Float(0.5));
+    let profiles = app.world().resource::<SettingsProfiles>();
+    assert!(profiles.is_active("Classroom"));
+    assert_eq!(
+        profiles.get("Classroom").unwrap().values.get("volume"),
+        Some(&SettingValue::Float(0.5))
+    );
+
+    app.world_mut()
+        .resource_mut::<SettingsProfiles>()
+        .switch("Kid mode");
+    app.update();
+    app.update();
+    assert_eq!(value(&app, hints), SettingValue::Bool(true));
+    assert_eq!(value(&app, volume), SettingValue::Float(0.5));
+
+    app.world_mut()
+        .resource_mut::<SettingsProfiles>()
+        .switch("Classroom");
+    app.update();
+    assert_eq!(value(&app, hints), SettingValue::Bool(false));
+
+    let mut profiles = app.world_mut().resource_mut::<SettingsProfiles>();
+    assert!(profiles.delete("Classroom"));
+    assert!(profiles.active().is_none());
+    assert_eq!(profiles.profiles().len(), 1);
+}
+
+crate::setting_ids! {
+    enum TestSettingId {
+        Volume = "volume",
+        Music = "music",
+    }
+}
+
+#[test]
+fn test_typed_setting_ids() {
+    let mut app = create_test_app();
+
+    let entity = app
+        .world_mut()
+        .spawn(Setting::new(
+            TestSettingId::Volume,
+            "Volume",
+            SettingValue::Float(0.5),
+            SettingType::FloatRange {
+                min: 0.0,
+                max: 1.0,
+                step: 0.1,
+            },
+        ))
+        .id();
+
+    let index = app.world().resource::<SettingsIndex>();
+    assert_eq!(index.get(TestSettingId::Volume), Some(entity));
+    assert!(!index.contains(TestSettingId::Music));
+
+    assert_eq!(TestSettingId::Music.to_string(), "music");
+    assert_eq!(
+        TestSettingId::from_setting_id("volume"),
+        Some(TestSettingId::Volume)
+    );
+    assert_eq!(TestSettingId::ALL.len(), 2);
+}
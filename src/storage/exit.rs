@@ -0,0 +1,125 @@
+use bevy::prelude::*;
+
+/// Message requesting all persisted state to be written right away, e.g.
+/// before the app exits.
+///
+/// The [`PersistenceScheduler`] writes its pending values at the end of the
+/// frame the message was sent in.
+///
+/// [`PersistenceScheduler`]: super::PersistenceScheduler
+#[derive(Message, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FlushStorage;
+
+/// Message requesting the app to exit once persisted state was flushed.
+///
+/// Send it instead of [`AppExit`] so settings and progress changed just
+/// before quitting are not lost: a [`FlushStorage`] is sent at the start of
+/// the next frame and [`AppExit`] at its end, after the save systems ran.
+#[derive(Message, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExitRequested;
+
+/// Resource telling whether persisted state has not been written yet.
+///
+/// On the web the browser asks the player before leaving the page while
+/// there are unsaved changes, as `beforeunload` can not wait for a flush.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UnsavedChanges(pub bool);
+
+/// Resource set while the app waits for the flush before exiting
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PendingExit(pub bool);
+
+/// Register the graceful exit, called by the [`StoragePlugin`]
+///
+/// [`StoragePlugin`]: super::StoragePlugin
+pub(super) fn build_graceful_exit(app: &mut App) {
+    #[cfg(target_arch = "wasm32")]
+    browser::listen_for_unload();
+
+    app.init_resource::<UnsavedChanges>()
+        .init_resource::<PendingExit>()
+        .add_message::<FlushStorage>()
+        .add_message::<ExitRequested>()
+        .add_message::<AppExit>()
+        .add_systems(First, begin_graceful_exit)
+        .add_systems(Last, finish_graceful_exit);
+
+    #[cfg(target_arch = "wasm32")]
+    app.add_systems(Last, sync_unload_guard);
+}
+
+/// System to flush the persisted state when an exit was requested
+pub fn begin_graceful_exit(
+    mut requests: MessageReader<ExitRequested>,
+    mut pending: ResMut<PendingExit>,
+    mut flush: MessageWriter<FlushStorage>,
+) {
+    if requests.read().count() == 0 || pending.0 {
+        return;
+    }
+    info!("Exit requested, flushing storage");
+    pending.0 = true;
+    flush.write(FlushStorage);
+}
+
+/// System to exit after the save systems flushed the persisted state
+pub fn finish_graceful_exit(mut pending: ResMut<PendingExit>, mut exit: MessageWriter<AppExit>) {
+    if !pending.0 {
+        return;
+    }
+    pending.0 = false;
+    exit.write(AppExit::Success);
+}
+
+/// System to let the browser ask before leaving a page with unsaved changes
+#[cfg(target_arch = "wasm32")]
+fn sync_unload_guard(unsaved: Res<UnsavedChanges>) {
+    if unsaved.is_changed() {
+        browser::set_confirm_unload(unsaved.0);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod browser {
+    use std::cell::Cell;
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        pub type BeforeUnloadEvent;
+
+        #[wasm_bindgen(method, js_name = preventDefault)]
+        fn prevent_default(this: &BeforeUnloadEvent);
+
+        #[wasm_bindgen(method, setter = returnValue)]
+        fn set_return_value(this: &BeforeUnloadEvent, value: &str);
+
+        #[wasm_bindgen(js_namespace = window, js_name = addEventListener)]
+        fn add_unload_listener(event: &str, listener: &Closure<dyn FnMut(BeforeUnloadEvent)>);
+    }
+
+    thread_local! {
+        static CONFIRM_UNLOAD: Cell<bool> = const { Cell::new(false) };
+        static LISTENING: Cell<bool> = const { Cell::new(false) };
+    }
+
+    pub fn listen_for_unload() {
+        if LISTENING.with(|listening| listening.replace(true)) {
+            return;
+        }
+
+        let on_unload = Closure::<dyn FnMut(BeforeUnloadEvent)>::new(|event: BeforeUnloadEvent| {
+            if CONFIRM_UNLOAD.with(Cell::get) {
+                // Browsers show their own "Leave site?" dialog
+                event.prevent_default();
+                event.set_return_value("");
+            }
+        });
+        add_unload_listener("beforeunload", &on_unload);
+        on_unload.forget();
+    }
+
+    pub fn set_confirm_unload(confirm: bool) {
+        CONFIRM_UNLOAD.with(|confirm_unload| confirm_unload.set(confirm));
+    }
+}
@@ -0,0 +1,368 @@
+pub mod exit;
+pub mod scheduler;
+
+pub use exit::*;
+pub use scheduler::*;
+
+use bevy::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+use thiserror::Error;
+
+/// Errors produced by storage backends
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum StorageError {
+    /// An IO Error
+    #[error("Could not access storage: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A serialization error
+    #[error("Could not (de)serialize stored value: {0}")]
+    Serialization(#[from] serde_yaml::Error),
+
+    /// A JSON serialization error, e.g. of an exported report
+    #[error("Could not serialize JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// The backend is not available on this platform
+    #[error("Storage backend unavailable: {0}")]
+    Unavailable(String),
+}
+
+/// Key-value storage used to persist game data
+pub trait StorageBackend: Send + Sync + 'static {
+    /// Read the value stored for a key
+    fn get(&self, key: &str) -> Option<String>;
+
+    /// Store a value for a key
+    fn set(&self, key: &str, value: &str) -> Result<(), StorageError>;
+
+    /// Remove a stored value
+    fn remove(&self, key: &str) -> Result<(), StorageError>;
+}
+
+/// In-memory storage, nothing survives a restart
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    values: Mutex<HashMap<String, String>>,
+}
+
+impl StorageBackend for MemoryStorage {
+    fn get(&self, key: &str) -> Option<String> {
+        self.values.lock().ok()?.get(key).cloned()
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), StorageError> {
+        if let Ok(mut values) = self.values.lock() {
+            values.insert(key.to_string(), value.to_string());
+        }
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<(), StorageError> {
+        if let Ok(mut values) = self.values.lock() {
+            values.remove(key);
+        }
+        Ok(())
+    }
+}
+
+/// Storage writing one YAML file per key into a directory
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub struct FileStorage {
+    directory: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileStorage {
+    pub fn new(directory: impl Into<std::path::PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    fn path(&self, key: &str) -> std::path::PathBuf {
+        let file_name: String = key
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || c == '-' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        self.directory.join(format!("{}.yml", file_name))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl StorageBackend for FileStorage {
+    fn get(&self, key: &str) -> Option<String> {
+        std::fs::read_to_string(self.path(key)).ok()
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), StorageError> {
+        std::fs::create_dir_all(&self.directory)?;
+        std::fs::write(self.path(key), value)?;
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<(), StorageError> {
+        let path = self.path(key);
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+}
+
+/// Storage backed by the browser's `localStorage`
+#[cfg(target_arch = "wasm32")]
+#[derive(Debug, Clone)]
+pub struct LocalStorage {
+    prefix: String,
+}
+
+#[cfg(target_arch = "wasm32")]
+mod local_storage {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["window", "localStorage"], js_name = getItem)]
+        pub fn get_item(key: &str) -> Option<String>;
+
+        #[wasm_bindgen(catch, js_namespace = ["window", "localStorage"], js_name = setItem)]
+        pub fn set_item(key: &str, value: &str) -> Result<(), JsValue>;
+
+        #[wasm_bindgen(js_namespace = ["window", "localStorage"], js_name = removeItem)]
+        pub fn remove_item(key: &str);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl LocalStorage {
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+        }
+    }
+
+    fn key(&self, key: &str) -> String {
+        format!("{}.{}", self.prefix, key)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl StorageBackend for LocalStorage {
+    fn get(&self, key: &str) -> Option<String> {
+        local_storage::get_item(&self.key(key))
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), StorageError> {
+        local_storage::set_item(&self.key(key), value)
+            .map_err(|e| StorageError::Unavailable(format!("{:?}", e)))
+    }
+
+    fn remove(&self, key: &str) -> Result<(), StorageError> {
+        local_storage::remove_item(&self.key(key));
+        Ok(())
+    }
+}
+
+/// Resource giving access to the configured storage backend
+#[derive(Resource, Clone)]
+pub struct Storage {
+    backend: Arc<dyn StorageBackend>,
+}
+
+impl Storage {
+    pub fn new(backend: impl StorageBackend) -> Self {
+        Self {
+            backend: Arc::new(backend),
+        }
+    }
+
+    /// In-memory storage, useful for tests
+    pub fn memory() -> Self {
+        Self::new(MemoryStorage::default())
+    }
+
+    /// Platform default: `localStorage` on the web, a local directory on native
+    pub fn platform_default(namespace: &str) -> Self {
+        #[cfg(target_arch = "wasm32")]
+        {
+            Self::new(LocalStorage::new(namespace))
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            Self::new(FileStorage::new(format!(".{}", namespace)))
+        }
+    }
+
+    /// Get the raw backend
+    pub fn backend(&self) -> &dyn StorageBackend {
+        self.backend.as_ref()
+    }
+
+    /// Load and deserialize a stored value
+    pub fn load<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, StorageError> {
+        match self.backend.get(key) {
+            Some(value) => Ok(Some(serde_yaml::from_str(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Serialize and store a value
+    pub fn save<T: Serialize>(&self, key: &str, value: &T) -> Result<(), StorageError> {
+        let value = serde_yaml::to_string(value)?;
+        self.backend.set(key, &value)
+    }
+
+    /// Remove a stored value
+    pub fn remove(&self, key: &str) -> Result<(), StorageError> {
+        self.backend.remove(key)
+    }
+}
+
+/// Plugin providing the [`Storage`] resource.
+///
+/// Also provides the [`PersistenceScheduler`] batching the writes of persisted
+/// state, and makes [`ExitRequested`] flush them before the app exits.
+pub struct StoragePlugin {
+    /// Namespace used for the storage location (directory or key prefix)
+    pub namespace: String,
+}
+
+impl Default for StoragePlugin {
+    fn default() -> Self {
+        Self {
+            namespace: "konnektoren".to_string(),
+        }
+    }
+}
+
+impl StoragePlugin {
+    pub fn new(namespace: impl Into<String>) -> Self {
+        Self {
+            namespace: namespace.into(),
+        }
+    }
+}
+
+impl Plugin for StoragePlugin {
+    fn build(&self, app: &mut App) {
+        // Keep a storage inserted by the app (e.g. a custom backend)
+        if !app.world().contains_resource::<Storage>() {
+            app.insert_resource(Storage::platform_default(&self.namespace));
+        }
+        exit::build_graceful_exit(app);
+        scheduler::build_auto_save(app);
+
+        info!("StoragePlugin loaded with namespace '{}'", self.namespace);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_storage_roundtrip() {
+        let storage = Storage::memory();
+
+        assert_eq!(storage.load::<Vec<String>>("missing").unwrap(), None);
+
+        let value = vec!["a".to_string(), "b".to_string()];
+        storage.save("list", &value).unwrap();
+        assert_eq!(storage.load::<Vec<String>>("list").unwrap(), Some(value));
+
+        storage.remove("list").unwrap();
+        assert_eq!(storage.load::<Vec<String>>("list").unwrap(), None);
+    }
+
+    #[test]
+    fn test_exit_flushes_storage() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(Storage::memory())
+            .add_plugins(StoragePlugin::default())
+            .add_systems(
+                PostUpdate,
+                |storage: Res<Storage>, mut flush: MessageReader<FlushStorage>| {
+                    if flush.read().count() > 0 {
+                        storage.save("level", &3).unwrap();
+                    }
+                },
+            );
+        app.update();
+        assert_eq!(app.should_exit(), None);
+
+        app.world_mut().write_message(ExitRequested);
+        app.update();
+        let storage = app.world().resource::<Storage>();
+        assert_eq!(storage.load::<u32>("level").unwrap(), Some(3));
+        assert_eq!(app.should_exit(), Some(AppExit::Success));
+    }
+
+    #[test]
+    fn test_persistence_scheduler() {
+        use bevy::time::TimeUpdateStrategy;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(Storage::memory())
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .insert_resource(PersistenceScheduler::new(Duration::from_millis(500)))
+            .add_plugins(StoragePlugin::default());
+        app.update();
+
+        // Writes to the same key are coalesced until the delay passed
+        for level in 1..=3 {
+            let mut scheduler = app.world_mut().resource_mut::<PersistenceScheduler>();
+            scheduler.schedule("level", &level);
+            app.update();
+        }
+        let level = |app: &App| app.world().resource::<Storage>().load::<u32>("level");
+        assert_eq!(level(&app).unwrap(), None);
+        assert_eq!(
+            *app.world().resource::<AutoSaveStatus>(),
+            AutoSaveStatus::Pending
+        );
+        assert_eq!(
+            *app.world().resource::<UnsavedChanges>(),
+            UnsavedChanges(true)
+        );
+
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(level(&app).unwrap(), Some(3));
+        assert!(matches!(
+            app.world().resource::<AutoSaveStatus>(),
+            AutoSaveStatus::Saved { .. }
+        ));
+        assert_eq!(
+            *app.world().resource::<UnsavedChanges>(),
+            UnsavedChanges(false)
+        );
+
+        // A flush writes right away
+        app.world_mut()
+            .resource_mut::<PersistenceScheduler>()
+            .schedule("level", &4);
+        app.world_mut().write_message(FlushStorage);
+        app.update();
+        assert_eq!(level(&app).unwrap(), Some(4));
+    }
+}
@@ -0,0 +1,182 @@
+use super::{exit::finish_graceful_exit, FlushStorage, Storage, StorageError, UnsavedChanges};
+use bevy::prelude::*;
+use serde::Serialize;
+use std::{collections::BTreeMap, time::Duration};
+
+/// Seconds the "Saved" state of the [`AutoSaveStatus`] stays visible
+pub const SAVED_VISIBLE_SECS: f64 = 2.0;
+
+/// Register the auto-save, called by the [`StoragePlugin`]
+///
+/// [`StoragePlugin`]: super::StoragePlugin
+pub(super) fn build_auto_save(app: &mut App) {
+    app.init_resource::<PersistenceScheduler>()
+        .init_resource::<AutoSaveStatus>()
+        .add_systems(
+            Last,
+            flush_persistence_scheduler.before(finish_graceful_exit),
+        );
+}
+
+/// Resource collecting the writes of persisted state.
+///
+/// Save systems schedule the new value of a resource whenever it changed
+/// instead of writing it right away. Values scheduled for the same key are
+/// coalesced, only the latest one is written when the scheduler flushes:
+///
+/// - `delay` after the first pending change,
+/// - when a [`FlushStorage`] is sent, e.g. before the app exits or by the
+///   [`LifecyclePlugin`] when the app is paused or hidden (mobile and web).
+///
+/// ```ignore
+/// fn save_progress(progress: Res<Progress>, scheduler: Option<ResMut<PersistenceScheduler>>) {
+///     if let (true, Some(mut scheduler)) = (progress.is_changed(), scheduler) {
+///         scheduler.schedule("progress", &*progress);
+///     }
+/// }
+/// ```
+///
+/// [`LifecyclePlugin`]: crate::lifecycle::LifecyclePlugin
+#[derive(Resource, Debug)]
+pub struct PersistenceScheduler {
+    /// Time changes are collected before they are written
+    pub delay: Duration,
+    /// Serialized values by storage key
+    pending: BTreeMap<String, String>,
+    timer: Timer,
+}
+
+impl Default for PersistenceScheduler {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(2))
+    }
+}
+
+impl PersistenceScheduler {
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            pending: BTreeMap::new(),
+            timer: Timer::new(delay, TimerMode::Once),
+        }
+    }
+
+    /// Schedule a value to be written to `key`, replacing a pending value
+    pub fn schedule<T: Serialize>(&mut self, key: &str, value: &T) {
+        let value = match serde_yaml::to_string(value) {
+            Ok(value) => value,
+            Err(e) => {
+                warn!("Failed to serialize '{}' for saving: {}", key, e);
+                return;
+            }
+        };
+
+        if self.pending.is_empty() {
+            self.timer = Timer::new(self.delay, TimerMode::Once);
+        }
+        self.pending.insert(key.to_string(), value);
+    }
+
+    /// Whether writes wait for the next flush
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Keys of the pending writes
+    pub fn pending_keys(&self) -> impl Iterator<Item = &str> {
+        self.pending.keys().map(String::as_str)
+    }
+
+    /// Write all pending values, returns the number of written values.
+    ///
+    /// Values that could not be written stay pending.
+    pub fn flush(&mut self, storage: &Storage) -> Result<usize, StorageError> {
+        let mut written = 0;
+        let mut result = Ok(());
+        for (key, value) in std::mem::take(&mut self.pending) {
+            match storage.backend().set(&key, &value) {
+                Ok(()) => written += 1,
+                Err(e) => {
+                    warn!("Failed to save '{}': {}", key, e);
+                    self.pending.insert(key, value);
+                    result = Err(e);
+                    // Retry after the delay
+                    self.timer = Timer::new(self.delay, TimerMode::Once);
+                }
+            }
+        }
+        result.map(|_| written)
+    }
+
+    /// Advance the delay, returns whether the pending writes are due
+    fn tick(&mut self, delta: Duration) -> bool {
+        self.is_pending() && self.timer.tick(delta).is_finished()
+    }
+}
+
+/// Resource with the state of the auto-save, e.g. for a saving indicator
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub enum AutoSaveStatus {
+    /// Nothing was saved yet
+    #[default]
+    Idle,
+    /// Changes wait for the next flush
+    Pending,
+    /// Everything was saved at the elapsed app time
+    Saved { at_secs: f64 },
+    /// The last flush failed, the changes stay pending
+    Failed(String),
+}
+
+impl AutoSaveStatus {
+    /// Short text of the state
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Idle => "",
+            Self::Pending => "Saving…",
+            Self::Saved { .. } => "Saved",
+            Self::Failed(_) => "Could not save",
+        }
+    }
+
+    /// Whether an indicator should show the state at `now_secs`
+    pub fn is_visible(&self, now_secs: f64) -> bool {
+        match self {
+            Self::Idle => false,
+            Self::Pending | Self::Failed(_) => true,
+            Self::Saved { at_secs } => now_secs - at_secs < SAVED_VISIBLE_SECS,
+        }
+    }
+}
+
+/// System to write the pending values when they are due
+fn flush_persistence_scheduler(
+    time: Res<Time>,
+    storage: Option<Res<Storage>>,
+    mut scheduler: ResMut<PersistenceScheduler>,
+    mut status: ResMut<AutoSaveStatus>,
+    mut unsaved: ResMut<UnsavedChanges>,
+    mut flush: MessageReader<FlushStorage>,
+) {
+    let requested = flush.read().count() > 0;
+    let due = scheduler.tick(time.delta());
+
+    if scheduler.is_pending() && (due || requested) {
+        if let Some(storage) = storage {
+            match scheduler.flush(&storage) {
+                Ok(written) => {
+                    debug!("Auto-saved {} values", written);
+                    *status = AutoSaveStatus::Saved {
+                        at_secs: time.elapsed_secs_f64(),
+                    };
+                }
+                Err(e) => *status = AutoSaveStatus::Failed(e.to_string()),
+            }
+        }
+    }
+
+    if scheduler.is_pending() && !matches!(*status, AutoSaveStatus::Failed(_)) {
+        status.set_if_neq(AutoSaveStatus::Pending);
+    }
+    unsaved.set_if_neq(UnsavedChanges(scheduler.is_pending()));
+}
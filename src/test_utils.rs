@@ -0,0 +1,1011 @@
+//! Headless harness for testing screens and widgets, built on
+//! [`egui_kittest`].
+//!
+//! ```ignore
+//! let mut harness = TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin, AboutPlugin));
+//! harness.world_mut().spawn(AboutConfig::new("My Game"));
+//! harness.step();
+//!
+//! harness.click("← Back");
+//! assert_eq!(harness.read_messages::<AboutDismissed>().len(), 1);
+//! ```
+
+use bevy::{
+    app::Plugins, ecs::message::MessageCursor, prelude::*, state::app::StatesPlugin,
+    window::WindowResolution,
+};
+use bevy_egui::{egui, EguiContext, EguiPrimaryContextPass, EguiUserTextures, PrimaryEguiContext};
+use egui_kittest::{
+    kittest::{by, AccessKitNode, NodeT, Queryable},
+    Harness,
+};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+};
+
+/// Default size of the headless screen
+pub const TEST_SCREEN_SIZE: Vec2 = Vec2::new(1280.0, 720.0);
+
+/// Time advanced per frame
+const FRAME_TIME: f32 = 1.0 / 60.0;
+
+/// App with `MinimalPlugins` driven by an [`egui_kittest::Harness`].
+///
+/// The primary egui context of the app is the context of the kittest harness.
+/// Every [`step`](TestHarness::step) updates the app and then runs the
+/// [`EguiPrimaryContextPass`] as a kittest frame with the queued input, like
+/// the egui plugin does in a windowed app. Widgets are found by their label
+/// through the kittest accessibility tree.
+pub struct TestHarness {
+    harness: Harness<'static, App>,
+    screen_size: Vec2,
+    cursors: HashMap<TypeId, Box<dyn Any>>,
+}
+
+impl Default for TestHarness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TestHarness {
+    pub fn new() -> Self {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default(), StatesPlugin))
+            .init_asset::<Image>()
+            .init_resource::<EguiUserTextures>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_schedule(EguiPrimaryContextPass);
+
+        let harness = Harness::builder()
+            .with_size(egui::vec2(TEST_SCREEN_SIZE.x, TEST_SCREEN_SIZE.y))
+            .with_step_dt(FRAME_TIME)
+            .build_state(
+                |_, app: &mut App| app.world_mut().run_schedule(EguiPrimaryContextPass),
+                app,
+            );
+
+        // Screens draw into the kittest context through `EguiContexts`
+        let ctx = harness.ctx.clone();
+        let mut harness = Self {
+            harness,
+            screen_size: TEST_SCREEN_SIZE,
+            cursors: HashMap::new(),
+        };
+        let world = harness.world_mut();
+        let context = world.spawn(PrimaryEguiContext).id();
+        *world
+            .get_mut::<EguiContext>(context)
+            .expect("primary context requires EguiContext")
+            .get_mut() = ctx;
+
+        harness.spawn_window();
+        harness
+    }
+
+    /// Add plugins under test
+    pub fn with_plugins<M>(mut self, plugins: impl Plugins<M>) -> Self {
+        self.app_mut().add_plugins(plugins);
+        self
+    }
+
+    /// Change the screen size, e.g. to test the mobile layout
+    pub fn with_screen_size(mut self, width: f32, height: f32) -> Self {
+        self.screen_size = Vec2::new(width, height);
+        self.harness.set_size(egui::vec2(width, height));
+        self.spawn_window();
+        self
+    }
+
+    fn spawn_window(&mut self) {
+        let screen_size = self.screen_size;
+        let world = self.world_mut();
+        let windows: Vec<Entity> = world
+            .query_filtered::<Entity, With<Window>>()
+            .iter(world)
+            .collect();
+        for window in windows {
+            world.despawn(window);
+        }
+
+        world.spawn(Window {
+            resolution: WindowResolution::from((screen_size.x as u32, screen_size.y as u32)),
+            ..default()
+        });
+    }
+
+    pub fn app(&self) -> &App {
+        self.harness.state()
+    }
+
+    pub fn app_mut(&mut self) -> &mut App {
+        self.harness.state_mut()
+    }
+
+    pub fn world(&self) -> &World {
+        self.app().world()
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app_mut().world_mut()
+    }
+
+    /// The kittest harness, e.g. for queries with [`by`]
+    pub fn kittest(&self) -> &Harness<'static, App> {
+        &self.harness
+    }
+
+    /// Run one frame: update the app and run the egui pass with the queued input
+    pub fn step(&mut self) {
+        self.app_mut().update();
+        self.harness.step();
+    }
+
+    fn queue(&mut self, event: egui::Event) {
+        self.harness.input_mut().events.push(event);
+    }
+
+    /// Run several frames
+    pub fn run_frames(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.step();
+        }
+    }
+
+    /// Output of the last egui pass
+    pub fn output(&self) -> &egui::FullOutput {
+        self.harness.output()
+    }
+
+    /// Labels of all widgets shown in the last frame
+    pub fn widget_labels(&self) -> Vec<String> {
+        self.harness
+            .query_all(by().predicate(|_| true))
+            .filter_map(|node| node_label(&node.accesskit_node()))
+            .collect()
+    }
+
+    /// Whether a widget with the label was shown in the last frame
+    pub fn has_widget(&self, label: &str) -> bool {
+        self.widget_rect(label).is_some()
+    }
+
+    /// Screen rectangle of the widget with the label in the last frame
+    pub fn widget_rect(&self, label: &str) -> Option<egui::Rect> {
+        self.harness
+            .query_all(by().predicate(|node| node_label(node).as_deref() == Some(label)))
+            .find(|node| node.accesskit_node().bounding_box().is_some())
+            .map(|node| node.rect())
+    }
+
+    /// Click the widget with the label and run the frames needed to handle it.
+    ///
+    /// Panics if no such widget was shown in the last frame.
+    pub fn click(&mut self, label: &str) {
+        let Some(rect) = self.widget_rect(label) else {
+            panic!(
+                "No widget labeled '{}', shown widgets: {:?}",
+                label,
+                self.widget_labels()
+            );
+        };
+        self.click_at(rect.center());
+    }
+
+    /// Click at a screen position
+    pub fn click_at(&mut self, pos: egui::Pos2) {
+        self.queue(egui::Event::PointerMoved(pos));
+        self.step();
+
+        for pressed in [true, false] {
+            self.queue(egui::Event::PointerButton {
+                pos,
+                button: egui::PointerButton::Primary,
+                pressed,
+                modifiers: egui::Modifiers::NONE,
+            });
+        }
+        self.step();
+        // Let Update systems see the messages written during the click
+        self.step();
+    }
+
+    /// Drag with the primary button from one screen position to another
+    pub fn drag(&mut self, from: egui::Pos2, to: egui::Pos2) {
+        self.queue(egui::Event::PointerMoved(from));
+        self.step();
+        self.queue(egui::Event::PointerButton {
+            pos: from,
+            button: egui::PointerButton::Primary,
+            pressed: true,
+            modifiers: egui::Modifiers::NONE,
+        });
+        self.step();
+
+        // Move in steps so egui detects the drag and the target the hovering
+        for step in 1..=4 {
+            self.queue(egui::Event::PointerMoved(from.lerp(to, step as f32 / 4.0)));
+            self.step();
+        }
+
+        self.queue(egui::Event::PointerButton {
+            pos: to,
+            button: egui::PointerButton::Primary,
+            pressed: false,
+            modifiers: egui::Modifiers::NONE,
+        });
+        self.step();
+    }
+
+    /// Press the primary button at a screen position and keep it held
+    pub fn press_at(&mut self, pos: egui::Pos2) {
+        self.queue(egui::Event::PointerMoved(pos));
+        self.step();
+        self.queue(egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed: true,
+            modifiers: egui::Modifiers::NONE,
+        });
+        self.step();
+    }
+
+    /// Release the primary button pressed with [`press_at`](Self::press_at)
+    pub fn release_at(&mut self, pos: egui::Pos2) {
+        self.queue(egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed: false,
+            modifiers: egui::Modifiers::NONE,
+        });
+        self.step();
+    }
+
+    /// Turn the mouse wheel at a screen position, positive `delta.y` scrolls up
+    pub fn scroll_at(&mut self, pos: egui::Pos2, delta: egui::Vec2) {
+        self.queue(egui::Event::PointerMoved(pos));
+        self.queue(egui::Event::MouseWheel {
+            unit: egui::MouseWheelUnit::Point,
+            delta,
+            modifiers: egui::Modifiers::NONE,
+        });
+        self.step();
+    }
+
+    /// Label of the widget with keyboard focus in the last frame
+    pub fn focused_widget(&self) -> Option<String> {
+        self.harness
+            .query_all(by().predicate(|node| node.is_focused()))
+            .find_map(|node| node_label(&node.accesskit_node()))
+    }
+
+    /// Press and release a key in the egui context
+    pub fn press_key(&mut self, key: egui::Key) {
+        for pressed in [true, false] {
+            self.queue(egui::Event::Key {
+                key,
+                physical_key: None,
+                pressed,
+                repeat: false,
+                modifiers: egui::Modifiers::NONE,
+            });
+        }
+        self.step();
+    }
+
+    /// Press and release a key through Bevy's [`ButtonInput<KeyCode>`]
+    pub fn press_key_code(&mut self, key: KeyCode) {
+        for pressed in [true, false] {
+            let mut input = self.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            input.clear();
+            if pressed {
+                input.press(key);
+            } else {
+                input.release(key);
+            }
+            self.step();
+        }
+        self.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .clear();
+    }
+
+    /// Type text into the focused text field
+    pub fn type_text(&mut self, text: &str) {
+        self.queue(egui::Event::Text(text.to_string()));
+        self.step();
+    }
+
+    /// Messages of type `M` sent since the last call
+    pub fn read_messages<M: Message + Clone>(&mut self) -> Vec<M> {
+        let Some(messages) = self.harness.state().world().get_resource::<Messages<M>>() else {
+            return Vec::new();
+        };
+
+        let cursor = self
+            .cursors
+            .entry(TypeId::of::<M>())
+            .or_insert_with(|| Box::new(MessageCursor::<M>::default()))
+            .downcast_mut::<MessageCursor<M>>()
+            .expect("message cursor of another type");
+        cursor.read(messages).cloned().collect()
+    }
+
+    /// Number of messages of type `M` sent since the last read
+    pub fn count_messages<M: Message>(&mut self) -> usize {
+        let Some(messages) = self.harness.state().world().get_resource::<Messages<M>>() else {
+            return 0;
+        };
+
+        let cursor = self
+            .cursors
+            .entry(TypeId::of::<M>())
+            .or_insert_with(|| Box::new(MessageCursor::<M>::default()))
+            .downcast_mut::<MessageCursor<M>>()
+            .expect("message cursor of another type");
+        cursor.read(messages).count()
+    }
+
+    /// Send a message as if it came from the app
+    pub fn write_message<M: Message>(&mut self, message: M) {
+        self.world_mut().write_message(message);
+    }
+}
+
+/// Label of a widget, plain text labels carry their text as value
+fn node_label(node: &AccessKitNode<'_>) -> Option<String> {
+    node.label().or_else(|| node.value())
+}
+
+#[cfg(all(test, feature = "screens"))]
+mod tests {
+    use super::*;
+    use crate::{
+        screens::{AboutConfig, AboutPlugin, ScreenAction, ScreenEvent, ScreenKind},
+        theme::{KonnektorenTheme, KonnektorenThemePlugin},
+        ui::{DragSource, DropTarget, UIPlugin},
+    };
+    use bevy_egui::EguiContexts;
+
+    #[test]
+    fn test_click_about_back_button() {
+        let mut harness =
+            TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin, AboutPlugin));
+        let about = harness.world_mut().spawn(AboutConfig::default()).id();
+        harness.run_frames(2);
+
+        assert!(harness.has_widget("← Back"));
+        harness.click("← Back");
+
+        let events = harness.read_messages::<ScreenEvent>();
+        assert_eq!(
+            events,
+            vec![ScreenEvent::new(
+                ScreenKind::About,
+                ScreenAction::Dismissed,
+                about
+            )]
+        );
+    }
+
+    #[test]
+    fn test_about_keyboard_navigation() {
+        let mut harness =
+            TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin, AboutPlugin));
+        let about = harness.world_mut().spawn(AboutConfig::default()).id();
+        harness.run_frames(2);
+
+        // Focus starts at the website link, the back button comes next
+        harness.press_key_code(KeyCode::ArrowDown);
+        assert!(harness.read_messages::<ScreenEvent>().is_empty());
+        harness.press_key_code(KeyCode::Enter);
+
+        let events = harness.read_messages::<ScreenEvent>();
+        assert_eq!(
+            events,
+            vec![ScreenEvent::new(
+                ScreenKind::About,
+                ScreenAction::Dismissed,
+                about
+            )]
+        );
+    }
+
+    #[test]
+    fn test_splash_queue_priority() {
+        use crate::screens::{SplashConfig, SplashDismissed, SplashPlugin, SplashSequenceFinished};
+
+        let mut harness =
+            TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin, SplashPlugin));
+        let title = harness
+            .world_mut()
+            .spawn(SplashConfig::new("Title").infinite())
+            .id();
+        let legal = harness
+            .world_mut()
+            .spawn(
+                SplashConfig::new("Legal Notice")
+                    .infinite()
+                    .with_priority(2),
+            )
+            .id();
+        let brand = harness
+            .world_mut()
+            .spawn(SplashConfig::new("Brand").infinite().with_priority(1))
+            .id();
+        harness.run_frames(2);
+
+        for (entity, shown) in [(legal, "Legal Notice"), (brand, "Brand"), (title, "Title")] {
+            assert!(harness.has_widget(shown));
+            for other in ["Legal Notice", "Brand", "Title"] {
+                assert_eq!(harness.has_widget(other), other == shown);
+            }
+            assert!(harness.read_messages::<SplashSequenceFinished>().is_empty());
+
+            harness.write_message(SplashDismissed { entity });
+            harness.run_frames(2);
+        }
+
+        assert_eq!(
+            harness.read_messages::<SplashSequenceFinished>(),
+            vec![SplashSequenceFinished]
+        );
+    }
+
+    #[test]
+    fn test_splash_image_load_failure() {
+        use crate::screens::{SplashAssetFailed, SplashConfig, SplashPlugin};
+
+        let mut harness =
+            TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin, SplashPlugin));
+        let splash = harness
+            .world_mut()
+            .spawn(
+                SplashConfig::new("Title")
+                    .with_image_logo("missing-logo.png")
+                    .infinite(),
+            )
+            .id();
+
+        // Loading fails on the IO task pool
+        let mut failures = Vec::new();
+        for _ in 0..200 {
+            harness.step();
+            failures.extend(harness.read_messages::<SplashAssetFailed>());
+            if !failures.is_empty() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].entity, splash);
+        assert_eq!(failures[0].path, "missing-logo.png");
+        harness.run_frames(2);
+        assert!(harness.read_messages::<SplashAssetFailed>().is_empty());
+        assert!(harness.has_widget("Title"));
+    }
+
+    #[test]
+    fn test_world_space_about_screen() {
+        use crate::screens::{WorldSpaceScreen, WorldSpaceScreenPlugin, WorldSpaceScreens};
+
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            AboutPlugin,
+            WorldSpaceScreenPlugin,
+        ));
+        let about = harness
+            .world_mut()
+            .spawn((
+                AboutConfig::default(),
+                WorldSpaceScreen::new(UVec2::new(512, 384), Vec2::new(1.6, 1.2)),
+            ))
+            .id();
+        harness.run_frames(2);
+
+        // The screen renders into its texture, not the window
+        assert!(!harness.has_widget("← Back"));
+        let camera = harness
+            .world()
+            .resource::<WorldSpaceScreens>()
+            .get(about)
+            .expect("world-space screen set up")
+            .camera;
+        assert!(harness.world().get_entity(camera).is_ok());
+
+        harness
+            .world_mut()
+            .entity_mut(about)
+            .remove::<WorldSpaceScreen>();
+        harness.step();
+
+        assert!(harness.world().get_entity(camera).is_err());
+        assert!(!harness
+            .world()
+            .resource::<WorldSpaceScreens>()
+            .contains(about));
+        assert!(harness.has_widget("← Back"));
+    }
+
+    #[cfg(all(feature = "settings", feature = "input"))]
+    #[test]
+    fn test_settings_pin_gate() {
+        use crate::{
+            screens::{SettingsScreenConfig, SettingsScreenPlugin, SettingsSection},
+            settings::ParentalControls,
+        };
+
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            SettingsScreenPlugin,
+        ));
+        harness.app_mut().add_message::<crate::input::InputEvent>();
+        harness.world_mut().insert_resource(ParentalControls {
+            enabled: true,
+            pin: "1234".to_string(),
+            ..Default::default()
+        });
+        harness.world_mut().spawn(
+            SettingsScreenConfig::new("Settings")
+                .add_section(SettingsSection::parental_controls_section()),
+        );
+        harness.run_frames(2);
+        assert!(harness.has_widget("Unlock"));
+        assert!(!harness.has_widget("Block External Links"));
+
+        harness.type_text("0000");
+        harness.click("Unlock");
+        assert!(harness.has_widget("Wrong PIN, try again"));
+
+        harness.type_text("1234");
+        harness.click("Unlock");
+        harness.step();
+        assert!(harness.has_widget("Block External Links"));
+    }
+
+    #[cfg(all(feature = "settings", feature = "input"))]
+    #[test]
+    fn test_settings_pin_gate_blocks_undo() {
+        use crate::{
+            screens::{SettingsScreenConfig, SettingsScreenPlugin, SettingsSection},
+            settings::{Edit, EditHistory, ParentalControls, SettingValue},
+        };
+
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            SettingsScreenPlugin,
+        ));
+        harness.app_mut().add_message::<crate::input::InputEvent>();
+        harness.world_mut().insert_resource(ParentalControls {
+            enabled: true,
+            pin: "1234".to_string(),
+            ..Default::default()
+        });
+        let mut history = EditHistory::default();
+        history.record(
+            Edit::Setting {
+                setting_id: "hints".to_string(),
+                old_value: SettingValue::Bool(true),
+                new_value: SettingValue::Bool(false),
+            },
+            0.0,
+        );
+        harness.world_mut().insert_resource(history);
+        harness.world_mut().spawn(
+            SettingsScreenConfig::new("Settings")
+                .add_section(SettingsSection::parental_controls_section()),
+        );
+        harness.run_frames(2);
+        assert!(harness.has_widget("Unlock"));
+
+        let mut input = harness.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        input.press(KeyCode::ControlLeft);
+        input.press(KeyCode::KeyZ);
+        harness.step();
+
+        assert!(harness.world().resource::<EditHistory>().can_undo());
+    }
+
+    #[cfg(all(feature = "settings", feature = "input"))]
+    #[test]
+    fn test_settings_set_pin() {
+        use crate::{
+            screens::{
+                SettingsScreenConfig, SettingsScreenEvent, SettingsScreenPlugin, SettingsSection,
+            },
+            settings::{ParentalControls, SettingValue, PARENTAL_CONTROLS_SETTING_ID},
+        };
+        use egui_kittest::kittest::Queryable;
+
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            SettingsScreenPlugin,
+        ));
+        harness.app_mut().add_message::<crate::input::InputEvent>();
+        harness.world_mut().init_resource::<ParentalControls>();
+        let screen = harness
+            .world_mut()
+            .spawn(
+                SettingsScreenConfig::new("Settings")
+                    .add_section(SettingsSection::parental_controls_section()),
+            )
+            .id();
+        harness.run_frames(2);
+        harness.write_message(SettingsScreenEvent::ValueChanged {
+            entity: screen,
+            setting_id: PARENTAL_CONTROLS_SETTING_ID.to_string(),
+            value: SettingValue::Bool(true),
+        });
+        harness.run_frames(2);
+        assert!(harness.world().resource::<ParentalControls>().enabled);
+
+        // Typing the PIN keeps the screen open, the PIN applies on Enter
+        let field = harness
+            .kittest()
+            .get_by_role(egui::accesskit::Role::PasswordInput)
+            .rect();
+        harness.click_at(field.center());
+        for digit in ["1", "2", "3", "4"] {
+            harness.type_text(digit);
+            assert!(!harness.has_widget("Unlock"));
+            assert!(harness
+                .world()
+                .resource::<ParentalControls>()
+                .pin
+                .is_empty());
+        }
+        harness.press_key(egui::Key::Enter);
+        harness.run_frames(2);
+
+        assert_eq!(harness.world().resource::<ParentalControls>().pin, "1234");
+        assert!(!harness.has_widget("Unlock"));
+        assert!(harness.has_widget("Block External Links"));
+
+        // The PIN change is not read out
+        let announcer = harness
+            .world()
+            .resource::<crate::ui::announcer::Announcer>();
+        assert!(announcer
+            .current()
+            .into_iter()
+            .chain(announcer.pending())
+            .all(|announcement| announcement.text != "PIN saved"));
+    }
+
+    #[cfg(all(feature = "settings", feature = "input"))]
+    #[test]
+    fn test_live_component_settings_screen() {
+        use crate::{
+            screens::{SettingsScreenConfig, SettingsScreenEvent, SettingsScreenPlugin},
+            settings::{
+                Setting, SettingChangedEvent, SettingType, SettingValue, SettingsIndex,
+                SettingsPlugin,
+            },
+        };
+
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            SettingsPlugin,
+            SettingsScreenPlugin,
+        ));
+        harness.app_mut().add_message::<crate::input::InputEvent>();
+        harness.world_mut().spawn(
+            Setting::new(
+                "hints",
+                "Show Hints",
+                SettingValue::Bool(true),
+                SettingType::Toggle,
+            )
+            .with_category("Learning"),
+        );
+        let screen = harness
+            .world_mut()
+            .spawn(SettingsScreenConfig::live_component_settings("Settings"))
+            .id();
+        harness.run_frames(2);
+        assert!(harness.has_widget("Learning"));
+        assert!(harness.has_widget("Show Hints"));
+
+        // Settings spawned while open appear without reopening the screen
+        let volume = harness
+            .world_mut()
+            .spawn(
+                Setting::new(
+                    "volume",
+                    "Volume",
+                    SettingValue::Float(1.0),
+                    SettingType::FloatRange {
+                        min: 0.0,
+                        max: 1.0,
+                        step: 0.1,
+                    },
+                )
+                .with_category("Audio"),
+            )
+            .id();
+        harness.run_frames(2);
+        assert!(harness.has_widget("Audio"));
+        assert!(harness.has_widget("Volume"));
+
+        // Changes on the screen reach the setting component
+        harness.read_messages::<SettingChangedEvent>();
+        harness.write_message(SettingsScreenEvent::ValueChanged {
+            entity: screen,
+            setting_id: "volume".to_string(),
+            value: SettingValue::Float(0.5),
+        });
+        harness.run_frames(3);
+        let setting = harness.world().get::<Setting>(volume).unwrap();
+        assert_eq!(setting.value, SettingValue::Float(0.5));
+        assert!(harness
+            .read_messages::<SettingChangedEvent>()
+            .iter()
+            .any(|event| event.is("volume")));
+
+        let hints = harness
+            .world()
+            .resource::<SettingsIndex>()
+            .get("hints")
+            .unwrap();
+        harness.world_mut().despawn(hints);
+        harness.run_frames(2);
+        assert!(!harness.has_widget("Show Hints"));
+        assert!(!harness.has_widget("Learning"));
+        assert!(harness.has_widget("Volume"));
+    }
+
+    #[cfg(all(feature = "settings", feature = "input"))]
+    #[test]
+    fn test_settings_screen_keyboard_navigation() {
+        use crate::{
+            screens::{ActiveSettingsScreen, SettingsScreenConfig, SettingsScreenPlugin},
+            settings::{Setting, SettingType, SettingValue, SettingsPlugin},
+        };
+
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            SettingsPlugin,
+            SettingsScreenPlugin,
+        ));
+        harness.app_mut().add_message::<crate::input::InputEvent>();
+        let delay = harness
+            .world_mut()
+            .spawn(
+                Setting::new(
+                    "hint_delay",
+                    "Hint Delay",
+                    SettingValue::Int(3),
+                    SettingType::IntRange {
+                        min: 1,
+                        max: 5,
+                        step: 1,
+                    },
+                )
+                .with_category("Learning"),
+            )
+            .id();
+        let screen = harness
+            .world_mut()
+            .spawn(SettingsScreenConfig::live_component_settings("Settings"))
+            .id();
+        harness.run_frames(2);
+
+        // Left and Right adjust the focused setting
+        harness.press_key_code(KeyCode::ArrowRight);
+        harness.press_key_code(KeyCode::ArrowRight);
+        harness.press_key_code(KeyCode::ArrowLeft);
+        harness.run_frames(2);
+        let setting = harness.world().get::<Setting>(delay).unwrap();
+        assert_eq!(setting.value, SettingValue::Int(4));
+
+        // The back button comes after the settings
+        harness.press_key_code(KeyCode::ArrowDown);
+        harness.press_key_code(KeyCode::Enter);
+        harness.run_frames(2);
+        assert!(harness
+            .world()
+            .get::<ActiveSettingsScreen>(screen)
+            .is_none());
+    }
+
+    #[cfg(all(feature = "settings", feature = "input"))]
+    #[test]
+    fn test_settings_screen_navigation_index() {
+        use crate::{
+            screens::{
+                ScreenSettingsItem, SettingsScreenConfig, SettingsScreenEvent,
+                SettingsScreenPlugin, SettingsSection,
+            },
+            settings::SettingValue,
+        };
+
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            SettingsScreenPlugin,
+        ));
+        harness.app_mut().add_message::<crate::input::InputEvent>();
+        harness.world_mut().spawn(
+            SettingsScreenConfig::new("Settings").add_section(
+                SettingsSection::new("Learning")
+                    .add_setting(ScreenSettingsItem::int_slider("hints", "Hints", 1, 0, 3, 1))
+                    .add_setting(
+                        ScreenSettingsItem::int_slider("rounds", "Rounds", 1, 0, 3, 1)
+                            .with_navigation_index(0),
+                    ),
+            ),
+        );
+        harness.run_frames(2);
+        harness.read_messages::<SettingsScreenEvent>();
+
+        // The configured index comes first, although shown second
+        harness.press_key_code(KeyCode::ArrowRight);
+        let changed: Vec<(String, SettingValue)> = harness
+            .read_messages::<SettingsScreenEvent>()
+            .into_iter()
+            .filter_map(|event| match event {
+                SettingsScreenEvent::ValueChanged {
+                    setting_id, value, ..
+                } => Some((setting_id, value)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(changed, vec![("rounds".to_string(), SettingValue::Int(2))]);
+    }
+
+    fn shadowed_text_ui(mut contexts: EguiContexts, theme: Res<KonnektorenTheme>) {
+        use crate::ui::{responsive::ResponsiveFontSize, widgets::ResponsiveText};
+
+        let Ok(ctx) = contexts.ctx_mut() else {
+            return;
+        };
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.add(
+                ResponsiveText::new("Shadowed", ResponsiveFontSize::Title, theme.primary)
+                    .shadow(&theme),
+            );
+            ui.add(ResponsiveText::new(
+                "Plain",
+                ResponsiveFontSize::Title,
+                theme.primary,
+            ));
+        });
+    }
+
+    #[test]
+    fn test_text_effect_keeps_label_layout() {
+        let mut harness = TestHarness::new().with_plugins(KonnektorenThemePlugin);
+        harness
+            .app_mut()
+            .add_systems(EguiPrimaryContextPass, shadowed_text_ui);
+        harness.run_frames(2);
+
+        // A shadowed text is labeled and sized like a plain one
+        let shadowed = harness
+            .widget_rect("Shadowed")
+            .expect("shadowed text shown");
+        let plain = harness.widget_rect("Plain").expect("plain text shown");
+        assert_eq!(shadowed.height(), plain.height());
+    }
+
+    const LONG_TEXT: &str = "A very long translated caption that does not fit";
+    const TRUNCATED_TEXT: &str = "A caption far too long for the card it is shown on";
+
+    fn fitted_text_ui(mut contexts: EguiContexts, theme: Res<KonnektorenTheme>) {
+        use crate::ui::{responsive::ResponsiveFontSize, widgets::ResponsiveText};
+
+        let Ok(ctx) = contexts.ctx_mut() else {
+            return;
+        };
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.add(
+                ResponsiveText::new(LONG_TEXT, ResponsiveFontSize::Title, theme.primary)
+                    .fit_to_width(300.0),
+            );
+            ui.allocate_ui(egui::vec2(150.0, 100.0), |ui| {
+                ui.add(
+                    ResponsiveText::new(TRUNCATED_TEXT, ResponsiveFontSize::Title, theme.primary)
+                        .truncate_with_ellipsis()
+                        .shadow(&theme),
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn test_text_fit_and_truncate() {
+        let mut harness = TestHarness::new().with_plugins(KonnektorenThemePlugin);
+        harness
+            .app_mut()
+            .add_systems(EguiPrimaryContextPass, fitted_text_ui);
+        harness.run_frames(2);
+
+        let fitted = harness.widget_rect(LONG_TEXT).expect("fitted text shown");
+        assert!(fitted.width() <= 300.0);
+        let truncated = harness
+            .widget_rect(TRUNCATED_TEXT)
+            .expect("truncated text shown");
+        assert!(truncated.width() <= 150.0);
+    }
+
+    #[derive(Resource)]
+    struct Coins(i64);
+
+    fn coins_ui(mut contexts: EguiContexts, theme: Res<KonnektorenTheme>, coins: Res<Coins>) {
+        use crate::ui::counter::{AnimatedCounter, CounterIcon};
+
+        let Ok(ctx) = contexts.ctx_mut() else {
+            return;
+        };
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.add(AnimatedCounter::new("coins", coins.0, &theme).icon(CounterIcon::Coins));
+        });
+    }
+
+    #[test]
+    fn test_animated_counter_rolls() {
+        let mut harness = TestHarness::new().with_plugins(KonnektorenThemePlugin);
+        harness
+            .app_mut()
+            .insert_resource(Coins(120))
+            .add_systems(EguiPrimaryContextPass, coins_ui);
+        harness.run_frames(2);
+        assert!(harness.has_widget("120"));
+
+        harness.world_mut().resource_mut::<Coins>().0 = 150;
+        harness.run_frames(10);
+        assert!(!harness.has_widget("120"));
+        assert!(!harness.has_widget("150"));
+
+        harness.run_frames(60);
+        assert!(harness.has_widget("150"));
+    }
+
+    #[derive(Resource, Default)]
+    struct Dropped(Vec<u32>);
+
+    fn drag_and_drop_ui(
+        mut contexts: EguiContexts,
+        theme: Res<KonnektorenTheme>,
+        mut dropped: ResMut<Dropped>,
+    ) {
+        let Ok(ctx) = contexts.ctx_mut() else {
+            return;
+        };
+        egui::CentralPanel::default().show(ctx, |ui| {
+            DragSource::new("card", 7u32, &theme).show(ui, |ui| ui.label("Card"));
+            ui.add_space(100.0);
+            let (_, payload) = DropTarget::<u32>::new(&theme)
+                .min_size(egui::vec2(200.0, 80.0))
+                .show(ui, |ui| ui.label("Target"));
+            if let Some(payload) = payload {
+                dropped.0.push(*payload);
+            }
+        });
+    }
+
+    #[test]
+    fn test_drag_and_drop() {
+        let mut harness = TestHarness::new().with_plugins(KonnektorenThemePlugin);
+        harness
+            .app_mut()
+            .init_resource::<Dropped>()
+            .add_systems(EguiPrimaryContextPass, drag_and_drop_ui);
+        harness.run_frames(2);
+
+        let card = harness.widget_rect("Card").expect("card shown");
+        let target = harness.widget_rect("Target").expect("target shown");
+        harness.drag(card.center(), target.center());
+
+        assert_eq!(harness.world().resource::<Dropped>().0, vec![7]);
+    }
+}
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+use std::borrow::Cow;
+
+/// Setting ID of the high visibility toggle
+pub const HIGH_VISIBILITY_SETTING_ID: &str = "high_visibility";
+
+/// Setting ID of the dyslexia-friendly typography toggle
+pub const DYSLEXIA_FRIENDLY_SETTING_ID: &str = "dyslexia_friendly";
+
+/// Minimum size of interactive elements in high visibility mode, following
+/// the common 44pt touch target guideline
+pub const MIN_HIT_TARGET: f32 = 44.0;
+
+/// Focus outline width in high visibility mode
+pub const HIGH_VISIBILITY_FOCUS_WIDTH: f32 = 3.0;
+
+/// Accessibility options applied to the egui style of all screens
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct AccessibilityOptions {
+    /// Large hit targets, thick focus outlines in the primary color and more
+    /// room between stacked buttons on mobile
+    pub high_visibility: bool,
+    /// Dyslexia-friendly font of the [`FontsPlugin`](super::FontsPlugin),
+    /// wider letter and line spacing and no all-caps labels
+    pub dyslexia_friendly: bool,
+}
+
+/// Sentence case for all-caps labels like "ON", which are harder to read
+/// for dyslexic readers. Other text is returned unchanged.
+pub fn readable_case(text: &str) -> Cow<'_, str> {
+    let mut letters = text.chars().filter(|c| c.is_alphabetic());
+    let all_caps = letters.clone().count() > 1 && letters.all(char::is_uppercase);
+    if !all_caps {
+        return Cow::Borrowed(text);
+    }
+
+    let mut readable = String::with_capacity(text.len());
+    let mut first = true;
+    for c in text.chars() {
+        if first && c.is_alphabetic() {
+            readable.push(c);
+            first = false;
+        } else {
+            readable.extend(c.to_lowercase());
+        }
+    }
+    Cow::Owned(readable)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin};
+    use bevy_egui::{egui, EguiContexts, EguiPrimaryContextPass};
+
+    fn button_ui(mut contexts: EguiContexts) {
+        let Ok(ctx) = contexts.ctx_mut() else {
+            return;
+        };
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let _ = ui.button("OK");
+        });
+    }
+
+    #[test]
+    fn test_readable_case() {
+        assert_eq!(readable_case("ON"), "On");
+        assert_eq!(readable_case("GAME OVER!"), "Game over!");
+        assert_eq!(readable_case("Level 2"), "Level 2");
+        assert_eq!(readable_case("A"), "A");
+    }
+
+    #[test]
+    fn test_high_visibility_enlarges_hit_targets() {
+        let mut harness = TestHarness::new().with_plugins(KonnektorenThemePlugin);
+        harness
+            .app_mut()
+            .add_systems(EguiPrimaryContextPass, button_ui);
+        harness.run_frames(2);
+        let height = harness.widget_rect("OK").unwrap().height();
+        assert!(height < MIN_HIT_TARGET);
+
+        harness
+            .world_mut()
+            .resource_mut::<AccessibilityOptions>()
+            .high_visibility = true;
+        harness.run_frames(2);
+        let height = harness.widget_rect("OK").unwrap().height();
+        assert!(height >= MIN_HIT_TARGET);
+    }
+}
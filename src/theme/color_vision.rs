@@ -0,0 +1,182 @@
+use super::resource::KonnektorenTheme;
+use bevy::prelude::*;
+use bevy_egui::egui::Color32;
+
+/// Setting ID of the color vision mode selection
+pub const COLOR_VISION_SETTING_ID: &str = "color_vision";
+
+/// Setting ID of the shape cues toggle
+pub const SHAPE_CUES_SETTING_ID: &str = "shape_cues";
+
+/// Color vision deficiency the theme palette is adapted to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorVisionMode {
+    #[default]
+    Normal,
+    /// Green-weak, the most common deficiency
+    Deuteranopia,
+    /// Red-weak
+    Protanopia,
+    /// Blue-weak
+    Tritanopia,
+}
+
+impl ColorVisionMode {
+    pub const ALL: [ColorVisionMode; 4] = [
+        ColorVisionMode::Normal,
+        ColorVisionMode::Deuteranopia,
+        ColorVisionMode::Protanopia,
+        ColorVisionMode::Tritanopia,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ColorVisionMode::Normal => "Normal",
+            ColorVisionMode::Deuteranopia => "Deuteranopia",
+            ColorVisionMode::Protanopia => "Protanopia",
+            ColorVisionMode::Tritanopia => "Tritanopia",
+        }
+    }
+
+    /// Mode for a selection index, as used by the appearance settings
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL.get(index).copied().unwrap_or_default()
+    }
+
+    pub fn index(&self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|mode| mode == self)
+            .unwrap_or_default()
+    }
+
+    /// Adapt the status colors of a theme so they stay distinguishable.
+    ///
+    /// Based on the Okabe-Ito palette, success and error never rely on the
+    /// red/green (or blue/yellow for tritanopia) contrast.
+    pub fn apply(&self, theme: &KonnektorenTheme) -> KonnektorenTheme {
+        let mut theme = theme.clone();
+        match self {
+            ColorVisionMode::Normal => {}
+            ColorVisionMode::Deuteranopia => {
+                theme.success = Color32::from_rgb(0, 114, 178);
+                theme.error = Color32::from_rgb(213, 94, 0);
+                theme.warning = Color32::from_rgb(240, 228, 66);
+                theme.info = Color32::from_rgb(86, 180, 233);
+            }
+            ColorVisionMode::Protanopia => {
+                theme.success = Color32::from_rgb(0, 114, 178);
+                // Red appears dark, use a bright orange instead
+                theme.error = Color32::from_rgb(230, 159, 0);
+                theme.warning = Color32::from_rgb(240, 228, 66);
+                theme.info = Color32::from_rgb(86, 180, 233);
+            }
+            ColorVisionMode::Tritanopia => {
+                theme.success = Color32::from_rgb(0, 158, 115);
+                theme.error = Color32::from_rgb(213, 40, 40);
+                theme.warning = Color32::from_rgb(204, 121, 167);
+                theme.info = Color32::from_rgb(90, 90, 90);
+            }
+        }
+        theme
+    }
+}
+
+/// Color-blindness assistance options
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct ColorAssistance {
+    /// Palette adaptation applied to the theme
+    pub mode: ColorVisionMode,
+    /// Add shapes and patterns wherever color alone conveys meaning
+    pub shape_cues: bool,
+}
+
+/// System to apply the color vision mode to the theme.
+///
+/// The unadapted theme is kept while a mode is active and restored when
+/// switching back to [`ColorVisionMode::Normal`]. A theme switched in the
+/// meantime becomes the new unadapted theme and is adapted as well.
+pub fn apply_color_vision(
+    assistance: Res<ColorAssistance>,
+    mut theme: ResMut<KonnektorenTheme>,
+    mut base_theme: Local<Option<KonnektorenTheme>>,
+    mut adapted_tick: Local<Option<u32>>,
+) {
+    // Changed since this system adapted it, the theme was switched
+    let switched = adapted_tick.is_some_and(|tick| tick != theme.last_changed().get());
+    if !assistance.is_changed() && !switched {
+        return;
+    }
+    if switched {
+        *base_theme = Some(theme.clone());
+    }
+
+    if assistance.mode == ColorVisionMode::Normal {
+        *adapted_tick = None;
+        if let Some(base) = base_theme.take().filter(|_| !switched) {
+            *theme = base;
+        }
+        return;
+    }
+
+    let base = base_theme.get_or_insert_with(|| theme.clone());
+    *theme = assistance.mode.apply(base);
+    *adapted_tick = Some(theme.last_changed().get());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_vision_mode_restores_theme() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<KonnektorenTheme>()
+            .init_resource::<ColorAssistance>()
+            .add_systems(Update, apply_color_vision);
+        let original = KonnektorenTheme::default();
+
+        app.world_mut().resource_mut::<ColorAssistance>().mode = ColorVisionMode::Deuteranopia;
+        app.update();
+        let theme = app.world().resource::<KonnektorenTheme>();
+        assert_ne!(theme.success, original.success);
+        assert_eq!(theme.primary, original.primary);
+
+        app.world_mut().resource_mut::<ColorAssistance>().mode = ColorVisionMode::Normal;
+        app.update();
+        let theme = app.world().resource::<KonnektorenTheme>();
+        assert_eq!(theme.success, original.success);
+        assert_eq!(theme.error, original.error);
+    }
+
+    #[test]
+    fn test_color_vision_mode_adapts_switched_theme() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<KonnektorenTheme>()
+            .init_resource::<ColorAssistance>()
+            .add_systems(Update, apply_color_vision);
+        app.world_mut().resource_mut::<ColorAssistance>().mode = ColorVisionMode::Protanopia;
+        app.update();
+
+        let switched = KonnektorenTheme {
+            success: Color32::from_rgb(0, 200, 120),
+            error: Color32::from_rgb(220, 40, 40),
+            ..Default::default()
+        };
+        app.insert_resource(switched.clone());
+        app.update();
+        let theme = app.world().resource::<KonnektorenTheme>();
+        let adapted = ColorVisionMode::Protanopia.apply(&switched);
+        assert_eq!(theme.success, adapted.success);
+        assert_eq!(theme.error, adapted.error);
+
+        // Switching back restores the switched theme, not the first one
+        app.world_mut().resource_mut::<ColorAssistance>().mode = ColorVisionMode::Normal;
+        app.update();
+        let theme = app.world().resource::<KonnektorenTheme>();
+        assert_eq!(theme.success, switched.success);
+        assert_eq!(theme.error, switched.error);
+    }
+}
@@ -0,0 +1,158 @@
+use super::accessibility::AccessibilityOptions;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use std::sync::Arc;
+
+/// Name of the dyslexia-friendly font in the egui font definitions
+pub const DYSLEXIA_FONT_NAME: &str = "dyslexia_friendly";
+
+/// Plugin loading extra fonts for egui.
+///
+/// The dyslexia-friendly font, e.g. OpenDyslexic or Lexend, replaces the
+/// proportional font while [`AccessibilityOptions::dyslexia_friendly`] is
+/// enabled. Without a font only the letter and line spacing change.
+///
+/// Apps with their own fonts, e.g. CJK or icon fonts, pass them as the base
+/// fonts. The plugin installs them and only adds or removes the
+/// dyslexia-friendly font on top.
+///
+/// ```ignore
+/// app.add_plugins(
+///     FontsPlugin::default()
+///         .base_fonts(app_fonts)
+///         .dyslexia_font("fonts/OpenDyslexic-Regular.otf"),
+/// );
+/// ```
+#[derive(Default)]
+pub struct FontsPlugin {
+    pub dyslexia_font: Option<String>,
+    /// Fonts of the app, egui's default fonts if not set
+    pub base_fonts: Option<egui::FontDefinitions>,
+}
+
+impl FontsPlugin {
+    /// Asset path of the dyslexia-friendly font
+    pub fn dyslexia_font(mut self, path: impl Into<String>) -> Self {
+        self.dyslexia_font = Some(path.into());
+        self
+    }
+
+    /// Fonts of the app the dyslexia-friendly font is added to
+    pub fn base_fonts(mut self, fonts: egui::FontDefinitions) -> Self {
+        self.base_fonts = Some(fonts);
+        self
+    }
+}
+
+impl Plugin for FontsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(FontPaths {
+            dyslexia: self.dyslexia_font.clone(),
+        })
+        .insert_resource(BaseFonts(self.base_fonts.clone()))
+        .init_resource::<ThemeFonts>()
+        .init_resource::<AccessibilityOptions>()
+        .add_systems(Startup, load_theme_fonts)
+        .add_systems(Update, apply_theme_fonts);
+    }
+}
+
+#[derive(Resource)]
+struct FontPaths {
+    dyslexia: Option<String>,
+}
+
+/// Fonts of the app from [`FontsPlugin::base_fonts`]
+#[derive(Resource)]
+struct BaseFonts(Option<egui::FontDefinitions>);
+
+/// Resource with the fonts of the [`FontsPlugin`].
+///
+/// Set a handle directly for embedded fonts, e.g. from
+/// `Font::try_from_bytes(include_bytes!(..).to_vec())`.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ThemeFonts {
+    pub dyslexia: Option<Handle<Font>>,
+}
+
+fn load_theme_fonts(
+    paths: Res<FontPaths>,
+    asset_server: Option<Res<AssetServer>>,
+    mut fonts: ResMut<ThemeFonts>,
+) {
+    let (Some(path), Some(asset_server)) = (&paths.dyslexia, asset_server) else {
+        return;
+    };
+    if fonts.dyslexia.is_none() {
+        fonts.dyslexia = Some(asset_server.load(path.clone()));
+    }
+}
+
+/// System to install the base fonts and switch the egui fonts when the
+/// dyslexia-friendly option changes or its font finished loading
+fn apply_theme_fonts(
+    mut contexts: EguiContexts,
+    options: Res<AccessibilityOptions>,
+    fonts: Res<ThemeFonts>,
+    base_fonts: Res<BaseFonts>,
+    font_assets: Option<Res<Assets<Font>>>,
+    mut applied: Local<Option<bool>>,
+) {
+    let font = options
+        .dyslexia_friendly
+        .then(|| fonts.dyslexia.as_ref())
+        .flatten()
+        .and_then(|handle| font_assets.as_ref()?.get(handle));
+    let use_font = font.is_some();
+    // Without base fonts egui's default fonts are already installed
+    let installed = applied.or_else(|| base_fonts.0.is_none().then_some(false));
+    if installed == Some(use_font) {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let mut definitions = base_fonts.0.clone().unwrap_or_default();
+    if let Some(font) = font {
+        definitions.font_data.insert(
+            DYSLEXIA_FONT_NAME.to_string(),
+            Arc::new(egui::FontData::from_owned(font.data.to_vec())),
+        );
+        definitions
+            .families
+            .entry(egui::FontFamily::Proportional)
+            .or_default()
+            .insert(0, DYSLEXIA_FONT_NAME.to_string());
+    }
+    ctx.set_fonts(definitions);
+    *applied = Some(use_font);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestHarness;
+
+    #[test]
+    fn test_base_fonts_kept() {
+        let icons = egui::FontFamily::Name("icons".into());
+        let mut base_fonts = egui::FontDefinitions::default();
+        let proportional = base_fonts.families[&egui::FontFamily::Proportional].clone();
+        base_fonts.families.insert(icons.clone(), proportional);
+
+        let mut harness =
+            TestHarness::new().with_plugins(FontsPlugin::default().base_fonts(base_fonts));
+        harness.run_frames(2);
+        let families =
+            |harness: &TestHarness| harness.kittest().ctx.fonts(|fonts| fonts.families());
+        assert!(families(&harness).contains(&icons));
+
+        harness
+            .world_mut()
+            .resource_mut::<AccessibilityOptions>()
+            .dyslexia_friendly = true;
+        harness.run_frames(2);
+        assert!(families(&harness).contains(&icons));
+    }
+}
@@ -1,8 +1,14 @@
+pub mod accessibility;
+pub mod color_vision;
 pub mod colors;
+pub mod fonts;
 mod plugin;
 pub mod resource;
 
+pub use accessibility::*;
+pub use color_vision::*;
 pub use colors::*;
+pub use fonts::*;
 use plugin::*;
 pub use resource::*;
 
@@ -13,6 +19,8 @@ pub struct KonnektorenThemePlugin;
 
 impl Plugin for KonnektorenThemePlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(EguiThemePlugin);
+        app.add_plugins(EguiThemePlugin)
+            .init_resource::<ColorAssistance>()
+            .add_systems(Update, apply_color_vision);
     }
 }
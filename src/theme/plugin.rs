@@ -1,4 +1,7 @@
-use super::resource::KonnektorenTheme;
+use super::{
+    accessibility::{AccessibilityOptions, HIGH_VISIBILITY_FOCUS_WIDTH, MIN_HIT_TARGET},
+    resource::KonnektorenTheme,
+};
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
@@ -7,12 +10,17 @@ pub struct EguiThemePlugin;
 impl Plugin for EguiThemePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<KonnektorenTheme>()
+            .init_resource::<AccessibilityOptions>()
             .add_systems(Update, apply_theme);
     }
 }
 
 /// System to apply the theme to egui
-fn apply_theme(mut contexts: EguiContexts, theme: Res<KonnektorenTheme>) {
+fn apply_theme(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    options: Res<AccessibilityOptions>,
+) {
     // Handle the Result properly
     let Ok(ctx) = contexts.ctx_mut() else {
         return;
@@ -91,6 +99,20 @@ fn apply_theme(mut contexts: EguiContexts, theme: Res<KonnektorenTheme>) {
     visuals.button_frame = true;
     visuals.collapsing_header_frame = true;
 
+    if options.high_visibility {
+        // Focused widgets use the active visuals, keep the fill light so the
+        // primary outline stands out
+        let focus_stroke = egui::Stroke::new(HIGH_VISIBILITY_FOCUS_WIDTH, theme.primary);
+        let active = &mut visuals.widgets.active;
+        active.bg_fill = theme.base_300;
+        active.weak_bg_fill = theme.base_300;
+        active.bg_stroke = focus_stroke;
+        active.fg_stroke = egui::Stroke::new(2.0, theme.primary);
+        active.expansion = 2.0;
+        visuals.widgets.hovered.bg_stroke.width = 2.0;
+        visuals.selection.stroke = focus_stroke;
+    }
+
     ctx.set_visuals(visuals);
 
     let mut style = (*ctx.style()).clone();
@@ -98,5 +120,13 @@ fn apply_theme(mut contexts: EguiContexts, theme: Res<KonnektorenTheme>) {
     style.spacing.window_margin = egui::Margin::same(16);
     style.spacing.button_padding = egui::vec2(8.0, 4.0);
 
+    if options.high_visibility {
+        style.spacing.interact_size = egui::vec2(MIN_HIT_TARGET, MIN_HIT_TARGET);
+        style.spacing.icon_width = 24.0;
+        style.spacing.icon_width_inner = 14.0;
+        style.spacing.item_spacing.y = 14.0;
+        style.spacing.button_padding = egui::vec2(12.0, 8.0);
+    }
+
     ctx.set_style(style);
 }
@@ -4,6 +4,9 @@ use bevy_egui::egui;
 /// Shared theme resource that works for both Bevy UI and egui
 #[derive(Resource, Clone)]
 pub struct KonnektorenTheme {
+    /// Display name of the theme
+    pub name: String,
+
     // Base colors
     pub base_100: egui::Color32,
     pub base_200: egui::Color32,
@@ -37,6 +40,8 @@ pub struct KonnektorenTheme {
 impl Default for KonnektorenTheme {
     fn default() -> Self {
         Self {
+            name: "Konnektoren".to_string(),
+
             // Base colors
             base_100: egui::Color32::from_rgb(246, 246, 246),
             base_200: egui::Color32::from_rgb(240, 240, 240),
@@ -122,6 +127,48 @@ impl KonnektorenTheme {
             self.error.b() as f32 / 255.0,
         )
     }
+
+    /// Resolve a palette color of this theme
+    pub fn color(&self, color: ThemeColor) -> egui::Color32 {
+        match color {
+            ThemeColor::Primary => self.primary,
+            ThemeColor::Secondary => self.secondary,
+            ThemeColor::Accent => self.accent,
+            ThemeColor::Info => self.info,
+            ThemeColor::Success => self.success,
+            ThemeColor::Warning => self.warning,
+            ThemeColor::Error => self.error,
+            ThemeColor::BaseContent => self.base_content,
+        }
+    }
+}
+
+/// Named color of the theme palette, resolved against the current theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub enum ThemeColor {
+    Primary,
+    Secondary,
+    Accent,
+    Info,
+    Success,
+    Warning,
+    Error,
+    BaseContent,
+}
+
+impl ThemeColor {
+    /// All palette colors
+    pub const ALL: [ThemeColor; 8] = [
+        ThemeColor::Primary,
+        ThemeColor::Secondary,
+        ThemeColor::Accent,
+        ThemeColor::Info,
+        ThemeColor::Success,
+        ThemeColor::Warning,
+        ThemeColor::Error,
+        ThemeColor::BaseContent,
+    ];
 }
 
 /// Builder for creating custom themes
@@ -136,6 +183,11 @@ impl KonnektorenThemeBuilder {
         }
     }
 
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.theme.name = name.into();
+        self
+    }
+
     pub fn primary(mut self, color: egui::Color32) -> Self {
         self.theme.primary = color;
         self
@@ -0,0 +1,250 @@
+use super::notifications::{Notification, NotificationKind};
+use bevy::prelude::*;
+#[cfg(feature = "accessibility")]
+use bevy_egui::{
+    egui::{self, accesskit},
+    EguiContexts, EguiPrimaryContextPass,
+};
+use std::collections::VecDeque;
+
+/// Seconds an announcement stays in the live region before the next one
+pub const ANNOUNCEMENT_DURATION: f32 = 1.5;
+
+/// Plugin routing [`Announcer`] messages to screen readers, added by the
+/// [`UIPlugin`](super::UIPlugin).
+///
+/// With the `accessibility` feature the current announcement is written to an
+/// AccessKit live region, which screen readers read out when it changes.
+/// Notifications are announced automatically.
+pub struct AnnouncerPlugin;
+
+impl Plugin for AnnouncerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Announcer>()
+            .add_message::<Notification>()
+            .add_systems(Update, (announce_notifications, update_announcer).chain());
+
+        #[cfg(feature = "accessibility")]
+        app.add_systems(EguiPrimaryContextPass, render_announcer_live_region);
+    }
+}
+
+/// How urgently a screen reader reads an announcement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Politeness {
+    /// Read when the screen reader is idle
+    #[default]
+    Polite,
+    /// Interrupts what the screen reader is reading
+    Assertive,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Announcement {
+    pub text: String,
+    pub politeness: Politeness,
+    /// Key of announcements replacing each other, see
+    /// [`Announcer::announce_keyed`]
+    pub key: Option<String>,
+}
+
+/// Resource queueing screen reader announcements for dynamic events, like
+/// "Setting saved" or "Time's up".
+///
+/// Polite announcements are read one after another. Assertive ones replace
+/// the current announcement and go before queued polite ones. Keyed
+/// announcements replace a queued one with the same key.
+#[derive(Resource, Debug, Default)]
+pub struct Announcer {
+    current: Option<Announcement>,
+    /// Seconds the current announcement was shown
+    elapsed: f32,
+    queue: VecDeque<Announcement>,
+}
+
+impl Announcer {
+    pub fn announce(&mut self, text: impl Into<String>, politeness: Politeness) {
+        self.push(Announcement {
+            text: text.into(),
+            politeness,
+            key: None,
+        });
+    }
+
+    /// Announce, replacing a queued announcement with the same key, so quick
+    /// updates like dragging a slider are read once with the latest text
+    pub fn announce_keyed(
+        &mut self,
+        key: impl Into<String>,
+        text: impl Into<String>,
+        politeness: Politeness,
+    ) {
+        let announcement = Announcement {
+            text: text.into(),
+            politeness,
+            key: Some(key.into()),
+        };
+
+        let queued = self
+            .queue
+            .iter_mut()
+            .find(|queued| queued.key == announcement.key && queued.politeness == politeness);
+        match queued {
+            Some(queued) => *queued = announcement,
+            None => self.push(announcement),
+        }
+    }
+
+    fn push(&mut self, announcement: Announcement) {
+        match announcement.politeness {
+            Politeness::Polite => self.queue.push_back(announcement),
+            Politeness::Assertive => {
+                let interrupts = self
+                    .current
+                    .as_ref()
+                    .is_none_or(|current| current.politeness == Politeness::Polite);
+                if interrupts {
+                    self.current = Some(announcement);
+                    self.elapsed = 0.0;
+                } else {
+                    // After earlier assertive announcements, before polite ones
+                    let position = self
+                        .queue
+                        .iter()
+                        .position(|queued| queued.politeness == Politeness::Polite)
+                        .unwrap_or(self.queue.len());
+                    self.queue.insert(position, announcement);
+                }
+            }
+        }
+    }
+
+    pub fn polite(&mut self, text: impl Into<String>) {
+        self.announce(text, Politeness::Polite);
+    }
+
+    pub fn assertive(&mut self, text: impl Into<String>) {
+        self.announce(text, Politeness::Assertive);
+    }
+
+    /// Announcement currently in the live region
+    pub fn current(&self) -> Option<&Announcement> {
+        self.current.as_ref()
+    }
+
+    /// Announcements waiting to be read
+    pub fn pending(&self) -> impl Iterator<Item = &Announcement> {
+        self.queue.iter()
+    }
+
+    /// Advance by `delta` seconds, moving to the next announcement once the
+    /// current one was shown long enough
+    pub fn tick(&mut self, delta: f32) {
+        self.elapsed += delta;
+        if self.current.is_none() || self.elapsed >= ANNOUNCEMENT_DURATION {
+            self.current = self.queue.pop_front();
+            self.elapsed = 0.0;
+        }
+    }
+}
+
+/// System to announce notifications, errors interrupt
+fn announce_notifications(
+    mut notifications: MessageReader<Notification>,
+    mut announcer: ResMut<Announcer>,
+) {
+    for notification in notifications.read() {
+        let text = if notification.message.is_empty() {
+            notification.title.clone()
+        } else {
+            format!("{}: {}", notification.title, notification.message)
+        };
+        let politeness = match notification.kind {
+            NotificationKind::Error => Politeness::Assertive,
+            _ => Politeness::Polite,
+        };
+        announcer.announce(text, politeness);
+    }
+}
+
+fn update_announcer(time: Res<Time>, mut announcer: ResMut<Announcer>) {
+    if announcer.current.is_some() || !announcer.queue.is_empty() {
+        announcer.tick(time.delta_secs());
+    }
+}
+
+/// System to write the current announcement to an AccessKit live region
+#[cfg(feature = "accessibility")]
+fn render_announcer_live_region(mut contexts: EguiContexts, announcer: Res<Announcer>) {
+    let Some(announcement) = announcer.current() else {
+        return;
+    };
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    ctx.accesskit_node_builder(egui::Id::new("konnektoren_announcer"), |node| {
+        node.set_role(accesskit::Role::Status);
+        node.set_live(match announcement.politeness {
+            Politeness::Polite => accesskit::Live::Polite,
+            Politeness::Assertive => accesskit::Live::Assertive,
+        });
+        node.set_label(announcement.text.as_str());
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assertive_announcements_go_first() {
+        let mut announcer = Announcer::default();
+        announcer.polite("Setting saved");
+        announcer.tick(0.0);
+        announcer.polite("Achievement unlocked: First Steps");
+        announcer.assertive("Time's up");
+
+        assert_eq!(announcer.current().unwrap().text, "Time's up");
+        announcer.tick(ANNOUNCEMENT_DURATION);
+        assert_eq!(
+            announcer.current().unwrap().text,
+            "Achievement unlocked: First Steps"
+        );
+        announcer.tick(ANNOUNCEMENT_DURATION);
+        assert!(announcer.current().is_none());
+    }
+
+    #[test]
+    fn test_keyed_announcements_replace_queued() {
+        let mut announcer = Announcer::default();
+        announcer.polite("Achievement unlocked: First Steps");
+        announcer.tick(0.0);
+        for _ in 0..100 {
+            announcer.announce_keyed("volume", "Volume saved", Politeness::Polite);
+        }
+        announcer.announce_keyed("music", "Music saved", Politeness::Polite);
+
+        let pending: Vec<&str> = announcer
+            .pending()
+            .map(|announcement| announcement.text.as_str())
+            .collect();
+        assert_eq!(pending, ["Volume saved", "Music saved"]);
+    }
+
+    #[cfg(feature = "accessibility")]
+    #[test]
+    fn test_notification_live_region() {
+        use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+
+        let mut harness = TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin));
+        harness
+            .world_mut()
+            .write_message(Notification::success("Saved", "Your progress is safe"));
+        harness.run_frames(2);
+
+        assert!(harness
+            .widget_labels()
+            .contains(&"Saved: Your progress is safe".to_string()));
+    }
+}
@@ -0,0 +1,332 @@
+use super::{
+    notifications::{notification_color, NotificationKind},
+    responsive::{ResponsiveFontSize, ResponsiveInfo},
+    widgets::ResponsiveText,
+};
+#[cfg(feature = "storage")]
+use crate::storage::{PersistenceScheduler, Storage};
+use crate::theme::KonnektorenTheme;
+use bevy::prelude::*;
+use bevy_egui::egui::{self, Widget};
+use std::collections::BTreeSet;
+
+/// Storage key of the dismissed banner ids
+pub const DISMISSED_BANNERS_STORAGE_KEY: &str = "dismissed_banners";
+
+/// Plugin keeping track of dismissed [`ThemedBanner`]s, added by the
+/// [`UIPlugin`](super::UIPlugin). With the `storage` feature dismissals are
+/// persisted, so a banner stays hidden after a restart.
+pub struct BannerPlugin;
+
+impl Plugin for BannerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DismissedBanners>();
+
+        #[cfg(feature = "storage")]
+        {
+            app.add_systems(Startup, load_dismissed_banners)
+                .add_systems(PostUpdate, save_dismissed_banners);
+        }
+    }
+}
+
+/// Resource with the ids of banners the user dismissed
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct DismissedBanners {
+    ids: BTreeSet<String>,
+}
+
+impl DismissedBanners {
+    pub fn is_dismissed(&self, id: &str) -> bool {
+        self.ids.contains(id)
+    }
+
+    pub fn dismiss(&mut self, id: impl Into<String>) {
+        self.ids.insert(id.into());
+    }
+
+    /// Show a dismissed banner again
+    pub fn restore(&mut self, id: &str) {
+        self.ids.remove(id);
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.ids.iter().map(String::as_str)
+    }
+
+    /// Show the banner unless it was dismissed before, and remember when it
+    /// gets dismissed now
+    pub fn show(&mut self, ui: &mut egui::Ui, banner: ThemedBanner) -> Option<BannerResponse> {
+        if self.is_dismissed(banner.id) {
+            return None;
+        }
+
+        let id = banner.id;
+        let response = banner.show(ui);
+        if response.dismissed {
+            self.dismiss(id);
+        }
+        Some(response)
+    }
+}
+
+/// Outcome of showing a [`ThemedBanner`]
+pub struct BannerResponse {
+    pub response: egui::Response,
+    /// The action button was clicked
+    pub action_clicked: bool,
+    /// The dismiss button was clicked
+    pub dismissed: bool,
+}
+
+/// Full width announcement, e.g. "Offline mode — progress will sync later"
+/// or a beta notice.
+///
+/// Use [`DismissedBanners::show`] for dismissible banners, so they stay
+/// hidden once dismissed.
+pub struct ThemedBanner<'a> {
+    pub id: &'a str,
+    pub message: &'a str,
+    pub title: Option<&'a str>,
+    pub kind: NotificationKind,
+    /// Accent color instead of the one of the kind
+    pub color: Option<egui::Color32>,
+    pub action: Option<&'a str>,
+    pub dismissible: bool,
+    pub theme: &'a KonnektorenTheme,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> ThemedBanner<'a> {
+    pub fn new(id: &'a str, message: &'a str, theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            id,
+            message,
+            title: None,
+            kind: NotificationKind::Info,
+            color: None,
+            action: None,
+            dismissible: true,
+            theme,
+            responsive_info: None,
+        }
+    }
+
+    pub fn info(id: &'a str, message: &'a str, theme: &'a KonnektorenTheme) -> Self {
+        Self::new(id, message, theme).kind(NotificationKind::Info)
+    }
+
+    pub fn warning(id: &'a str, message: &'a str, theme: &'a KonnektorenTheme) -> Self {
+        Self::new(id, message, theme).kind(NotificationKind::Warning)
+    }
+
+    pub fn error(id: &'a str, message: &'a str, theme: &'a KonnektorenTheme) -> Self {
+        Self::new(id, message, theme).kind(NotificationKind::Error)
+    }
+
+    pub fn kind(mut self, kind: NotificationKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    pub fn color(mut self, color: egui::Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn action(mut self, label: &'a str) -> Self {
+        self.action = Some(label);
+        self
+    }
+
+    pub fn dismissible(mut self, dismissible: bool) -> Self {
+        self.dismissible = dismissible;
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+
+    pub fn show(self, ui: &mut egui::Ui) -> BannerResponse {
+        let theme = self.theme;
+        let color = self
+            .color
+            .unwrap_or_else(|| notification_color(theme, self.kind));
+        let icon = match self.kind {
+            NotificationKind::Info => "ℹ",
+            NotificationKind::Success => "✔",
+            NotificationKind::Warning => "⚠",
+            NotificationKind::Error => "⛔",
+        };
+        let font_size = self.responsive_info.map_or(16.0, |responsive_info| {
+            responsive_info.font_size(ResponsiveFontSize::Medium)
+        });
+
+        let mut action_clicked = false;
+        let mut dismissed = false;
+
+        let frame = egui::Frame {
+            inner_margin: egui::Margin::symmetric(12, 8),
+            corner_radius: egui::CornerRadius::same(theme.radius),
+            fill: theme.base_200,
+            stroke: egui::Stroke::new(1.5, color),
+            ..Default::default()
+        };
+
+        let response = frame
+            .show(ui, |ui| {
+                ui.set_width(ui.available_width());
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(icon).size(font_size).color(color));
+
+                    // Leave room for the buttons on the right
+                    let buttons_width = font_size
+                        * (2.0 + self.action.map_or(0.0, |action| action.len() as f32 * 0.6));
+                    ui.vertical(|ui| {
+                        ui.set_max_width((ui.available_width() - buttons_width).max(0.0));
+                        if let Some(title) = self.title {
+                            let mut text =
+                                ResponsiveText::new(title, ResponsiveFontSize::Medium, color)
+                                    .strong();
+                            if let Some(responsive_info) = self.responsive_info {
+                                text = text.responsive(responsive_info);
+                            }
+                            text.ui(ui);
+                        }
+                        let mut text = ResponsiveText::new(
+                            self.message,
+                            ResponsiveFontSize::Small,
+                            theme.base_content,
+                        );
+                        if let Some(responsive_info) = self.responsive_info {
+                            text = text.responsive(responsive_info);
+                        }
+                        text.ui(ui);
+                    });
+
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if self.dismissible {
+                            let close = egui::Button::new(
+                                egui::RichText::new("✕")
+                                    .size(font_size)
+                                    .color(theme.base_content),
+                            )
+                            .frame(false);
+                            if ui.add(close).on_hover_text("Dismiss").clicked() {
+                                dismissed = true;
+                            }
+                        }
+
+                        if let Some(action) = self.action {
+                            let button = egui::Button::new(
+                                egui::RichText::new(action)
+                                    .size(font_size * 0.9)
+                                    .color(color)
+                                    .strong(),
+                            )
+                            .fill(theme.base_100)
+                            .stroke(egui::Stroke::new(1.0, color));
+                            if ui.add(button).clicked() {
+                                action_clicked = true;
+                            }
+                        }
+                    });
+                });
+            })
+            .response;
+
+        let label = match self.title {
+            Some(title) => format!("{}: {}", title, self.message),
+            None => self.message.to_string(),
+        };
+        response.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Label, true, &label));
+
+        BannerResponse {
+            response,
+            action_clicked,
+            dismissed,
+        }
+    }
+}
+
+/// System to restore the dismissed banners from storage
+#[cfg(feature = "storage")]
+fn load_dismissed_banners(storage: Option<Res<Storage>>, mut banners: ResMut<DismissedBanners>) {
+    let Some(storage) = storage else {
+        return;
+    };
+
+    match storage.load::<BTreeSet<String>>(DISMISSED_BANNERS_STORAGE_KEY) {
+        Ok(Some(ids)) => banners.ids = ids,
+        Ok(None) => {}
+        Err(e) => warn!("Failed to load dismissed banners: {}", e),
+    }
+}
+
+/// System to persist the dismissed banners whenever they change
+#[cfg(feature = "storage")]
+fn save_dismissed_banners(
+    scheduler: Option<ResMut<PersistenceScheduler>>,
+    banners: Res<DismissedBanners>,
+) {
+    if !banners.is_changed() {
+        return;
+    }
+
+    if let Some(mut scheduler) = scheduler {
+        scheduler.schedule(DISMISSED_BANNERS_STORAGE_KEY, &banners.ids);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestHarness;
+    use bevy_egui::{EguiContexts, EguiPrimaryContextPass};
+
+    fn banner_ui(
+        mut contexts: EguiContexts,
+        mut banners: ResMut<DismissedBanners>,
+        mut synced: Local<u32>,
+    ) {
+        let Ok(ctx) = contexts.ctx_mut() else {
+            return;
+        };
+        let theme = KonnektorenTheme::default();
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let banner = ThemedBanner::warning("offline", "Progress will sync later", &theme)
+                .title("Offline mode")
+                .action("Retry");
+            if let Some(response) = banners.show(ui, banner) {
+                if response.action_clicked {
+                    *synced += 1;
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn test_banner_dismissal() {
+        let mut harness = TestHarness::new().with_plugins(BannerPlugin);
+        harness
+            .app_mut()
+            .add_systems(EguiPrimaryContextPass, banner_ui);
+        harness.run_frames(2);
+        assert!(harness.has_widget("Offline mode: Progress will sync later"));
+
+        harness.click("✕");
+        harness.step();
+        assert!(harness
+            .world()
+            .resource::<DismissedBanners>()
+            .is_dismissed("offline"));
+        assert!(!harness.has_widget("Offline mode: Progress will sync later"));
+    }
+}
@@ -0,0 +1,484 @@
+use super::{
+    announcer::Announcer,
+    responsive::{ResponsiveFontSize, ResponsiveInfo},
+};
+use crate::theme::KonnektorenTheme;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts, EguiPrimaryContextPass};
+
+/// Plugin for celebration effects.
+///
+/// High scores in a [`ChallengeFinished`], an [`AchievementUnlocked`] and a
+/// [`LevelUp`] spawn a [`CelebrationEffect`] with confetti or fireworks in the
+/// theme colors. Effects can also be spawned directly. With
+/// [`ResponsiveInfo::reduced_motion`] a calm glow is shown instead of particles.
+#[derive(Default)]
+pub struct CelebrationPlugin {
+    pub config: CelebrationConfig,
+}
+
+impl CelebrationPlugin {
+    pub fn with_config(config: CelebrationConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Plugin for CelebrationPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.config.clone())
+            .add_message::<ChallengeFinished>()
+            .add_message::<AchievementUnlocked>()
+            .add_message::<LevelUp>()
+            .add_systems(
+                Update,
+                (spawn_celebrations, update_celebration_effects).chain(),
+            )
+            .add_systems(EguiPrimaryContextPass, render_celebration_effects);
+    }
+}
+
+/// When and how celebrations are shown
+#[derive(Resource, Debug, Clone)]
+pub struct CelebrationConfig {
+    /// Minimum score ratio of a finished challenge that is celebrated
+    pub high_score_threshold: f32,
+    pub celebrate_achievements: bool,
+    pub celebrate_level_ups: bool,
+    /// Duration of an effect in seconds
+    pub duration: f32,
+    /// Particles per effect
+    pub density: usize,
+}
+
+impl Default for CelebrationConfig {
+    fn default() -> Self {
+        Self {
+            high_score_threshold: 0.9,
+            celebrate_achievements: true,
+            celebrate_level_ups: true,
+            duration: 2.5,
+            density: 120,
+        }
+    }
+}
+
+/// Message sent by games when a challenge was finished
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct ChallengeFinished {
+    pub challenge_id: String,
+    pub score: u32,
+    pub max_score: u32,
+}
+
+impl ChallengeFinished {
+    /// Score between 0 and 1
+    pub fn ratio(&self) -> f32 {
+        if self.max_score == 0 {
+            0.0
+        } else {
+            self.score as f32 / self.max_score as f32
+        }
+    }
+}
+
+/// Message sent by games when an achievement was unlocked
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct AchievementUnlocked {
+    pub id: String,
+    pub name: String,
+}
+
+/// Message sent by games when the player reached a new level
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelUp {
+    pub level: u32,
+}
+
+/// Look of a celebration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CelebrationKind {
+    /// Confetti falling from the top
+    #[default]
+    Confetti,
+    /// Bursts of sparks
+    Fireworks,
+}
+
+/// Component for a celebration overlay, despawned when it is over
+#[derive(Component, Debug, Clone)]
+pub struct CelebrationEffect {
+    pub kind: CelebrationKind,
+    /// Text shown in the center, e.g. "Level 3!"
+    pub message: Option<String>,
+    pub duration: f32,
+    pub density: usize,
+    elapsed: f32,
+    started: bool,
+    particles: Vec<Particle>,
+    rng: u32,
+}
+
+impl CelebrationEffect {
+    pub fn new(kind: CelebrationKind) -> Self {
+        let config = CelebrationConfig::default();
+        Self {
+            kind,
+            message: None,
+            duration: config.duration,
+            density: config.density,
+            elapsed: 0.0,
+            started: false,
+            particles: vec![],
+            rng: 0x9e37_79b9,
+        }
+    }
+
+    pub fn confetti() -> Self {
+        Self::new(CelebrationKind::Confetti)
+    }
+
+    pub fn fireworks() -> Self {
+        Self::new(CelebrationKind::Fireworks)
+    }
+
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_density(mut self, density: usize) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Progress from 0 to 1
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / self.duration).min(1.0)
+        }
+    }
+
+    fn from_config(kind: CelebrationKind, config: &CelebrationConfig) -> Self {
+        Self::new(kind)
+            .with_duration(config.duration)
+            .with_density(config.density)
+    }
+
+    /// Pseudo random number between 0 and 1
+    fn random(&mut self) -> f32 {
+        // xorshift32, good enough for particles
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 17;
+        self.rng ^= self.rng << 5;
+        (self.rng >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    fn spawn_particles(&mut self, screen: Vec2) {
+        match self.kind {
+            CelebrationKind::Confetti => {
+                for index in 0..self.density {
+                    let position =
+                        Vec2::new(self.random() * screen.x, -self.random() * screen.y * 0.5);
+                    let velocity =
+                        Vec2::new((self.random() - 0.5) * 120.0, 60.0 + self.random() * 120.0);
+                    let particle = Particle {
+                        position,
+                        velocity,
+                        rotation: self.random() * std::f32::consts::TAU,
+                        spin: (self.random() - 0.5) * 10.0,
+                        size: 6.0 + self.random() * 6.0,
+                        color: index % PARTICLE_COLORS,
+                        delay: 0.0,
+                    };
+                    self.particles.push(particle);
+                }
+            }
+            CelebrationKind::Fireworks => {
+                let bursts = 3.max(self.density / 40);
+                let per_burst = self.density / bursts;
+                for burst in 0..bursts {
+                    let center = Vec2::new(
+                        (0.2 + self.random() * 0.6) * screen.x,
+                        (0.2 + self.random() * 0.4) * screen.y,
+                    );
+                    let delay = burst as f32 * self.duration * 0.2;
+                    for _ in 0..per_burst {
+                        let angle = self.random() * std::f32::consts::TAU;
+                        let speed = 80.0 + self.random() * 180.0;
+                        let particle = Particle {
+                            position: center,
+                            velocity: Vec2::from_angle(angle) * speed,
+                            rotation: 0.0,
+                            spin: 0.0,
+                            size: 3.0 + self.random() * 2.0,
+                            color: burst % PARTICLE_COLORS,
+                            delay,
+                        };
+                        self.particles.push(particle);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Number of theme colors used for particles
+const PARTICLE_COLORS: usize = 5;
+
+#[derive(Debug, Clone)]
+struct Particle {
+    position: Vec2,
+    velocity: Vec2,
+    rotation: f32,
+    spin: f32,
+    size: f32,
+    color: usize,
+    /// Seconds before the particle appears
+    delay: f32,
+}
+
+/// System to spawn celebrations for high scores, achievements and level ups
+fn spawn_celebrations(
+    mut commands: Commands,
+    config: Res<CelebrationConfig>,
+    mut challenges: MessageReader<ChallengeFinished>,
+    mut achievements: MessageReader<AchievementUnlocked>,
+    mut level_ups: MessageReader<LevelUp>,
+    mut announcer: Option<ResMut<Announcer>>,
+) {
+    for challenge in challenges.read() {
+        if let Some(announcer) = announcer.as_mut() {
+            announcer.polite(format!(
+                "Challenge finished: {} of {} points",
+                challenge.score, challenge.max_score
+            ));
+        }
+        if challenge.ratio() >= config.high_score_threshold {
+            commands.spawn((
+                Name::new("Celebration"),
+                CelebrationEffect::from_config(CelebrationKind::Confetti, &config),
+            ));
+        }
+    }
+
+    for achievement in achievements.read() {
+        if let Some(announcer) = announcer.as_mut() {
+            announcer.polite(format!("Achievement unlocked: {}", achievement.name));
+        }
+        if config.celebrate_achievements {
+            commands.spawn((
+                Name::new("Celebration"),
+                CelebrationEffect::from_config(CelebrationKind::Fireworks, &config)
+                    .with_message(achievement.name.clone()),
+            ));
+        }
+    }
+
+    for level_up in level_ups.read() {
+        if let Some(announcer) = announcer.as_mut() {
+            announcer.polite(format!("Level {} reached", level_up.level));
+        }
+        if config.celebrate_level_ups {
+            commands.spawn((
+                Name::new("Celebration"),
+                CelebrationEffect::from_config(CelebrationKind::Fireworks, &config)
+                    .with_message(format!("Level {}!", level_up.level)),
+            ));
+        }
+    }
+}
+
+/// System to move the particles and despawn finished effects
+fn update_celebration_effects(
+    mut commands: Commands,
+    time: Res<Time>,
+    responsive: Res<ResponsiveInfo>,
+    mut effects: Query<(Entity, &mut CelebrationEffect)>,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut effect) in &mut effects {
+        if !effect.started {
+            effect.started = true;
+            effect.rng ^= entity.to_bits() as u32;
+            if !responsive.reduced_motion {
+                effect.spawn_particles(responsive.screen_size);
+            }
+        }
+
+        effect.elapsed += delta;
+        if effect.elapsed >= effect.duration {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let gravity = match effect.kind {
+            CelebrationKind::Confetti => 40.0,
+            CelebrationKind::Fireworks => 120.0,
+        };
+        for particle in &mut effect.particles {
+            if particle.delay > 0.0 {
+                particle.delay -= delta;
+                continue;
+            }
+            particle.velocity.y += gravity * delta;
+            particle.position += particle.velocity * delta;
+            particle.rotation += particle.spin * delta;
+        }
+    }
+}
+
+/// System to draw the celebration effects on top of everything
+fn render_celebration_effects(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    effects: Query<(Entity, &CelebrationEffect)>,
+) {
+    if effects.is_empty() {
+        return;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let colors: [egui::Color32; PARTICLE_COLORS] = [
+        theme.primary,
+        theme.secondary,
+        theme.success,
+        theme.info,
+        theme.warning,
+    ];
+    let screen = egui::Rect::from_min_size(
+        egui::Pos2::ZERO,
+        egui::vec2(responsive.screen_size.x, responsive.screen_size.y),
+    );
+
+    for (entity, effect) in &effects {
+        let painter = ctx.layer_painter(egui::LayerId::new(
+            egui::Order::Tooltip,
+            egui::Id::new(("konnektoren_celebration", entity)),
+        ));
+        // Fade out during the last quarter
+        let fade = ((1.0 - effect.progress()) * 4.0).min(1.0);
+
+        if responsive.reduced_motion {
+            // Calm glow around the screen instead of moving particles
+            let glow = theme.primary.linear_multiply(0.6 * fade);
+            for inset in 0..4 {
+                painter.rect_stroke(
+                    screen.shrink(inset as f32 * 4.0),
+                    0.0,
+                    egui::Stroke::new(4.0, glow.linear_multiply(1.0 - inset as f32 * 0.25)),
+                    egui::StrokeKind::Inside,
+                );
+            }
+        }
+
+        for particle in effect.particles.iter().filter(|p| p.delay <= 0.0) {
+            let color = colors[particle.color].linear_multiply(fade);
+            let center = egui::pos2(particle.position.x, particle.position.y);
+            match effect.kind {
+                CelebrationKind::Confetti => {
+                    let (sin, cos) = particle.rotation.sin_cos();
+                    let half = egui::vec2(particle.size * 0.5, particle.size * 0.25);
+                    let points = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+                        .map(|(x, y)| {
+                            let offset = egui::vec2(x * half.x, y * half.y);
+                            center
+                                + egui::vec2(
+                                    offset.x * cos - offset.y * sin,
+                                    offset.x * sin + offset.y * cos,
+                                )
+                        })
+                        .to_vec();
+                    painter.add(egui::Shape::convex_polygon(
+                        points,
+                        color,
+                        egui::Stroke::NONE,
+                    ));
+                }
+                CelebrationKind::Fireworks => {
+                    painter.circle_filled(center, particle.size, color);
+                }
+            }
+        }
+
+        if let Some(message) = &effect.message {
+            painter.text(
+                screen.center(),
+                egui::Align2::CENTER_CENTER,
+                message,
+                egui::FontId::proportional(responsive.font_size(ResponsiveFontSize::Title) * 1.5),
+                theme.primary.linear_multiply(fade),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_app(reduced_motion: bool) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(ResponsiveInfo {
+                reduced_motion,
+                ..Default::default()
+            })
+            .insert_resource(CelebrationConfig::default())
+            .add_message::<ChallengeFinished>()
+            .add_message::<AchievementUnlocked>()
+            .add_message::<LevelUp>()
+            .add_systems(
+                Update,
+                (spawn_celebrations, update_celebration_effects).chain(),
+            );
+        app
+    }
+
+    #[test]
+    fn test_high_score_celebration() {
+        let mut app = test_app(false);
+        app.world_mut().write_message(ChallengeFinished {
+            challenge_id: "articles".to_string(),
+            score: 5,
+            max_score: 10,
+        });
+        app.world_mut().write_message(ChallengeFinished {
+            challenge_id: "articles".to_string(),
+            score: 10,
+            max_score: 10,
+        });
+        app.update();
+        app.update();
+
+        let mut effects = app.world_mut().query::<&CelebrationEffect>();
+        let effects: Vec<_> = effects.iter(app.world()).collect();
+        assert_eq!(effects.len(), 1);
+        assert_eq!(effects[0].kind, CelebrationKind::Confetti);
+        assert_eq!(effects[0].particles.len(), 120);
+    }
+
+    #[test]
+    fn test_reduced_motion_celebration() {
+        let mut app = test_app(true);
+        app.world_mut().write_message(LevelUp { level: 3 });
+        app.update();
+        app.update();
+
+        let mut effects = app.world_mut().query::<&CelebrationEffect>();
+        let effect = effects.single(app.world()).unwrap();
+        assert_eq!(effect.message.as_deref(), Some("Level 3!"));
+        assert!(effect.particles.is_empty());
+    }
+}
@@ -0,0 +1,667 @@
+use super::responsive::{ResponsiveFontSize, ResponsiveInfo};
+use crate::theme::KonnektorenTheme;
+use bevy_egui::egui;
+
+/// Colors of series, bars and slices without an explicit color, in order
+pub fn chart_palette(theme: &KonnektorenTheme) -> [egui::Color32; 7] {
+    [
+        theme.primary,
+        theme.secondary,
+        theme.accent,
+        theme.info,
+        theme.success,
+        theme.warning,
+        theme.error,
+    ]
+}
+
+/// A named sequence of values of a [`LineChart`]
+#[derive(Debug, Clone)]
+pub struct ChartSeries<'a> {
+    pub name: &'a str,
+    pub values: &'a [f32],
+    pub color: Option<egui::Color32>,
+}
+
+impl<'a> ChartSeries<'a> {
+    pub fn new(name: &'a str, values: &'a [f32]) -> Self {
+        Self {
+            name,
+            values,
+            color: None,
+        }
+    }
+
+    pub fn color(mut self, color: egui::Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// Size and text shared by all charts
+struct ChartLayout {
+    font_size: f32,
+    height: f32,
+}
+
+impl ChartLayout {
+    fn new(responsive_info: Option<&ResponsiveInfo>, height: Option<f32>) -> Self {
+        let font_size = responsive_info.map_or(12.0, |responsive_info| {
+            responsive_info.font_size(ResponsiveFontSize::Small)
+        });
+        let is_mobile = responsive_info.is_some_and(|responsive_info| responsive_info.is_mobile());
+        Self {
+            font_size,
+            height: height.unwrap_or(if is_mobile { 180.0 } else { 240.0 }),
+        }
+    }
+}
+
+/// Round up to 1, 2 or 5 times a power of ten for the axis maximum
+fn nice_ceiling(value: f32) -> f32 {
+    if value <= 0.0 {
+        return 1.0;
+    }
+    let magnitude = 10f32.powf(value.log10().floor());
+    let normalized = value / magnitude;
+    let nice = if normalized <= 1.0 {
+        1.0
+    } else if normalized <= 2.0 {
+        2.0
+    } else if normalized <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * magnitude
+}
+
+fn format_value(value: f32) -> String {
+    if value.fract().abs() < 0.01 {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.1}", value)
+    }
+}
+
+/// Paint the value axis and grid, returning the plot area and its maximum
+fn paint_axes(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    max_value: f32,
+    font_size: f32,
+    theme: &KonnektorenTheme,
+) -> (egui::Rect, f32) {
+    let max_value = nice_ceiling(max_value);
+    let font = egui::FontId::proportional(font_size);
+    let label_width = painter
+        .layout_no_wrap(format_value(max_value), font.clone(), theme.base_content)
+        .size()
+        .x;
+    let plot = egui::Rect::from_min_max(
+        rect.min + egui::vec2(label_width + 6.0, font_size / 2.0),
+        rect.max - egui::vec2(0.0, font_size + 6.0),
+    );
+
+    for step in 0..=4 {
+        let value = max_value * step as f32 / 4.0;
+        let y = plot.bottom() - plot.height() * step as f32 / 4.0;
+        let color = if step == 0 {
+            theme.base_content.linear_multiply(0.5)
+        } else {
+            theme.base_300
+        };
+        painter.hline(plot.x_range(), y, egui::Stroke::new(1.0, color));
+        painter.text(
+            egui::pos2(plot.left() - 6.0, y),
+            egui::Align2::RIGHT_CENTER,
+            format_value(value),
+            font.clone(),
+            theme.base_content,
+        );
+    }
+    (plot, max_value)
+}
+
+fn paint_x_label(
+    painter: &egui::Painter,
+    x: f32,
+    plot: egui::Rect,
+    label: &str,
+    font_size: f32,
+    theme: &KonnektorenTheme,
+) {
+    painter.text(
+        egui::pos2(x, plot.bottom() + 4.0),
+        egui::Align2::CENTER_TOP,
+        label,
+        egui::FontId::proportional(font_size),
+        theme.base_content,
+    );
+}
+
+/// Legend with a color swatch per entry
+fn show_legend<'a>(
+    ui: &mut egui::Ui,
+    entries: impl Iterator<Item = (&'a str, egui::Color32)>,
+    font_size: f32,
+    theme: &KonnektorenTheme,
+) {
+    ui.horizontal_wrapped(|ui| {
+        for (name, color) in entries {
+            let (swatch, _) =
+                ui.allocate_exact_size(egui::vec2(font_size, font_size), egui::Sense::hover());
+            ui.painter().rect_filled(swatch, 2.0, color);
+            ui.label(
+                egui::RichText::new(name)
+                    .size(font_size)
+                    .color(theme.base_content),
+            );
+            ui.add_space(font_size / 2.0);
+        }
+    });
+}
+
+/// Line chart of one or more series sharing the x labels.
+///
+/// Hovering shows the values at the nearest label.
+pub struct LineChart<'a> {
+    pub series: Vec<ChartSeries<'a>>,
+    pub labels: &'a [&'a str],
+    pub title: &'a str,
+    pub theme: &'a KonnektorenTheme,
+    pub height: Option<f32>,
+    pub show_legend: bool,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> LineChart<'a> {
+    pub fn new(title: &'a str, theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            series: vec![],
+            labels: &[],
+            title,
+            theme,
+            height: None,
+            show_legend: true,
+            responsive_info: None,
+        }
+    }
+
+    pub fn series(mut self, series: ChartSeries<'a>) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    pub fn labels(mut self, labels: &'a [&'a str]) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Self {
+        self.show_legend = show_legend;
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+
+    fn series_color(&self, index: usize) -> egui::Color32 {
+        let palette = chart_palette(self.theme);
+        self.series[index]
+            .color
+            .unwrap_or(palette[index % palette.len()])
+    }
+
+    fn accessibility_label(&self) -> String {
+        let series = self
+            .series
+            .iter()
+            .map(|series| {
+                let values: Vec<_> = series.values.iter().map(|v| format_value(*v)).collect();
+                format!("{} {}", series.name, values.join(", "))
+            })
+            .collect::<Vec<_>>();
+        format!("Line chart {}: {}", self.title, series.join("; "))
+    }
+}
+
+impl<'a> egui::Widget for LineChart<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let layout = ChartLayout::new(self.responsive_info, self.height);
+        let theme = self.theme;
+
+        ui.vertical(|ui| {
+            let size = egui::vec2(ui.available_width(), layout.height);
+            let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+            let label = self.accessibility_label();
+            response
+                .widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Other, true, &label));
+
+            let painter = ui.painter_at(rect);
+            let max_value = self
+                .series
+                .iter()
+                .flat_map(|series| series.values.iter().copied())
+                .fold(0.0, f32::max);
+            let (plot, max_value) = paint_axes(&painter, rect, max_value, layout.font_size, theme);
+
+            let points = self
+                .series
+                .iter()
+                .map(|series| series.values.len())
+                .chain([self.labels.len()])
+                .max()
+                .unwrap_or_default();
+            let x_at = |index: usize| {
+                if points <= 1 {
+                    plot.center().x
+                } else {
+                    plot.left() + plot.width() * index as f32 / (points - 1) as f32
+                }
+            };
+            let y_at =
+                |value: f32| plot.bottom() - plot.height() * (value / max_value).clamp(0.0, 1.0);
+
+            // Skip labels that would overlap on narrow charts
+            let label_step = ((points as f32 * layout.font_size * 3.0) / plot.width())
+                .ceil()
+                .max(1.0) as usize;
+            for (index, label) in self.labels.iter().enumerate().step_by(label_step) {
+                paint_x_label(&painter, x_at(index), plot, label, layout.font_size, theme);
+            }
+
+            for (index, series) in self.series.iter().enumerate() {
+                let color = self.series_color(index);
+                let line: Vec<_> = series
+                    .values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| egui::pos2(x_at(i), y_at(*value)))
+                    .collect();
+                painter.add(egui::Shape::line(
+                    line.clone(),
+                    egui::Stroke::new(2.0, color),
+                ));
+                for point in line {
+                    painter.circle_filled(point, 3.0, color);
+                }
+            }
+
+            let hovered = response.hover_pos().filter(|_| points > 0).map(|pointer| {
+                let t = ((pointer.x - plot.left()) / plot.width()).clamp(0.0, 1.0);
+                (t * (points.saturating_sub(1)) as f32).round() as usize
+            });
+            let response = match hovered {
+                Some(index) => {
+                    painter.vline(
+                        x_at(index),
+                        plot.y_range(),
+                        egui::Stroke::new(1.0, theme.base_content.linear_multiply(0.4)),
+                    );
+                    let mut lines = vec![];
+                    if let Some(label) = self.labels.get(index) {
+                        lines.push(label.to_string());
+                    }
+                    for series in &self.series {
+                        if let Some(value) = series.values.get(index) {
+                            lines.push(format!("{}: {}", series.name, format_value(*value)));
+                        }
+                    }
+                    response.on_hover_text_at_pointer(lines.join("\n"))
+                }
+                None => response,
+            };
+
+            if self.show_legend && !self.series.is_empty() {
+                show_legend(
+                    ui,
+                    self.series
+                        .iter()
+                        .enumerate()
+                        .map(|(index, series)| (series.name, self.series_color(index))),
+                    layout.font_size,
+                    theme,
+                );
+            }
+            response
+        })
+        .inner
+    }
+}
+
+/// Bar chart of labeled values
+pub struct BarChart<'a> {
+    pub bars: &'a [(&'a str, f32)],
+    pub title: &'a str,
+    pub theme: &'a KonnektorenTheme,
+    /// Color of all bars, each bar gets a palette color if `None`
+    pub color: Option<egui::Color32>,
+    pub height: Option<f32>,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> BarChart<'a> {
+    pub fn new(title: &'a str, bars: &'a [(&'a str, f32)], theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            bars,
+            title,
+            theme,
+            color: Some(theme.primary),
+            height: None,
+            responsive_info: None,
+        }
+    }
+
+    pub fn color(mut self, color: Option<egui::Color32>) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+
+    fn bar_color(&self, index: usize) -> egui::Color32 {
+        let palette = chart_palette(self.theme);
+        self.color.unwrap_or(palette[index % palette.len()])
+    }
+}
+
+impl<'a> egui::Widget for BarChart<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let layout = ChartLayout::new(self.responsive_info, self.height);
+        let theme = self.theme;
+
+        let size = egui::vec2(ui.available_width(), layout.height);
+        let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+        let bars: Vec<_> = self
+            .bars
+            .iter()
+            .map(|(label, value)| format!("{} {}", label, format_value(*value)))
+            .collect();
+        let label = format!("Bar chart {}: {}", self.title, bars.join(", "));
+        response.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Other, true, &label));
+
+        let painter = ui.painter_at(rect);
+        let max_value = self
+            .bars
+            .iter()
+            .map(|(_, value)| *value)
+            .fold(0.0, f32::max);
+        let (plot, max_value) = paint_axes(&painter, rect, max_value, layout.font_size, theme);
+        if self.bars.is_empty() {
+            return response;
+        }
+
+        let slot = plot.width() / self.bars.len() as f32;
+        let bar_width = (slot * 0.7).min(64.0);
+        let mut hovered = None;
+        for (index, (label, value)) in self.bars.iter().enumerate() {
+            let center_x = plot.left() + slot * (index as f32 + 0.5);
+            let top = plot.bottom() - plot.height() * (value / max_value).clamp(0.0, 1.0);
+            let bar = egui::Rect::from_min_max(
+                egui::pos2(center_x - bar_width / 2.0, top),
+                egui::pos2(center_x + bar_width / 2.0, plot.bottom()),
+            );
+
+            let is_hovered = response.hover_pos().is_some_and(|pointer| {
+                pointer.x >= center_x - slot / 2.0 && pointer.x < center_x + slot / 2.0
+            });
+            let color = self.bar_color(index);
+            let fill = if is_hovered {
+                color
+            } else {
+                color.linear_multiply(0.85)
+            };
+            let radius = egui::CornerRadius {
+                nw: theme.radius.min(4),
+                ne: theme.radius.min(4),
+                sw: 0,
+                se: 0,
+            };
+            painter.rect_filled(bar, radius, fill);
+            if is_hovered {
+                hovered = Some(format!("{}: {}", label, format_value(*value)));
+            }
+
+            if slot >= layout.font_size * 2.0 || index % 2 == 0 {
+                paint_x_label(&painter, center_x, plot, label, layout.font_size, theme);
+            }
+        }
+
+        match hovered {
+            Some(text) => response.on_hover_text_at_pointer(text),
+            None => response,
+        }
+    }
+}
+
+/// Pie chart of the shares of labeled values
+pub struct PieChart<'a> {
+    pub slices: &'a [(&'a str, f32)],
+    pub title: &'a str,
+    pub theme: &'a KonnektorenTheme,
+    pub size: Option<f32>,
+    pub show_legend: bool,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> PieChart<'a> {
+    pub fn new(title: &'a str, slices: &'a [(&'a str, f32)], theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            slices,
+            title,
+            theme,
+            size: None,
+            show_legend: true,
+            responsive_info: None,
+        }
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Self {
+        self.show_legend = show_legend;
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+
+    fn total(&self) -> f32 {
+        self.slices.iter().map(|(_, value)| value.max(0.0)).sum()
+    }
+
+    /// Share of each slice in percent
+    pub fn percentages(&self) -> Vec<f32> {
+        let total = self.total();
+        self.slices
+            .iter()
+            .map(|(_, value)| {
+                if total > 0.0 {
+                    value.max(0.0) / total * 100.0
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+}
+
+impl<'a> egui::Widget for PieChart<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let layout = ChartLayout::new(self.responsive_info, self.size);
+        let theme = self.theme;
+        let palette = chart_palette(theme);
+        let percentages = self.percentages();
+
+        ui.vertical(|ui| {
+            let diameter = layout.height.min(ui.available_width());
+            let (rect, response) =
+                ui.allocate_exact_size(egui::vec2(diameter, diameter), egui::Sense::hover());
+            let shares: Vec<_> = self
+                .slices
+                .iter()
+                .zip(&percentages)
+                .map(|((label, _), percent)| format!("{} {:.0}%", label, percent))
+                .collect();
+            let label = format!("Pie chart {}: {}", self.title, shares.join(", "));
+            response
+                .widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Other, true, &label));
+
+            let painter = ui.painter_at(rect);
+            let center = rect.center();
+            let radius = diameter / 2.0 - 2.0;
+            if self.total() <= 0.0 {
+                painter.circle_filled(center, radius, theme.base_200);
+                return response;
+            }
+
+            let hovered_angle = response.hover_pos().and_then(|pointer| {
+                let offset = pointer - center;
+                (offset.length() <= radius)
+                    .then(|| offset.y.atan2(offset.x).rem_euclid(std::f32::consts::TAU))
+            });
+
+            // Start at 12 o'clock, going clockwise
+            let mut start = -std::f32::consts::FRAC_PI_2;
+            let mut hovered = None;
+            for (index, ((label, _), percent)) in self.slices.iter().zip(&percentages).enumerate() {
+                let sweep = percent / 100.0 * std::f32::consts::TAU;
+                if sweep <= 0.0 {
+                    continue;
+                }
+                let is_hovered = hovered_angle.is_some_and(|angle| {
+                    let relative = (angle - start).rem_euclid(std::f32::consts::TAU);
+                    relative < sweep
+                });
+                let color = palette[index % palette.len()];
+                let slice_radius = if is_hovered { radius } else { radius - 3.0 };
+
+                // Convex polygons only, so split the slice into quarter circles at most
+                let segments = (sweep / std::f32::consts::FRAC_PI_2).ceil() as usize;
+                for segment in 0..segments {
+                    let from = start + sweep * segment as f32 / segments as f32;
+                    let to = start + sweep * (segment + 1) as f32 / segments as f32;
+                    let steps = ((to - from) * 16.0).ceil().max(2.0) as usize;
+                    let mut points = vec![center];
+                    for step in 0..=steps {
+                        let angle = from + (to - from) * step as f32 / steps as f32;
+                        points.push(center + egui::vec2(angle.cos(), angle.sin()) * slice_radius);
+                    }
+                    painter.add(egui::Shape::convex_polygon(
+                        points,
+                        color,
+                        egui::Stroke::NONE,
+                    ));
+                }
+                // Separate the slices
+                painter.line_segment(
+                    [
+                        center,
+                        center + egui::vec2(start.cos(), start.sin()) * slice_radius,
+                    ],
+                    egui::Stroke::new(1.5, theme.base_100),
+                );
+
+                if is_hovered {
+                    hovered = Some(format!("{}: {:.0}%", label, percent));
+                }
+                start += sweep;
+            }
+
+            let response = match hovered {
+                Some(text) => response.on_hover_text_at_pointer(text),
+                None => response,
+            };
+
+            if self.show_legend {
+                let entries: Vec<_> = self
+                    .slices
+                    .iter()
+                    .zip(&percentages)
+                    .enumerate()
+                    .map(|(index, ((label, _), percent))| {
+                        (
+                            format!("{} {:.0}%", label, percent),
+                            palette[index % palette.len()],
+                        )
+                    })
+                    .collect();
+                show_legend(
+                    ui,
+                    entries.iter().map(|(name, color)| (name.as_str(), *color)),
+                    layout.font_size,
+                    theme,
+                );
+            }
+            response
+        })
+        .inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestHarness;
+    use bevy_egui::{EguiContexts, EguiPrimaryContextPass};
+
+    fn chart_ui(mut contexts: EguiContexts) {
+        let Ok(ctx) = contexts.ctx_mut() else {
+            return;
+        };
+        let theme = KonnektorenTheme::default();
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.add(BarChart::new(
+                "Answers",
+                &[("Right", 8.0), ("Wrong", 2.0)],
+                &theme,
+            ));
+            ui.add(PieChart::new(
+                "Answers",
+                &[("Right", 3.0), ("Wrong", 1.0)],
+                &theme,
+            ));
+        });
+    }
+
+    #[test]
+    fn test_chart_accessibility_labels() {
+        let mut harness = TestHarness::new();
+        harness
+            .app_mut()
+            .add_systems(EguiPrimaryContextPass, chart_ui);
+        harness.run_frames(2);
+
+        assert!(harness.has_widget("Bar chart Answers: Right 8, Wrong 2"));
+        assert!(harness.has_widget("Pie chart Answers: Right 75%, Wrong 25%"));
+    }
+
+    #[test]
+    fn test_nice_ceiling() {
+        assert_eq!(nice_ceiling(0.0), 1.0);
+        assert_eq!(nice_ceiling(8.0), 10.0);
+        assert_eq!(nice_ceiling(17.0), 20.0);
+        assert_eq!(nice_ceiling(420.0), 500.0);
+    }
+}
@@ -0,0 +1,457 @@
+use super::{
+    responsive::{ResponsiveFontSize, ResponsiveInfo},
+    widgets::{ResponsiveText, ThemedButton},
+};
+use crate::theme::KonnektorenTheme;
+use bevy::prelude::*;
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+
+/// Plugin for guided tours that introduce first-time users to a screen,
+/// added by the [`UIPlugin`](super::UIPlugin).
+///
+/// Each step of a [`CoachMarkTour`] highlights a widget registered with
+/// [`CoachMarkExt::coach_mark`] or a fixed region, and explains it in a text
+/// bubble with Next/Skip buttons. The tour entity is despawned when the tour
+/// ends and a [`CoachMarksEvent`] is sent.
+///
+/// ```ignore
+/// // While rendering the map
+/// ui.add(challenge_button).coach_mark("map.challenge.articles");
+///
+/// commands.spawn(
+///     CoachMarkTour::new("map-intro")
+///         .add_step(CoachMarkStep::widget("map.challenge.articles", "Challenges", "Start here."))
+///         .add_step(CoachMarkStep::text("Have fun", "Complete challenges to unlock more.")),
+/// );
+/// ```
+pub struct CoachMarksPlugin;
+
+impl Plugin for CoachMarksPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<CoachMarksEvent>()
+            .add_systems(EguiPrimaryContextPass, render_coach_marks);
+    }
+}
+
+/// What a coach mark step points at
+#[derive(Debug, Clone, PartialEq)]
+pub enum CoachMarkTarget {
+    /// Widget registered with [`CoachMarkExt::coach_mark`]
+    Widget(String),
+    /// Fixed screen region
+    Region(egui::Rect),
+    /// Nothing, the bubble is centered
+    None,
+}
+
+/// A single step of a tour
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoachMarkStep {
+    pub target: CoachMarkTarget,
+    pub title: String,
+    pub text: String,
+}
+
+impl CoachMarkStep {
+    pub fn new(target: CoachMarkTarget, title: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            target,
+            title: title.into(),
+            text: text.into(),
+        }
+    }
+
+    /// Step highlighting a registered widget
+    pub fn widget(
+        anchor: impl Into<String>,
+        title: impl Into<String>,
+        text: impl Into<String>,
+    ) -> Self {
+        Self::new(CoachMarkTarget::Widget(anchor.into()), title, text)
+    }
+
+    /// Step highlighting a screen region
+    pub fn region(rect: egui::Rect, title: impl Into<String>, text: impl Into<String>) -> Self {
+        Self::new(CoachMarkTarget::Region(rect), title, text)
+    }
+
+    /// Step without highlight
+    pub fn text(title: impl Into<String>, text: impl Into<String>) -> Self {
+        Self::new(CoachMarkTarget::None, title, text)
+    }
+}
+
+/// Component for a guided tour, spawn it to start the tour
+#[derive(Component, Debug, Clone)]
+pub struct CoachMarkTour {
+    pub id: String,
+    pub steps: Vec<CoachMarkStep>,
+    pub allow_skip: bool,
+    pub next_text: String,
+    pub skip_text: String,
+    pub finish_text: String,
+    /// Index of the current step
+    pub current: usize,
+}
+
+impl CoachMarkTour {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            steps: vec![],
+            allow_skip: true,
+            next_text: "Next".to_string(),
+            skip_text: "Skip".to_string(),
+            finish_text: "Got it".to_string(),
+            current: 0,
+        }
+    }
+
+    pub fn add_step(mut self, step: CoachMarkStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    pub fn with_skip(mut self, allow_skip: bool) -> Self {
+        self.allow_skip = allow_skip;
+        self
+    }
+
+    pub fn with_button_texts(
+        mut self,
+        next: impl Into<String>,
+        skip: impl Into<String>,
+        finish: impl Into<String>,
+    ) -> Self {
+        self.next_text = next.into();
+        self.skip_text = skip.into();
+        self.finish_text = finish.into();
+        self
+    }
+
+    /// Tour through the settings screen
+    pub fn settings_screen() -> Self {
+        Self::new("settings-intro")
+            .add_step(CoachMarkStep::widget(
+                "settings.content",
+                "Settings",
+                "Adjust sound, display and controls to your liking.",
+            ))
+            .add_step(CoachMarkStep::widget(
+                "settings.back",
+                "Done?",
+                "Your changes are applied right away, go back when you are finished.",
+            ))
+    }
+
+    /// Tour through the input configuration screen
+    pub fn input_configuration() -> Self {
+        Self::new("input-configuration-intro")
+            .add_step(CoachMarkStep::widget(
+                "input.devices",
+                "Devices",
+                "These are the keyboards, mice and gamepads that were found.",
+            ))
+            .add_step(CoachMarkStep::widget(
+                "input.player.0",
+                "Players",
+                "Pick a device for every player. Each device can only be used once.",
+            ))
+    }
+
+    pub fn current_step(&self) -> Option<&CoachMarkStep> {
+        self.steps.get(self.current)
+    }
+
+    pub fn is_last_step(&self) -> bool {
+        self.current + 1 >= self.steps.len()
+    }
+}
+
+/// Events sent when a tour ends
+#[derive(Message, Debug, Clone, PartialEq)]
+pub enum CoachMarksEvent {
+    /// All steps were shown
+    Finished { entity: Entity, tour_id: String },
+    /// The user skipped the tour at `step`
+    Skipped {
+        entity: Entity,
+        tour_id: String,
+        step: usize,
+    },
+}
+
+/// Extension to register widgets as coach mark targets
+pub trait CoachMarkExt {
+    /// Register the widget under `anchor` so tours can highlight it
+    fn coach_mark(self, anchor: &str) -> Self;
+}
+
+impl CoachMarkExt for egui::Response {
+    fn coach_mark(self, anchor: &str) -> Self {
+        register_coach_mark(&self.ctx, anchor, self.rect);
+        self
+    }
+}
+
+/// Register a screen rect as coach mark target
+pub fn register_coach_mark(ctx: &egui::Context, anchor: &str, rect: egui::Rect) {
+    let pass = ctx.cumulative_pass_nr();
+    ctx.data_mut(|data| data.insert_temp(coach_mark_id(anchor), (rect, pass)));
+}
+
+/// Rect of a target registered in this or the previous pass
+pub fn coach_mark_rect(ctx: &egui::Context, anchor: &str) -> Option<egui::Rect> {
+    let pass = ctx.cumulative_pass_nr();
+    ctx.data(|data| data.get_temp::<(egui::Rect, u64)>(coach_mark_id(anchor)))
+        .filter(|(_, registered)| registered + 1 >= pass)
+        .map(|(rect, _)| rect)
+}
+
+fn coach_mark_id(anchor: &str) -> egui::Id {
+    egui::Id::new(("konnektoren_coach_mark", anchor))
+}
+
+/// Outcome of the buttons of the current step
+enum StepAction {
+    Next,
+    Skip,
+}
+
+/// System to render the current step of every tour
+pub fn render_coach_marks(
+    mut contexts: EguiContexts,
+    mut commands: Commands,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    mut tours: Query<(Entity, &mut CoachMarkTour)>,
+    mut events: MessageWriter<CoachMarksEvent>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    for (entity, mut tour) in &mut tours {
+        let Some(step) = tour.current_step().cloned() else {
+            commands.entity(entity).despawn();
+            events.write(CoachMarksEvent::Finished {
+                entity,
+                tour_id: tour.id.clone(),
+            });
+            continue;
+        };
+
+        let target = match &step.target {
+            CoachMarkTarget::Widget(anchor) => coach_mark_rect(ctx, anchor),
+            CoachMarkTarget::Region(rect) => Some(*rect),
+            CoachMarkTarget::None => None,
+        };
+
+        let action = render_step(ctx, &tour, &step, target, entity, &theme, &responsive);
+
+        match action {
+            Some(StepAction::Next) if tour.is_last_step() => {
+                commands.entity(entity).despawn();
+                events.write(CoachMarksEvent::Finished {
+                    entity,
+                    tour_id: tour.id.clone(),
+                });
+            }
+            Some(StepAction::Next) => tour.current += 1,
+            Some(StepAction::Skip) => {
+                commands.entity(entity).despawn();
+                events.write(CoachMarksEvent::Skipped {
+                    entity,
+                    tour_id: tour.id.clone(),
+                    step: tour.current,
+                });
+            }
+            None => {}
+        }
+    }
+}
+
+fn render_step(
+    ctx: &egui::Context,
+    tour: &CoachMarkTour,
+    step: &CoachMarkStep,
+    target: Option<egui::Rect>,
+    entity: Entity,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+) -> Option<StepAction> {
+    let screen = egui::Rect::from_min_size(
+        egui::Pos2::ZERO,
+        egui::vec2(responsive.screen_size.x, responsive.screen_size.y),
+    );
+    let shade = egui::Color32::from_black_alpha(160);
+    let painter = ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Foreground,
+        egui::Id::new(("konnektoren_coach_marks", entity)),
+    ));
+
+    // Spotlight: shade everything around the target
+    let spotlight = target.map(|rect| rect.expand(6.0).intersect(screen));
+    match spotlight {
+        Some(hole) => {
+            for rect in [
+                egui::Rect::from_min_max(screen.min, egui::pos2(screen.max.x, hole.min.y)),
+                egui::Rect::from_min_max(egui::pos2(screen.min.x, hole.max.y), screen.max),
+                egui::Rect::from_min_max(
+                    egui::pos2(screen.min.x, hole.min.y),
+                    egui::pos2(hole.min.x, hole.max.y),
+                ),
+                egui::Rect::from_min_max(
+                    egui::pos2(hole.max.x, hole.min.y),
+                    egui::pos2(screen.max.x, hole.max.y),
+                ),
+            ] {
+                painter.rect_filled(rect, 0.0, shade);
+            }
+            painter.rect_stroke(
+                hole,
+                theme.radius,
+                egui::Stroke::new(2.0, theme.primary),
+                egui::StrokeKind::Outside,
+            );
+        }
+        None => {
+            painter.rect_filled(screen, 0.0, shade);
+        }
+    }
+
+    // Bubble below the target, or above it when the target is low on screen
+    const GAP: f32 = 28.0;
+    let (pivot, position) = match spotlight {
+        Some(hole) if hole.bottom() < screen.height() * 0.6 => {
+            let position = hole.center_bottom() + egui::vec2(0.0, GAP);
+            painter.arrow(
+                position,
+                hole.center_bottom() - position,
+                egui::Stroke::new(3.0, theme.primary),
+            );
+            (egui::Align2::CENTER_TOP, position)
+        }
+        Some(hole) => {
+            let position = hole.center_top() - egui::vec2(0.0, GAP);
+            painter.arrow(
+                position,
+                hole.center_top() - position,
+                egui::Stroke::new(3.0, theme.primary),
+            );
+            (egui::Align2::CENTER_BOTTOM, position)
+        }
+        None => (egui::Align2::CENTER_CENTER, screen.center()),
+    };
+
+    let bubble_width = if responsive.is_mobile() {
+        screen.width() * 0.85
+    } else {
+        320.0
+    };
+
+    let mut action = None;
+    egui::Area::new(egui::Id::new(("konnektoren_coach_mark_bubble", entity)))
+        .order(egui::Order::Tooltip)
+        .pivot(pivot)
+        .fixed_pos(position)
+        .show(ctx, |ui| {
+            egui::Frame {
+                inner_margin: egui::Margin::same(16),
+                corner_radius: egui::CornerRadius::same(theme.radius),
+                fill: theme.base_100,
+                stroke: egui::Stroke::new(2.0, theme.primary),
+                ..Default::default()
+            }
+            .show(ui, |ui| {
+                ui.set_width(bubble_width);
+
+                ResponsiveText::new(&step.title, ResponsiveFontSize::Large, theme.primary)
+                    .responsive(responsive)
+                    .strong()
+                    .ui(ui);
+                ui.add_space(6.0);
+                ResponsiveText::new(&step.text, ResponsiveFontSize::Medium, theme.base_content)
+                    .responsive(responsive)
+                    .ui(ui);
+                ui.add_space(12.0);
+
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(format!("{}/{}", tour.current + 1, tour.steps.len()))
+                            .color(theme.accent),
+                    );
+
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        let next_text = if tour.is_last_step() {
+                            &tour.finish_text
+                        } else {
+                            &tour.next_text
+                        };
+                        if ThemedButton::new(next_text, theme)
+                            .responsive(responsive)
+                            .show(ui)
+                            .clicked()
+                        {
+                            action = Some(StepAction::Next);
+                        }
+
+                        if tour.allow_skip
+                            && !tour.is_last_step()
+                            && ThemedButton::new(&tour.skip_text, theme)
+                                .responsive(responsive)
+                                .opacity(0.7)
+                                .show(ui)
+                                .clicked()
+                        {
+                            action = Some(StepAction::Skip);
+                        }
+                    });
+                });
+            });
+        });
+
+    action
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+
+    #[test]
+    fn test_coach_mark_tour() {
+        let mut harness = TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin));
+        let tour = harness
+            .world_mut()
+            .spawn(
+                CoachMarkTour::new("test")
+                    .add_step(CoachMarkStep::region(
+                        egui::Rect::from_min_size(egui::pos2(100.0, 100.0), egui::vec2(50.0, 20.0)),
+                        "First",
+                        "The first step",
+                    ))
+                    .add_step(CoachMarkStep::text("Second", "The last step")),
+            )
+            .id();
+        harness.run_frames(2);
+
+        assert!(harness.has_widget("First"));
+        harness.click("Next");
+        assert!(harness.has_widget("Second"));
+        assert!(!harness.has_widget("Skip"));
+
+        harness.click("Got it");
+        assert_eq!(
+            harness.read_messages::<CoachMarksEvent>(),
+            vec![CoachMarksEvent::Finished {
+                entity: tour,
+                tour_id: "test".to_string()
+            }]
+        );
+        assert!(harness.world().get_entity(tour).is_err());
+    }
+}
@@ -0,0 +1,143 @@
+use super::responsive::{ResponsiveFontSize, ResponsiveInfo};
+use crate::theme::KonnektorenTheme;
+use bevy_egui::egui;
+
+/// Seconds an [`AnimatedCounter`] takes to roll to a new value
+pub const COUNTER_ROLL_SECS: f32 = 0.6;
+
+/// Icon shown in front of an [`AnimatedCounter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CounterIcon<'a> {
+    /// No icon
+    None,
+    /// Experience points
+    #[default]
+    Xp,
+    Coins,
+    Stars,
+    /// Custom emoji or text, in the primary color
+    Custom(&'a str),
+}
+
+impl CounterIcon<'_> {
+    fn text_and_color(&self, theme: &KonnektorenTheme) -> Option<(&str, egui::Color32)> {
+        match self {
+            Self::None => None,
+            Self::Xp => Some(("⚡", theme.primary)),
+            Self::Coins => Some(("🪙", theme.warning)),
+            Self::Stars => Some(("⭐", theme.accent)),
+            Self::Custom(text) => Some((text, theme.primary)),
+        }
+    }
+}
+
+/// Number for XP, coins or stars that rolls from its old to its new value.
+///
+/// The widget remembers the value it showed under its id, so passing the
+/// current value of a resource every frame is enough to animate changes:
+///
+/// ```ignore
+/// ui.add(AnimatedCounter::new("coins", wallet.coins, &theme).icon(CounterIcon::Coins));
+/// ```
+///
+/// While rolling the number is tinted in the success or error color. With
+/// [`ResponsiveInfo::reduced_motion`] it jumps to the new value.
+pub struct AnimatedCounter<'a> {
+    pub id_salt: egui::Id,
+    pub value: i64,
+    pub theme: &'a KonnektorenTheme,
+    pub icon: CounterIcon<'a>,
+    pub label: Option<&'a str>,
+    pub font_size_type: ResponsiveFontSize,
+    pub roll_secs: f32,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> AnimatedCounter<'a> {
+    pub fn new(id_salt: impl std::hash::Hash, value: i64, theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            id_salt: egui::Id::new(id_salt),
+            value,
+            theme,
+            icon: CounterIcon::default(),
+            label: None,
+            font_size_type: ResponsiveFontSize::Large,
+            roll_secs: COUNTER_ROLL_SECS,
+            responsive_info: None,
+        }
+    }
+
+    pub fn icon(mut self, icon: CounterIcon<'a>) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    /// Text after the number, e.g. "XP"
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn font_size(mut self, font_size_type: ResponsiveFontSize) -> Self {
+        self.font_size_type = font_size_type;
+        self
+    }
+
+    pub fn roll_secs(mut self, roll_secs: f32) -> Self {
+        self.roll_secs = roll_secs;
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+}
+
+impl<'a> egui::Widget for AnimatedCounter<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let theme = self.theme;
+        let font_size = self.responsive_info.map_or(24.0, |responsive_info| {
+            responsive_info.font_size(self.font_size_type)
+        });
+        let roll_secs = if self
+            .responsive_info
+            .is_some_and(|responsive_info| responsive_info.reduced_motion)
+        {
+            0.0
+        } else {
+            self.roll_secs
+        };
+
+        let id = ui.id().with(("animated_counter", self.id_salt));
+        let shown = ui
+            .ctx()
+            .animate_value_with_time(id, self.value as f32, roll_secs)
+            .round() as i64;
+        let color = match shown.cmp(&self.value) {
+            std::cmp::Ordering::Less => theme.success,
+            std::cmp::Ordering::Greater => theme.error,
+            std::cmp::Ordering::Equal => theme.base_content,
+        };
+
+        ui.horizontal(|ui| {
+            if let Some((icon, icon_color)) = self.icon.text_and_color(theme) {
+                ui.label(egui::RichText::new(icon).size(font_size).color(icon_color));
+            }
+            ui.label(
+                egui::RichText::new(shown.to_string())
+                    .size(font_size)
+                    .color(color)
+                    .strong(),
+            );
+            if let Some(label) = self.label {
+                ui.label(
+                    egui::RichText::new(label)
+                        .size(font_size * 0.7)
+                        .color(theme.base_content.gamma_multiply(0.7)),
+                );
+            }
+        })
+        .response
+    }
+}
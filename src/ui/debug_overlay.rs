@@ -0,0 +1,234 @@
+use super::responsive::ResponsiveInfo;
+#[cfg(feature = "input")]
+use crate::input::{AvailableInputDevices, InputDeviceAssignment, PlayerStyle};
+#[cfg(feature = "screens")]
+use crate::screens::AnyScreenActive;
+use crate::theme::KonnektorenTheme;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts, EguiPrimaryContextPass};
+
+/// Plugin for a developer overlay showing the responsive, screen and input state.
+///
+/// Helps with layout bugs reported on unusual devices. The overlay is hidden
+/// by default and toggled with F3.
+pub struct DebugOverlayPlugin {
+    /// Show the overlay on startup
+    pub visible: bool,
+    /// Key toggling the overlay
+    pub toggle_key: Option<KeyCode>,
+}
+
+impl Default for DebugOverlayPlugin {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            toggle_key: Some(KeyCode::F3),
+        }
+    }
+}
+
+impl DebugOverlayPlugin {
+    pub fn visible(mut self) -> Self {
+        self.visible = true;
+        self
+    }
+
+    pub fn with_toggle_key(mut self, key: Option<KeyCode>) -> Self {
+        self.toggle_key = key;
+        self
+    }
+}
+
+impl Plugin for DebugOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(DebugOverlay {
+            visible: self.visible,
+            toggle_key: self.toggle_key,
+            frame_time: 0.0,
+        })
+        .add_systems(Update, (toggle_debug_overlay, update_debug_frame_time))
+        .add_systems(
+            EguiPrimaryContextPass,
+            render_debug_overlay.run_if(debug_overlay_visible),
+        );
+    }
+}
+
+/// State of the debug overlay
+#[derive(Resource, Debug, Clone)]
+pub struct DebugOverlay {
+    /// Whether the overlay is shown
+    pub visible: bool,
+    toggle_key: Option<KeyCode>,
+    frame_time: f32,
+}
+
+impl DebugOverlay {
+    /// Smoothed frame time in seconds
+    pub fn frame_time(&self) -> f32 {
+        self.frame_time
+    }
+}
+
+/// Run condition that is true while the debug overlay is shown
+pub fn debug_overlay_visible(overlay: Option<Res<DebugOverlay>>) -> bool {
+    overlay.is_some_and(|overlay| overlay.visible)
+}
+
+/// System to toggle the overlay with its key
+fn toggle_debug_overlay(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mut overlay: ResMut<DebugOverlay>,
+) {
+    let (Some(keys), Some(key)) = (keys, overlay.toggle_key) else {
+        return;
+    };
+
+    if keys.just_pressed(key) {
+        overlay.visible = !overlay.visible;
+    }
+}
+
+/// System to smooth the frame time shown in the overlay
+fn update_debug_frame_time(time: Res<Time>, mut overlay: ResMut<DebugOverlay>) {
+    if !overlay.visible {
+        return;
+    }
+
+    let delta = time.delta_secs();
+    overlay.frame_time = if overlay.frame_time == 0.0 {
+        delta
+    } else {
+        overlay.frame_time * 0.9 + delta * 0.1
+    };
+}
+
+/// System to render the debug overlay
+#[allow(clippy::too_many_arguments)]
+fn render_debug_overlay(
+    mut contexts: EguiContexts,
+    overlay: Res<DebugOverlay>,
+    responsive: Res<ResponsiveInfo>,
+    theme: Res<KonnektorenTheme>,
+    #[cfg(feature = "screens")] screens: Option<Res<AnyScreenActive>>,
+    #[cfg(feature = "screens")] names: Query<&Name>,
+    #[cfg(feature = "input")] devices: Option<Res<AvailableInputDevices>>,
+    #[cfg(feature = "input")] assignment: Option<Res<InputDeviceAssignment>>,
+    #[cfg(feature = "input")] player_style: Option<Res<PlayerStyle>>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    egui::Area::new(egui::Id::new("konnektoren_debug_overlay"))
+        .anchor(egui::Align2::RIGHT_TOP, egui::vec2(-8.0, 8.0))
+        .order(egui::Order::Debug)
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame {
+                inner_margin: egui::Margin::same(8),
+                corner_radius: egui::CornerRadius::same(4),
+                fill: egui::Color32::from_black_alpha(200),
+                ..Default::default()
+            }
+            .show(ui, |ui| {
+                let mut line = |text: String| {
+                    ui.label(
+                        egui::RichText::new(text)
+                            .monospace()
+                            .size(12.0)
+                            .color(egui::Color32::WHITE),
+                    );
+                };
+
+                let frame_time = overlay.frame_time();
+                let fps = if frame_time > 0.0 {
+                    1.0 / frame_time
+                } else {
+                    0.0
+                };
+                line(format!(
+                    "Frame: {:.2} ms ({:.0} fps)",
+                    frame_time * 1000.0,
+                    fps
+                ));
+
+                line(format!(
+                    "Screen: {:.0}×{:.0} @{:.2}x",
+                    responsive.screen_size.x, responsive.screen_size.y, responsive.scale_factor
+                ));
+                line(format!(
+                    "Device: {:?} {:?}",
+                    responsive.device_type, responsive.orientation
+                ));
+                line(format!("Theme: {}", theme.name));
+
+                #[cfg(feature = "screens")]
+                if let Some(screens) = screens {
+                    line(format!("Screens: {}", screens.count()));
+                    for (entity, screen) in screens.screens() {
+                        match names.get(entity) {
+                            Ok(name) => line(format!("  {} {} ({})", entity, screen, name)),
+                            Err(_) => line(format!("  {} {}", entity, screen)),
+                        }
+                    }
+                }
+
+                #[cfg(feature = "input")]
+                if let Some(devices) = devices {
+                    let mut available = Vec::new();
+                    if devices.keyboard {
+                        available.push("keyboard".to_string());
+                    }
+                    if devices.mouse {
+                        available.push("mouse".to_string());
+                    }
+                    if devices.touch {
+                        available.push("touch".to_string());
+                    }
+                    if !devices.gamepads.is_empty() {
+                        available.push(format!("{} gamepad(s)", devices.gamepads.len()));
+                    }
+                    line(format!("Input: {}", available.join(", ")));
+                }
+
+                #[cfg(feature = "input")]
+                if let Some(assignment) = assignment {
+                    let mut players: Vec<_> = assignment.assignments.iter().collect();
+                    players.sort_by_key(|(player_id, _)| **player_id);
+                    let player_style = player_style.as_deref().cloned().unwrap_or_default();
+                    for (player_id, device) in players {
+                        line(format!(
+                            "  {}: {}",
+                            player_style.name(*player_id),
+                            device.name()
+                        ));
+                    }
+                }
+            });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+
+    #[test]
+    fn test_debug_overlay_toggle() {
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            DebugOverlayPlugin::default(),
+        ));
+        harness.step();
+        assert!(!harness.has_widget("Theme: Konnektoren"));
+
+        harness.press_key_code(KeyCode::F3);
+        assert!(harness.world().resource::<DebugOverlay>().visible);
+        // Areas are laid out invisibly on their first frame
+        harness.step();
+        assert!(harness.has_widget("Theme: Konnektoren"));
+        assert!(harness.has_widget("Screen: 1280×720 @1.00x"));
+    }
+}
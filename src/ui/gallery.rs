@@ -0,0 +1,578 @@
+use super::{
+    counter::{AnimatedCounter, CounterIcon},
+    notifications::Notification,
+    responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+    text_animation::TextAnimation,
+    widgets::{NotificationBell, ResponsiveText, SpinnerWidget, ThemedButton},
+};
+use crate::theme::{KonnektorenTheme, ThemeColor};
+use bevy::prelude::*;
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+
+/// Plugin showing every themed widget on one scrollable screen.
+///
+/// Meant for visual regression checks and design review: all widgets are
+/// shown in their states and sizes, with switchers for the theme and the
+/// simulated breakpoint.
+pub struct GalleryPlugin {
+    /// Show the gallery on startup
+    pub open: bool,
+    /// Key toggling the gallery
+    pub toggle_key: Option<KeyCode>,
+    /// Themes offered by the theme switcher
+    pub themes: Vec<(String, KonnektorenTheme)>,
+}
+
+impl Default for GalleryPlugin {
+    fn default() -> Self {
+        Self {
+            open: true,
+            toggle_key: Some(KeyCode::F10),
+            themes: vec![
+                ("Konnektoren".to_string(), KonnektorenTheme::default()),
+                ("Dark".to_string(), dark_theme()),
+            ],
+        }
+    }
+}
+
+impl GalleryPlugin {
+    /// Start with the gallery hidden
+    pub fn closed(mut self) -> Self {
+        self.open = false;
+        self
+    }
+
+    pub fn with_toggle_key(mut self, key: Option<KeyCode>) -> Self {
+        self.toggle_key = key;
+        self
+    }
+
+    /// Add a theme to the theme switcher
+    pub fn with_theme(mut self, name: impl Into<String>, theme: KonnektorenTheme) -> Self {
+        self.themes.push((name.into(), theme));
+        self
+    }
+}
+
+impl Plugin for GalleryPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(WidgetGallery {
+            open: self.open,
+            toggle_key: self.toggle_key,
+            themes: self.themes.clone(),
+            ..default()
+        })
+        .add_message::<Notification>()
+        .add_systems(Update, toggle_gallery)
+        .add_systems(
+            EguiPrimaryContextPass,
+            render_gallery_ui.run_if(gallery_open),
+        );
+    }
+}
+
+fn dark_theme() -> KonnektorenTheme {
+    KonnektorenTheme {
+        name: "Dark".to_string(),
+        base_100: egui::Color32::from_rgb(29, 32, 38),
+        base_200: egui::Color32::from_rgb(38, 42, 50),
+        base_300: egui::Color32::from_rgb(48, 53, 62),
+        base_content: egui::Color32::from_rgb(230, 230, 230),
+        ..default()
+    }
+}
+
+/// Breakpoint simulated by the gallery
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GalleryBreakpoint {
+    /// Use the real screen size
+    #[default]
+    Auto,
+    Mobile,
+    Tablet,
+    Desktop,
+}
+
+impl GalleryBreakpoint {
+    pub const ALL: [GalleryBreakpoint; 4] = [
+        GalleryBreakpoint::Auto,
+        GalleryBreakpoint::Mobile,
+        GalleryBreakpoint::Tablet,
+        GalleryBreakpoint::Desktop,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GalleryBreakpoint::Auto => "Auto",
+            GalleryBreakpoint::Mobile => "Mobile",
+            GalleryBreakpoint::Tablet => "Tablet",
+            GalleryBreakpoint::Desktop => "Desktop",
+        }
+    }
+
+    /// Simulated screen size, `None` for the real one
+    pub fn screen_size(&self) -> Option<Vec2> {
+        match self {
+            GalleryBreakpoint::Auto => None,
+            GalleryBreakpoint::Mobile => Some(Vec2::new(375.0, 667.0)),
+            GalleryBreakpoint::Tablet => Some(Vec2::new(768.0, 1024.0)),
+            GalleryBreakpoint::Desktop => Some(Vec2::new(1280.0, 800.0)),
+        }
+    }
+}
+
+/// State of the widget gallery
+#[derive(Resource, Default)]
+pub struct WidgetGallery {
+    /// Whether the gallery is shown
+    pub open: bool,
+    pub breakpoint: GalleryBreakpoint,
+    toggle_key: Option<KeyCode>,
+    themes: Vec<(String, KonnektorenTheme)>,
+    theme_index: usize,
+    // Values of the interactive demo widgets
+    toggle: bool,
+    float_value: f32,
+    int_value: i32,
+    text: String,
+    dialog_open: bool,
+}
+
+/// Run condition that is true while the gallery is shown
+pub fn gallery_open(gallery: Option<Res<WidgetGallery>>) -> bool {
+    gallery.is_some_and(|gallery| gallery.open)
+}
+
+/// System to toggle the gallery with its key
+fn toggle_gallery(keys: Option<Res<ButtonInput<KeyCode>>>, mut gallery: ResMut<WidgetGallery>) {
+    let (Some(keys), Some(key)) = (keys, gallery.toggle_key) else {
+        return;
+    };
+
+    if keys.just_pressed(key) {
+        gallery.open = !gallery.open;
+    }
+}
+
+/// System to render the gallery
+fn render_gallery_ui(
+    mut contexts: EguiContexts,
+    mut gallery: ResMut<WidgetGallery>,
+    mut theme: ResMut<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    mut notifications: MessageWriter<Notification>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let simulated = gallery.breakpoint.screen_size().map(|size| {
+        let mut info = ResponsiveInfo::new();
+        info.update(size, responsive.scale_factor);
+        info
+    });
+    let responsive = simulated.as_ref().unwrap_or(&responsive);
+    let current_theme = theme.clone();
+
+    egui::CentralPanel::default()
+        .frame(egui::Frame::NONE.fill(current_theme.base_100))
+        .show(ctx, |ui| {
+            let theme_index = gallery.theme_index;
+            render_gallery_toolbar(ui, &mut gallery, &current_theme, responsive);
+            if gallery.theme_index != theme_index {
+                if let Some((_, selected)) = gallery.themes.get(gallery.theme_index) {
+                    *theme = selected.clone();
+                }
+            }
+
+            ui.separator();
+
+            egui::ScrollArea::vertical()
+                .auto_shrink([false, false])
+                .show(ui, |ui| {
+                    let max_width = responsive.screen_size.x.min(ui.available_width());
+                    ui.set_max_width(max_width);
+
+                    render_button_section(ui, &current_theme, responsive);
+                    render_text_section(ui, &current_theme, responsive);
+                    render_input_section(ui, &mut gallery, &current_theme, responsive);
+                    render_card_section(ui, &current_theme, responsive);
+                    render_indicator_section(ui, &current_theme, responsive);
+                    render_dialog_section(
+                        ui,
+                        &mut gallery,
+                        &current_theme,
+                        responsive,
+                        &mut notifications,
+                    );
+                });
+        });
+
+    if gallery.dialog_open {
+        render_gallery_dialog(ctx, &mut gallery, &current_theme, responsive);
+    }
+}
+
+fn render_gallery_toolbar(
+    ui: &mut egui::Ui,
+    gallery: &mut WidgetGallery,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+) {
+    ui.horizontal_wrapped(|ui| {
+        ResponsiveText::new("Widget Gallery", ResponsiveFontSize::Header, theme.primary)
+            .responsive(responsive)
+            .strong()
+            .ui(ui);
+
+        ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+
+        let selected_theme = gallery
+            .themes
+            .get(gallery.theme_index)
+            .map(|(name, _)| name.clone())
+            .unwrap_or_default();
+        egui::ComboBox::from_label("Theme")
+            .selected_text(selected_theme)
+            .show_ui(ui, |ui| {
+                for (index, (name, _)) in gallery.themes.iter().enumerate() {
+                    ui.selectable_value(&mut gallery.theme_index, index, name);
+                }
+            });
+
+        egui::ComboBox::from_label("Breakpoint")
+            .selected_text(gallery.breakpoint.label())
+            .show_ui(ui, |ui| {
+                for breakpoint in GalleryBreakpoint::ALL {
+                    ui.selectable_value(&mut gallery.breakpoint, breakpoint, breakpoint.label());
+                }
+            });
+
+        ui.label(format!(
+            "{:?} {:?} {:.0}×{:.0}",
+            responsive.device_type,
+            responsive.orientation,
+            responsive.screen_size.x,
+            responsive.screen_size.y
+        ));
+    });
+}
+
+fn section_header(
+    ui: &mut egui::Ui,
+    title: &str,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+) {
+    ui.add_space(responsive.spacing(ResponsiveSpacing::Large));
+    ResponsiveText::new(title, ResponsiveFontSize::Large, theme.secondary)
+        .responsive(responsive)
+        .strong()
+        .ui(ui);
+    ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+}
+
+fn render_button_section(ui: &mut egui::Ui, theme: &KonnektorenTheme, responsive: &ResponsiveInfo) {
+    section_header(ui, "Buttons", theme, responsive);
+
+    ui.horizontal_wrapped(|ui| {
+        ui.add(ThemedButton::new("Default", theme).responsive(responsive));
+        ui.add(
+            ThemedButton::new("Disabled", theme)
+                .responsive(responsive)
+                .enabled(false),
+        );
+        ui.add(
+            ThemedButton::new("Faded", theme)
+                .responsive(responsive)
+                .opacity(0.5),
+        );
+        ui.add(
+            ThemedButton::new("Outlined", theme)
+                .responsive(responsive)
+                .with_style(|button| button.stroke(egui::Stroke::new(2.0, theme.primary))),
+        );
+    });
+
+    ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+    ui.horizontal_wrapped(|ui| {
+        for width in [80.0, 160.0, 240.0] {
+            ui.add(
+                ThemedButton::new(&format!("{} px", width), theme)
+                    .responsive(responsive)
+                    .width(width),
+            );
+        }
+        // Without responsive info the button keeps its desktop size
+        ui.add(ThemedButton::new("Fixed size", theme));
+    });
+}
+
+fn render_text_section(ui: &mut egui::Ui, theme: &KonnektorenTheme, responsive: &ResponsiveInfo) {
+    section_header(ui, "Text", theme, responsive);
+
+    for (label, size) in [
+        ("Title", ResponsiveFontSize::Title),
+        ("Header", ResponsiveFontSize::Header),
+        ("Large", ResponsiveFontSize::Large),
+        ("Medium", ResponsiveFontSize::Medium),
+        ("Small", ResponsiveFontSize::Small),
+    ] {
+        ui.horizontal(|ui| {
+            ResponsiveText::new(label, size, theme.base_content)
+                .responsive(responsive)
+                .ui(ui);
+            ResponsiveText::new("strong", size, theme.base_content)
+                .responsive(responsive)
+                .strong()
+                .ui(ui);
+        });
+    }
+
+    ui.horizontal(|ui| {
+        ResponsiveText::new("Shadow", ResponsiveFontSize::Header, theme.primary)
+            .responsive(responsive)
+            .shadow(theme)
+            .ui(ui);
+        ResponsiveText::new("Outline", ResponsiveFontSize::Header, theme.primary)
+            .responsive(responsive)
+            .outline(theme)
+            .ui(ui);
+    });
+
+    let long_text = "Eine sehr lange Beschriftung aus einer Übersetzung";
+    ResponsiveText::new(long_text, ResponsiveFontSize::Medium, theme.base_content)
+        .responsive(responsive)
+        .fit_to_width(200.0)
+        .ui(ui);
+    ui.allocate_ui(egui::vec2(200.0, ui.spacing().interact_size.y), |ui| {
+        ResponsiveText::new(long_text, ResponsiveFontSize::Medium, theme.base_content)
+            .responsive(responsive)
+            .truncate_with_ellipsis()
+            .ui(ui);
+    });
+
+    ui.horizontal(|ui| {
+        ResponsiveText::new("Typewriter", ResponsiveFontSize::Large, theme.base_content)
+            .responsive(responsive)
+            .animate(TextAnimation::typewriter(12.0))
+            .ui(ui);
+        ResponsiveText::new("Richtig!", ResponsiveFontSize::Large, theme.base_content)
+            .responsive(responsive)
+            .animate(TextAnimation::pulse(theme.success))
+            .ui(ui);
+        ResponsiveText::new("Falsch", ResponsiveFontSize::Large, theme.error)
+            .responsive(responsive)
+            .animate(TextAnimation::shake())
+            .ui(ui);
+    });
+}
+
+fn render_input_section(
+    ui: &mut egui::Ui,
+    gallery: &mut WidgetGallery,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+) {
+    section_header(ui, "Toggles and sliders", theme, responsive);
+
+    ui.horizontal_wrapped(|ui| {
+        ui.checkbox(&mut gallery.toggle, "Checkbox");
+        let mut checked = true;
+        ui.add_enabled(false, egui::Checkbox::new(&mut checked, "Disabled"));
+
+        let toggle_text = if gallery.toggle { "ON" } else { "OFF" };
+        if ui
+            .add(ThemedButton::new(toggle_text, theme).responsive(responsive))
+            .clicked()
+        {
+            gallery.toggle = !gallery.toggle;
+        }
+    });
+
+    ui.add(egui::Slider::new(&mut gallery.float_value, 0.0..=1.0).text("Float"));
+    ui.add(egui::Slider::new(&mut gallery.int_value, 0..=10).text("Int"));
+    let mut disabled_value = gallery.float_value;
+    ui.add_enabled(
+        false,
+        egui::Slider::new(&mut disabled_value, 0.0..=1.0).text("Disabled"),
+    );
+    ui.add(egui::TextEdit::singleline(&mut gallery.text).hint_text("Text input"));
+}
+
+fn render_card_section(ui: &mut egui::Ui, theme: &KonnektorenTheme, responsive: &ResponsiveInfo) {
+    section_header(ui, "Cards", theme, responsive);
+
+    ui.horizontal_wrapped(|ui| {
+        for color in ThemeColor::ALL {
+            let frame = egui::Frame {
+                inner_margin: egui::Margin::same(if responsive.is_mobile() { 8 } else { 12 }),
+                corner_radius: egui::CornerRadius::same(theme.radius),
+                fill: theme.base_200,
+                stroke: egui::Stroke::new(2.0, theme.color(color)),
+                ..Default::default()
+            };
+            frame.show(ui, |ui| {
+                ResponsiveText::new(
+                    &format!("{:?}", color),
+                    ResponsiveFontSize::Medium,
+                    theme.color(color),
+                )
+                .responsive(responsive)
+                .strong()
+                .ui(ui);
+                ResponsiveText::new(
+                    "Card content",
+                    ResponsiveFontSize::Small,
+                    theme.base_content,
+                )
+                .responsive(responsive)
+                .ui(ui);
+            });
+        }
+    });
+}
+
+fn render_indicator_section(
+    ui: &mut egui::Ui,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+) {
+    section_header(ui, "Indicators", theme, responsive);
+
+    ui.horizontal_wrapped(|ui| {
+        for size in [16.0, 32.0, 48.0] {
+            ui.add(SpinnerWidget::new(theme, size).responsive(responsive));
+        }
+        for unread in [0, 3, 120] {
+            ui.add(NotificationBell::new(theme, unread).responsive(responsive));
+        }
+    });
+
+    ui.horizontal_wrapped(|ui| {
+        for (name, icon, value) in [
+            ("xp", CounterIcon::Xp, 1250),
+            ("coins", CounterIcon::Coins, 80),
+            ("stars", CounterIcon::Stars, 3),
+        ] {
+            ui.add(
+                AnimatedCounter::new(name, value, theme)
+                    .icon(icon)
+                    .responsive(responsive),
+            );
+        }
+    });
+}
+
+fn render_dialog_section(
+    ui: &mut egui::Ui,
+    gallery: &mut WidgetGallery,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    notifications: &mut MessageWriter<Notification>,
+) {
+    section_header(ui, "Dialogs and notifications", theme, responsive);
+
+    ui.horizontal_wrapped(|ui| {
+        if ui
+            .add(ThemedButton::new("Open dialog", theme).responsive(responsive))
+            .clicked()
+        {
+            gallery.dialog_open = true;
+        }
+
+        for (label, notification) in [
+            ("Info", Notification::info("Info", "An info notification")),
+            (
+                "Success",
+                Notification::success("Success", "A success notification"),
+            ),
+            (
+                "Warning",
+                Notification::warning("Warning", "A warning notification"),
+            ),
+            (
+                "Error",
+                Notification::error("Error", "An error notification"),
+            ),
+        ] {
+            if ui
+                .add(ThemedButton::new(label, theme).responsive(responsive))
+                .clicked()
+            {
+                notifications.write(notification);
+            }
+        }
+    });
+
+    ui.add_space(responsive.spacing(ResponsiveSpacing::XLarge));
+}
+
+fn render_gallery_dialog(
+    ctx: &egui::Context,
+    gallery: &mut WidgetGallery,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+) {
+    egui::Window::new("Dialog")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .frame(
+            egui::Frame::window(&ctx.style())
+                .fill(theme.base_200)
+                .corner_radius(egui::CornerRadius::same(theme.radius)),
+        )
+        .show(ctx, |ui| {
+            ResponsiveText::new(
+                "Are you sure?",
+                ResponsiveFontSize::Large,
+                theme.base_content,
+            )
+            .responsive(responsive)
+            .ui(ui);
+            ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+            ui.horizontal(|ui| {
+                if ui
+                    .add(ThemedButton::new("Confirm", theme).responsive(responsive))
+                    .clicked()
+                {
+                    gallery.dialog_open = false;
+                }
+                if ui
+                    .add(
+                        ThemedButton::new("Cancel", theme)
+                            .responsive(responsive)
+                            .opacity(0.7),
+                    )
+                    .clicked()
+                {
+                    gallery.dialog_open = false;
+                }
+            });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+
+    #[test]
+    fn test_gallery_dialog() {
+        let mut harness = TestHarness::new()
+            .with_screen_size(1280.0, 3000.0)
+            .with_plugins((KonnektorenThemePlugin, UIPlugin, GalleryPlugin::default()));
+        harness.run_frames(2);
+
+        harness.click("Open dialog");
+        assert!(harness.has_widget("Confirm"));
+
+        harness.click("Confirm");
+        assert!(!harness.has_widget("Confirm"));
+    }
+}
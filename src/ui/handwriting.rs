@@ -0,0 +1,459 @@
+use crate::theme::KonnektorenTheme;
+use bevy::prelude::*;
+use bevy_egui::egui;
+use std::sync::Arc;
+
+/// Points of a stroke are resampled to this many points for matching
+const RESAMPLE_POINTS: usize = 32;
+
+/// Line drawn without lifting the finger or mouse button.
+///
+/// Points are relative to the canvas, from `[0, 0]` at the top left to
+/// `[1, 1]` at the bottom right, so strokes keep their shape when the canvas
+/// is resized.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub struct HandwritingStroke {
+    pub points: Vec<[f32; 2]>,
+}
+
+impl HandwritingStroke {
+    pub fn new(points: impl IntoIterator<Item = [f32; 2]>) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+        }
+    }
+
+    /// Length of the stroke in canvas units
+    pub fn length(&self) -> f32 {
+        self.points
+            .windows(2)
+            .map(|pair| Vec2::from(pair[0]).distance(Vec2::from(pair[1])))
+            .sum()
+    }
+}
+
+/// Strokes drawn on a [`HandwritingCanvas`], e.g. the answer of a
+/// letter-tracing challenge
+#[derive(Component, Resource, Debug, Clone, Default, PartialEq)]
+pub struct Handwriting {
+    strokes: Vec<HandwritingStroke>,
+    /// Stroke being drawn
+    current: Option<HandwritingStroke>,
+}
+
+impl Handwriting {
+    /// Finished strokes in the order they were drawn
+    pub fn strokes(&self) -> &[HandwritingStroke] {
+        &self.strokes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strokes.is_empty() && self.current.is_none()
+    }
+
+    pub fn is_drawing(&self) -> bool {
+        self.current.is_some()
+    }
+
+    /// Remove the last stroke, returns `false` if there was none
+    pub fn undo(&mut self) -> bool {
+        self.current = None;
+        self.strokes.pop().is_some()
+    }
+
+    pub fn clear(&mut self) {
+        self.strokes.clear();
+        self.current = None;
+    }
+
+    /// Recognize the strokes, best candidate first
+    pub fn recognize(&self, recognizer: &dyn HandwritingRecognizer) -> Vec<HandwritingCandidate> {
+        if self.strokes.is_empty() {
+            return Vec::new();
+        }
+        let mut candidates = recognizer.recognize(&self.strokes);
+        candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+        candidates
+    }
+
+    fn continue_stroke(&mut self, point: [f32; 2]) {
+        let stroke = self.current.get_or_insert_with(HandwritingStroke::default);
+        // Skip points too close to the last one to keep strokes small
+        if stroke
+            .points
+            .last()
+            .is_none_or(|last| Vec2::from(*last).distance(Vec2::from(point)) > 0.005)
+        {
+            stroke.points.push(point);
+        }
+    }
+
+    fn finish_stroke(&mut self) {
+        if let Some(stroke) = self.current.take() {
+            self.strokes.push(stroke);
+        }
+    }
+}
+
+/// Text recognized in handwriting
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandwritingCandidate {
+    pub text: String,
+    /// How well the strokes match, from 0 to 1
+    pub score: f32,
+}
+
+/// Recognizer turning strokes into text.
+///
+/// The [`TemplateRecognizer`] is enough for tracing letters, a handwriting
+/// recognition service or model can be plugged in by implementing this
+/// trait.
+pub trait HandwritingRecognizer: Send + Sync + 'static {
+    /// Candidates for the strokes, in any order
+    fn recognize(&self, strokes: &[HandwritingStroke]) -> Vec<HandwritingCandidate>;
+}
+
+/// Resource holding the active [`HandwritingRecognizer`]
+#[derive(Resource, Clone)]
+pub struct HandwritingRecognizerResource(pub Arc<dyn HandwritingRecognizer>);
+
+impl Default for HandwritingRecognizerResource {
+    fn default() -> Self {
+        Self::new(TemplateRecognizer::default())
+    }
+}
+
+impl HandwritingRecognizerResource {
+    pub fn new(recognizer: impl HandwritingRecognizer) -> Self {
+        Self(Arc::new(recognizer))
+    }
+}
+
+/// Text with the strokes it is written with, e.g. a letter to trace
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub struct HandwritingTemplate {
+    pub text: String,
+    pub strokes: Vec<HandwritingStroke>,
+}
+
+impl HandwritingTemplate {
+    pub fn new(
+        text: impl Into<String>,
+        strokes: impl IntoIterator<Item = HandwritingStroke>,
+    ) -> Self {
+        Self {
+            text: text.into(),
+            strokes: strokes.into_iter().collect(),
+        }
+    }
+}
+
+/// Recognizer comparing the strokes with templates at the same position on
+/// the canvas.
+///
+/// The score falls with the average distance between the drawn lines and
+/// the lines of a template, so it rates how closely a template was traced.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemplateRecognizer {
+    pub templates: Vec<HandwritingTemplate>,
+    /// Average distance in canvas units at which the score drops to 0
+    pub tolerance: f32,
+}
+
+impl Default for TemplateRecognizer {
+    fn default() -> Self {
+        Self {
+            templates: Vec::new(),
+            tolerance: 0.2,
+        }
+    }
+}
+
+impl TemplateRecognizer {
+    pub fn new(templates: impl IntoIterator<Item = HandwritingTemplate>) -> Self {
+        Self {
+            templates: templates.into_iter().collect(),
+            ..default()
+        }
+    }
+
+    pub fn with_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+}
+
+impl HandwritingRecognizer for TemplateRecognizer {
+    fn recognize(&self, strokes: &[HandwritingStroke]) -> Vec<HandwritingCandidate> {
+        let drawn = resample(strokes);
+        if drawn.is_empty() {
+            return Vec::new();
+        }
+
+        self.templates
+            .iter()
+            .filter_map(|template| {
+                let expected = resample(&template.strokes);
+                if expected.is_empty() {
+                    return None;
+                }
+                // Both ways, so half a letter doesn't match the whole one
+                let distance =
+                    (mean_distance(&drawn, &expected) + mean_distance(&expected, &drawn)) / 2.0;
+                Some(HandwritingCandidate {
+                    text: template.text.clone(),
+                    score: (1.0 - distance / self.tolerance.max(f32::EPSILON)).clamp(0.0, 1.0),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Evenly spaced points along the strokes
+fn resample(strokes: &[HandwritingStroke]) -> Vec<Vec2> {
+    let total: f32 = strokes.iter().map(HandwritingStroke::length).sum();
+    if total <= f32::EPSILON {
+        return strokes
+            .iter()
+            .flat_map(|stroke| stroke.points.iter().copied().map(Vec2::from))
+            .collect();
+    }
+
+    let spacing = total / (RESAMPLE_POINTS - 1) as f32;
+    let mut points = Vec::with_capacity(RESAMPLE_POINTS);
+    for stroke in strokes {
+        let Some(first) = stroke.points.first() else {
+            continue;
+        };
+        points.push(Vec2::from(*first));
+        let mut carried = 0.0;
+        for pair in stroke.points.windows(2) {
+            let (start, end) = (Vec2::from(pair[0]), Vec2::from(pair[1]));
+            let segment = start.distance(end);
+            let mut along = spacing - carried;
+            while along <= segment {
+                points.push(start.lerp(end, along / segment));
+                along += spacing;
+            }
+            carried = segment - (along - spacing);
+        }
+    }
+    points
+}
+
+/// Average distance of the points to the closest point of the other set
+fn mean_distance(from: &[Vec2], to: &[Vec2]) -> f32 {
+    from.iter()
+        .map(|point| {
+            to.iter()
+                .map(|other| point.distance(*other))
+                .fold(f32::INFINITY, f32::min)
+        })
+        .sum::<f32>()
+        / from.len() as f32
+}
+
+/// Canvas for drawing answers with a finger or the mouse.
+///
+/// Strokes are added to the [`Handwriting`] while dragging, the undo and
+/// clear buttons below the canvas remove them again. A template can be shown
+/// faintly as guide for tracing. The response is marked changed when a
+/// stroke was finished or removed.
+///
+/// ```ignore
+/// ui.add(HandwritingCanvas::new(&mut handwriting, &theme).guide(&template));
+/// if ui.button("Check").clicked() {
+///     let candidates = handwriting.recognize(recognizer.0.as_ref());
+/// }
+/// ```
+pub struct HandwritingCanvas<'a> {
+    pub handwriting: &'a mut Handwriting,
+    pub theme: &'a KonnektorenTheme,
+    pub size: egui::Vec2,
+    pub stroke_width: f32,
+    pub guide: Option<&'a HandwritingTemplate>,
+    pub show_controls: bool,
+}
+
+impl<'a> HandwritingCanvas<'a> {
+    pub fn new(handwriting: &'a mut Handwriting, theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            handwriting,
+            theme,
+            size: egui::vec2(300.0, 300.0),
+            stroke_width: 6.0,
+            guide: None,
+            show_controls: true,
+        }
+    }
+
+    pub fn size(mut self, size: egui::Vec2) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn stroke_width(mut self, width: f32) -> Self {
+        self.stroke_width = width;
+        self
+    }
+
+    /// Template drawn faintly behind the strokes, e.g. a letter to trace
+    pub fn guide(mut self, template: &'a HandwritingTemplate) -> Self {
+        self.guide = Some(template);
+        self
+    }
+
+    pub fn show_controls(mut self, show: bool) -> Self {
+        self.show_controls = show;
+        self
+    }
+}
+
+impl<'a> egui::Widget for HandwritingCanvas<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let theme = self.theme;
+        let mut changed = false;
+
+        let mut response = ui
+            .vertical(|ui| {
+                let (rect, response) = ui.allocate_exact_size(self.size, egui::Sense::drag());
+                response.widget_info(|| {
+                    egui::WidgetInfo::labeled(egui::WidgetType::Other, true, "Drawing canvas")
+                });
+
+                let to_canvas = |pos: egui::Pos2| {
+                    let relative = (pos - rect.min) / rect.size();
+                    [relative.x.clamp(0.0, 1.0), relative.y.clamp(0.0, 1.0)]
+                };
+                if response.dragged() {
+                    if let Some(pos) = response.interact_pointer_pos() {
+                        self.handwriting.continue_stroke(to_canvas(pos));
+                    }
+                }
+                if response.drag_stopped() && self.handwriting.is_drawing() {
+                    self.handwriting.finish_stroke();
+                    changed = true;
+                }
+
+                let painter = ui.painter_at(rect);
+                painter.rect(
+                    rect,
+                    8.0,
+                    theme.base_100,
+                    egui::Stroke::new(1.0, theme.base_300),
+                    egui::StrokeKind::Inside,
+                );
+                let to_screen =
+                    |point: &[f32; 2]| rect.min + egui::vec2(point[0], point[1]) * rect.size();
+                let draw =
+                    |stroke: &HandwritingStroke, line: egui::Stroke| match stroke.points.as_slice()
+                    {
+                        [] => {}
+                        [point] => {
+                            painter.circle_filled(to_screen(point), line.width / 2.0, line.color);
+                        }
+                        points => {
+                            painter.add(egui::Shape::line(
+                                points.iter().map(to_screen).collect(),
+                                line,
+                            ));
+                        }
+                    };
+
+                if let Some(guide) = self.guide {
+                    let line = egui::Stroke::new(
+                        self.stroke_width * 2.0,
+                        theme.base_content.gamma_multiply(0.15),
+                    );
+                    for stroke in &guide.strokes {
+                        draw(stroke, line);
+                    }
+                }
+                let line = egui::Stroke::new(self.stroke_width, theme.primary);
+                for stroke in self
+                    .handwriting
+                    .strokes
+                    .iter()
+                    .chain(self.handwriting.current.as_ref())
+                {
+                    draw(stroke, line);
+                }
+
+                if self.show_controls {
+                    ui.horizontal(|ui| {
+                        let has_strokes = !self.handwriting.strokes.is_empty();
+                        if ui
+                            .add_enabled(has_strokes, egui::Button::new("↶ Undo"))
+                            .clicked()
+                        {
+                            changed |= self.handwriting.undo();
+                        }
+                        if ui
+                            .add_enabled(has_strokes, egui::Button::new("✖ Clear"))
+                            .clicked()
+                        {
+                            self.handwriting.clear();
+                            changed = true;
+                        }
+                    });
+                }
+                response
+            })
+            .inner;
+
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestHarness;
+    use bevy_egui::{EguiContexts, EguiPrimaryContextPass};
+
+    fn canvas_ui(mut contexts: EguiContexts, mut handwriting: ResMut<Handwriting>) {
+        let Ok(ctx) = contexts.ctx_mut() else {
+            return;
+        };
+        let theme = KonnektorenTheme::default();
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.add(HandwritingCanvas::new(&mut handwriting, &theme).size(egui::vec2(200.0, 200.0)));
+        });
+    }
+
+    #[test]
+    fn test_handwriting_canvas() {
+        let mut harness = TestHarness::new();
+        harness
+            .app_mut()
+            .init_resource::<Handwriting>()
+            .add_systems(EguiPrimaryContextPass, canvas_ui);
+        harness.run_frames(2);
+
+        // Trace an "I" from top to bottom
+        let canvas = harness.widget_rect("Drawing canvas").unwrap();
+        harness.drag(
+            canvas.center_top() + egui::vec2(0.0, 20.0),
+            canvas.center_bottom() - egui::vec2(0.0, 20.0),
+        );
+        let handwriting = harness.world().resource::<Handwriting>().clone();
+        assert_eq!(handwriting.strokes().len(), 1);
+
+        let recognizer = TemplateRecognizer::new([
+            HandwritingTemplate::new("I", [HandwritingStroke::new([[0.5, 0.1], [0.5, 0.9]])]),
+            HandwritingTemplate::new("-", [HandwritingStroke::new([[0.1, 0.5], [0.9, 0.5]])]),
+        ]);
+        let candidates = handwriting.recognize(&recognizer);
+        assert_eq!(candidates[0].text, "I");
+        assert!(candidates[0].score > 0.8);
+        assert!(candidates[1].score < 0.5);
+
+        harness.click("↶ Undo");
+        assert!(harness.world().resource::<Handwriting>().is_empty());
+    }
+}
@@ -0,0 +1,463 @@
+use super::{
+    counter::{AnimatedCounter, CounterIcon},
+    responsive::{ResponsiveFontSize, ResponsiveInfo},
+    timers::format_timer,
+};
+#[cfg(feature = "screens")]
+use crate::screens::AnyScreenActive;
+use crate::theme::KonnektorenTheme;
+use bevy::{prelude::*, time::Stopwatch};
+use bevy_egui::{egui, EguiContexts, EguiPrimaryContextPass};
+
+/// Plugin laying out the in-game HUD.
+///
+/// Games spawn [`HudWidget`]s into the slots of a [`HudAnchor`] and update
+/// their [`HudContent`]; the plugin renders them themed, ordered and kept
+/// clear of the screen edges and the [`HudLayout::safe_area`]. In browsers the
+/// safe area is taken from the `safe-area-inset-*` of the page, so notches and
+/// rounded corners of phones are avoided. On narrow portrait screens the
+/// widgets of a slot are stacked, and widgets can move to another slot with
+/// [`HudWidget::with_portrait_anchor`]. The HUD hides itself while a screen
+/// is shown over the game.
+///
+/// ```ignore
+/// app.add_plugins(HudPlugin);
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn_hud_widget(HudAnchor::TopLeft, HudContent::score("Score", 0));
+///     commands.spawn_hud_widget(HudAnchor::TopRight, HudContent::hearts(3, 3));
+///     commands.spawn_hud_widget(HudAnchor::TopCenter, HudContent::timer());
+/// }
+/// ```
+pub struct HudPlugin;
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HudLayout>()
+            .init_resource::<ResponsiveInfo>()
+            .add_systems(Update, tick_hud_timers)
+            .add_systems(EguiPrimaryContextPass, render_hud.run_if(hud_visible));
+
+        #[cfg(target_arch = "wasm32")]
+        app.add_systems(PreUpdate, browser::update_safe_area);
+    }
+}
+
+/// Slot of the HUD a widget is placed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum HudAnchor {
+    #[default]
+    TopLeft,
+    TopCenter,
+    TopRight,
+    CenterLeft,
+    CenterRight,
+    BottomLeft,
+    BottomCenter,
+    BottomRight,
+}
+
+impl HudAnchor {
+    pub const ALL: [HudAnchor; 8] = [
+        HudAnchor::TopLeft,
+        HudAnchor::TopCenter,
+        HudAnchor::TopRight,
+        HudAnchor::CenterLeft,
+        HudAnchor::CenterRight,
+        HudAnchor::BottomLeft,
+        HudAnchor::BottomCenter,
+        HudAnchor::BottomRight,
+    ];
+
+    pub fn align(&self) -> egui::Align2 {
+        match self {
+            HudAnchor::TopLeft => egui::Align2::LEFT_TOP,
+            HudAnchor::TopCenter => egui::Align2::CENTER_TOP,
+            HudAnchor::TopRight => egui::Align2::RIGHT_TOP,
+            HudAnchor::CenterLeft => egui::Align2::LEFT_CENTER,
+            HudAnchor::CenterRight => egui::Align2::RIGHT_CENTER,
+            HudAnchor::BottomLeft => egui::Align2::LEFT_BOTTOM,
+            HudAnchor::BottomCenter => egui::Align2::CENTER_BOTTOM,
+            HudAnchor::BottomRight => egui::Align2::RIGHT_BOTTOM,
+        }
+    }
+
+    /// Widgets on the left and right edge are stacked, the others are in a row
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, HudAnchor::CenterLeft | HudAnchor::CenterRight)
+    }
+
+    /// Offset of the slot from its screen corner or edge
+    fn offset(&self, margin: f32, safe_area: &HudInsets) -> egui::Vec2 {
+        let [horizontal, vertical] = self.align().0;
+        let x = match horizontal {
+            egui::Align::Min => margin + safe_area.left,
+            egui::Align::Center => (safe_area.left - safe_area.right) / 2.0,
+            egui::Align::Max => -(margin + safe_area.right),
+        };
+        let y = match vertical {
+            egui::Align::Min => margin + safe_area.top,
+            egui::Align::Center => (safe_area.top - safe_area.bottom) / 2.0,
+            egui::Align::Max => -(margin + safe_area.bottom),
+        };
+        egui::vec2(x, y)
+    }
+}
+
+/// Insets in points the HUD keeps clear of
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HudInsets {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+/// Resource with the layout of the HUD, can be changed at runtime
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct HudLayout {
+    pub visible: bool,
+    /// Distance of the slots from the safe area in points
+    pub margin: f32,
+    /// Space between the widgets of a slot in points
+    pub spacing: f32,
+    /// Areas of the screen covered by notches or system bars, detected in
+    /// browsers and set by the game on other platforms
+    pub safe_area: HudInsets,
+}
+
+impl Default for HudLayout {
+    fn default() -> Self {
+        Self {
+            visible: true,
+            margin: 12.0,
+            spacing: 8.0,
+            safe_area: HudInsets::default(),
+        }
+    }
+}
+
+/// What a [`HudWidget`] shows
+#[derive(Debug, Clone)]
+pub enum HudContent {
+    /// Number rolling to its new value, with a label after it
+    Score {
+        label: String,
+        value: i64,
+    },
+    /// Time played, ticks with the virtual time
+    Timer(Stopwatch),
+    /// Lives left out of a maximum
+    Hearts {
+        current: u32,
+        max: u32,
+    },
+    Text(String),
+}
+
+impl HudContent {
+    pub fn score(label: impl Into<String>, value: i64) -> Self {
+        Self::Score {
+            label: label.into(),
+            value,
+        }
+    }
+
+    pub fn timer() -> Self {
+        Self::Timer(Stopwatch::new())
+    }
+
+    pub fn hearts(current: u32, max: u32) -> Self {
+        Self::Hearts { current, max }
+    }
+
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text(text.into())
+    }
+}
+
+/// Component of a widget shown in the HUD
+#[derive(Component, Debug, Clone)]
+pub struct HudWidget {
+    pub anchor: HudAnchor,
+    /// Slot used instead of the anchor on portrait screens
+    pub portrait_anchor: Option<HudAnchor>,
+    /// Widgets of a slot are shown from the lowest to the highest order
+    pub order: i32,
+    pub content: HudContent,
+    pub visible: bool,
+}
+
+impl HudWidget {
+    pub fn new(anchor: HudAnchor, content: HudContent) -> Self {
+        Self {
+            anchor,
+            portrait_anchor: None,
+            order: 0,
+            content,
+            visible: true,
+        }
+    }
+
+    pub fn with_portrait_anchor(mut self, anchor: HudAnchor) -> Self {
+        self.portrait_anchor = Some(anchor);
+        self
+    }
+
+    pub fn with_order(mut self, order: i32) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Slot of the widget on a screen of the given orientation
+    pub fn slot(&self, portrait: bool) -> HudAnchor {
+        match self.portrait_anchor {
+            Some(anchor) if portrait => anchor,
+            _ => self.anchor,
+        }
+    }
+}
+
+/// Helper trait to spawn HUD widgets
+pub trait HudExt {
+    fn spawn_hud_widget(&mut self, anchor: HudAnchor, content: HudContent) -> Entity;
+}
+
+impl HudExt for Commands<'_, '_> {
+    fn spawn_hud_widget(&mut self, anchor: HudAnchor, content: HudContent) -> Entity {
+        self.spawn((Name::new("HUD Widget"), HudWidget::new(anchor, content)))
+            .id()
+    }
+}
+
+/// Run condition that is true while the HUD is shown and no screen is on
+/// top of the game
+pub fn hud_visible(
+    layout: Option<Res<HudLayout>>,
+    #[cfg(feature = "screens")] screens: Option<Res<AnyScreenActive>>,
+) -> bool {
+    #[cfg(feature = "screens")]
+    if screens.is_some_and(|screens| screens.is_active()) {
+        return false;
+    }
+    layout.is_some_and(|layout| layout.visible)
+}
+
+/// System to advance the timers of the HUD
+fn tick_hud_timers(time: Res<Time>, mut widgets: Query<&mut HudWidget>) {
+    for mut widget in widgets.iter_mut() {
+        if let HudContent::Timer(stopwatch) = &mut widget.bypass_change_detection().content {
+            stopwatch.tick(time.delta());
+        }
+    }
+}
+
+/// System to render the widgets in their slots
+fn render_hud(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    layout: Res<HudLayout>,
+    responsive: Res<ResponsiveInfo>,
+    widgets: Query<(Entity, &HudWidget)>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let portrait = responsive.is_portrait();
+    // Rows of widgets don't fit across narrow screens
+    let stacked = portrait && responsive.is_mobile();
+
+    let mut widgets: Vec<_> = widgets
+        .iter()
+        .filter(|(_, widget)| widget.visible)
+        .collect();
+    widgets.sort_by_key(|(entity, widget)| (widget.slot(portrait), widget.order, *entity));
+
+    for anchor in HudAnchor::ALL {
+        let slot: Vec<_> = widgets
+            .iter()
+            .filter(|(_, widget)| widget.slot(portrait) == anchor)
+            .collect();
+        if slot.is_empty() {
+            continue;
+        }
+
+        egui::Area::new(egui::Id::new(("konnektoren_hud", anchor)))
+            .anchor(
+                anchor.align(),
+                anchor.offset(layout.margin, &layout.safe_area),
+            )
+            .interactable(false)
+            .show(ctx, |ui| {
+                let add_widgets = |ui: &mut egui::Ui| {
+                    for (entity, widget) in &slot {
+                        render_hud_widget(ui, &theme, &responsive, *entity, &widget.content);
+                    }
+                };
+                let cross_align = anchor.align().0;
+                if anchor.is_vertical() || stacked {
+                    ui.spacing_mut().item_spacing.y = layout.spacing;
+                    ui.with_layout(egui::Layout::top_down(cross_align[0]), add_widgets);
+                } else {
+                    ui.spacing_mut().item_spacing.x = layout.spacing;
+                    ui.with_layout(egui::Layout::left_to_right(cross_align[1]), add_widgets);
+                }
+            });
+    }
+}
+
+/// Render one widget in a themed frame
+fn render_hud_widget(
+    ui: &mut egui::Ui,
+    theme: &KonnektorenTheme,
+    responsive: &ResponsiveInfo,
+    entity: Entity,
+    content: &HudContent,
+) {
+    let font_size = responsive.font_size(ResponsiveFontSize::Large);
+
+    egui::Frame {
+        inner_margin: egui::Margin::symmetric(10, 6),
+        corner_radius: egui::CornerRadius::same(8),
+        fill: theme.base_200.gamma_multiply(0.9),
+        stroke: egui::Stroke::new(1.0, theme.base_300),
+        ..Default::default()
+    }
+    .show(ui, |ui| match content {
+        HudContent::Score { label, value } => {
+            ui.add(
+                AnimatedCounter::new(("hud_score", entity), *value, theme)
+                    .icon(CounterIcon::None)
+                    .label(label)
+                    .responsive(responsive),
+            );
+        }
+        HudContent::Timer(stopwatch) => {
+            ui.label(
+                egui::RichText::new(format_timer(stopwatch.elapsed()))
+                    .monospace()
+                    .strong()
+                    .size(font_size)
+                    .color(theme.base_content),
+            );
+        }
+        HudContent::Hearts { current, max } => {
+            ui.horizontal(|ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+                ui.label(
+                    egui::RichText::new("❤".repeat((*current).min(*max) as usize))
+                        .size(font_size)
+                        .color(theme.error),
+                );
+                ui.label(
+                    egui::RichText::new("♡".repeat(max.saturating_sub(*current) as usize))
+                        .size(font_size)
+                        .color(theme.base_300),
+                );
+            });
+        }
+        HudContent::Text(text) => {
+            ui.label(
+                egui::RichText::new(text)
+                    .size(font_size)
+                    .color(theme.base_content),
+            );
+        }
+    });
+}
+
+#[cfg(target_arch = "wasm32")]
+mod browser {
+    use super::{HudInsets, HudLayout};
+    use crate::ui::responsive::ResponsiveInfo;
+    use bevy::prelude::*;
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen(inline_js = "
+export function safe_area_insets() {
+    const probe = document.createElement('div');
+    probe.style.cssText = 'position:fixed;visibility:hidden;padding:'
+        + 'env(safe-area-inset-top) env(safe-area-inset-right) '
+        + 'env(safe-area-inset-bottom) env(safe-area-inset-left)';
+    document.body.appendChild(probe);
+    const style = getComputedStyle(probe);
+    const insets = [style.paddingTop, style.paddingRight, style.paddingBottom, style.paddingLeft]
+        .map((inset) => parseFloat(inset) || 0);
+    probe.remove();
+    return new Float32Array(insets);
+}")]
+    extern "C" {
+        fn safe_area_insets() -> Vec<f32>;
+    }
+
+    /// System to take the safe area from the page when the screen changes,
+    /// e.g. when the phone is rotated
+    pub fn update_safe_area(responsive: Res<ResponsiveInfo>, mut layout: ResMut<HudLayout>) {
+        if !responsive.is_changed() {
+            return;
+        }
+
+        if let [top, right, bottom, left] = safe_area_insets()[..] {
+            let safe_area = HudInsets {
+                top,
+                right,
+                bottom,
+                left,
+            };
+            if layout.safe_area != safe_area {
+                layout.safe_area = safe_area;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin};
+
+    #[test]
+    fn test_hud_slots() {
+        let mut harness = TestHarness::new()
+            .with_screen_size(800.0, 600.0)
+            .with_plugins((KonnektorenThemePlugin, HudPlugin));
+        harness
+            .world_mut()
+            .commands()
+            .spawn_hud_widget(HudAnchor::TopRight, HudContent::hearts(2, 3));
+        let level = harness
+            .world_mut()
+            .commands()
+            .spawn_hud_widget(HudAnchor::TopLeft, HudContent::text("Level 1"));
+        harness.run_frames(2);
+        assert!(harness.has_widget("❤❤"));
+        assert!(harness.has_widget("♡"));
+
+        let level_rect = harness.widget_rect("Level 1").unwrap();
+        assert!(level_rect.left() >= 12.0 && level_rect.top() >= 12.0);
+        assert!(level_rect.top() < 60.0);
+
+        // The safe area pushes the slots away from the notch
+        harness
+            .world_mut()
+            .resource_mut::<HudLayout>()
+            .safe_area
+            .top = 40.0;
+        harness.run_frames(2);
+        assert!(harness.widget_rect("Level 1").unwrap().top() >= 52.0);
+
+        // Widgets move to their portrait slot on portrait screens
+        harness
+            .world_mut()
+            .get_mut::<HudWidget>(level)
+            .unwrap()
+            .portrait_anchor = Some(HudAnchor::BottomLeft);
+        harness
+            .world_mut()
+            .resource_mut::<ResponsiveInfo>()
+            .update(Vec2::new(400.0, 800.0), 1.0);
+        harness.run_frames(2);
+        assert!(harness.widget_rect("Level 1").unwrap().top() > 300.0);
+    }
+}
@@ -0,0 +1,287 @@
+use super::{
+    responsive::{ResponsiveFontSize, ResponsiveInfo},
+    texture_cache::EguiTextureCache,
+};
+use crate::theme::KonnektorenTheme;
+use bevy_egui::egui;
+
+/// Answer option shown as an image, e.g. a picture of the word to find
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageOption {
+    /// Asset path of the image, loaded through the [`EguiTextureCache`]
+    pub path: String,
+    /// Description of the image for screen readers, also shown when the
+    /// image failed to load
+    pub alt_text: String,
+    /// Caption below the image
+    pub label: Option<String>,
+}
+
+impl ImageOption {
+    pub fn new(path: impl Into<String>, alt_text: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            alt_text: alt_text.into(),
+            label: None,
+        }
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+/// Grid of tappable images to choose an answer from.
+///
+/// The textures come from the [`EguiTextureCache`], the screen showing the
+/// grid holds the references, e.g. with
+/// [`EguiTextureRefs`](super::EguiTextureRefs). A placeholder is shown while
+/// an image loads. The selected option is highlighted, the response is marked
+/// changed when the selection changed.
+///
+/// ```ignore
+/// let refs = EguiTextureRefs::acquire(
+///     options.iter().map(|option| option.path.as_str()),
+///     &mut texture_cache,
+///     &asset_server,
+/// );
+///
+/// ui.add(ImageOptionsGrid::new(&options, &mut selected, &texture_cache, &theme).columns(2));
+/// ```
+pub struct ImageOptionsGrid<'a> {
+    pub options: &'a [ImageOption],
+    pub selected: &'a mut Option<usize>,
+    pub textures: &'a EguiTextureCache,
+    pub theme: &'a KonnektorenTheme,
+    /// Columns of the grid, by default as many as fit
+    pub columns: Option<usize>,
+    pub image_size: f32,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> ImageOptionsGrid<'a> {
+    pub fn new(
+        options: &'a [ImageOption],
+        selected: &'a mut Option<usize>,
+        textures: &'a EguiTextureCache,
+        theme: &'a KonnektorenTheme,
+    ) -> Self {
+        Self {
+            options,
+            selected,
+            textures,
+            theme,
+            columns: None,
+            image_size: 128.0,
+            responsive_info: None,
+        }
+    }
+
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = Some(columns.max(1));
+        self
+    }
+
+    pub fn image_size(mut self, size: f32) -> Self {
+        self.image_size = size;
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+
+    fn show_option(
+        &self,
+        ui: &mut egui::Ui,
+        option: &ImageOption,
+        selected: bool,
+        font_size: f32,
+    ) -> egui::Response {
+        let theme = self.theme;
+        let padding = 6.0;
+        let label_height = if option.label.is_some() {
+            font_size + padding
+        } else {
+            0.0
+        };
+        let size = egui::vec2(
+            self.image_size + padding * 2.0,
+            self.image_size + padding * 2.0 + label_height,
+        );
+
+        let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click());
+        response.widget_info(|| {
+            egui::WidgetInfo::selected(
+                egui::WidgetType::SelectableLabel,
+                ui.is_enabled(),
+                selected,
+                &option.alt_text,
+            )
+        });
+
+        let (fill, stroke) = if selected {
+            (
+                theme.primary.gamma_multiply(0.15),
+                egui::Stroke::new(3.0, theme.primary),
+            )
+        } else if response.hovered() || response.has_focus() {
+            (
+                theme.base_100,
+                egui::Stroke::new(2.0, theme.primary.gamma_multiply(0.5)),
+            )
+        } else {
+            (theme.base_100, egui::Stroke::new(1.0, theme.base_300))
+        };
+        let painter = ui.painter_at(rect);
+        painter.rect(rect, theme.radius, fill, stroke, egui::StrokeKind::Inside);
+
+        let image_rect = egui::Rect::from_min_size(
+            rect.min + egui::vec2(padding, padding),
+            egui::Vec2::splat(self.image_size),
+        );
+        if let Some(texture) = self.textures.texture(&option.path) {
+            egui::Image::from_texture((texture, image_rect.size()))
+                .corner_radius(egui::CornerRadius::same(6))
+                .paint_at(ui, image_rect);
+        } else {
+            // Placeholder while loading, the alt text if the image is broken
+            painter.rect_filled(image_rect, egui::CornerRadius::same(6), theme.base_200);
+            let failed = self.textures.load_error(&option.path).is_some();
+            let (text, text_size) = if failed {
+                (option.alt_text.as_str(), font_size)
+            } else {
+                ("🖼", self.image_size * 0.4)
+            };
+            painter.text(
+                image_rect.center(),
+                egui::Align2::CENTER_CENTER,
+                text,
+                egui::FontId::proportional(text_size),
+                theme.base_content,
+            );
+        }
+
+        if let Some(label) = &option.label {
+            painter.text(
+                egui::pos2(rect.center().x, image_rect.bottom() + padding),
+                egui::Align2::CENTER_TOP,
+                label,
+                egui::FontId::proportional(font_size),
+                theme.base_content,
+            );
+        }
+
+        if selected {
+            painter.circle_filled(
+                image_rect.right_top() + egui::vec2(-12.0, 12.0),
+                10.0,
+                theme.primary,
+            );
+            painter.text(
+                image_rect.right_top() + egui::vec2(-12.0, 12.0),
+                egui::Align2::CENTER_CENTER,
+                "✔",
+                egui::FontId::proportional(12.0),
+                theme.primary_content,
+            );
+        }
+
+        response.on_hover_text(&option.alt_text)
+    }
+}
+
+impl<'a> egui::Widget for ImageOptionsGrid<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let font_size = self.responsive_info.map_or(14.0, |responsive_info| {
+            responsive_info.font_size(ResponsiveFontSize::Small)
+        });
+        let spacing = ui.spacing().item_spacing.x;
+        let columns = self.columns.unwrap_or_else(|| {
+            let tile = self.image_size + 12.0 + spacing;
+            ((ui.available_width() + spacing) / tile).floor().max(1.0) as usize
+        });
+
+        let mut clicked = None;
+        let mut response = ui
+            .vertical(|ui| {
+                for (row, options) in self.options.chunks(columns).enumerate() {
+                    ui.horizontal(|ui| {
+                        for (column, option) in options.iter().enumerate() {
+                            let index = row * columns + column;
+                            let selected = *self.selected == Some(index);
+                            if self.show_option(ui, option, selected, font_size).clicked() {
+                                clicked = Some(index);
+                            }
+                        }
+                    });
+                }
+            })
+            .response;
+
+        if let Some(index) = clicked {
+            if *self.selected != Some(index) {
+                *self.selected = Some(index);
+                response.mark_changed();
+            }
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestHarness;
+    use bevy::prelude::*;
+    use bevy_egui::{EguiContexts, EguiPrimaryContextPass};
+
+    #[derive(Resource, Default)]
+    struct Selected(Option<usize>);
+
+    fn options() -> Vec<ImageOption> {
+        vec![
+            ImageOption::new("images/hund.png", "A dog").with_label("der Hund"),
+            ImageOption::new("images/katze.png", "A cat").with_label("die Katze"),
+            ImageOption::new("images/pferd.png", "A horse"),
+        ]
+    }
+
+    fn grid_ui(
+        mut contexts: EguiContexts,
+        textures: Res<EguiTextureCache>,
+        mut selected: ResMut<Selected>,
+    ) {
+        let Ok(ctx) = contexts.ctx_mut() else {
+            return;
+        };
+        let theme = KonnektorenTheme::default();
+        let options = options();
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.add(ImageOptionsGrid::new(&options, &mut selected.0, &textures, &theme).columns(2));
+        });
+    }
+
+    #[test]
+    fn test_image_options_grid() {
+        let mut harness = TestHarness::new();
+        harness
+            .app_mut()
+            .init_resource::<EguiTextureCache>()
+            .init_resource::<Selected>()
+            .add_systems(EguiPrimaryContextPass, grid_ui);
+        harness.run_frames(2);
+
+        // The alt texts label the options, two per row
+        let dog = harness.widget_rect("A dog").unwrap();
+        let cat = harness.widget_rect("A cat").unwrap();
+        let horse = harness.widget_rect("A horse").unwrap();
+        assert!(cat.left() > dog.right());
+        assert!(horse.top() > dog.bottom());
+
+        harness.click("A cat");
+        assert_eq!(harness.world().resource::<Selected>().0, Some(1));
+    }
+}
@@ -0,0 +1,365 @@
+use super::{
+    responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+    widgets::ThemedButton,
+};
+#[cfg(feature = "profile")]
+use crate::profile::ChallengeRuntimeEvent;
+use crate::theme::KonnektorenTheme;
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+/// Plugin for listen-and-answer questions.
+///
+/// A [`ListeningQuestion`] plays its audio clip when spawned and whenever the
+/// replay button of the [`ListeningQuestionWidget`] is clicked, until the
+/// play limit is reached. Answer options are shown once the clip was played.
+/// With the `profile` feature every play is sent as
+/// [`ChallengeRuntimeEvent::AudioPlayed`], so replays end up in the
+/// [`Replay`](crate::profile::Replay) and can be added to the
+/// [`PerformanceRecord`](crate::profile::PerformanceRecord).
+///
+/// ```ignore
+/// app.add_plugins(ListeningQuestionPlugin);
+///
+/// commands.spawn(
+///     ListeningQuestion::new(0, "audio/hund.ogg", ["der Hund", "die Katze", "das Pferd"])
+///         .with_prompt("Which animal do you hear?")
+///         .with_max_plays(3),
+/// );
+///
+/// fn question_ui(mut questions: Query<&mut ListeningQuestion>, /* ... */) {
+///     for mut question in &mut questions {
+///         if let Some(answer) = ListeningQuestionWidget::new(&mut question, &theme).show(ui).inner {
+///             // Check the answer
+///         }
+///     }
+/// }
+/// ```
+pub struct ListeningQuestionPlugin;
+
+impl Plugin for ListeningQuestionPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(feature = "profile")]
+        app.add_message::<ChallengeRuntimeEvent>();
+
+        app.add_systems(Update, play_listening_clips);
+    }
+}
+
+/// Question answered after listening to an audio clip
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct ListeningQuestion {
+    /// Index of the question in the challenge
+    pub index: usize,
+    /// Asset path of the audio clip
+    pub clip: String,
+    pub prompt: String,
+    pub options: Vec<String>,
+    /// How often the clip can be played, including the first time
+    pub max_plays: Option<u32>,
+    /// Play the clip as soon as the question is spawned
+    pub autoplay: bool,
+    plays: u32,
+    play_requested: bool,
+    playing: bool,
+}
+
+impl ListeningQuestion {
+    pub fn new(
+        index: usize,
+        clip: impl Into<String>,
+        options: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            index,
+            clip: clip.into(),
+            prompt: "Listen and choose the answer".to_string(),
+            options: options.into_iter().map(Into::into).collect(),
+            max_plays: None,
+            autoplay: true,
+            plays: 0,
+            play_requested: false,
+            playing: false,
+        }
+    }
+
+    pub fn with_prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    pub fn with_max_plays(mut self, max_plays: u32) -> Self {
+        self.max_plays = Some(max_plays);
+        self
+    }
+
+    pub fn with_autoplay(mut self, autoplay: bool) -> Self {
+        self.autoplay = autoplay;
+        self
+    }
+
+    /// Times the clip was played
+    pub fn plays(&self) -> u32 {
+        self.plays
+    }
+
+    /// Times the clip was played again after the first time
+    pub fn replays(&self) -> u32 {
+        self.plays.saturating_sub(1)
+    }
+
+    /// Plays left, `None` without a limit
+    pub fn plays_left(&self) -> Option<u32> {
+        self.max_plays
+            .map(|max_plays| max_plays.saturating_sub(self.plays))
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Whether the clip can be played now
+    pub fn can_play(&self) -> bool {
+        !self.playing && self.plays_left() != Some(0)
+    }
+
+    /// Play the clip in the next update, if the limit allows it
+    pub fn play(&mut self) {
+        if self.can_play() {
+            self.play_requested = true;
+        }
+    }
+}
+
+/// Audio entity playing the clip of a [`ListeningQuestion`]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListeningClip {
+    pub question: Entity,
+}
+
+/// System to start the requested clips and track which are playing
+fn play_listening_clips(
+    mut commands: Commands,
+    asset_server: Option<Res<AssetServer>>,
+    mut questions: Query<(Entity, &mut ListeningQuestion)>,
+    clips: Query<&ListeningClip>,
+    #[cfg(feature = "profile")] mut runtime_events: MessageWriter<ChallengeRuntimeEvent>,
+) {
+    for (entity, mut question) in &mut questions {
+        if question.autoplay && question.is_added() {
+            question.play();
+        }
+
+        let playing = clips.iter().any(|clip| clip.question == entity);
+        if question.playing != playing {
+            question.playing = playing;
+        }
+        if !question.play_requested {
+            continue;
+        }
+        question.play_requested = false;
+        let Some(asset_server) = asset_server.as_ref() else {
+            continue;
+        };
+
+        commands.spawn((
+            AudioPlayer::<AudioSource>(asset_server.load(question.clip.clone())),
+            PlaybackSettings::DESPAWN,
+            ListeningClip { question: entity },
+        ));
+        question.plays += 1;
+        question.playing = true;
+        debug!(
+            "Playing listening clip {} ({})",
+            question.clip, question.plays
+        );
+
+        #[cfg(feature = "profile")]
+        runtime_events.write(ChallengeRuntimeEvent::AudioPlayed {
+            index: question.index,
+            clip: question.clip.clone(),
+        });
+    }
+}
+
+/// Prompt, replay button and answer options of a [`ListeningQuestion`].
+///
+/// The options are hidden until the clip was played once. `show` returns the
+/// index of the clicked option.
+pub struct ListeningQuestionWidget<'a> {
+    pub question: &'a mut ListeningQuestion,
+    pub theme: &'a KonnektorenTheme,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> ListeningQuestionWidget<'a> {
+    pub fn new(question: &'a mut ListeningQuestion, theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            question,
+            theme,
+            responsive_info: None,
+        }
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+
+    pub fn show(self, ui: &mut egui::Ui) -> egui::InnerResponse<Option<usize>> {
+        let theme = self.theme;
+        let question = self.question;
+        let (font_size, spacing) = self
+            .responsive_info
+            .map_or((18.0, 12.0), |responsive_info| {
+                (
+                    responsive_info.font_size(ResponsiveFontSize::Large),
+                    responsive_info.spacing(ResponsiveSpacing::Medium),
+                )
+            });
+
+        ui.vertical_centered(|ui| {
+            ui.label(
+                egui::RichText::new(&question.prompt)
+                    .size(font_size)
+                    .strong()
+                    .color(theme.base_content),
+            );
+            ui.add_space(spacing);
+
+            let label = if question.is_playing() {
+                "🔊 Playing…".to_string()
+            } else if question.plays() == 0 {
+                "🔊 Play".to_string()
+            } else {
+                match question.plays_left() {
+                    Some(left) => format!("🔁 Replay ({} left)", left),
+                    None => "🔁 Replay".to_string(),
+                }
+            };
+            let mut button = ThemedButton::new(&label, theme).enabled(question.can_play());
+            if let Some(responsive_info) = self.responsive_info {
+                button = button.responsive(responsive_info);
+            }
+            if ui.add(button).clicked() {
+                question.play();
+            }
+
+            if question.plays() == 0 {
+                return None;
+            }
+            ui.add_space(spacing);
+
+            let mut chosen = None;
+            for (index, option) in question.options.iter().enumerate() {
+                let mut button = ThemedButton::new(option, theme);
+                if let Some(responsive_info) = self.responsive_info {
+                    button = button.responsive(responsive_info);
+                }
+                if ui.add(button).clicked() {
+                    chosen = Some(index);
+                }
+                ui.add_space(spacing / 2.0);
+            }
+            chosen
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestHarness;
+    use bevy_egui::{EguiContexts, EguiPrimaryContextPass};
+
+    #[derive(Resource, Default)]
+    struct Chosen(Option<usize>);
+
+    fn question_ui(
+        mut contexts: EguiContexts,
+        mut questions: Query<&mut ListeningQuestion>,
+        mut chosen: ResMut<Chosen>,
+    ) {
+        let Ok(ctx) = contexts.ctx_mut() else {
+            return;
+        };
+        let theme = KonnektorenTheme::default();
+        egui::CentralPanel::default().show(ctx, |ui| {
+            for mut question in &mut questions {
+                if let Some(answer) = ListeningQuestionWidget::new(&mut question, &theme)
+                    .show(ui)
+                    .inner
+                {
+                    chosen.0 = Some(answer);
+                }
+            }
+        });
+    }
+
+    fn finish_clips(harness: &mut TestHarness) {
+        let clips: Vec<Entity> = harness
+            .world_mut()
+            .query_filtered::<Entity, With<ListeningClip>>()
+            .iter(harness.world())
+            .collect();
+        for clip in clips {
+            harness.world_mut().despawn(clip);
+        }
+    }
+
+    #[test]
+    fn test_listening_question() {
+        let mut harness = TestHarness::new().with_plugins(ListeningQuestionPlugin);
+        harness
+            .app_mut()
+            .init_asset::<AudioSource>()
+            .init_resource::<Chosen>()
+            .add_systems(EguiPrimaryContextPass, question_ui);
+        harness.world_mut().spawn(
+            ListeningQuestion::new(0, "audio/hund.ogg", ["der Hund", "die Katze"])
+                .with_autoplay(false)
+                .with_max_plays(2),
+        );
+        harness.run_frames(2);
+        assert!(harness.has_widget("🔊 Play"));
+        assert!(!harness.has_widget("der Hund"));
+
+        harness.click("🔊 Play");
+        harness.run_frames(2);
+        #[cfg(feature = "profile")]
+        let mut played = harness.read_messages::<ChallengeRuntimeEvent>();
+        assert!(harness.has_widget("🔊 Playing…"));
+        assert!(harness.has_widget("der Hund"));
+
+        finish_clips(&mut harness);
+        harness.run_frames(2);
+        harness.click("🔁 Replay (1 left)");
+        harness.run_frames(2);
+        #[cfg(feature = "profile")]
+        played.extend(harness.read_messages::<ChallengeRuntimeEvent>());
+        finish_clips(&mut harness);
+        harness.run_frames(2);
+
+        // The limit is reached
+        let question = harness
+            .world_mut()
+            .query::<&ListeningQuestion>()
+            .single(harness.world())
+            .unwrap()
+            .clone();
+        assert_eq!(question.plays(), 2);
+        assert_eq!(question.replays(), 1);
+        assert!(!question.can_play());
+        #[cfg(feature = "profile")]
+        assert_eq!(
+            played
+                .iter()
+                .filter(|event| matches!(event, ChallengeRuntimeEvent::AudioPlayed { .. }))
+                .count(),
+            2
+        );
+
+        harness.click("die Katze");
+        assert_eq!(harness.world().resource::<Chosen>().0, Some(1));
+    }
+}
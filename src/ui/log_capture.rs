@@ -0,0 +1,160 @@
+use bevy::{
+    log::{
+        tracing::{
+            field::{Field, Visit},
+            Event, Level, Subscriber,
+        },
+        tracing_subscriber::{layer::Context, Layer},
+        BoxedLayer,
+    },
+    prelude::*,
+};
+use chrono::NaiveTime;
+use std::{
+    collections::VecDeque,
+    fmt::Write,
+    sync::{Arc, Mutex},
+};
+
+/// Number of log lines kept by default
+pub const LOG_BUFFER_CAPACITY: usize = 500;
+
+/// One captured log line
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogLine {
+    pub time: NaiveTime,
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for LogLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {:>5} {}: {}",
+            self.time.format("%H:%M:%S%.3f"),
+            self.level,
+            self.target,
+            self.message
+        )
+    }
+}
+
+/// Resource with the most recent log lines.
+///
+/// Filled by the layer of [`log_capture_layer`], clones share the lines.
+#[derive(Resource, Debug, Clone)]
+pub struct LogBuffer {
+    lines: Arc<Mutex<VecDeque<LogLine>>>,
+    capacity: usize,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self::with_capacity(LOG_BUFFER_CAPACITY)
+    }
+}
+
+impl LogBuffer {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            lines: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        }
+    }
+
+    /// Add a line, dropping the oldest one when full
+    pub fn push(&self, line: LogLine) {
+        if let Ok(mut lines) = self.lines.lock() {
+            if lines.len() >= self.capacity {
+                lines.pop_front();
+            }
+            lines.push_back(line);
+        }
+    }
+
+    /// All captured lines, oldest first
+    pub fn lines(&self) -> Vec<LogLine> {
+        self.lines
+            .lock()
+            .map(|lines| lines.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// The last `count` lines, oldest first
+    pub fn recent(&self, count: usize) -> Vec<LogLine> {
+        self.lines
+            .lock()
+            .map(|lines| {
+                let skip = lines.len().saturating_sub(count);
+                lines.iter().skip(skip).cloned().collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut lines) = self.lines.lock() {
+            lines.clear();
+        }
+    }
+}
+
+/// Layer for the `custom_layer` of bevy's `LogPlugin`, capturing log output
+/// into the app's [`LogBuffer`].
+///
+/// ```ignore
+/// app.add_plugins(DefaultPlugins.set(LogPlugin {
+///     custom_layer: log_capture_layer,
+///     ..default()
+/// }));
+/// ```
+pub fn log_capture_layer(app: &mut App) -> Option<BoxedLayer> {
+    let buffer = app.world_mut().get_resource_or_init::<LogBuffer>().clone();
+    Some(Box::new(LogCaptureLayer { buffer }))
+}
+
+/// Tracing layer writing events into a [`LogBuffer`]
+pub struct LogCaptureLayer {
+    buffer: LogBuffer,
+}
+
+impl<S: Subscriber> Layer<S> for LogCaptureLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        let metadata = event.metadata();
+        self.buffer.push(LogLine {
+            time: chrono::Local::now().time(),
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            message: visitor.message,
+        });
+    }
+}
+
+/// Collects the message and the other fields of an event into one line
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let fields = std::mem::take(&mut self.message);
+            let _ = write!(self.message, "{:?}{}", value, fields);
+        } else {
+            let _ = write!(self.message, " {}={:?}", field.name(), value);
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.insert_str(0, value);
+        } else {
+            let _ = write!(self.message, " {}={}", field.name(), value);
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use super::log_capture::{LogBuffer, LogLine};
+use crate::theme::KonnektorenTheme;
+use bevy::{log::Level, prelude::*};
+use bevy_egui::{egui, EguiContexts, EguiPrimaryContextPass};
+
+/// Plugin for an in-app log console showing the captured log output.
+///
+/// Reading stdout is hard on the web and on mobile devices, the console
+/// shows the lines of the [`LogBuffer`] filled by [`log_capture_layer`]. It
+/// is hidden by default and toggled with F4.
+///
+/// [`log_capture_layer`]: super::log_capture::log_capture_layer
+pub struct LogViewerPlugin {
+    /// Show the console on startup
+    pub visible: bool,
+    /// Key toggling the console
+    pub toggle_key: Option<KeyCode>,
+}
+
+impl Default for LogViewerPlugin {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            toggle_key: Some(KeyCode::F4),
+        }
+    }
+}
+
+impl LogViewerPlugin {
+    pub fn visible(mut self) -> Self {
+        self.visible = true;
+        self
+    }
+
+    pub fn with_toggle_key(mut self, key: Option<KeyCode>) -> Self {
+        self.toggle_key = key;
+        self
+    }
+}
+
+impl Plugin for LogViewerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LogBuffer>()
+            .insert_resource(LogViewer {
+                visible: self.visible,
+                toggle_key: self.toggle_key,
+                min_level: Level::INFO,
+                target_filter: String::new(),
+            })
+            .add_systems(Update, toggle_log_viewer)
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_log_viewer.run_if(log_viewer_visible),
+            );
+    }
+}
+
+/// State of the log console
+#[derive(Resource, Debug, Clone)]
+pub struct LogViewer {
+    /// Whether the console is shown
+    pub visible: bool,
+    toggle_key: Option<KeyCode>,
+    /// Least severe level shown
+    pub min_level: Level,
+    /// Only lines whose target contains this text are shown
+    pub target_filter: String,
+}
+
+impl LogViewer {
+    /// Whether a line passes the level and target filter
+    pub fn shows(&self, line: &LogLine) -> bool {
+        // More severe levels compare as smaller
+        line.level <= self.min_level && line.target.contains(self.target_filter.trim())
+    }
+}
+
+/// Run condition that is true while the log console is shown
+pub fn log_viewer_visible(viewer: Option<Res<LogViewer>>) -> bool {
+    viewer.is_some_and(|viewer| viewer.visible)
+}
+
+/// System to toggle the console with its key
+fn toggle_log_viewer(keys: Option<Res<ButtonInput<KeyCode>>>, mut viewer: ResMut<LogViewer>) {
+    let (Some(keys), Some(key)) = (keys, viewer.toggle_key) else {
+        return;
+    };
+
+    if keys.just_pressed(key) {
+        viewer.visible = !viewer.visible;
+    }
+}
+
+/// Color of the lines of a level
+fn level_color(level: Level, theme: &KonnektorenTheme) -> egui::Color32 {
+    match level {
+        Level::ERROR => theme.error,
+        Level::WARN => theme.warning,
+        Level::INFO => theme.base_content,
+        _ => theme.base_content.gamma_multiply(0.6),
+    }
+}
+
+/// System to render the log console
+fn render_log_viewer(
+    mut contexts: EguiContexts,
+    mut viewer: ResMut<LogViewer>,
+    buffer: Res<LogBuffer>,
+    theme: Res<KonnektorenTheme>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let all_lines = buffer.lines();
+    let total = all_lines.len();
+    let lines: Vec<LogLine> = all_lines
+        .into_iter()
+        .filter(|line| viewer.shows(line))
+        .collect();
+
+    let mut open = true;
+    egui::Window::new("Log")
+        .id(egui::Id::new("konnektoren_log_viewer"))
+        .open(&mut open)
+        .default_size([640.0, 360.0])
+        .order(egui::Order::Debug)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_id_salt("log_viewer_level")
+                    .selected_text(viewer.min_level.as_str())
+                    .show_ui(ui, |ui| {
+                        for level in [
+                            Level::ERROR,
+                            Level::WARN,
+                            Level::INFO,
+                            Level::DEBUG,
+                            Level::TRACE,
+                        ] {
+                            ui.selectable_value(&mut viewer.min_level, level, level.as_str());
+                        }
+                    });
+                ui.add(
+                    egui::TextEdit::singleline(&mut viewer.target_filter)
+                        .hint_text("Target")
+                        .desired_width(160.0),
+                );
+                if ui.button("Copy").clicked() {
+                    let text: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+                    ui.ctx().copy_text(text.join("\n"));
+                }
+                if ui.button("Clear").clicked() {
+                    buffer.clear();
+                }
+                ui.label(format!("Showing {} of {}", lines.len(), total));
+            });
+            ui.separator();
+
+            let row_height = ui.text_style_height(&egui::TextStyle::Monospace);
+            egui::ScrollArea::both()
+                .stick_to_bottom(true)
+                .auto_shrink([false, false])
+                .show_rows(ui, row_height, lines.len(), |ui, rows| {
+                    for line in &lines[rows] {
+                        ui.label(
+                            egui::RichText::new(line.to_string())
+                                .monospace()
+                                .color(level_color(line.level, &theme)),
+                        );
+                    }
+                });
+        });
+
+    if !open {
+        viewer.visible = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin};
+    use chrono::NaiveTime;
+
+    fn line(level: Level, target: &str, message: &str) -> LogLine {
+        LogLine {
+            time: NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+            level,
+            target: target.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_log_viewer_filters() {
+        let mut harness = TestHarness::new()
+            .with_plugins((KonnektorenThemePlugin, LogViewerPlugin::default().visible()));
+        let buffer = harness.world().resource::<LogBuffer>().clone();
+        buffer.push(line(
+            Level::DEBUG,
+            "konnektoren_bevy::input",
+            "gamepad polled",
+        ));
+        buffer.push(line(
+            Level::WARN,
+            "konnektoren_bevy::storage",
+            "quota exceeded",
+        ));
+        buffer.push(line(Level::INFO, "bevy_asset", "loaded"));
+        harness.run_frames(2);
+
+        assert!(harness.has_widget("Showing 2 of 3"));
+        assert!(harness.has_widget(&buffer.lines()[1].to_string()));
+
+        harness
+            .world_mut()
+            .resource_mut::<LogViewer>()
+            .target_filter = "konnektoren".to_string();
+        harness.step();
+        assert!(harness.has_widget("Showing 1 of 3"));
+
+        harness.world_mut().resource_mut::<LogViewer>().min_level = Level::DEBUG;
+        harness.step();
+        assert!(harness.has_widget("Showing 2 of 3"));
+    }
+}
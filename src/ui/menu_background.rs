@@ -0,0 +1,348 @@
+use super::{
+    responsive::ResponsiveInfo,
+    texture_cache::{EguiTextureCache, EguiTextureCachePlugin},
+};
+use crate::theme::KonnektorenTheme;
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+/// Plugin for animated backgrounds behind the splash, about and credits
+/// screens.
+///
+/// The screens paint the [`MenuBackgroundState`] into their panel when the
+/// plugin is added, unless they have an explicit background color. Custom
+/// screens can call [`MenuBackgroundState::paint`] the same way. With
+/// [`ResponsiveInfo::reduced_motion`] the background stands still.
+#[derive(Default)]
+pub struct MenuBackgroundPlugin {
+    pub background: MenuBackground,
+}
+
+impl MenuBackgroundPlugin {
+    pub fn new(background: MenuBackground) -> Self {
+        Self { background }
+    }
+}
+
+impl Plugin for MenuBackgroundPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<EguiTextureCachePlugin>() {
+            app.add_plugins(EguiTextureCachePlugin);
+        }
+
+        app.insert_resource(MenuBackgroundState::new(self.background.clone()))
+            .add_systems(
+                Update,
+                (update_menu_background, sync_parallax_textures).chain(),
+            );
+    }
+}
+
+/// Built-in animated backgrounds
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum MenuBackground {
+    /// Soft gradient in the theme colors, slowly drifting
+    #[default]
+    GradientDrift,
+    /// Translucent circles, squares and triangles floating upwards
+    FloatingShapes { count: usize },
+    /// Image layers scrolling sideways, the first layer is the farthest
+    Parallax(Vec<ParallaxLayer>),
+}
+
+impl MenuBackground {
+    pub fn floating_shapes() -> Self {
+        MenuBackground::FloatingShapes { count: 24 }
+    }
+
+    pub fn parallax(layers: impl IntoIterator<Item = ParallaxLayer>) -> Self {
+        MenuBackground::Parallax(layers.into_iter().collect())
+    }
+}
+
+/// Image layer of a [`MenuBackground::Parallax`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParallaxLayer {
+    /// Asset path of the image, tiled horizontally
+    pub path: String,
+    /// Scroll speed in screen widths per minute
+    pub speed: f32,
+    pub opacity: f32,
+}
+
+impl ParallaxLayer {
+    pub fn new(path: impl Into<String>, speed: f32) -> Self {
+        Self {
+            path: path.into(),
+            speed,
+            opacity: 1.0,
+        }
+    }
+
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+}
+
+/// Resource holding the active menu background and its animation time
+#[derive(Resource, Debug, Clone)]
+pub struct MenuBackgroundState {
+    background: MenuBackground,
+    elapsed: f32,
+    /// Texture of each parallax layer, once loaded
+    layer_textures: Vec<Option<egui::TextureId>>,
+    /// Paths the texture cache holds references to
+    acquired: Vec<String>,
+}
+
+impl MenuBackgroundState {
+    pub fn new(background: MenuBackground) -> Self {
+        Self {
+            background,
+            elapsed: 0.0,
+            layer_textures: vec![],
+            acquired: vec![],
+        }
+    }
+
+    pub fn background(&self) -> &MenuBackground {
+        &self.background
+    }
+
+    /// Switch to another background, restarting the animation
+    pub fn set_background(&mut self, background: MenuBackground) {
+        if self.background != background {
+            self.background = background;
+            self.elapsed = 0.0;
+        }
+    }
+
+    /// Animation time in seconds
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Paint the background into the given rect
+    pub fn paint(&self, painter: &egui::Painter, rect: egui::Rect, theme: &KonnektorenTheme) {
+        painter.rect_filled(rect, egui::CornerRadius::ZERO, theme.base_100);
+
+        match &self.background {
+            MenuBackground::GradientDrift => self.paint_gradient(painter, rect, theme),
+            MenuBackground::FloatingShapes { count } => {
+                self.paint_shapes(painter, rect, theme, *count)
+            }
+            MenuBackground::Parallax(layers) => self.paint_parallax(painter, rect, layers),
+        }
+    }
+
+    fn paint_gradient(&self, painter: &egui::Painter, rect: egui::Rect, theme: &KonnektorenTheme) {
+        let t = self.elapsed * 0.15;
+        // Each corner pulls a different theme color in and out of the base
+        let corners = [
+            (rect.left_top(), theme.primary, 0.0),
+            (rect.right_top(), theme.secondary, 1.6),
+            (rect.right_bottom(), theme.accent, 3.2),
+            (rect.left_bottom(), theme.primary, 4.8),
+        ];
+
+        let mut mesh = egui::Mesh::default();
+        for (pos, color, phase) in corners {
+            let amount = 0.12 + 0.1 * (t + phase).sin();
+            mesh.colored_vertex(pos, lerp_color(theme.base_100, color, amount));
+        }
+        mesh.add_triangle(0, 1, 2);
+        mesh.add_triangle(0, 2, 3);
+        painter.add(egui::Shape::mesh(mesh));
+    }
+
+    fn paint_shapes(
+        &self,
+        painter: &egui::Painter,
+        rect: egui::Rect,
+        theme: &KonnektorenTheme,
+        count: usize,
+    ) {
+        let colors = [theme.primary, theme.secondary, theme.accent];
+        let scale = rect.width().min(rect.height()).max(1.0);
+
+        for index in 0..count {
+            let radius = scale * (0.02 + 0.04 * hash01(index, 0));
+            let speed = 0.01 + 0.02 * hash01(index, 1);
+            let sway = (self.elapsed * 0.3 + hash01(index, 2) * 6.0).sin() * 0.02;
+
+            // Normalized positions wrap around, shapes leave at the top and
+            // come back in at the bottom
+            let x = (hash01(index, 3) + sway).rem_euclid(1.0);
+            let y = (hash01(index, 4) - self.elapsed * speed).rem_euclid(1.0);
+            let center = egui::pos2(
+                rect.left() - radius + x * (rect.width() + 2.0 * radius),
+                rect.top() - radius + y * (rect.height() + 2.0 * radius),
+            );
+            let color = colors[index % colors.len()].gamma_multiply(0.15);
+
+            match index % 3 {
+                0 => {
+                    painter.circle_filled(center, radius, color);
+                }
+                1 => {
+                    let rotation = self.elapsed * 0.2 + hash01(index, 5) * std::f32::consts::TAU;
+                    let corners = (0..4)
+                        .map(|corner| {
+                            let angle = rotation + corner as f32 * std::f32::consts::FRAC_PI_2;
+                            center + egui::vec2(angle.cos(), angle.sin()) * radius
+                        })
+                        .collect();
+                    painter.add(egui::Shape::convex_polygon(
+                        corners,
+                        color,
+                        egui::Stroke::NONE,
+                    ));
+                }
+                _ => {
+                    let rotation = -self.elapsed * 0.2 + hash01(index, 5) * std::f32::consts::TAU;
+                    let corners = (0..3)
+                        .map(|corner| {
+                            let angle = rotation + corner as f32 * std::f32::consts::TAU / 3.0;
+                            center + egui::vec2(angle.cos(), angle.sin()) * radius
+                        })
+                        .collect();
+                    painter.add(egui::Shape::convex_polygon(
+                        corners,
+                        color,
+                        egui::Stroke::NONE,
+                    ));
+                }
+            }
+        }
+    }
+
+    fn paint_parallax(&self, painter: &egui::Painter, rect: egui::Rect, layers: &[ParallaxLayer]) {
+        for (layer, texture) in layers.iter().zip(&self.layer_textures) {
+            let Some(texture) = texture else {
+                continue;
+            };
+
+            let offset = (self.elapsed * layer.speed / 60.0).rem_euclid(1.0) * rect.width();
+            let tint = egui::Color32::WHITE.gamma_multiply(layer.opacity);
+            let uv = egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0));
+            for tile in 0..2 {
+                let left = rect.left() - offset + tile as f32 * rect.width();
+                let tile_rect =
+                    egui::Rect::from_min_size(egui::pos2(left, rect.top()), rect.size());
+                painter
+                    .with_clip_rect(rect)
+                    .image(*texture, tile_rect, uv, tint);
+            }
+        }
+    }
+}
+
+/// Deterministic pseudo random value between 0 and 1 for a shape property
+fn hash01(index: usize, salt: u32) -> f32 {
+    let mut x = (index as u32)
+        .wrapping_mul(0x9e37_79b9)
+        .wrapping_add(salt.wrapping_mul(0x85eb_ca6b));
+    x ^= x >> 16;
+    x = x.wrapping_mul(0x7feb_352d);
+    x ^= x >> 15;
+    (x >> 8) as f32 / (1u32 << 24) as f32
+}
+
+fn lerp_color(from: egui::Color32, to: egui::Color32, t: f32) -> egui::Color32 {
+    let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    egui::Color32::from_rgb(
+        lerp(from.r(), to.r()),
+        lerp(from.g(), to.g()),
+        lerp(from.b(), to.b()),
+    )
+}
+
+/// System to advance the background animation
+fn update_menu_background(
+    time: Res<Time>,
+    responsive: Option<Res<ResponsiveInfo>>,
+    mut state: ResMut<MenuBackgroundState>,
+) {
+    if responsive.is_some_and(|responsive| responsive.reduced_motion) {
+        return;
+    }
+    state.elapsed += time.delta_secs();
+}
+
+/// System to keep texture references for the parallax layers
+fn sync_parallax_textures(
+    mut state: ResMut<MenuBackgroundState>,
+    mut texture_cache: ResMut<EguiTextureCache>,
+    asset_server: Option<Res<AssetServer>>,
+) {
+    let paths: Vec<String> = match &state.background {
+        MenuBackground::Parallax(layers) => layers.iter().map(|layer| layer.path.clone()).collect(),
+        _ => vec![],
+    };
+
+    if paths != state.acquired {
+        for path in &state.acquired {
+            texture_cache.release(path);
+        }
+        state.acquired.clear();
+
+        if let Some(asset_server) = asset_server {
+            for path in &paths {
+                texture_cache.acquire(path, &asset_server);
+            }
+            state.acquired = paths.clone();
+        }
+    }
+
+    let textures: Vec<_> = paths
+        .iter()
+        .map(|path| texture_cache.texture(path))
+        .collect();
+    if textures != state.layer_textures {
+        state.layer_textures = textures;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestHarness;
+
+    #[test]
+    fn test_menu_background_animation() {
+        let mut harness =
+            TestHarness::new().with_plugins(MenuBackgroundPlugin::new(MenuBackground::parallax([
+                ParallaxLayer::new("backgrounds/far.png", 2.0),
+                ParallaxLayer::new("backgrounds/near.png", 6.0),
+            ])));
+        harness.run_frames(3);
+
+        let cache = harness.world().resource::<EguiTextureCache>();
+        assert_eq!(cache.ref_count("backgrounds/far.png"), 1);
+        assert_eq!(cache.ref_count("backgrounds/near.png"), 1);
+
+        harness
+            .world_mut()
+            .resource_mut::<MenuBackgroundState>()
+            .set_background(MenuBackground::floating_shapes());
+        harness.step();
+
+        let cache = harness.world().resource::<EguiTextureCache>();
+        assert_eq!(cache.ref_count("backgrounds/far.png"), 0);
+    }
+
+    #[test]
+    fn test_menu_background_reduced_motion() {
+        let mut harness = TestHarness::new()
+            .with_plugins(MenuBackgroundPlugin::new(MenuBackground::GradientDrift));
+        harness.world_mut().insert_resource(ResponsiveInfo {
+            reduced_motion: true,
+            ..Default::default()
+        });
+        harness.run_frames(3);
+
+        let state = harness.world().resource::<MenuBackgroundState>();
+        assert_eq!(state.elapsed(), 0.0);
+    }
+}
@@ -0,0 +1,283 @@
+use super::timers::format_timer;
+#[cfg(feature = "profile")]
+use crate::profile::PlayerProfile;
+#[cfg(feature = "screens")]
+use crate::screens::AnyScreenActive;
+#[cfg(feature = "storage")]
+use crate::storage::{PersistenceScheduler, Storage};
+use crate::theme::KonnektorenTheme;
+use bevy::{prelude::*, time::Stopwatch};
+use bevy_egui::{egui, EguiContexts, EguiPrimaryContextPass};
+use std::collections::BTreeMap;
+
+/// Storage key of the mini HUD placements
+pub const MINI_HUD_STORAGE_KEY: &str = "mini_hud";
+
+/// Profile the placement is stored for without the `profile` feature
+const DEFAULT_PROFILE_ID: &str = "player";
+
+/// Plugin for a small floating panel with the timer, score and streak of the
+/// current game.
+///
+/// The player can drag the panel and collapse it to the timer. Its placement
+/// is remembered per [`PlayerProfile`](crate::profile::PlayerProfile) and,
+/// with the `storage` feature, persisted. The panel hides itself while a
+/// screen such as the pause menu is shown over the game.
+///
+/// ```ignore
+/// app.add_plugins(MiniHudPlugin);
+///
+/// fn start_game(mut hud: ResMut<MiniHud>) {
+///     hud.start();
+/// }
+///
+/// fn on_answer(mut hud: ResMut<MiniHud>) {
+///     hud.score += 10;
+///     hud.streak += 1;
+/// }
+/// ```
+pub struct MiniHudPlugin;
+
+impl Plugin for MiniHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MiniHud>()
+            .init_resource::<MiniHudLayouts>()
+            .add_systems(Update, tick_mini_hud)
+            .add_systems(
+                EguiPrimaryContextPass,
+                render_mini_hud.run_if(mini_hud_visible),
+            );
+
+        #[cfg(feature = "storage")]
+        {
+            app.add_systems(Startup, load_mini_hud_layouts)
+                .add_systems(PostUpdate, save_mini_hud_layouts);
+        }
+    }
+}
+
+/// Values shown by the mini HUD, updated by the game
+#[derive(Resource, Debug, Clone, Default)]
+pub struct MiniHud {
+    /// Whether the HUD is shown, it is still hidden under screens
+    pub visible: bool,
+    /// Time played, ticks with the virtual time while not paused
+    pub stopwatch: Stopwatch,
+    pub score: u32,
+    /// Correct answers in a row
+    pub streak: u32,
+}
+
+impl MiniHud {
+    /// Show the HUD with the timer, score and streak reset
+    pub fn start(&mut self) {
+        *self = Self {
+            visible: true,
+            ..Default::default()
+        };
+    }
+
+    /// Hide the HUD and stop the timer, e.g. when the game ends
+    pub fn stop(&mut self) {
+        self.visible = false;
+        self.stopwatch.pause();
+    }
+}
+
+/// Position and collapsed state of the mini HUD
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "storage", derive(serde::Serialize, serde::Deserialize))]
+pub struct MiniHudPlacement {
+    /// Top left corner in points
+    pub position: [f32; 2],
+    /// Only the timer is shown
+    pub collapsed: bool,
+}
+
+impl Default for MiniHudPlacement {
+    fn default() -> Self {
+        Self {
+            position: [16.0, 16.0],
+            collapsed: false,
+        }
+    }
+}
+
+/// Resource with the mini HUD placement of every profile
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct MiniHudLayouts {
+    placements: BTreeMap<String, MiniHudPlacement>,
+}
+
+impl MiniHudLayouts {
+    pub fn get(&self, profile_id: &str) -> MiniHudPlacement {
+        self.placements.get(profile_id).copied().unwrap_or_default()
+    }
+
+    pub fn set(&mut self, profile_id: &str, placement: MiniHudPlacement) {
+        self.placements.insert(profile_id.to_string(), placement);
+    }
+}
+
+/// Run condition that is true while the mini HUD is shown and no screen is
+/// on top of the game
+pub fn mini_hud_visible(
+    hud: Option<Res<MiniHud>>,
+    #[cfg(feature = "screens")] screens: Option<Res<AnyScreenActive>>,
+) -> bool {
+    #[cfg(feature = "screens")]
+    if screens.is_some_and(|screens| screens.is_active()) {
+        return false;
+    }
+    hud.is_some_and(|hud| hud.visible)
+}
+
+/// System to advance the timer
+fn tick_mini_hud(time: Res<Time>, mut hud: ResMut<MiniHud>) {
+    if hud.visible && !hud.stopwatch.is_paused() {
+        hud.stopwatch.tick(time.delta());
+    }
+}
+
+/// System to render the mini HUD and remember where the player put it
+fn render_mini_hud(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    hud: Res<MiniHud>,
+    mut layouts: ResMut<MiniHudLayouts>,
+    #[cfg(feature = "profile")] profile: Option<Res<PlayerProfile>>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    #[cfg(feature = "profile")]
+    let profile_id = profile.map_or(DEFAULT_PROFILE_ID.to_string(), |profile| profile.id.clone());
+    #[cfg(not(feature = "profile"))]
+    let profile_id = DEFAULT_PROFILE_ID.to_string();
+
+    let mut placement = layouts.get(&profile_id);
+    let [x, y] = placement.position;
+
+    // One area per profile, so switching profiles moves the panel
+    let area = egui::Area::new(egui::Id::new(("konnektoren_mini_hud", &profile_id)))
+        .movable(true)
+        .default_pos(egui::pos2(x, y))
+        .constrain(true)
+        .show(ctx, |ui| {
+            egui::Frame {
+                inner_margin: egui::Margin::symmetric(10, 6),
+                corner_radius: egui::CornerRadius::same(8),
+                fill: theme.base_200.gamma_multiply(0.9),
+                stroke: egui::Stroke::new(1.0, theme.base_300),
+                ..Default::default()
+            }
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(format_timer(hud.stopwatch.elapsed()))
+                            .monospace()
+                            .strong()
+                            .color(theme.base_content),
+                    );
+                    let toggle = if placement.collapsed { "+" } else { "−" };
+                    if ui.small_button(toggle).clicked() {
+                        placement.collapsed = !placement.collapsed;
+                    }
+                });
+
+                if !placement.collapsed {
+                    ui.label(
+                        egui::RichText::new(format!("Score: {}", hud.score)).color(theme.primary),
+                    );
+                    ui.label(
+                        egui::RichText::new(format!("Streak: {}", hud.streak))
+                            .color(theme.secondary),
+                    );
+                }
+            });
+        });
+
+    if area.response.drag_stopped() {
+        let position = area.response.rect.min;
+        placement.position = [position.x, position.y];
+    }
+    if layouts.get(&profile_id) != placement {
+        layouts.set(&profile_id, placement);
+    }
+}
+
+/// System to restore the mini HUD placements from storage
+#[cfg(feature = "storage")]
+fn load_mini_hud_layouts(storage: Option<Res<Storage>>, mut layouts: ResMut<MiniHudLayouts>) {
+    let Some(storage) = storage else {
+        return;
+    };
+
+    match storage.load::<BTreeMap<String, MiniHudPlacement>>(MINI_HUD_STORAGE_KEY) {
+        Ok(Some(placements)) => layouts.placements = placements,
+        Ok(None) => {}
+        Err(e) => warn!("Failed to load mini HUD placements: {}", e),
+    }
+}
+
+/// System to persist the mini HUD placements whenever they change
+#[cfg(feature = "storage")]
+fn save_mini_hud_layouts(
+    scheduler: Option<ResMut<PersistenceScheduler>>,
+    layouts: Res<MiniHudLayouts>,
+) {
+    if !layouts.is_changed() {
+        return;
+    }
+
+    if let Some(mut scheduler) = scheduler {
+        scheduler.schedule(MINI_HUD_STORAGE_KEY, &layouts.placements);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin};
+
+    #[test]
+    fn test_mini_hud() {
+        let mut harness = TestHarness::new().with_plugins((KonnektorenThemePlugin, MiniHudPlugin));
+        harness.run_frames(2);
+        assert!(!harness.has_widget("Score: 0"));
+
+        {
+            let mut hud = harness.world_mut().resource_mut::<MiniHud>();
+            hud.start();
+            hud.score = 120;
+            hud.streak = 3;
+        }
+        harness.run_frames(2);
+        assert!(harness.has_widget("Score: 120"));
+        assert!(harness.has_widget("Streak: 3"));
+
+        // Collapsing keeps the timer only and is remembered
+        harness.click("−");
+        harness.run_frames(2);
+        assert!(!harness.has_widget("Score: 120"));
+        assert!(harness.has_widget("+"));
+        let layouts = harness.world().resource::<MiniHudLayouts>();
+        assert!(layouts.get(DEFAULT_PROFILE_ID).collapsed);
+
+        #[cfg(feature = "screens")]
+        {
+            #[derive(Component)]
+            struct PauseMenu;
+
+            crate::screens::track_active_screen::<PauseMenu>(harness.app_mut());
+            let menu = harness.world_mut().spawn(PauseMenu).id();
+            harness.run_frames(2);
+            assert!(!harness.has_widget("+"));
+
+            harness.world_mut().despawn(menu);
+            harness.run_frames(2);
+            assert!(harness.has_widget("+"));
+        }
+    }
+}
@@ -1,8 +1,68 @@
+pub mod announcer;
+pub mod banner;
+pub mod celebration;
+pub mod charts;
+pub mod coach_marks;
+pub mod counter;
+pub mod debug_overlay;
+#[cfg(feature = "gallery")]
+pub mod gallery;
+pub mod handwriting;
+pub mod hud;
+pub mod image_options;
+pub mod listening;
+pub mod log_capture;
+#[cfg(feature = "log-viewer")]
+pub mod log_viewer;
+pub mod menu_background;
+pub mod mini_hud;
+pub mod notifications;
+pub mod performance_hud;
+pub mod play_queue;
+pub mod qr_code;
 pub mod responsive;
+pub mod scope;
+pub mod screen_time;
+#[cfg(feature = "screenshot")]
+pub mod screenshot;
+pub mod text_animation;
+pub mod texture_cache;
+pub mod timers;
 pub mod widgets;
+pub mod wizard;
 
+pub use announcer::*;
+pub use banner::*;
+pub use celebration::*;
+pub use charts::*;
+pub use coach_marks::*;
+pub use counter::*;
+pub use debug_overlay::*;
+#[cfg(feature = "gallery")]
+pub use gallery::*;
+pub use handwriting::*;
+pub use hud::*;
+pub use image_options::*;
+pub use listening::*;
+pub use log_capture::*;
+#[cfg(feature = "log-viewer")]
+pub use log_viewer::*;
+pub use menu_background::*;
+pub use mini_hud::*;
+pub use notifications::*;
+pub use performance_hud::*;
+pub use play_queue::*;
+pub use qr_code::*;
 pub use responsive::*;
+pub use scope::*;
+pub use screen_time::*;
+#[cfg(feature = "screenshot")]
+pub use screenshot::*;
+pub use text_animation::*;
+pub use texture_cache::*;
+pub use timers::*;
 pub use widgets::*;
+pub use wizard::*;
 
 use bevy::prelude::*;
 
@@ -11,8 +71,16 @@ pub struct UIPlugin;
 
 impl Plugin for UIPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(ResponsivePlugin);
+        app.add_plugins(ResponsivePlugin)
+            .add_plugins(NotificationPlugin)
+            .add_plugins(BannerPlugin)
+            .add_plugins(AnnouncerPlugin)
+            .add_plugins(CoachMarksPlugin);
 
-        info!("UIPlugin loaded with responsive and widget support");
+        if !app.is_plugin_added::<EguiTextureCachePlugin>() {
+            app.add_plugins(EguiTextureCachePlugin);
+        }
+
+        info!("UIPlugin loaded with responsive, widget, notification, banner, announcer, coach mark and texture cache support");
     }
 }
@@ -0,0 +1,514 @@
+use super::{
+    responsive::{ResponsiveFontSize, ResponsiveInfo},
+    widgets::ResponsiveText,
+};
+use crate::theme::KonnektorenTheme;
+use bevy::prelude::*;
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+
+/// Kind of a notification, determines its color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NotificationKind {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+/// Message to show a notification toast
+#[derive(Message, Debug, Clone)]
+pub struct Notification {
+    pub kind: NotificationKind,
+    pub title: String,
+    pub message: String,
+    /// How long the toast stays visible in seconds
+    pub duration: f32,
+}
+
+impl Notification {
+    pub fn new(
+        kind: NotificationKind,
+        title: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            title: title.into(),
+            message: message.into(),
+            duration: 4.0,
+        }
+    }
+
+    pub fn info(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(NotificationKind::Info, title, message)
+    }
+
+    pub fn success(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(NotificationKind::Success, title, message)
+    }
+
+    pub fn warning(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(NotificationKind::Warning, title, message)
+    }
+
+    pub fn error(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(NotificationKind::Error, title, message)
+    }
+
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+/// A notification currently shown as toast
+#[derive(Debug, Clone)]
+pub struct ActiveToast {
+    pub notification: Notification,
+    pub timer: Timer,
+}
+
+/// Resource holding the currently visible toasts
+#[derive(Resource, Debug, Default)]
+pub struct NotificationToasts {
+    pub toasts: Vec<ActiveToast>,
+    /// Maximum number of toasts shown at the same time
+    pub max_visible: usize,
+}
+
+impl NotificationToasts {
+    pub fn new(max_visible: usize) -> Self {
+        Self {
+            toasts: Vec::new(),
+            max_visible,
+        }
+    }
+}
+
+/// Message to show a snackbar at the bottom of the screen.
+///
+/// Unlike toasts, only one snackbar is shown at a time and it can carry a
+/// single action like "Undo". A new snackbar replaces the current one. When
+/// it goes away a [`SnackbarClosed`] message with the same key reports
+/// whether the action was taken, so destructive operations like resetting
+/// settings can be reverted, or only be committed once the time ran out.
+#[derive(Message, Debug, Clone)]
+pub struct Snackbar {
+    /// Identifies the operation in the [`SnackbarClosed`] message
+    pub key: String,
+    pub message: String,
+    /// Label of the action button
+    pub action: Option<String>,
+    /// How long the snackbar stays visible in seconds
+    pub duration: f32,
+}
+
+impl Snackbar {
+    pub fn new(key: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            message: message.into(),
+            action: None,
+            duration: 6.0,
+        }
+    }
+
+    /// Snackbar with an "Undo" action
+    pub fn undo(key: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(key, message).with_action("Undo")
+    }
+
+    pub fn with_action(mut self, label: impl Into<String>) -> Self {
+        self.action = Some(label.into());
+        self
+    }
+
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+/// How a snackbar went away
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnackbarOutcome {
+    /// The action button was clicked
+    Action,
+    /// The time ran out, or the snackbar was dismissed or replaced
+    Expired,
+}
+
+/// Message sent when a snackbar went away
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct SnackbarClosed {
+    pub key: String,
+    pub outcome: SnackbarOutcome,
+}
+
+impl SnackbarClosed {
+    /// Whether the action was taken for the given key
+    pub fn is_action(&self, key: &str) -> bool {
+        self.key == key && self.outcome == SnackbarOutcome::Action
+    }
+
+    /// Whether the snackbar for the given key went away without its action
+    pub fn is_expired(&self, key: &str) -> bool {
+        self.key == key && self.outcome == SnackbarOutcome::Expired
+    }
+}
+
+/// Resource holding the currently visible snackbar
+#[derive(Resource, Debug, Default)]
+pub struct ActiveSnackbar {
+    pub snackbar: Option<(Snackbar, Timer)>,
+    /// Set by the render system when the snackbar should close
+    closing: Option<SnackbarOutcome>,
+}
+
+/// Plugin showing [`Notification`] messages as themed toasts and
+/// [`Snackbar`] messages at the bottom of the screen
+pub struct NotificationPlugin;
+
+impl Plugin for NotificationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<Notification>()
+            .add_message::<Snackbar>()
+            .add_message::<SnackbarClosed>()
+            .insert_resource(NotificationToasts::new(3))
+            .init_resource::<ActiveSnackbar>()
+            .add_systems(
+                Update,
+                (
+                    collect_notifications,
+                    update_notification_toasts,
+                    update_snackbar,
+                ),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                (render_notification_toasts, render_snackbar),
+            );
+    }
+}
+
+/// System to turn notification messages into toasts
+pub fn collect_notifications(
+    mut notifications: MessageReader<Notification>,
+    mut toasts: ResMut<NotificationToasts>,
+) {
+    for notification in notifications.read() {
+        let timer = Timer::from_seconds(notification.duration.max(0.0), TimerMode::Once);
+        toasts.toasts.push(ActiveToast {
+            notification: notification.clone(),
+            timer,
+        });
+
+        // Drop the oldest toasts when too many are queued
+        let max_visible = toasts.max_visible.max(1);
+        if toasts.toasts.len() > max_visible {
+            let overflow = toasts.toasts.len() - max_visible;
+            toasts.toasts.drain(0..overflow);
+        }
+    }
+}
+
+/// System to expire toasts
+pub fn update_notification_toasts(time: Res<Time>, mut toasts: ResMut<NotificationToasts>) {
+    for toast in toasts.toasts.iter_mut() {
+        toast.timer.tick(time.delta());
+    }
+    toasts.toasts.retain(|toast| !toast.timer.is_finished());
+}
+
+/// System to show new snackbars and close expired or answered ones
+pub fn update_snackbar(
+    time: Res<Time>,
+    mut snackbars: MessageReader<Snackbar>,
+    mut closed: MessageWriter<SnackbarClosed>,
+    mut active: ResMut<ActiveSnackbar>,
+) {
+    if let Some(outcome) = active.closing.take() {
+        if let Some((snackbar, _)) = active.snackbar.take() {
+            closed.write(SnackbarClosed {
+                key: snackbar.key,
+                outcome,
+            });
+        }
+    }
+
+    for snackbar in snackbars.read() {
+        if let Some((replaced, _)) = active.snackbar.take() {
+            closed.write(SnackbarClosed {
+                key: replaced.key,
+                outcome: SnackbarOutcome::Expired,
+            });
+        }
+        let timer = Timer::from_seconds(snackbar.duration.max(0.0), TimerMode::Once);
+        active.snackbar = Some((snackbar.clone(), timer));
+    }
+
+    let expired = active.snackbar.as_mut().is_some_and(|(_, timer)| {
+        timer.tick(time.delta());
+        timer.is_finished()
+    });
+    if expired {
+        if let Some((snackbar, _)) = active.snackbar.take() {
+            closed.write(SnackbarClosed {
+                key: snackbar.key,
+                outcome: SnackbarOutcome::Expired,
+            });
+        }
+    }
+}
+
+/// Get the accent color of a notification kind
+pub fn notification_color(theme: &KonnektorenTheme, kind: NotificationKind) -> egui::Color32 {
+    match kind {
+        NotificationKind::Info => theme.info,
+        NotificationKind::Success => theme.success,
+        NotificationKind::Warning => theme.warning,
+        NotificationKind::Error => theme.error,
+    }
+}
+
+/// System to render the toasts in the top right corner
+fn render_notification_toasts(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    mut toasts: ResMut<NotificationToasts>,
+) {
+    if toasts.toasts.is_empty() {
+        return;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let width = if responsive.is_mobile() {
+        (responsive.screen_size.x * 0.9).min(360.0)
+    } else {
+        320.0
+    };
+
+    let mut dismissed = None;
+
+    egui::Area::new(egui::Id::new("konnektoren_notification_toasts"))
+        .anchor(egui::Align2::RIGHT_TOP, egui::vec2(-12.0, 12.0))
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            ui.set_width(width);
+
+            for (index, toast) in toasts.toasts.iter().enumerate().rev() {
+                let color = notification_color(&theme, toast.notification.kind);
+                let frame = egui::Frame {
+                    inner_margin: egui::Margin::same(12),
+                    corner_radius: egui::CornerRadius::same(theme.radius),
+                    fill: theme.base_200,
+                    stroke: egui::Stroke::new(2.0, color),
+                    ..Default::default()
+                };
+
+                let response = frame
+                    .show(ui, |ui| {
+                        ui.set_width(width - 24.0);
+                        ResponsiveText::new(
+                            &toast.notification.title,
+                            ResponsiveFontSize::Medium,
+                            color,
+                        )
+                        .responsive(&responsive)
+                        .strong()
+                        .ui(ui);
+
+                        if !toast.notification.message.is_empty() {
+                            ResponsiveText::new(
+                                &toast.notification.message,
+                                ResponsiveFontSize::Small,
+                                theme.base_content,
+                            )
+                            .responsive(&responsive)
+                            .ui(ui);
+                        }
+                    })
+                    .response
+                    .interact(egui::Sense::click());
+
+                // Tap/click a toast to dismiss it early
+                if response.clicked() {
+                    dismissed = Some(index);
+                }
+
+                ui.add_space(8.0);
+            }
+        });
+
+    if let Some(index) = dismissed {
+        toasts.toasts.remove(index);
+    }
+}
+
+/// System to render the snackbar at the bottom center
+fn render_snackbar(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    mut active: ResMut<ActiveSnackbar>,
+) {
+    let Some((snackbar, timer)) = &active.snackbar else {
+        return;
+    };
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let width = if responsive.is_mobile() {
+        responsive.screen_size.x * 0.92
+    } else {
+        480.0
+    };
+
+    let mut closing = None;
+
+    egui::Area::new(egui::Id::new("konnektoren_snackbar"))
+        .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -16.0))
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            ui.set_width(width);
+
+            // Inverted colors stand out from the screen below
+            let frame = egui::Frame {
+                inner_margin: egui::Margin::symmetric(16, 10),
+                corner_radius: egui::CornerRadius::same(theme.radius),
+                fill: theme.base_content,
+                ..Default::default()
+            };
+
+            frame.show(ui, |ui| {
+                ui.set_width(width - 32.0);
+                ui.horizontal(|ui| {
+                    ResponsiveText::new(
+                        &snackbar.message,
+                        ResponsiveFontSize::Medium,
+                        theme.base_100,
+                    )
+                    .responsive(&responsive)
+                    .ui(ui);
+
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        let close =
+                            egui::Button::new(egui::RichText::new("✕").color(theme.base_100))
+                                .frame(false);
+                        if ui.add(close).on_hover_text("Dismiss").clicked() {
+                            closing = Some(SnackbarOutcome::Expired);
+                        }
+
+                        if let Some(action) = &snackbar.action {
+                            let button = egui::Button::new(
+                                egui::RichText::new(action)
+                                    .size(responsive.font_size(ResponsiveFontSize::Medium))
+                                    .color(theme.primary)
+                                    .strong(),
+                            )
+                            .frame(false);
+                            if ui.add(button).clicked() {
+                                closing = Some(SnackbarOutcome::Action);
+                            }
+                        }
+                    });
+                });
+
+                // Time left until the snackbar closes on its own
+                let (rect, _) = ui.allocate_exact_size(
+                    egui::vec2(ui.available_width(), 2.0),
+                    egui::Sense::hover(),
+                );
+                let mut left = rect;
+                left.set_width(rect.width() * timer.fraction_remaining());
+                ui.painter()
+                    .rect_filled(left, egui::CornerRadius::ZERO, theme.primary);
+            });
+        });
+
+    if closing.is_some() {
+        active.closing = closing;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    #[test]
+    fn test_snackbar_undo() {
+        let mut harness = TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin));
+        harness
+            .world_mut()
+            .write_message(Snackbar::undo("reset_settings", "Settings reset"));
+        harness.run_frames(2);
+
+        harness.click("Undo");
+        harness.step();
+        let closed = harness.read_messages::<SnackbarClosed>();
+        assert_eq!(closed.len(), 1);
+        assert!(closed[0].is_action("reset_settings"));
+        assert!(harness
+            .world()
+            .resource::<ActiveSnackbar>()
+            .snackbar
+            .is_none());
+
+        harness
+            .world_mut()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                50,
+            )));
+        harness
+            .world_mut()
+            .write_message(Snackbar::undo("clear_progress", "Progress cleared").with_duration(0.1));
+        let mut closed = vec![];
+        for _ in 0..5 {
+            harness.step();
+            closed.extend(harness.read_messages::<SnackbarClosed>());
+        }
+        assert_eq!(closed.len(), 1);
+        assert!(closed[0].is_expired("clear_progress"));
+    }
+
+    #[test]
+    fn test_negative_notification_duration() {
+        let mut harness = TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin));
+        harness
+            .world_mut()
+            .write_message(Notification::info("Saved", "Progress saved").with_duration(-1.0));
+        harness.run_frames(2);
+
+        assert!(harness
+            .world()
+            .resource::<NotificationToasts>()
+            .toasts
+            .is_empty());
+    }
+
+    #[test]
+    fn test_negative_snackbar_duration() {
+        let mut harness = TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin));
+        harness
+            .world_mut()
+            .write_message(Snackbar::undo("delete_profile", "Profile deleted").with_duration(-1.0));
+        harness.step();
+
+        let closed = harness.read_messages::<SnackbarClosed>();
+        assert_eq!(closed.len(), 1);
+        assert!(closed[0].is_expired("delete_profile"));
+    }
+}
@@ -0,0 +1,408 @@
+use crate::theme::KonnektorenTheme;
+use bevy::prelude::*;
+use bevy_egui::{
+    egui::{self, epaint::Primitive},
+    EguiContexts, EguiPrimaryContextPass, EguiRenderOutput, PrimaryEguiContext,
+};
+use std::collections::VecDeque;
+
+/// Number of frames shown in the frame time graph
+const FRAME_HISTORY: usize = 120;
+
+/// Plugin for a compact performance HUD with FPS, a frame time graph, the
+/// entity count and the egui paint statistics.
+///
+/// Values over the [`PerformanceBudget`] tint the HUD red. With
+/// [`with_warnings`](Self::with_warnings) a [`PerformanceWarning`] is sent
+/// whenever a value goes over its budget, also while the HUD is hidden, e.g.
+/// to report slow devices to analytics. The HUD is toggled with F5.
+pub struct PerformanceHudPlugin {
+    /// Show the HUD on startup
+    pub visible: bool,
+    /// Key toggling the HUD
+    pub toggle_key: Option<KeyCode>,
+    pub budget: PerformanceBudget,
+    /// Send [`PerformanceWarning`]s
+    pub warnings: bool,
+}
+
+impl Default for PerformanceHudPlugin {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            toggle_key: Some(KeyCode::F5),
+            budget: PerformanceBudget::default(),
+            warnings: false,
+        }
+    }
+}
+
+impl PerformanceHudPlugin {
+    pub fn visible(mut self) -> Self {
+        self.visible = true;
+        self
+    }
+
+    pub fn with_toggle_key(mut self, key: Option<KeyCode>) -> Self {
+        self.toggle_key = key;
+        self
+    }
+
+    pub fn with_budget(mut self, budget: PerformanceBudget) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    pub fn with_warnings(mut self) -> Self {
+        self.warnings = true;
+        self
+    }
+}
+
+impl Plugin for PerformanceHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(PerformanceHud {
+            visible: self.visible,
+            toggle_key: self.toggle_key,
+            budget: self.budget.clone(),
+            warnings: self.warnings,
+            stats: PerformanceStats::default(),
+            frame_times: VecDeque::with_capacity(FRAME_HISTORY),
+            over_budget: vec![],
+        })
+        .add_message::<PerformanceWarning>()
+        .add_systems(
+            Update,
+            (toggle_performance_hud, update_performance_stats).chain(),
+        )
+        .add_systems(
+            EguiPrimaryContextPass,
+            render_performance_hud.run_if(performance_hud_visible),
+        );
+    }
+}
+
+/// Thresholds above which the HUD turns red, `None` for no limit
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerformanceBudget {
+    /// Average frame time in milliseconds
+    pub frame_time_ms: Option<f32>,
+    pub entities: Option<usize>,
+    /// Vertices of the egui meshes of the last frame
+    pub egui_vertices: Option<usize>,
+}
+
+impl Default for PerformanceBudget {
+    fn default() -> Self {
+        Self {
+            // 30 fps
+            frame_time_ms: Some(1000.0 / 30.0),
+            entities: None,
+            egui_vertices: None,
+        }
+    }
+}
+
+impl PerformanceBudget {
+    /// Budget of a metric
+    pub fn limit(&self, metric: PerformanceMetric) -> Option<f32> {
+        match metric {
+            PerformanceMetric::FrameTime => self.frame_time_ms,
+            PerformanceMetric::Entities => self.entities.map(|limit| limit as f32),
+            PerformanceMetric::EguiVertices => self.egui_vertices.map(|limit| limit as f32),
+        }
+    }
+}
+
+/// Value tracked by the performance HUD
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PerformanceMetric {
+    FrameTime,
+    Entities,
+    EguiVertices,
+}
+
+/// Message sent when a value goes over its budget
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct PerformanceWarning {
+    pub metric: PerformanceMetric,
+    pub value: f32,
+    pub budget: f32,
+}
+
+/// Measured values of the last frame
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PerformanceStats {
+    /// Average frame time in milliseconds
+    pub frame_time_ms: f32,
+    pub entities: usize,
+    pub egui_meshes: usize,
+    pub egui_vertices: usize,
+}
+
+impl PerformanceStats {
+    pub fn fps(&self) -> f32 {
+        if self.frame_time_ms > 0.0 {
+            1000.0 / self.frame_time_ms
+        } else {
+            0.0
+        }
+    }
+
+    pub fn value(&self, metric: PerformanceMetric) -> f32 {
+        match metric {
+            PerformanceMetric::FrameTime => self.frame_time_ms,
+            PerformanceMetric::Entities => self.entities as f32,
+            PerformanceMetric::EguiVertices => self.egui_vertices as f32,
+        }
+    }
+}
+
+/// State of the performance HUD
+#[derive(Resource, Debug, Clone)]
+pub struct PerformanceHud {
+    /// Whether the HUD is shown
+    pub visible: bool,
+    toggle_key: Option<KeyCode>,
+    pub budget: PerformanceBudget,
+    /// Send [`PerformanceWarning`]s
+    pub warnings: bool,
+    stats: PerformanceStats,
+    /// Frame times in milliseconds, oldest first
+    frame_times: VecDeque<f32>,
+    over_budget: Vec<PerformanceMetric>,
+}
+
+impl PerformanceHud {
+    pub fn stats(&self) -> &PerformanceStats {
+        &self.stats
+    }
+
+    /// Whether a value is over its budget
+    pub fn is_over_budget(&self, metric: PerformanceMetric) -> bool {
+        self.over_budget.contains(&metric)
+    }
+}
+
+/// Run condition that is true while the performance HUD is shown
+pub fn performance_hud_visible(hud: Option<Res<PerformanceHud>>) -> bool {
+    hud.is_some_and(|hud| hud.visible)
+}
+
+/// System to toggle the HUD with its key
+fn toggle_performance_hud(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mut hud: ResMut<PerformanceHud>,
+) {
+    let (Some(keys), Some(key)) = (keys, hud.toggle_key) else {
+        return;
+    };
+
+    if keys.just_pressed(key) {
+        hud.visible = !hud.visible;
+    }
+}
+
+/// System to measure the frame and check the budget
+fn update_performance_stats(
+    time: Res<Time>,
+    mut hud: ResMut<PerformanceHud>,
+    entities: Query<Entity>,
+    egui_output: Query<&EguiRenderOutput, With<PrimaryEguiContext>>,
+    mut warnings: MessageWriter<PerformanceWarning>,
+) {
+    if !hud.visible && !hud.warnings {
+        return;
+    }
+
+    if hud.frame_times.len() >= FRAME_HISTORY {
+        hud.frame_times.pop_front();
+    }
+    hud.frame_times.push_back(time.delta_secs() * 1000.0);
+
+    // Average over the last half second at 60 fps to ignore single spikes
+    let recent = hud.frame_times.len().min(30);
+    let frame_time_ms = hud.frame_times.iter().rev().take(recent).sum::<f32>() / recent as f32;
+
+    let (egui_meshes, egui_vertices) = egui_output
+        .iter()
+        .flat_map(|output| &output.paint_jobs)
+        .filter_map(|job| match &job.primitive {
+            Primitive::Mesh(mesh) => Some(mesh.vertices.len()),
+            Primitive::Callback(_) => None,
+        })
+        .fold((0, 0), |(meshes, vertices), count| {
+            (meshes + 1, vertices + count)
+        });
+
+    hud.stats = PerformanceStats {
+        frame_time_ms,
+        entities: entities.iter().count(),
+        egui_meshes,
+        egui_vertices,
+    };
+
+    for metric in [
+        PerformanceMetric::FrameTime,
+        PerformanceMetric::Entities,
+        PerformanceMetric::EguiVertices,
+    ] {
+        let value = hud.stats.value(metric);
+        let budget = hud.budget.limit(metric);
+        let over = budget.is_some_and(|budget| value > budget);
+        let was_over = hud.is_over_budget(metric);
+
+        if over && !was_over {
+            hud.over_budget.push(metric);
+            if let (true, Some(budget)) = (hud.warnings, budget) {
+                warnings.write(PerformanceWarning {
+                    metric,
+                    value,
+                    budget,
+                });
+            }
+        } else if !over && was_over {
+            hud.over_budget.retain(|m| *m != metric);
+        }
+    }
+}
+
+/// System to render the performance HUD
+fn render_performance_hud(
+    mut contexts: EguiContexts,
+    hud: Res<PerformanceHud>,
+    theme: Res<KonnektorenTheme>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let stats = hud.stats();
+    let color = |metric| {
+        if hud.is_over_budget(metric) {
+            theme.error
+        } else {
+            theme.base_content
+        }
+    };
+    let fill = if hud.over_budget.is_empty() {
+        theme.base_200.gamma_multiply(0.9)
+    } else {
+        theme.error.gamma_multiply(0.25)
+    };
+
+    egui::Area::new(egui::Id::new("konnektoren_performance_hud"))
+        .anchor(egui::Align2::LEFT_TOP, egui::vec2(8.0, 8.0))
+        .order(egui::Order::Debug)
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame {
+                inner_margin: egui::Margin::same(6),
+                corner_radius: egui::CornerRadius::same(4),
+                fill,
+                stroke: egui::Stroke::new(1.0, theme.base_300),
+                ..Default::default()
+            }
+            .show(ui, |ui| {
+                let line = |ui: &mut egui::Ui, text: String, color: egui::Color32| {
+                    ui.label(
+                        egui::RichText::new(text)
+                            .monospace()
+                            .size(11.0)
+                            .color(color),
+                    );
+                };
+
+                line(
+                    ui,
+                    format!("{:.0} fps  {:.1} ms", stats.fps(), stats.frame_time_ms),
+                    color(PerformanceMetric::FrameTime),
+                );
+                render_frame_graph(ui, &hud, &theme);
+                line(
+                    ui,
+                    format!("Entities: {}", stats.entities),
+                    color(PerformanceMetric::Entities),
+                );
+                line(
+                    ui,
+                    format!(
+                        "egui: {} meshes, {} vertices",
+                        stats.egui_meshes, stats.egui_vertices
+                    ),
+                    color(PerformanceMetric::EguiVertices),
+                );
+            });
+        });
+}
+
+/// Draw the frame times as bars with the frame time budget as a line
+fn render_frame_graph(ui: &mut egui::Ui, hud: &PerformanceHud, theme: &KonnektorenTheme) {
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(160.0, 36.0), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 2.0, theme.base_100);
+
+    let budget = hud.budget.frame_time_ms;
+    // Scale to twice the budget, so the budget line sits in the middle
+    let max = budget
+        .map(|budget| budget * 2.0)
+        .unwrap_or_else(|| hud.frame_times.iter().copied().fold(1.0, f32::max));
+    let bar_width = rect.width() / FRAME_HISTORY as f32;
+
+    for (i, frame_time) in hud.frame_times.iter().enumerate() {
+        let height = (frame_time / max).min(1.0) * rect.height();
+        let x = rect.left() + i as f32 * bar_width;
+        let color = if budget.is_some_and(|budget| *frame_time > budget) {
+            theme.error
+        } else {
+            theme.success
+        };
+        painter.rect_filled(
+            egui::Rect::from_min_max(
+                egui::pos2(x, rect.bottom() - height),
+                egui::pos2(x + bar_width, rect.bottom()),
+            ),
+            0.0,
+            color,
+        );
+    }
+
+    if budget.is_some() {
+        let y = rect.center().y;
+        painter.hline(rect.x_range(), y, egui::Stroke::new(1.0, theme.warning));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin};
+
+    #[test]
+    fn test_performance_budget_warnings() {
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            PerformanceHudPlugin::default()
+                .with_budget(PerformanceBudget {
+                    frame_time_ms: None,
+                    entities: Some(20),
+                    egui_vertices: None,
+                })
+                .with_warnings(),
+        ));
+        harness.run_frames(2);
+        assert!(harness.read_messages::<PerformanceWarning>().is_empty());
+
+        harness
+            .world_mut()
+            .spawn_batch((0..20).map(|_| Transform::default()));
+        harness.run_frames(2);
+        let warnings = harness.read_messages::<PerformanceWarning>();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].metric, PerformanceMetric::Entities);
+        assert!(harness
+            .world()
+            .resource::<PerformanceHud>()
+            .is_over_budget(PerformanceMetric::Entities));
+    }
+}
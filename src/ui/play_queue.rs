@@ -0,0 +1,459 @@
+use super::{
+    celebration::ChallengeFinished,
+    responsive::{ResponsiveFontSize, ResponsiveInfo},
+    widgets::{ResponsiveText, ThemedButton},
+};
+use crate::theme::KonnektorenTheme;
+use bevy::prelude::*;
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+use std::collections::VecDeque;
+
+/// Plugin for the [`PlayQueue`], the single place deciding what is played next.
+///
+/// The level select screen, a review scheduler or the daily challenge push
+/// challenges and levels into the queue, the game starts the item of every
+/// [`PlayNext`]:
+///
+/// ```ignore
+/// app.add_plugins(PlayQueuePlugin);
+///
+/// fn queue_daily_challenge(mut queue: ResMut<PlayQueue>) {
+///     queue.queue_challenge("articles");
+///     queue.queue_level("level-2");
+///     queue.play_next();
+/// }
+///
+/// fn start_item(mut next: MessageReader<PlayNext>) {
+///     for PlayNext { item } in next.read() {
+///         // Start the challenge or level of `item`
+///     }
+/// }
+/// ```
+///
+/// When the current item is finished ([`ChallengeFinished`] for challenges,
+/// [`LevelFinished`] for levels) its result is shown between the items and
+/// the next item starts after [`PlayQueue::interstitial_secs`], or when the
+/// player continues.
+pub struct PlayQueuePlugin;
+
+impl Plugin for PlayQueuePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayQueue>()
+            .add_message::<PlayNext>()
+            .add_message::<ChallengeFinished>()
+            .add_message::<LevelFinished>()
+            .add_systems(
+                Update,
+                (finish_queued_items, advance_play_queue, announce_next_item).chain(),
+            )
+            .add_systems(EguiPrimaryContextPass, render_play_queue_interstitial);
+    }
+}
+
+/// Kind of content in the [`PlayQueue`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlayItemKind {
+    Challenge,
+    Level,
+}
+
+/// A challenge or level in the [`PlayQueue`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayItem {
+    pub kind: PlayItemKind,
+    /// ID of the challenge or level asset
+    pub id: String,
+    /// Name shown between the items, the ID if not set
+    pub title: Option<String>,
+}
+
+impl PlayItem {
+    pub fn challenge(id: impl Into<String>) -> Self {
+        Self {
+            kind: PlayItemKind::Challenge,
+            id: id.into(),
+            title: None,
+        }
+    }
+
+    pub fn level(id: impl Into<String>) -> Self {
+        Self {
+            kind: PlayItemKind::Level,
+            id: id.into(),
+            title: None,
+        }
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Name shown to the player
+    pub fn title(&self) -> &str {
+        self.title.as_deref().unwrap_or(&self.id)
+    }
+}
+
+/// Result of a finished item, shown until the next item starts
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayResult {
+    pub item: PlayItem,
+    pub score: u32,
+    pub max_score: u32,
+}
+
+/// Resource with the item being played and the items queued after it
+#[derive(Resource, Debug, Clone)]
+pub struct PlayQueue {
+    upcoming: VecDeque<PlayItem>,
+    current: Option<PlayItem>,
+    /// The game was told to start the current item
+    announced: bool,
+    result: Option<(PlayResult, Timer)>,
+    /// Start the next item on its own after a finished one
+    pub auto_advance: bool,
+    /// Seconds the result of a finished item is shown
+    pub interstitial_secs: f32,
+}
+
+impl Default for PlayQueue {
+    fn default() -> Self {
+        Self {
+            upcoming: VecDeque::new(),
+            current: None,
+            announced: true,
+            result: None,
+            auto_advance: true,
+            interstitial_secs: 4.0,
+        }
+    }
+}
+
+impl PlayQueue {
+    /// Add a challenge at the end of the queue
+    pub fn queue_challenge(&mut self, id: impl Into<String>) {
+        self.queue(PlayItem::challenge(id));
+    }
+
+    /// Add a level at the end of the queue
+    pub fn queue_level(&mut self, id: impl Into<String>) {
+        self.queue(PlayItem::level(id));
+    }
+
+    /// Add an item at the end of the queue
+    pub fn queue(&mut self, item: PlayItem) {
+        self.upcoming.push_back(item);
+    }
+
+    /// Add an item to be played before the others, e.g. the daily challenge
+    pub fn queue_first(&mut self, item: PlayItem) {
+        self.upcoming.push_front(item);
+    }
+
+    /// Start the next item, the game receives a [`PlayNext`] for it.
+    ///
+    /// Replaces the current item and the shown result, returns the started
+    /// item or `None` if the queue is empty.
+    pub fn play_next(&mut self) -> Option<&PlayItem> {
+        self.result = None;
+        self.current = self.upcoming.pop_front();
+        self.announced = self.current.is_none();
+        self.current.as_ref()
+    }
+
+    /// Mark the current item as finished and show its result until the
+    /// next item starts
+    pub fn finish_current(&mut self, score: u32, max_score: u32) {
+        let Some(item) = self.current.take() else {
+            return;
+        };
+        let timer = Timer::from_seconds(self.interstitial_secs.max(0.0), TimerMode::Once);
+        self.result = Some((
+            PlayResult {
+                item,
+                score,
+                max_score,
+            },
+            timer,
+        ));
+    }
+
+    /// Item being played
+    pub fn current(&self) -> Option<&PlayItem> {
+        self.current.as_ref()
+    }
+
+    /// Items after the current one, in play order
+    pub fn upcoming(&self) -> impl Iterator<Item = &PlayItem> {
+        self.upcoming.iter()
+    }
+
+    /// Result shown between the items
+    pub fn result(&self) -> Option<&PlayResult> {
+        self.result.as_ref().map(|(result, _)| result)
+    }
+
+    /// Remove an upcoming item
+    pub fn remove(&mut self, index: usize) -> Option<PlayItem> {
+        self.upcoming.remove(index)
+    }
+
+    /// Remove all upcoming items, the current item keeps playing
+    pub fn clear(&mut self) {
+        self.upcoming.clear();
+    }
+
+    /// Number of upcoming items
+    pub fn len(&self) -> usize {
+        self.upcoming.len()
+    }
+
+    /// Whether no items are upcoming
+    pub fn is_empty(&self) -> bool {
+        self.upcoming.is_empty()
+    }
+
+    /// Whether an item is played or its result is shown
+    pub fn is_active(&self) -> bool {
+        self.current.is_some() || self.result.is_some()
+    }
+}
+
+/// Message telling the game to start an item of the [`PlayQueue`]
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct PlayNext {
+    pub item: PlayItem,
+}
+
+/// Message sent by games when a level was finished
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct LevelFinished {
+    pub level_id: String,
+    pub score: u32,
+    pub max_score: u32,
+}
+
+/// System to finish the current item when the game reports its result
+fn finish_queued_items(
+    mut challenges: MessageReader<ChallengeFinished>,
+    mut levels: MessageReader<LevelFinished>,
+    mut queue: ResMut<PlayQueue>,
+) {
+    let finished = challenges
+        .read()
+        .map(|finished| {
+            (
+                PlayItemKind::Challenge,
+                &finished.challenge_id,
+                finished.score,
+                finished.max_score,
+            )
+        })
+        .chain(levels.read().map(|finished| {
+            (
+                PlayItemKind::Level,
+                &finished.level_id,
+                finished.score,
+                finished.max_score,
+            )
+        }));
+
+    for (kind, id, score, max_score) in finished {
+        let is_current = queue
+            .current()
+            .is_some_and(|item| item.kind == kind && item.id == *id);
+        if is_current {
+            queue.finish_current(score, max_score);
+        }
+    }
+}
+
+/// System to start the next item once the result was shown long enough
+fn advance_play_queue(time: Res<Time>, mut queue: ResMut<PlayQueue>) {
+    if !queue.auto_advance {
+        return;
+    }
+    let Some((_, timer)) = queue.result.as_mut() else {
+        return;
+    };
+    if !timer.tick(time.delta()).is_finished() {
+        return;
+    }
+
+    if queue.play_next().is_none() {
+        info!("Play queue finished");
+    }
+}
+
+/// System to tell the game to start the current item
+fn announce_next_item(mut queue: ResMut<PlayQueue>, mut next: MessageWriter<PlayNext>) {
+    if queue.announced {
+        return;
+    }
+    queue.announced = true;
+
+    if let Some(item) = queue.current.clone() {
+        info!("Playing {:?} '{}' from the play queue", item.kind, item.id);
+        next.write(PlayNext { item });
+    }
+}
+
+/// System to show the result of the finished item and what comes next
+fn render_play_queue_interstitial(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    mut queue: ResMut<PlayQueue>,
+) {
+    let Some((result, timer)) = &queue.result else {
+        return;
+    };
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let width = if responsive.is_mobile() {
+        responsive.screen_size.x * 0.9
+    } else {
+        420.0
+    };
+    let next = queue.upcoming.front();
+    let mut continue_clicked = false;
+
+    egui::Area::new(egui::Id::new("konnektoren_play_queue_interstitial"))
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            let frame = egui::Frame {
+                inner_margin: egui::Margin::same(24),
+                corner_radius: egui::CornerRadius::same(theme.radius),
+                fill: theme.base_200,
+                stroke: egui::Stroke::new(1.0, theme.base_300),
+                ..Default::default()
+            };
+
+            frame.show(ui, |ui| {
+                ui.set_width(width);
+                ui.vertical_centered(|ui| {
+                    ResponsiveText::new(
+                        result.item.title(),
+                        ResponsiveFontSize::Large,
+                        theme.primary,
+                    )
+                    .responsive(&responsive)
+                    .strong()
+                    .ui(ui);
+                    ui.add_space(8.0);
+                    ResponsiveText::new(
+                        &format!("Score: {} / {}", result.score, result.max_score),
+                        ResponsiveFontSize::Medium,
+                        theme.base_content,
+                    )
+                    .responsive(&responsive)
+                    .ui(ui);
+                    ui.add_space(16.0);
+
+                    let (up_next, button_text) = match next {
+                        Some(item) => (format!("Up next: {}", item.title()), "Continue"),
+                        None => ("All done!".to_string(), "Finish"),
+                    };
+                    ResponsiveText::new(&up_next, ResponsiveFontSize::Medium, theme.secondary)
+                        .responsive(&responsive)
+                        .ui(ui);
+                    ui.add_space(16.0);
+
+                    let button = ThemedButton::new(button_text, &theme).responsive(&responsive);
+                    continue_clicked = ui.add(button).clicked();
+
+                    // Time left until the next item starts on its own
+                    if queue.auto_advance {
+                        ui.add_space(8.0);
+                        let (rect, _) = ui.allocate_exact_size(
+                            egui::vec2(ui.available_width(), 2.0),
+                            egui::Sense::hover(),
+                        );
+                        let mut left = rect;
+                        left.set_width(rect.width() * timer.fraction_remaining());
+                        ui.painter()
+                            .rect_filled(left, egui::CornerRadius::ZERO, theme.primary);
+                    }
+                });
+            });
+        });
+
+    if continue_clicked {
+        queue.play_next();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    #[test]
+    fn test_play_queue_interstitial() {
+        let mut harness =
+            TestHarness::new().with_plugins((KonnektorenThemePlugin, UIPlugin, PlayQueuePlugin));
+        harness
+            .world_mut()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )));
+
+        {
+            let mut queue = harness.world_mut().resource_mut::<PlayQueue>();
+            queue.interstitial_secs = 1.0;
+            queue.queue_level("level-1");
+            queue.queue_first(PlayItem::challenge("daily").with_title("Daily Challenge"));
+            queue.play_next();
+        }
+        harness.step();
+        assert_eq!(
+            harness.read_messages::<PlayNext>(),
+            vec![PlayNext {
+                item: PlayItem::challenge("daily").with_title("Daily Challenge")
+            }]
+        );
+
+        // Results of other challenges don't finish the current item
+        harness.write_message(ChallengeFinished {
+            challenge_id: "articles".to_string(),
+            score: 3,
+            max_score: 10,
+        });
+        harness.write_message(ChallengeFinished {
+            challenge_id: "daily".to_string(),
+            score: 8,
+            max_score: 10,
+        });
+        harness.run_frames(2);
+        assert!(harness.has_widget("Score: 8 / 10"));
+        assert!(harness.has_widget("Up next: level-1"));
+
+        harness.click("Continue");
+        harness.step();
+        let queue = harness.world().resource::<PlayQueue>();
+        assert_eq!(queue.current(), Some(&PlayItem::level("level-1")));
+        assert!(queue.is_empty());
+
+        // The last result closes on its own
+        harness.write_message(LevelFinished {
+            level_id: "level-1".to_string(),
+            score: 5,
+            max_score: 5,
+        });
+        harness.run_frames(2);
+        assert!(harness.has_widget("All done!"));
+        harness.run_frames(10);
+        assert!(!harness.world().resource::<PlayQueue>().is_active());
+        assert!(!harness.has_widget("All done!"));
+    }
+}
@@ -0,0 +1,556 @@
+use super::responsive::ResponsiveInfo;
+use crate::theme::KonnektorenTheme;
+use bevy_egui::egui;
+
+/// Highest QR code version [`QrCode::encode`] produces, 57×57 modules
+pub const QR_MAX_VERSION: usize = 10;
+
+/// Error correction codewords per block at level M, by version
+const ECC_CODEWORDS_PER_BLOCK: [usize; QR_MAX_VERSION] = [10, 16, 26, 18, 24, 16, 18, 22, 22, 26];
+
+/// Error correction blocks at level M, by version
+const NUM_ERROR_CORRECTION_BLOCKS: [usize; QR_MAX_VERSION] = [1, 1, 1, 2, 2, 4, 4, 4, 5, 5];
+
+/// A QR code in byte mode with medium (~15%) error correction.
+///
+/// Covers the short texts games share, like join codes and links, up to
+/// 213 bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QrCode {
+    version: usize,
+    size: usize,
+    modules: Vec<bool>,
+    function: Vec<bool>,
+}
+
+impl QrCode {
+    /// Encode a text in the smallest fitting version, `None` if it is too long
+    pub fn encode(text: &str) -> Option<Self> {
+        let data = text.as_bytes();
+        let version = (1..=QR_MAX_VERSION).find(|&version| {
+            4 + char_count_bits(version) + data.len() * 8 <= data_codewords(version) * 8
+        })?;
+
+        let mut qr = Self {
+            version,
+            size: version * 4 + 17,
+            modules: vec![],
+            function: vec![],
+        };
+        qr.modules = vec![false; qr.size * qr.size];
+        qr.function = vec![false; qr.size * qr.size];
+
+        qr.draw_function_patterns();
+        let codewords = add_ecc_and_interleave(version, &encode_data(version, data));
+        qr.draw_codewords(&codewords);
+
+        let mask = (0..8)
+            .min_by_key(|&mask| {
+                qr.apply_mask(mask);
+                qr.draw_format_bits(mask);
+                let penalty = qr.penalty_score();
+                qr.apply_mask(mask);
+                penalty
+            })
+            .unwrap_or_default();
+        qr.apply_mask(mask);
+        qr.draw_format_bits(mask);
+        Some(qr)
+    }
+
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    /// Modules per side, without the quiet zone
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Whether the module at column `x` and row `y` is dark
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        x < self.size && y < self.size && self.modules[y * self.size + x]
+    }
+
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        let index = y * self.size + x;
+        self.modules[index] = dark;
+        self.function[index] = true;
+    }
+
+    fn draw_function_patterns(&mut self) {
+        let size = self.size;
+        for i in 0..size {
+            self.set_function(6, i, i % 2 == 0);
+            self.set_function(i, 6, i % 2 == 0);
+        }
+
+        for (x, y) in [(3, 3), (size - 4, 3), (3, size - 4)] {
+            self.draw_finder_pattern(x, y);
+        }
+
+        let positions = alignment_pattern_positions(self.version);
+        let last = positions.len().saturating_sub(1);
+        for (i, &x) in positions.iter().enumerate() {
+            for (j, &y) in positions.iter().enumerate() {
+                // Skip the three corners with finder patterns
+                if (i == 0 && (j == 0 || j == last)) || (i == last && j == 0) {
+                    continue;
+                }
+                self.draw_alignment_pattern(x, y);
+            }
+        }
+
+        // Reserve the format areas, drawn for real once the mask is known
+        self.draw_format_bits(0);
+        self.draw_version();
+    }
+
+    fn draw_finder_pattern(&mut self, x: usize, y: usize) {
+        for dy in -4i32..=4 {
+            for dx in -4i32..=4 {
+                let (xx, yy) = (x as i32 + dx, y as i32 + dy);
+                if (0..self.size as i32).contains(&xx) && (0..self.size as i32).contains(&yy) {
+                    let distance = dx.abs().max(dy.abs());
+                    self.set_function(xx as usize, yy as usize, distance != 2 && distance != 4);
+                }
+            }
+        }
+    }
+
+    fn draw_alignment_pattern(&mut self, x: usize, y: usize) {
+        for dy in -2i32..=2 {
+            for dx in -2i32..=2 {
+                self.set_function(
+                    (x as i32 + dx) as usize,
+                    (y as i32 + dy) as usize,
+                    dx.abs().max(dy.abs()) != 1,
+                );
+            }
+        }
+    }
+
+    fn draw_format_bits(&mut self, mask: u32) {
+        // Level M has the format bits 00
+        let data = mask;
+        let mut remainder = data;
+        for _ in 0..10 {
+            remainder = (remainder << 1) ^ ((remainder >> 9) * 0x537);
+        }
+        let bits = ((data << 10) | remainder) ^ 0x5412;
+        let bit = |i: usize| (bits >> i) & 1 != 0;
+
+        for i in 0..6 {
+            self.set_function(8, i, bit(i));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(i));
+        }
+
+        let size = self.size;
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(i));
+        }
+        // Always dark
+        self.set_function(8, size - 8, true);
+    }
+
+    fn draw_version(&mut self) {
+        if self.version < 7 {
+            return;
+        }
+
+        let version = self.version as u32;
+        let mut remainder = version;
+        for _ in 0..12 {
+            remainder = (remainder << 1) ^ ((remainder >> 11) * 0x1F25);
+        }
+        let bits = (version << 12) | remainder;
+        for i in 0..18 {
+            let dark = (bits >> i) & 1 != 0;
+            let a = self.size - 11 + i % 3;
+            let b = i / 3;
+            self.set_function(a, b, dark);
+            self.set_function(b, a, dark);
+        }
+    }
+
+    /// Place the codewords in the zigzag pattern from the bottom right
+    fn draw_codewords(&mut self, codewords: &[u8]) {
+        let size = self.size;
+        let mut i = 0;
+        let mut right = size - 1;
+        loop {
+            if right == 6 {
+                right = 5;
+            }
+            for vertical in 0..size {
+                for j in 0..2 {
+                    let x = right - j;
+                    let upward = (right + 1) & 2 == 0;
+                    let y = if upward {
+                        size - 1 - vertical
+                    } else {
+                        vertical
+                    };
+                    let index = y * size + x;
+                    if !self.function[index] && i < codewords.len() * 8 {
+                        self.modules[index] = (codewords[i >> 3] >> (7 - (i & 7))) & 1 != 0;
+                        i += 1;
+                    }
+                }
+            }
+            if right < 2 {
+                break;
+            }
+            right -= 2;
+        }
+    }
+
+    /// XOR the data modules with a mask pattern, applying it twice undoes it
+    fn apply_mask(&mut self, mask: u32) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let invert = match mask {
+                    0 => (x + y) % 2 == 0,
+                    1 => y % 2 == 0,
+                    2 => x % 3 == 0,
+                    3 => (x + y) % 3 == 0,
+                    4 => (x / 3 + y / 2) % 2 == 0,
+                    5 => x * y % 2 + x * y % 3 == 0,
+                    6 => (x * y % 2 + x * y % 3) % 2 == 0,
+                    _ => ((x + y) % 2 + x * y % 3) % 2 == 0,
+                };
+                let index = y * self.size + x;
+                if invert && !self.function[index] {
+                    self.modules[index] = !self.modules[index];
+                }
+            }
+        }
+    }
+
+    /// Penalty of the current modules, the mask with the lowest is used
+    fn penalty_score(&self) -> usize {
+        let size = self.size;
+        let mut penalty = 0;
+
+        // Runs of five or more modules of the same color, and patterns
+        // looking like finder patterns, in rows and columns
+        let finder_like = |line: &[bool], start: usize| {
+            const PATTERN: [bool; 7] = [true, false, true, true, true, false, true];
+            let light = |from: usize| (from..from + 4).all(|i| !line[i]);
+            line[start..start + 7] == PATTERN
+                && ((start >= 4 && light(start - 4))
+                    || (start + 11 <= line.len() && light(start + 7)))
+        };
+        for horizontal in [true, false] {
+            for a in 0..size {
+                let line: Vec<bool> = (0..size)
+                    .map(|b| {
+                        if horizontal {
+                            self.get(b, a)
+                        } else {
+                            self.get(a, b)
+                        }
+                    })
+                    .collect();
+                let mut run = 1;
+                for b in 1..=size {
+                    if b < size && line[b] == line[b - 1] {
+                        run += 1;
+                        continue;
+                    }
+                    if run >= 5 {
+                        penalty += run - 2;
+                    }
+                    run = 1;
+                }
+                penalty += (0..=size - 7).filter(|&b| finder_like(&line, b)).count() * 40;
+            }
+        }
+
+        // 2×2 blocks of the same color
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let color = self.get(x, y);
+                if color == self.get(x + 1, y)
+                    && color == self.get(x, y + 1)
+                    && color == self.get(x + 1, y + 1)
+                {
+                    penalty += 3;
+                }
+            }
+        }
+
+        // Balance of dark and light modules
+        let total = size * size;
+        let dark = self.modules.iter().filter(|dark| **dark).count();
+        let k = (dark * 20)
+            .abs_diff(total * 10)
+            .div_ceil(total)
+            .saturating_sub(1);
+        penalty + k * 10
+    }
+}
+
+fn char_count_bits(version: usize) -> usize {
+    if version <= 9 {
+        8
+    } else {
+        16
+    }
+}
+
+fn raw_data_modules(version: usize) -> usize {
+    let mut modules = (16 * version + 128) * version + 64;
+    if version >= 2 {
+        let alignment = version / 7 + 2;
+        modules -= (25 * alignment - 10) * alignment - 55;
+        if version >= 7 {
+            modules -= 36;
+        }
+    }
+    modules
+}
+
+fn data_codewords(version: usize) -> usize {
+    raw_data_modules(version) / 8
+        - ECC_CODEWORDS_PER_BLOCK[version - 1] * NUM_ERROR_CORRECTION_BLOCKS[version - 1]
+}
+
+fn alignment_pattern_positions(version: usize) -> Vec<usize> {
+    if version == 1 {
+        return vec![];
+    }
+    let count = version / 7 + 2;
+    let size = version * 4 + 17;
+    let step = (version * 8 + count * 3 + 5) / (count * 4 - 4) * 2;
+    let mut positions: Vec<usize> = (0..count - 1).map(|i| size - 7 - i * step).collect();
+    positions.push(6);
+    positions.reverse();
+    positions
+}
+
+/// Mode indicator, length, data, terminator and padding as data codewords
+fn encode_data(version: usize, data: &[u8]) -> Vec<u8> {
+    let mut bits: Vec<bool> = vec![];
+    let mut push = |value: usize, count: usize| {
+        bits.extend((0..count).rev().map(|i| (value >> i) & 1 != 0));
+    };
+    push(0b0100, 4);
+    push(data.len(), char_count_bits(version));
+    for byte in data {
+        push(*byte as usize, 8);
+    }
+
+    let capacity = data_codewords(version) * 8;
+    let terminator = (capacity - bits.len()).min(4);
+    bits.extend(std::iter::repeat_n(false, terminator));
+    bits.extend(std::iter::repeat_n(false, (8 - bits.len() % 8) % 8));
+
+    let mut codewords: Vec<u8> = bits
+        .chunks(8)
+        .map(|byte| byte.iter().fold(0, |acc, bit| (acc << 1) | *bit as u8))
+        .collect();
+    for pad in [0xEC, 0x11].into_iter().cycle() {
+        if codewords.len() >= capacity / 8 {
+            break;
+        }
+        codewords.push(pad);
+    }
+    codewords
+}
+
+/// Split the data into blocks, add the error correction of each block and
+/// interleave them
+fn add_ecc_and_interleave(version: usize, data: &[u8]) -> Vec<u8> {
+    let num_blocks = NUM_ERROR_CORRECTION_BLOCKS[version - 1];
+    let ecc_len = ECC_CODEWORDS_PER_BLOCK[version - 1];
+    let raw_codewords = raw_data_modules(version) / 8;
+    let num_short_blocks = num_blocks - raw_codewords % num_blocks;
+    let short_block_len = raw_codewords / num_blocks;
+
+    let divisor = reed_solomon_divisor(ecc_len);
+    let mut blocks: Vec<Vec<u8>> = vec![];
+    let mut offset = 0;
+    for i in 0..num_blocks {
+        let len = short_block_len - ecc_len + usize::from(i >= num_short_blocks);
+        let mut block = data[offset..offset + len].to_vec();
+        offset += len;
+        let ecc = reed_solomon_remainder(&block, &divisor);
+        if i < num_short_blocks {
+            // Placeholder so all blocks have the same length
+            block.push(0);
+        }
+        block.extend(ecc);
+        blocks.push(block);
+    }
+
+    let mut result = Vec::with_capacity(raw_codewords);
+    for i in 0..blocks[0].len() {
+        for (j, block) in blocks.iter().enumerate() {
+            if i != short_block_len - ecc_len || j >= num_short_blocks {
+                result.push(block[i]);
+            }
+        }
+    }
+    result
+}
+
+/// Multiply in GF(2^8) modulo x^8 + x^4 + x^3 + x^2 + 1
+fn gf_multiply(x: u8, y: u8) -> u8 {
+    let mut z: u16 = 0;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x11D);
+        z ^= ((y as u16 >> i) & 1) * x as u16;
+    }
+    z as u8
+}
+
+fn reed_solomon_divisor(degree: usize) -> Vec<u8> {
+    let mut result = vec![0; degree];
+    result[degree - 1] = 1;
+    let mut root = 1;
+    for _ in 0..degree {
+        for j in 0..degree {
+            result[j] = gf_multiply(result[j], root);
+            if j + 1 < degree {
+                result[j] ^= result[j + 1];
+            }
+        }
+        root = gf_multiply(root, 0x02);
+    }
+    result
+}
+
+fn reed_solomon_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut result = vec![0; divisor.len()];
+    for byte in data {
+        let factor = byte ^ result.remove(0);
+        result.push(0);
+        for (value, coefficient) in result.iter_mut().zip(divisor) {
+            *value ^= gf_multiply(*coefficient, factor);
+        }
+    }
+    result
+}
+
+/// Widget drawing a QR code, e.g. of a join code or link.
+///
+/// The modules are always black on white with the quiet zone scanners need,
+/// also with a dark theme. Texts too long for a QR code are shown as an empty
+/// frame.
+pub struct QrCodeWidget<'a> {
+    pub text: &'a str,
+    pub theme: &'a KonnektorenTheme,
+    pub size: f32,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> QrCodeWidget<'a> {
+    pub fn new(text: &'a str, theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            text,
+            theme,
+            size: 200.0,
+            responsive_info: None,
+        }
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+}
+
+impl<'a> egui::Widget for QrCodeWidget<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let size = match self.responsive_info {
+            Some(responsive_info) if responsive_info.is_mobile() => {
+                self.size.min(ui.available_width() * 0.8)
+            }
+            _ => self.size,
+        };
+        let (rect, response) = ui.allocate_exact_size(egui::vec2(size, size), egui::Sense::hover());
+        let text = self.text.to_string();
+        response.widget_info(|| {
+            egui::WidgetInfo::labeled(egui::WidgetType::Other, true, format!("QR code: {}", text))
+        });
+
+        if !ui.is_rect_visible(rect) {
+            return response;
+        }
+
+        let painter = ui.painter();
+        painter.rect_filled(rect, 4.0, egui::Color32::WHITE);
+
+        let Some(qr) = QrCode::encode(self.text) else {
+            painter.rect_stroke(
+                rect,
+                4.0,
+                egui::Stroke::new(1.0, self.theme.error),
+                egui::StrokeKind::Inside,
+            );
+            return response;
+        };
+
+        // Four modules of quiet zone on each side
+        let modules = qr.size() + 8;
+        let module = (size / modules as f32).floor().max(1.0);
+        let origin = rect.center() - egui::Vec2::splat(module * qr.size() as f32 / 2.0);
+        for y in 0..qr.size() {
+            for x in 0..qr.size() {
+                if qr.get(x, y) {
+                    let min = origin + egui::vec2(x as f32, y as f32) * module;
+                    painter.rect_filled(
+                        egui::Rect::from_min_size(min, egui::Vec2::splat(module)),
+                        0.0,
+                        egui::Color32::BLACK,
+                    );
+                }
+            }
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reed_solomon() {
+        // "HELLO WORLD" as version 1-M, the common worked example
+        let data = [
+            32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17,
+        ];
+        let ecc = reed_solomon_remainder(&data, &reed_solomon_divisor(10));
+        assert_eq!(ecc, [196, 35, 39, 119, 235, 215, 231, 226, 93, 23]);
+    }
+
+    #[test]
+    fn test_qr_code_versions() {
+        let qr = QrCode::encode("K7QX2M").unwrap();
+        assert_eq!((qr.version(), qr.size()), (1, 21));
+        // Finder pattern corners and the always dark module
+        assert!(qr.get(0, 0) && qr.get(20, 0) && qr.get(0, 20));
+        assert!(qr.get(8, 13));
+        assert!(!qr.get(7, 7));
+
+        let url = "https://konnektoren.help/classroom/join?code=K7QX2M";
+        assert_eq!(QrCode::encode(url).unwrap().version(), 4);
+        assert!(QrCode::encode(&"x".repeat(214)).is_none());
+    }
+}
@@ -1,5 +1,7 @@
+use crate::theme::{readable_case, AccessibilityOptions, MIN_HIT_TARGET};
 use bevy::prelude::*;
 use bevy_egui::egui;
+use std::borrow::Cow;
 
 /// Resource that tracks the current screen size and provides responsive utilities
 #[derive(Resource)]
@@ -8,6 +10,15 @@ pub struct ResponsiveInfo {
     pub device_type: DeviceType,
     pub orientation: Orientation,
     pub scale_factor: f32,
+    /// The user prefers reduced motion, animations should be toned down.
+    /// Detected from `prefers-reduced-motion` in the browser.
+    pub reduced_motion: bool,
+    /// High visibility mode of the [`AccessibilityOptions`], with large hit
+    /// targets and more room between stacked buttons on mobile
+    pub high_visibility: bool,
+    /// Dyslexia-friendly typography of the [`AccessibilityOptions`], with
+    /// wider letter and line spacing and no all-caps labels
+    pub dyslexia_friendly: bool,
 }
 
 impl Default for ResponsiveInfo {
@@ -18,6 +29,9 @@ impl Default for ResponsiveInfo {
             device_type: DeviceType::Desktop,
             orientation: Orientation::Landscape,
             scale_factor: 1.0,
+            reduced_motion: prefers_reduced_motion(),
+            high_visibility: false,
+            dyslexia_friendly: false,
         };
         // Update device type based on default screen size
         info.update(info.screen_size, info.scale_factor);
@@ -143,6 +157,8 @@ impl ResponsiveInfo {
     /// Get responsive spacing
     pub fn spacing(&self, spacing_type: ResponsiveSpacing) -> f32 {
         let base_scale = match (self.device_type, self.orientation) {
+            // Keep stacked buttons apart on small screens
+            (DeviceType::Mobile, _) if self.high_visibility => 1.0,
             (DeviceType::Mobile, Orientation::Landscape) => 0.6,
             (DeviceType::Mobile, Orientation::Portrait) => 0.7,
             (DeviceType::Tablet, _) => 0.85,
@@ -262,13 +278,96 @@ impl ResponsiveInfo {
     pub fn is_portrait(&self) -> bool {
         self.orientation == Orientation::Portrait
     }
+
+    /// Minimum height of buttons and other interactive elements
+    pub fn min_hit_target(&self) -> f32 {
+        if self.is_mobile() || self.high_visibility {
+            MIN_HIT_TARGET
+        } else {
+            32.0
+        }
+    }
+
+    /// Extra space between letters for a font size
+    pub fn letter_spacing(&self, font_size: f32) -> f32 {
+        if self.dyslexia_friendly {
+            font_size * 0.12
+        } else {
+            0.0
+        }
+    }
+
+    /// Line height for a font size, `None` for the font default
+    pub fn line_height(&self, font_size: f32) -> Option<f32> {
+        self.dyslexia_friendly.then_some(font_size * 1.5)
+    }
+
+    /// Label text to display, all-caps labels are shown in sentence case
+    /// with dyslexia-friendly typography
+    pub fn label<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if self.dyslexia_friendly {
+            readable_case(text)
+        } else {
+            Cow::Borrowed(text)
+        }
+    }
+
+    /// Apply the letter and line spacing to a text
+    pub fn readable_text(&self, text: egui::RichText, font_size: f32) -> egui::RichText {
+        if !self.dyslexia_friendly {
+            return text;
+        }
+        text.extra_letter_spacing(self.letter_spacing(font_size))
+            .line_height(self.line_height(font_size))
+    }
+
+    /// Check if animations should be toned down
+    pub fn prefers_reduced_motion(&self) -> bool {
+        self.reduced_motion
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn prefers_reduced_motion() -> bool {
+    browser::match_media("(prefers-reduced-motion: reduce)").is_some_and(|query| query.matches())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn prefers_reduced_motion() -> bool {
+    false
+}
+
+#[cfg(target_arch = "wasm32")]
+mod browser {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        pub type MediaQueryList;
+
+        #[wasm_bindgen(js_namespace = window, js_name = matchMedia)]
+        pub fn match_media(query: &str) -> Option<MediaQueryList>;
+
+        #[wasm_bindgen(method, getter)]
+        pub fn matches(this: &MediaQueryList) -> bool;
+    }
 }
 
 /// System to update responsive info when window size changes
 pub fn update_responsive_info(
     mut responsive_info: ResMut<ResponsiveInfo>,
     windows: Query<&Window>,
+    options: Option<Res<AccessibilityOptions>>,
 ) {
+    if let Some(options) = options {
+        if responsive_info.high_visibility != options.high_visibility {
+            responsive_info.high_visibility = options.high_visibility;
+        }
+        if responsive_info.dyslexia_friendly != options.dyslexia_friendly {
+            responsive_info.dyslexia_friendly = options.dyslexia_friendly;
+        }
+    }
+
     if let Ok(window) = windows.single() {
         let window_size = Vec2::new(window.width(), window.height());
         responsive_info.update(window_size, window.scale_factor());
@@ -0,0 +1,55 @@
+use bevy_egui::egui;
+use std::hash::Hash;
+
+/// Add contents in a child ui whose widget ids are salted with `id_salt`.
+///
+/// Widgets without an explicit id get one from their position in the ui
+/// tree, so two screens or two rows showing the same widgets can end up
+/// with the same ids. Screens scope their content by their entity and a tag,
+/// repeated sections by a tag and their index:
+///
+/// ```ignore
+/// egui::CentralPanel::default().show(ctx, |ui| {
+///     scoped_ui(ui, (entity, "about"), |ui| {
+///         for (index, player) in players.iter().enumerate() {
+///             scoped_ui(ui, ("player", index), |ui| {
+///                 ui.label(&player.name);
+///             });
+///         }
+///     })
+/// });
+/// ```
+pub fn scoped_ui<R>(
+    ui: &mut egui::Ui,
+    id_salt: impl Hash,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> R {
+    ui.push_id(id_salt, add_contents).inner
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scoped_ui_separates_ids() {
+        let ctx = egui::Context::default();
+        let mut ids = Vec::new();
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                for index in 0..2 {
+                    scoped_ui(ui, ("row", index), |ui| {
+                        ids.push(ui.make_persistent_id("button"));
+                    });
+                }
+                scoped_ui(ui, ("row", 0), |ui| {
+                    ids.push(ui.make_persistent_id("button"));
+                });
+            });
+        });
+
+        assert_ne!(ids[0], ids[1]);
+        // The same salt in the same parent gives the same ids
+        assert_eq!(ids[0], ids[2]);
+    }
+}
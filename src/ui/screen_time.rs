@@ -0,0 +1,407 @@
+use super::{
+    responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+    timers::format_timer,
+    widgets::{ResponsiveText, ThemedButton},
+};
+#[cfg(feature = "settings")]
+use crate::settings::ParentalControls;
+use crate::theme::KonnektorenTheme;
+use bevy::prelude::*;
+use bevy_egui::{
+    egui::{self, Widget},
+    EguiContexts, EguiPrimaryContextPass,
+};
+use std::time::Duration;
+
+/// Plugin reminding players to take a break after playing for a while.
+///
+/// Continuous play time is tracked while the window has focus. Every
+/// [`reminder_interval`](ScreenTimeConfig::reminder_interval) a break
+/// reminder dialog is shown. The player can keep playing or take a break.
+/// With [`enforce_lock`](ScreenTimeConfig::enforce_lock), or once the session
+/// time limit of the [`ParentalControls`] is reached, the game is locked for
+/// the [`break_duration`](ScreenTimeConfig::break_duration) instead. Being
+/// away from the window for a break resets the play time as well.
+///
+/// ```ignore
+/// app.add_plugins(ScreenTimePlugin::new(Duration::from_secs(20 * 60)).enforce_lock(true));
+/// ```
+#[derive(Default)]
+pub struct ScreenTimePlugin {
+    pub config: ScreenTimeConfig,
+}
+
+impl ScreenTimePlugin {
+    pub fn new(reminder_interval: Duration) -> Self {
+        Self {
+            config: ScreenTimeConfig {
+                reminder_interval: Some(reminder_interval),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn break_duration(mut self, duration: Duration) -> Self {
+        self.config.break_duration = duration;
+        self
+    }
+
+    pub fn enforce_lock(mut self, enforce: bool) -> Self {
+        self.config.enforce_lock = enforce;
+        self
+    }
+}
+
+impl Plugin for ScreenTimePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.config.clone())
+            .init_resource::<ScreenTime>()
+            .add_message::<ScreenTimeEvent>()
+            .add_systems(Update, update_screen_time)
+            .add_systems(EguiPrimaryContextPass, render_break_reminder);
+    }
+}
+
+/// Reminder intervals and texts of the [`ScreenTimePlugin`]
+#[derive(Resource, Debug, Clone)]
+pub struct ScreenTimeConfig {
+    /// Play time between reminders, no reminders if `None`
+    pub reminder_interval: Option<Duration>,
+    /// How long a break lasts
+    pub break_duration: Duration,
+    /// Lock the game for a break instead of only reminding
+    pub enforce_lock: bool,
+    pub title: String,
+    /// Reminder text, `{minutes}` is replaced with the play time
+    pub message: String,
+    /// Text while the game is locked for a break
+    pub locked_message: String,
+    pub continue_text: String,
+    pub break_text: String,
+    /// Button ending a voluntary break early
+    pub resume_text: String,
+}
+
+impl Default for ScreenTimeConfig {
+    fn default() -> Self {
+        Self {
+            reminder_interval: Some(Duration::from_secs(20 * 60)),
+            break_duration: Duration::from_secs(5 * 60),
+            enforce_lock: false,
+            title: "Time for a break!".to_string(),
+            message: "You have been learning for {minutes} minutes. Great job! \
+                      Stretch, drink some water and rest your eyes."
+                .to_string(),
+            locked_message: "Your brain needs a little rest to remember everything. \
+                             Come back when the timer is done!"
+                .to_string(),
+            continue_text: "Keep playing".to_string(),
+            break_text: "Take a break".to_string(),
+            resume_text: "I'm back!".to_string(),
+        }
+    }
+}
+
+/// What the player is doing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScreenTimeState {
+    #[default]
+    Playing,
+    /// The break reminder is shown
+    Reminder,
+    /// On a break, the game is locked while `enforced`
+    OnBreak { remaining: Duration, enforced: bool },
+}
+
+/// Resource with the continuous play time
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ScreenTime {
+    played: Duration,
+    since_reminder: Duration,
+    /// Time the window was not focused
+    away: Duration,
+    state: ScreenTimeState,
+}
+
+impl ScreenTime {
+    /// Continuous play time since the last break
+    pub fn played(&self) -> Duration {
+        self.played
+    }
+
+    pub fn state(&self) -> ScreenTimeState {
+        self.state
+    }
+
+    /// Whether the game is locked for an enforced break
+    pub fn is_locked(&self) -> bool {
+        matches!(self.state, ScreenTimeState::OnBreak { enforced: true, .. })
+    }
+
+    /// Start a break, e.g. from a pause menu
+    pub fn start_break(&mut self, duration: Duration, enforced: bool) {
+        self.state = ScreenTimeState::OnBreak {
+            remaining: duration,
+            enforced,
+        };
+    }
+
+    /// Dismiss the reminder and keep playing until the next one
+    pub fn snooze(&mut self) {
+        self.since_reminder = Duration::ZERO;
+        self.state = ScreenTimeState::Playing;
+    }
+
+    /// Reset the play time after a break
+    pub fn reset(&mut self) {
+        self.played = Duration::ZERO;
+        self.since_reminder = Duration::ZERO;
+        self.away = Duration::ZERO;
+        self.state = ScreenTimeState::Playing;
+    }
+}
+
+/// Event sent when the screen time state changes
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScreenTimeEvent {
+    /// The break reminder was shown after the given play time
+    Reminder { played: Duration },
+    /// A break started, `enforced` breaks lock the game
+    BreakStarted { duration: Duration, enforced: bool },
+    /// The break is over, the play time starts again
+    BreakEnded,
+}
+
+/// System to track the play time and start reminders and breaks
+#[allow(clippy::too_many_arguments)]
+fn update_screen_time(
+    time: Res<Time<Real>>,
+    config: Res<ScreenTimeConfig>,
+    mut screen_time: ResMut<ScreenTime>,
+    mut events: MessageWriter<ScreenTimeEvent>,
+    windows: Query<&Window>,
+    #[cfg(feature = "settings")] parental_controls: Option<Res<ParentalControls>>,
+) {
+    let delta = time.delta();
+    #[cfg(feature = "settings")]
+    let session_limit = parental_controls.and_then(|controls| controls.session_limit());
+    #[cfg(not(feature = "settings"))]
+    let session_limit: Option<Duration> = None;
+
+    match screen_time.state {
+        ScreenTimeState::Playing | ScreenTimeState::Reminder => {
+            if !windows.iter().all(|window| window.focused) {
+                // Being away long enough counts as a break
+                screen_time.away += delta;
+                if screen_time.away >= config.break_duration {
+                    screen_time.reset();
+                }
+                return;
+            }
+            screen_time.away = Duration::ZERO;
+            screen_time.played += delta;
+            screen_time.since_reminder += delta;
+
+            let limit_reached = session_limit.is_some_and(|limit| screen_time.played >= limit);
+            let reminder_due = screen_time.state == ScreenTimeState::Playing
+                && config
+                    .reminder_interval
+                    .is_some_and(|interval| screen_time.since_reminder >= interval);
+
+            if limit_reached || (reminder_due && config.enforce_lock) {
+                screen_time.start_break(config.break_duration, true);
+                events.write(ScreenTimeEvent::BreakStarted {
+                    duration: config.break_duration,
+                    enforced: true,
+                });
+            } else if reminder_due {
+                screen_time.state = ScreenTimeState::Reminder;
+                events.write(ScreenTimeEvent::Reminder {
+                    played: screen_time.played,
+                });
+            }
+        }
+        ScreenTimeState::OnBreak {
+            remaining,
+            enforced,
+        } => {
+            let remaining = remaining.saturating_sub(delta);
+            if remaining.is_zero() {
+                screen_time.reset();
+                events.write(ScreenTimeEvent::BreakEnded);
+            } else {
+                screen_time.state = ScreenTimeState::OnBreak {
+                    remaining,
+                    enforced,
+                };
+            }
+        }
+    }
+}
+
+/// Button clicked in the break dialog
+enum BreakAction {
+    Continue,
+    TakeBreak,
+    Resume,
+}
+
+/// System to show the break reminder or lock dialog
+fn render_break_reminder(
+    mut contexts: EguiContexts,
+    theme: Res<KonnektorenTheme>,
+    responsive: Res<ResponsiveInfo>,
+    config: Res<ScreenTimeConfig>,
+    mut screen_time: ResMut<ScreenTime>,
+    mut events: MessageWriter<ScreenTimeEvent>,
+) {
+    if screen_time.state == ScreenTimeState::Playing {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let width = if responsive.is_mobile() {
+        responsive.screen_size.x * 0.85
+    } else {
+        380.0
+    };
+    let mut action = None;
+
+    egui::Modal::new(egui::Id::new("konnektoren_break_reminder"))
+        .backdrop_color(egui::Color32::from_black_alpha(160))
+        .frame(egui::Frame {
+            inner_margin: egui::Margin::same(24),
+            corner_radius: egui::CornerRadius::same(theme.radius),
+            fill: theme.base_100,
+            stroke: egui::Stroke::new(2.0, theme.primary),
+            ..Default::default()
+        })
+        .show(ctx, |ui| {
+            ui.set_width(width);
+            ui.vertical_centered(|ui| {
+                let on_break = match screen_time.state {
+                    ScreenTimeState::OnBreak { remaining, .. } => Some(remaining),
+                    _ => None,
+                };
+
+                ui.label(
+                    egui::RichText::new(if on_break.is_some() { "🌙" } else { "⏰" }).size(48.0),
+                );
+                ResponsiveText::new(&config.title, ResponsiveFontSize::Header, theme.primary)
+                    .responsive(&responsive)
+                    .strong()
+                    .ui(ui);
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+
+                let message = if on_break.is_some() && screen_time.is_locked() {
+                    config.locked_message.clone()
+                } else {
+                    config.message.replace(
+                        "{minutes}",
+                        &(screen_time.played.as_secs() / 60).to_string(),
+                    )
+                };
+                ResponsiveText::new(&message, ResponsiveFontSize::Medium, theme.base_content)
+                    .responsive(&responsive)
+                    .ui(ui);
+                ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+
+                if let Some(remaining) = on_break {
+                    ResponsiveText::new(
+                        &format_timer(remaining),
+                        ResponsiveFontSize::Title,
+                        theme.accent,
+                    )
+                    .responsive(&responsive)
+                    .strong()
+                    .ui(ui);
+
+                    if !screen_time.is_locked() {
+                        ui.add_space(responsive.spacing(ResponsiveSpacing::Medium));
+                        if ui
+                            .add(
+                                ThemedButton::new(&config.resume_text, &theme)
+                                    .responsive(&responsive),
+                            )
+                            .clicked()
+                        {
+                            action = Some(BreakAction::Resume);
+                        }
+                    }
+                } else {
+                    if ui
+                        .add(ThemedButton::new(&config.break_text, &theme).responsive(&responsive))
+                        .clicked()
+                    {
+                        action = Some(BreakAction::TakeBreak);
+                    }
+                    ui.add_space(responsive.spacing(ResponsiveSpacing::Small));
+                    let keep_playing = ThemedButton::new(&config.continue_text, &theme)
+                        .responsive(&responsive)
+                        .with_style(|button| {
+                            button
+                                .fill(theme.base_200)
+                                .stroke(egui::Stroke::new(1.0, theme.base_300))
+                        });
+                    if ui.add(keep_playing).clicked() {
+                        action = Some(BreakAction::Continue);
+                    }
+                }
+            });
+        });
+
+    match action {
+        Some(BreakAction::Continue) => screen_time.snooze(),
+        Some(BreakAction::TakeBreak) => {
+            screen_time.start_break(config.break_duration, false);
+            events.write(ScreenTimeEvent::BreakStarted {
+                duration: config.break_duration,
+                enforced: false,
+            });
+        }
+        Some(BreakAction::Resume) => {
+            screen_time.reset();
+            events.write(ScreenTimeEvent::BreakEnded);
+        }
+        None => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin, ui::UIPlugin};
+    use bevy::time::TimeUpdateStrategy;
+
+    #[test]
+    fn test_break_reminder() {
+        let mut harness = TestHarness::new().with_plugins((
+            KonnektorenThemePlugin,
+            UIPlugin,
+            ScreenTimePlugin::new(Duration::from_secs(1)).break_duration(Duration::from_secs(2)),
+        ));
+        harness
+            .world_mut()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                300,
+            )));
+        harness.run_frames(5);
+        assert_eq!(
+            harness.world().resource::<ScreenTime>().state(),
+            ScreenTimeState::Reminder
+        );
+        harness.run_frames(2);
+        assert!(harness.has_widget("Time for a break!"));
+
+        harness.click("Take a break");
+        harness.step();
+        assert!(harness.has_widget("I'm back!"));
+
+        harness.run_frames(8);
+        let screen_time = harness.world().resource::<ScreenTime>();
+        assert_eq!(screen_time.state(), ScreenTimeState::Playing);
+        assert!(screen_time.played() < Duration::from_secs(1));
+    }
+}
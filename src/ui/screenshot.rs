@@ -0,0 +1,354 @@
+use super::notifications::Notification;
+use crate::theme::KonnektorenTheme;
+use bevy::{
+    prelude::*,
+    render::view::screenshot::{Screenshot, ScreenshotCaptured},
+};
+use image::{ImageFormat, RgbaImage};
+use std::path::PathBuf;
+
+/// Plugin capturing the current frame to PNG.
+///
+/// On native the file is written to the configured directory, in the browser
+/// it is offered as download. The screenshot can be framed in the theme's
+/// primary color and marked with a watermark image. Press the key (F12 by
+/// default) or send [`TakeScreenshot`] to capture.
+pub struct ScreenshotPlugin {
+    /// Key capturing a screenshot
+    pub key: Option<KeyCode>,
+    pub config: ScreenshotConfig,
+}
+
+impl Default for ScreenshotPlugin {
+    fn default() -> Self {
+        Self {
+            key: Some(KeyCode::F12),
+            config: ScreenshotConfig::default(),
+        }
+    }
+}
+
+impl ScreenshotPlugin {
+    pub fn with_key(mut self, key: Option<KeyCode>) -> Self {
+        self.key = key;
+        self
+    }
+
+    pub fn with_config(mut self, config: ScreenshotConfig) -> Self {
+        self.config = config;
+        self
+    }
+}
+
+impl Plugin for ScreenshotPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.config.clone())
+            .insert_resource(ScreenshotKey(self.key))
+            .add_message::<TakeScreenshot>()
+            .add_message::<ScreenshotTaken>()
+            .add_message::<Notification>()
+            .add_observer(save_screenshot)
+            .add_systems(
+                Update,
+                (
+                    screenshot_key_input,
+                    request_screenshots,
+                    notify_screenshot_taken,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Configuration of captured screenshots
+#[derive(Resource, Debug, Clone)]
+pub struct ScreenshotConfig {
+    /// Directory the screenshots are written to on native
+    pub directory: PathBuf,
+    /// File name prefix, followed by a timestamp
+    pub file_prefix: String,
+    /// Width of the frame in the theme's primary color, 0 for no frame
+    pub frame_width: u32,
+    /// Image drawn into the bottom right corner
+    pub watermark: Option<Handle<Image>>,
+    /// Opacity of the watermark
+    pub watermark_opacity: f32,
+    /// Show a notification after a screenshot was taken
+    pub notify: bool,
+}
+
+impl Default for ScreenshotConfig {
+    fn default() -> Self {
+        Self {
+            directory: PathBuf::from("screenshots"),
+            file_prefix: "konnektoren".to_string(),
+            frame_width: 0,
+            watermark: None,
+            watermark_opacity: 0.8,
+            notify: true,
+        }
+    }
+}
+
+impl ScreenshotConfig {
+    pub fn with_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = directory.into();
+        self
+    }
+
+    pub fn with_file_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.file_prefix = prefix.into();
+        self
+    }
+
+    pub fn with_frame(mut self, width: u32) -> Self {
+        self.frame_width = width;
+        self
+    }
+
+    pub fn with_watermark(mut self, watermark: Handle<Image>, opacity: f32) -> Self {
+        self.watermark = Some(watermark);
+        self.watermark_opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn without_notification(mut self) -> Self {
+        self.notify = false;
+        self
+    }
+}
+
+/// Key bound to taking a screenshot
+#[derive(Resource)]
+struct ScreenshotKey(Option<KeyCode>);
+
+/// Message requesting a screenshot of the primary window
+#[derive(Message, Debug, Clone, Default)]
+pub struct TakeScreenshot;
+
+/// Message sent after a screenshot was saved
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct ScreenshotTaken {
+    /// Written file on native, name of the downloaded file in the browser
+    pub path: String,
+}
+
+/// File a pending screenshot is saved to
+#[derive(Component, Debug, Clone)]
+struct ScreenshotFile(PathBuf);
+
+fn screenshot_key_input(
+    key: Res<ScreenshotKey>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mut requests: MessageWriter<TakeScreenshot>,
+) {
+    let (Some(key), Some(keys)) = (key.0, keys) else {
+        return;
+    };
+
+    if keys.just_pressed(key) {
+        requests.write(TakeScreenshot);
+    }
+}
+
+/// System to spawn a [`Screenshot`] for every request
+fn request_screenshots(
+    mut commands: Commands,
+    mut requests: MessageReader<TakeScreenshot>,
+    config: Res<ScreenshotConfig>,
+) {
+    // Several requests in one frame capture the same image
+    if requests.read().last().is_none() {
+        return;
+    }
+
+    let file_name = format!(
+        "{}-{}.png",
+        config.file_prefix,
+        chrono::Local::now().format("%Y%m%d-%H%M%S%.3f")
+    );
+    commands.spawn((
+        Name::new("Screenshot"),
+        Screenshot::primary_window(),
+        ScreenshotFile(config.directory.join(file_name)),
+    ));
+}
+
+/// Observer to compose and save captured screenshots requested by this plugin
+fn save_screenshot(
+    captured: On<ScreenshotCaptured>,
+    files: Query<&ScreenshotFile>,
+    config: Res<ScreenshotConfig>,
+    theme: Option<Res<KonnektorenTheme>>,
+    images: Res<Assets<Image>>,
+    mut taken: MessageWriter<ScreenshotTaken>,
+) {
+    let Ok(ScreenshotFile(path)) = files.get(captured.entity) else {
+        return;
+    };
+
+    let mut image = match captured.image.clone().try_into_dynamic() {
+        Ok(image) => image.to_rgba8(),
+        Err(err) => {
+            error!("Cannot convert screenshot: {}", err);
+            return;
+        }
+    };
+
+    let frame = (config.frame_width > 0).then(|| {
+        let color = theme.map(|theme| theme.primary).unwrap_or_default();
+        (config.frame_width, color.to_array())
+    });
+    let watermark = config
+        .watermark
+        .as_ref()
+        .and_then(|handle| images.get(handle))
+        .and_then(|watermark| watermark.clone().try_into_dynamic().ok())
+        .map(|watermark| watermark.to_rgba8());
+    compose_screenshot(
+        &mut image,
+        frame,
+        watermark
+            .as_ref()
+            .map(|watermark| (watermark, config.watermark_opacity)),
+    );
+
+    // Drop the alpha channel, it holds brightness values with HDR
+    let image = image::DynamicImage::ImageRgba8(image).to_rgb8();
+    let mut bytes = std::io::Cursor::new(Vec::new());
+    if let Err(err) = image.write_to(&mut bytes, ImageFormat::Png) {
+        error!("Cannot encode screenshot: {}", err);
+        return;
+    }
+
+    match write_screenshot(path, bytes.get_ref()) {
+        Ok(path) => {
+            info!("Screenshot saved to {}", path);
+            taken.write(ScreenshotTaken { path });
+        }
+        Err(err) => error!("Cannot save screenshot to {}: {}", path.display(), err),
+    }
+}
+
+/// Draw the frame and the watermark into the screenshot
+pub fn compose_screenshot(
+    image: &mut RgbaImage,
+    frame: Option<(u32, [u8; 4])>,
+    watermark: Option<(&RgbaImage, f32)>,
+) {
+    let (width, height) = image.dimensions();
+    let frame_width = frame.map_or(0, |(frame_width, _)| frame_width);
+
+    if let Some((frame_width, color)) = frame {
+        for (x, y, pixel) in image.enumerate_pixels_mut() {
+            if x < frame_width
+                || y < frame_width
+                || x + frame_width >= width
+                || y + frame_width >= height
+            {
+                pixel.0 = color;
+            }
+        }
+    }
+
+    if let Some((watermark, opacity)) = watermark {
+        let margin = frame_width + 16;
+        let (mark_width, mark_height) = watermark.dimensions();
+        let (Some(left), Some(top)) = (
+            width.checked_sub(mark_width + margin),
+            height.checked_sub(mark_height + margin),
+        ) else {
+            return;
+        };
+
+        for (x, y, mark) in watermark.enumerate_pixels() {
+            let alpha = mark.0[3] as f32 / 255.0 * opacity;
+            let pixel = image.get_pixel_mut(left + x, top + y);
+            for channel in 0..3 {
+                let blended =
+                    pixel.0[channel] as f32 * (1.0 - alpha) + mark.0[channel] as f32 * alpha;
+                pixel.0[channel] = blended.round() as u8;
+            }
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write_screenshot(path: &std::path::Path, bytes: &[u8]) -> std::io::Result<String> {
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory)?;
+    }
+    std::fs::write(path, bytes)?;
+    Ok(path.display().to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn write_screenshot(path: &std::path::Path, bytes: &[u8]) -> std::io::Result<String> {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "screenshot.png".to_string());
+    browser::download_png(&file_name, bytes);
+    Ok(file_name)
+}
+
+#[cfg(target_arch = "wasm32")]
+mod browser {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen(inline_js = "
+export function download_png(name, bytes) {
+    const url = URL.createObjectURL(new Blob([bytes], { type: 'image/png' }));
+    const link = document.createElement('a');
+    link.href = url;
+    link.download = name;
+    link.click();
+    URL.revokeObjectURL(url);
+}")]
+    extern "C" {
+        pub fn download_png(name: &str, bytes: &[u8]);
+    }
+}
+
+/// System to show a notification for taken screenshots
+fn notify_screenshot_taken(
+    config: Res<ScreenshotConfig>,
+    mut taken: MessageReader<ScreenshotTaken>,
+    mut notifications: MessageWriter<Notification>,
+) {
+    for screenshot in taken.read() {
+        if config.notify {
+            notifications.write(Notification::success(
+                "Screenshot saved",
+                screenshot.path.clone(),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compose_screenshot() {
+        let mut image = RgbaImage::from_pixel(64, 48, image::Rgba([0, 0, 0, 255]));
+        let watermark = RgbaImage::from_pixel(4, 4, image::Rgba([255, 255, 255, 255]));
+
+        compose_screenshot(
+            &mut image,
+            Some((2, [255, 140, 0, 255])),
+            Some((&watermark, 0.5)),
+        );
+
+        assert_eq!(image.get_pixel(0, 0).0, [255, 140, 0, 255]);
+        assert_eq!(image.get_pixel(63, 47).0, [255, 140, 0, 255]);
+        assert_eq!(image.get_pixel(10, 10).0, [0, 0, 0, 255]);
+        // Watermark in the bottom right corner, inside the frame
+        assert_eq!(
+            image.get_pixel(64 - 18 - 1, 48 - 18 - 1).0,
+            [128, 128, 128, 255]
+        );
+        assert_eq!(image.get_pixel(64 - 18, 48 - 18).0, [0, 0, 0, 255]);
+    }
+}
@@ -0,0 +1,195 @@
+use bevy_egui::egui::{self, text::LayoutJob};
+
+/// Seconds a [`TextAnimation::Pulse`] takes to travel across the text
+pub const TEXT_PULSE_SECS: f32 = 0.8;
+
+/// Seconds a [`TextAnimation::Shake`] lasts
+pub const TEXT_SHAKE_SECS: f32 = 0.4;
+
+/// Characters a color pulse spans
+const PULSE_WIDTH: f32 = 4.0;
+
+/// Short text animation for feedback, e.g. on answers in a challenge.
+///
+/// Added to a [`ResponsiveText`] with [`ResponsiveText::animate`], it starts
+/// the first frame the text is shown. With [`ResponsiveInfo::reduced_motion`]
+/// the text does not move: the typewriter shows all text at once, the pulse
+/// tints the whole text and the shake is left out.
+///
+/// [`ResponsiveText`]: super::widgets::ResponsiveText
+/// [`ResponsiveText::animate`]: super::widgets::ResponsiveText::animate
+/// [`ResponsiveInfo::reduced_motion`]: super::responsive::ResponsiveInfo::reduced_motion
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextAnimation {
+    /// Reveal the text character by character
+    Typewriter { chars_per_sec: f32 },
+    /// Let a wave of the color run across the characters, e.g. green for a
+    /// correct answer
+    Pulse { color: egui::Color32 },
+    /// Shake the text sideways, e.g. on a wrong answer
+    Shake { amplitude: f32 },
+}
+
+impl TextAnimation {
+    pub fn typewriter(chars_per_sec: f32) -> Self {
+        Self::Typewriter { chars_per_sec }
+    }
+
+    pub fn pulse(color: egui::Color32) -> Self {
+        Self::Pulse { color }
+    }
+
+    /// Gentle shake of a few pixels
+    pub fn shake() -> Self {
+        Self::Shake { amplitude: 4.0 }
+    }
+
+    /// Seconds until the animation is finished for a text with `chars` characters
+    pub fn duration(&self, chars: usize) -> f32 {
+        match *self {
+            Self::Typewriter { chars_per_sec } => chars as f32 / chars_per_sec.max(f32::EPSILON),
+            Self::Pulse { .. } => TEXT_PULSE_SECS,
+            Self::Shake { .. } => TEXT_SHAKE_SECS,
+        }
+    }
+
+    /// Color the characters of a laid out text `elapsed` seconds into the animation
+    pub(crate) fn apply(&self, job: &mut LayoutJob, elapsed: f32, reduced_motion: bool) {
+        let chars = job.text.chars().count();
+        match *self {
+            Self::Typewriter { chars_per_sec } => {
+                if reduced_motion {
+                    return;
+                }
+                let shown = (elapsed * chars_per_sec) as usize;
+                recolor_chars(job, |index, color| {
+                    if index < shown {
+                        color
+                    } else {
+                        egui::Color32::TRANSPARENT
+                    }
+                });
+            }
+            Self::Pulse { color: pulse } => {
+                if elapsed >= TEXT_PULSE_SECS {
+                    return;
+                }
+                if reduced_motion {
+                    recolor_chars(job, |_, _| pulse);
+                    return;
+                }
+                let center =
+                    elapsed / TEXT_PULSE_SECS * (chars as f32 + PULSE_WIDTH) - PULSE_WIDTH / 2.0;
+                recolor_chars(job, |index, color| {
+                    let distance = (index as f32 - center).abs() / (PULSE_WIDTH / 2.0);
+                    color.lerp_to_gamma(pulse, (1.0 - distance).clamp(0.0, 1.0))
+                });
+            }
+            Self::Shake { .. } => {}
+        }
+    }
+
+    /// Sideways offset of the text `elapsed` seconds into the animation
+    pub(crate) fn offset(&self, elapsed: f32, reduced_motion: bool) -> egui::Vec2 {
+        match *self {
+            Self::Shake { amplitude } if !reduced_motion && elapsed < TEXT_SHAKE_SECS => {
+                let damping = 1.0 - elapsed / TEXT_SHAKE_SECS;
+                let swing = (elapsed * 40.0).sin();
+                egui::vec2(amplitude * swing * damping, 0.0)
+            }
+            _ => egui::Vec2::ZERO,
+        }
+    }
+}
+
+/// Split the sections of a job into one per character, colored by `color_of`
+/// from the character index and its current color
+fn recolor_chars(
+    job: &mut LayoutJob,
+    mut color_of: impl FnMut(usize, egui::Color32) -> egui::Color32,
+) {
+    let mut sections = Vec::with_capacity(job.text.len());
+    let mut index = 0;
+    for section in std::mem::take(&mut job.sections) {
+        let text = &job.text[section.byte_range.clone()];
+        let mut leading_space = section.leading_space;
+        for (offset, character) in text.char_indices() {
+            let start = section.byte_range.start + offset;
+            let mut format = section.format.clone();
+            format.color = color_of(index, format.color);
+            sections.push(egui::text::LayoutSection {
+                leading_space,
+                byte_range: start..start + character.len_utf8(),
+                format,
+            });
+            leading_space = 0.0;
+            index += 1;
+        }
+    }
+    job.sections = sections;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(text: &str) -> LayoutJob {
+        LayoutJob::simple_format(
+            text.to_string(),
+            egui::TextFormat {
+                color: egui::Color32::WHITE,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn colors(job: &LayoutJob) -> Vec<egui::Color32> {
+        job.sections
+            .iter()
+            .map(|section| section.format.color)
+            .collect()
+    }
+
+    #[test]
+    fn test_typewriter_reveals_characters() {
+        let animation = TextAnimation::typewriter(10.0);
+        let mut revealing = job("Hallo");
+        animation.apply(&mut revealing, 0.25, false);
+        assert_eq!(
+            colors(&revealing),
+            vec![
+                egui::Color32::WHITE,
+                egui::Color32::WHITE,
+                egui::Color32::TRANSPARENT,
+                egui::Color32::TRANSPARENT,
+                egui::Color32::TRANSPARENT,
+            ]
+        );
+
+        // Reduced motion shows all text at once
+        let mut reduced = job("Hallo");
+        animation.apply(&mut reduced, 0.0, true);
+        assert_eq!(colors(&reduced), vec![egui::Color32::WHITE]);
+    }
+
+    #[test]
+    fn test_pulse_and_shake_finish() {
+        let pulse = TextAnimation::pulse(egui::Color32::GREEN);
+        let mut pulsing = job("Ja");
+        pulse.apply(&mut pulsing, TEXT_PULSE_SECS / 2.0, false);
+        assert!(colors(&pulsing).contains(&egui::Color32::GREEN));
+
+        let mut reduced = job("Ja");
+        pulse.apply(&mut reduced, 0.0, true);
+        assert_eq!(colors(&reduced), vec![egui::Color32::GREEN; 2]);
+
+        let mut finished = job("Ja");
+        pulse.apply(&mut finished, TEXT_PULSE_SECS, false);
+        assert_eq!(colors(&finished), vec![egui::Color32::WHITE]);
+
+        let shake = TextAnimation::shake();
+        assert_ne!(shake.offset(0.05, false), egui::Vec2::ZERO);
+        assert_eq!(shake.offset(0.05, true), egui::Vec2::ZERO);
+        assert_eq!(shake.offset(TEXT_SHAKE_SECS, false), egui::Vec2::ZERO);
+    }
+}
@@ -0,0 +1,234 @@
+use bevy::{
+    asset::AssetLoadFailedEvent,
+    ecs::{lifecycle::HookContext, world::DeferredWorld},
+    prelude::*,
+};
+use bevy_egui::{egui::TextureId, EguiUserTextures};
+use std::collections::HashMap;
+
+/// Plugin providing the shared [`EguiTextureCache`]
+pub struct EguiTextureCachePlugin;
+
+impl Plugin for EguiTextureCachePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EguiTextureCache>()
+            .add_systems(PostUpdate, update_egui_texture_cache);
+    }
+}
+
+#[derive(Debug)]
+struct CachedTexture {
+    // Keeps the image alive while it is referenced
+    handle: Handle<Image>,
+    texture: Option<TextureId>,
+    ref_count: usize,
+    /// Why the image failed to load
+    error: Option<String>,
+}
+
+/// Crate-level cache of egui textures keyed by asset path.
+///
+/// Screens showing the same image share one texture. Every [`acquire`] has to
+/// be paired with a [`release`]; textures without references are evicted.
+/// [`EguiTextureRefs`] does the releasing automatically when it is removed.
+///
+/// [`acquire`]: EguiTextureCache::acquire
+/// [`release`]: EguiTextureCache::release
+#[derive(Resource, Debug, Default)]
+pub struct EguiTextureCache {
+    entries: HashMap<String, CachedTexture>,
+}
+
+impl EguiTextureCache {
+    /// Take a reference to an image, loading it if it is not cached yet
+    pub fn acquire(&mut self, path: &str, asset_server: &AssetServer) -> Handle<Image> {
+        let entry = self.entries.entry(path.to_string()).or_insert_with(|| {
+            debug!("Caching egui texture: {}", path);
+            CachedTexture {
+                handle: asset_server.load(path.to_string()),
+                texture: None,
+                ref_count: 0,
+                error: None,
+            }
+        });
+        entry.ref_count += 1;
+        entry.handle.clone()
+    }
+
+    /// Give up a reference taken with [`EguiTextureCache::acquire`]
+    pub fn release(&mut self, path: &str) {
+        if let Some(entry) = self.entries.get_mut(path) {
+            entry.ref_count = entry.ref_count.saturating_sub(1);
+        }
+    }
+
+    /// Get the egui texture of an image path, once loaded
+    pub fn texture(&self, path: &str) -> Option<TextureId> {
+        self.entries.get(path).and_then(|entry| entry.texture)
+    }
+
+    /// Why the image of a path failed to load, `None` while loading or loaded
+    pub fn load_error(&self, path: &str) -> Option<&str> {
+        self.entries
+            .get(path)
+            .and_then(|entry| entry.error.as_deref())
+    }
+
+    /// Number of references to an image path
+    pub fn ref_count(&self, path: &str) -> usize {
+        self.entries.get(path).map_or(0, |entry| entry.ref_count)
+    }
+
+    /// Number of cached images
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Component holding texture references of an entity.
+///
+/// The references are released when the component is removed or the entity
+/// is despawned.
+#[derive(Component, Debug, Default)]
+#[component(on_remove = release_egui_texture_refs)]
+pub struct EguiTextureRefs {
+    paths: Vec<String>,
+}
+
+impl EguiTextureRefs {
+    /// Acquire references to the given paths
+    pub fn acquire<'a>(
+        paths: impl IntoIterator<Item = &'a str>,
+        cache: &mut EguiTextureCache,
+        asset_server: &AssetServer,
+    ) -> Self {
+        let mut refs = Self::default();
+        for path in paths {
+            refs.add(path, cache, asset_server);
+        }
+        refs
+    }
+
+    /// Acquire a reference to a path, unless this component already holds one
+    pub fn add(&mut self, path: &str, cache: &mut EguiTextureCache, asset_server: &AssetServer) {
+        if !self.contains(path) {
+            cache.acquire(path, asset_server);
+            self.paths.push(path.to_string());
+        }
+    }
+
+    pub fn contains(&self, path: &str) -> bool {
+        self.paths.iter().any(|p| p == path)
+    }
+
+    pub fn paths(&self) -> &[String] {
+        &self.paths
+    }
+}
+
+fn release_egui_texture_refs(mut world: DeferredWorld, context: HookContext) {
+    let Some(paths) = world
+        .get::<EguiTextureRefs>(context.entity)
+        .map(|refs| refs.paths.clone())
+    else {
+        return;
+    };
+
+    if let Some(mut cache) = world.get_resource_mut::<EguiTextureCache>() {
+        for path in &paths {
+            cache.release(path);
+        }
+    }
+}
+
+/// System to release the texture references of entities whose active screen component `T` was removed
+pub fn release_texture_refs_on_removal<T: Component>(
+    mut commands: Commands,
+    mut removed: RemovedComponents<T>,
+    query: Query<(), With<EguiTextureRefs>>,
+) {
+    for entity in removed.read() {
+        if query.contains(entity) {
+            commands.entity(entity).remove::<EguiTextureRefs>();
+        }
+    }
+}
+
+/// System to register loaded images with egui, record failed loads and
+/// evict unused textures
+pub fn update_egui_texture_cache(
+    mut cache: ResMut<EguiTextureCache>,
+    images: Res<Assets<Image>>,
+    mut egui_user_textures: ResMut<EguiUserTextures>,
+    mut failed_loads: MessageReader<AssetLoadFailedEvent<Image>>,
+) {
+    for failed in failed_loads.read() {
+        if let Some(entry) = cache
+            .entries
+            .values_mut()
+            .find(|entry| entry.handle.id() == failed.id)
+        {
+            warn!(
+                "Failed to load egui texture {}: {}",
+                failed.path, failed.error
+            );
+            entry.error = Some(failed.error.to_string());
+        }
+    }
+
+    if cache.is_empty() {
+        return;
+    }
+
+    cache.entries.retain(|path, entry| {
+        if entry.ref_count == 0 {
+            debug!("Evicting egui texture: {}", path);
+            if entry.texture.is_some() {
+                egui_user_textures.remove_image(&entry.handle);
+            }
+            return false;
+        }
+
+        if entry.texture.is_none() && images.get(&entry.handle).is_some() {
+            entry.texture = Some(
+                egui_user_textures
+                    .add_image(bevy_egui::EguiTextureHandle::Strong(entry.handle.clone())),
+            );
+        }
+        true
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_texture_refs_release_on_despawn() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Image>()
+            .init_resource::<EguiTextureCache>();
+
+        let world = app.world_mut();
+        let asset_server = world.resource::<AssetServer>().clone();
+        let mut cache = world.resource_mut::<EguiTextureCache>();
+        let first = EguiTextureRefs::acquire(["logo.png"], &mut cache, &asset_server);
+        let second = EguiTextureRefs::acquire(["logo.png"], &mut cache, &asset_server);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.ref_count("logo.png"), 2);
+
+        let first = world.spawn(first).id();
+        world.spawn(second);
+        world.despawn(first);
+
+        assert_eq!(
+            world.resource::<EguiTextureCache>().ref_count("logo.png"),
+            1
+        );
+    }
+}
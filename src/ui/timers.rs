@@ -0,0 +1,387 @@
+use super::responsive::{ResponsiveFontSize, ResponsiveInfo};
+use crate::theme::KonnektorenTheme;
+use bevy::time::{Stopwatch, Timer};
+use bevy_egui::egui;
+use std::time::Duration;
+
+/// Look of the countdown and stopwatch widgets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimerStyle {
+    /// Progress ring with the time in the middle
+    #[default]
+    Ring,
+    /// Time only, in large digits
+    Digital,
+}
+
+/// Format a duration as "mm:ss", or "h:mm:ss" from one hour on
+pub fn format_timer(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
+
+/// What the shared timer rendering shows
+struct TimerDisplay<'a> {
+    text: String,
+    /// Filled part of the ring between 0 and 1
+    progress: f32,
+    color: egui::Color32,
+    accessibility_label: String,
+    style: TimerStyle,
+    size: Option<f32>,
+    paused: bool,
+    pausable: bool,
+    theme: &'a KonnektorenTheme,
+    responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl TimerDisplay<'_> {
+    /// Show the timer, the response is marked changed when pause was toggled
+    fn show(self, ui: &mut egui::Ui) -> egui::Response {
+        let font_size = self.responsive_info.map_or(24.0, |responsive_info| {
+            responsive_info.font_size(ResponsiveFontSize::Large)
+        });
+        let theme = self.theme;
+        let color = if self.paused {
+            theme.base_content.linear_multiply(0.5)
+        } else {
+            self.color
+        };
+
+        ui.vertical_centered(|ui| {
+            let response = match self.style {
+                TimerStyle::Ring => {
+                    let diameter = self.size.unwrap_or(font_size * 4.0);
+                    let (rect, response) = ui
+                        .allocate_exact_size(egui::vec2(diameter, diameter), egui::Sense::hover());
+                    let painter = ui.painter_at(rect);
+                    let stroke_width = (diameter * 0.08).max(3.0);
+                    let radius = diameter / 2.0 - stroke_width;
+                    let center = rect.center();
+
+                    painter.circle_stroke(
+                        center,
+                        radius,
+                        egui::Stroke::new(stroke_width, theme.base_300),
+                    );
+                    let progress = self.progress.clamp(0.0, 1.0);
+                    if progress > 0.0 {
+                        // Clockwise from 12 o'clock
+                        let steps = (progress * 64.0).ceil().max(2.0) as usize;
+                        let start = -std::f32::consts::FRAC_PI_2;
+                        let points = (0..=steps)
+                            .map(|step| {
+                                let angle = start
+                                    + std::f32::consts::TAU * progress * step as f32 / steps as f32;
+                                center + egui::vec2(angle.cos(), angle.sin()) * radius
+                            })
+                            .collect();
+                        painter.add(egui::Shape::line(
+                            points,
+                            egui::Stroke::new(stroke_width, color),
+                        ));
+                    }
+                    painter.text(
+                        center,
+                        egui::Align2::CENTER_CENTER,
+                        &self.text,
+                        egui::FontId::monospace(diameter * 0.22),
+                        theme.base_content,
+                    );
+                    response
+                }
+                TimerStyle::Digital => {
+                    let text_size = self.size.unwrap_or(font_size * 1.6);
+                    let galley = ui.painter().layout_no_wrap(
+                        self.text.clone(),
+                        egui::FontId::monospace(text_size),
+                        color,
+                    );
+                    let padding = egui::vec2(text_size * 0.5, text_size * 0.25);
+                    let (rect, response) =
+                        ui.allocate_exact_size(galley.size() + padding * 2.0, egui::Sense::hover());
+                    ui.painter().rect(
+                        rect,
+                        theme.radius,
+                        theme.base_200,
+                        egui::Stroke::new(2.0, color),
+                        egui::StrokeKind::Inside,
+                    );
+                    ui.painter().galley(rect.min + padding, galley, color);
+                    response
+                }
+            };
+
+            let label = if self.paused {
+                format!("{}, paused", self.accessibility_label)
+            } else {
+                self.accessibility_label.clone()
+            };
+            response
+                .widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Other, true, &label));
+
+            if self.pausable {
+                let text = if self.paused {
+                    "▶ Resume"
+                } else {
+                    "⏸ Pause"
+                };
+                let button = egui::Button::new(
+                    egui::RichText::new(text)
+                        .size(font_size * 0.6)
+                        .color(theme.base_content),
+                )
+                .fill(theme.base_200);
+                let toggled = ui.add(button).clicked();
+                let mut response = response;
+                if toggled {
+                    response.mark_changed();
+                }
+                response
+            } else {
+                response
+            }
+        })
+        .inner
+    }
+}
+
+/// Countdown of a [`Timer`], e.g. for timed challenges.
+///
+/// The ring empties as time runs out and turns to the warning color below
+/// the threshold, and to the error color when the time is up. With
+/// [`pausable`](Self::pausable) a button pauses and resumes the timer; the
+/// response is marked changed when it was toggled. The app ticks the timer.
+pub struct CountdownWidget<'a> {
+    pub timer: &'a mut Timer,
+    pub theme: &'a KonnektorenTheme,
+    pub style: TimerStyle,
+    /// Remaining time below which the warning color is used
+    pub warning_threshold: Option<Duration>,
+    pub pausable: bool,
+    pub size: Option<f32>,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> CountdownWidget<'a> {
+    pub fn new(timer: &'a mut Timer, theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            timer,
+            theme,
+            style: TimerStyle::default(),
+            warning_threshold: Some(Duration::from_secs(10)),
+            pausable: false,
+            size: None,
+            responsive_info: None,
+        }
+    }
+
+    pub fn style(mut self, style: TimerStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn warning_threshold(mut self, threshold: Option<Duration>) -> Self {
+        self.warning_threshold = threshold;
+        self
+    }
+
+    pub fn pausable(mut self, pausable: bool) -> Self {
+        self.pausable = pausable;
+        self
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+}
+
+impl<'a> egui::Widget for CountdownWidget<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let remaining = self.timer.remaining();
+        // Round up, so 00:00 is only shown when the time is up
+        let shown =
+            Duration::from_secs(remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0));
+        let color = if self.timer.is_finished() {
+            self.theme.error
+        } else if self
+            .warning_threshold
+            .is_some_and(|threshold| remaining <= threshold)
+        {
+            self.theme.warning
+        } else {
+            self.theme.primary
+        };
+
+        let response = TimerDisplay {
+            text: format_timer(shown),
+            progress: 1.0 - self.timer.fraction(),
+            color,
+            accessibility_label: format!("Time remaining {}", format_timer(shown)),
+            style: self.style,
+            size: self.size,
+            paused: self.timer.is_paused(),
+            pausable: self.pausable && !self.timer.is_finished(),
+            theme: self.theme,
+            responsive_info: self.responsive_info,
+        }
+        .show(ui);
+
+        if response.changed() {
+            if self.timer.is_paused() {
+                self.timer.unpause();
+            } else {
+                self.timer.pause();
+            }
+        }
+        response
+    }
+}
+
+/// Elapsed time of a [`Stopwatch`], e.g. for quizzes without a time limit.
+///
+/// The ring fills once per minute. The warning color is used after the
+/// optional threshold. Pausing works like with the [`CountdownWidget`].
+pub struct StopwatchWidget<'a> {
+    pub stopwatch: &'a mut Stopwatch,
+    pub theme: &'a KonnektorenTheme,
+    pub style: TimerStyle,
+    /// Elapsed time after which the warning color is used
+    pub warning_threshold: Option<Duration>,
+    pub pausable: bool,
+    pub size: Option<f32>,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> StopwatchWidget<'a> {
+    pub fn new(stopwatch: &'a mut Stopwatch, theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            stopwatch,
+            theme,
+            style: TimerStyle::default(),
+            warning_threshold: None,
+            pausable: false,
+            size: None,
+            responsive_info: None,
+        }
+    }
+
+    pub fn style(mut self, style: TimerStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn warning_threshold(mut self, threshold: Option<Duration>) -> Self {
+        self.warning_threshold = threshold;
+        self
+    }
+
+    pub fn pausable(mut self, pausable: bool) -> Self {
+        self.pausable = pausable;
+        self
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+}
+
+impl<'a> egui::Widget for StopwatchWidget<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let elapsed = self.stopwatch.elapsed();
+        let color = if self
+            .warning_threshold
+            .is_some_and(|threshold| elapsed >= threshold)
+        {
+            self.theme.warning
+        } else {
+            self.theme.primary
+        };
+
+        let response = TimerDisplay {
+            text: format_timer(elapsed),
+            progress: (elapsed.as_secs_f32() % 60.0) / 60.0,
+            color,
+            accessibility_label: format!("Elapsed time {}", format_timer(elapsed)),
+            style: self.style,
+            size: self.size,
+            paused: self.stopwatch.is_paused(),
+            pausable: self.pausable,
+            theme: self.theme,
+            responsive_info: self.responsive_info,
+        }
+        .show(ui);
+
+        if response.changed() {
+            if self.stopwatch.is_paused() {
+                self.stopwatch.unpause();
+            } else {
+                self.stopwatch.pause();
+            }
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestHarness;
+    use bevy::prelude::*;
+    use bevy_egui::{EguiContexts, EguiPrimaryContextPass};
+
+    #[derive(Resource)]
+    struct QuizTimer(Timer);
+
+    fn countdown_ui(mut contexts: EguiContexts, mut timer: ResMut<QuizTimer>) {
+        let Ok(ctx) = contexts.ctx_mut() else {
+            return;
+        };
+        let theme = KonnektorenTheme::default();
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.add(CountdownWidget::new(&mut timer.0, &theme).pausable(true));
+        });
+    }
+
+    #[test]
+    fn test_countdown_pause() {
+        let mut timer = Timer::from_seconds(90.0, TimerMode::Once);
+        timer.tick(Duration::from_millis(25_500));
+
+        let mut harness = TestHarness::new();
+        harness
+            .app_mut()
+            .insert_resource(QuizTimer(timer))
+            .add_systems(EguiPrimaryContextPass, countdown_ui);
+        harness.run_frames(2);
+        assert!(harness.has_widget("Time remaining 01:05"));
+
+        harness.click("⏸ Pause");
+        assert!(harness.world().resource::<QuizTimer>().0.is_paused());
+        assert!(harness.has_widget("Time remaining 01:05, paused"));
+    }
+
+    #[test]
+    fn test_format_timer() {
+        assert_eq!(format_timer(Duration::from_secs(5)), "00:05");
+        assert_eq!(format_timer(Duration::from_secs(754)), "12:34");
+        assert_eq!(format_timer(Duration::from_secs(3725)), "1:02:05");
+    }
+}
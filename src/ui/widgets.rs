@@ -1,4 +1,7 @@
-use super::responsive::{ResponsiveFontSize, ResponsiveInfo};
+use super::{
+    responsive::{ResponsiveFontSize, ResponsiveInfo},
+    text_animation::TextAnimation,
+};
 use crate::theme::KonnektorenTheme;
 use bevy::prelude::*;
 use bevy_egui::egui;
@@ -70,11 +73,7 @@ impl<'a> ThemedButton<'a> {
         let (font_size, min_height, min_width) = if let Some(responsive_info) = self.responsive_info
         {
             let font_size = responsive_info.font_size(ResponsiveFontSize::Medium);
-            let min_height = if responsive_info.is_mobile() {
-                44.0
-            } else {
-                32.0
-            };
+            let min_height = responsive_info.min_hit_target();
             let min_width = if responsive_info.is_mobile() {
                 120.0
             } else {
@@ -85,12 +84,19 @@ impl<'a> ThemedButton<'a> {
             (18.0, 32.0, 80.0)
         };
 
-        let mut button = egui::Button::new(
-            egui::RichText::new(self.label)
-                .color(self.theme.primary_content.linear_multiply(self.opacity))
-                .size(font_size),
-        )
-        .fill(self.theme.primary.linear_multiply(self.opacity));
+        let label = self
+            .responsive_info
+            .map_or(self.label.into(), |responsive_info| {
+                responsive_info.label(self.label)
+            });
+        let mut text = egui::RichText::new(label)
+            .color(self.theme.primary_content.linear_multiply(self.opacity))
+            .size(font_size);
+        if let Some(responsive_info) = self.responsive_info {
+            text = responsive_info.readable_text(text, font_size);
+        }
+        let mut button =
+            egui::Button::new(text).fill(self.theme.primary.linear_multiply(self.opacity));
 
         // Apply custom styling if provided
         if let Some(style_fn) = self.custom_style {
@@ -116,11 +122,7 @@ impl<'a> ThemedButton<'a> {
     /// Get the configured minimum dimensions for external use
     pub fn get_min_dimensions(&self) -> (f32, f32) {
         if let Some(responsive_info) = self.responsive_info {
-            let min_height = if responsive_info.is_mobile() {
-                44.0
-            } else {
-                32.0
-            };
+            let min_height = responsive_info.min_hit_target();
             let min_width = if responsive_info.is_mobile() {
                 120.0
             } else {
@@ -133,6 +135,83 @@ impl<'a> ThemedButton<'a> {
     }
 }
 
+/// Shadow or outline drawn behind text to keep it legible over background
+/// images and animated menu backgrounds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextEffect {
+    /// Copy of the text at an offset, softened by layering it around the
+    /// offset within the blur radius
+    Shadow {
+        offset: egui::Vec2,
+        blur: f32,
+        color: egui::Color32,
+    },
+    /// Copies of the text around it at the given width
+    Outline { width: f32, color: egui::Color32 },
+}
+
+impl TextEffect {
+    /// Soft drop shadow in the theme's darker base color
+    pub fn shadow(theme: &KonnektorenTheme) -> Self {
+        Self::Shadow {
+            offset: egui::vec2(2.0, 2.0),
+            blur: 1.0,
+            color: theme.base_300,
+        }
+    }
+
+    /// Outline in the theme's background color
+    pub fn outline(theme: &KonnektorenTheme) -> Self {
+        Self::Outline {
+            width: 1.5,
+            color: theme.base_100,
+        }
+    }
+
+    /// Offsets and color of the copies drawn behind the text
+    fn layers(&self) -> (Vec<egui::Vec2>, egui::Color32) {
+        let around = |center: egui::Vec2, radius: f32| {
+            (0..8).map(move |i| {
+                let angle = i as f32 * std::f32::consts::FRAC_PI_4;
+                center + radius * egui::vec2(angle.cos(), angle.sin())
+            })
+        };
+
+        match *self {
+            Self::Shadow {
+                offset,
+                blur,
+                color,
+            } if blur > 0.0 => {
+                let offsets: Vec<_> = std::iter::once(offset)
+                    .chain(around(offset, blur))
+                    .collect();
+                // The layers add up to about the shadow color
+                let alpha = 2.0 / offsets.len() as f32;
+                (offsets, color.gamma_multiply(alpha))
+            }
+            Self::Shadow { offset, color, .. } => (vec![offset], color),
+            Self::Outline { width, color } => (around(egui::Vec2::ZERO, width).collect(), color),
+        }
+    }
+}
+
+/// Smallest font size [`TextFit::FitToWidth`] shrinks text to
+pub const MIN_FIT_FONT_SIZE: f32 = 8.0;
+
+/// How [`ResponsiveText`] handles text wider than the space it has
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TextFit {
+    /// Wrap onto more lines, like a label
+    #[default]
+    Wrap,
+    /// Stay on one line, shrinking the font until it is at most this wide
+    FitToWidth(f32),
+    /// Stay on one line, cut off with "…" at the available width. The full
+    /// text shows on hover.
+    Truncate,
+}
+
 /// Responsive text widget that adjusts size based on device type
 pub struct ResponsiveText<'a> {
     pub text: &'a str,
@@ -140,6 +219,11 @@ pub struct ResponsiveText<'a> {
     pub color: egui::Color32,
     pub responsive_info: Option<&'a ResponsiveInfo>,
     pub strong: bool,
+    pub effect: Option<TextEffect>,
+    pub fit: TextFit,
+    pub animation: Option<TextAnimation>,
+    /// Id the animation start is remembered under, the text by default
+    pub animation_id: Option<egui::Id>,
 }
 
 impl<'a> ResponsiveText<'a> {
@@ -150,6 +234,10 @@ impl<'a> ResponsiveText<'a> {
             color,
             responsive_info: None,
             strong: false,
+            effect: None,
+            fit: TextFit::Wrap,
+            animation: None,
+            animation_id: None,
         }
     }
 
@@ -162,6 +250,97 @@ impl<'a> ResponsiveText<'a> {
         self.strong = true;
         self
     }
+
+    /// Draw a drop shadow behind the text, see [`TextEffect::shadow`]
+    pub fn shadow(self, theme: &KonnektorenTheme) -> Self {
+        self.with_effect(TextEffect::shadow(theme))
+    }
+
+    /// Draw an outline around the text, see [`TextEffect::outline`]
+    pub fn outline(self, theme: &KonnektorenTheme) -> Self {
+        self.with_effect(TextEffect::outline(theme))
+    }
+
+    pub fn with_effect(mut self, effect: TextEffect) -> Self {
+        self.effect = Some(effect);
+        self
+    }
+
+    /// Shrink the font until the text fits on one line of at most `max_width`,
+    /// e.g. for long translations on buttons
+    pub fn fit_to_width(mut self, max_width: f32) -> Self {
+        self.fit = TextFit::FitToWidth(max_width);
+        self
+    }
+
+    /// Cut the text off with "…" instead of wrapping it
+    pub fn truncate_with_ellipsis(mut self) -> Self {
+        self.fit = TextFit::Truncate;
+        self
+    }
+
+    /// Animate the text, starting the first frame it is shown
+    pub fn animate(mut self, animation: TextAnimation) -> Self {
+        self.animation = Some(animation);
+        self
+    }
+
+    /// Restart the animation whenever this changes, e.g. with the number of
+    /// the attempt on a question
+    pub fn animation_id(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.animation_id = Some(egui::Id::new(id_salt));
+        self
+    }
+
+    /// Seconds since the animation of the text started
+    fn animation_elapsed(&self, ui: &egui::Ui, text: &str) -> f32 {
+        let id = ui.id().with((
+            "text_animation",
+            self.animation_id.unwrap_or(egui::Id::new(text)),
+        ));
+        let time = ui.input(|i| i.time);
+        let started = ui.data_mut(|data| *data.get_temp_mut_or_insert_with(id, || time));
+        (time - started) as f32
+    }
+
+    fn rich_text(&self, font_size: f32) -> egui::RichText {
+        let text = self
+            .responsive_info
+            .map_or(self.text.into(), |responsive_info| {
+                responsive_info.label(self.text)
+            });
+        let mut rich_text = egui::RichText::new(text).size(font_size).color(self.color);
+
+        if self.strong {
+            rich_text = rich_text.strong();
+        }
+        if let Some(responsive_info) = self.responsive_info {
+            rich_text = responsive_info.readable_text(rich_text, font_size);
+        }
+        rich_text
+    }
+
+    /// Largest font size up to `font_size` the text fits into `max_width` with
+    fn fitting_font_size(&self, ui: &egui::Ui, font_size: f32, max_width: f32) -> f32 {
+        let mut font_size = font_size;
+        while font_size > MIN_FIT_FONT_SIZE {
+            let width = egui::WidgetText::from(self.rich_text(font_size))
+                .into_galley(
+                    ui,
+                    Some(egui::TextWrapMode::Extend),
+                    f32::INFINITY,
+                    egui::TextStyle::Body,
+                )
+                .size()
+                .x;
+            if width <= max_width {
+                break;
+            }
+            // Text width is about proportional to the font size
+            font_size = (font_size * (max_width / width).min(0.95)).max(MIN_FIT_FONT_SIZE);
+        }
+        font_size
+    }
 }
 
 impl<'a> egui::Widget for ResponsiveText<'a> {
@@ -178,15 +357,79 @@ impl<'a> egui::Widget for ResponsiveText<'a> {
             }
         };
 
-        let mut rich_text = egui::RichText::new(self.text)
-            .size(font_size)
-            .color(self.color);
+        let (font_size, wrap_mode) = match self.fit {
+            TextFit::Wrap => (font_size, None),
+            TextFit::FitToWidth(max_width) => (
+                self.fitting_font_size(ui, font_size, max_width),
+                Some(egui::TextWrapMode::Extend),
+            ),
+            TextFit::Truncate => (font_size, Some(egui::TextWrapMode::Truncate)),
+        };
+        let rich_text = self.rich_text(font_size);
 
-        if self.strong {
-            rich_text = rich_text.strong();
+        if self.effect.is_none() && self.animation.is_none() {
+            let mut label = egui::Label::new(rich_text);
+            if let Some(wrap_mode) = wrap_mode {
+                label = label.wrap_mode(wrap_mode);
+            }
+            return ui.add(label);
+        }
+
+        // Lay the text out like a label to paint effects and animations
+        let full_text = rich_text.text().to_string();
+        let mut job = egui::text::LayoutJob::default();
+        rich_text.append_to(
+            &mut job,
+            ui.style(),
+            egui::TextStyle::Body.into(),
+            ui.text_valign(),
+        );
+        job.wrap = egui::text::TextWrapping::from_wrap_mode_and_width(
+            wrap_mode.unwrap_or_else(|| ui.wrap_mode()),
+            ui.available_width(),
+        );
+
+        let mut shake = egui::Vec2::ZERO;
+        if let Some(animation) = self.animation {
+            let reduced_motion = self
+                .responsive_info
+                .is_some_and(|responsive_info| responsive_info.reduced_motion);
+            let elapsed = self.animation_elapsed(ui, &full_text);
+            if elapsed < animation.duration(full_text.chars().count()) {
+                ui.ctx().request_repaint();
+            }
+            animation.apply(&mut job, elapsed, reduced_motion);
+            shake = animation.offset(elapsed, reduced_motion);
+        }
+
+        let galley = ui.painter().layout_job(job.clone());
+        let (rect, mut response) = ui.allocate_exact_size(galley.size(), egui::Sense::hover());
+        response.widget_info(|| {
+            egui::WidgetInfo::labeled(egui::WidgetType::Label, ui.is_enabled(), &full_text)
+        });
+        if galley.elided {
+            response = response.on_hover_text(full_text);
+        }
+
+        if ui.is_rect_visible(rect) {
+            if let Some(effect) = self.effect {
+                let (offsets, color) = effect.layers();
+                // Characters hidden by an animation stay hidden in the effect
+                for section in &mut job.sections {
+                    if section.format.color != egui::Color32::TRANSPARENT {
+                        section.format.color = color;
+                    }
+                }
+                let effect_galley = ui.painter().layout_job(job);
+                for offset in offsets {
+                    ui.painter()
+                        .galley(rect.min + shake + offset, effect_galley.clone(), color);
+                }
+            }
+            ui.painter().galley(rect.min + shake, galley, self.color);
         }
 
-        ui.label(rich_text)
+        response
     }
 }
 
@@ -254,3 +497,1538 @@ impl<'a> egui::Widget for SpinnerWidget<'a> {
         response
     }
 }
+
+/// A bell icon button with a badge showing the number of unread notifications.
+pub struct NotificationBell<'a> {
+    pub theme: &'a KonnektorenTheme,
+    pub unread: usize,
+    pub size: f32,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> NotificationBell<'a> {
+    pub fn new(theme: &'a KonnektorenTheme, unread: usize) -> Self {
+        Self {
+            theme,
+            unread,
+            size: 32.0,
+            responsive_info: None,
+        }
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+}
+
+impl<'a> egui::Widget for NotificationBell<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        // Bigger touch target on mobile and in high visibility mode
+        let size = match self.responsive_info {
+            Some(responsive_info) => self.size.max(responsive_info.min_hit_target()),
+            None => self.size,
+        };
+
+        let (rect, response) = ui.allocate_exact_size(egui::vec2(size, size), egui::Sense::click());
+        let painter = ui.painter();
+
+        let bell_color = if response.hovered() {
+            self.theme.primary
+        } else {
+            self.theme.base_content
+        };
+        painter.text(
+            rect.center(),
+            egui::Align2::CENTER_CENTER,
+            "🔔",
+            egui::FontId::proportional(size * 0.6),
+            bell_color,
+        );
+
+        if self.unread > 0 {
+            let label = if self.unread > 99 {
+                "99+".to_string()
+            } else {
+                self.unread.to_string()
+            };
+            let badge_radius = size * 0.22;
+            let badge_center = egui::pos2(rect.right() - badge_radius, rect.top() + badge_radius);
+
+            painter.circle_filled(badge_center, badge_radius, self.theme.error);
+            painter.text(
+                badge_center,
+                egui::Align2::CENTER_CENTER,
+                label,
+                egui::FontId::proportional(badge_radius * 1.1),
+                self.theme.error_content,
+            );
+        }
+
+        response
+    }
+}
+
+/// A star toggle marking an item as a favorite.
+///
+/// The returned response is clicked when the player toggles the star, the
+/// caller flips `favorite` and e.g. sends a `FavoriteToggled`.
+pub struct FavoriteStar<'a> {
+    pub theme: &'a KonnektorenTheme,
+    pub favorite: bool,
+    pub size: f32,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> FavoriteStar<'a> {
+    pub fn new(theme: &'a KonnektorenTheme, favorite: bool) -> Self {
+        Self {
+            theme,
+            favorite,
+            size: 28.0,
+            responsive_info: None,
+        }
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+}
+
+impl<'a> egui::Widget for FavoriteStar<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        // Bigger touch target on mobile and in high visibility mode
+        let size = match self.responsive_info {
+            Some(responsive_info) => self.size.max(responsive_info.min_hit_target()),
+            None => self.size,
+        };
+
+        let (rect, response) = ui.allocate_exact_size(egui::vec2(size, size), egui::Sense::click());
+        let (star, color) = match (self.favorite, response.hovered()) {
+            (true, _) => ("★", self.theme.warning),
+            (false, true) => ("☆", self.theme.warning),
+            (false, false) => ("☆", self.theme.base_content),
+        };
+        ui.painter().text(
+            rect.center(),
+            egui::Align2::CENTER_CENTER,
+            star,
+            egui::FontId::proportional(size * 0.75),
+            color,
+        );
+
+        let label = if self.favorite {
+            "Remove from favorites"
+        } else {
+            "Add to favorites"
+        };
+        response.widget_info(|| {
+            egui::WidgetInfo::selected(egui::WidgetType::Checkbox, true, self.favorite, label)
+        });
+        response.on_hover_text(label)
+    }
+}
+
+/// Small indicator of the auto-save, e.g. in a corner of the HUD.
+///
+/// Shows "Saving…" while changes are pending, "Saved" for a moment after
+/// they were written and a warning when saving failed. Nothing is shown
+/// otherwise.
+#[cfg(feature = "storage")]
+pub struct AutoSaveIndicator<'a> {
+    pub theme: &'a KonnektorenTheme,
+    pub status: &'a crate::storage::AutoSaveStatus,
+    /// Elapsed app time in seconds
+    pub now_secs: f64,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+#[cfg(feature = "storage")]
+impl<'a> AutoSaveIndicator<'a> {
+    pub fn new(
+        theme: &'a KonnektorenTheme,
+        status: &'a crate::storage::AutoSaveStatus,
+        now_secs: f64,
+    ) -> Self {
+        Self {
+            theme,
+            status,
+            now_secs,
+            responsive_info: None,
+        }
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+}
+
+#[cfg(feature = "storage")]
+impl<'a> egui::Widget for AutoSaveIndicator<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        use crate::storage::AutoSaveStatus;
+
+        if !self.status.is_visible(self.now_secs) {
+            return ui.allocate_response(egui::Vec2::ZERO, egui::Sense::hover());
+        }
+
+        let size = match self.responsive_info {
+            Some(responsive_info) => responsive_info.font_size(ResponsiveFontSize::Small),
+            None => 12.0,
+        };
+        let color = match self.status {
+            AutoSaveStatus::Failed(_) => self.theme.error,
+            AutoSaveStatus::Saved { .. } => self.theme.success,
+            _ => self.theme.base_content,
+        };
+
+        let response = ui
+            .horizontal(|ui| {
+                if *self.status == AutoSaveStatus::Pending {
+                    ui.add(SpinnerWidget::new(self.theme, size));
+                }
+                ui.label(
+                    egui::RichText::new(self.status.label())
+                        .size(size)
+                        .color(color),
+                );
+            })
+            .response;
+        match self.status {
+            AutoSaveStatus::Failed(error) => response.on_hover_text(error),
+            _ => response,
+        }
+    }
+}
+
+/// Row of tabs selecting one of several views, e.g. "All" and "Favorites".
+///
+/// The returned response is marked changed when another tab was selected.
+pub struct ThemedTabs<'a> {
+    pub selected: &'a mut usize,
+    pub labels: &'a [&'a str],
+    pub theme: &'a KonnektorenTheme,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> ThemedTabs<'a> {
+    pub fn new(
+        selected: &'a mut usize,
+        labels: &'a [&'a str],
+        theme: &'a KonnektorenTheme,
+    ) -> Self {
+        Self {
+            selected,
+            labels,
+            theme,
+            responsive_info: None,
+        }
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+}
+
+impl<'a> egui::Widget for ThemedTabs<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let font_size = self.responsive_info.map_or(16.0, |responsive_info| {
+            responsive_info.font_size(ResponsiveFontSize::Medium)
+        });
+        let mut selected = *self.selected;
+
+        let mut response = ui
+            .horizontal(|ui| {
+                for (index, label) in self.labels.iter().enumerate() {
+                    let is_selected = index == selected;
+                    let text_color = if is_selected {
+                        self.theme.primary
+                    } else {
+                        self.theme.base_content
+                    };
+                    let tab = egui::Button::new(
+                        egui::RichText::new(*label)
+                            .size(font_size)
+                            .color(text_color),
+                    )
+                    .frame(false)
+                    .selected(is_selected);
+                    let tab = ui.add(tab);
+                    if is_selected {
+                        // Underline the selected tab
+                        let rect = tab.rect;
+                        ui.painter().hline(
+                            rect.x_range(),
+                            rect.bottom(),
+                            egui::Stroke::new(2.0, self.theme.primary),
+                        );
+                    }
+                    if tab.clicked() {
+                        selected = index;
+                    }
+                }
+            })
+            .response;
+
+        if selected != *self.selected {
+            *self.selected = selected;
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// A texture layer of an avatar, drawn with a tint color.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AvatarLayerTexture {
+    pub texture: egui::TextureId,
+    pub tint: egui::Color32,
+}
+
+/// Size presets of an [`AvatarWidget`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AvatarSize {
+    /// For lists and chat lines
+    Small,
+    /// For leaderboards and join screens
+    #[default]
+    Medium,
+    /// For profile headers
+    Large,
+}
+
+impl AvatarSize {
+    pub fn pixels(&self) -> f32 {
+        match self {
+            AvatarSize::Small => 32.0,
+            AvatarSize::Medium => 64.0,
+            AvatarSize::Large => 112.0,
+        }
+    }
+}
+
+/// Status shown as a dot at the bottom right of an [`AvatarWidget`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvatarStatus {
+    Online,
+    Away,
+    Busy,
+    Offline,
+}
+
+impl AvatarStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AvatarStatus::Online => "online",
+            AvatarStatus::Away => "away",
+            AvatarStatus::Busy => "busy",
+            AvatarStatus::Offline => "offline",
+        }
+    }
+
+    fn color(&self, theme: &KonnektorenTheme) -> egui::Color32 {
+        match self {
+            AvatarStatus::Online => theme.success,
+            AvatarStatus::Away => theme.warning,
+            AvatarStatus::Busy => theme.error,
+            AvatarStatus::Offline => theme.base_300,
+        }
+    }
+}
+
+/// Up to two initials of a name, e.g. "AL" for "Ada Lovelace"
+pub fn avatar_initials(name: &str) -> String {
+    let words: Vec<&str> = name.split_whitespace().collect();
+    let initials: String = match words.as_slice() {
+        [] => String::new(),
+        [word] => word.chars().take(2).collect(),
+        [first, .., last] => first.chars().take(1).chain(last.chars().take(1)).collect(),
+    };
+    initials.to_uppercase()
+}
+
+/// A round avatar showing an image, stacked texture layers or the initials
+/// of a name, in this order of preference.
+///
+/// The initials circle takes a theme color picked from the name, so the same
+/// player always gets the same color. An optional status dot shows whether
+/// the player is online.
+pub struct AvatarWidget<'a> {
+    pub theme: &'a KonnektorenTheme,
+    pub layers: &'a [AvatarLayerTexture],
+    pub image: Option<egui::TextureId>,
+    pub name: Option<&'a str>,
+    pub status: Option<AvatarStatus>,
+    pub size: f32,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> AvatarWidget<'a> {
+    pub fn new(theme: &'a KonnektorenTheme, layers: &'a [AvatarLayerTexture]) -> Self {
+        Self {
+            theme,
+            layers,
+            image: None,
+            name: None,
+            status: None,
+            size: AvatarSize::default().pixels(),
+            responsive_info: None,
+        }
+    }
+
+    /// Avatar showing the initials of the name
+    pub fn from_name(theme: &'a KonnektorenTheme, name: &'a str) -> Self {
+        Self::new(theme, &[]).name(name)
+    }
+
+    /// Avatar showing a loaded image, e.g. from the
+    /// [`EguiTextureCache`](super::texture_cache::EguiTextureCache)
+    pub fn from_image(theme: &'a KonnektorenTheme, texture: egui::TextureId) -> Self {
+        Self::new(theme, &[]).image(Some(texture))
+    }
+
+    /// Image to show, the initials are used while it is `None`
+    pub fn image(mut self, texture: Option<egui::TextureId>) -> Self {
+        self.image = texture;
+        self
+    }
+
+    /// Name for the initials and the accessibility label
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn status(mut self, status: AvatarStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn size_preset(mut self, size: AvatarSize) -> Self {
+        self.size = size.pixels();
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+
+    /// Background and text color of the initials circle
+    fn initials_colors(&self, name: &str) -> (egui::Color32, egui::Color32) {
+        let theme = self.theme;
+        let colors = [
+            (theme.primary, theme.primary_content),
+            (theme.secondary, theme.secondary_content),
+            (theme.accent, theme.accent_content),
+        ];
+        let hash = name.bytes().fold(0usize, |hash, byte| {
+            hash.wrapping_mul(31).wrapping_add(byte as usize)
+        });
+        colors[hash % colors.len()]
+    }
+}
+
+impl<'a> egui::Widget for AvatarWidget<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        // Scale down a bit on small screens
+        let size = match self.responsive_info {
+            Some(responsive_info) if responsive_info.is_mobile() => self.size * 0.85,
+            _ => self.size,
+        };
+
+        let (rect, response) = ui.allocate_exact_size(egui::vec2(size, size), egui::Sense::click());
+        let radius = size / 2.0;
+        let corner_radius = egui::CornerRadius::same(radius.min(255.0) as u8);
+
+        if let Some(texture) = self.image {
+            ui.painter()
+                .circle_filled(rect.center(), radius, self.theme.base_300);
+            egui::Image::from_texture((texture, rect.size()))
+                .corner_radius(corner_radius)
+                .paint_at(ui, rect);
+        } else if !self.layers.is_empty() {
+            ui.painter()
+                .circle_filled(rect.center(), radius, self.theme.base_300);
+            for layer in self.layers {
+                egui::Image::from_texture((layer.texture, rect.size()))
+                    .tint(layer.tint)
+                    .corner_radius(corner_radius)
+                    .paint_at(ui, rect);
+            }
+        } else if let Some(name) = self.name {
+            let (fill, text_color) = self.initials_colors(name);
+            ui.painter().circle_filled(rect.center(), radius, fill);
+            ui.painter().text(
+                rect.center(),
+                egui::Align2::CENTER_CENTER,
+                avatar_initials(name),
+                egui::FontId::proportional(size * 0.4),
+                text_color,
+            );
+        } else {
+            ui.painter()
+                .circle_filled(rect.center(), radius, self.theme.base_300);
+        }
+
+        ui.painter().circle_stroke(
+            rect.center(),
+            radius,
+            egui::Stroke::new(self.theme.border_width.max(1.0), self.theme.primary),
+        );
+
+        if let Some(status) = self.status {
+            // Sits on the circle at the bottom right, with a ring in the
+            // background color to separate it from the avatar
+            let dot_radius = (size * 0.14).max(4.0);
+            let center =
+                rect.center() + egui::vec2(1.0, 1.0) * radius * std::f32::consts::FRAC_1_SQRT_2;
+            ui.painter().circle(
+                center,
+                dot_radius,
+                status.color(self.theme),
+                egui::Stroke::new((dot_radius * 0.35).max(1.5), self.theme.base_100),
+            );
+        }
+
+        let label = match (self.name, self.status) {
+            (Some(name), Some(status)) => format!("Avatar {}, {}", name, status.label()),
+            (Some(name), None) => format!("Avatar {}", name),
+            (None, Some(status)) => format!("Avatar, {}", status.label()),
+            (None, None) => "Avatar".to_string(),
+        };
+        response.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Image, true, &label));
+
+        response
+    }
+}
+
+/// State of an answer option
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnswerState {
+    #[default]
+    Neutral,
+    Selected,
+    Correct,
+    Incorrect,
+}
+
+impl AnswerState {
+    pub fn color(&self, theme: &KonnektorenTheme) -> egui::Color32 {
+        match self {
+            AnswerState::Neutral => theme.base_300,
+            AnswerState::Selected => theme.primary,
+            AnswerState::Correct => theme.success,
+            AnswerState::Incorrect => theme.error,
+        }
+    }
+
+    /// Symbol shown with shape cues enabled
+    pub fn symbol(&self) -> Option<&'static str> {
+        match self {
+            AnswerState::Neutral => None,
+            AnswerState::Selected => Some("●"),
+            AnswerState::Correct => Some("✔"),
+            AnswerState::Incorrect => Some("✖"),
+        }
+    }
+}
+
+/// An answer option whose state is shown by color.
+///
+/// With shape cues the state is also shown by a symbol, and incorrect
+/// answers are hatched, so it doesn't rely on color alone.
+pub struct AnswerFeedback<'a> {
+    pub text: &'a str,
+    pub state: AnswerState,
+    pub theme: &'a KonnektorenTheme,
+    pub shape_cues: bool,
+    pub width: Option<f32>,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> AnswerFeedback<'a> {
+    pub fn new(text: &'a str, state: AnswerState, theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            text,
+            state,
+            theme,
+            shape_cues: false,
+            width: None,
+            responsive_info: None,
+        }
+    }
+
+    pub fn shape_cues(mut self, shape_cues: bool) -> Self {
+        self.shape_cues = shape_cues;
+        self
+    }
+
+    /// Use the shape cue option of the color assistance
+    pub fn assistance(self, assistance: &crate::theme::ColorAssistance) -> Self {
+        self.shape_cues(assistance.shape_cues)
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+}
+
+impl<'a> egui::Widget for AnswerFeedback<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let font_size = self.responsive_info.map_or(18.0, |responsive_info| {
+            responsive_info.font_size(ResponsiveFontSize::Medium)
+        });
+        let label = match (self.shape_cues, self.state.symbol()) {
+            (true, Some(symbol)) => format!("{} {}", symbol, self.text),
+            _ => self.text.to_string(),
+        };
+
+        let galley = ui.painter().layout_no_wrap(
+            label.clone(),
+            egui::FontId::proportional(font_size),
+            self.theme.base_content,
+        );
+        let padding = egui::vec2(12.0, 8.0);
+        let min_width = galley.size().x + padding.x * 2.0;
+        let size = egui::vec2(
+            self.width.unwrap_or(min_width).max(min_width),
+            galley.size().y + padding.y * 2.0,
+        );
+
+        let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click());
+        response.widget_info(|| {
+            egui::WidgetInfo::labeled(egui::WidgetType::Button, ui.is_enabled(), &label)
+        });
+
+        let color = self.state.color(self.theme);
+        let painter = ui.painter_at(rect);
+        painter.rect(
+            rect,
+            self.theme.radius,
+            color.linear_multiply(0.2),
+            egui::Stroke::new(2.0, color),
+            egui::StrokeKind::Inside,
+        );
+
+        if self.shape_cues && self.state == AnswerState::Incorrect {
+            let stroke = egui::Stroke::new(1.5, color.linear_multiply(0.5));
+            let mut x = rect.left() - rect.height();
+            while x < rect.right() {
+                painter.line_segment(
+                    [
+                        egui::pos2(x, rect.bottom()),
+                        egui::pos2(x + rect.height(), rect.top()),
+                    ],
+                    stroke,
+                );
+                x += 10.0;
+            }
+        }
+
+        let text_pos = egui::pos2(
+            rect.left() + padding.x,
+            rect.center().y - galley.size().y / 2.0,
+        );
+        painter.galley(text_pos, galley, self.theme.base_content);
+
+        response
+    }
+}
+
+/// Makes its contents draggable, carrying a payload for a [`DropTarget`].
+///
+/// While dragged, the contents follow the pointer as a ghost in a themed
+/// frame and a placeholder stays in their place.
+pub struct DragSource<'a, T> {
+    pub id: egui::Id,
+    pub payload: T,
+    pub theme: &'a KonnektorenTheme,
+    pub enabled: bool,
+}
+
+impl<'a, T: Send + Sync + 'static> DragSource<'a, T> {
+    pub fn new(id_salt: impl std::hash::Hash, payload: T, theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            id: egui::Id::new(id_salt),
+            payload,
+            theme,
+            enabled: true,
+        }
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Whether a payload of type `T` is being dragged
+    pub fn is_dragging(ctx: &egui::Context) -> bool {
+        egui::DragAndDrop::has_payload_of_type::<T>(ctx)
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut egui::Ui,
+        add_contents: impl FnOnce(&mut egui::Ui) -> R,
+    ) -> egui::InnerResponse<R> {
+        let theme = self.theme;
+
+        if self.enabled && ui.ctx().is_being_dragged(self.id) {
+            egui::DragAndDrop::set_payload(ui.ctx(), self.payload);
+            ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
+
+            // Paint the contents on a layer above everything, moved with the pointer
+            let layer_id = egui::LayerId::new(egui::Order::Tooltip, self.id);
+            let ghost = ui.scope_builder(egui::UiBuilder::new().layer_id(layer_id), |ui| {
+                egui::Frame::NONE
+                    .fill(theme.base_100)
+                    .stroke(egui::Stroke::new(2.0, theme.primary))
+                    .corner_radius(theme.radius)
+                    .shadow(ui.style().visuals.popup_shadow)
+                    .show(ui, add_contents)
+                    .inner
+            });
+            ui.painter().rect(
+                ghost.response.rect,
+                theme.radius,
+                theme.base_200,
+                egui::Stroke::new(1.0, theme.base_300),
+                egui::StrokeKind::Inside,
+            );
+
+            if let Some(pointer) = ui.ctx().pointer_interact_pos() {
+                let delta = pointer - ghost.response.rect.center();
+                ui.ctx().transform_layer_shapes(
+                    layer_id,
+                    egui::emath::TSTransform::from_translation(delta),
+                );
+            }
+            return ghost;
+        }
+
+        let inner = ui.scope(add_contents);
+        if !self.enabled {
+            return inner;
+        }
+
+        let response = ui
+            .interact(inner.response.rect, self.id, egui::Sense::drag())
+            .on_hover_cursor(egui::CursorIcon::Grab);
+        if response.hovered() {
+            ui.painter().rect_stroke(
+                response.rect,
+                theme.radius,
+                egui::Stroke::new(1.0, theme.primary.linear_multiply(0.5)),
+                egui::StrokeKind::Outside,
+            );
+        }
+        egui::InnerResponse::new(inner.inner, response | inner.response)
+    }
+}
+
+/// Area accepting payloads of type `T` dragged from a [`DragSource`].
+///
+/// The area is outlined while a matching payload is dragged and highlighted
+/// when it is dragged over it. Payloads of other types are ignored.
+pub struct DropTarget<'a, T> {
+    pub theme: &'a KonnektorenTheme,
+    pub min_size: egui::Vec2,
+    payload: std::marker::PhantomData<T>,
+}
+
+impl<'a, T: Send + Sync + 'static> DropTarget<'a, T> {
+    pub fn new(theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            theme,
+            min_size: egui::Vec2::ZERO,
+            payload: std::marker::PhantomData,
+        }
+    }
+
+    pub fn min_size(mut self, min_size: egui::Vec2) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Show the target, returning the payload dropped on it in this frame
+    pub fn show<R>(
+        self,
+        ui: &mut egui::Ui,
+        add_contents: impl FnOnce(&mut egui::Ui) -> R,
+    ) -> (egui::InnerResponse<R>, Option<std::sync::Arc<T>>) {
+        let theme = self.theme;
+        let can_accept = egui::DragAndDrop::has_payload_of_type::<T>(ui.ctx());
+
+        let mut frame = egui::Frame::NONE
+            .inner_margin(8.0)
+            .corner_radius(theme.radius)
+            .begin(ui);
+        frame.content_ui.set_min_size(self.min_size);
+        let inner = add_contents(&mut frame.content_ui);
+        let response = frame.allocate_space(ui);
+
+        let (fill, stroke) = if can_accept && response.contains_pointer() {
+            (
+                theme.primary.linear_multiply(0.2),
+                egui::Stroke::new(2.0, theme.primary),
+            )
+        } else if can_accept {
+            (
+                theme.primary.linear_multiply(0.05),
+                egui::Stroke::new(1.5, theme.primary.linear_multiply(0.6)),
+            )
+        } else {
+            (
+                egui::Color32::TRANSPARENT,
+                egui::Stroke::new(1.0, theme.base_300),
+            )
+        };
+        frame.frame.fill = fill;
+        frame.frame.stroke = stroke;
+        frame.paint(ui);
+
+        let payload = response.dnd_release_payload::<T>();
+        (egui::InnerResponse::new(inner, response), payload)
+    }
+}
+
+/// Breadcrumb trail like "Home › Settings › Input".
+///
+/// Every segment but the last, which is the current screen, can be clicked.
+/// On mobile the middle segments collapse into "…" for long trails.
+pub struct ThemedBreadcrumbs<'a> {
+    pub segments: &'a [&'a str],
+    pub theme: &'a KonnektorenTheme,
+    pub separator: &'a str,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> ThemedBreadcrumbs<'a> {
+    pub fn new(segments: &'a [&'a str], theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            segments,
+            theme,
+            separator: "›",
+            responsive_info: None,
+        }
+    }
+
+    pub fn separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+
+    /// Show the trail, returning the index of the clicked segment
+    pub fn show(self, ui: &mut egui::Ui) -> egui::InnerResponse<Option<usize>> {
+        let font_size = self.responsive_info.map_or(14.0, |responsive_info| {
+            responsive_info.font_size(ResponsiveFontSize::Small)
+        });
+        let collapse = self
+            .responsive_info
+            .is_some_and(|responsive_info| responsive_info.is_mobile())
+            && self.segments.len() > 3;
+        let last = self.segments.len().saturating_sub(1);
+
+        ui.horizontal_wrapped(|ui| {
+            let mut clicked = None;
+            for (index, segment) in self.segments.iter().enumerate() {
+                if collapse && index > 0 && index < last - 1 {
+                    // Only the first of the hidden segments leaves a trace
+                    if index == 1 {
+                        ui.label(
+                            egui::RichText::new("…")
+                                .size(font_size)
+                                .color(self.theme.base_300),
+                        );
+                        self.show_separator(ui, font_size);
+                    }
+                    continue;
+                }
+
+                if index == last {
+                    ui.label(
+                        egui::RichText::new(*segment)
+                            .size(font_size)
+                            .strong()
+                            .color(self.theme.primary),
+                    );
+                } else {
+                    let link = egui::Button::new(
+                        egui::RichText::new(*segment)
+                            .size(font_size)
+                            .color(self.theme.base_content),
+                    )
+                    .frame(false);
+                    let response = ui.add(link).on_hover_cursor(egui::CursorIcon::PointingHand);
+                    if response.hovered() {
+                        let rect = response.rect;
+                        ui.painter().hline(
+                            rect.x_range(),
+                            rect.bottom(),
+                            egui::Stroke::new(1.0, self.theme.primary),
+                        );
+                    }
+                    if response.clicked() {
+                        clicked = Some(index);
+                    }
+                    self.show_separator(ui, font_size);
+                }
+            }
+            clicked
+        })
+    }
+
+    fn show_separator(&self, ui: &mut egui::Ui, font_size: f32) {
+        ui.label(
+            egui::RichText::new(self.separator)
+                .size(font_size)
+                .color(self.theme.base_300),
+        );
+    }
+}
+
+/// Page navigation for long lists with prev/next, page numbers and an
+/// optional jump-to-page field.
+///
+/// `page` is zero-based. The returned response is marked changed when the
+/// page was switched. On mobile, or with [`compact`](Self::compact), only
+/// "‹ 3 / 20 ›" is shown.
+pub struct ThemedPagination<'a> {
+    pub page: &'a mut usize,
+    pub page_count: usize,
+    pub theme: &'a KonnektorenTheme,
+    pub compact: bool,
+    pub jump_to_page: bool,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> ThemedPagination<'a> {
+    pub fn new(page: &'a mut usize, page_count: usize, theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            page,
+            page_count,
+            theme,
+            compact: false,
+            jump_to_page: false,
+            responsive_info: None,
+        }
+    }
+
+    /// Number of pages needed for `item_count` items
+    pub fn page_count(item_count: usize, items_per_page: usize) -> usize {
+        item_count.div_ceil(items_per_page.max(1)).max(1)
+    }
+
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    pub fn jump_to_page(mut self, jump_to_page: bool) -> Self {
+        self.jump_to_page = jump_to_page;
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+
+    fn page_button(&self, ui: &mut egui::Ui, text: &str, selected: bool, font_size: f32) -> bool {
+        let (text_color, fill) = if selected {
+            (self.theme.primary_content, self.theme.primary)
+        } else {
+            (self.theme.base_content, self.theme.base_200)
+        };
+        let button = egui::Button::new(egui::RichText::new(text).size(font_size).color(text_color))
+            .fill(fill)
+            .min_size(egui::vec2(font_size * 2.0, font_size * 2.0));
+        ui.add(button).clicked() && !selected
+    }
+}
+
+/// Page numbers to show around the current page, `None` for a gap
+fn visible_pages(page: usize, page_count: usize) -> Vec<Option<usize>> {
+    let mut pages = Vec::new();
+    for index in 0..page_count {
+        let near_current = index + 1 >= page && index <= page + 1;
+        if index == 0 || index + 1 == page_count || near_current {
+            pages.push(Some(index));
+        } else if pages.last() != Some(&None) {
+            pages.push(None);
+        }
+    }
+    pages
+}
+
+impl<'a> egui::Widget for ThemedPagination<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let font_size = self.responsive_info.map_or(16.0, |responsive_info| {
+            responsive_info.font_size(ResponsiveFontSize::Medium)
+        });
+        let compact = self.compact
+            || self
+                .responsive_info
+                .is_some_and(|responsive_info| responsive_info.is_mobile());
+        let page_count = self.page_count.max(1);
+        let mut page = (*self.page).min(page_count - 1);
+
+        let mut response = ui
+            .horizontal(|ui| {
+                ui.add_enabled_ui(page > 0, |ui| {
+                    if self.page_button(ui, "‹", false, font_size) {
+                        page -= 1;
+                    }
+                });
+
+                if compact {
+                    ui.label(
+                        egui::RichText::new(format!("{} / {}", page + 1, page_count))
+                            .size(font_size)
+                            .color(self.theme.base_content),
+                    );
+                } else {
+                    for entry in visible_pages(page, page_count) {
+                        match entry {
+                            Some(index) => {
+                                let text = (index + 1).to_string();
+                                if self.page_button(ui, &text, index == page, font_size) {
+                                    page = index;
+                                }
+                            }
+                            None => {
+                                ui.label(
+                                    egui::RichText::new("…")
+                                        .size(font_size)
+                                        .color(self.theme.base_300),
+                                );
+                            }
+                        }
+                    }
+                }
+
+                ui.add_enabled_ui(page + 1 < page_count, |ui| {
+                    if self.page_button(ui, "›", false, font_size) {
+                        page += 1;
+                    }
+                });
+
+                if self.jump_to_page && !compact {
+                    ui.add_space(font_size);
+                    ui.label(
+                        egui::RichText::new("Go to")
+                            .size(font_size)
+                            .color(self.theme.base_content),
+                    );
+                    let mut number = page + 1;
+                    ui.add(egui::DragValue::new(&mut number).range(1..=page_count));
+                    page = number.clamp(1, page_count) - 1;
+                }
+            })
+            .response;
+
+        if page != *self.page {
+            *self.page = page;
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// GitHub-style calendar of practice activity.
+///
+/// One column per week ending with `end`, one row per weekday starting on
+/// Monday. Days are shaded from the base to the primary color by their
+/// count relative to the busiest day. The number of weeks shrinks to the
+/// available width.
+pub struct CalendarHeatmap<'a> {
+    pub days: &'a std::collections::BTreeMap<chrono::NaiveDate, u32>,
+    pub theme: &'a KonnektorenTheme,
+    /// Last day shown, today by default
+    pub end: chrono::NaiveDate,
+    pub weeks: usize,
+    pub cell_size: f32,
+    pub show_legend: bool,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> CalendarHeatmap<'a> {
+    pub fn new(
+        days: &'a std::collections::BTreeMap<chrono::NaiveDate, u32>,
+        theme: &'a KonnektorenTheme,
+    ) -> Self {
+        Self {
+            days,
+            theme,
+            end: chrono::Local::now().date_naive(),
+            weeks: 26,
+            cell_size: 12.0,
+            show_legend: true,
+            responsive_info: None,
+        }
+    }
+
+    pub fn end(mut self, end: chrono::NaiveDate) -> Self {
+        self.end = end;
+        self
+    }
+
+    pub fn weeks(mut self, weeks: usize) -> Self {
+        self.weeks = weeks.max(1);
+        self
+    }
+
+    pub fn cell_size(mut self, cell_size: f32) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Self {
+        self.show_legend = show_legend;
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+
+    /// Color of an activity level between 0 and 4
+    fn level_color(&self, level: u32) -> egui::Color32 {
+        if level == 0 {
+            return self.theme.base_200;
+        }
+        let t = level as f32 / 4.0;
+        let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        let (from, to) = (self.theme.base_300, self.theme.primary);
+        egui::Color32::from_rgb(
+            lerp(from.r(), to.r()),
+            lerp(from.g(), to.g()),
+            lerp(from.b(), to.b()),
+        )
+    }
+}
+
+impl<'a> egui::Widget for CalendarHeatmap<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        use chrono::Datelike;
+
+        let font_size = self.responsive_info.map_or(12.0, |responsive_info| {
+            responsive_info.font_size(ResponsiveFontSize::Small)
+        });
+        let cell = self.cell_size;
+        let gap = (cell * 0.25).max(2.0);
+        let label_width = font_size * 2.5;
+        let header_height = font_size + gap;
+        let legend_height = if self.show_legend {
+            cell + gap * 2.0
+        } else {
+            0.0
+        };
+
+        let fitting = ((ui.available_width() - label_width + gap) / (cell + gap)).floor() as usize;
+        let weeks = self.weeks.min(fitting.max(1));
+
+        // First column starts on the Monday `weeks - 1` weeks before the end
+        let offset = self.end.weekday().num_days_from_monday() as u64;
+        let start = self
+            .end
+            .checked_sub_days(chrono::Days::new(offset + 7 * (weeks as u64 - 1)))
+            .unwrap_or(self.end);
+        let max_count = self
+            .days
+            .range(start..=self.end)
+            .map(|(_, count)| *count)
+            .max()
+            .unwrap_or_default();
+        let active_days = self
+            .days
+            .range(start..=self.end)
+            .filter(|(_, count)| **count > 0)
+            .count();
+
+        let size = egui::vec2(
+            label_width + weeks as f32 * (cell + gap),
+            header_height + 7.0 * (cell + gap) + legend_height,
+        );
+        let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+        response.widget_info(|| {
+            egui::WidgetInfo::labeled(
+                egui::WidgetType::Other,
+                true,
+                format!(
+                    "Practice activity: {} days in the last {} weeks",
+                    active_days, weeks
+                ),
+            )
+        });
+
+        let painter = ui.painter_at(rect);
+        let font = egui::FontId::proportional(font_size);
+        let grid_origin = rect.min + egui::vec2(label_width, header_height);
+
+        for (row, name) in [(0, "Mon"), (2, "Wed"), (4, "Fri")] {
+            painter.text(
+                egui::pos2(
+                    rect.left(),
+                    grid_origin.y + row as f32 * (cell + gap) + cell / 2.0,
+                ),
+                egui::Align2::LEFT_CENTER,
+                name,
+                font.clone(),
+                self.theme.base_content,
+            );
+        }
+
+        let mut hovered = None;
+        let pointer = response.hover_pos();
+        for week in 0..weeks {
+            let column_x = grid_origin.x + week as f32 * (cell + gap);
+            for weekday in 0..7 {
+                let Some(date) =
+                    start.checked_add_days(chrono::Days::new((week * 7 + weekday) as u64))
+                else {
+                    continue;
+                };
+                if date > self.end {
+                    break;
+                }
+
+                // Month name above the first week starting in it
+                if weekday == 0 && date.day() <= 7 {
+                    painter.text(
+                        egui::pos2(column_x, rect.top()),
+                        egui::Align2::LEFT_TOP,
+                        date.format("%b").to_string(),
+                        font.clone(),
+                        self.theme.base_content,
+                    );
+                }
+
+                let count = self.days.get(&date).copied().unwrap_or_default();
+                let level = if count == 0 || max_count == 0 {
+                    0
+                } else {
+                    (count * 4).div_ceil(max_count).clamp(1, 4)
+                };
+                let cell_rect = egui::Rect::from_min_size(
+                    egui::pos2(column_x, grid_origin.y + weekday as f32 * (cell + gap)),
+                    egui::vec2(cell, cell),
+                );
+                painter.rect_filled(cell_rect, 2.0, self.level_color(level));
+
+                if pointer.is_some_and(|pointer| cell_rect.expand(gap / 2.0).contains(pointer)) {
+                    painter.rect_stroke(
+                        cell_rect,
+                        2.0,
+                        egui::Stroke::new(1.0, self.theme.base_content),
+                        egui::StrokeKind::Outside,
+                    );
+                    hovered = Some((date, count));
+                }
+            }
+        }
+
+        if self.show_legend {
+            let y = rect.bottom() - cell - gap / 2.0;
+            let mut x = rect.right() - 5.0 * (cell + gap) - font_size * 3.0;
+            painter.text(
+                egui::pos2(x - gap, y + cell / 2.0),
+                egui::Align2::RIGHT_CENTER,
+                "Less",
+                font.clone(),
+                self.theme.base_content,
+            );
+            for level in 0..=4 {
+                let legend_rect =
+                    egui::Rect::from_min_size(egui::pos2(x, y), egui::vec2(cell, cell));
+                painter.rect_filled(legend_rect, 2.0, self.level_color(level));
+                x += cell + gap;
+            }
+            painter.text(
+                egui::pos2(x, y + cell / 2.0),
+                egui::Align2::LEFT_CENTER,
+                "More",
+                font,
+                self.theme.base_content,
+            );
+        }
+
+        match hovered {
+            Some((date, count)) => response.on_hover_text_at_pointer(format!(
+                "{} on {}",
+                match count {
+                    1 => "1 session".to_string(),
+                    count => format!("{} sessions", count),
+                },
+                date.format("%Y-%m-%d")
+            )),
+            None => response,
+        }
+    }
+}
+
+/// Delay before a held stepper button starts repeating, in seconds
+const STEPPER_REPEAT_DELAY: f64 = 0.4;
+
+/// "-" value "+" control for numeric settings.
+///
+/// Holding a button repeats the step with increasing speed, the mouse wheel
+/// over the control steps up and down, and the arrow keys step while one of
+/// the buttons has keyboard focus, without moving the focus. The caller
+/// applies the returned steps, e.g. `(value + steps as f32 * step).max(min).min(max)`.
+pub struct ThemedStepper<'a> {
+    pub value_text: &'a str,
+    pub theme: &'a KonnektorenTheme,
+    pub button_width: f32,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+/// Outcome of showing a [`ThemedStepper`]
+pub struct StepperResponse {
+    pub response: egui::Response,
+    /// Steps to apply in this frame, negative to decrease the value
+    pub steps: i32,
+}
+
+impl<'a> ThemedStepper<'a> {
+    pub fn new(value_text: &'a str, theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            value_text,
+            theme,
+            button_width: 30.0,
+            responsive_info: None,
+        }
+    }
+
+    pub fn button_width(mut self, width: f32) -> Self {
+        self.button_width = width;
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+
+    pub fn show(self, ui: &mut egui::Ui) -> StepperResponse {
+        let spacing = self.responsive_info.map_or(8.0, |responsive_info| {
+            responsive_info.spacing(super::responsive::ResponsiveSpacing::Small)
+        });
+
+        let inner = ui.horizontal(|ui| {
+            let button = |label| {
+                // Egui turns a press held longer than a click into a drag,
+                // sensing drags keeps the button pressed while held
+                let button = ThemedButton::new(label, self.theme)
+                    .width(self.button_width)
+                    .with_style(|button| button.sense(egui::Sense::click_and_drag()));
+                match self.responsive_info {
+                    Some(responsive_info) => button.responsive(responsive_info),
+                    None => button,
+                }
+            };
+
+            let decrease = ui.add(button("-"));
+            ui.add_space(spacing);
+            let mut text = ResponsiveText::new(
+                self.value_text,
+                ResponsiveFontSize::Medium,
+                self.theme.base_content,
+            );
+            if let Some(responsive_info) = self.responsive_info {
+                text = text.responsive(responsive_info);
+            }
+            ui.add(text);
+            ui.add_space(spacing);
+            let increase = ui.add(button("+"));
+
+            let mut steps = repeat_steps(ui, &increase) - repeat_steps(ui, &decrease);
+            for button in [&decrease, &increase] {
+                if button.has_focus() {
+                    // The arrow keys step the value instead of moving the focus
+                    ui.memory_mut(|memory| {
+                        memory.set_focus_lock_filter(
+                            button.id,
+                            egui::EventFilter {
+                                horizontal_arrows: true,
+                                vertical_arrows: true,
+                                ..Default::default()
+                            },
+                        )
+                    });
+                }
+            }
+            if decrease.has_focus() || increase.has_focus() {
+                let (up, down) = ui.input(|input| {
+                    (
+                        input.key_pressed(egui::Key::ArrowRight)
+                            || input.key_pressed(egui::Key::ArrowUp),
+                        input.key_pressed(egui::Key::ArrowLeft)
+                            || input.key_pressed(egui::Key::ArrowDown),
+                    )
+                });
+                steps += i32::from(up) - i32::from(down);
+            }
+            steps
+        });
+
+        let mut steps = inner.inner;
+        let response = inner.response;
+        if response.contains_pointer() {
+            let scroll = ui.input(|input| input.raw_scroll_delta.y);
+            if scroll != 0.0 {
+                steps += scroll.signum() as i32;
+                // Keep a surrounding scroll area from scrolling as well
+                ui.ctx().input_mut(|input| {
+                    input.raw_scroll_delta = egui::Vec2::ZERO;
+                    input.smooth_scroll_delta = egui::Vec2::ZERO;
+                });
+            }
+        }
+
+        response.widget_info(|| {
+            egui::WidgetInfo::labeled(egui::WidgetType::Other, true, self.value_text)
+        });
+        StepperResponse { response, steps }
+    }
+}
+
+/// Steps of a stepper button in this frame: one on press, then after a
+/// delay repeating with increasing speed while the button is held
+fn repeat_steps(ui: &egui::Ui, response: &egui::Response) -> i32 {
+    let id = response.id.with("stepper_hold");
+    let now = ui.input(|input| input.time);
+    // Press time and steps emitted since then
+    let hold: Option<(f64, i32)> = ui.data(|data| data.get_temp(id));
+
+    if response.is_pointer_button_down_on() {
+        let (start, emitted) = hold.unwrap_or((now, 0));
+        let held = (now - start - STEPPER_REPEAT_DELAY).max(0.0);
+        let target = 1 + (8.0 * held + 4.0 * held * held) as i32;
+        ui.data_mut(|data| data.insert_temp(id, (start, target)));
+        ui.ctx().request_repaint();
+        return target - emitted;
+    }
+
+    if hold.is_some() {
+        // Released, the click of the release was already counted on press
+        ui.data_mut(|data| data.remove::<(f64, i32)>(id));
+        return 0;
+    }
+
+    // Keyboard activation, or press and release within a frame
+    i32::from(response.clicked())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestHarness;
+    use bevy_egui::{EguiContexts, EguiPrimaryContextPass};
+
+    #[derive(Resource, Default)]
+    struct StepperValue(i32);
+
+    fn stepper_ui(mut contexts: EguiContexts, mut value: ResMut<StepperValue>) {
+        let Ok(ctx) = contexts.ctx_mut() else {
+            return;
+        };
+        let theme = KonnektorenTheme::default();
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let steps = ThemedStepper::new(&value.0.to_string(), &theme)
+                .show(ui)
+                .steps;
+            if steps != 0 {
+                value.0 += steps;
+            }
+        });
+    }
+
+    fn stepper_harness() -> TestHarness {
+        let mut harness = TestHarness::new();
+        harness
+            .app_mut()
+            .init_resource::<StepperValue>()
+            .add_systems(EguiPrimaryContextPass, stepper_ui);
+        harness.run_frames(2);
+        harness
+    }
+
+    fn value(harness: &TestHarness) -> i32 {
+        harness.world().resource::<StepperValue>().0
+    }
+
+    #[test]
+    fn test_stepper_hold_to_repeat() {
+        let mut harness = stepper_harness();
+        let plus = harness.widget_rect("+").unwrap().center();
+
+        harness.press_at(plus);
+        assert_eq!(value(&harness), 1);
+
+        // Nothing is repeated before the delay, then faster the longer it is held
+        harness.run_frames(12);
+        assert_eq!(value(&harness), 1);
+        harness.run_frames(48);
+        let held = value(&harness);
+        assert!(held > 5, "held value {}", held);
+
+        harness.release_at(plus);
+        harness.run_frames(10);
+        assert_eq!(value(&harness), held);
+    }
+
+    #[test]
+    fn test_stepper_wheel_and_keys() {
+        let mut harness = stepper_harness();
+        let text = harness.widget_rect("0").unwrap().center();
+
+        harness.scroll_at(text, egui::vec2(0.0, 40.0));
+        assert_eq!(value(&harness), 1);
+        harness.scroll_at(text, egui::vec2(0.0, -40.0));
+        harness.scroll_at(text, egui::vec2(0.0, -40.0));
+        assert_eq!(value(&harness), -1);
+
+        // The arrow keys step while a button is focused and keep the focus
+        harness.press_key(egui::Key::Tab);
+        harness.step();
+        assert_eq!(harness.focused_widget().as_deref(), Some("-"));
+        harness.press_key(egui::Key::ArrowRight);
+        harness.step();
+        assert_eq!(value(&harness), 0);
+        assert_eq!(harness.focused_widget().as_deref(), Some("-"));
+        harness.press_key(egui::Key::ArrowDown);
+        assert_eq!(value(&harness), -1);
+    }
+}
@@ -0,0 +1,385 @@
+use super::{
+    responsive::{ResponsiveFontSize, ResponsiveInfo, ResponsiveSpacing},
+    scope::scoped_ui,
+    widgets::ThemedButton,
+};
+use crate::theme::KonnektorenTheme;
+use bevy_egui::egui;
+
+/// Content of a wizard step, returning why the step is not complete yet
+type StepContent<'a, S> = Box<dyn FnMut(&mut egui::Ui, &mut S) -> Result<(), String> + 'a>;
+
+/// Multi-step flow with a numbered steps header and Back/Next navigation.
+///
+/// Each step is a closure showing its content for the shared state `S` and
+/// validating it. Next stays disabled while the current step returns an
+/// error, which is shown below the content. Completed steps can be revisited
+/// from the header. The current step index is owned by the caller.
+///
+/// ```ignore
+/// let wizard = WizardFlow::new(&mut setup.step, &theme)
+///     .step("Name", |ui, profile: &mut NewProfile| {
+///         ui.text_edit_singleline(&mut profile.name);
+///         if profile.name.is_empty() {
+///             Err("Enter a name".to_string())
+///         } else {
+///             Ok(())
+///         }
+///     })
+///     .step("Avatar", |ui, profile| { /* ... */ Ok(()) })
+///     .show(ui, &mut setup.profile);
+/// if wizard.finished { /* ... */ }
+/// ```
+pub struct WizardFlow<'a, S> {
+    pub current: &'a mut usize,
+    pub theme: &'a KonnektorenTheme,
+    steps: Vec<(String, StepContent<'a, S>)>,
+    pub back_text: &'a str,
+    pub next_text: &'a str,
+    pub finish_text: &'a str,
+    /// Text of the back button on the first step, no button if `None`
+    pub cancel_text: Option<&'a str>,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+/// Outcome of showing a [`WizardFlow`]
+pub struct WizardResponse {
+    pub response: egui::Response,
+    /// The step changed in this frame
+    pub step_changed: bool,
+    /// Finish was clicked on the last step
+    pub finished: bool,
+    /// Cancel was clicked on the first step
+    pub cancelled: bool,
+}
+
+impl<'a, S> WizardFlow<'a, S> {
+    pub fn new(current: &'a mut usize, theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            current,
+            theme,
+            steps: vec![],
+            back_text: "← Back",
+            next_text: "Next →",
+            finish_text: "Finish",
+            cancel_text: None,
+            responsive_info: None,
+        }
+    }
+
+    /// Add a step with its title and content
+    pub fn step(
+        mut self,
+        title: impl Into<String>,
+        content: impl FnMut(&mut egui::Ui, &mut S) -> Result<(), String> + 'a,
+    ) -> Self {
+        self.steps.push((title.into(), Box::new(content)));
+        self
+    }
+
+    pub fn button_texts(mut self, back: &'a str, next: &'a str, finish: &'a str) -> Self {
+        self.back_text = back;
+        self.next_text = next;
+        self.finish_text = finish;
+        self
+    }
+
+    pub fn cancel_text(mut self, text: Option<&'a str>) -> Self {
+        self.cancel_text = text;
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+
+    pub fn show(mut self, ui: &mut egui::Ui, state: &mut S) -> WizardResponse {
+        let theme = self.theme;
+        let responsive_info = self.responsive_info;
+        let spacing =
+            |size| responsive_info.map_or(12.0, |responsive_info| responsive_info.spacing(size));
+        let step_count = self.steps.len();
+        let current = (*self.current).min(step_count.saturating_sub(1));
+        let mut target = current;
+        let mut finished = false;
+        let mut cancelled = false;
+
+        let inner = ui.vertical(|ui| {
+            if let Some(step) = self.show_header(ui, current) {
+                target = step;
+            }
+            ui.add_space(spacing(ResponsiveSpacing::Large));
+
+            let Some((_, content)) = self.steps.get_mut(current) else {
+                return;
+            };
+            let validation = scoped_ui(ui, ("wizard_step", current), |ui| content(ui, state));
+
+            if let Err(message) = &validation {
+                ui.add_space(spacing(ResponsiveSpacing::Small));
+                ui.label(egui::RichText::new(message).color(theme.error));
+            }
+            ui.add_space(spacing(ResponsiveSpacing::Large));
+
+            ui.horizontal(|ui| {
+                let back_text = if current == 0 {
+                    self.cancel_text
+                } else {
+                    Some(self.back_text)
+                };
+                if let Some(back_text) = back_text {
+                    let mut back = ThemedButton::new(back_text, theme).with_style(|button| {
+                        button
+                            .fill(theme.base_200)
+                            .stroke(egui::Stroke::new(1.0, theme.base_300))
+                    });
+                    if let Some(responsive_info) = self.responsive_info {
+                        back = back.responsive(responsive_info);
+                    }
+                    if ui.add(back).clicked() {
+                        if current == 0 {
+                            cancelled = true;
+                        } else {
+                            target = current - 1;
+                        }
+                    }
+                }
+
+                let is_last = current + 1 == step_count;
+                let next_text = if is_last {
+                    self.finish_text
+                } else {
+                    self.next_text
+                };
+                let mut next = ThemedButton::new(next_text, theme).enabled(validation.is_ok());
+                if let Some(responsive_info) = self.responsive_info {
+                    next = next.responsive(responsive_info);
+                }
+                if ui.add(next).clicked() {
+                    if is_last {
+                        finished = true;
+                    } else {
+                        target = current + 1;
+                    }
+                }
+            });
+        });
+
+        let step_changed = target != *self.current;
+        *self.current = target;
+        WizardResponse {
+            response: inner.response,
+            step_changed,
+            finished,
+            cancelled,
+        }
+    }
+
+    /// Numbered steps connected by lines, or "Step 2 of 4" on mobile.
+    /// Returns a completed step that was clicked.
+    fn show_header(&self, ui: &mut egui::Ui, current: usize) -> Option<usize> {
+        let theme = self.theme;
+        let font_size = self.responsive_info.map_or(14.0, |responsive_info| {
+            responsive_info.font_size(ResponsiveFontSize::Small)
+        });
+        let (title, _) = self.steps.get(current)?;
+
+        if self
+            .responsive_info
+            .is_some_and(|responsive_info| responsive_info.is_mobile())
+        {
+            ui.label(
+                egui::RichText::new(format!(
+                    "Step {} of {} · {}",
+                    current + 1,
+                    self.steps.len(),
+                    title
+                ))
+                .size(font_size)
+                .color(theme.base_content),
+            );
+            let progress = (current + 1) as f32 / self.steps.len() as f32;
+            ui.add(
+                egui::ProgressBar::new(progress)
+                    .fill(theme.primary)
+                    .desired_height(4.0),
+            );
+            return None;
+        }
+
+        let circle = font_size * 2.0;
+        let slot = (ui.available_width() / self.steps.len() as f32).max(circle);
+        let (rect, _) = ui.allocate_exact_size(
+            egui::vec2(slot * self.steps.len() as f32, circle + font_size * 1.8),
+            egui::Sense::hover(),
+        );
+        let painter = ui.painter_at(rect);
+        let center_of = |index: usize| {
+            egui::pos2(
+                rect.left() + slot * (index as f32 + 0.5),
+                rect.top() + circle / 2.0,
+            )
+        };
+
+        let mut clicked = None;
+        for (index, (title, _)) in self.steps.iter().enumerate() {
+            let center = center_of(index);
+            if index > 0 {
+                let color = if index <= current {
+                    theme.primary
+                } else {
+                    theme.base_300
+                };
+                painter.line_segment(
+                    [
+                        center_of(index - 1) + egui::vec2(circle / 2.0, 0.0),
+                        center - egui::vec2(circle / 2.0, 0.0),
+                    ],
+                    egui::Stroke::new(2.0, color),
+                );
+            }
+
+            let (fill, text_color, text) = if index < current {
+                (theme.success, theme.base_100, "✔".to_string())
+            } else if index == current {
+                (
+                    theme.primary,
+                    theme.primary_content,
+                    (index + 1).to_string(),
+                )
+            } else {
+                (theme.base_200, theme.base_content, (index + 1).to_string())
+            };
+            let circle_rect = egui::Rect::from_center_size(center, egui::vec2(circle, circle));
+
+            // Completed steps can be revisited
+            let response = ui.interact(
+                circle_rect,
+                ui.id().with(("wizard_header", index)),
+                if index < current {
+                    egui::Sense::click()
+                } else {
+                    egui::Sense::hover()
+                },
+            );
+            response.widget_info(|| {
+                egui::WidgetInfo::labeled(
+                    egui::WidgetType::Button,
+                    index < current,
+                    format!("Step {}: {}", index + 1, title),
+                )
+            });
+            if response.clicked() {
+                clicked = Some(index);
+            }
+            let response = if index < current {
+                response.on_hover_cursor(egui::CursorIcon::PointingHand)
+            } else {
+                response
+            };
+
+            painter.circle(
+                center,
+                circle / 2.0,
+                fill,
+                if response.hovered() && index < current {
+                    egui::Stroke::new(2.0, theme.primary)
+                } else {
+                    egui::Stroke::NONE
+                },
+            );
+            painter.text(
+                center,
+                egui::Align2::CENTER_CENTER,
+                text,
+                egui::FontId::proportional(font_size),
+                text_color,
+            );
+            painter.text(
+                egui::pos2(center.x, rect.top() + circle + font_size * 0.4),
+                egui::Align2::CENTER_TOP,
+                title,
+                egui::FontId::proportional(font_size),
+                if index == current {
+                    theme.primary
+                } else {
+                    theme.base_content
+                },
+            );
+        }
+        clicked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestHarness;
+    use bevy::prelude::*;
+    use bevy_egui::{EguiContexts, EguiPrimaryContextPass};
+
+    #[derive(Resource, Default)]
+    struct Setup {
+        step: usize,
+        name: String,
+        finished: bool,
+    }
+
+    fn wizard_ui(mut contexts: EguiContexts, mut setup: ResMut<Setup>) {
+        let Ok(ctx) = contexts.ctx_mut() else {
+            return;
+        };
+        let theme = KonnektorenTheme::default();
+        let Setup {
+            step,
+            name,
+            finished,
+        } = &mut *setup;
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let wizard = WizardFlow::new(step, &theme)
+                .step("Name", |ui, name: &mut String| {
+                    ui.label("Who are you?");
+                    if name.is_empty() {
+                        Err("Enter a name".to_string())
+                    } else {
+                        Ok(())
+                    }
+                })
+                .step("Done", |ui, name| {
+                    ui.label(format!("Welcome {}", name));
+                    Ok(())
+                })
+                .show(ui, name);
+            *finished |= wizard.finished;
+        });
+    }
+
+    #[test]
+    fn test_wizard_validation_and_navigation() {
+        let mut harness = TestHarness::new();
+        harness
+            .app_mut()
+            .init_resource::<Setup>()
+            .add_systems(EguiPrimaryContextPass, wizard_ui);
+        harness.run_frames(2);
+
+        assert!(harness.has_widget("Enter a name"));
+        harness.click("Next →");
+        assert_eq!(harness.world().resource::<Setup>().step, 0);
+
+        harness.world_mut().resource_mut::<Setup>().name = "Ada".to_string();
+        harness.step();
+        harness.click("Next →");
+        assert_eq!(harness.world().resource::<Setup>().step, 1);
+        assert!(harness.has_widget("Welcome Ada"));
+
+        harness.click("Step 1: Name");
+        assert_eq!(harness.world().resource::<Setup>().step, 0);
+
+        harness.click("Next →");
+        harness.click("Finish");
+        assert!(harness.world().resource::<Setup>().finished);
+    }
+}
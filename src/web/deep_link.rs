@@ -0,0 +1,559 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+#[cfg(feature = "assets")]
+use crate::assets::KonnektorenAssetRegistry;
+#[cfg(feature = "screens")]
+use crate::screens::{
+    AboutScreenExt, CreditsScreenExt, InboxScreenExt, RedeemCodeScreenExt, SettingsScreenExt,
+};
+
+/// Query parameter naming the screen to open
+pub const DEEP_LINK_SCREEN_PARAM: &str = "screen";
+
+/// Query parameter naming the challenge to load
+pub const DEEP_LINK_CHALLENGE_PARAM: &str = "challenge";
+
+/// Plugin reading deep links from the page URL at startup.
+///
+/// Supports query parameters (`?screen=about`, `?challenge=articles`) and hash
+/// routes (`#/about`, `#/challenge/articles`). On native builds the deep link
+/// is empty unless the app inserts a [`DeepLink`] before adding the plugin.
+pub struct DeepLinkPlugin {
+    /// Base URL for generated links, defaults to the current page on the web
+    pub base_url: Option<String>,
+    /// Spawn the screen named by the link using [`DeepLinkRoutes`]
+    pub auto_spawn_screen: bool,
+    /// Load the challenge named by the link into the asset registry
+    pub auto_load_challenge: bool,
+    /// Asset path of linked challenges, `{id}` is replaced by the challenge ID.
+    /// Only IDs of ASCII letters, digits, `-` and `_` are loaded.
+    pub challenge_path: String,
+}
+
+impl Default for DeepLinkPlugin {
+    fn default() -> Self {
+        Self {
+            base_url: None,
+            auto_spawn_screen: true,
+            auto_load_challenge: true,
+            challenge_path: "challenges/{id}.yml".to_string(),
+        }
+    }
+}
+
+impl DeepLinkPlugin {
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    pub fn with_challenge_path(mut self, challenge_path: impl Into<String>) -> Self {
+        self.challenge_path = challenge_path.into();
+        self
+    }
+
+    pub fn no_auto_spawn(mut self) -> Self {
+        self.auto_spawn_screen = false;
+        self
+    }
+
+    pub fn no_auto_load(mut self) -> Self {
+        self.auto_load_challenge = false;
+        self
+    }
+}
+
+impl Plugin for DeepLinkPlugin {
+    fn build(&self, app: &mut App) {
+        // Keep a deep link inserted by the app (e.g. from command line arguments)
+        if !app.world().contains_resource::<DeepLink>() {
+            app.insert_resource(DeepLink::from_current_url());
+        }
+
+        let base_url = self
+            .base_url
+            .clone()
+            .unwrap_or_else(DeepLinkConfig::current_base_url);
+
+        app.insert_resource(DeepLinkConfig {
+            base_url,
+            auto_spawn_screen: self.auto_spawn_screen,
+            auto_load_challenge: self.auto_load_challenge,
+            challenge_path: self.challenge_path.clone(),
+        })
+        .init_resource::<DeepLinkRoutes>()
+        .add_message::<DeepLinkEvent>()
+        .add_systems(Startup, apply_deep_link);
+    }
+}
+
+/// A deep link into the app, parsed from a URL
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeepLink {
+    /// Hash route segments, e.g. `["challenge", "articles"]` for `#/challenge/articles`
+    pub path: Vec<String>,
+    /// Query parameters in the order they appear
+    pub params: Vec<(String, String)>,
+}
+
+impl DeepLink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a full URL or just its query and hash part
+    pub fn parse(url: &str) -> Self {
+        let mut link = Self::new();
+
+        let (before_hash, hash) = match url.split_once('#') {
+            Some((before, hash)) => (before, Some(hash)),
+            None => (url, None),
+        };
+
+        if let Some((_, query)) = before_hash.split_once('?') {
+            link.parse_query(query);
+        }
+
+        if let Some(hash) = hash {
+            let (route, query) = match hash.split_once('?') {
+                Some((route, query)) => (route, Some(query)),
+                None => (hash, None),
+            };
+
+            link.path = route
+                .split('/')
+                .filter(|segment| !segment.is_empty())
+                .map(percent_decode)
+                .collect();
+
+            if let Some(query) = query {
+                link.parse_query(query);
+            }
+        }
+
+        link
+    }
+
+    /// Deep link of the page the app runs in, empty on native
+    pub fn from_current_url() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let (search, hash) =
+                browser::LOCATION.with(|location| (location.search(), location.hash()));
+            let link = Self::parse(&format!("{}{}", search, hash));
+            if !link.is_empty() {
+                info!("Parsed deep link: {:?}", link);
+            }
+            link
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            Self::default()
+        }
+    }
+
+    fn parse_query(&mut self, query: &str) {
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            self.set_param(percent_decode(key), percent_decode(value));
+        }
+    }
+
+    /// Set a query parameter, replacing an existing one
+    pub fn set_param(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        let key = key.into();
+        let value = value.into();
+        match self.params.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, existing)) => *existing = value,
+            None => self.params.push((key, value)),
+        }
+    }
+
+    pub fn with_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.set_param(key, value);
+        self
+    }
+
+    pub fn with_screen(self, screen: impl Into<String>) -> Self {
+        self.with_param(DEEP_LINK_SCREEN_PARAM, screen)
+    }
+
+    pub fn with_challenge(self, challenge_id: impl Into<String>) -> Self {
+        self.with_param(DEEP_LINK_CHALLENGE_PARAM, challenge_id)
+    }
+
+    pub fn with_path(mut self, path: Vec<String>) -> Self {
+        self.path = path;
+        self
+    }
+
+    /// Get a query parameter
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Screen to open, from `?screen=` or the first hash route segment
+    pub fn screen(&self) -> Option<&str> {
+        self.get(DEEP_LINK_SCREEN_PARAM).or_else(|| {
+            self.path
+                .first()
+                .map(String::as_str)
+                .filter(|segment| *segment != DEEP_LINK_CHALLENGE_PARAM)
+        })
+    }
+
+    /// Challenge to load, from `?challenge=` or a `#/challenge/<id>` route
+    pub fn challenge(&self) -> Option<&str> {
+        self.get(DEEP_LINK_CHALLENGE_PARAM)
+            .or_else(|| match self.path.as_slice() {
+                [route, id, ..] if route == DEEP_LINK_CHALLENGE_PARAM => Some(id.as_str()),
+                _ => None,
+            })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.path.is_empty() && self.params.is_empty()
+    }
+
+    /// Query and hash part of the link, e.g. `?screen=about#/about`
+    pub fn to_query_string(&self) -> String {
+        let mut result = String::new();
+
+        for (index, (key, value)) in self.params.iter().enumerate() {
+            result.push(if index == 0 { '?' } else { '&' });
+            result.push_str(&percent_encode(key));
+            result.push('=');
+            result.push_str(&percent_encode(value));
+        }
+
+        if !self.path.is_empty() {
+            result.push_str("#/");
+            let segments: Vec<String> = self.path.iter().map(|s| percent_encode(s)).collect();
+            result.push_str(&segments.join("/"));
+        }
+
+        result
+    }
+
+    /// Full shareable URL for this link
+    pub fn to_url(&self, base_url: &str) -> String {
+        format!("{}{}", base_url, self.to_query_string())
+    }
+}
+
+/// Resource with the deep link settings of the app
+#[derive(Resource, Debug, Clone)]
+pub struct DeepLinkConfig {
+    pub base_url: String,
+    pub auto_spawn_screen: bool,
+    pub auto_load_challenge: bool,
+    pub challenge_path: String,
+}
+
+impl DeepLinkConfig {
+    /// Base URL of the running page, empty on native
+    pub fn current_base_url() -> String {
+        #[cfg(target_arch = "wasm32")]
+        {
+            browser::LOCATION
+                .with(|location| format!("{}{}", location.origin(), location.pathname()))
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            String::new()
+        }
+    }
+
+    /// Generate a shareable link
+    pub fn share_link(&self, link: &DeepLink) -> String {
+        link.to_url(&self.base_url)
+    }
+
+    /// Asset path of a linked challenge, `None` if the ID is not a valid
+    /// challenge ID and could point outside the challenge directory
+    pub fn challenge_asset_path(&self, challenge_id: &str) -> Option<String> {
+        is_valid_challenge_id(challenge_id)
+            .then(|| self.challenge_path.replace("{id}", challenge_id))
+    }
+}
+
+/// Function spawning the screen of a deep link route
+pub type DeepLinkRoute = fn(&mut Commands, &DeepLink);
+
+/// Resource mapping screen names of deep links to spawn functions
+#[derive(Resource, Clone)]
+pub struct DeepLinkRoutes {
+    routes: HashMap<String, DeepLinkRoute>,
+}
+
+impl Default for DeepLinkRoutes {
+    fn default() -> Self {
+        #[allow(unused_mut)]
+        let mut routes = Self::empty();
+
+        #[cfg(feature = "screens")]
+        {
+            routes.add_route("about", |commands, _| {
+                commands.spawn_simple_about("About");
+            });
+            routes.add_route("credits", |commands, _| {
+                commands.spawn_simple_credits("Credits");
+            });
+            routes.add_route("settings", |commands, _| {
+                commands.spawn_simple_settings_screen("Settings");
+            });
+            routes.add_route("inbox", |commands, _| {
+                commands.spawn_simple_inbox();
+            });
+            routes.add_route("redeem", |commands, _| {
+                commands.spawn_simple_redeem_code();
+            });
+        }
+
+        routes
+    }
+}
+
+impl DeepLinkRoutes {
+    /// Routes without the built-in screens
+    pub fn empty() -> Self {
+        Self {
+            routes: HashMap::new(),
+        }
+    }
+
+    /// Add or replace a route
+    pub fn add_route(&mut self, screen: impl Into<String>, route: DeepLinkRoute) {
+        self.routes.insert(screen.into(), route);
+    }
+
+    pub fn with_route(mut self, screen: impl Into<String>, route: DeepLinkRoute) -> Self {
+        self.add_route(screen, route);
+        self
+    }
+
+    pub fn get(&self, screen: &str) -> Option<DeepLinkRoute> {
+        self.routes.get(screen).copied()
+    }
+}
+
+/// Events sent while applying the deep link at startup
+#[derive(Message, Debug, Clone)]
+pub enum DeepLinkEvent {
+    /// A screen was spawned for the link
+    ScreenOpened { screen: String },
+    /// The link names a screen without a route
+    UnknownScreen { screen: String },
+    /// The link names a challenge
+    ChallengeRequested { challenge_id: String },
+    /// The link names a challenge with an invalid ID, it is not loaded
+    InvalidChallenge { challenge_id: String },
+}
+
+/// System to spawn the linked screen and load the linked challenge
+pub fn apply_deep_link(
+    mut commands: Commands,
+    link: Res<DeepLink>,
+    config: Res<DeepLinkConfig>,
+    routes: Res<DeepLinkRoutes>,
+    mut events: MessageWriter<DeepLinkEvent>,
+    #[cfg(feature = "assets")] asset_server: Option<Res<AssetServer>>,
+    #[cfg(feature = "assets")] registry: Option<ResMut<KonnektorenAssetRegistry>>,
+) {
+    if link.is_empty() {
+        return;
+    }
+
+    if let Some(screen) = link.screen() {
+        match routes.get(screen) {
+            Some(route) if config.auto_spawn_screen => {
+                info!("Opening deep linked screen '{}'", screen);
+                route(&mut commands, &link);
+                events.write(DeepLinkEvent::ScreenOpened {
+                    screen: screen.to_string(),
+                });
+            }
+            Some(_) => {}
+            None => {
+                warn!("Deep link names unknown screen '{}'", screen);
+                events.write(DeepLinkEvent::UnknownScreen {
+                    screen: screen.to_string(),
+                });
+            }
+        }
+    }
+
+    if let Some(challenge_id) = link.challenge() {
+        if !is_valid_challenge_id(challenge_id) {
+            warn!("Deep link names invalid challenge '{}'", challenge_id);
+            events.write(DeepLinkEvent::InvalidChallenge {
+                challenge_id: challenge_id.to_string(),
+            });
+            return;
+        }
+
+        #[cfg(feature = "assets")]
+        if config.auto_load_challenge {
+            if let (Some(asset_server), Some(mut registry), Some(path)) = (
+                asset_server,
+                registry,
+                config.challenge_asset_path(challenge_id),
+            ) {
+                info!(
+                    "Loading deep linked challenge '{}' from '{}'",
+                    challenge_id, path
+                );
+                registry.register_challenge(challenge_id.to_string(), asset_server.load(path));
+            }
+        }
+
+        events.write(DeepLinkEvent::ChallengeRequested {
+            challenge_id: challenge_id.to_string(),
+        });
+    }
+}
+
+/// Whether a challenge ID only has ASCII letters, digits, `-` and `_`
+fn is_valid_challenge_id(challenge_id: &str) -> bool {
+    !challenge_id.is_empty()
+        && challenge_id
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_')
+}
+
+fn percent_encode(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                result.push(byte as char)
+            }
+            _ => result.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    result
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'+' => result.push(b' '),
+            b'%' if index + 2 < bytes.len() => {
+                let high = (bytes[index + 1] as char).to_digit(16);
+                let low = (bytes[index + 2] as char).to_digit(16);
+                match (high, low) {
+                    (Some(high), Some(low)) => {
+                        result.push((high * 16 + low) as u8);
+                        index += 2;
+                    }
+                    _ => result.push(b'%'),
+                }
+            }
+            byte => result.push(byte),
+        }
+        index += 1;
+    }
+
+    String::from_utf8_lossy(&result).into_owned()
+}
+
+#[cfg(target_arch = "wasm32")]
+mod browser {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        pub type Location;
+
+        #[wasm_bindgen(thread_local_v2, js_namespace = window, js_name = location)]
+        pub static LOCATION: Location;
+
+        #[wasm_bindgen(method, getter)]
+        pub fn search(this: &Location) -> String;
+
+        #[wasm_bindgen(method, getter)]
+        pub fn hash(this: &Location) -> String;
+
+        #[wasm_bindgen(method, getter)]
+        pub fn origin(this: &Location) -> String;
+
+        #[wasm_bindgen(method, getter)]
+        pub fn pathname(this: &Location) -> String;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deep_link_parsing() {
+        let link = DeepLink::parse(
+            "https://example.org/game/?screen=about&name=Max%20M#/challenge/articles",
+        );
+        assert_eq!(link.screen(), Some("about"));
+        assert_eq!(link.challenge(), Some("articles"));
+        assert_eq!(link.get("name"), Some("Max M"));
+
+        let link = DeepLink::parse("#/credits");
+        assert_eq!(link.screen(), Some("credits"));
+        assert_eq!(link.challenge(), None);
+
+        assert!(DeepLink::parse("https://example.org/").is_empty());
+    }
+
+    #[test]
+    fn test_deep_link_roundtrip() {
+        let link = DeepLink::new()
+            .with_screen("about")
+            .with_challenge("articles")
+            .with_param("text", "a&b c");
+
+        let url = link.to_url("https://example.org/");
+        assert_eq!(
+            url,
+            "https://example.org/?screen=about&challenge=articles&text=a%26b%20c"
+        );
+        assert_eq!(DeepLink::parse(&url), link);
+    }
+
+    #[test]
+    fn test_deep_link_rejects_invalid_challenge() {
+        let config = DeepLinkConfig {
+            base_url: String::new(),
+            auto_spawn_screen: true,
+            auto_load_challenge: true,
+            challenge_path: "challenges/{id}.yml".to_string(),
+        };
+        assert_eq!(
+            config.challenge_asset_path("articles_2"),
+            Some("challenges/articles_2.yml".to_string())
+        );
+        assert_eq!(config.challenge_asset_path("../../secrets"), None);
+
+        let mut app = App::new();
+        app.insert_resource(DeepLink::parse("?challenge=..%2F..%2Fsecrets"))
+            .add_plugins(DeepLinkPlugin::default());
+        app.update();
+
+        let events: Vec<DeepLinkEvent> = app
+            .world_mut()
+            .resource_mut::<Messages<DeepLinkEvent>>()
+            .drain()
+            .collect();
+        assert!(matches!(
+            events.as_slice(),
+            [DeepLinkEvent::InvalidChallenge { challenge_id }] if challenge_id == "../../secrets"
+        ));
+    }
+}
@@ -0,0 +1,29 @@
+pub mod deep_link;
+pub mod pwa;
+pub mod share;
+pub mod speech;
+
+pub use deep_link::*;
+pub use pwa::*;
+pub use share::*;
+pub use speech::*;
+
+use bevy::prelude::*;
+
+/// Plugin bundling the web platform integrations (deep links, sharing,
+/// PWA installation).
+///
+/// Everything also works on native builds, where the browser specific parts
+/// fall back to sensible defaults.
+pub struct WebPlugin;
+
+impl Plugin for WebPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<DeepLinkPlugin>() {
+            app.add_plugins(DeepLinkPlugin::default());
+        }
+        app.add_plugins((SharePlugin, PwaPlugin));
+
+        info!("WebPlugin loaded with deep link, share and PWA install support");
+    }
+}
@@ -0,0 +1,251 @@
+use crate::{theme::KonnektorenTheme, ui::responsive::ResponsiveInfo, ui::widgets::ThemedButton};
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+/// Plugin for installing the game as a progressive web app.
+///
+/// On WASM the `beforeinstallprompt` event is captured as soon as the plugin
+/// is built, so no JavaScript glue in the page is needed. On native builds the
+/// install state stays unavailable.
+pub struct PwaPlugin;
+
+impl Plugin for PwaPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(target_arch = "wasm32")]
+        browser::listen_for_install_prompt();
+
+        app.init_resource::<PwaInstallAvailable>()
+            .add_message::<PwaInstallRequest>()
+            .add_message::<PwaInstallEvent>()
+            .add_systems(
+                Update,
+                (update_pwa_install_state, handle_pwa_install_requests).chain(),
+            );
+    }
+}
+
+/// Resource telling whether the browser offers to install the app
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PwaInstallAvailable {
+    /// The install prompt can be shown
+    pub available: bool,
+    /// The app was installed during this session
+    pub installed: bool,
+}
+
+impl PwaInstallAvailable {
+    pub fn is_available(&self) -> bool {
+        self.available && !self.installed
+    }
+}
+
+/// Message requesting the native install prompt
+#[derive(Message, Debug, Clone, Copy)]
+pub struct PwaInstallRequest;
+
+/// Events about the app installation
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PwaInstallEvent {
+    /// The browser offers to install the app
+    Available,
+    /// The install prompt was shown
+    Prompted,
+    /// The app was installed
+    Installed,
+}
+
+/// System to mirror the browser install state into [`PwaInstallAvailable`]
+pub fn update_pwa_install_state(
+    mut install_state: ResMut<PwaInstallAvailable>,
+    mut events: MessageWriter<PwaInstallEvent>,
+) {
+    let (available, installed) = browser_install_state();
+
+    if available && !install_state.available {
+        info!("PWA install prompt available");
+        events.write(PwaInstallEvent::Available);
+    }
+    if installed && !install_state.installed {
+        info!("PWA installed");
+        events.write(PwaInstallEvent::Installed);
+    }
+
+    install_state.set_if_neq(PwaInstallAvailable {
+        available,
+        installed,
+    });
+}
+
+/// System to show the install prompt when requested
+pub fn handle_pwa_install_requests(
+    mut requests: MessageReader<PwaInstallRequest>,
+    install_state: Res<PwaInstallAvailable>,
+    mut events: MessageWriter<PwaInstallEvent>,
+) {
+    if requests.read().last().is_none() {
+        return;
+    }
+
+    if !install_state.is_available() {
+        warn!("PWA install requested but no install prompt is available");
+        return;
+    }
+
+    if show_install_prompt() {
+        events.write(PwaInstallEvent::Prompted);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn browser_install_state() -> (bool, bool) {
+    (browser::has_install_prompt(), browser::is_installed())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn browser_install_state() -> (bool, bool) {
+    (false, false)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn show_install_prompt() -> bool {
+    browser::show_install_prompt()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn show_install_prompt() -> bool {
+    false
+}
+
+#[cfg(target_arch = "wasm32")]
+mod browser {
+    use bevy::prelude::*;
+    use std::cell::{Cell, RefCell};
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        pub type BeforeInstallPromptEvent;
+
+        #[wasm_bindgen(method, js_name = preventDefault)]
+        fn prevent_default(this: &BeforeInstallPromptEvent);
+
+        #[wasm_bindgen(catch, method)]
+        fn prompt(this: &BeforeInstallPromptEvent) -> Result<JsValue, JsValue>;
+
+        #[wasm_bindgen(js_namespace = window, js_name = addEventListener)]
+        fn add_prompt_listener(
+            event: &str,
+            listener: &Closure<dyn FnMut(BeforeInstallPromptEvent)>,
+        );
+
+        #[wasm_bindgen(js_namespace = window, js_name = addEventListener)]
+        fn add_installed_listener(event: &str, listener: &Closure<dyn FnMut(JsValue)>);
+    }
+
+    thread_local! {
+        static DEFERRED_PROMPT: RefCell<Option<BeforeInstallPromptEvent>> = const { RefCell::new(None) };
+        static INSTALLED: Cell<bool> = const { Cell::new(false) };
+        static LISTENING: Cell<bool> = const { Cell::new(false) };
+    }
+
+    pub fn listen_for_install_prompt() {
+        if LISTENING.with(|listening| listening.replace(true)) {
+            return;
+        }
+
+        let on_prompt = Closure::<dyn FnMut(BeforeInstallPromptEvent)>::new(
+            |event: BeforeInstallPromptEvent| {
+                // Keep the browser from showing its own mini info bar
+                event.prevent_default();
+                DEFERRED_PROMPT.with(|prompt| *prompt.borrow_mut() = Some(event));
+            },
+        );
+        add_prompt_listener("beforeinstallprompt", &on_prompt);
+        on_prompt.forget();
+
+        let on_installed = Closure::<dyn FnMut(JsValue)>::new(|_| {
+            INSTALLED.with(|installed| installed.set(true));
+            DEFERRED_PROMPT.with(|prompt| prompt.borrow_mut().take());
+        });
+        add_installed_listener("appinstalled", &on_installed);
+        on_installed.forget();
+    }
+
+    pub fn has_install_prompt() -> bool {
+        DEFERRED_PROMPT.with(|prompt| prompt.borrow().is_some())
+    }
+
+    pub fn is_installed() -> bool {
+        INSTALLED.with(Cell::get)
+    }
+
+    /// Show the deferred prompt, it can only be used once
+    pub fn show_install_prompt() -> bool {
+        let Some(event) = DEFERRED_PROMPT.with(|prompt| prompt.borrow_mut().take()) else {
+            return false;
+        };
+
+        match event.prompt() {
+            Ok(_) => true,
+            Err(err) => {
+                warn!("Could not show PWA install prompt: {:?}", err);
+                false
+            }
+        }
+    }
+}
+
+/// Themed "Install app" button, only shown while the app can be installed.
+///
+/// Send a [`PwaInstallRequest`] when it is clicked.
+pub struct InstallAppButton<'a> {
+    pub label: &'a str,
+    pub theme: &'a KonnektorenTheme,
+    pub install_state: &'a PwaInstallAvailable,
+    pub width: Option<f32>,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> InstallAppButton<'a> {
+    pub fn new(theme: &'a KonnektorenTheme, install_state: &'a PwaInstallAvailable) -> Self {
+        Self {
+            label: "Install app",
+            theme,
+            install_state,
+            width: None,
+            responsive_info: None,
+        }
+    }
+
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.label = label;
+        self
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+
+    /// Show the button, returns `None` when the app cannot be installed
+    pub fn show(self, ui: &mut egui::Ui) -> Option<egui::Response> {
+        if !self.install_state.is_available() {
+            return None;
+        }
+
+        let mut button = ThemedButton::new(self.label, self.theme);
+        if let Some(responsive_info) = self.responsive_info {
+            button = button.responsive(responsive_info);
+        }
+        if let Some(width) = self.width {
+            button = button.width(width);
+        }
+
+        Some(button.show(ui))
+    }
+}
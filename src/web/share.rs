@@ -0,0 +1,343 @@
+use super::deep_link::{DeepLink, DeepLinkConfig};
+#[cfg(feature = "settings")]
+use crate::settings::ParentalControls;
+use crate::{
+    theme::KonnektorenTheme,
+    ui::{notifications::Notification, responsive::ResponsiveInfo, widgets::ThemedButton},
+};
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts, EguiPrimaryContextPass};
+
+/// Plugin for sharing results.
+///
+/// Shared results are copied to the clipboard on desktop. On mobile browsers
+/// the Web Share API is used, falling back to the clipboard when unavailable.
+/// With the `settings` feature, requests are refused while the
+/// [`ParentalControls`] block sharing.
+pub struct SharePlugin;
+
+impl Plugin for SharePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<ShareRequest>()
+            .add_message::<ShareEvent>()
+            .add_message::<Notification>()
+            .add_systems(EguiPrimaryContextPass, handle_share_requests);
+    }
+}
+
+/// A result summary that can be shared
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShareResult {
+    pub title: String,
+    pub score: Option<u32>,
+    pub max_score: Option<u32>,
+    pub streak: Option<u32>,
+    pub message: Option<String>,
+    pub link: Option<String>,
+}
+
+impl ShareResult {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_score(mut self, score: u32, max_score: u32) -> Self {
+        self.score = Some(score);
+        self.max_score = Some(max_score);
+        self
+    }
+
+    pub fn with_streak(mut self, streak: u32) -> Self {
+        self.streak = Some(streak);
+        self
+    }
+
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    pub fn with_link(mut self, link: impl Into<String>) -> Self {
+        self.link = Some(link.into());
+        self
+    }
+
+    /// Link back into the app using the configured base URL
+    pub fn with_deep_link(self, config: &DeepLinkConfig, link: &DeepLink) -> Self {
+        self.with_link(config.share_link(link))
+    }
+
+    /// Summary text without the link
+    pub fn text(&self) -> String {
+        let mut lines = vec![self.title.clone()];
+
+        match (self.score, self.max_score) {
+            (Some(score), Some(max_score)) => lines.push(format!("Score: {}/{}", score, max_score)),
+            (Some(score), None) => lines.push(format!("Score: {}", score)),
+            _ => {}
+        }
+
+        if let Some(streak) = self.streak {
+            lines.push(format!("Streak: {}", streak));
+        }
+
+        if let Some(message) = &self.message {
+            lines.push(message.clone());
+        }
+
+        lines.join("\n")
+    }
+
+    /// Formatted summary including the link
+    pub fn summary(&self) -> String {
+        match &self.link {
+            Some(link) => format!("{}\n{}", self.text(), link),
+            None => self.text(),
+        }
+    }
+}
+
+/// Message requesting to share a result
+#[derive(Message, Debug, Clone)]
+pub struct ShareRequest {
+    pub result: ShareResult,
+}
+
+impl ShareRequest {
+    pub fn new(result: ShareResult) -> Self {
+        Self { result }
+    }
+}
+
+/// Events sent after a result was shared
+#[derive(Message, Debug, Clone)]
+pub enum ShareEvent {
+    /// The result was handed to the Web Share API
+    Shared { title: String },
+    /// The result summary was copied to the clipboard
+    Copied { title: String },
+    /// Sharing is blocked by the parental controls, nothing was shared
+    Blocked { title: String },
+}
+
+/// System to share requested results
+pub fn handle_share_requests(
+    mut contexts: EguiContexts,
+    mut requests: MessageReader<ShareRequest>,
+    responsive: Option<Res<ResponsiveInfo>>,
+    mut share_events: MessageWriter<ShareEvent>,
+    mut notifications: MessageWriter<Notification>,
+    #[cfg(feature = "settings")] parental_controls: Option<Res<ParentalControls>>,
+) {
+    if requests.is_empty() {
+        return;
+    }
+
+    #[cfg(feature = "settings")]
+    if parental_controls.is_some_and(|controls| !controls.sharing_allowed()) {
+        for request in requests.read() {
+            info!(
+                "Sharing '{}' blocked by parental controls",
+                request.result.title
+            );
+            share_events.write(ShareEvent::Blocked {
+                title: request.result.title.clone(),
+            });
+        }
+        notifications.write(Notification::warning(
+            "Sharing blocked",
+            "Sharing is turned off in the parental controls",
+        ));
+        return;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let use_web_share = responsive.is_some_and(|responsive| responsive.is_mobile());
+
+    for request in requests.read() {
+        let result = &request.result;
+
+        if use_web_share && web_share(result) {
+            info!("Shared result '{}'", result.title);
+            share_events.write(ShareEvent::Shared {
+                title: result.title.clone(),
+            });
+            continue;
+        }
+
+        ctx.copy_text(result.summary());
+        info!("Copied result '{}' to clipboard", result.title);
+        notifications.write(Notification::success(
+            "Copied",
+            "Result copied to clipboard",
+        ));
+        share_events.write(ShareEvent::Copied {
+            title: result.title.clone(),
+        });
+    }
+}
+
+/// Invoke the Web Share API, returns false when it is unavailable
+#[cfg(target_arch = "wasm32")]
+fn web_share(result: &ShareResult) -> bool {
+    let data = browser::ShareData::new();
+    data.set_title(&result.title);
+    data.set_text(&result.text());
+    if let Some(link) = &result.link {
+        data.set_url(link);
+    }
+
+    match browser::share(&data) {
+        Ok(_) => true,
+        Err(err) => {
+            warn!("Web Share API unavailable: {:?}", err);
+            false
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn web_share(_result: &ShareResult) -> bool {
+    false
+}
+
+#[cfg(target_arch = "wasm32")]
+mod browser {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_name = Object)]
+        pub type ShareData;
+
+        #[wasm_bindgen(constructor, js_class = Object)]
+        pub fn new() -> ShareData;
+
+        #[wasm_bindgen(method, setter)]
+        pub fn set_title(this: &ShareData, title: &str);
+
+        #[wasm_bindgen(method, setter)]
+        pub fn set_text(this: &ShareData, text: &str);
+
+        #[wasm_bindgen(method, setter)]
+        pub fn set_url(this: &ShareData, url: &str);
+
+        #[wasm_bindgen(catch, js_namespace = navigator, js_name = share)]
+        pub fn share(data: &ShareData) -> Result<JsValue, JsValue>;
+    }
+}
+
+/// Themed "Share" button for result screens, send a [`ShareRequest`] on click
+pub struct ShareButton<'a> {
+    pub label: &'a str,
+    pub theme: &'a KonnektorenTheme,
+    pub width: Option<f32>,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> ShareButton<'a> {
+    pub fn new(theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            label: "Share",
+            theme,
+            width: None,
+            responsive_info: None,
+        }
+    }
+
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.label = label;
+        self
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+}
+
+impl egui::Widget for ShareButton<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let mut button = ThemedButton::new(self.label, self.theme)
+            .with_style(|button| button.stroke(egui::Stroke::new(1.0, self.theme.accent)));
+
+        if let Some(responsive_info) = self.responsive_info {
+            button = button.responsive(responsive_info);
+        }
+        if let Some(width) = self.width {
+            button = button.width(width);
+        }
+
+        button.show(ui)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_share_result_summary() {
+        let result = ShareResult::new("Articles")
+            .with_score(8, 10)
+            .with_streak(3)
+            .with_link("https://example.org/?challenge=articles");
+
+        assert_eq!(
+            result.summary(),
+            "Articles\nScore: 8/10\nStreak: 3\nhttps://example.org/?challenge=articles"
+        );
+    }
+
+    #[cfg(feature = "settings")]
+    #[test]
+    fn test_share_blocked_by_parental_controls() {
+        use crate::test_utils::TestHarness;
+
+        fn copied(harness: &TestHarness) -> bool {
+            harness
+                .output()
+                .platform_output
+                .commands
+                .iter()
+                .any(|command| matches!(command, egui::OutputCommand::CopyText(_)))
+        }
+
+        let mut harness = TestHarness::new().with_plugins(SharePlugin);
+        harness
+            .world_mut()
+            .insert_resource(ParentalControls::default());
+        harness.run_frames(1);
+
+        harness.write_message(ShareRequest::new(ShareResult::new("Articles")));
+        harness.run_frames(1);
+        assert!(copied(&harness));
+        assert!(matches!(
+            harness.read_messages::<ShareEvent>().as_slice(),
+            [ShareEvent::Copied { .. }]
+        ));
+
+        harness
+            .world_mut()
+            .resource_mut::<ParentalControls>()
+            .enabled = true;
+        harness.write_message(ShareRequest::new(ShareResult::new("Articles")));
+        harness.run_frames(1);
+        assert!(!copied(&harness));
+        assert!(matches!(
+            harness.read_messages::<ShareEvent>().as_slice(),
+            [ShareEvent::Blocked { title }] if title == "Articles"
+        ));
+    }
+}
@@ -0,0 +1,580 @@
+use crate::{
+    theme::KonnektorenTheme,
+    ui::{responsive::ResponsiveInfo, widgets::ThemedButton},
+};
+use bevy::prelude::*;
+use bevy_egui::egui;
+use std::sync::Arc;
+
+/// Plugin turning spoken answers into text, e.g. for pronunciation practice.
+///
+/// In browsers the Web Speech Recognition API is used, other platforms can
+/// plug in a [`SpeechBackend`] through the [`SpeechBackendResource`]. The
+/// player holds the [`PushToTalkButton`] while speaking, the recognized text
+/// is sent as [`SpeechInputEvent::Recognized`] when its confidence reaches the
+/// [`SpeechInputSettings::confidence_threshold`]:
+///
+/// ```ignore
+/// app.add_plugins(SpeechInputPlugin);
+///
+/// // In the UI of the question
+/// ui.add(PushToTalkButton::new(&mut speech, &theme));
+///
+/// fn check(mut events: MessageReader<SpeechInputEvent>) {
+///     for event in events.read() {
+///         if let SpeechInputEvent::Recognized(answer) = event {
+///             let correct = answer.matches("der Hund");
+///         }
+///     }
+/// }
+/// ```
+pub struct SpeechInputPlugin;
+
+impl Plugin for SpeechInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpeechInputSettings>()
+            .init_resource::<SpeechInput>()
+            .init_resource::<SpeechBackendResource>()
+            .add_message::<SpeechInputEvent>()
+            .add_systems(Update, update_speech_input);
+    }
+}
+
+/// Settings of the speech recognition, can be changed at runtime
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct SpeechInputSettings {
+    /// BCP 47 language tag of the spoken answers
+    pub language: String,
+    /// Results below this confidence (0 to 1) are rejected
+    pub confidence_threshold: f32,
+    /// Alternative transcripts requested per result
+    pub max_alternatives: u32,
+}
+
+impl Default for SpeechInputSettings {
+    fn default() -> Self {
+        Self {
+            language: "de-DE".to_string(),
+            confidence_threshold: 0.5,
+            max_alternatives: 3,
+        }
+    }
+}
+
+/// One possible transcript of what was said
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeechAlternative {
+    pub transcript: String,
+    /// Confidence of the recognizer from 0 to 1
+    pub confidence: f32,
+}
+
+/// Recognized spoken answer
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpokenAnswer {
+    /// Most confident transcript
+    pub transcript: String,
+    pub confidence: f32,
+    /// All transcripts, most confident first
+    pub alternatives: Vec<SpeechAlternative>,
+}
+
+impl SpokenAnswer {
+    /// Whether one of the transcripts is the answer, ignoring case and
+    /// punctuation
+    pub fn matches(&self, answer: &str) -> bool {
+        let answer = normalize_transcript(answer);
+        self.alternatives
+            .iter()
+            .any(|alternative| normalize_transcript(&alternative.transcript) == answer)
+    }
+}
+
+/// Lowercase words of a transcript without punctuation
+fn normalize_transcript(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Events of the speech recognition
+#[derive(Message, Debug, Clone, PartialEq)]
+pub enum SpeechInputEvent {
+    /// The player started speaking
+    Started,
+    /// An answer was recognized with enough confidence
+    Recognized(SpokenAnswer),
+    /// An answer was recognized, but below the confidence threshold
+    Rejected { transcript: String, confidence: f32 },
+    /// Recognition failed, e.g. because the microphone was blocked
+    Error(String),
+}
+
+/// What a [`SpeechBackend`] reports when polled
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpeechBackendEvent {
+    /// Final result with its alternatives
+    Result(Vec<SpeechAlternative>),
+    Error(String),
+    /// The backend stopped listening
+    Ended,
+}
+
+/// Speech recognition engine
+pub trait SpeechBackend: Send + Sync + 'static {
+    fn is_available(&self) -> bool;
+
+    /// Start listening
+    fn start(&self, language: &str, max_alternatives: u32) -> Result<(), String>;
+
+    /// Stop listening, results of what was said so far still follow
+    fn stop(&self);
+
+    /// Events since the last poll
+    fn poll(&self) -> Vec<SpeechBackendEvent>;
+}
+
+/// Backend for platforms without speech recognition
+#[derive(Debug, Default, Clone)]
+pub struct NoSpeechBackend;
+
+impl SpeechBackend for NoSpeechBackend {
+    fn is_available(&self) -> bool {
+        false
+    }
+
+    fn start(&self, _language: &str, _max_alternatives: u32) -> Result<(), String> {
+        Err("Speech recognition is not available".to_string())
+    }
+
+    fn stop(&self) {}
+
+    fn poll(&self) -> Vec<SpeechBackendEvent> {
+        Vec::new()
+    }
+}
+
+/// Resource holding the active speech backend
+#[derive(Resource, Clone)]
+pub struct SpeechBackendResource(pub Arc<dyn SpeechBackend>);
+
+impl Default for SpeechBackendResource {
+    fn default() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        return Self::new(browser::WebSpeechBackend);
+        #[cfg(not(target_arch = "wasm32"))]
+        Self::new(NoSpeechBackend)
+    }
+}
+
+impl SpeechBackendResource {
+    pub fn new(backend: impl SpeechBackend) -> Self {
+        Self(Arc::new(backend))
+    }
+}
+
+/// Resource with the state of the speech input
+#[derive(Resource, Debug, Default)]
+pub struct SpeechInput {
+    /// Listening was requested, e.g. by holding the push-to-talk button
+    requested: bool,
+    listening: bool,
+    available: bool,
+    last_transcript: Option<String>,
+}
+
+impl SpeechInput {
+    /// Start listening in the next update
+    pub fn start_listening(&mut self) {
+        self.requested = true;
+    }
+
+    /// Stop listening, the answer is recognized afterwards
+    pub fn stop_listening(&mut self) {
+        self.requested = false;
+    }
+
+    pub fn is_listening(&self) -> bool {
+        self.listening
+    }
+
+    /// Whether the backend can recognize speech on this platform
+    pub fn is_available(&self) -> bool {
+        self.available
+    }
+
+    /// Transcript of the last result, recognized or rejected
+    pub fn last_transcript(&self) -> Option<&str> {
+        self.last_transcript.as_deref()
+    }
+}
+
+/// System to start and stop the backend and to send its results
+fn update_speech_input(
+    backend: Res<SpeechBackendResource>,
+    settings: Res<SpeechInputSettings>,
+    mut speech: ResMut<SpeechInput>,
+    mut events: MessageWriter<SpeechInputEvent>,
+) {
+    let available = backend.0.is_available();
+    if speech.available != available {
+        speech.available = available;
+    }
+
+    if speech.requested && !speech.listening {
+        match backend
+            .0
+            .start(&settings.language, settings.max_alternatives)
+        {
+            Ok(()) => {
+                speech.listening = true;
+                events.write(SpeechInputEvent::Started);
+            }
+            Err(e) => {
+                warn!("Failed to start speech recognition: {}", e);
+                speech.requested = false;
+                events.write(SpeechInputEvent::Error(e));
+            }
+        }
+    } else if !speech.requested && speech.listening {
+        backend.0.stop();
+        speech.listening = false;
+    }
+
+    for event in backend.0.poll() {
+        match event {
+            SpeechBackendEvent::Result(mut alternatives) => {
+                alternatives.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+                let Some(best) = alternatives.first().cloned() else {
+                    continue;
+                };
+                speech.last_transcript = Some(best.transcript.clone());
+
+                if best.confidence >= settings.confidence_threshold {
+                    events.write(SpeechInputEvent::Recognized(SpokenAnswer {
+                        transcript: best.transcript,
+                        confidence: best.confidence,
+                        alternatives,
+                    }));
+                } else {
+                    events.write(SpeechInputEvent::Rejected {
+                        transcript: best.transcript,
+                        confidence: best.confidence,
+                    });
+                }
+            }
+            SpeechBackendEvent::Error(e) => {
+                warn!("Speech recognition error: {}", e);
+                events.write(SpeechInputEvent::Error(e));
+            }
+            SpeechBackendEvent::Ended => {
+                // Don't restart while the button is still held
+                speech.listening = false;
+                speech.requested = false;
+            }
+        }
+    }
+}
+
+/// Themed button listening while it is held down
+pub struct PushToTalkButton<'a> {
+    pub speech: &'a mut SpeechInput,
+    pub theme: &'a KonnektorenTheme,
+    pub label: &'a str,
+    pub listening_label: &'a str,
+    pub responsive_info: Option<&'a ResponsiveInfo>,
+}
+
+impl<'a> PushToTalkButton<'a> {
+    pub fn new(speech: &'a mut SpeechInput, theme: &'a KonnektorenTheme) -> Self {
+        Self {
+            speech,
+            theme,
+            label: "🎤 Hold to speak",
+            listening_label: "🎤 Listening…",
+            responsive_info: None,
+        }
+    }
+
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.label = label;
+        self
+    }
+
+    pub fn listening_label(mut self, label: &'a str) -> Self {
+        self.listening_label = label;
+        self
+    }
+
+    pub fn responsive(mut self, responsive_info: &'a ResponsiveInfo) -> Self {
+        self.responsive_info = Some(responsive_info);
+        self
+    }
+}
+
+impl egui::Widget for PushToTalkButton<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let theme = self.theme;
+        let listening = self.speech.is_listening();
+        let label = if listening {
+            self.listening_label
+        } else {
+            self.label
+        };
+
+        let mut button = ThemedButton::new(label, theme);
+        if listening {
+            button = button.with_style(|button| {
+                button
+                    .fill(theme.accent)
+                    .stroke(egui::Stroke::new(2.0, theme.primary))
+            });
+        }
+        if let Some(responsive_info) = self.responsive_info {
+            button = button.responsive(responsive_info);
+        }
+
+        let response = ui
+            .add_enabled_ui(self.speech.is_available(), |ui| button.show(ui))
+            .inner;
+        if response.is_pointer_button_down_on() {
+            self.speech.start_listening();
+        } else if self.speech.requested {
+            self.speech.stop_listening();
+        }
+        response
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod browser {
+    use super::{SpeechAlternative, SpeechBackend, SpeechBackendEvent};
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen(inline_js = "
+let recognition = null;
+const results = [];
+let current = [];
+let error = null;
+let ended = false;
+
+function recognitionClass() {
+    return window.SpeechRecognition || window.webkitSpeechRecognition;
+}
+
+export function speech_available() {
+    return !!recognitionClass();
+}
+
+export function speech_start(lang, maxAlternatives) {
+    const Recognition = recognitionClass();
+    if (!Recognition) {
+        return false;
+    }
+    recognition = new Recognition();
+    recognition.lang = lang;
+    recognition.interimResults = false;
+    recognition.maxAlternatives = maxAlternatives;
+    recognition.onresult = (event) => {
+        for (let i = event.resultIndex; i < event.results.length; i++) {
+            const result = event.results[i];
+            if (result.isFinal) {
+                results.push(Array.from(result, (alternative) => [alternative.transcript, alternative.confidence]));
+            }
+        }
+    };
+    recognition.onerror = (event) => { error = event.error; };
+    recognition.onend = () => { ended = true; };
+    recognition.start();
+    return true;
+}
+
+export function speech_stop() {
+    if (recognition) {
+        recognition.stop();
+    }
+}
+
+export function speech_next_transcripts() {
+    current = results.shift() || [];
+    return current.map((alternative) => alternative[0]);
+}
+
+export function speech_current_confidences() {
+    return new Float32Array(current.map((alternative) => alternative[1]));
+}
+
+export function speech_take_error() {
+    const taken = error;
+    error = null;
+    return taken;
+}
+
+export function speech_take_ended() {
+    const taken = ended;
+    ended = false;
+    return taken;
+}")]
+    extern "C" {
+        fn speech_available() -> bool;
+        fn speech_start(lang: &str, max_alternatives: u32) -> bool;
+        fn speech_stop();
+        fn speech_next_transcripts() -> Vec<String>;
+        fn speech_current_confidences() -> Vec<f32>;
+        fn speech_take_error() -> Option<String>;
+        fn speech_take_ended() -> bool;
+    }
+
+    /// Backend using the Web Speech Recognition API of the browser
+    pub struct WebSpeechBackend;
+
+    impl SpeechBackend for WebSpeechBackend {
+        fn is_available(&self) -> bool {
+            speech_available()
+        }
+
+        fn start(&self, language: &str, max_alternatives: u32) -> Result<(), String> {
+            if speech_start(language, max_alternatives) {
+                Ok(())
+            } else {
+                Err("The browser does not support speech recognition".to_string())
+            }
+        }
+
+        fn stop(&self) {
+            speech_stop();
+        }
+
+        fn poll(&self) -> Vec<SpeechBackendEvent> {
+            let mut events = Vec::new();
+            loop {
+                let transcripts = speech_next_transcripts();
+                if transcripts.is_empty() {
+                    break;
+                }
+                let alternatives = transcripts
+                    .into_iter()
+                    .zip(speech_current_confidences())
+                    .map(|(transcript, confidence)| SpeechAlternative {
+                        transcript,
+                        confidence,
+                    })
+                    .collect();
+                events.push(SpeechBackendEvent::Result(alternatives));
+            }
+            if let Some(error) = speech_take_error() {
+                events.push(SpeechBackendEvent::Error(error));
+            }
+            if speech_take_ended() {
+                events.push(SpeechBackendEvent::Ended);
+            }
+            events
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TestHarness, theme::KonnektorenThemePlugin};
+    use bevy_egui::{EguiContexts, EguiPrimaryContextPass};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct TestBackend {
+        listening: Mutex<bool>,
+        events: Mutex<Vec<SpeechBackendEvent>>,
+    }
+
+    impl SpeechBackend for TestBackend {
+        fn is_available(&self) -> bool {
+            true
+        }
+
+        fn start(&self, _language: &str, _max_alternatives: u32) -> Result<(), String> {
+            *self.listening.lock().unwrap() = true;
+            Ok(())
+        }
+
+        fn stop(&self) {
+            *self.listening.lock().unwrap() = false;
+            // The answer is recognized after the player let go
+            self.events.lock().unwrap().extend([
+                SpeechBackendEvent::Result(vec![
+                    SpeechAlternative {
+                        transcript: "der Hund.".to_string(),
+                        confidence: 0.9,
+                    },
+                    SpeechAlternative {
+                        transcript: "der Mund".to_string(),
+                        confidence: 0.4,
+                    },
+                ]),
+                SpeechBackendEvent::Result(vec![SpeechAlternative {
+                    transcript: "die Katze".to_string(),
+                    confidence: 0.2,
+                }]),
+                SpeechBackendEvent::Ended,
+            ]);
+        }
+
+        fn poll(&self) -> Vec<SpeechBackendEvent> {
+            std::mem::take(&mut *self.events.lock().unwrap())
+        }
+    }
+
+    fn render_push_to_talk(
+        mut contexts: EguiContexts,
+        mut speech: ResMut<SpeechInput>,
+        theme: Res<KonnektorenTheme>,
+    ) {
+        let Ok(ctx) = contexts.ctx_mut() else {
+            return;
+        };
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.add(PushToTalkButton::new(&mut speech, &theme));
+        });
+    }
+
+    #[test]
+    fn test_push_to_talk() {
+        let backend = Arc::new(TestBackend::default());
+        let mut harness =
+            TestHarness::new().with_plugins((KonnektorenThemePlugin, SpeechInputPlugin));
+        harness
+            .app_mut()
+            .insert_resource(SpeechBackendResource(backend.clone()))
+            .add_systems(EguiPrimaryContextPass, render_push_to_talk);
+        harness.run_frames(2);
+
+        let button = harness.widget_rect("🎤 Hold to speak").unwrap().center();
+        harness.drag(button, button);
+        harness.run_frames(2);
+        assert!(!*backend.listening.lock().unwrap());
+
+        let events = harness.read_messages::<SpeechInputEvent>();
+        assert_eq!(events[0], SpeechInputEvent::Started);
+        let SpeechInputEvent::Recognized(answer) = &events[1] else {
+            panic!("Expected a recognized answer, got {:?}", events);
+        };
+        assert!(answer.matches("Der Hund"));
+        assert!(!answer.matches("die Katze"));
+        assert_eq!(
+            events[2],
+            SpeechInputEvent::Rejected {
+                transcript: "die Katze".to_string(),
+                confidence: 0.2,
+            }
+        );
+        let speech = harness.world().resource::<SpeechInput>();
+        assert!(!speech.is_listening());
+        assert_eq!(speech.last_transcript(), Some("die Katze"));
+    }
+}